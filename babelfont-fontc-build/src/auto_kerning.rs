@@ -0,0 +1,266 @@
+// Kerning suggestions module
+//
+// This module proposes kerning values from outline geometry. Glyphs are
+// interpolated at a location, flattened, and cut into horizontal rows; the
+// ink extents of each row give a glyph's left and right profile. The white
+// space between the right profile of one glyph and the left profile of the
+// next, averaged over the rows (the area between the profiles divided by
+// its height), is compared with that of a reference pair such as "HH", and
+// the difference is the suggested kerning. Open counters only count up to
+// a limited depth, so a "T" or "L" is not kerned as if its arms were absent.
+
+use std::collections::HashMap;
+
+use crate::errors::ApiError;
+use crate::glyph_outlines::{design_location, flatten_layer_components_cached, LayerSource};
+use crate::spacing::{measure_zone, shapes_polylines, spacing_category};
+
+/// Height of the rows profiles are measured in, in font units
+const ROW_HEIGHT: f64 = 10.0;
+
+/// Most pairs measured in one request
+const MAX_PAIRS: usize = 50_000;
+
+/// Ink profile of a glyph
+struct Profile {
+    width: f64,
+    /// Leftmost and rightmost ink of each row, if the row has ink
+    rows: Vec<Option<(f64, f64)>>,
+    left: f64,
+    right: f64,
+}
+
+impl Profile {
+    /// Measure a glyph at the location of a layer source
+    fn measure(layers: &LayerSource, glyph_name: &str, bottom: f64, row_count: usize) -> Result<Profile, ApiError> {
+        let layer = layers.layer(glyph_name)?;
+        let shapes = flatten_layer_components_cached(&layer, layers)?;
+        let polylines = shapes_polylines(&shapes);
+        let rows: Vec<Option<(f64, f64)>> = (0..row_count)
+            .map(|row| {
+                let row_bottom = bottom + row as f64 * ROW_HEIGHT;
+                measure_zone(&polylines, row_bottom, row_bottom + ROW_HEIGHT).map(|zone| (zone.left, zone.right))
+            })
+            .collect();
+        let left = rows.iter().flatten().map(|(left, _)| *left).fold(f64::INFINITY, f64::min);
+        let right = rows.iter().flatten().map(|(_, right)| *right).fold(f64::NEG_INFINITY, f64::max);
+        if !left.is_finite() {
+            return Err("Glyph has no outline".to_string());
+        }
+        Ok(Profile { width: layer.width as f64, rows, left, right })
+    }
+}
+
+/// Mean white space between two glyphs set side by side, and their closest distance
+///
+/// Each row's white space runs from the right profile of the left glyph to
+/// the left profile of the right glyph, with each profile no deeper than
+/// `depth` from the glyph's extreme. Rows where neither glyph has ink are
+/// left out; the closest distance only counts rows where both have ink.
+fn pair_gap(left: &Profile, right: &Profile, depth: f64) -> (f64, f64) {
+    let mut total = 0.0;
+    let mut count = 0;
+    let mut closest = f64::INFINITY;
+    for (left_row, right_row) in left.rows.iter().zip(&right.rows) {
+        if left_row.is_none() && right_row.is_none() {
+            continue;
+        }
+        let left_edge = left_row.map_or(f64::NEG_INFINITY, |(_, right)| right).max(left.right - depth);
+        let right_edge = right_row.map_or(f64::INFINITY, |(left, _)| left).min(right.left + depth);
+        let gap = (left.width - left_edge) + right_edge;
+        total += gap;
+        count += 1;
+        if let (Some((_, ink_right)), Some((ink_left, _))) = (left_row, right_row) {
+            closest = closest.min(left.width - ink_right + ink_left);
+        }
+    }
+    (if count > 0 { total / count as f64 } else { 0.0 }, closest)
+}
+
+/// Glyphs making up one side of the requested pairs: a list of names or a spacing category
+fn side_glyphs(font: &babelfont::Font, side: &serde_json::Value) -> Result<Vec<String>, ApiError> {
+    match side {
+        serde_json::Value::String(category) => {
+            const CATEGORIES: [&str; 5] = ["uppercase", "lowercase", "figures", "punctuation", "other"];
+            if !CATEGORIES.contains(&category.as_str()) {
+                return Err(ApiError::invalid_argument("category", category));
+            }
+            Ok(font
+                .glyphs
+                .iter()
+                .filter(|glyph| glyph.exported && spacing_category(font, glyph) == category)
+                .map(|glyph| glyph.name.to_string())
+                .collect())
+        }
+        serde_json::Value::Array(names) => names
+            .iter()
+            .map(|name| name.as_str().map(str::to_string).ok_or_else(|| ApiError::invalid_argument("glyph name", name)))
+            .collect(),
+        other => Err(ApiError::invalid_json("pairs", format!("Expected a category or a list of glyph names, got {}", other))),
+    }
+}
+
+/// The pairs to measure: an array of [left, right] pairs or an object with `left` and `right` sides
+fn requested_pairs(font: &babelfont::Font, spec: &serde_json::Value) -> Result<Vec<(String, String)>, ApiError> {
+    let pairs: Vec<(String, String)> = match spec {
+        serde_json::Value::Array(pairs) => pairs
+            .iter()
+            .map(|pair| match pair.as_array().map(|pair| pair.as_slice()) {
+                Some([serde_json::Value::String(left), serde_json::Value::String(right)]) => {
+                    Ok((left.clone(), right.clone()))
+                }
+                _ => Err(ApiError::invalid_json("pairs", format!("Expected a pair of glyph names, got {}", pair))),
+            })
+            .collect::<Result<_, ApiError>>()?,
+        serde_json::Value::Object(_) => {
+            let side = |key: &str| {
+                spec.get(key)
+                    .ok_or_else(|| ApiError::invalid_json("pairs", format!("Missing '{}' side", key)))
+                    .and_then(|side| side_glyphs(font, side))
+            };
+            let (lefts, rights) = (side("left")?, side("right")?);
+            if lefts.len().saturating_mul(rights.len()) > MAX_PAIRS {
+                return Err(ApiError::invalid_argument(
+                    "pair count",
+                    format!("{} × {} (at most {})", lefts.len(), rights.len(), MAX_PAIRS),
+                ));
+            }
+            lefts
+                .iter()
+                .flat_map(|left| rights.iter().map(move |right| (left.clone(), right.clone())))
+                .collect()
+        }
+        other => return Err(ApiError::invalid_json("pairs", format!("Expected pairs or categories, got {}", other))),
+    };
+    if pairs.len() > MAX_PAIRS {
+        return Err(ApiError::invalid_argument("pair count", format!("{} (at most {})", pairs.len(), MAX_PAIRS)));
+    }
+    Ok(pairs)
+}
+
+/// Suggest kerning for pairs from the white space between their outlines
+///
+/// Every pair is compared with a reference pair: the suggested value makes
+/// the pair's mean white space (with counters no deeper than `depth`)
+/// equal to the reference's, but never brings the outlines closer than
+/// `minGap`. Values are rounded to multiples of `round`; pairs whose
+/// suggestion is smaller than `threshold` are left out. Components are
+/// flattened. Glyphs without outlines are skipped.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `pairs_json` - JSON array of pairs, e.g., '[["A", "V"], ["T", "o"]]', or an object with sides, each a list of glyph names or a category ("uppercase", "lowercase", "figures", "punctuation", "other"), e.g., '{"left": "uppercase", "right": ["o", "e"]}'
+/// * `location_json` - JSON object with axis tags and values in USER SPACE; empty for the default location
+/// * `options_json` - Optional JSON object:
+///   - `reference`: [string, string] - Pair whose white space is the target (default: ["H", "H"], else ["n", "n"])
+///   - `depth`: number - Deepest counter that counts as white space, in font units (default: 15% of the UPM)
+///   - `minGap`: number - Closest the outlines may come, in font units (default: 2% of the UPM)
+///   - `round`: number - Round values to multiples of this (default: 5)
+///   - `threshold`: number - Leave out suggestions smaller than this (default: the rounding step)
+///
+/// # Returns
+/// * `JsonValue` - '{"suggestions": [{"left": "A", "right": "V", "value": -80, "gap": 132.5}], "reference": {"left": "H", "right": "H", "gap": 180.0}, "skipped": [{"glyph": "space", "reason": "..."}]}'
+pub fn suggest_kerning(
+    font: &babelfont::Font,
+    pairs_json: &str,
+    location_json: &str,
+    options_json: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let spec: serde_json::Value =
+        serde_json::from_str(pairs_json).map_err(|e| ApiError::invalid_json("pairs", e))?;
+    let pairs = requested_pairs(font, &spec)?;
+
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let upm = font.upm as f64;
+    let depth = options.get("depth").and_then(|v| v.as_f64()).unwrap_or(upm * 0.15);
+    let min_gap = options.get("minGap").and_then(|v| v.as_f64()).unwrap_or(upm * 0.02);
+    let round = options.get("round").and_then(|v| v.as_f64()).filter(|round| *round > 0.0).unwrap_or(5.0);
+    let threshold = options.get("threshold").and_then(|v| v.as_f64()).unwrap_or(round);
+    let reference = match options.get("reference") {
+        Some(reference) => match reference.as_array().map(|pair| pair.as_slice()) {
+            Some([serde_json::Value::String(left), serde_json::Value::String(right)]) => (left.clone(), right.clone()),
+            _ => return Err(ApiError::invalid_argument("reference pair", reference)),
+        },
+        None => ["H", "n"]
+            .into_iter()
+            .find(|name| font.glyphs.get(name).is_some())
+            .map(|name| (name.to_string(), name.to_string()))
+            .ok_or_else(|| ApiError::glyph_not_found("H"))?,
+    };
+
+    // Rows span the default master's descender to ascender
+    let master = crate::source_checks::default_master_id(font)
+        .and_then(|id| font.masters.iter().find(|master| master.id == id))
+        .or_else(|| font.masters.first());
+    let metric = |metric: babelfont::MetricType| {
+        master.and_then(|master| master.metrics.get(&metric)).map(|&value| value as f64)
+    };
+    let bottom = metric(babelfont::MetricType::Descender).unwrap_or(-0.25 * upm).min(0.0);
+    let top = metric(babelfont::MetricType::Ascender).unwrap_or(0.85 * upm).max(bottom + ROW_HEIGHT);
+    let row_count = ((top - bottom) / ROW_HEIGHT).ceil() as usize;
+
+    let location = design_location(font, location_json)?;
+    let layers = LayerSource::new(font, &location);
+    let mut profiles: HashMap<String, Result<Profile, ApiError>> = HashMap::new();
+    let names = pairs.iter().flat_map(|(left, right)| [left, right]).chain([&reference.0, &reference.1]);
+    for name in names {
+        if !profiles.contains_key(name) {
+            let measured = match font.glyphs.get(name) {
+                Some(_) => Profile::measure(&layers, name, bottom, row_count),
+                None => Err(ApiError::glyph_not_found(name.as_str())),
+            };
+            profiles.insert(name.clone(), measured);
+        }
+    }
+
+    let (target, _) = match (&profiles[&reference.0], &profiles[&reference.1]) {
+        (Ok(left), Ok(right)) => pair_gap(left, right, depth),
+        (Err(error @ ApiError::GlyphNotFound { .. }), _) | (_, Err(error @ ApiError::GlyphNotFound { .. })) => {
+            return Err(error.clone())
+        }
+        (Err(reason), _) | (_, Err(reason)) => {
+            return Err(format!("Cannot measure reference pair {} {}: {}", reference.0, reference.1, reason).into())
+        }
+    };
+
+    let mut suggestions = Vec::new();
+    for (left, right) in &pairs {
+        let (Ok(left_profile), Ok(right_profile)) = (&profiles[left], &profiles[right]) else { continue };
+        let (gap, closest) = pair_gap(left_profile, right_profile, depth);
+        let mut value = target - gap;
+        if closest.is_finite() {
+            value = value.max(min_gap - closest);
+        }
+        let value = (value / round).round() * round;
+        if value.abs() < threshold || value == 0.0 {
+            continue;
+        }
+        suggestions.push(serde_json::json!({
+            "left": left,
+            "right": right,
+            "value": value,
+            "gap": (gap * 10.0).round() / 10.0,
+        }));
+    }
+
+    let mut skipped: Vec<serde_json::Value> = profiles
+        .iter()
+        .filter_map(|(glyph, profile)| profile.as_ref().err().map(|reason| serde_json::json!({ "glyph": glyph, "reason": reason.to_string() })))
+        .collect();
+    skipped.sort_by(|a, b| a["glyph"].as_str().cmp(&b["glyph"].as_str()));
+
+    Ok(serde_json::json!({
+        "suggestions": suggestions,
+        "reference": {
+            "left": reference.0,
+            "right": reference.1,
+            "gap": (target * 10.0).round() / 10.0,
+        },
+        "skipped": skipped,
+    }))
+}
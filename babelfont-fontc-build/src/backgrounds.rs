@@ -0,0 +1,208 @@
+// Background layers module
+//
+// This module manages the background layers of source glyphs, which hold
+// outlines for tracing and for comparing revisions but are never compiled.
+// A foreground layer refers to its background by `background_layer_id`.
+// Foreground layers can be copied to their backgrounds, swapped with them
+// and backgrounds removed; backgrounds can also be interpolated at a
+// location to show them behind interpolated outlines.
+
+use babelfont::Layer;
+use fontdrasil::coords::DesignLocation;
+use std::collections::HashSet;
+
+use crate::errors::ApiError;
+use crate::path_ops::in_scope;
+use crate::source_checks::selected_glyphs;
+
+/// Index of the background layer of a foreground layer
+fn background_index(glyph: &babelfont::Glyph, layer: &Layer) -> Option<usize> {
+    let id = layer.background_layer_id.as_ref()?;
+    glyph
+        .layers
+        .iter()
+        .position(|candidate| candidate.is_background && candidate.id.as_ref() == Some(id))
+}
+
+/// The background layer of a foreground layer
+pub(crate) fn background_of<'a>(glyph: &'a babelfont::Glyph, layer: &Layer) -> Option<&'a Layer> {
+    background_index(glyph, layer).map(|index| &glyph.layers[index])
+}
+
+/// A new background layer for a foreground layer, with an ID not used by the glyph
+fn new_background(glyph: &babelfont::Glyph, layer: &Layer) -> Layer {
+    let base = format!("{}.background", layer.id.as_deref().unwrap_or("layer"));
+    let mut id = base.clone();
+    let mut counter = 1;
+    while glyph.layers.iter().any(|candidate| candidate.id.as_deref() == Some(id.as_str())) {
+        counter += 1;
+        id = format!("{}{}", base, counter);
+    }
+    let mut background = layer.clone();
+    background.id = Some(id);
+    background.name = None;
+    background.shapes = Vec::new();
+    background.anchors = Vec::new();
+    background.guides = Vec::new();
+    background.is_background = true;
+    background.background_layer_id = None;
+    background.layer_index = None;
+    background.format_specific = Default::default();
+    background
+}
+
+/// Background edit applied to each foreground layer in scope
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackgroundOp {
+    /// Replace the background's shapes and anchors by the foreground's
+    CopyToBackground,
+    /// Exchange shapes and anchors of foreground and background
+    Swap,
+    /// Remove the background layer
+    Clear,
+}
+
+/// Apply a background edit to the layers of glyphs
+///
+/// Copying and swapping create a background layer where a foreground layer
+/// has none. Swapping leaves the foreground empty if the background was.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID
+/// * `op` - Edit to apply
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 2, "glyphs": ["a"]}' and the names of glyphs whose foreground changed (only when swapping)
+pub fn edit_backgrounds(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    layer_scope: &str,
+    op: BackgroundOp,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let names: Vec<String> = selected_glyphs(font, glyph_names_json)?
+        .iter()
+        .map(|glyph| glyph.name.to_string())
+        .collect();
+    let scoped: Vec<(String, Vec<usize>)> = names
+        .into_iter()
+        .filter_map(|name| {
+            let glyph = font.glyphs.get(&name)?;
+            let layers: Vec<usize> = glyph
+                .layers
+                .iter()
+                .enumerate()
+                .filter(|(_, layer)| in_scope(font, layer, layer_scope))
+                .map(|(index, _)| index)
+                .collect();
+            Some((name, layers))
+        })
+        .collect();
+
+    let mut layer_count = 0;
+    let mut edited = HashSet::new();
+    let mut foreground_changed = HashSet::new();
+    for (name, layers) in scoped {
+        let Some(glyph) = font.glyphs.get_mut(&name) else { continue };
+        let mut removed = Vec::new();
+        for index in layers {
+            let existing = background_index(glyph, &glyph.layers[index]);
+            match op {
+                BackgroundOp::Clear => {
+                    glyph.layers[index].background_layer_id = None;
+                    match existing {
+                        Some(background) => removed.push(background),
+                        None => continue,
+                    }
+                }
+                BackgroundOp::CopyToBackground | BackgroundOp::Swap => {
+                    let background = match existing {
+                        Some(background) => background,
+                        None => {
+                            let background = new_background(glyph, &glyph.layers[index]);
+                            glyph.layers[index].background_layer_id = background.id.clone();
+                            glyph.layers.push(background);
+                            glyph.layers.len() - 1
+                        }
+                    };
+                    if op == BackgroundOp::Swap {
+                        let (shapes, anchors) = {
+                            let background = &mut glyph.layers[background];
+                            (std::mem::take(&mut background.shapes), std::mem::take(&mut background.anchors))
+                        };
+                        let foreground = &mut glyph.layers[index];
+                        let old_shapes = std::mem::replace(&mut foreground.shapes, shapes);
+                        let old_anchors = std::mem::replace(&mut foreground.anchors, anchors);
+                        glyph.layers[background].shapes = old_shapes;
+                        glyph.layers[background].anchors = old_anchors;
+                        foreground_changed.insert(name.clone());
+                    } else {
+                        let (shapes, anchors) = (glyph.layers[index].shapes.clone(), glyph.layers[index].anchors.clone());
+                        glyph.layers[background].shapes = shapes;
+                        glyph.layers[background].anchors = anchors;
+                    }
+                }
+            }
+            layer_count += 1;
+            edited.insert(name.clone());
+        }
+        // Remove from the back so earlier indices stay valid
+        removed.sort_unstable();
+        removed.dedup();
+        for index in removed.into_iter().rev() {
+            glyph.layers.remove(index);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = edited.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+    });
+    Ok((result, foreground_changed))
+}
+
+/// Whether a master sits at a design location
+fn master_at(master: &babelfont::Master, location: &DesignLocation) -> bool {
+    master.location.iter().all(|(tag, coord)| {
+        location
+            .iter()
+            .find(|(candidate, _)| *candidate == tag)
+            .is_none_or(|(_, target)| (target.to_f64() - coord.to_f64()).abs() < 1e-6)
+    })
+}
+
+/// Background of a glyph at a design location
+///
+/// At a master's location this is the background of the master layer.
+/// Elsewhere the backgrounds of all master layers are interpolated, which
+/// needs every master to have a compatible background.
+pub(crate) fn interpolated_background(
+    font: &babelfont::Font,
+    glyph: &babelfont::Glyph,
+    location: &DesignLocation,
+) -> Option<Layer> {
+    let master_layer = |master: &babelfont::Master| {
+        glyph
+            .layers
+            .iter()
+            .find(|layer| !layer.is_background && layer.id.as_ref() == Some(&master.id))
+    };
+    if let Some(layer) = font.masters.iter().find(|master| master_at(master, location)).and_then(master_layer) {
+        return background_of(glyph, layer).cloned();
+    }
+
+    // Interpolate a stand-in glyph whose master layers are the backgrounds
+    let mut backgrounds = Vec::new();
+    for master in font.masters.iter() {
+        let mut background = background_of(glyph, master_layer(master)?)?.clone();
+        background.id = Some(master.id.clone());
+        background.is_background = false;
+        backgrounds.push(background);
+    }
+    let mut stand_in = glyph.clone();
+    stand_in.layers = backgrounds;
+    crate::interpolation::manually_interpolate_layer(font, &stand_in, location).ok()
+}
@@ -0,0 +1,722 @@
+// Bitmap strike module
+//
+// This module stores per-glyph PNG bitmaps for emoji-style fonts and writes
+// them into sbix or CBDT/CBLC tables after compilation, as babelfont has no
+// bitmap glyph model. Bitmaps are kept base64-encoded in each glyph's
+// format-specific data, one per strike size (pixels per em), together with
+// the offset of the bitmap's bottom-left corner from the glyph origin in
+// pixels. The tables are written byte by byte. A reader lists the strikes
+// of compiled fonts with either table.
+
+use std::collections::{BTreeMap, HashMap};
+
+use read_fonts::types::{GlyphId, Tag};
+use read_fonts::{FontRef, TableProvider};
+use wasm_bindgen::prelude::*;
+use write_fonts::FontBuilder;
+
+use crate::errors::ApiError;
+
+/// Format-specific key under which a glyph's bitmaps are stored
+pub const BITMAP_STRIKES_KEY: &str = "space.counterpunch.bitmapStrikes";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+/// Pixels per inch written for sbix strikes
+const SBIX_PPI: u16 = 72;
+
+/// CBDT image format: small metrics and PNG data
+const CBDT_PNG_SMALL_METRICS: u16 = 17;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Table format bitmaps are compiled into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitmapFormat {
+    /// Apple `sbix`
+    Sbix,
+    /// Google `CBDT`/`CBLC`
+    Cbdt,
+}
+
+impl BitmapFormat {
+    pub fn parse(name: &str) -> Result<Self, ApiError> {
+        match name {
+            "sbix" => Ok(BitmapFormat::Sbix),
+            "cbdt" | "CBDT" => Ok(BitmapFormat::Cbdt),
+            other => Err(ApiError::invalid_argument("bitmap format", other)),
+        }
+    }
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = (chunk[0] as u32) << 16
+            | (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+            | chunk.get(2).copied().unwrap_or(0) as u32;
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(triple >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in encoded.bytes().filter(|c| !c.is_ascii_whitespace() && *c != b'=') {
+        let value = BASE64_ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = buffer << 6 | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits & 0xff) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Width and height of a PNG image, or None if the data is not a PNG
+fn png_size(png: &[u8]) -> Option<(u32, u32)> {
+    if png.len() < 24 || png[..8] != PNG_SIGNATURE || &png[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(png[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(png[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// A PNG bitmap of a glyph for one strike
+#[derive(Clone, Debug)]
+pub struct GlyphBitmap {
+    /// Pixels per em of the strike
+    pub ppem: u16,
+    pub png: Vec<u8>,
+    /// Offset of the bitmap's bottom-left corner from the glyph origin, in pixels
+    pub origin_x: i16,
+    pub origin_y: i16,
+}
+
+impl GlyphBitmap {
+    /// Create a bitmap, checking that the data is a PNG image
+    pub fn new(ppem: u16, png: Vec<u8>, origin_x: i16, origin_y: i16) -> Result<Self, ApiError> {
+        if ppem == 0 {
+            return Err(ApiError::invalid_argument("ppem", ppem));
+        }
+        if png_size(&png).is_none() {
+            return Err(ApiError::invalid_argument("PNG data", format!("{} bytes without a PNG header", png.len())));
+        }
+        Ok(GlyphBitmap { ppem, png, origin_x, origin_y })
+    }
+
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        Some(GlyphBitmap {
+            ppem: value.get("ppem")?.as_u64()? as u16,
+            png: base64_decode(value.get("png")?.as_str()?)?,
+            origin_x: value.get("originX").and_then(|v| v.as_i64()).unwrap_or(0) as i16,
+            origin_y: value.get("originY").and_then(|v| v.as_i64()).unwrap_or(0) as i16,
+        })
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "ppem": self.ppem,
+            "png": base64_encode(&self.png),
+            "originX": self.origin_x,
+            "originY": self.origin_y,
+        })
+    }
+
+    /// Width and height in pixels
+    pub fn size(&self) -> (u32, u32) {
+        png_size(&self.png).unwrap_or((0, 0))
+    }
+}
+
+/// Data URL of image data for showing it in an `<img>` element
+fn data_url(graphic_type: &str, data: &[u8]) -> Option<String> {
+    let mime = match graphic_type {
+        "png " => "image/png",
+        "jpg " => "image/jpeg",
+        "tiff" => "image/tiff",
+        _ => return None,
+    };
+    Some(format!("data:{};base64,{}", mime, base64_encode(data)))
+}
+
+/// Read the bitmaps stored on a glyph, sorted by strike size
+pub fn glyph_bitmaps(glyph: &babelfont::Glyph) -> Vec<GlyphBitmap> {
+    let mut bitmaps: Vec<GlyphBitmap> = glyph
+        .format_specific
+        .get(BITMAP_STRIKES_KEY)
+        .and_then(|value| value.as_array())
+        .map(|values| values.iter().filter_map(GlyphBitmap::from_json).collect())
+        .unwrap_or_default();
+    bitmaps.sort_by_key(|bitmap| bitmap.ppem);
+    bitmaps
+}
+
+/// Replace the bitmaps stored on a glyph
+fn write_glyph_bitmaps(glyph: &mut babelfont::Glyph, bitmaps: &[GlyphBitmap]) {
+    if bitmaps.is_empty() {
+        glyph.format_specific.remove(BITMAP_STRIKES_KEY);
+    } else {
+        let value = serde_json::Value::Array(bitmaps.iter().map(GlyphBitmap::to_json).collect());
+        glyph.format_specific.insert(BITMAP_STRIKES_KEY.to_string(), value);
+    }
+}
+
+/// Attach a bitmap to a glyph, replacing its bitmap of the same strike size
+pub fn set_glyph_bitmap(font: &mut babelfont::Font, glyph_name: &str, bitmap: GlyphBitmap) -> Result<(), ApiError> {
+    let glyph = font.glyphs.get_mut(glyph_name).ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let mut bitmaps = glyph_bitmaps(glyph);
+    bitmaps.retain(|existing| existing.ppem != bitmap.ppem);
+    bitmaps.push(bitmap);
+    bitmaps.sort_by_key(|bitmap| bitmap.ppem);
+    write_glyph_bitmaps(glyph, &bitmaps);
+    Ok(())
+}
+
+/// Remove the bitmap of one strike size, or all bitmaps, from a glyph
+///
+/// # Returns
+/// * `usize` - Number of bitmaps removed
+pub fn remove_glyph_bitmaps(font: &mut babelfont::Font, glyph_name: &str, ppem: Option<u16>) -> Result<usize, ApiError> {
+    let glyph = font.glyphs.get_mut(glyph_name).ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let mut bitmaps = glyph_bitmaps(glyph);
+    let count = bitmaps.len();
+    bitmaps.retain(|bitmap| ppem.is_some_and(|ppem| bitmap.ppem != ppem));
+    write_glyph_bitmaps(glyph, &bitmaps);
+    Ok(count - bitmaps.len())
+}
+
+/// Describe the bitmaps of a glyph
+///
+/// # Returns
+/// * `JsonValue` - Array '[{"ppem": 136, "width": 136, "height": 128, "originX": 0, "originY": -10, "dataUrl": "data:image/png;base64,..."}]'
+pub fn describe_glyph_bitmaps(font: &babelfont::Font, glyph_name: &str) -> Result<serde_json::Value, ApiError> {
+    let glyph = font.glyphs.get(glyph_name).ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let bitmaps: Vec<serde_json::Value> = glyph_bitmaps(glyph)
+        .iter()
+        .map(|bitmap| {
+            let (width, height) = bitmap.size();
+            serde_json::json!({
+                "ppem": bitmap.ppem,
+                "width": width,
+                "height": height,
+                "originX": bitmap.origin_x,
+                "originY": bitmap.origin_y,
+                "dataUrl": data_url("png ", &bitmap.png),
+            })
+        })
+        .collect();
+    Ok(serde_json::Value::Array(bitmaps))
+}
+
+/// List the strike sizes of a font and the glyphs with a bitmap in each
+///
+/// # Returns
+/// * `JsonValue` - Array '[{"ppem": 136, "glyphs": ["u1F600", ...]}]'
+pub fn list_strikes(font: &babelfont::Font) -> serde_json::Value {
+    let mut strikes: BTreeMap<u16, Vec<String>> = BTreeMap::new();
+    for glyph in font.glyphs.iter() {
+        for bitmap in glyph_bitmaps(glyph) {
+            strikes.entry(bitmap.ppem).or_default().push(glyph.name.to_string());
+        }
+    }
+    serde_json::Value::Array(
+        strikes
+            .into_iter()
+            .map(|(ppem, glyphs)| serde_json::json!({ "ppem": ppem, "glyphs": glyphs }))
+            .collect(),
+    )
+}
+
+/// Bitmaps collected from the source for compilation
+#[derive(Default)]
+pub struct SourceBitmaps {
+    /// Glyph name, names it may have in the compiled font, and bitmap
+    bitmaps: Vec<(String, Vec<String>, GlyphBitmap)>,
+}
+
+/// Collect the bitmaps of exported glyphs for compilation
+pub fn collect_bitmaps(font: &babelfont::Font) -> SourceBitmaps {
+    let mut collected = SourceBitmaps::default();
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported) {
+        let bitmaps = glyph_bitmaps(glyph);
+        if bitmaps.is_empty() {
+            continue;
+        }
+        let names = crate::variation_sequences::compiled_names(glyph);
+        for bitmap in bitmaps {
+            collected.bitmaps.push((glyph.name.to_string(), names.clone(), bitmap));
+        }
+    }
+    collected
+}
+
+fn push_u16(data: &mut Vec<u8>, value: u16) {
+    data.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Bitmaps by strike size and glyph ID
+type Strikes<'a> = BTreeMap<u16, BTreeMap<u16, &'a GlyphBitmap>>;
+
+/// Write an sbix table
+fn build_sbix(strikes: &Strikes, num_glyphs: u16) -> Vec<u8> {
+    let mut sbix = Vec::new();
+    push_u16(&mut sbix, 1); // version
+    push_u16(&mut sbix, 1); // flags: bit 0 is always set
+    push_u32(&mut sbix, strikes.len() as u32);
+    let offsets_start = sbix.len();
+    sbix.resize(offsets_start + 4 * strikes.len(), 0);
+
+    for (index, (ppem, bitmaps)) in strikes.iter().enumerate() {
+        let strike_start = sbix.len();
+        sbix[offsets_start + 4 * index..offsets_start + 4 * index + 4].copy_from_slice(&(strike_start as u32).to_be_bytes());
+
+        let mut strike = Vec::new();
+        push_u16(&mut strike, *ppem);
+        push_u16(&mut strike, SBIX_PPI);
+        let mut glyph_data = Vec::new();
+        let data_start = 4 + 4 * (num_glyphs as usize + 1);
+        for gid in 0..=num_glyphs {
+            push_u32(&mut strike, (data_start + glyph_data.len()) as u32);
+            if let Some(bitmap) = bitmaps.get(&gid) {
+                glyph_data.extend_from_slice(&bitmap.origin_x.to_be_bytes());
+                glyph_data.extend_from_slice(&bitmap.origin_y.to_be_bytes());
+                glyph_data.extend_from_slice(b"png ");
+                glyph_data.extend_from_slice(&bitmap.png);
+            }
+        }
+        strike.extend_from_slice(&glyph_data);
+        sbix.extend_from_slice(&strike);
+    }
+    sbix
+}
+
+/// Scale font units to pixels at a strike size, clamped to a byte range
+fn pixels(units: f64, ppem: u16, upm: f64, min: i32, max: i32) -> i32 {
+    ((units * ppem as f64 / upm).round() as i32).clamp(min, max)
+}
+
+/// Write CBDT and CBLC tables
+///
+/// Each strike has one index subtable (format 1) covering the glyph IDs
+/// from its first to its last bitmap; glyphs in between without a bitmap
+/// have empty data.
+fn build_cbdt(font: &FontRef, strikes: &Strikes) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let upm = font.head().map_err(|e| format!("Failed to read head table: {:?}", e))?.units_per_em() as f64;
+    let hhea = font.hhea().map_err(|e| format!("Failed to read hhea table: {:?}", e))?;
+    let hmtx = font.hmtx().map_err(|e| format!("Failed to read hmtx table: {:?}", e))?;
+    let (ascender, descender) = (hhea.ascender().to_i16() as f64, hhea.descender().to_i16() as f64);
+
+    let mut cbdt = Vec::new();
+    push_u16(&mut cbdt, 3); // major version
+    push_u16(&mut cbdt, 0);
+
+    let size_records_start = 8;
+    let mut cblc = Vec::new();
+    push_u16(&mut cblc, 3); // major version
+    push_u16(&mut cblc, 0);
+    push_u32(&mut cblc, strikes.len() as u32);
+    cblc.resize(size_records_start + 48 * strikes.len(), 0);
+
+    for (index, (ppem, bitmaps)) in strikes.iter().enumerate() {
+        let (Some(&first), Some(&last)) = (bitmaps.keys().next(), bitmaps.keys().next_back()) else {
+            continue;
+        };
+        if *ppem > u8::MAX as u16 {
+            return Err(format!("CBDT strikes are at most 255 pixels per em, got {}", ppem));
+        }
+
+        // Glyph images and their offsets from the start of the strike's image data
+        let image_data_offset = cbdt.len() as u32;
+        let mut image_offsets = Vec::with_capacity((last - first) as usize + 2);
+        let (mut width_max, mut min_origin_sb, mut min_advance_sb) = (0i32, i32::MAX, i32::MAX);
+        let (mut max_before_bl, mut min_after_bl) = (i32::MIN, i32::MAX);
+        for gid in first..=last {
+            image_offsets.push(cbdt.len() as u32 - image_data_offset);
+            let Some(bitmap) = bitmaps.get(&gid) else { continue };
+            let (width, height) = bitmap.size();
+            if width > u8::MAX as u32 || height > u8::MAX as u32 {
+                return Err(format!("CBDT bitmaps are at most 255 pixels wide and high, got {}x{}", width, height));
+            }
+            let (width, height) = (width as i32, height as i32);
+            let advance = hmtx.advance(GlyphId::new(gid as u32)).unwrap_or(0) as f64;
+            let advance = pixels(advance, *ppem, upm, 0, u8::MAX as i32);
+            let bearing_x = (bitmap.origin_x as i32).clamp(i8::MIN as i32, i8::MAX as i32);
+            let bearing_y = (bitmap.origin_y as i32 + height).clamp(i8::MIN as i32, i8::MAX as i32);
+
+            cbdt.extend_from_slice(&[height as u8, width as u8, bearing_x as i8 as u8, bearing_y as i8 as u8, advance as u8]);
+            push_u32(&mut cbdt, bitmap.png.len() as u32);
+            cbdt.extend_from_slice(&bitmap.png);
+
+            width_max = width_max.max(width);
+            min_origin_sb = min_origin_sb.min(bearing_x);
+            min_advance_sb = min_advance_sb.min(advance - bearing_x - width);
+            max_before_bl = max_before_bl.max(bearing_y);
+            min_after_bl = min_after_bl.min(bearing_y - height);
+        }
+        image_offsets.push(cbdt.len() as u32 - image_data_offset);
+
+        // Index subtable array with a single format 1 subtable
+        let array_offset = cblc.len() as u32;
+        push_u16(&mut cblc, first);
+        push_u16(&mut cblc, last);
+        push_u32(&mut cblc, 8); // offset of the subtable from the array
+        push_u16(&mut cblc, 1); // index format
+        push_u16(&mut cblc, CBDT_PNG_SMALL_METRICS);
+        push_u32(&mut cblc, image_data_offset);
+        for offset in image_offsets {
+            push_u32(&mut cblc, offset);
+        }
+        let index_tables_size = cblc.len() as u32 - array_offset;
+
+        // Bitmap size record
+        let clamp_i8 = |value: i32| value.clamp(i8::MIN as i32, i8::MAX as i32) as i8 as u8;
+        let line_metrics = [
+            clamp_i8(pixels(ascender, *ppem, upm, i8::MIN as i32, i8::MAX as i32)),
+            clamp_i8(pixels(descender, *ppem, upm, i8::MIN as i32, i8::MAX as i32)),
+            width_max as u8,
+            0, // caret slope numerator
+            0, // caret slope denominator
+            0, // caret offset
+            clamp_i8(min_origin_sb),
+            clamp_i8(min_advance_sb),
+            clamp_i8(max_before_bl),
+            clamp_i8(min_after_bl),
+            0,
+            0,
+        ];
+        let mut record = Vec::with_capacity(48);
+        push_u32(&mut record, array_offset);
+        push_u32(&mut record, index_tables_size);
+        push_u32(&mut record, 1); // number of index subtables
+        push_u32(&mut record, 0); // color ref
+        record.extend_from_slice(&line_metrics); // horizontal
+        record.extend_from_slice(&line_metrics); // vertical
+        push_u16(&mut record, first);
+        push_u16(&mut record, last);
+        record.extend_from_slice(&[*ppem as u8, *ppem as u8, 32, 0x01]); // ppemX, ppemY, bit depth, horizontal metrics
+        let start = size_records_start + 48 * index;
+        cblc[start..start + 48].copy_from_slice(&record);
+    }
+    Ok((cbdt, cblc))
+}
+
+/// Add bitmap strikes to a compiled font
+///
+/// # Arguments
+/// * `font_bytes` - Compiled font bytes
+/// * `bitmaps` - Bitmaps collected from the source before compiling
+/// * `format` - Tables to write: sbix, or CBDT and CBLC
+///
+/// # Returns
+/// * `Vec<u8>` - Font bytes with the bitmap tables
+pub fn add_to_compiled(font_bytes: Vec<u8>, bitmaps: &SourceBitmaps, format: BitmapFormat) -> Result<Vec<u8>, String> {
+    if bitmaps.bitmaps.is_empty() {
+        return Ok(font_bytes);
+    }
+
+    let font = FontRef::new(&font_bytes).map_err(|e| format!("Failed to parse compiled font: {:?}", e))?;
+    let glyph_order = crate::font_reader::read_glyph_order(&font).map_err(|e| e.to_string())?;
+    let name_to_gid: HashMap<&str, u16> =
+        glyph_order.iter().enumerate().map(|(gid, name)| (name.as_str(), gid as u16)).collect();
+
+    let mut strikes: Strikes = BTreeMap::new();
+    for (glyph_name, names, bitmap) in &bitmaps.bitmaps {
+        let gid = names
+            .iter()
+            .find_map(|name| name_to_gid.get(name.as_str()).copied())
+            .ok_or_else(|| format!("Glyph '{}' with a bitmap is not in the compiled font", glyph_name))?;
+        strikes.entry(bitmap.ppem).or_default().insert(gid, bitmap);
+    }
+
+    let mut builder = FontBuilder::new();
+    match format {
+        BitmapFormat::Sbix => {
+            builder.add_raw(Tag::new(b"sbix"), build_sbix(&strikes, glyph_order.len() as u16));
+        }
+        BitmapFormat::Cbdt => {
+            let (cbdt, cblc) = build_cbdt(&font, &strikes)?;
+            builder.add_raw(Tag::new(b"CBDT"), cbdt);
+            builder.add_raw(Tag::new(b"CBLC"), cblc);
+        }
+    }
+    builder.copy_missing_tables(font);
+    Ok(builder.build())
+}
+
+/// Big-endian reads with bounds checks
+struct Bytes<'a>(&'a [u8]);
+
+impl<'a> Bytes<'a> {
+    fn slice(&self, offset: usize, len: usize) -> Result<&'a [u8], String> {
+        self.0
+            .get(offset..offset.checked_add(len).ok_or("Table offset overflows")?)
+            .ok_or_else(|| format!("Table data ends before offset {}", offset + len))
+    }
+
+    fn u8(&self, offset: usize) -> Result<u8, String> {
+        Ok(self.slice(offset, 1)?[0])
+    }
+
+    fn i8(&self, offset: usize) -> Result<i8, String> {
+        Ok(self.u8(offset)? as i8)
+    }
+
+    fn u16(&self, offset: usize) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.slice(offset, 2)?.try_into().unwrap()))
+    }
+
+    fn i16(&self, offset: usize) -> Result<i16, String> {
+        Ok(self.u16(offset)? as i16)
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.slice(offset, 4)?.try_into().unwrap()))
+    }
+}
+
+/// A glyph bitmap read from a compiled font
+struct StrikeGlyph<'a> {
+    glyph_id: u16,
+    /// sbix graphic type ("png ", "jpg ", "tiff", "dupe", ...) or "png " for CBDT
+    graphic_type: String,
+    origin_x: i32,
+    origin_y: i32,
+    width: u32,
+    height: u32,
+    data: &'a [u8],
+}
+
+/// A strike read from a compiled font
+struct Strike<'a> {
+    table: &'static str,
+    ppem: u16,
+    ppi: Option<u16>,
+    glyphs: Vec<StrikeGlyph<'a>>,
+}
+
+/// Read the strikes of an sbix table
+fn read_sbix(data: &[u8], num_glyphs: u16) -> Result<Vec<Strike<'_>>, String> {
+    let sbix = Bytes(data);
+    let mut strikes = Vec::new();
+    for index in 0..sbix.u32(4)? as usize {
+        let start = sbix.u32(8 + 4 * index)? as usize;
+        let mut glyphs = Vec::new();
+        for gid in 0..num_glyphs {
+            let offset = sbix.u32(start + 4 + 4 * gid as usize)? as usize;
+            let next = sbix.u32(start + 4 + 4 * (gid as usize + 1))? as usize;
+            if next < offset + 8 {
+                continue;
+            }
+            let graphic_type = String::from_utf8_lossy(sbix.slice(start + offset + 4, 4)?).into_owned();
+            let image = sbix.slice(start + offset + 8, next - offset - 8)?;
+            let (width, height) = png_size(image).unwrap_or((0, 0));
+            glyphs.push(StrikeGlyph {
+                glyph_id: gid,
+                graphic_type,
+                origin_x: sbix.i16(start + offset)? as i32,
+                origin_y: sbix.i16(start + offset + 2)? as i32,
+                width,
+                height,
+                data: image,
+            });
+        }
+        strikes.push(Strike { table: "sbix", ppem: sbix.u16(start)?, ppi: Some(sbix.u16(start + 2)?), glyphs });
+    }
+    Ok(strikes)
+}
+
+/// Read one glyph image from CBDT
+///
+/// `big_metrics` are the metrics of index formats 2 and 5, used by image format 19.
+fn read_cbdt_glyph<'a>(
+    cbdt: &Bytes<'a>,
+    glyph_id: u16,
+    image_format: u16,
+    offset: usize,
+    big_metrics: Option<(u8, u8, i8, i8)>,
+) -> Result<StrikeGlyph<'a>, String> {
+    let (metrics, data_start) = match image_format {
+        17 => ((cbdt.u8(offset)?, cbdt.u8(offset + 1)?, cbdt.i8(offset + 2)?, cbdt.i8(offset + 3)?), offset + 5),
+        18 => ((cbdt.u8(offset)?, cbdt.u8(offset + 1)?, cbdt.i8(offset + 2)?, cbdt.i8(offset + 3)?), offset + 8),
+        19 => (big_metrics.ok_or("Image format 19 needs metrics in the index subtable")?, offset),
+        other => {
+            return Ok(StrikeGlyph {
+                glyph_id,
+                graphic_type: format!("format {}", other),
+                origin_x: 0,
+                origin_y: 0,
+                width: 0,
+                height: 0,
+                data: &[],
+            })
+        }
+    };
+    let (height, width, bearing_x, bearing_y) = metrics;
+    let length = cbdt.u32(data_start)? as usize;
+    Ok(StrikeGlyph {
+        glyph_id,
+        graphic_type: "png ".to_string(),
+        origin_x: bearing_x as i32,
+        origin_y: bearing_y as i32 - height as i32,
+        width: width as u32,
+        height: height as u32,
+        data: cbdt.slice(data_start + 4, length)?,
+    })
+}
+
+/// Read the strikes of CBLC and CBDT tables
+fn read_cbdt<'a>(cblc: &[u8], cbdt: &'a [u8]) -> Result<Vec<Strike<'a>>, String> {
+    let (cblc, cbdt) = (Bytes(cblc), Bytes(cbdt));
+    let mut strikes = Vec::new();
+    for index in 0..cblc.u32(4)? as usize {
+        let record = 8 + 48 * index;
+        let array_offset = cblc.u32(record)? as usize;
+        let subtable_count = cblc.u32(record + 8)? as usize;
+        let mut glyphs = Vec::new();
+        for subtable_index in 0..subtable_count {
+            let entry = array_offset + 8 * subtable_index;
+            let (first, last) = (cblc.u16(entry)?, cblc.u16(entry + 2)?);
+            let subtable = array_offset + cblc.u32(entry + 4)? as usize;
+            let index_format = cblc.u16(subtable)?;
+            let image_format = cblc.u16(subtable + 4)?;
+            let image_data = cblc.u32(subtable + 8)? as usize;
+            let big_metrics = |offset: usize| -> Result<(u8, u8, i8, i8), String> {
+                Ok((cblc.u8(offset)?, cblc.u8(offset + 1)?, cblc.i8(offset + 2)?, cblc.i8(offset + 3)?))
+            };
+
+            // (glyph ID, image offset in CBDT, metrics from the index subtable)
+            let mut located: Vec<(u16, usize, Option<(u8, u8, i8, i8)>)> = Vec::new();
+            match index_format {
+                1 | 3 => {
+                    let read_offset = |i: usize| -> Result<usize, String> {
+                        Ok(if index_format == 1 {
+                            cblc.u32(subtable + 8 + 4 * i)? as usize
+                        } else {
+                            cblc.u16(subtable + 8 + 2 * i)? as usize
+                        })
+                    };
+                    for (i, gid) in (first..=last).enumerate() {
+                        let (offset, next) = (read_offset(i)?, read_offset(i + 1)?);
+                        if next > offset {
+                            located.push((gid, image_data + offset, None));
+                        }
+                    }
+                }
+                2 => {
+                    let image_size = cblc.u32(subtable + 8)? as usize;
+                    let metrics = big_metrics(subtable + 12)?;
+                    for (i, gid) in (first..=last).enumerate() {
+                        located.push((gid, image_data + i * image_size, Some(metrics)));
+                    }
+                }
+                4 => {
+                    let count = cblc.u32(subtable + 8)? as usize;
+                    for i in 0..count {
+                        let pair = subtable + 12 + 4 * i;
+                        let (offset, next) = (cblc.u16(pair + 2)? as usize, cblc.u16(pair + 6)? as usize);
+                        if next > offset {
+                            located.push((cblc.u16(pair)?, image_data + offset, None));
+                        }
+                    }
+                }
+                5 => {
+                    let image_size = cblc.u32(subtable + 8)? as usize;
+                    let metrics = big_metrics(subtable + 12)?;
+                    let count = cblc.u32(subtable + 20)? as usize;
+                    for i in 0..count {
+                        located.push((cblc.u16(subtable + 24 + 2 * i)?, image_data + i * image_size, Some(metrics)));
+                    }
+                }
+                other => return Err(format!("Unknown CBLC index format {}", other)),
+            }
+            for (gid, offset, metrics) in located {
+                glyphs.push(read_cbdt_glyph(&cbdt, gid, image_format, offset, metrics)?);
+            }
+        }
+        strikes.push(Strike { table: "CBDT", ppem: cblc.u8(record + 44)? as u16, ppi: None, glyphs });
+    }
+    Ok(strikes)
+}
+
+/// Get the bitmap strikes of a compiled font
+///
+/// Reads sbix and CBDT/CBLC tables. Bitmap origins are the offset of the
+/// bitmap's bottom-left corner from the glyph origin in pixels, as for
+/// source bitmaps.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `include_data` - Add each image as a `dataUrl` (default: false)
+///
+/// # Returns
+/// * `String` - JSON array: '[{"table": "sbix", "ppem": 136, "ppi": 72, "glyphs": [{"glyphId": 5, "glyph": "u1F600", "graphicType": "png ", "originX": 0, "originY": -10, "width": 136, "height": 128, "size": 4211}]}]'
+#[wasm_bindgen]
+pub fn get_bitmap_strikes(font_bytes: &[u8], include_data: Option<bool>) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let glyph_order = crate::font_reader::read_glyph_order(&font)?;
+
+    let mut strikes = Vec::new();
+    if let Some(sbix) = font.table_data(Tag::new(b"sbix")) {
+        strikes.extend(
+            read_sbix(sbix.as_bytes(), glyph_order.len() as u16)
+                .map_err(|e| ApiError::missing_table("sbix", e))?,
+        );
+    }
+    if let (Some(cblc), Some(cbdt)) = (font.table_data(Tag::new(b"CBLC")), font.table_data(Tag::new(b"CBDT"))) {
+        strikes.extend(
+            read_cbdt(cblc.as_bytes(), cbdt.as_bytes())
+                .map_err(|e| ApiError::missing_table("CBDT/CBLC", e))?,
+        );
+    }
+
+    let result: Vec<serde_json::Value> = strikes
+        .iter()
+        .map(|strike| {
+            let glyphs: Vec<serde_json::Value> = strike
+                .glyphs
+                .iter()
+                .map(|glyph| {
+                    let mut value = serde_json::json!({
+                        "glyphId": glyph.glyph_id,
+                        "glyph": glyph_order.get(glyph.glyph_id as usize),
+                        "graphicType": glyph.graphic_type,
+                        "originX": glyph.origin_x,
+                        "originY": glyph.origin_y,
+                        "width": glyph.width,
+                        "height": glyph.height,
+                        "size": glyph.data.len(),
+                    });
+                    if include_data.unwrap_or(false) {
+                        value["dataUrl"] = serde_json::json!(data_url(&glyph.graphic_type, glyph.data));
+                    }
+                    value
+                })
+                .collect();
+            serde_json::json!({
+                "table": strike.table,
+                "ppem": strike.ppem,
+                "ppi": strike.ppi,
+                "glyphs": glyphs,
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
@@ -0,0 +1,580 @@
+// Named character sets
+//
+// This module provides codepoint definitions for the common character sets
+// used to judge the coverage of a font (Google Fonts glyphsets, Adobe Latin).
+// Sets are stored as inclusive codepoint ranges and may include other sets.
+// It also suggests production-friendly glyph names for missing characters.
+
+use std::collections::BTreeSet;
+
+use crate::errors::ApiError;
+
+/// A named character set
+pub struct CharacterSet {
+    /// Stable identifier, e.g. "gf-latin-core"
+    pub id: &'static str,
+    /// Human readable name, e.g. "GF Latin Core"
+    pub name: &'static str,
+    /// Identifiers of other sets whose codepoints are included in this one
+    pub includes: &'static [&'static str],
+    /// Inclusive codepoint ranges added by this set
+    pub ranges: &'static [(u32, u32)],
+}
+
+impl CharacterSet {
+    /// All codepoints in this set, including those of included sets
+    pub fn codepoints(&self) -> BTreeSet<u32> {
+        let mut codepoints = BTreeSet::new();
+        for include in self.includes {
+            if let Some(included) = find_charset(include) {
+                codepoints.extend(included.codepoints());
+            }
+        }
+        for &(start, end) in self.ranges {
+            codepoints.extend(start..=end);
+        }
+        codepoints
+    }
+}
+
+// The Google Fonts sets are generated from the .nam files of
+// googlefonts/glyphsets v1.1.3 (Lib/glyphsets/results/nam, as published in
+// the google-fonts-glyphsets 1.1.3 crate); each lists the codepoints it adds
+// to the sets it includes. Update them from a newer release, not by hand.
+
+const GF_LATIN_KERNEL: CharacterSet = CharacterSet {
+    id: "gf-latin-kernel",
+    name: "GF Latin Kernel",
+    includes: &[],
+    ranges: &[
+        (0x0020, 0x007E),
+        (0x00A0, 0x00A0),
+        (0x00A2, 0x00A3),
+        (0x00A5, 0x00A5),
+        (0x00A9, 0x00A9),
+        (0x00AE, 0x00AE),
+        (0x00B0, 0x00B0),
+        (0x00B7, 0x00B7),
+        (0x00D7, 0x00D7),
+        (0x00F7, 0x00F7),
+        (0x2013, 0x2014),
+        (0x2018, 0x2019),
+        (0x201C, 0x201D),
+        (0x2022, 0x2022),
+        (0x2026, 0x2026),
+        (0x20AC, 0x20AC),
+        (0x2122, 0x2122),
+        (0x2212, 0x2212),
+    ],
+};
+
+const GF_LATIN_CORE: CharacterSet = CharacterSet {
+    id: "gf-latin-core",
+    name: "GF Latin Core",
+    includes: &["gf-latin-kernel"],
+    ranges: &[
+        (0x00A1, 0x00A1),
+        (0x00A7, 0x00A8),
+        (0x00AA, 0x00AB),
+        (0x00AF, 0x00AF),
+        (0x00B4, 0x00B4),
+        (0x00B6, 0x00B6),
+        (0x00B8, 0x00B8),
+        (0x00BA, 0x00BB),
+        (0x00BF, 0x00D6),
+        (0x00D8, 0x00F6),
+        (0x00F8, 0x0107),
+        (0x010A, 0x0113),
+        (0x0116, 0x011B),
+        (0x011E, 0x0123),
+        (0x0126, 0x0127),
+        (0x012A, 0x012B),
+        (0x012E, 0x0131),
+        (0x0136, 0x0137),
+        (0x0139, 0x013E),
+        (0x0141, 0x0148),
+        (0x0150, 0x0155),
+        (0x0158, 0x015B),
+        (0x015E, 0x0161),
+        (0x0164, 0x0165),
+        (0x016A, 0x016B),
+        (0x016E, 0x017E),
+        (0x0218, 0x021B),
+        (0x0237, 0x0237),
+        (0x02C6, 0x02C7),
+        (0x02D8, 0x02DD),
+        (0x0300, 0x0304),
+        (0x0306, 0x0308),
+        (0x030A, 0x030C),
+        (0x0326, 0x0328),
+        (0x1E80, 0x1E85),
+        (0x1E9E, 0x1E9E),
+        (0x1EF2, 0x1EF3),
+        (0x201A, 0x201A),
+        (0x201E, 0x201E),
+        (0x2039, 0x203A),
+    ],
+};
+
+const GF_LATIN_PLUS: CharacterSet = CharacterSet {
+    id: "gf-latin-plus",
+    name: "GF Latin Plus",
+    includes: &["gf-latin-core"],
+    ranges: &[
+        (0x00A6, 0x00A6),
+        (0x00AC, 0x00AC),
+        (0x00B1, 0x00B3),
+        (0x00B5, 0x00B5),
+        (0x00B9, 0x00B9),
+        (0x00BC, 0x00BE),
+        (0x03C0, 0x03C0),
+        (0x0E3F, 0x0E3F),
+        (0x2016, 0x2016),
+        (0x2020, 0x2021),
+        (0x2030, 0x2030),
+        (0x2032, 0x2033),
+        (0x2044, 0x2044),
+        (0x2074, 0x2079),
+        (0x2081, 0x2089),
+        (0x20A1, 0x20A1),
+        (0x20A6, 0x20A6),
+        (0x20A8, 0x20AB),
+        (0x20AD, 0x20AE),
+        (0x20B1, 0x20B2),
+        (0x20B4, 0x20B5),
+        (0x20B8, 0x20BA),
+        (0x20BC, 0x20BF),
+        (0x2113, 0x2113),
+        (0x2116, 0x2116),
+        (0x2126, 0x2126),
+        (0x212E, 0x212E),
+        (0x2153, 0x2154),
+        (0x2190, 0x2199),
+        (0x2202, 0x2202),
+        (0x2205, 0x2206),
+        (0x220F, 0x220F),
+        (0x2211, 0x2211),
+        (0x221A, 0x221A),
+        (0x221E, 0x221E),
+        (0x222B, 0x222B),
+        (0x2248, 0x2248),
+        (0x2260, 0x2260),
+        (0x2264, 0x2265),
+        (0x24B9, 0x24B9),
+        (0x25A0, 0x25A1),
+        (0x25AA, 0x25AB),
+        (0x25B2, 0x25B9),
+        (0x25BC, 0x25C3),
+        (0x25C6, 0x25C7),
+        (0x25CA, 0x25CB),
+        (0x25CF, 0x25CF),
+        (0x25E6, 0x25E6),
+        (0x27E8, 0x27E9),
+    ],
+};
+
+// Adobe Latin 3 is typed in by hand as an approximation of Adobe's list
+// (adobe-latin-3 in github.com/adobe-type-tools/adobe-latin-charsets). That
+// list is not published in a package it could be generated from, and these
+// ranges have not been checked against it, so they may differ from it.
+const ADOBE_LATIN_3: CharacterSet = CharacterSet {
+    id: "adobe-latin-3",
+    name: "Adobe Latin 3",
+    includes: &[],
+    ranges: &[
+        (0x0020, 0x007E), // Basic Latin
+        (0x00A0, 0x00FF), // Latin-1 Supplement
+        (0x0100, 0x017F), // Latin Extended-A
+        (0x0192, 0x0192),
+        (0x0218, 0x021B),
+        (0x0237, 0x0237),
+        (0x02C6, 0x02C7),
+        (0x02C9, 0x02C9),
+        (0x02D8, 0x02DD),
+        (0x0394, 0x0394),
+        (0x03A9, 0x03A9),
+        (0x03BC, 0x03BC),
+        (0x03C0, 0x03C0),
+        (0x1E80, 0x1E85),
+        (0x1E9E, 0x1E9E),
+        (0x1EF2, 0x1EF3),
+        (0x2013, 0x2014),
+        (0x2018, 0x201A),
+        (0x201C, 0x201E),
+        (0x2020, 0x2022),
+        (0x2026, 0x2026),
+        (0x2030, 0x2030),
+        (0x2039, 0x203A),
+        (0x2044, 0x2044),
+        (0x2070, 0x2070),
+        (0x2074, 0x2079),
+        (0x2080, 0x2089),
+        (0x20AC, 0x20AC),
+        (0x20BA, 0x20BA),
+        (0x20BD, 0x20BD),
+        (0x2113, 0x2113),
+        (0x2116, 0x2116),
+        (0x2122, 0x2122),
+        (0x2126, 0x2126),
+        (0x212E, 0x212E),
+        (0x2153, 0x215E),
+        (0x2202, 0x2202),
+        (0x2206, 0x2206),
+        (0x220F, 0x220F),
+        (0x2211, 0x2212),
+        (0x2215, 0x2215),
+        (0x2219, 0x221A),
+        (0x221E, 0x221E),
+        (0x222B, 0x222B),
+        (0x2248, 0x2248),
+        (0x2260, 0x2260),
+        (0x2264, 0x2265),
+        (0x25CA, 0x25CA),
+        (0xFB01, 0xFB02),
+    ],
+};
+
+const GF_CYRILLIC_CORE: CharacterSet = CharacterSet {
+    id: "gf-cyrillic-core",
+    name: "GF Cyrillic Core",
+    includes: &["gf-latin-kernel"],
+    ranges: &[
+        (0x00AB, 0x00AB),
+        (0x00BB, 0x00BB),
+        (0x02BC, 0x02BC),
+        (0x0300, 0x0301),
+        (0x0304, 0x0304),
+        (0x0306, 0x0306),
+        (0x0308, 0x0308),
+        (0x0401, 0x0402),
+        (0x0404, 0x0404),
+        (0x0406, 0x040B),
+        (0x040E, 0x044F),
+        (0x0451, 0x0452),
+        (0x0454, 0x0454),
+        (0x0456, 0x045B),
+        (0x045E, 0x045F),
+        (0x0490, 0x0493),
+        (0x0496, 0x0497),
+        (0x049A, 0x049B),
+        (0x04A2, 0x04A3),
+        (0x04AE, 0x04B3),
+        (0x04B6, 0x04B7),
+        (0x04BA, 0x04BB),
+        (0x04D8, 0x04D9),
+        (0x04E2, 0x04E3),
+        (0x04E8, 0x04E9),
+        (0x04EE, 0x04EF),
+        (0x201A, 0x201A),
+        (0x201E, 0x201E),
+        (0x2116, 0x2116),
+    ],
+};
+
+const GF_CYRILLIC_PLUS: CharacterSet = CharacterSet {
+    id: "gf-cyrillic-plus",
+    name: "GF Cyrillic Plus",
+    includes: &["gf-cyrillic-core"],
+    ranges: &[
+        (0x030B, 0x030B),
+        (0x0403, 0x0403),
+        (0x0405, 0x0405),
+        (0x040C, 0x040C),
+        (0x0453, 0x0453),
+        (0x0455, 0x0455),
+        (0x045C, 0x045C),
+        (0x0494, 0x0495),
+        (0x0498, 0x0499),
+        (0x049C, 0x049D),
+        (0x04A0, 0x04A1),
+        (0x04A4, 0x04A5),
+        (0x04AA, 0x04AB),
+        (0x04B8, 0x04B9),
+        (0x04C0, 0x04C0),
+        (0x04CF, 0x04D1),
+        (0x04D4, 0x04D7),
+        (0x04DC, 0x04DF),
+        (0x04E4, 0x04E7),
+        (0x04F0, 0x04F5),
+    ],
+};
+
+const GF_GREEK_CORE: CharacterSet = CharacterSet {
+    id: "gf-greek-core",
+    name: "GF Greek Core",
+    includes: &["gf-latin-kernel"],
+    ranges: &[
+        (0x00AB, 0x00AB),
+        (0x00BB, 0x00BB),
+        (0x0301, 0x0301),
+        (0x0308, 0x0308),
+        (0x0374, 0x0375),
+        (0x037E, 0x037E),
+        (0x0384, 0x038A),
+        (0x038C, 0x038C),
+        (0x038E, 0x03A1),
+        (0x03A3, 0x03CF),
+        (0x03D7, 0x03D7),
+    ],
+};
+
+const GF_GREEK_PLUS: CharacterSet = CharacterSet {
+    id: "gf-greek-plus",
+    name: "GF Greek Plus",
+    includes: &["gf-greek-core"],
+    ranges: &[
+        (0x0342, 0x0345),
+        (0x037A, 0x037A),
+        (0x1F00, 0x1F15),
+        (0x1F18, 0x1F1D),
+        (0x1F20, 0x1F45),
+        (0x1F48, 0x1F4D),
+        (0x1F50, 0x1F57),
+        (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B),
+        (0x1F5D, 0x1F5D),
+        (0x1F5F, 0x1F7D),
+        (0x1F80, 0x1FB4),
+        (0x1FB6, 0x1FC4),
+        (0x1FC6, 0x1FD3),
+        (0x1FD6, 0x1FDB),
+        (0x1FDD, 0x1FEF),
+        (0x1FF2, 0x1FF4),
+        (0x1FF6, 0x1FFE),
+    ],
+};
+
+/// All known character sets
+pub static CHARACTER_SETS: &[CharacterSet] = &[
+    GF_LATIN_KERNEL,
+    GF_LATIN_CORE,
+    GF_LATIN_PLUS,
+    GF_CYRILLIC_CORE,
+    GF_CYRILLIC_PLUS,
+    GF_GREEK_CORE,
+    GF_GREEK_PLUS,
+    ADOBE_LATIN_3,
+];
+
+/// Preferred glyph names of common characters, as runs of consecutive
+/// codepoints starting at the given one (empty strings are gaps)
+const GLYPH_NAMES: &[(u32, &[&str])] = &[
+    (0x0020, &[
+        "space", "exclam", "quotedbl", "numbersign", "dollar", "percent", "ampersand",
+        "quotesingle", "parenleft", "parenright", "asterisk", "plus", "comma", "hyphen", "period",
+        "slash", "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        "colon", "semicolon", "less", "equal", "greater", "question", "at", "A", "B", "C", "D",
+        "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V",
+        "W", "X", "Y", "Z", "bracketleft", "backslash", "bracketright", "asciicircum",
+        "underscore", "grave", "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m",
+        "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z", "braceleft", "bar",
+        "braceright", "asciitilde",
+    ]),
+    (0x00A0, &[
+        "nbspace", "exclamdown", "cent", "sterling", "currency", "yen", "brokenbar", "section",
+        "dieresis", "copyright", "ordfeminine", "guillemetleft", "logicalnot", "softhyphen",
+        "registered", "macron", "degree", "plusminus", "twosuperior", "threesuperior", "acute",
+        "micro", "paragraph", "periodcentered", "cedilla", "onesuperior", "ordmasculine",
+        "guillemetright", "onequarter", "onehalf", "threequarters", "questiondown", "Agrave",
+        "Aacute", "Acircumflex", "Atilde", "Adieresis", "Aring", "AE", "Ccedilla", "Egrave",
+        "Eacute", "Ecircumflex", "Edieresis", "Igrave", "Iacute", "Icircumflex", "Idieresis",
+        "Eth", "Ntilde", "Ograve", "Oacute", "Ocircumflex", "Otilde", "Odieresis", "multiply",
+        "Oslash", "Ugrave", "Uacute", "Ucircumflex", "Udieresis", "Yacute", "Thorn", "germandbls",
+        "agrave", "aacute", "acircumflex", "atilde", "adieresis", "aring", "ae", "ccedilla",
+        "egrave", "eacute", "ecircumflex", "edieresis", "igrave", "iacute", "icircumflex",
+        "idieresis", "eth", "ntilde", "ograve", "oacute", "ocircumflex", "otilde", "odieresis",
+        "divide", "oslash", "ugrave", "uacute", "ucircumflex", "udieresis", "yacute", "thorn",
+        "ydieresis",
+    ]),
+    (0x0100, &[
+        "Amacron", "amacron", "Abreve", "abreve", "Aogonek", "aogonek", "Cacute", "cacute",
+        "Ccircumflex", "ccircumflex", "Cdotaccent", "cdotaccent", "Ccaron", "ccaron", "Dcaron",
+        "dcaron", "Dcroat", "dcroat", "Emacron", "emacron", "Ebreve", "ebreve", "Edotaccent",
+        "edotaccent", "Eogonek", "eogonek", "Ecaron", "ecaron", "Gcircumflex", "gcircumflex",
+        "Gbreve", "gbreve", "Gdotaccent", "gdotaccent", "Gcommaaccent", "gcommaaccent",
+        "Hcircumflex", "hcircumflex", "Hbar", "hbar", "Itilde", "itilde", "Imacron", "imacron",
+        "Ibreve", "ibreve", "Iogonek", "iogonek", "Idotaccent", "idotless", "IJ", "ij",
+        "Jcircumflex", "jcircumflex", "Kcommaaccent", "kcommaaccent", "kgreenlandic", "Lacute",
+        "lacute", "Lcommaaccent", "lcommaaccent", "Lcaron", "lcaron", "Ldot", "ldot", "Lslash",
+        "lslash", "Nacute", "nacute", "Ncommaaccent", "ncommaaccent", "Ncaron", "ncaron",
+        "napostrophe", "Eng", "eng", "Omacron", "omacron", "Obreve", "obreve", "Ohungarumlaut",
+        "ohungarumlaut", "OE", "oe", "Racute", "racute", "Rcommaaccent", "rcommaaccent", "Rcaron",
+        "rcaron", "Sacute", "sacute", "Scircumflex", "scircumflex", "Scedilla", "scedilla",
+        "Scaron", "scaron", "Tcedilla", "tcedilla", "Tcaron", "tcaron", "Tbar", "tbar", "Utilde",
+        "utilde", "Umacron", "umacron", "Ubreve", "ubreve", "Uring", "uring", "Uhungarumlaut",
+        "uhungarumlaut", "Uogonek", "uogonek", "Wcircumflex", "wcircumflex", "Ycircumflex",
+        "ycircumflex", "Ydieresis", "Zacute", "zacute", "Zdotaccent", "zdotaccent", "Zcaron",
+        "zcaron", "longs",
+    ]),
+    (0x0218, &[
+        "Scommaaccent", "scommaaccent", "Tcommaaccent", "tcommaaccent",
+    ]),
+    (0x02C6, &[
+        "circumflex", "caron",
+    ]),
+    (0x02D8, &[
+        "breve", "dotaccent", "ring", "ogonek", "tilde", "hungarumlaut",
+    ]),
+    (0x0300, &[
+        "gravecomb", "acutecomb", "circumflexcomb", "tildecomb", "macroncomb", "", "brevecomb",
+        "dotaccentcomb", "dieresiscomb", "hookabovecomb", "ringcomb", "hungarumlautcomb",
+        "caroncomb",
+    ]),
+    (0x0326, &[
+        "commaaccentcomb", "cedillacomb", "ogonekcomb",
+    ]),
+    (0x0391, &[
+        "Alpha", "Beta", "Gamma", "Delta", "Epsilon", "Zeta", "Eta", "Theta", "Iota", "Kappa",
+        "Lambda", "Mu", "Nu", "Xi", "Omicron", "Pi", "Rho", "", "Sigma", "Tau", "Upsilon", "Phi",
+        "Chi", "Psi", "Omega",
+    ]),
+    (0x03B1, &[
+        "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+        "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigmafinal", "sigma", "tau",
+        "upsilon", "phi", "chi", "psi", "omega",
+    ]),
+    (0x0410, &[
+        "A-cy", "Be-cy", "Ve-cy", "Ge-cy", "De-cy", "Ie-cy", "Zhe-cy", "Ze-cy", "Ii-cy",
+        "Iishort-cy", "Ka-cy", "El-cy", "Em-cy", "En-cy", "O-cy", "Pe-cy", "Er-cy", "Es-cy",
+        "Te-cy", "U-cy", "Ef-cy", "Ha-cy", "Tse-cy", "Che-cy", "Sha-cy", "Shcha-cy", "Hard-cy",
+        "Yeru-cy", "Soft-cy", "Ereversed-cy", "Iu-cy", "Ia-cy", "a-cy", "be-cy", "ve-cy", "ge-cy",
+        "de-cy", "ie-cy", "zhe-cy", "ze-cy", "ii-cy", "iishort-cy", "ka-cy", "el-cy", "em-cy",
+        "en-cy", "o-cy", "pe-cy", "er-cy", "es-cy", "te-cy", "u-cy", "ef-cy", "ha-cy", "tse-cy",
+        "che-cy", "sha-cy", "shcha-cy", "hard-cy", "yeru-cy", "soft-cy", "ereversed-cy", "iu-cy",
+        "ia-cy",
+    ]),
+    (0x1E9E, &[
+        "Germandbls",
+    ]),
+    (0x2013, &[
+        "endash", "emdash", "", "", "", "quoteleft", "quoteright", "quotesinglbase", "",
+        "quotedblleft", "quotedblright", "quotedblbase", "", "dagger", "daggerdbl", "bullet", "",
+        "", "", "ellipsis",
+    ]),
+    (0x2030, &[
+        "perthousand",
+    ]),
+    (0x2039, &[
+        "guilsinglleft", "guilsinglright",
+    ]),
+    (0x2044, &[
+        "fraction",
+    ]),
+    (0x20AC, &[
+        "Euro",
+    ]),
+    (0x2116, &[
+        "numero",
+    ]),
+    (0x2122, &[
+        "trademark",
+    ]),
+    (0x2212, &[
+        "minus",
+    ]),
+    (0xFB01, &[
+        "fi", "fl",
+    ]),
+];
+
+/// Suggest a glyph name for a codepoint
+///
+/// Common Latin, Greek and Cyrillic characters get their customary
+/// (AGL/Glyphs style) names; other characters get "uniXXXX" or "uXXXXX".
+pub fn suggested_glyph_name(codepoint: u32) -> String {
+    for &(start, names) in GLYPH_NAMES {
+        if let Some(name) = codepoint
+            .checked_sub(start)
+            .and_then(|offset| names.get(offset as usize))
+            .filter(|name| !name.is_empty())
+        {
+            return name.to_string();
+        }
+    }
+    if codepoint <= 0xFFFF {
+        format!("uni{:04X}", codepoint)
+    } else {
+        format!("u{:05X}", codepoint)
+    }
+}
+
+/// Codepoint a glyph name stands for, if any
+///
+/// Recognizes "uniXXXX", "uXXXX"–"uXXXXXX" and the customary names used by
+/// suggested_glyph_name(). Names with a suffix ("a.sc") or of ligatures
+/// ("uni00410042", "f_i") imply no single codepoint.
+pub fn glyph_name_codepoint(name: &str) -> Option<u32> {
+    let hex = name
+        .strip_prefix("uni")
+        .filter(|hex| hex.len() == 4)
+        .or_else(|| name.strip_prefix('u').filter(|hex| (4..=6).contains(&hex.len())));
+    if let Some(codepoint) = hex
+        .filter(|hex| hex.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()))
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .filter(|&codepoint| char::from_u32(codepoint).is_some())
+    {
+        return Some(codepoint);
+    }
+    GLYPH_NAMES.iter().find_map(|&(start, names)| {
+        names
+            .iter()
+            .position(|candidate| !candidate.is_empty() && *candidate == name)
+            .map(|offset| start + offset as u32)
+    })
+}
+
+/// Normalize a set name for lookup ("GF Latin Core" -> "gflatincore")
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Find a character set by identifier or human readable name
+///
+/// Matching ignores case, spaces and punctuation. Google Fonts sets may be
+/// given without the "GF" prefix, so "Latin Plus" finds "GF Latin Plus".
+pub fn find_charset(name: &str) -> Option<&'static CharacterSet> {
+    let wanted = normalize_name(name);
+    CHARACTER_SETS.iter().find(|set| {
+        let id = normalize_name(set.id);
+        let display = normalize_name(set.name);
+        wanted == id
+            || wanted == display
+            || (id.starts_with("gf") && wanted == id[2..])
+    })
+}
+
+/// Resolve a character set argument to codepoints
+///
+/// Accepts either a set name (see `find_charset`) or a JSON array of
+/// codepoints, given as numbers or as "U+XXXX" strings.
+///
+/// # Returns
+/// * `(String, BTreeSet<u32>)` - Display name of the set and its codepoints
+pub fn resolve_charset(charset: &str) -> Result<(String, BTreeSet<u32>), ApiError> {
+    let trimmed = charset.trim();
+    if trimmed.starts_with('[') {
+        let values: Vec<serde_json::Value> = serde_json::from_str(trimmed)
+            .map_err(|e| ApiError::invalid_json("codepoint list", e))?;
+        let codepoints = values
+            .iter()
+            .map(|value| match value {
+                serde_json::Value::Number(n) => n
+                    .as_u64()
+                    .map(|n| n as u32)
+                    .ok_or_else(|| ApiError::invalid_argument("codepoint", n)),
+                serde_json::Value::String(s) => {
+                    let hex = s.trim_start_matches("U+").trim_start_matches("u+");
+                    u32::from_str_radix(hex, 16).map_err(|_| ApiError::invalid_argument("codepoint", s))
+                }
+                other => Err(ApiError::invalid_argument("codepoint", other)),
+            })
+            .collect::<Result<BTreeSet<u32>, ApiError>>()?;
+        return Ok(("Custom".to_string(), codepoints));
+    }
+
+    let set = find_charset(trimmed).ok_or_else(|| ApiError::invalid_argument("character set", trimmed))?;
+    Ok((set.name.to_string(), set.codepoints()))
+}
@@ -0,0 +1,267 @@
+// Clipboard module
+//
+// This module serializes shapes of a source layer into a compact clipboard
+// payload and pastes such payloads into layers, so copy and paste work on
+// the source font rather than on the editor's copy of it. The payload is a
+// small JSON object that carries the font's units per em and keeps nodes
+// of each path in one string ("100 0 l 200 0 o 250 50 o 300 100 cs"), so
+// it can be passed through the system clipboard and pasted into another
+// font or a later session.
+
+use babelfont::{Component, Node, NodeType, Path, Shape};
+use kurbo::Affine;
+
+use crate::errors::ApiError;
+
+/// Format identifier of clipboard payloads
+pub const PAYLOAD_FORMAT: &str = "counterpunch-shapes";
+
+/// Version of the clipboard payload format
+pub const PAYLOAD_VERSION: u64 = 1;
+
+/// Code of a node type in payload node strings
+fn node_code(nodetype: NodeType) -> &'static str {
+    match nodetype {
+        NodeType::Move => "m",
+        NodeType::Line => "l",
+        NodeType::OffCurve => "o",
+        NodeType::Curve => "c",
+        NodeType::QCurve => "q",
+    }
+}
+
+/// Node type and smoothness of a payload node code
+fn parse_node_code(code: &str) -> Option<(NodeType, bool)> {
+    let (code, smooth) = match code.strip_suffix('s') {
+        Some(code) => (code, true),
+        None => (code, false),
+    };
+    let nodetype = match code {
+        "m" => NodeType::Move,
+        "l" => NodeType::Line,
+        "o" => NodeType::OffCurve,
+        "c" => NodeType::Curve,
+        "q" => NodeType::QCurve,
+        _ => return None,
+    };
+    Some((nodetype, smooth))
+}
+
+/// Nodes of a path as a payload node string
+fn nodes_to_string(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| format!("{} {} {}{}", node.x, node.y, node_code(node.nodetype), if node.smooth { "s" } else { "" }))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a payload node string
+fn parse_nodes(nodes: &str) -> Result<Vec<Node>, ApiError> {
+    let tokens: Vec<&str> = nodes.split_whitespace().collect();
+    if tokens.len() % 3 != 0 {
+        return Err(ApiError::invalid_json("clipboard payload", "Node string must consist of x, y and type triples"));
+    }
+    tokens
+        .chunks(3)
+        .map(|chunk| {
+            let coordinate = |token: &str| {
+                token.parse::<f64>().map_err(|_| ApiError::invalid_json("clipboard payload", format!("Invalid coordinate '{}'", token)))
+            };
+            let (nodetype, smooth) =
+                parse_node_code(chunk[2]).ok_or_else(|| ApiError::invalid_json("clipboard payload", format!("Invalid node type '{}'", chunk[2])))?;
+            Ok(Node { x: coordinate(chunk[0])?, y: coordinate(chunk[1])?, nodetype, smooth })
+        })
+        .collect()
+}
+
+/// Copy shapes of a layer into a clipboard payload
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_name` - Glyph to copy from
+/// * `layer_id` - ID of the layer to copy from
+/// * `shape_indices` - Indices of the shapes to copy, in the order given; all shapes if None
+///
+/// # Returns
+/// * `JsonValue` - Payload '{"format": "counterpunch-shapes", "version": 1, "upm": 1000, "glyph": "a", "layer": "m01", "shapes": [{"closed": true, "nodes": "100 0 l ..."}, {"ref": "acutecomb", "transform": [1, 0, 0, 1, 120, 0]}]}'
+pub fn copy_shapes(
+    font: &babelfont::Font,
+    glyph_name: &str,
+    layer_id: &str,
+    shape_indices: Option<&[usize]>,
+) -> Result<serde_json::Value, ApiError> {
+    let glyph = font
+        .glyphs
+        .get(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let layer = glyph
+        .layers
+        .iter()
+        .find(|layer| layer.id.as_deref() == Some(layer_id))
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id))?;
+
+    let indices: Vec<usize> = match shape_indices {
+        Some(indices) => indices.to_vec(),
+        None => (0..layer.shapes.len()).collect(),
+    };
+    let mut shapes = Vec::new();
+    for index in indices {
+        let shape = layer
+            .shapes
+            .get(index)
+            .ok_or_else(|| ApiError::invalid_argument("shape index", index))?;
+        shapes.push(match shape {
+            Shape::Path(path) => serde_json::json!({
+                "closed": path.closed,
+                "nodes": nodes_to_string(&path.nodes),
+            }),
+            Shape::Component(component) => serde_json::json!({
+                "ref": component.reference.to_string(),
+                "transform": component.transform.as_coeffs(),
+            }),
+        });
+    }
+
+    Ok(serde_json::json!({
+        "format": PAYLOAD_FORMAT,
+        "version": PAYLOAD_VERSION,
+        "upm": font.upm,
+        "glyph": glyph_name,
+        "layer": layer_id,
+        "shapes": shapes,
+    }))
+}
+
+/// Paste a clipboard payload into a layer
+///
+/// Shapes are appended to the layer, or replace its shapes with `replace`.
+/// Payloads copied from a font with other units per em are scaled to this
+/// font's unless `scale` is false. Components of glyphs missing from the
+/// font, or that would make the glyph refer to itself, are skipped.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Glyph to paste into
+/// * `layer_id` - ID of the layer to paste into
+/// * `payload` - Payload from copy_shapes()
+/// * `options_json` - Optional JSON object:
+///   - `replace`: bool - Replace the layer's shapes (default: false)
+///   - `dx`, `dy`: number - Offset of the pasted shapes in font units (default: 0)
+///   - `scale`: bool - Scale to this font's units per em (default: true)
+///
+/// # Returns
+/// * `JsonValue` - Result '{"shapes": [3, 4], "skipped": [{"ref": "acutecomb", "reason": "..."}]}' with the indices of the pasted shapes
+pub fn paste_shapes(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    layer_id: &str,
+    payload: &str,
+    options_json: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let payload: serde_json::Value =
+        serde_json::from_str(payload).map_err(|e| ApiError::invalid_json("clipboard payload", e))?;
+    if payload.get("format").and_then(|v| v.as_str()) != Some(PAYLOAD_FORMAT) {
+        return Err(ApiError::invalid_json("clipboard payload", "Clipboard does not hold shapes"));
+    }
+    let version = payload.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+    if version > PAYLOAD_VERSION {
+        return Err(ApiError::Unsupported {
+            format: PAYLOAD_FORMAT.to_string(),
+            detail: format!("Clipboard payload version {} is not supported", version),
+        });
+    }
+
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let replace = options.get("replace").and_then(|v| v.as_bool()).unwrap_or(false);
+    let dx = options.get("dx").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let dy = options.get("dy").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let scale = options.get("scale").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    let factor = match payload.get("upm").and_then(|v| v.as_f64()) {
+        Some(upm) if scale && upm > 0.0 => font.upm as f64 / upm,
+        _ => 1.0,
+    };
+    let placement = Affine::translate((dx, dy)) * Affine::scale(factor);
+
+    // Glyphs using the target glyph cannot become its components
+    let dependents = crate::glyph_outlines::with_component_dependents(font, glyph_name);
+    let mut shapes = Vec::new();
+    let mut skipped = Vec::new();
+    let entries = payload
+        .get("shapes")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| ApiError::invalid_json("clipboard payload", "Clipboard payload has no shapes"))?;
+    for entry in entries {
+        if let Some(reference) = entry.get("ref").and_then(|v| v.as_str()) {
+            let reason = if font.glyphs.get(reference).is_none() {
+                Some("Glyph is not in the font")
+            } else if reference == glyph_name || dependents.contains(reference) {
+                Some("Component would make the glyph refer to itself")
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                skipped.push(serde_json::json!({ "ref": reference, "reason": reason }));
+                continue;
+            }
+            let coeffs: Vec<f64> = entry
+                .get("transform")
+                .and_then(|v| v.as_array())
+                .map(|values| values.iter().filter_map(|v| v.as_f64()).collect())
+                .unwrap_or_else(|| Affine::IDENTITY.as_coeffs().to_vec());
+            let coeffs: [f64; 6] = coeffs
+                .try_into()
+                .map_err(|_| ApiError::invalid_json("clipboard payload", format!("Invalid transform of component '{}'", reference)))?;
+            // Scale the offset, not the component, as the base glyph is already at this font's size
+            let [a, b, c, d, x, y] = coeffs;
+            let offset = placement * kurbo::Point::new(x, y);
+            shapes.push(Shape::Component(Component {
+                reference: reference.into(),
+                transform: Affine::new([a, b, c, d, offset.x, offset.y]),
+                format_specific: Default::default(),
+            }));
+        } else {
+            let nodes = entry
+                .get("nodes")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ApiError::invalid_json("clipboard payload", "Clipboard shape is neither a path nor a component"))?;
+            let mut nodes = parse_nodes(nodes)?;
+            for node in &mut nodes {
+                let point = placement * kurbo::Point::new(node.x, node.y);
+                node.x = point.x;
+                node.y = point.y;
+            }
+            shapes.push(Shape::Path(Path {
+                nodes,
+                closed: entry.get("closed").and_then(|v| v.as_bool()).unwrap_or(true),
+                format_specific: Default::default(),
+            }));
+        }
+    }
+
+    let layer = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?
+        .layers
+        .iter_mut()
+        .find(|layer| layer.id.as_deref() == Some(layer_id))
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id))?;
+    if replace {
+        layer.shapes.clear();
+    }
+    let first = layer.shapes.len();
+    layer.shapes.extend(shapes);
+    let pasted: Vec<usize> = (first..layer.shapes.len()).collect();
+
+    Ok(serde_json::json!({
+        "shapes": pasted,
+        "skipped": skipped,
+    }))
+}
@@ -0,0 +1,817 @@
+// Color glyph module
+//
+// This module stores COLRv1 paint graphs on source layers and writes them
+// into COLR and CPAL tables after compilation, as babelfont has no color
+// glyph model of its own. A paint graph is kept as JSON in the layer's
+// format-specific data, so it survives saving and loading the .babelfont
+// file: solid fills, linear, radial and sweep gradients, glyph clips,
+// references to other color glyphs, affine transforms, compositing and
+// layer lists. Colors are RGBA values on the paints; the palette is built
+// from the distinct colors at compile time. The compiled COLR table is
+// static and uses the paints of the default master; previews interpolate
+// the paints of compatible masters.
+
+use std::collections::{BTreeSet, HashMap};
+
+use fontdrasil::coords::DesignLocation;
+use kurbo::{Affine, Point};
+use read_fonts::FontRef;
+use write_fonts::tables::colr::{
+    Affine2x3, BaseGlyphList, BaseGlyphPaint, ColorLine, ColorStop, Colr, CompositeMode, Extend, LayerList, Paint,
+    PaintColrGlyph, PaintColrLayers, PaintComposite, PaintGlyph, PaintLinearGradient, PaintRadialGradient, PaintSolid,
+    PaintSweepGradient, PaintTransform,
+};
+use write_fonts::tables::cpal::{ColorRecord, Cpal};
+use write_fonts::types::{F2Dot14, FWord, Fixed, GlyphId16, UfWord};
+use write_fonts::FontBuilder;
+
+use crate::errors::ApiError;
+
+/// Format-specific key under which a layer's paint graph is stored
+pub const COLOR_PAINT_KEY: &str = "space.counterpunch.colorPaint";
+
+/// Composite modes by their JSON name, in COLR order
+const COMPOSITE_MODES: [(&str, CompositeMode); 28] = [
+    ("clear", CompositeMode::Clear),
+    ("src", CompositeMode::Src),
+    ("dest", CompositeMode::Dest),
+    ("srcOver", CompositeMode::SrcOver),
+    ("destOver", CompositeMode::DestOver),
+    ("srcIn", CompositeMode::SrcIn),
+    ("destIn", CompositeMode::DestIn),
+    ("srcOut", CompositeMode::SrcOut),
+    ("destOut", CompositeMode::DestOut),
+    ("srcAtop", CompositeMode::SrcAtop),
+    ("destAtop", CompositeMode::DestAtop),
+    ("xor", CompositeMode::Xor),
+    ("plus", CompositeMode::Plus),
+    ("screen", CompositeMode::Screen),
+    ("overlay", CompositeMode::Overlay),
+    ("darken", CompositeMode::Darken),
+    ("lighten", CompositeMode::Lighten),
+    ("colorDodge", CompositeMode::ColorDodge),
+    ("colorBurn", CompositeMode::ColorBurn),
+    ("hardLight", CompositeMode::HardLight),
+    ("softLight", CompositeMode::SoftLight),
+    ("difference", CompositeMode::Difference),
+    ("exclusion", CompositeMode::Exclusion),
+    ("multiply", CompositeMode::Multiply),
+    ("hue", CompositeMode::HslHue),
+    ("saturation", CompositeMode::HslSaturation),
+    ("color", CompositeMode::HslColor),
+    ("luminosity", CompositeMode::HslLuminosity),
+];
+
+/// An RGBA color
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rgba(pub [u8; 4]);
+
+impl Rgba {
+    /// Parse a hex color: "#rgb", "#rgba", "#rrggbb" or "#rrggbbaa"
+    pub fn parse(color: &str) -> Result<Self, String> {
+        let hex = color.trim().trim_start_matches('#');
+        let digits: Vec<u8> = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|digit| digit as u8))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("Invalid color '{}'", color))?;
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+            6 | 8 => digits.chunks(2).map(|pair| pair[0] * 16 + pair[1]).collect(),
+            _ => return Err(format!("Invalid color '{}': expected #rgb, #rgba, #rrggbb or #rrggbbaa", color)),
+        };
+        Ok(Rgba([channels[0], channels[1], channels[2], channels.get(3).copied().unwrap_or(255)]))
+    }
+
+    /// Color as "#rrggbbaa"
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.0;
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+/// How a gradient continues beyond its first and last stop
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientExtend {
+    Pad,
+    Repeat,
+    Reflect,
+}
+
+/// Color stops of a gradient
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub extend: GradientExtend,
+    /// Stop offsets and colors, sorted by offset
+    pub stops: Vec<(f64, Rgba)>,
+}
+
+/// A node of a paint graph
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColorPaint {
+    Solid(Rgba),
+    LinearGradient { p0: Point, p1: Point, p2: Point, gradient: Gradient },
+    RadialGradient { c0: Point, r0: f64, c1: Point, r1: f64, gradient: Gradient },
+    SweepGradient { center: Point, start_angle: f64, end_angle: f64, gradient: Gradient },
+    /// Fill the outline of a glyph with a paint
+    Glyph { glyph: String, paint: Box<ColorPaint> },
+    /// Paint another color glyph
+    ColrGlyph(String),
+    Transform { transform: Affine, paint: Box<ColorPaint> },
+    Composite { mode: &'static str, source: Box<ColorPaint>, backdrop: Box<ColorPaint> },
+    /// Paints drawn bottom to top
+    Layers(Vec<ColorPaint>),
+}
+
+fn point_from_json(value: &serde_json::Value, key: &str) -> Result<Point, String> {
+    let coordinates: Vec<f64> = value
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|values| values.iter().filter_map(|v| v.as_f64()).collect())
+        .unwrap_or_default();
+    match coordinates[..] {
+        [x, y] => Ok(Point::new(x, y)),
+        _ => Err(format!("'{}' must be an [x, y] array", key)),
+    }
+}
+
+fn number_from_json(value: &serde_json::Value, key: &str) -> Result<f64, String> {
+    value.get(key).and_then(|v| v.as_f64()).ok_or_else(|| format!("'{}' must be a number", key))
+}
+
+fn string_from_json<'a>(value: &'a serde_json::Value, key: &str) -> Result<&'a str, String> {
+    value.get(key).and_then(|v| v.as_str()).ok_or_else(|| format!("'{}' must be a string", key))
+}
+
+fn child_from_json(value: &serde_json::Value, key: &str) -> Result<Box<ColorPaint>, String> {
+    let child = value.get(key).ok_or_else(|| format!("Missing '{}' paint", key))?;
+    Ok(Box::new(ColorPaint::from_json(child)?))
+}
+
+impl Gradient {
+    fn from_json(value: &serde_json::Value) -> Result<Self, String> {
+        let extend = match value.get("extend").and_then(|v| v.as_str()).unwrap_or("pad") {
+            "pad" => GradientExtend::Pad,
+            "repeat" => GradientExtend::Repeat,
+            "reflect" => GradientExtend::Reflect,
+            other => return Err(format!("Invalid gradient extend '{}': expected pad, repeat or reflect", other)),
+        };
+        let mut stops = value
+            .get("stops")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| "A gradient needs 'stops'".to_string())?
+            .iter()
+            .map(|stop| Ok((number_from_json(stop, "offset")?, Rgba::parse(string_from_json(stop, "color")?)?)))
+            .collect::<Result<Vec<_>, String>>()?;
+        if stops.is_empty() {
+            return Err("A gradient needs at least one stop".to_string());
+        }
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Gradient { extend, stops })
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let extend = match self.extend {
+            GradientExtend::Pad => "pad",
+            GradientExtend::Repeat => "repeat",
+            GradientExtend::Reflect => "reflect",
+        };
+        let stops: Vec<serde_json::Value> = self
+            .stops
+            .iter()
+            .map(|(offset, color)| serde_json::json!({ "offset": offset, "color": color.to_hex() }))
+            .collect();
+        serde_json::json!({ "extend": extend, "stops": stops })
+    }
+}
+
+impl ColorPaint {
+    /// Parse a paint from JSON, e.g. '{"type": "glyph", "glyph": "A", "paint": {"type": "solid", "color": "#ff0000"}}'
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, String> {
+        let kind = string_from_json(value, "type")?;
+        Ok(match kind {
+            "solid" => ColorPaint::Solid(Rgba::parse(string_from_json(value, "color")?)?),
+            "linearGradient" => {
+                let p0 = point_from_json(value, "p0")?;
+                let p1 = point_from_json(value, "p1")?;
+                // Without p2 the gradient is not skewed: p2 is p1 - p0 turned by 90°
+                let p2 = match value.get("p2") {
+                    Some(_) => point_from_json(value, "p2")?,
+                    None => p0 + (p1 - p0).turn_90(),
+                };
+                ColorPaint::LinearGradient { p0, p1, p2, gradient: Gradient::from_json(value)? }
+            }
+            "radialGradient" => ColorPaint::RadialGradient {
+                c0: point_from_json(value, "c0")?,
+                r0: number_from_json(value, "r0")?,
+                c1: point_from_json(value, "c1")?,
+                r1: number_from_json(value, "r1")?,
+                gradient: Gradient::from_json(value)?,
+            },
+            "sweepGradient" => ColorPaint::SweepGradient {
+                center: point_from_json(value, "center")?,
+                start_angle: number_from_json(value, "startAngle")?,
+                end_angle: number_from_json(value, "endAngle")?,
+                gradient: Gradient::from_json(value)?,
+            },
+            "glyph" => ColorPaint::Glyph {
+                glyph: string_from_json(value, "glyph")?.to_string(),
+                paint: child_from_json(value, "paint")?,
+            },
+            "colrGlyph" => ColorPaint::ColrGlyph(string_from_json(value, "glyph")?.to_string()),
+            "transform" => {
+                let coeffs: Vec<f64> = value
+                    .get("transform")
+                    .and_then(|v| v.as_array())
+                    .map(|values| values.iter().filter_map(|v| v.as_f64()).collect())
+                    .unwrap_or_default();
+                let coeffs: [f64; 6] =
+                    coeffs.try_into().map_err(|_| "'transform' must be an array of 6 numbers".to_string())?;
+                ColorPaint::Transform { transform: Affine::new(coeffs), paint: child_from_json(value, "paint")? }
+            }
+            "composite" => {
+                let mode = value.get("mode").and_then(|v| v.as_str()).unwrap_or("srcOver");
+                let mode = COMPOSITE_MODES
+                    .iter()
+                    .find(|(name, _)| *name == mode)
+                    .map(|(name, _)| *name)
+                    .ok_or_else(|| format!("Invalid composite mode '{}'", mode))?;
+                ColorPaint::Composite {
+                    mode,
+                    source: child_from_json(value, "source")?,
+                    backdrop: child_from_json(value, "backdrop")?,
+                }
+            }
+            "layers" => {
+                let layers = value
+                    .get("layers")
+                    .and_then(|v| v.as_array())
+                    .ok_or_else(|| "'layers' must be an array of paints".to_string())?
+                    .iter()
+                    .map(ColorPaint::from_json)
+                    .collect::<Result<Vec<_>, String>>()?;
+                if layers.len() > u8::MAX as usize {
+                    return Err(format!("A layer list holds at most {} paints", u8::MAX));
+                }
+                ColorPaint::Layers(layers)
+            }
+            other => {
+                return Err(format!(
+                    "Unknown paint type '{}': expected solid, linearGradient, radialGradient, sweepGradient, glyph, colrGlyph, transform, composite or layers",
+                    other
+                ))
+            }
+        })
+    }
+
+    /// Serialize in the format read by from_json()
+    pub fn to_json(&self) -> serde_json::Value {
+        let with_gradient = |mut value: serde_json::Value, gradient: &Gradient| {
+            if let (Some(object), serde_json::Value::Object(extra)) = (value.as_object_mut(), gradient.to_json()) {
+                object.extend(extra);
+            }
+            value
+        };
+        match self {
+            ColorPaint::Solid(color) => serde_json::json!({ "type": "solid", "color": color.to_hex() }),
+            ColorPaint::LinearGradient { p0, p1, p2, gradient } => with_gradient(
+                serde_json::json!({
+                    "type": "linearGradient",
+                    "p0": [p0.x, p0.y],
+                    "p1": [p1.x, p1.y],
+                    "p2": [p2.x, p2.y],
+                }),
+                gradient,
+            ),
+            ColorPaint::RadialGradient { c0, r0, c1, r1, gradient } => with_gradient(
+                serde_json::json!({
+                    "type": "radialGradient",
+                    "c0": [c0.x, c0.y],
+                    "r0": r0,
+                    "c1": [c1.x, c1.y],
+                    "r1": r1,
+                }),
+                gradient,
+            ),
+            ColorPaint::SweepGradient { center, start_angle, end_angle, gradient } => with_gradient(
+                serde_json::json!({
+                    "type": "sweepGradient",
+                    "center": [center.x, center.y],
+                    "startAngle": start_angle,
+                    "endAngle": end_angle,
+                }),
+                gradient,
+            ),
+            ColorPaint::Glyph { glyph, paint } => {
+                serde_json::json!({ "type": "glyph", "glyph": glyph, "paint": paint.to_json() })
+            }
+            ColorPaint::ColrGlyph(glyph) => serde_json::json!({ "type": "colrGlyph", "glyph": glyph }),
+            ColorPaint::Transform { transform, paint } => {
+                serde_json::json!({ "type": "transform", "transform": transform.as_coeffs(), "paint": paint.to_json() })
+            }
+            ColorPaint::Composite { mode, source, backdrop } => serde_json::json!({
+                "type": "composite",
+                "mode": mode,
+                "source": source.to_json(),
+                "backdrop": backdrop.to_json(),
+            }),
+            ColorPaint::Layers(layers) => serde_json::json!({
+                "type": "layers",
+                "layers": layers.iter().map(ColorPaint::to_json).collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    /// Glyphs the paint refers to, as outlines (`glyph`) or color glyphs (`colrGlyph`)
+    pub fn glyph_references<'a>(&'a self, references: &mut Vec<&'a str>) {
+        match self {
+            ColorPaint::Glyph { glyph, paint } => {
+                references.push(glyph);
+                paint.glyph_references(references);
+            }
+            ColorPaint::ColrGlyph(glyph) => references.push(glyph),
+            ColorPaint::Transform { paint, .. } => paint.glyph_references(references),
+            ColorPaint::Composite { source, backdrop, .. } => {
+                source.glyph_references(references);
+                backdrop.glyph_references(references);
+            }
+            ColorPaint::Layers(layers) => layers.iter().for_each(|layer| layer.glyph_references(references)),
+            _ => {}
+        }
+    }
+
+    /// Color glyphs the paint refers to with `colrGlyph`
+    fn colr_references<'a>(&'a self, references: &mut Vec<&'a str>) {
+        match self {
+            ColorPaint::ColrGlyph(glyph) => references.push(glyph),
+            ColorPaint::Glyph { paint, .. } | ColorPaint::Transform { paint, .. } => paint.colr_references(references),
+            ColorPaint::Composite { source, backdrop, .. } => {
+                source.colr_references(references);
+                backdrop.colr_references(references);
+            }
+            ColorPaint::Layers(layers) => layers.iter().for_each(|layer| layer.colr_references(references)),
+            _ => {}
+        }
+    }
+
+    /// Colors used by the paint
+    pub fn colors(&self, colors: &mut BTreeSet<Rgba>) {
+        match self {
+            ColorPaint::Solid(color) => {
+                colors.insert(*color);
+            }
+            ColorPaint::LinearGradient { gradient, .. }
+            | ColorPaint::RadialGradient { gradient, .. }
+            | ColorPaint::SweepGradient { gradient, .. } => colors.extend(gradient.stops.iter().map(|(_, color)| *color)),
+            ColorPaint::Glyph { paint, .. } | ColorPaint::Transform { paint, .. } => paint.colors(colors),
+            ColorPaint::Composite { source, backdrop, .. } => {
+                source.colors(colors);
+                backdrop.colors(colors);
+            }
+            ColorPaint::Layers(layers) => layers.iter().for_each(|layer| layer.colors(colors)),
+            ColorPaint::ColrGlyph(_) => {}
+        }
+    }
+}
+
+/// A number or color of a paint graph, visited for interpolation
+enum Scalar<'a> {
+    Number(&'a mut f64),
+    Color(&'a mut Rgba),
+}
+
+impl Gradient {
+    fn visit(&mut self, visitor: &mut dyn FnMut(Scalar<'_>)) {
+        for (offset, color) in &mut self.stops {
+            visitor(Scalar::Number(offset));
+            visitor(Scalar::Color(color));
+        }
+    }
+}
+
+impl ColorPaint {
+    /// Visit the numbers and colors of the paint graph in a fixed order
+    fn visit(&mut self, visitor: &mut dyn FnMut(Scalar<'_>)) {
+        fn point(point: &mut Point, visitor: &mut dyn FnMut(Scalar<'_>)) {
+            visitor(Scalar::Number(&mut point.x));
+            visitor(Scalar::Number(&mut point.y));
+        }
+        match self {
+            ColorPaint::Solid(color) => visitor(Scalar::Color(color)),
+            ColorPaint::LinearGradient { p0, p1, p2, gradient } => {
+                point(p0, visitor);
+                point(p1, visitor);
+                point(p2, visitor);
+                gradient.visit(visitor);
+            }
+            ColorPaint::RadialGradient { c0, r0, c1, r1, gradient } => {
+                point(c0, visitor);
+                visitor(Scalar::Number(r0));
+                point(c1, visitor);
+                visitor(Scalar::Number(r1));
+                gradient.visit(visitor);
+            }
+            ColorPaint::SweepGradient { center, start_angle, end_angle, gradient } => {
+                point(center, visitor);
+                visitor(Scalar::Number(start_angle));
+                visitor(Scalar::Number(end_angle));
+                gradient.visit(visitor);
+            }
+            ColorPaint::Glyph { paint, .. } => paint.visit(visitor),
+            ColorPaint::ColrGlyph(_) => {}
+            ColorPaint::Transform { transform, paint } => {
+                let mut coeffs = transform.as_coeffs();
+                coeffs.iter_mut().for_each(|coeff| visitor(Scalar::Number(coeff)));
+                *transform = Affine::new(coeffs);
+                paint.visit(visitor);
+            }
+            ColorPaint::Composite { source, backdrop, .. } => {
+                source.visit(visitor);
+                backdrop.visit(visitor);
+            }
+            ColorPaint::Layers(layers) => layers.iter_mut().for_each(|layer| layer.visit(visitor)),
+        }
+    }
+
+    /// Numbers and color channels of the paint graph, in visiting order
+    fn scalars(&self) -> Vec<f64> {
+        let mut values = Vec::new();
+        self.clone().visit(&mut |scalar| match scalar {
+            Scalar::Number(number) => values.push(*number),
+            Scalar::Color(color) => values.extend(color.0.iter().map(|&channel| channel as f64)),
+        });
+        values
+    }
+
+    /// Replace the numbers and color channels with values in visiting order
+    fn set_scalars(&mut self, values: &[f64]) {
+        let mut values = values.iter().copied();
+        self.visit(&mut |scalar| match scalar {
+            Scalar::Number(number) => *number = values.next().unwrap_or(*number),
+            Scalar::Color(color) => {
+                for channel in color.0.iter_mut() {
+                    *channel = values.next().map(|value| value.round().clamp(0.0, 255.0) as u8).unwrap_or(*channel);
+                }
+            }
+        });
+    }
+
+    /// Whether two paint graphs differ only in numbers and colors
+    fn is_compatible(&self, other: &ColorPaint) -> bool {
+        let skeleton = |paint: &ColorPaint| {
+            let mut paint = paint.clone();
+            paint.set_scalars(&vec![0.0; paint.scalars().len()]);
+            paint
+        };
+        skeleton(self) == skeleton(other)
+    }
+}
+
+/// Paint graph of a glyph at a design location
+///
+/// Paint graphs of the masters are interpolated when they have the same
+/// structure (paint types, glyphs, stop counts); otherwise the default
+/// master's paint graph is used as is.
+///
+/// # Returns
+/// * `Option<ColorPaint>` - The paint graph, or None if no master layer has one
+pub fn paint_at(font: &babelfont::Font, glyph: &babelfont::Glyph, location: &DesignLocation) -> Option<ColorPaint> {
+    let masters: Vec<(&str, ColorPaint)> = font
+        .masters
+        .iter()
+        .filter_map(|master| Some((master.id.as_str(), master_layer(font, glyph, Some(master.id.as_str())).and_then(layer_paint)?)))
+        .collect();
+    let default_id = crate::source_checks::default_master_id(font);
+    let fallback = masters.iter().find(|(id, _)| Some(*id) == default_id).or(masters.first()).map(|(_, paint)| paint.clone());
+    let first = &masters.first()?.1;
+    if masters.len() == 1 || masters.iter().any(|(_, paint)| !paint.is_compatible(first)) {
+        return fallback;
+    }
+
+    let master_ids: Vec<&str> = masters.iter().map(|(id, _)| *id).collect();
+    let Ok(weights) = crate::interpolation::master_weights(font, &master_ids, location) else {
+        return fallback;
+    };
+    let scalars: Vec<Vec<f64>> = masters.iter().map(|(_, paint)| paint.scalars()).collect();
+    let interpolated: Vec<f64> = (0..scalars[0].len())
+        .map(|index| scalars.iter().zip(&weights).map(|(values, weight)| values[index] * weight).sum())
+        .collect();
+    let mut paint = first.clone();
+    paint.set_scalars(&interpolated);
+    Some(paint)
+}
+
+/// Read the paint graph stored on a layer
+pub fn layer_paint(layer: &babelfont::Layer) -> Option<ColorPaint> {
+    layer.format_specific.get(COLOR_PAINT_KEY).and_then(|value| ColorPaint::from_json(value).ok())
+}
+
+/// Master layer of a glyph, the default master's without a layer ID
+fn master_layer<'a>(font: &babelfont::Font, glyph: &'a babelfont::Glyph, layer_id: Option<&str>) -> Option<&'a babelfont::Layer> {
+    let layer_id = layer_id.or_else(|| crate::source_checks::default_master_id(font))?;
+    glyph.layers.iter().find(|layer| !layer.is_background && layer.id.as_deref() == Some(layer_id))
+}
+
+/// Read the paint graph of a glyph's layer
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_name` - Glyph to read
+/// * `layer_id` - Layer to read; the default master's layer if None
+///
+/// # Returns
+/// * `Option<ColorPaint>` - The paint graph, or None if the layer has none
+pub fn get_color_paint(font: &babelfont::Font, glyph_name: &str, layer_id: Option<&str>) -> Result<Option<ColorPaint>, ApiError> {
+    let glyph = font.glyphs.get(glyph_name).ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let layer = master_layer(font, glyph, layer_id)
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id.unwrap_or("default")))?;
+    Ok(layer_paint(layer))
+}
+
+/// Whether following `colrGlyph` references from `paint` leads back to `glyph_name`
+fn references_itself(font: &babelfont::Font, glyph_name: &str, paint: &ColorPaint) -> bool {
+    let mut pending = Vec::new();
+    paint.colr_references(&mut pending);
+    let mut pending: Vec<String> = pending.into_iter().map(str::to_string).collect();
+    let mut visited = BTreeSet::new();
+    while let Some(name) = pending.pop() {
+        if name == glyph_name {
+            return true;
+        }
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        let referenced = font.glyphs.get(&name).and_then(|glyph| master_layer(font, glyph, None)).and_then(layer_paint);
+        if let Some(referenced) = referenced {
+            let mut next = Vec::new();
+            referenced.colr_references(&mut next);
+            pending.extend(next.into_iter().map(str::to_string));
+        }
+    }
+    false
+}
+
+/// Store (or remove) the paint graph of a glyph's layer
+///
+/// Glyphs the paint refers to must exist, and `colrGlyph` references must
+/// not lead back to the glyph.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Glyph to change
+/// * `layer_id` - Layer to change; the default master's layer if None
+/// * `paint` - New paint graph; None removes it
+pub fn set_color_paint(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    layer_id: Option<&str>,
+    paint: Option<ColorPaint>,
+) -> Result<(), ApiError> {
+    if let Some(paint) = &paint {
+        let mut references = Vec::new();
+        paint.glyph_references(&mut references);
+        if let Some(missing) = references.iter().find(|name| font.glyphs.get(name).is_none()) {
+            return Err(ApiError::glyph_not_found(missing));
+        }
+        if references_itself(font, glyph_name, paint) {
+            return Err(ApiError::invalid_json("paint", format!("'{}' would refer to itself", glyph_name)));
+        }
+    }
+    let layer_id = match layer_id {
+        Some(id) => id.to_string(),
+        None => crate::source_checks::default_master_id(font)
+            .map(str::to_string)
+            .ok_or_else(|| "The font has no default master".to_string())?,
+    };
+    let layer = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?
+        .layers
+        .iter_mut()
+        .find(|layer| !layer.is_background && layer.id.as_deref() == Some(layer_id.as_str()))
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id))?;
+    match paint {
+        Some(paint) => {
+            layer.format_specific.insert(COLOR_PAINT_KEY.to_string(), paint.to_json());
+        }
+        None => {
+            layer.format_specific.remove(COLOR_PAINT_KEY);
+        }
+    }
+    Ok(())
+}
+
+/// List the color glyphs of a font
+///
+/// # Returns
+/// * `JsonValue` - Object '{"glyphs": [{"glyph": "A.color", "layers": ["m01"]}], "palette": ["#ff0000ff"]}' with the distinct colors of all paints
+pub fn list_color_glyphs(font: &babelfont::Font) -> serde_json::Value {
+    let mut glyphs = Vec::new();
+    let mut colors = BTreeSet::new();
+    for glyph in font.glyphs.iter() {
+        let mut layers = Vec::new();
+        for layer in glyph.layers.iter().filter(|layer| !layer.is_background) {
+            if let Some(paint) = layer_paint(layer) {
+                paint.colors(&mut colors);
+                layers.push(layer.id.clone().unwrap_or_default());
+            }
+        }
+        if !layers.is_empty() {
+            glyphs.push(serde_json::json!({ "glyph": glyph.name.to_string(), "layers": layers }));
+        }
+    }
+    serde_json::json!({
+        "glyphs": glyphs,
+        "palette": colors.into_iter().map(Rgba::to_hex).collect::<Vec<_>>(),
+    })
+}
+
+/// Color glyphs collected from the source for compilation
+#[derive(Default)]
+pub struct SourceColorGlyphs {
+    /// Exported glyphs with a paint graph on the default master
+    paints: Vec<(String, ColorPaint)>,
+    /// Names each involved source glyph may have in the compiled font
+    compiled_names: HashMap<String, Vec<String>>,
+}
+
+/// Collect the paint graphs of the default master for compilation
+pub fn collect_color_glyphs(font: &babelfont::Font) -> SourceColorGlyphs {
+    let mut collected = SourceColorGlyphs::default();
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported) {
+        let Some(paint) = master_layer(font, glyph, None).and_then(layer_paint) else {
+            continue;
+        };
+        let mut names = vec![glyph.name.to_string()];
+        let mut references = Vec::new();
+        paint.glyph_references(&mut references);
+        names.extend(references.into_iter().map(str::to_string));
+        for name in names {
+            if let Some(glyph) = font.glyphs.get(&name) {
+                collected.compiled_names.entry(name).or_insert_with(|| crate::variation_sequences::compiled_names(glyph));
+            }
+        }
+        collected.paints.push((glyph.name.to_string(), paint));
+    }
+    collected
+}
+
+/// Builds COLR paints, gathering layer list entries and palette colors
+struct ColrBuilder<'a> {
+    gids: HashMap<&'a str, u16>,
+    palette: Vec<Rgba>,
+    palette_indices: HashMap<Rgba, u16>,
+    layers: Vec<Paint>,
+}
+
+fn fword(value: f64) -> FWord {
+    FWord::new(value.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16)
+}
+
+fn ufword(value: f64) -> UfWord {
+    UfWord::new(value.round().clamp(0.0, u16::MAX as f64) as u16)
+}
+
+/// Angle in degrees as COLR stores it: in units of 180°
+fn angle(degrees: f64) -> F2Dot14 {
+    F2Dot14::from_f32((degrees / 180.0) as f32)
+}
+
+impl ColrBuilder<'_> {
+    fn color_index(&mut self, color: Rgba) -> u16 {
+        if let Some(&index) = self.palette_indices.get(&color) {
+            return index;
+        }
+        let index = self.palette.len() as u16;
+        self.palette.push(color);
+        self.palette_indices.insert(color, index);
+        index
+    }
+
+    fn gid(&self, glyph_name: &str) -> Result<GlyphId16, String> {
+        self.gids
+            .get(glyph_name)
+            .map(|&gid| GlyphId16::new(gid))
+            .ok_or_else(|| format!("Glyph '{}' used by a color glyph is not in the compiled font", glyph_name))
+    }
+
+    fn color_line(&mut self, gradient: &Gradient) -> ColorLine {
+        let extend = match gradient.extend {
+            GradientExtend::Pad => Extend::Pad,
+            GradientExtend::Repeat => Extend::Repeat,
+            GradientExtend::Reflect => Extend::Reflect,
+        };
+        let stops = gradient
+            .stops
+            .iter()
+            .map(|(offset, color)| ColorStop::new(F2Dot14::from_f32(*offset as f32), self.color_index(*color), F2Dot14::from_f32(1.0)))
+            .collect();
+        ColorLine::new(extend, stops)
+    }
+
+    fn paint(&mut self, paint: &ColorPaint) -> Result<Paint, String> {
+        Ok(match paint {
+            ColorPaint::Solid(color) => Paint::Solid(PaintSolid::new(self.color_index(*color), F2Dot14::from_f32(1.0))),
+            ColorPaint::LinearGradient { p0, p1, p2, gradient } => Paint::LinearGradient(PaintLinearGradient::new(
+                self.color_line(gradient),
+                fword(p0.x),
+                fword(p0.y),
+                fword(p1.x),
+                fword(p1.y),
+                fword(p2.x),
+                fword(p2.y),
+            )),
+            ColorPaint::RadialGradient { c0, r0, c1, r1, gradient } => Paint::RadialGradient(PaintRadialGradient::new(
+                self.color_line(gradient),
+                fword(c0.x),
+                fword(c0.y),
+                ufword(*r0),
+                fword(c1.x),
+                fword(c1.y),
+                ufword(*r1),
+            )),
+            ColorPaint::SweepGradient { center, start_angle, end_angle, gradient } => {
+                Paint::SweepGradient(PaintSweepGradient::new(
+                    self.color_line(gradient),
+                    fword(center.x),
+                    fword(center.y),
+                    angle(*start_angle),
+                    angle(*end_angle),
+                ))
+            }
+            ColorPaint::Glyph { glyph, paint } => Paint::Glyph(PaintGlyph::new(self.paint(paint)?, self.gid(glyph)?)),
+            ColorPaint::ColrGlyph(glyph) => Paint::ColrGlyph(PaintColrGlyph::new(self.gid(glyph)?)),
+            ColorPaint::Transform { transform, paint } => {
+                let [xx, yx, xy, yy, dx, dy] = transform.as_coeffs().map(Fixed::from_f64);
+                Paint::Transform(PaintTransform::new(self.paint(paint)?, Affine2x3::new(xx, yx, xy, yy, dx, dy)))
+            }
+            ColorPaint::Composite { mode, source, backdrop } => {
+                let mode = COMPOSITE_MODES
+                    .iter()
+                    .find(|(name, _)| name == mode)
+                    .map(|(_, mode)| *mode)
+                    .unwrap_or(CompositeMode::SrcOver);
+                Paint::Composite(PaintComposite::new(self.paint(source)?, mode, self.paint(backdrop)?))
+            }
+            ColorPaint::Layers(layers) => {
+                // Nested layer lists are added while converting, so this list stays contiguous
+                let paints = layers.iter().map(|layer| self.paint(layer)).collect::<Result<Vec<_>, String>>()?;
+                let first = self.layers.len() as u32;
+                let count = paints.len() as u8;
+                self.layers.extend(paints);
+                Paint::ColrLayers(PaintColrLayers::new(count, first))
+            }
+        })
+    }
+}
+
+/// Add COLR and CPAL tables for the collected color glyphs to compiled font bytes
+///
+/// Any existing COLR and CPAL tables are replaced. Returns the bytes
+/// unchanged if there are no color glyphs.
+pub fn add_to_compiled(font_bytes: Vec<u8>, color_glyphs: &SourceColorGlyphs) -> Result<Vec<u8>, String> {
+    if color_glyphs.paints.is_empty() {
+        return Ok(font_bytes);
+    }
+
+    let font = FontRef::new(&font_bytes).map_err(|e| format!("Failed to parse compiled font: {:?}", e))?;
+    let glyph_order = crate::font_reader::read_glyph_order(&font).map_err(|e| e.to_string())?;
+    let name_to_gid: HashMap<&str, u16> =
+        glyph_order.iter().enumerate().map(|(gid, name)| (name.as_str(), gid as u16)).collect();
+    let gids: HashMap<&str, u16> = color_glyphs
+        .compiled_names
+        .iter()
+        .filter_map(|(name, candidates)| {
+            let gid = candidates.iter().find_map(|candidate| name_to_gid.get(candidate.as_str()).copied())?;
+            Some((name.as_str(), gid))
+        })
+        .collect();
+
+    let mut builder = ColrBuilder { gids, palette: Vec::new(), palette_indices: HashMap::new(), layers: Vec::new() };
+    let mut records = Vec::new();
+    for (glyph_name, paint) in &color_glyphs.paints {
+        let gid = builder.gid(glyph_name)?;
+        records.push(BaseGlyphPaint::new(gid, builder.paint(paint)?));
+    }
+    records.sort_by_key(|record| record.glyph_id);
+
+    let colr = Colr {
+        base_glyph_list: Some(BaseGlyphList::new(records)).into(),
+        layer_list: (!builder.layers.is_empty()).then(|| LayerList::new(std::mem::take(&mut builder.layers))).into(),
+        ..Default::default()
+    };
+    let color_records: Vec<ColorRecord> = builder
+        .palette
+        .iter()
+        .map(|Rgba([r, g, b, a])| ColorRecord::new(*b, *g, *r, *a))
+        .collect();
+    let count = color_records.len() as u16;
+    let cpal = Cpal::new(count, 1, count, Some(color_records), vec![0]);
+
+    let mut font_builder = FontBuilder::new();
+    font_builder.add_table(&colr).map_err(|e| format!("Failed to write COLR table: {:?}", e))?;
+    font_builder.add_table(&cpal).map_err(|e| format!("Failed to write CPAL table: {:?}", e))?;
+    font_builder.copy_missing_tables(font);
+    Ok(font_builder.build())
+}
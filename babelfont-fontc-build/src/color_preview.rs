@@ -0,0 +1,720 @@
+// Color preview module
+//
+// This module flattens color glyphs into filled paths for previews. Source
+// paint graphs are walked at a design location and compiled COLR glyphs are
+// painted with skrifa: glyph clips become paths in font units, and each fill
+// keeps its resolved colors, its gradient geometry and the transform it is
+// drawn with. The flat layers are returned as JSON or written as SVG, so the
+// canvas can draw color glyphs without a COLR renderer. SVG has no sweep gradients, so they
+// are drawn with the color at the middle of their color line; nested clips
+// use the innermost glyph; composite modes become CSS blend modes where one
+// exists and are drawn as srcOver otherwise.
+
+use kurbo::{Affine, BezPath, Point, Rect, Shape as _, Vec2};
+use skrifa::color::{Brush, ColorPainter, ColorStop, CompositeMode, Extend, Transform};
+use skrifa::instance::{Location, Size};
+use skrifa::outline::OutlineGlyphCollection;
+use skrifa::raw::types::BoundingBox;
+use skrifa::raw::TableProvider;
+use skrifa::MetadataProvider;
+use wasm_bindgen::prelude::*;
+
+use crate::errors::ApiError;
+use crate::color_paints::{self, ColorPaint, Gradient, GradientExtend, Rgba};
+use crate::font_reader::{read_glyph_order, skrifa_location};
+use crate::glyph_outlines::{design_location, flatten_layer_components_cached, shapes_to_bezpath, LayerSource};
+use crate::text_render::{glyph_path, svg_number};
+
+/// Deepest nesting of color glyph references followed
+const MAX_PAINT_DEPTH: usize = 64;
+
+/// Fill of a flattened layer, in the coordinates of its fill transform
+#[derive(Clone, Debug)]
+pub enum Fill {
+    Solid(Rgba),
+    /// Color line from `p0` to `p1`, with the COLR `p2` skew already applied
+    LinearGradient { p0: Point, p1: Point, gradient: Gradient },
+    RadialGradient { c0: Point, r0: f64, c1: Point, r1: f64, gradient: Gradient },
+    SweepGradient { center: Point, start_angle: f64, end_angle: f64, gradient: Gradient },
+}
+
+/// A filled path of a flattened color glyph
+#[derive(Clone, Debug)]
+pub struct FlatLayer {
+    /// Outline in font units
+    pub path: BezPath,
+    pub fill: Fill,
+    /// Transform of the gradient geometry
+    pub fill_transform: Affine,
+    /// COLR composite mode the layer is drawn with
+    pub blend: &'static str,
+}
+
+/// End of the color line of a COLR linear gradient
+///
+/// COLR rotates the color line so it is perpendicular to `p0`-`p2`; SVG
+/// gradients only have the color line, so `p1` is projected onto that
+/// perpendicular.
+pub fn linear_gradient_end(p0: Point, p1: Point, p2: Point) -> Point {
+    let direction = p2 - p0;
+    let normal = Vec2::new(direction.y, -direction.x);
+    let length = normal.hypot2();
+    if length == 0.0 {
+        return p1;
+    }
+    p0 + normal * ((p1 - p0).dot(normal) / length)
+}
+
+/// Color of a gradient at an offset of its color line
+pub fn gradient_color_at(gradient: &Gradient, offset: f64) -> Rgba {
+    let Some(&(first_offset, first_color)) = gradient.stops.first() else {
+        return Rgba([0, 0, 0, 0]);
+    };
+    if offset <= first_offset {
+        return first_color;
+    }
+    for pair in gradient.stops.windows(2) {
+        let ((start, from), (end, to)) = (pair[0], pair[1]);
+        if offset <= end {
+            let t = if end > start { (offset - start) / (end - start) } else { 1.0 };
+            let mut channels = [0u8; 4];
+            for (channel, (a, b)) in channels.iter_mut().zip(from.0.iter().zip(to.0.iter())) {
+                *channel = (*a as f64 + (*b as f64 - *a as f64) * t).round() as u8;
+            }
+            return Rgba(channels);
+        }
+    }
+    gradient.stops.last().map(|(_, color)| *color).unwrap_or(first_color)
+}
+
+/// Walks the paint graphs of source glyphs at a location
+struct SourceFlattener<'a> {
+    layers: &'a LayerSource<'a>,
+}
+
+impl SourceFlattener<'_> {
+    /// Outline of a glyph at the location, with components flattened
+    fn outline(&self, glyph_name: &str) -> Result<BezPath, ApiError> {
+        let layer = self
+            .layers
+            .layer(glyph_name)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?;
+        let shapes = flatten_layer_components_cached(&layer, self.layers)?;
+        Ok(shapes_to_bezpath(&shapes))
+    }
+
+    fn walk(
+        &self,
+        paint: &ColorPaint,
+        transform: Affine,
+        clip: Option<&BezPath>,
+        blend: &'static str,
+        depth: usize,
+        out: &mut Vec<FlatLayer>,
+    ) -> Result<(), ApiError> {
+        if depth > MAX_PAINT_DEPTH {
+            return Err(format!("Color glyph references nested deeper than {}", MAX_PAINT_DEPTH).into());
+        }
+        let fill = match paint {
+            ColorPaint::Solid(color) => Fill::Solid(*color),
+            ColorPaint::LinearGradient { p0, p1, p2, gradient } => Fill::LinearGradient {
+                p0: *p0,
+                p1: linear_gradient_end(*p0, *p1, *p2),
+                gradient: gradient.clone(),
+            },
+            ColorPaint::RadialGradient { c0, r0, c1, r1, gradient } => Fill::RadialGradient {
+                c0: *c0,
+                r0: *r0,
+                c1: *c1,
+                r1: *r1,
+                gradient: gradient.clone(),
+            },
+            ColorPaint::SweepGradient { center, start_angle, end_angle, gradient } => Fill::SweepGradient {
+                center: *center,
+                start_angle: *start_angle,
+                end_angle: *end_angle,
+                gradient: gradient.clone(),
+            },
+            ColorPaint::Glyph { glyph, paint } => {
+                let clip = transform * self.outline(glyph)?;
+                return self.walk(paint, transform, Some(&clip), blend, depth, out);
+            }
+            ColorPaint::ColrGlyph(name) => {
+                let font = self.layers.font();
+                let glyph = font.glyphs.get(name).ok_or_else(|| ApiError::glyph_not_found(name))?;
+                if let Some(paint) = color_paints::paint_at(font, glyph, self.layers.location()) {
+                    self.walk(&paint, transform, clip, blend, depth + 1, out)?;
+                }
+                return Ok(());
+            }
+            ColorPaint::Transform { transform: inner, paint } => {
+                return self.walk(paint, transform * *inner, clip, blend, depth, out);
+            }
+            ColorPaint::Composite { mode, source, backdrop } => {
+                self.walk(backdrop, transform, clip, blend, depth, out)?;
+                return self.walk(source, transform, clip, *mode, depth, out);
+            }
+            ColorPaint::Layers(layers) => {
+                for layer in layers {
+                    self.walk(layer, transform, clip, blend, depth, out)?;
+                }
+                return Ok(());
+            }
+        };
+        // A fill without a glyph clip would cover the whole canvas; it is left out
+        if let Some(clip) = clip {
+            out.push(FlatLayer { path: clip.clone(), fill, fill_transform: transform, blend });
+        }
+        Ok(())
+    }
+}
+
+/// CSS blend mode for a COLR composite mode, if there is one
+fn css_blend_mode(mode: &str) -> Option<&'static str> {
+    Some(match mode {
+        "plus" => "plus-lighter",
+        "screen" => "screen",
+        "overlay" => "overlay",
+        "darken" => "darken",
+        "lighten" => "lighten",
+        "colorDodge" => "color-dodge",
+        "colorBurn" => "color-burn",
+        "hardLight" => "hard-light",
+        "softLight" => "soft-light",
+        "difference" => "difference",
+        "exclusion" => "exclusion",
+        "multiply" => "multiply",
+        "hue" => "hue",
+        "saturation" => "saturation",
+        "color" => "color",
+        "luminosity" => "luminosity",
+        _ => return None,
+    })
+}
+
+/// SVG color and opacity attributes of a color, named with a prefix ("fill", "stop")
+fn color_attributes(prefix: &str, color: Rgba) -> String {
+    let [r, g, b, a] = color.0;
+    let name = if prefix == "fill" { prefix.to_string() } else { format!("{}-color", prefix) };
+    let mut attributes = format!("{}=\"#{:02x}{:02x}{:02x}\"", name, r, g, b);
+    if a < 255 {
+        attributes.push_str(&format!(" {}-opacity=\"{}\"", prefix, svg_number(a as f64 / 255.0)));
+    }
+    attributes
+}
+
+/// Name of a gradient extend mode, as in SVG `spreadMethod`
+fn extend_name(extend: GradientExtend) -> &'static str {
+    match extend {
+        GradientExtend::Pad => "pad",
+        GradientExtend::Repeat => "repeat",
+        GradientExtend::Reflect => "reflect",
+    }
+}
+
+/// SVG element of a gradient's color stops and shared attributes
+fn gradient_element(element: &str, id: &str, geometry: String, gradient: &Gradient, transform: Affine) -> String {
+    let spread = extend_name(gradient.extend);
+    let [a, b, c, d, e, f] = transform.as_coeffs();
+    let mut svg = format!(
+        "<{} id=\"{}\" {} gradientUnits=\"userSpaceOnUse\" spreadMethod=\"{}\" gradientTransform=\"matrix({} {} {} {} {} {})\">\n",
+        element,
+        id,
+        geometry,
+        spread,
+        svg_number(a),
+        svg_number(b),
+        svg_number(c),
+        svg_number(d),
+        svg_number(e),
+        svg_number(f),
+    );
+    for (offset, color) in &gradient.stops {
+        svg.push_str(&format!("<stop offset=\"{}\" {}/>\n", svg_number(*offset), color_attributes("stop", *color)));
+    }
+    svg.push_str(&format!("</{}>\n", element));
+    svg
+}
+
+/// JSON description of a fill
+fn fill_json(fill: &Fill, transform: Affine) -> serde_json::Value {
+    let point = |point: &Point| serde_json::json!([point.x, point.y]);
+    let gradient_json = |kind: &str, gradient: &Gradient| {
+        let extend = extend_name(gradient.extend);
+        let stops: Vec<serde_json::Value> = gradient
+            .stops
+            .iter()
+            .map(|(offset, color)| serde_json::json!({ "offset": offset, "color": color.to_hex() }))
+            .collect();
+        serde_json::json!({ "type": kind, "extend": extend, "stops": stops, "transform": transform.as_coeffs() })
+    };
+    match fill {
+        Fill::Solid(color) => serde_json::json!({ "type": "solid", "color": color.to_hex() }),
+        Fill::LinearGradient { p0, p1, gradient } => {
+            let mut value = gradient_json("linearGradient", gradient);
+            value["p0"] = point(p0);
+            value["p1"] = point(p1);
+            value
+        }
+        Fill::RadialGradient { c0, r0, c1, r1, gradient } => {
+            let mut value = gradient_json("radialGradient", gradient);
+            value["c0"] = point(c0);
+            value["r0"] = serde_json::json!(r0);
+            value["c1"] = point(c1);
+            value["r1"] = serde_json::json!(r1);
+            value
+        }
+        Fill::SweepGradient { center, start_angle, end_angle, gradient } => {
+            let mut value = gradient_json("sweepGradient", gradient);
+            value["center"] = point(center);
+            value["startAngle"] = serde_json::json!(start_angle);
+            value["endAngle"] = serde_json::json!(end_angle);
+            value
+        }
+    }
+}
+
+/// A color glyph flattened at a location
+pub struct FlatGlyph {
+    pub layers: Vec<FlatLayer>,
+    pub width: f64,
+    pub ascender: f64,
+    pub descender: f64,
+}
+
+impl FlatGlyph {
+    /// Layers as JSON: '{"width": 600, "ascender": 800, "descender": -200, "layers": [{"path": "M0 0L...", "fill": {"type": "solid", "color": "#ff0000ff"}, "blend": "srcOver"}]}'
+    ///
+    /// Paths are in font units (y-up). Gradient fills have their stops and
+    /// the `transform` of their geometry.
+    pub fn to_json(&self) -> serde_json::Value {
+        let layers: Vec<serde_json::Value> = self
+            .layers
+            .iter()
+            .map(|layer| {
+                serde_json::json!({
+                    "path": layer.path.to_svg(),
+                    "fill": fill_json(&layer.fill, layer.fill_transform),
+                    "blend": layer.blend,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "width": self.width,
+            "ascender": self.ascender,
+            "descender": self.descender,
+            "layers": layers,
+        })
+    }
+
+    /// Write the layers as an SVG document
+    ///
+    /// Coordinates are font units with y flipped; the view box spans the
+    /// advance width and the descender to the ascender.
+    pub fn to_svg(&self) -> String {
+        let (layers, width, ascender) = (&self.layers, self.width, self.ascender);
+        let height = self.ascender - self.descender;
+        let mut defs = String::new();
+        let mut paths = String::new();
+        for (index, layer) in layers.iter().enumerate() {
+            let id = format!("paint{}", index);
+            let fill = match &layer.fill {
+                Fill::Solid(color) => color_attributes("fill", *color),
+                Fill::LinearGradient { p0, p1, gradient } => {
+                    let geometry = format!(
+                        "x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+                        svg_number(p0.x),
+                        svg_number(p0.y),
+                        svg_number(p1.x),
+                        svg_number(p1.y)
+                    );
+                    defs.push_str(&gradient_element("linearGradient", &id, geometry, gradient, layer.fill_transform));
+                    format!("fill=\"url(#{})\"", id)
+                }
+                Fill::RadialGradient { c0, r0, c1, r1, gradient } => {
+                    let geometry = format!(
+                        "fx=\"{}\" fy=\"{}\" fr=\"{}\" cx=\"{}\" cy=\"{}\" r=\"{}\"",
+                        svg_number(c0.x),
+                        svg_number(c0.y),
+                        svg_number(*r0),
+                        svg_number(c1.x),
+                        svg_number(c1.y),
+                        svg_number(*r1)
+                    );
+                    defs.push_str(&gradient_element("radialGradient", &id, geometry, gradient, layer.fill_transform));
+                    format!("fill=\"url(#{})\"", id)
+                }
+                Fill::SweepGradient { gradient, .. } => color_attributes("fill", gradient_color_at(gradient, 0.5)),
+            };
+            let blend = css_blend_mode(layer.blend)
+                .map(|mode| format!(" style=\"mix-blend-mode:{}\"", mode))
+                .unwrap_or_default();
+            paths.push_str(&format!("<path d=\"{}\" {}{}/>\n", layer.path.to_svg(), fill, blend));
+        }
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 {top} {w} {h}\">\n",
+            w = svg_number(width),
+            h = svg_number(height),
+            top = svg_number(-ascender),
+        );
+        if !defs.is_empty() {
+            svg.push_str("<defs>\n");
+            svg.push_str(&defs);
+            svg.push_str("</defs>\n");
+        }
+        svg.push_str("<g transform=\"scale(1 -1)\" style=\"isolation:isolate\">\n");
+        svg.push_str(&paths);
+        svg.push_str("</g>\n</svg>\n");
+        svg
+    }
+}
+
+/// Flatten a glyph of a source font at a location
+///
+/// Glyphs without a paint graph are filled with the foreground color.
+fn flatten_source_glyph(
+    font: &babelfont::Font,
+    glyph_name: &str,
+    location_json: &str,
+    foreground: Rgba,
+) -> Result<FlatGlyph, ApiError> {
+    let glyph = font.glyphs.get(glyph_name).ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let location = design_location(font, location_json)?;
+    let layers = LayerSource::persistent(font, &location);
+    let flattener = SourceFlattener { layers: &layers };
+
+    let paint = color_paints::paint_at(font, glyph, &location).unwrap_or_else(|| ColorPaint::Glyph {
+        glyph: glyph_name.to_string(),
+        paint: Box::new(ColorPaint::Solid(foreground)),
+    });
+    let mut flat = Vec::new();
+    flattener.walk(&paint, Affine::IDENTITY, None, "srcOver", 0, &mut flat)?;
+
+    let width = layers
+        .layer(glyph_name)
+        .map_err(|e| ApiError::interpolation(glyph_name, e))?
+        .width as f64;
+    let upm = font.upm as f64;
+    let master = crate::source_checks::default_master_id(font)
+        .and_then(|id| font.masters.iter().find(|master| master.id == id))
+        .or_else(|| font.masters.first());
+    let metric = |metric: babelfont::MetricType| {
+        master.and_then(|master| master.metrics.get(&metric)).map(|&value| value as f64)
+    };
+
+    Ok(FlatGlyph {
+        layers: flat,
+        width,
+        ascender: metric(babelfont::MetricType::Ascender).unwrap_or(0.8 * upm),
+        descender: metric(babelfont::MetricType::Descender).unwrap_or(-0.2 * upm),
+    })
+}
+
+/// Flatten a source color glyph at a location and write it as SVG
+///
+/// Glyphs without a paint graph are filled black, so any glyph can be
+/// previewed.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_name` - Glyph to flatten
+/// * `location_json` - JSON object with axis tags and values in user space; '{}' is the default location
+///
+/// # Returns
+/// * `String` - SVG document in font units
+pub fn color_glyph_svg(font: &babelfont::Font, glyph_name: &str, location_json: &str) -> Result<String, ApiError> {
+    Ok(flatten_source_glyph(font, glyph_name, location_json, Rgba([0, 0, 0, 255]))?.to_svg())
+}
+
+/// COLR composite mode names, as in source paint graphs
+fn composite_mode_name(mode: CompositeMode) -> &'static str {
+    match mode {
+        CompositeMode::Clear => "clear",
+        CompositeMode::Src => "src",
+        CompositeMode::Dest => "dest",
+        CompositeMode::DestOver => "destOver",
+        CompositeMode::SrcIn => "srcIn",
+        CompositeMode::DestIn => "destIn",
+        CompositeMode::SrcOut => "srcOut",
+        CompositeMode::DestOut => "destOut",
+        CompositeMode::SrcAtop => "srcAtop",
+        CompositeMode::DestAtop => "destAtop",
+        CompositeMode::Xor => "xor",
+        CompositeMode::Plus => "plus",
+        CompositeMode::Screen => "screen",
+        CompositeMode::Overlay => "overlay",
+        CompositeMode::Darken => "darken",
+        CompositeMode::Lighten => "lighten",
+        CompositeMode::ColorDodge => "colorDodge",
+        CompositeMode::ColorBurn => "colorBurn",
+        CompositeMode::HardLight => "hardLight",
+        CompositeMode::SoftLight => "softLight",
+        CompositeMode::Difference => "difference",
+        CompositeMode::Exclusion => "exclusion",
+        CompositeMode::Multiply => "multiply",
+        CompositeMode::HslHue => "hue",
+        CompositeMode::HslSaturation => "saturation",
+        CompositeMode::HslColor => "color",
+        CompositeMode::HslLuminosity => "luminosity",
+        _ => "srcOver",
+    }
+}
+
+/// Collects the layers of a compiled color glyph painted by skrifa
+struct CompiledFlattener<'a> {
+    outlines: &'a OutlineGlyphCollection<'a>,
+    location: &'a Location,
+    palette: Vec<Rgba>,
+    foreground: Rgba,
+    transforms: Vec<Affine>,
+    clips: Vec<BezPath>,
+    blends: Vec<&'static str>,
+    layers: Vec<FlatLayer>,
+}
+
+impl CompiledFlattener<'_> {
+    fn transform(&self) -> Affine {
+        self.transforms.last().copied().unwrap_or(Affine::IDENTITY)
+    }
+
+    /// Palette color with an alpha multiplier; index 0xFFFF is the foreground
+    fn color(&self, palette_index: u16, alpha: f32) -> Rgba {
+        let Rgba([r, g, b, a]) = match palette_index {
+            0xFFFF => self.foreground,
+            index => self.palette.get(index as usize).copied().unwrap_or(Rgba([0, 0, 0, 255])),
+        };
+        Rgba([r, g, b, (a as f32 * alpha.clamp(0.0, 1.0)).round() as u8])
+    }
+
+    fn gradient(&self, stops: &[ColorStop], extend: Extend) -> Gradient {
+        let extend = match extend {
+            Extend::Repeat => GradientExtend::Repeat,
+            Extend::Reflect => GradientExtend::Reflect,
+            _ => GradientExtend::Pad,
+        };
+        let stops = stops
+            .iter()
+            .map(|stop| (stop.offset as f64, self.color(stop.palette_index, stop.alpha)))
+            .collect();
+        Gradient { extend, stops }
+    }
+}
+
+fn skrifa_point(point: skrifa::raw::types::Point<f32>) -> Point {
+    Point::new(point.x as f64, point.y as f64)
+}
+
+impl ColorPainter for CompiledFlattener<'_> {
+    fn push_transform(&mut self, transform: Transform) {
+        let Transform { xx, yx, xy, yy, dx, dy } = transform;
+        let transform = Affine::new([xx, yx, xy, yy, dx, dy].map(|value| value as f64));
+        self.transforms.push(self.transform() * transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transforms.pop();
+    }
+
+    fn push_clip_glyph(&mut self, glyph_id: skrifa::GlyphId) {
+        let outline = glyph_path(self.outlines, glyph_id.to_u32(), self.location).unwrap_or_default();
+        self.clips.push(self.transform() * outline);
+    }
+
+    fn push_clip_box(&mut self, clip_box: BoundingBox<f32>) {
+        let rect = Rect::new(
+            clip_box.x_min as f64,
+            clip_box.y_min as f64,
+            clip_box.x_max as f64,
+            clip_box.y_max as f64,
+        );
+        self.clips.push(self.transform() * rect.to_path(0.1));
+    }
+
+    fn pop_clip(&mut self) {
+        self.clips.pop();
+    }
+
+    fn fill(&mut self, brush: Brush<'_>) {
+        // A fill without a glyph clip would cover the whole canvas; it is left out
+        let Some(path) = self.clips.last().cloned() else {
+            return;
+        };
+        let fill = match brush {
+            Brush::Solid { palette_index, alpha } => Fill::Solid(self.color(palette_index, alpha)),
+            Brush::LinearGradient { p0, p1, color_stops, extend } => Fill::LinearGradient {
+                p0: skrifa_point(p0),
+                p1: skrifa_point(p1),
+                gradient: self.gradient(color_stops, extend),
+            },
+            Brush::RadialGradient { c0, r0, c1, r1, color_stops, extend } => Fill::RadialGradient {
+                c0: skrifa_point(c0),
+                r0: r0 as f64,
+                c1: skrifa_point(c1),
+                r1: r1 as f64,
+                gradient: self.gradient(color_stops, extend),
+            },
+            Brush::SweepGradient { c0, start_angle, end_angle, color_stops, extend } => Fill::SweepGradient {
+                center: skrifa_point(c0),
+                start_angle: start_angle as f64,
+                end_angle: end_angle as f64,
+                gradient: self.gradient(color_stops, extend),
+            },
+        };
+        self.layers.push(FlatLayer {
+            path,
+            fill,
+            fill_transform: self.transform(),
+            blend: self.blends.last().copied().unwrap_or("srcOver"),
+        });
+    }
+
+    fn push_layer(&mut self, composite_mode: CompositeMode) {
+        self.blends.push(composite_mode_name(composite_mode));
+    }
+
+    fn pop_layer(&mut self) {
+        self.blends.pop();
+    }
+}
+
+/// Colors of a CPAL palette
+fn palette_colors(font: &skrifa::FontRef, palette: usize) -> Result<Vec<Rgba>, ApiError> {
+    let Ok(cpal) = font.cpal() else {
+        return Ok(Vec::new());
+    };
+    let start = cpal
+        .color_record_indices()
+        .get(palette)
+        .ok_or_else(|| ApiError::invalid_argument("palette", palette))?
+        .get() as usize;
+    let records = match cpal.color_records_array() {
+        Some(records) => records.map_err(|e| ApiError::missing_table("CPAL", e))?,
+        None => return Ok(Vec::new()),
+    };
+    Ok(records
+        .iter()
+        .skip(start)
+        .take(cpal.num_palette_entries() as usize)
+        .map(|record| Rgba([record.red(), record.green(), record.blue(), record.alpha()]))
+        .collect())
+}
+
+/// Flatten a glyph of a compiled font at a location
+///
+/// Color glyphs are painted from COLR (v1 or v0) with a CPAL palette;
+/// other glyphs are filled with the foreground color.
+fn flatten_compiled_glyph(
+    font_bytes: &[u8],
+    glyph_name: &str,
+    location_json: Option<&str>,
+    palette: usize,
+    foreground: Rgba,
+) -> Result<FlatGlyph, ApiError> {
+    let font = skrifa::FontRef::new(font_bytes).map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+    let glyph_id = glyph_order
+        .iter()
+        .position(|name| name == glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))? as u32;
+    let location = skrifa_location(&font, location_json)?;
+    let outlines = font.outline_glyphs();
+
+    let mut flattener = CompiledFlattener {
+        outlines: &outlines,
+        location: &location,
+        palette: palette_colors(&font, palette)?,
+        foreground,
+        transforms: Vec::new(),
+        clips: Vec::new(),
+        blends: Vec::new(),
+        layers: Vec::new(),
+    };
+    match font.color_glyphs().get(skrifa::GlyphId::new(glyph_id)) {
+        Some(color_glyph) => color_glyph
+            .paint(&location, &mut flattener)
+            .map_err(|e| format!("Failed to paint '{}': {:?}", glyph_name, e))?,
+        None => {
+            flattener.push_clip_glyph(skrifa::GlyphId::new(glyph_id));
+            flattener.fill(Brush::Solid { palette_index: 0xFFFF, alpha: 1.0 });
+        }
+    }
+
+    let metrics = font.metrics(Size::unscaled(), &location);
+    let width = font
+        .glyph_metrics(Size::unscaled(), &location)
+        .advance_width(skrifa::GlyphId::new(glyph_id))
+        .unwrap_or(0.0) as f64;
+    Ok(FlatGlyph {
+        layers: flattener.layers,
+        width,
+        ascender: metrics.ascent as f64,
+        descender: metrics.descent as f64,
+    })
+}
+
+/// Render a color glyph as flat filled paths or SVG
+///
+/// Resolves COLR layers and paints (or, for the cached source font, the
+/// paint graphs on its layers) into a flat list of filled paths with
+/// resolved RGBA colors, so color glyphs can be shown without implementing
+/// the paint model in JS. Glyphs without color data are filled with the
+/// foreground color.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes, or undefined/empty to use the cached source font
+/// * `glyph_name` - Glyph to render
+/// * `location_json` - Optional JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'
+/// * `options_json` - Optional JSON object:
+///   - `palette`: number - CPAL palette index for compiled fonts (default: 0); source fonts have one palette
+///   - `foreground`: string - Hex color for the text foreground color (default: "#000000")
+///   - `format`: "paths" | "svg" (default: "paths")
+///   - `fontId`: number - Handle of the cached source font to use without font bytes (default: the current font)
+///
+/// # Returns
+/// * `String` - JSON object '{"width": 600, "ascender": 800, "descender": -200, "layers": [{"path": "M...", "fill": {"type": "solid", "color": "#ff0000ff"}, "blend": "srcOver"}]}', or an SVG document
+#[wasm_bindgen]
+pub fn render_color_glyph(
+    font_bytes: Option<Vec<u8>>,
+    glyph_name: &str,
+    location_json: Option<String>,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    let options_json = options_json.as_deref().map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("render options", e))?
+    };
+    let palette = options.get("palette").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+    let foreground = options
+        .get("foreground")
+        .and_then(|v| v.as_str())
+        .map(|color| Rgba::parse(color).map_err(|_| ApiError::invalid_argument("foreground", color)))
+        .transpose()?
+        .unwrap_or(Rgba([0, 0, 0, 255]));
+    let format = options.get("format").and_then(|v| v.as_str()).unwrap_or("paths");
+    if format != "paths" && format != "svg" {
+        return Err(ApiError::invalid_argument("format", format).into());
+    }
+
+    let glyph = match font_bytes.filter(|bytes| !bytes.is_empty()) {
+        Some(font_bytes) => flatten_compiled_glyph(&font_bytes, glyph_name, location_json.as_deref(), palette, foreground),
+        None => {
+            let font_id = options.get("fontId").and_then(|v| v.as_u64()).map(|id| id as u32);
+            let mut fonts = crate::FONT_CACHE.lock().unwrap();
+            let font = fonts.font(font_id)?;
+            if palette > 0 {
+                return Err(ApiError::invalid_argument("palette", palette).into());
+            }
+            flatten_source_glyph(font, glyph_name, location_json.as_deref().unwrap_or(""), foreground)
+        }
+    }?;
+
+    if format == "svg" {
+        return Ok(glyph.to_svg());
+    }
+    serde_json::to_string(&glyph.to_json())
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
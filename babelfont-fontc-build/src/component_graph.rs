@@ -0,0 +1,198 @@
+// Component dependency graph module
+//
+// This module records which glyphs each glyph uses as components (over all
+// of its layers) and, in reverse, which composites use each glyph. The graph
+// is built once when a font is stored, so flattening and cache invalidation
+// do not have to scan the glyph set for component references on every call.
+
+use babelfont::Shape;
+use std::collections::{HashMap, HashSet};
+
+/// Forward and reverse component references of a font
+#[derive(Clone, Debug, Default)]
+pub struct ComponentGraph {
+    /// Glyph -> glyphs it uses as components (in any layer)
+    uses: HashMap<String, Vec<String>>,
+    /// Glyph -> composites that use it as a component
+    used_by: HashMap<String, Vec<String>>,
+    /// Glyphs that reference themselves, directly or through nested components
+    cyclic: HashSet<String>,
+}
+
+impl ComponentGraph {
+    /// Build the graph from all layers of a font
+    pub fn build(font: &babelfont::Font) -> Self {
+        let mut graph = ComponentGraph::default();
+        for glyph in font.glyphs.iter() {
+            let components = glyph_components(glyph);
+            if !components.is_empty() {
+                graph.uses.insert(glyph.name.to_string(), components);
+            }
+        }
+        graph.link();
+        graph
+    }
+
+    /// Update the references of a single glyph after it was edited
+    pub fn update_glyph(&mut self, glyph: &babelfont::Glyph) {
+        let components = glyph_components(glyph);
+        if components.is_empty() {
+            self.uses.remove(glyph.name.as_str());
+        } else {
+            self.uses.insert(glyph.name.to_string(), components);
+        }
+        self.link();
+    }
+
+    /// Whether a glyph uses components in any of its layers
+    pub fn is_composite(&self, glyph_name: &str) -> bool {
+        self.uses.contains_key(glyph_name)
+    }
+
+    /// Whether flattening a glyph would follow a component cycle
+    pub fn is_cyclic(&self, glyph_name: &str) -> bool {
+        self.cyclic.contains(glyph_name)
+    }
+
+    /// Names of a glyph and every composite that uses it, directly or nested
+    pub fn with_dependents(&self, glyph_name: &str) -> HashSet<String> {
+        let mut affected = HashSet::from([glyph_name.to_string()]);
+        let mut pending = vec![glyph_name];
+        while let Some(name) = pending.pop() {
+            for dependent in self.used_by.get(name).into_iter().flatten() {
+                if affected.insert(dependent.clone()) {
+                    pending.push(dependent);
+                }
+            }
+        }
+        affected
+    }
+
+    /// Composites that use a glyph, as (composite, depth, via)
+    ///
+    /// Direct users have depth 1 and are reached via the glyph itself; a
+    /// composite using one of them has depth 2 and is reached via it, and so
+    /// on. Each composite is listed once, at its shallowest depth, ordered by
+    /// depth and then name.
+    pub fn usages(&self, glyph_name: &str) -> Vec<(String, usize, String)> {
+        let mut usages = Vec::new();
+        let mut seen = HashSet::from([glyph_name]);
+        let mut level = vec![glyph_name];
+        let mut depth = 0;
+        while !level.is_empty() {
+            depth += 1;
+            let mut next: Vec<(&str, &str)> = Vec::new();
+            for &name in &level {
+                for dependent in self.used_by.get(name).into_iter().flatten() {
+                    if seen.insert(dependent.as_str()) {
+                        next.push((dependent.as_str(), name));
+                    }
+                }
+            }
+            next.sort();
+            usages.extend(next.iter().map(|&(dependent, via)| (dependent.to_string(), depth, via.to_string())));
+            level = next.into_iter().map(|(dependent, _)| dependent).collect();
+        }
+        usages
+    }
+
+    /// Component reference loops, each listed once starting at its first glyph by name
+    ///
+    /// A loop `["a", "b"]` means that a uses b and b uses a. Glyphs that use
+    /// themselves directly form single-glyph loops.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cyclic: Vec<&String> = self.cyclic.iter().collect();
+        cyclic.sort();
+
+        let mut seen: HashSet<Vec<String>> = HashSet::new();
+        let mut cycles = Vec::new();
+        for start in cyclic {
+            let Some(mut cycle) = self.shortest_loop(start) else { continue };
+
+            // Rotate to start at the smallest name so each loop is listed once
+            let first = (0..cycle.len()).min_by_key(|&index| &cycle[index]).unwrap_or(0);
+            cycle.rotate_left(first);
+            if seen.insert(cycle.clone()) {
+                cycles.push(cycle);
+            }
+        }
+        cycles
+    }
+
+    /// The shortest chain of component references leading from a glyph back to itself
+    fn shortest_loop(&self, start: &str) -> Option<Vec<String>> {
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut queue = std::collections::VecDeque::from([start]);
+        while let Some(name) = queue.pop_front() {
+            for component in self.uses.get(name).into_iter().flatten() {
+                if component == start {
+                    let mut chain = vec![name.to_string()];
+                    let mut current = name;
+                    while current != start {
+                        current = previous[current];
+                        chain.push(current.to_string());
+                    }
+                    chain.reverse();
+                    return Some(chain);
+                }
+                if !previous.contains_key(component.as_str()) {
+                    previous.insert(component.as_str(), name);
+                    queue.push_back(component);
+                }
+            }
+        }
+        None
+    }
+
+    /// Rebuild the reverse references and the set of cyclic glyphs
+    fn link(&mut self) {
+        self.used_by.clear();
+        for (glyph, components) in &self.uses {
+            for component in components {
+                self.used_by.entry(component.clone()).or_default().push(glyph.clone());
+            }
+        }
+        for dependents in self.used_by.values_mut() {
+            dependents.sort();
+        }
+
+        // A glyph is cyclic if it can reach itself through component references
+        self.cyclic = self
+            .uses
+            .keys()
+            .filter(|glyph| self.reaches(glyph, glyph))
+            .cloned()
+            .collect();
+    }
+
+    /// Whether `target` is reachable from the components of `start`
+    fn reaches(&self, start: &str, target: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending: Vec<&str> = self.uses.get(start).into_iter().flatten().map(String::as_str).collect();
+        while let Some(name) = pending.pop() {
+            if name == target {
+                return true;
+            }
+            if visited.insert(name) {
+                pending.extend(self.uses.get(name).into_iter().flatten().map(String::as_str));
+            }
+        }
+        false
+    }
+}
+
+/// Distinct component references of all layers of a glyph
+fn glyph_components(glyph: &babelfont::Glyph) -> Vec<String> {
+    let mut components: Vec<String> = Vec::new();
+    for layer in &glyph.layers {
+        for shape in &layer.shapes {
+            if let Shape::Component(component) = shape {
+                let reference = component.reference.to_string();
+                if !components.contains(&reference) {
+                    components.push(reference);
+                }
+            }
+        }
+    }
+    components
+}
@@ -0,0 +1,545 @@
+// Composite glyph construction module
+//
+// This module builds accented glyphs from components: a character's
+// canonical Unicode decomposition names its base and marks ("Aacute" is
+// "A" + U+0301), and the marks are positioned in every master by matching
+// their `_name` anchors with the anchors of the base or of earlier marks,
+// as mark attachment does at shaping time. Composites can also be
+// auto-aligned: their components are then re-positioned from the anchors
+// and metrics of their base whenever a component glyph changes. Before a
+// base is deleted or renamed, the composites using it can be listed.
+
+use babelfont::{Anchor, Component, Layer, Shape};
+use kurbo::{Affine, Point, Vec2};
+use std::collections::{HashMap, HashSet};
+
+use crate::charsets::{glyph_name_codepoint, suggested_glyph_name};
+use crate::errors::ApiError;
+use crate::spacing::base_component;
+use crate::standard_glyphs::new_glyph;
+
+/// Format-specific key marking a glyph as auto-aligned
+pub const AUTO_ALIGNMENT_KEY: &str = "space.counterpunch.autoAlignment";
+
+/// A composite glyph to build
+#[derive(Clone, Debug, PartialEq)]
+struct CompositeRecipe {
+    /// Name of the composite glyph
+    name: String,
+    /// Codepoint to assign if the glyph is created
+    codepoint: Option<u32>,
+    /// Base glyph name
+    base: String,
+    /// Mark glyph names, in attachment order
+    marks: Vec<String>,
+}
+
+/// Canonical decomposition of a character into a base and combining marks
+fn decompose(c: char) -> Vec<char> {
+    let mut parts = Vec::new();
+    unicode_normalization::char::decompose_canonical(c, |part| parts.push(part));
+    parts
+}
+
+/// Glyph of the font that stands for a codepoint: encoded with it, else by customary name
+fn glyph_for_codepoint(font: &babelfont::Font, encoded: &HashMap<u32, String>, codepoint: u32) -> Option<String> {
+    encoded.get(&codepoint).cloned().or_else(|| {
+        let name = suggested_glyph_name(codepoint);
+        font.glyphs.get(&name).map(|_| name)
+    })
+}
+
+/// Whether any non-background layer of a glyph has an anchor
+fn has_anchor(font: &babelfont::Font, glyph_name: &str, anchor: &str) -> bool {
+    font.glyphs.get(glyph_name).is_some_and(|glyph| {
+        glyph
+            .layers
+            .iter()
+            .filter(|layer| !layer.is_background)
+            .any(|layer| layer.anchors.iter().any(|candidate| candidate.name.as_str() == anchor))
+    })
+}
+
+/// Work out base and marks of a composite from its name or codepoint
+///
+/// Suffixed names ("Aacute.sc") use suffixed components where the font has
+/// them. Marks over uppercase bases prefer ".case" variants, and "i" and
+/// "j" under top marks are replaced by their dotless forms.
+fn recipe(
+    font: &babelfont::Font,
+    encoded: &HashMap<u32, String>,
+    name: &str,
+    codepoint: Option<u32>,
+) -> Result<CompositeRecipe, String> {
+    let (stem, suffix) = match name.find('.') {
+        Some(index) if index > 0 => name.split_at(index),
+        _ => (name, ""),
+    };
+    let character = codepoint
+        .or_else(|| font.glyphs.get(stem).and_then(|glyph| glyph.codepoints.first().copied()))
+        .or_else(|| glyph_name_codepoint(stem))
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("Cannot tell which character '{}' stands for", name))?;
+
+    let parts = decompose(character);
+    let (&base_char, mark_chars) = parts
+        .split_first()
+        .filter(|(_, marks)| !marks.is_empty())
+        .ok_or_else(|| format!("U+{:04X} has no canonical decomposition into base and marks", character as u32))?;
+
+    let with_suffix = |glyph: String| -> String {
+        let suffixed = format!("{}{}", glyph, suffix);
+        if !suffix.is_empty() && font.glyphs.get(&suffixed).is_some() {
+            suffixed
+        } else {
+            glyph
+        }
+    };
+    let lookup = |codepoint: u32| -> Result<String, String> {
+        glyph_for_codepoint(font, encoded, codepoint).ok_or_else(|| {
+            format!(
+                "'{}' needs '{}' (U+{:04X}), which is not in the font",
+                name,
+                suggested_glyph_name(codepoint),
+                codepoint
+            )
+        })
+    };
+
+    let mut marks = Vec::new();
+    for &mark in mark_chars {
+        let mark = with_suffix(lookup(mark as u32)?);
+        let case_variant = format!("{}.case", mark);
+        if base_char.is_uppercase() && font.glyphs.get(&case_variant).is_some() {
+            marks.push(case_variant);
+        } else {
+            marks.push(mark);
+        }
+    }
+
+    let mut base = with_suffix(lookup(base_char as u32)?);
+    let dotless = match base_char {
+        'i' => Some(0x0131),
+        'j' => Some(0x0237),
+        _ => None,
+    };
+    if let Some(dotless) = dotless.and_then(|codepoint| glyph_for_codepoint(font, encoded, codepoint)) {
+        if marks.first().is_some_and(|mark| has_anchor(font, mark, "_top")) {
+            base = with_suffix(dotless);
+        }
+    }
+
+    Ok(CompositeRecipe {
+        name: name.to_string(),
+        codepoint: suffix.is_empty().then_some(character as u32),
+        base,
+        marks,
+    })
+}
+
+/// Non-background layer of a glyph for a master
+fn master_layer<'a>(font: &'a babelfont::Font, glyph_name: &str, master_id: &str) -> Option<&'a Layer> {
+    font.glyphs
+        .get(glyph_name)?
+        .layers
+        .iter()
+        .find(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id))
+}
+
+/// Build the layer of a composite for one master
+///
+/// The base's layer provides the advance width and the starting anchors.
+/// Each mark attaches with its first `_name` anchor that matches an anchor
+/// placed so far; its own anchors then replace those of the same name, so
+/// following marks stack on top of it.
+fn composite_layer(font: &babelfont::Font, recipe: &CompositeRecipe, master_id: &str) -> Result<Layer, String> {
+    let missing = |glyph: &str| format!("'{}' has no layer for master '{}'", glyph, master_id);
+    let base_layer = master_layer(font, &recipe.base, master_id).ok_or_else(|| missing(&recipe.base))?;
+
+    let mut anchors: Vec<Anchor> = base_layer.anchors.clone();
+    let mut shapes = vec![Shape::Component(Component {
+        reference: recipe.base.as_str().into(),
+        transform: Affine::IDENTITY,
+        format_specific: Default::default(),
+    })];
+
+    for mark in &recipe.marks {
+        let mark_layer = master_layer(font, mark, master_id).ok_or_else(|| missing(mark))?;
+        let offset = mark_layer
+            .anchors
+            .iter()
+            .find_map(|anchor| {
+                let name = anchor.name.strip_prefix('_')?;
+                let target = anchors.iter().find(|candidate| candidate.name.as_str() == name)?;
+                Some(Vec2::new(target.x - anchor.x, target.y - anchor.y))
+            })
+            .ok_or_else(|| {
+                format!(
+                    "No anchor of '{}' matches an anchor of '{}' in master '{}'",
+                    mark, recipe.base, master_id
+                )
+            })?;
+
+        for anchor in mark_layer.anchors.iter().filter(|anchor| !anchor.name.starts_with('_')) {
+            let mut moved = anchor.clone();
+            moved.x += offset.x;
+            moved.y += offset.y;
+            match anchors.iter_mut().find(|existing| existing.name == anchor.name) {
+                Some(existing) => *existing = moved,
+                None => anchors.push(moved),
+            }
+        }
+        shapes.push(Shape::Component(Component {
+            reference: mark.as_str().into(),
+            transform: Affine::translate(offset),
+            format_specific: Default::default(),
+        }));
+    }
+
+    let mut layer = base_layer.clone();
+    layer.shapes = shapes;
+    layer.anchors = anchors;
+    layer.guides = Vec::new();
+    layer.format_specific = Default::default();
+    Ok(layer)
+}
+
+/// Build composite glyphs from Unicode decompositions and anchors
+///
+/// Each requested glyph gets, in every master, a component of its base and
+/// one of each mark positioned by anchors. Missing glyphs are created with
+/// the character's codepoint; existing glyphs that already have outlines or
+/// components are left alone unless `replace` is set. A glyph is skipped as
+/// a whole if any master cannot be built.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `requests_json` - JSON array of glyph names and/or codepoints, e.g., '["Aacute", 7865, "aacute.sc"]'
+/// * `replace` - Rebuild glyphs that already have shapes
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"built": [{"glyph": "Aacute", "base": "A", "marks": ["acutecomb.case"], "created": true}], "skipped": [{"glyph": "Lslash", "reason": "..."}]}' and the changed glyph names
+pub fn build_composites(
+    font: &mut babelfont::Font,
+    requests_json: &str,
+    replace: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let requests: Vec<serde_json::Value> =
+        serde_json::from_str(requests_json).map_err(|e| ApiError::invalid_json("glyph list", e))?;
+
+    let mut encoded: HashMap<u32, String> = HashMap::new();
+    for glyph in font.glyphs.iter() {
+        for &codepoint in &glyph.codepoints {
+            encoded.entry(codepoint).or_insert_with(|| glyph.name.to_string());
+        }
+    }
+    let masters: Vec<String> = font.masters.iter().map(|master| master.id.clone()).collect();
+
+    let mut built = Vec::new();
+    let mut skipped = Vec::new();
+    let mut changed = HashSet::new();
+
+    for request in &requests {
+        let (name, codepoint) = match request {
+            serde_json::Value::String(name) => (name.clone(), None),
+            serde_json::Value::Number(number) => {
+                let codepoint = number
+                    .as_u64()
+                    .and_then(|value| u32::try_from(value).ok())
+                    .filter(|&value| char::from_u32(value).is_some())
+                    .ok_or_else(|| ApiError::invalid_argument("codepoint", number))?;
+                let name = encoded.get(&codepoint).cloned().unwrap_or_else(|| suggested_glyph_name(codepoint));
+                (name, Some(codepoint))
+            }
+            other => return Err(ApiError::invalid_argument("glyph name or codepoint", other)),
+        };
+
+        let existing = font.glyphs.get(&name);
+        let has_shapes = existing.is_some_and(|glyph| {
+            glyph.layers.iter().any(|layer| !layer.is_background && !layer.shapes.is_empty())
+        });
+        if has_shapes && !replace {
+            skipped.push(serde_json::json!({ "glyph": name, "reason": "Glyph already has outlines or components" }));
+            continue;
+        }
+
+        let recipe = match recipe(font, &encoded, &name, codepoint) {
+            Ok(recipe) => recipe,
+            Err(reason) => {
+                skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+                continue;
+            }
+        };
+        if recipe.base == recipe.name || recipe.marks.contains(&recipe.name) {
+            skipped.push(serde_json::json!({ "glyph": name, "reason": "Glyph would use itself as a component" }));
+            continue;
+        }
+        let layers: Result<Vec<Layer>, String> = masters
+            .iter()
+            .map(|master_id| composite_layer(font, &recipe, master_id))
+            .collect();
+        let layers = match layers {
+            Ok(layers) => layers,
+            Err(reason) => {
+                skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+                continue;
+            }
+        };
+
+        let created = existing.is_none();
+        if created {
+            let codepoints = recipe.codepoint.filter(|codepoint| !encoded.contains_key(codepoint));
+            let mut glyph = new_glyph(&recipe.name, codepoints.into_iter().collect())?;
+            glyph.layers = layers;
+            font.glyphs.push(glyph);
+            if let Some(codepoint) = recipe.codepoint {
+                encoded.entry(codepoint).or_insert_with(|| recipe.name.clone());
+            }
+        } else if let Some(glyph) = font.glyphs.get_mut(&name) {
+            // Replace the master layers, keeping backgrounds and intermediate layers
+            for layer in layers {
+                match glyph.layers.iter_mut().find(|existing| !existing.is_background && existing.id == layer.id) {
+                    Some(existing) => {
+                        existing.shapes = layer.shapes;
+                        existing.anchors = layer.anchors;
+                        existing.width = layer.width;
+                    }
+                    None => glyph.layers.push(layer),
+                }
+            }
+        }
+
+        built.push(serde_json::json!({
+            "glyph": recipe.name,
+            "base": recipe.base,
+            "marks": recipe.marks,
+            "created": created,
+        }));
+        changed.insert(recipe.name);
+    }
+
+    let result = serde_json::json!({
+        "built": built,
+        "skipped": skipped,
+    });
+    Ok((result, changed))
+}
+
+/// Whether a glyph is marked as auto-aligned
+pub fn is_auto_aligned(glyph: &babelfont::Glyph) -> bool {
+    glyph
+        .format_specific
+        .get(AUTO_ALIGNMENT_KEY)
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+/// Align the components of a composite layer to its base
+///
+/// The first component is the base: the layer takes its advance width and
+/// its anchors (through the component's transform). Every further
+/// component keeps its scale but moves so its first `_name` anchor sits on
+/// the matching anchor placed so far, whose anchors it then replaces, as in
+/// composite_layer(). Components without a matching anchor stay in place.
+/// Returns None if the base has no layer with the same ID.
+fn aligned_layer(font: &babelfont::Font, layer: &Layer) -> Option<Layer> {
+    let layer_id = layer.id.as_deref()?;
+    let base = base_component(layer)?;
+    let base_layer = master_layer(font, &base, layer_id)?;
+    let Some(Shape::Component(base_shape)) = layer.shapes.first() else { return None };
+
+    let placed = |anchor: &Anchor, transform: Affine| {
+        let point = transform * Point::new(anchor.x, anchor.y);
+        let mut moved = anchor.clone();
+        moved.x = point.x;
+        moved.y = point.y;
+        moved
+    };
+    let mut anchors: Vec<Anchor> = base_layer
+        .anchors
+        .iter()
+        .filter(|anchor| !anchor.name.starts_with('_'))
+        .map(|anchor| placed(anchor, base_shape.transform))
+        .collect();
+
+    let mut aligned = layer.clone();
+    aligned.width = base_layer.width;
+    for shape in aligned.shapes.iter_mut().skip(1) {
+        let Shape::Component(component) = shape else { continue };
+        let Some(mark_layer) = master_layer(font, &component.reference, layer_id) else { continue };
+        let [a, b, c, d, _, _] = component.transform.as_coeffs();
+        let linear = Affine::new([a, b, c, d, 0.0, 0.0]);
+        let offset = mark_layer.anchors.iter().find_map(|anchor| {
+            let name = anchor.name.strip_prefix('_')?;
+            let target = anchors.iter().find(|candidate| candidate.name.as_str() == name)?;
+            Some(Point::new(target.x, target.y) - linear * Point::new(anchor.x, anchor.y))
+        });
+        let Some(offset) = offset else { continue };
+        component.transform = Affine::translate(offset) * linear;
+
+        for anchor in mark_layer.anchors.iter().filter(|anchor| !anchor.name.starts_with('_')) {
+            let moved = placed(anchor, component.transform);
+            match anchors.iter_mut().find(|existing| existing.name == anchor.name) {
+                Some(existing) => *existing = moved,
+                None => anchors.push(moved),
+            }
+        }
+    }
+    aligned.anchors = anchors;
+    Some(aligned)
+}
+
+/// Re-align the layers of an auto-aligned glyph, returning how many changed
+fn align_glyph(font: &mut babelfont::Font, glyph_name: &str) -> usize {
+    let Some(glyph) = font.glyphs.get(glyph_name) else { return 0 };
+    let updates: Vec<(usize, Layer)> = glyph
+        .layers
+        .iter()
+        .enumerate()
+        .filter(|(_, layer)| !layer.is_background)
+        .filter_map(|(index, layer)| aligned_layer(font, layer).map(|aligned| (index, aligned)))
+        .collect();
+    let count = updates.len();
+    if let Some(glyph) = font.glyphs.get_mut(glyph_name) {
+        for (index, layer) in updates {
+            glyph.layers[index] = layer;
+        }
+    }
+    count
+}
+
+/// Turn automatic alignment of a composite on or off
+///
+/// An auto-aligned glyph must be made of components only, the first being
+/// its base. Turning alignment on aligns the glyph right away; from then on
+/// realign_dependents() keeps it aligned when its components change. The
+/// setting is kept in the glyph's format-specific data.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Name of the composite glyph
+/// * `enabled` - Whether the glyph is auto-aligned
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"glyph": "Aacute", "autoAligned": true, "layers": 2}' and the changed glyph names
+pub fn set_auto_alignment(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    enabled: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let glyph = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let mut changed = HashSet::new();
+    let mut layer_count = 0;
+    if enabled {
+        let composite = glyph.layers.iter().filter(|layer| !layer.is_background).all(|layer| {
+            !layer.shapes.is_empty() && layer.shapes.iter().all(|shape| matches!(shape, Shape::Component(_)))
+        });
+        if !composite {
+            return Err(format!("'{}' can only be auto-aligned if all its layers are made of components", glyph_name).into());
+        }
+        glyph.format_specific.insert(AUTO_ALIGNMENT_KEY.to_string(), serde_json::Value::Bool(true));
+        layer_count = align_glyph(font, glyph_name);
+        if layer_count > 0 {
+            changed.insert(glyph_name.to_string());
+        }
+    } else {
+        glyph.format_specific.remove(AUTO_ALIGNMENT_KEY);
+    }
+
+    let result = serde_json::json!({
+        "glyph": glyph_name,
+        "autoAligned": enabled,
+        "layers": layer_count,
+    });
+    Ok((result, changed))
+}
+
+/// Re-align auto-aligned composites that use changed glyphs
+///
+/// Composites referencing a changed glyph, directly or through nested
+/// components, are aligned with their components before them, so nested
+/// auto-aligned composites see their updated bases.
+///
+/// # Returns
+/// * `HashSet<String>` - Names of the re-aligned glyphs
+pub fn realign_dependents(font: &mut babelfont::Font, changed: &HashSet<String>) -> HashSet<String> {
+    let mut pending: HashSet<String> = changed
+        .iter()
+        .flat_map(|name| crate::glyph_outlines::with_component_dependents(font, name))
+        .filter(|name| font.glyphs.get(name).is_some_and(is_auto_aligned))
+        .collect();
+
+    let mut realigned = HashSet::new();
+    while !pending.is_empty() {
+        let uses_pending = |name: &String| {
+            font.glyphs.get(name).is_some_and(|glyph| {
+                glyph.layers.iter().flat_map(|layer| layer.shapes.iter()).any(|shape| match shape {
+                    Shape::Component(component) => {
+                        let reference = component.reference.to_string();
+                        reference != *name && pending.contains(&reference)
+                    }
+                    Shape::Path(_) => false,
+                })
+            })
+        };
+        // Fall back to any glyph if components refer to each other in a cycle
+        let mut ready: Vec<String> = pending.iter().filter(|name| !uses_pending(name)).cloned().collect();
+        if ready.is_empty() {
+            ready.extend(pending.iter().next().cloned());
+        }
+        for name in ready {
+            pending.remove(&name);
+            if align_glyph(font, &name) > 0 {
+                realigned.insert(name);
+            }
+        }
+    }
+    realigned
+}
+
+/// Composites that use a glyph as a component, directly or nested
+///
+/// Direct users list the layers referencing the glyph, which a rename or
+/// deletion has to touch; nested users name the composite they reach the
+/// glyph through.
+///
+/// # Returns
+/// * `serde_json::Value` - '{"glyph": "acutecomb", "direct": [{"glyph": "aacute", "layers": ["m01", "m02"]}], "nested": [{"glyph": "aacute.sc", "depth": 2, "via": "aacute"}], "all": ["aacute", "aacute.sc"]}'
+pub fn component_usages(font: &babelfont::Font, glyph_name: &str) -> Result<serde_json::Value, ApiError> {
+    if font.glyphs.get(glyph_name).is_none() {
+        return Err(ApiError::glyph_not_found(glyph_name));
+    }
+    let usages = crate::glyph_outlines::component_graph(font).usages(glyph_name);
+
+    let mut direct = Vec::new();
+    let mut nested = Vec::new();
+    for (user, depth, via) in &usages {
+        if *depth > 1 {
+            nested.push(serde_json::json!({ "glyph": user, "depth": depth, "via": via }));
+            continue;
+        }
+        let layers: Vec<String> = font
+            .glyphs
+            .get(user)
+            .into_iter()
+            .flat_map(|glyph| glyph.layers.iter().enumerate())
+            .filter(|(_, layer)| {
+                layer.shapes.iter().any(|shape| {
+                    matches!(shape, Shape::Component(component) if component.reference.as_str() == glyph_name)
+                })
+            })
+            .map(|(index, layer)| layer.id.clone().unwrap_or_else(|| format!("#{}", index)))
+            .collect();
+        direct.push(serde_json::json!({ "glyph": user, "layers": layers }));
+    }
+    let all: Vec<&String> = usages.iter().map(|(user, _, _)| user).collect();
+    Ok(serde_json::json!({
+        "glyph": glyph_name,
+        "direct": direct,
+        "nested": nested,
+        "all": all,
+    }))
+}
@@ -0,0 +1,216 @@
+// Error module
+//
+// Errors raised by the exported functions, so the UI can branch on the kind
+// of error and localize its message instead of matching on English strings.
+// On the JS side an error is thrown as an `Error` whose `message` is the
+// English message, with two extra properties:
+//
+//   code    - Stable identifier of the kind of error, e.g. "GLYPH_NOT_FOUND"
+//   context - Object with the values the message was built from, e.g.
+//             { "glyph": "A.ss01" }
+//
+// Failed glyph, master, layer and class lookups are reported with their own
+// codes by every module, and arguments the caller can fix (unparsable JSON,
+// unknown option values, out-of-range numbers) as INVALID_JSON or
+// INVALID_ARGUMENT. OPERATION_FAILED is left for internal failures that the
+// modules report as plain strings.
+
+use std::fmt;
+
+use serde_json::{json, Value as JsonValue};
+use wasm_bindgen::JsValue;
+
+/// An error of an exported function
+#[derive(Debug, Clone)]
+pub enum ApiError {
+    /// No font is open
+    NoFont,
+    /// A font handle was closed or never stored
+    UnknownFont { id: u32 },
+    /// No snapshot was taken of a font with create_snapshot()
+    NoSnapshot { id: u32 },
+    /// A handle of another kind (interpolation context, ...) is unknown
+    UnknownHandle { kind: &'static str, handle: u32 },
+    GlyphNotFound { glyph: String },
+    MasterNotFound { master: String },
+    /// A glyph has no layer with an id
+    LayerNotFound { glyph: String, layer: String },
+    /// A glyph already has a layer where one would be added
+    LayerExists { glyph: String },
+    FeatureNotFound { feature: String },
+    /// The feature code has no glyph class with a name
+    ClassNotFound { class: String },
+    /// A glyph class with a name already exists
+    ClassExists { class: String },
+    /// A glyph class is still referred to in feature code
+    ClassInUse { class: String, usages: usize },
+    /// A JSON argument could not be parsed
+    InvalidJson { argument: String, detail: String },
+    /// A location could not be parsed or is incomplete
+    InvalidLocation { detail: String },
+    /// An argument has a value outside of its allowed set
+    InvalidArgument { argument: String, value: String },
+    /// Font bytes could not be parsed
+    InvalidFont { detail: String },
+    /// A source file could not be loaded
+    InvalidSource { format: String, detail: String },
+    /// A table the function reads is absent or damaged
+    MissingTable { table: String, detail: String },
+    /// The file format is not supported in the browser
+    Unsupported { format: String, detail: String },
+    InterpolationFailed { glyph: String, detail: String },
+    CompilationFailed { stage: String, detail: String },
+    SerializationFailed { what: String, detail: String },
+    /// Any other failure, with the module's message
+    OperationFailed { message: String },
+}
+
+impl ApiError {
+    pub fn glyph_not_found(glyph: impl Into<String>) -> Self {
+        ApiError::GlyphNotFound { glyph: glyph.into() }
+    }
+
+    pub fn master_not_found(master: impl Into<String>) -> Self {
+        ApiError::MasterNotFound { master: master.into() }
+    }
+
+    pub fn layer_not_found(glyph: impl Into<String>, layer: impl Into<String>) -> Self {
+        ApiError::LayerNotFound { glyph: glyph.into(), layer: layer.into() }
+    }
+
+    pub fn invalid_json(argument: &str, detail: impl fmt::Display) -> Self {
+        ApiError::InvalidJson { argument: argument.to_string(), detail: detail.to_string() }
+    }
+
+    pub fn invalid_location(detail: impl fmt::Display) -> Self {
+        ApiError::InvalidLocation { detail: detail.to_string() }
+    }
+
+    pub fn invalid_argument(argument: &str, value: impl fmt::Display) -> Self {
+        ApiError::InvalidArgument { argument: argument.to_string(), value: value.to_string() }
+    }
+
+    pub fn invalid_font(detail: impl fmt::Debug) -> Self {
+        ApiError::InvalidFont { detail: format!("{:?}", detail) }
+    }
+
+    pub fn missing_table(table: &str, detail: impl fmt::Debug) -> Self {
+        ApiError::MissingTable { table: table.to_string(), detail: format!("{:?}", detail) }
+    }
+
+    pub fn interpolation(glyph: &str, detail: impl fmt::Display) -> Self {
+        ApiError::InterpolationFailed { glyph: glyph.to_string(), detail: detail.to_string() }
+    }
+
+    pub fn compilation(stage: &str, detail: impl fmt::Display) -> Self {
+        ApiError::CompilationFailed { stage: stage.to_string(), detail: detail.to_string() }
+    }
+
+    pub fn serialization(what: &str, detail: impl fmt::Display) -> Self {
+        ApiError::SerializationFailed { what: what.to_string(), detail: detail.to_string() }
+    }
+
+    /// Stable identifier of the kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::NoFont => "NO_FONT",
+            ApiError::UnknownFont { .. } => "UNKNOWN_FONT",
+            ApiError::NoSnapshot { .. } => "NO_SNAPSHOT",
+            ApiError::UnknownHandle { .. } => "UNKNOWN_HANDLE",
+            ApiError::GlyphNotFound { .. } => "GLYPH_NOT_FOUND",
+            ApiError::MasterNotFound { .. } => "MASTER_NOT_FOUND",
+            ApiError::LayerNotFound { .. } => "LAYER_NOT_FOUND",
+            ApiError::LayerExists { .. } => "LAYER_EXISTS",
+            ApiError::FeatureNotFound { .. } => "FEATURE_NOT_FOUND",
+            ApiError::ClassNotFound { .. } => "CLASS_NOT_FOUND",
+            ApiError::ClassExists { .. } => "CLASS_EXISTS",
+            ApiError::ClassInUse { .. } => "CLASS_IN_USE",
+            ApiError::InvalidJson { .. } => "INVALID_JSON",
+            ApiError::InvalidLocation { .. } => "INVALID_LOCATION",
+            ApiError::InvalidArgument { .. } => "INVALID_ARGUMENT",
+            ApiError::InvalidFont { .. } => "INVALID_FONT",
+            ApiError::InvalidSource { .. } => "INVALID_SOURCE",
+            ApiError::MissingTable { .. } => "MISSING_TABLE",
+            ApiError::Unsupported { .. } => "UNSUPPORTED",
+            ApiError::InterpolationFailed { .. } => "INTERPOLATION_FAILED",
+            ApiError::CompilationFailed { .. } => "COMPILATION_FAILED",
+            ApiError::SerializationFailed { .. } => "SERIALIZATION_FAILED",
+            ApiError::OperationFailed { .. } => "OPERATION_FAILED",
+        }
+    }
+
+    /// The values the message is built from, for localized messages
+    pub fn context(&self) -> JsonValue {
+        match self {
+            ApiError::NoFont => json!({}),
+            ApiError::UnknownFont { id } | ApiError::NoSnapshot { id } => json!({ "fontId": id }),
+            ApiError::UnknownHandle { kind, handle } => json!({ "kind": kind, "handle": handle }),
+            ApiError::GlyphNotFound { glyph } => json!({ "glyph": glyph }),
+            ApiError::MasterNotFound { master } => json!({ "master": master }),
+            ApiError::LayerNotFound { glyph, layer } => json!({ "glyph": glyph, "layer": layer }),
+            ApiError::LayerExists { glyph } => json!({ "glyph": glyph }),
+            ApiError::FeatureNotFound { feature } => json!({ "feature": feature }),
+            ApiError::ClassNotFound { class } | ApiError::ClassExists { class } => json!({ "class": class }),
+            ApiError::ClassInUse { class, usages } => json!({ "class": class, "usages": usages }),
+            ApiError::InvalidJson { argument, detail } => json!({ "argument": argument, "detail": detail }),
+            ApiError::InvalidLocation { detail } => json!({ "detail": detail }),
+            ApiError::InvalidArgument { argument, value } => json!({ "argument": argument, "value": value }),
+            ApiError::InvalidFont { detail } => json!({ "detail": detail }),
+            ApiError::InvalidSource { format, detail } => json!({ "format": format, "detail": detail }),
+            ApiError::MissingTable { table, detail } => json!({ "table": table, "detail": detail }),
+            ApiError::Unsupported { format, detail } => json!({ "format": format, "detail": detail }),
+            ApiError::InterpolationFailed { glyph, detail } => json!({ "glyph": glyph, "detail": detail }),
+            ApiError::CompilationFailed { stage, detail } => json!({ "stage": stage, "detail": detail }),
+            ApiError::SerializationFailed { what, detail } => json!({ "what": what, "detail": detail }),
+            ApiError::OperationFailed { .. } => json!({}),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::NoFont => write!(f, "No font cached. Call store_font() first."),
+            ApiError::UnknownFont { id } => write!(f, "Unknown font {}; it was closed or never stored", id),
+            ApiError::NoSnapshot { id } => write!(f, "No snapshot of font {}. Call create_snapshot() first.", id),
+            ApiError::UnknownHandle { kind, handle } => write!(f, "Unknown {} {}", kind, handle),
+            ApiError::GlyphNotFound { glyph } => write!(f, "Glyph '{}' not found", glyph),
+            ApiError::MasterNotFound { master } => write!(f, "Master '{}' not found", master),
+            ApiError::LayerNotFound { glyph, layer } => write!(f, "Layer '{}' not found in glyph '{}'", layer, glyph),
+            ApiError::LayerExists { glyph } => write!(f, "'{}' already has a layer at this location", glyph),
+            ApiError::FeatureNotFound { feature } => write!(f, "Feature '{}' not found in source", feature),
+            ApiError::ClassNotFound { class } => write!(f, "Class '{}' not found", class),
+            ApiError::ClassExists { class } => write!(f, "Class '{}' already exists", class),
+            ApiError::ClassInUse { class, usages } => {
+                write!(f, "Class '{}' is used {} time(s) in feature code", class, usages)
+            }
+            ApiError::InvalidJson { argument, detail } => write!(f, "Failed to parse {}: {}", argument, detail),
+            ApiError::InvalidLocation { detail } => write!(f, "Location parse error: {}", detail),
+            ApiError::InvalidArgument { argument, value } => write!(f, "Invalid {}: '{}'", argument, value),
+            ApiError::InvalidFont { detail } => write!(f, "Failed to parse font: {}", detail),
+            ApiError::InvalidSource { format, detail } => write!(f, "Failed to load .{} file: {}", format, detail),
+            ApiError::MissingTable { table, detail } => write!(f, "Failed to read {} table: {}", table, detail),
+            ApiError::Unsupported { detail, .. } => write!(f, "{}", detail),
+            ApiError::InterpolationFailed { glyph, detail } => write!(f, "Interpolation failed for '{}': {}", glyph, detail),
+            ApiError::CompilationFailed { stage, detail } => write!(f, "{} failed: {}", stage, detail),
+            ApiError::SerializationFailed { what, detail } => write!(f, "Failed to serialize {}: {}", what, detail),
+            ApiError::OperationFailed { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        ApiError::OperationFailed { message }
+    }
+}
+
+impl From<ApiError> for JsValue {
+    fn from(error: ApiError) -> JsValue {
+        let js_error = js_sys::Error::new(&error.to_string());
+        let context = js_sys::JSON::parse(&error.context().to_string()).unwrap_or(JsValue::NULL);
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from_str(error.code()));
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("context"), &context);
+        js_error.into()
+    }
+}
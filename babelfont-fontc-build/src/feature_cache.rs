@@ -0,0 +1,310 @@
+// Feature compilation cache module
+//
+// This module keeps the layout tables (GSUB, GPOS, GDEF) of the last full
+// compile of the cached font together with a hash of everything they are
+// built from: feature code, kerning and kerning groups, axes and masters,
+// the glyph order and categories, all anchors, and the component
+// references and transforms through which composites inherit anchors. When
+// the inputs are unchanged, recompiles skip feature and kerning compilation
+// and splice the cached tables into the new binary, which speeds up
+// outline-only edits. The OS/2 usMaxContext of such a binary is computed
+// without layout tables, so it is recomputed from the cached ones. Each open
+// font has its own entry.
+
+
+use read_fonts::tables::gpos::{Gpos, PositionSubtables};
+use read_fonts::tables::gsub::{Gsub, LigatureSubstFormat1, SubstitutionSubtables};
+use read_fonts::tables::layout::{ChainedSequenceContext, SequenceContext};
+use read_fonts::{FontData, FontRead, FontRef, ReadError};
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use std::sync::Mutex;
+use write_fonts::types::Tag;
+use write_fonts::FontBuilder;
+
+/// Tables produced by feature and kerning compilation
+const LAYOUT_TABLES: [Tag; 3] = [Tag::new(b"GDEF"), Tag::new(b"GPOS"), Tag::new(b"GSUB")];
+
+/// Offset of usMaxContext in OS/2 tables of version 2 and later
+const US_MAX_CONTEXT_OFFSET: usize = 94;
+
+// Layout tables of the last full compile of each font handle, with the hash
+// of their inputs
+static FEATURE_CACHE: Mutex<BTreeMap<u32, FeatureCache>> = Mutex::new(BTreeMap::new());
+
+struct FeatureCache {
+    hash: u64,
+    tables: Vec<(Tag, Vec<u8>)>,
+}
+
+/// Clear the cached layout tables of all fonts
+pub fn clear_feature_cache() {
+    FEATURE_CACHE.lock().unwrap().clear();
+}
+
+/// Clear the cached layout tables of a closed font
+pub fn remove_feature_cache(font_id: u32) {
+    FEATURE_CACHE.lock().unwrap().remove(&font_id);
+}
+
+/// Feeds serialized JSON into a hasher without building a string
+pub struct HashWriter<'a, H: Hasher>(pub &'a mut H);
+
+impl<H: Hasher> std::io::Write for HashWriter<'_, H> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.0.write(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Hash the inputs of feature and kerning compilation
+///
+/// Returns None if the layout tables cannot be reused independently of the
+/// rest of the font: feature code with `featureNames` or `cvParameters`
+/// blocks adds name table entries that a compile without features lacks.
+pub fn layout_inputs_hash(font: &babelfont::Font) -> Option<u64> {
+    let uses_name_table = font.features.features.iter().any(|(_, code)| {
+        let code = code.to_string();
+        code.contains("featureNames") || code.contains("cvParameters")
+    });
+    if uses_name_table {
+        return None;
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    macro_rules! hash_json {
+        ($value:expr) => {
+            serde_json::to_writer(HashWriter(&mut hasher), $value).ok()?
+        };
+    }
+
+    // Feature code, kerning (stored on masters), groups and the design space
+    hash_json!(&font.features);
+    hash_json!(&font.first_kern_groups);
+    hash_json!(&font.second_kern_groups);
+    hash_json!(&font.axes);
+    hash_json!(&font.masters);
+
+    // Glyph order, GDEF classes, and the anchors and components of every
+    // layer: composites without anchors of their own inherit the anchors of
+    // their components, moved by the component transforms
+    for glyph in &font.glyphs {
+        hash_json!(&glyph.name);
+        hash_json!(&glyph.category);
+        hash_json!(&glyph.exported);
+        for layer in &glyph.layers {
+            hash_json!(&layer.id);
+            hash_json!(&layer.master);
+            hash_json!(&layer.location);
+            hash_json!(&layer.anchors);
+            for shape in &layer.shapes {
+                if let babelfont::Shape::Component(component) = shape {
+                    hash_json!(&component.reference);
+                    for coefficient in component.transform.as_coeffs() {
+                        hasher.write_u64(coefficient.to_bits());
+                    }
+                }
+            }
+        }
+    }
+
+    Some(hasher.finish())
+}
+
+/// Layout tables cached for a font and the given input hash
+pub fn cached_layout_tables(font_id: u32, hash: u64) -> Option<Vec<(Tag, Vec<u8>)>> {
+    let cache = FEATURE_CACHE.lock().unwrap();
+    cache
+        .get(&font_id)
+        .filter(|cache| cache.hash == hash)
+        .map(|cache| cache.tables.clone())
+}
+
+/// Remember the layout tables of a full compile of a font
+pub fn store_layout_tables(font_id: u32, hash: u64, font_bytes: &[u8]) -> Result<(), String> {
+    let font = FontRef::new(font_bytes).map_err(|e| format!("Failed to parse compiled font: {:?}", e))?;
+    let tables = LAYOUT_TABLES
+        .iter()
+        .filter_map(|tag| font.table_data(*tag).map(|data| (*tag, data.as_bytes().to_vec())))
+        .collect();
+
+    let mut cache = FEATURE_CACHE.lock().unwrap();
+    cache.insert(font_id, FeatureCache { hash, tables });
+    Ok(())
+}
+
+/// Longest context of the rules of a contextual subtable
+fn sequence_context_length(context: &SequenceContext) -> usize {
+    let mut longest = 0;
+    match context {
+        SequenceContext::Format1(table) => {
+            for rule_set in table.seq_rule_sets().iter().flatten().filter_map(|set| set.ok()) {
+                for rule in rule_set.seq_rules().iter().filter_map(|rule| rule.ok()) {
+                    longest = longest.max(rule.input_sequence().len() + 1);
+                }
+            }
+        }
+        SequenceContext::Format2(table) => {
+            for rule_set in table.class_seq_rule_sets().iter().flatten().filter_map(|set| set.ok()) {
+                for rule in rule_set.class_seq_rules().iter().filter_map(|rule| rule.ok()) {
+                    longest = longest.max(rule.input_sequence().len() + 1);
+                }
+            }
+        }
+        SequenceContext::Format3(table) => longest = table.coverages().len(),
+    }
+    longest
+}
+
+/// Longest input and lookahead of the rules of a chained contextual subtable
+fn chained_context_length(context: &ChainedSequenceContext) -> usize {
+    let mut longest = 0;
+    match context {
+        ChainedSequenceContext::Format1(table) => {
+            for rule_set in table.chained_seq_rule_sets().iter().flatten().filter_map(|set| set.ok()) {
+                for rule in rule_set.chained_seq_rules().iter().filter_map(|rule| rule.ok()) {
+                    longest = longest.max(rule.input_sequence().len() + 1 + rule.lookahead_sequence().len());
+                }
+            }
+        }
+        ChainedSequenceContext::Format2(table) => {
+            for rule_set in table.chained_class_seq_rule_sets().iter().flatten().filter_map(|set| set.ok()) {
+                for rule in rule_set.chained_class_seq_rules().iter().filter_map(|rule| rule.ok()) {
+                    longest = longest.max(rule.input_sequence().len() + 1 + rule.lookahead_sequence().len());
+                }
+            }
+        }
+        ChainedSequenceContext::Format3(table) => {
+            longest = table.input_coverages().len() + table.lookahead_coverages().len();
+        }
+    }
+    longest
+}
+
+/// Most components of a ligature of a ligature subtable
+fn ligature_length(table: &LigatureSubstFormat1) -> usize {
+    let mut longest = 0;
+    for ligature_set in table.ligature_sets().iter().filter_map(|set| set.ok()) {
+        for ligature in ligature_set.ligatures().iter().filter_map(|ligature| ligature.ok()) {
+            longest = longest.max(ligature.component_glyph_ids().len() + 1);
+        }
+    }
+    longest
+}
+
+/// Longest glyph context of the lookups of a GSUB table
+fn gsub_max_context(gsub: &Gsub) -> Result<usize, ReadError> {
+    let mut longest = 0;
+    for lookup in gsub.lookup_list()?.lookups().iter() {
+        let length = match lookup?.subtables()? {
+            SubstitutionSubtables::Single(_)
+            | SubstitutionSubtables::Multiple(_)
+            | SubstitutionSubtables::Alternate(_) => 1,
+            SubstitutionSubtables::Ligature(subtables) => {
+                subtables.iter().filter_map(|table| table.ok()).map(|table| ligature_length(&table)).max().unwrap_or(0)
+            }
+            SubstitutionSubtables::Contextual(subtables) => subtables
+                .iter()
+                .filter_map(|table| table.ok())
+                .map(|table| sequence_context_length(&table))
+                .max()
+                .unwrap_or(0),
+            SubstitutionSubtables::ChainContextual(subtables) => subtables
+                .iter()
+                .filter_map(|table| table.ok())
+                .map(|table| chained_context_length(&table))
+                .max()
+                .unwrap_or(0),
+            SubstitutionSubtables::Reverse(subtables) => subtables
+                .iter()
+                .filter_map(|table| table.ok())
+                .map(|table| 1 + table.lookahead_coverages().len())
+                .max()
+                .unwrap_or(0),
+        };
+        longest = longest.max(length);
+    }
+    Ok(longest)
+}
+
+/// Longest glyph context of the lookups of a GPOS table
+///
+/// Cursive and mark attachment do not count, as in fontTools.
+fn gpos_max_context(gpos: &Gpos) -> Result<usize, ReadError> {
+    let mut longest = 0;
+    for lookup in gpos.lookup_list()?.lookups().iter() {
+        let length = match lookup?.subtables()? {
+            PositionSubtables::Single(_) => 1,
+            PositionSubtables::Pair(_) => 2,
+            PositionSubtables::Cursive(_)
+            | PositionSubtables::MarkToBase(_)
+            | PositionSubtables::MarkToLig(_)
+            | PositionSubtables::MarkToMark(_) => 0,
+            PositionSubtables::Contextual(subtables) => subtables
+                .iter()
+                .filter_map(|table| table.ok())
+                .map(|table| sequence_context_length(&table))
+                .max()
+                .unwrap_or(0),
+            PositionSubtables::ChainContextual(subtables) => subtables
+                .iter()
+                .filter_map(|table| table.ok())
+                .map(|table| chained_context_length(&table))
+                .max()
+                .unwrap_or(0),
+        };
+        longest = longest.max(length);
+    }
+    Ok(longest)
+}
+
+/// OS/2 usMaxContext of layout tables, computed as fontc (and fontTools' maxContextCalc) does
+fn max_context(tables: &[(Tag, Vec<u8>)]) -> Result<u16, String> {
+    let mut longest = 0;
+    for (tag, data) in tables {
+        let length = if *tag == Tag::new(b"GSUB") {
+            Gsub::read(FontData::new(data)).and_then(|gsub| gsub_max_context(&gsub))
+        } else if *tag == Tag::new(b"GPOS") {
+            Gpos::read(FontData::new(data)).and_then(|gpos| gpos_max_context(&gpos))
+        } else {
+            continue;
+        }
+        .map_err(|e| format!("Failed to read cached {} table: {:?}", tag, e))?;
+        longest = longest.max(length);
+    }
+    Ok(longest.min(u16::MAX as usize) as u16)
+}
+
+/// Replace the layout tables of compiled font bytes with cached ones
+///
+/// Layout tables of the compiled font that are not in `tables` are dropped,
+/// and the OS/2 usMaxContext is set from the cached tables, so the result
+/// matches a full compile.
+pub fn replace_layout_tables(font_bytes: Vec<u8>, tables: &[(Tag, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    let font = FontRef::new(&font_bytes).map_err(|e| format!("Failed to parse compiled font: {:?}", e))?;
+    let max_context = max_context(tables)?;
+
+    let mut builder = FontBuilder::new();
+    for record in font.table_directory.table_records() {
+        let tag = record.tag();
+        if LAYOUT_TABLES.contains(&tag) {
+            continue;
+        }
+        let Some(data) = font.table_data(tag) else { continue };
+        if tag == Tag::new(b"OS/2") && data.len() >= US_MAX_CONTEXT_OFFSET + 2 {
+            let mut os2 = data.as_bytes().to_vec();
+            os2[US_MAX_CONTEXT_OFFSET..US_MAX_CONTEXT_OFFSET + 2].copy_from_slice(&max_context.to_be_bytes());
+            builder.add_raw(tag, os2);
+        } else {
+            builder.add_raw(tag, data.as_bytes());
+        }
+    }
+    for (tag, data) in tables {
+        builder.add_raw(*tag, data.as_slice());
+    }
+    Ok(builder.build())
+}
@@ -0,0 +1,190 @@
+// Feature class module
+//
+// This module edits the named glyph classes of the source feature code (the
+// Glyphs-style class snippets, whose code is a list of members), so the
+// classes panel can change them without rewriting feature code in JS.
+// Members are glyph names or references to other classes (`@Uppercase`);
+// they are checked against the font before a class is changed. Usages are
+// the `@name` references in prefixes, features and other classes.
+
+use crate::errors::ApiError;
+use crate::feature_syntax;
+
+/// Check that a class name is valid in feature code
+///
+/// Up to 63 letters, digits, '_', '.' and '-', not starting with a digit,
+/// '.' or '-'.
+fn validate_class_name(name: &str) -> Result<(), ApiError> {
+    let valid_chars = name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    let valid_start = name.chars().next().is_some_and(|c| !c.is_ascii_digit() && c != '.' && c != '-');
+    if !valid_chars || !valid_start || name.len() > 63 {
+        return Err(ApiError::invalid_argument("class name", name));
+    }
+    Ok(())
+}
+
+/// Members of a class snippet
+fn class_members(code: &str) -> Vec<String> {
+    feature_syntax::statements(code)
+        .into_iter()
+        .flatten()
+        .filter(|token| token.chars().any(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Members that are neither glyphs of the font nor defined classes
+fn missing_members(font: &babelfont::Font, members: &[String]) -> Vec<String> {
+    members
+        .iter()
+        .filter(|member| match member.strip_prefix('@') {
+            Some(class) => font.features.classes.get(class).is_none(),
+            None => font.glyphs.get(member.trim_start_matches('\\')).is_none(),
+        })
+        .cloned()
+        .collect()
+}
+
+/// Check that all members exist
+fn validate_members(font: &babelfont::Font, members: &[String]) -> Result<(), ApiError> {
+    let missing = missing_members(font, members);
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiError::invalid_argument("class members", missing.join(", ")))
+    }
+}
+
+/// References to a class in the feature code of a font
+fn class_usages(font: &babelfont::Font, name: &str) -> Vec<serde_json::Value> {
+    let mut usages = Vec::new();
+    for snippet in feature_syntax::font_snippets(font) {
+        for (line, column) in feature_syntax::class_references(&snippet, name) {
+            usages.push(serde_json::json!({
+                "source": snippet.source,
+                "line": line,
+                "column": column,
+            }));
+        }
+    }
+    usages
+}
+
+/// Description of a class
+fn class_json(font: &babelfont::Font, name: &str, code: &str) -> serde_json::Value {
+    let members = class_members(code);
+    serde_json::json!({
+        "name": name,
+        "missing": missing_members(font, &members),
+        "members": members,
+        "usages": class_usages(font, name),
+    })
+}
+
+/// Parse a JSON array of member names
+pub fn parse_members(members_json: &str) -> Result<Vec<String>, ApiError> {
+    serde_json::from_str(members_json).map_err(|e| ApiError::invalid_json("class members", e))
+}
+
+/// List the classes of a font
+///
+/// # Returns
+/// * `JsonValue` - Array '[{"name": "Uppercase", "members": ["A", "B"], "missing": [], "usages": [{"source": "feature:case", "line": 1, "column": 5}]}]'
+pub fn list_classes(font: &babelfont::Font) -> serde_json::Value {
+    let classes: Vec<serde_json::Value> = font
+        .features
+        .classes
+        .iter()
+        .map(|(name, code)| class_json(font, &name.to_string(), &code.to_string()))
+        .collect();
+    serde_json::Value::Array(classes)
+}
+
+/// Create a class
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `name` - Class name, without `@`
+/// * `members` - Glyph names and `@class` references
+///
+/// # Returns
+/// * `JsonValue` - The class, as in list_classes()
+pub fn create_class(font: &mut babelfont::Font, name: &str, members: &[String]) -> Result<serde_json::Value, ApiError> {
+    let name = name.trim_start_matches('@');
+    validate_class_name(name)?;
+    if font.features.classes.get(name).is_some() {
+        return Err(ApiError::ClassExists { class: name.to_string() });
+    }
+    validate_members(font, members)?;
+    let code = members.join(" ");
+    font.features.classes.insert(name.into(), code.clone().into());
+    Ok(class_json(font, name, &code))
+}
+
+/// Add members to a class
+///
+/// Members already in the class are not added again.
+///
+/// # Returns
+/// * `JsonValue` - The class, as in list_classes()
+pub fn add_class_members(font: &mut babelfont::Font, name: &str, members: &[String]) -> Result<serde_json::Value, ApiError> {
+    let name = name.trim_start_matches('@');
+    if members.iter().any(|member| member.strip_prefix('@') == Some(name)) {
+        return Err(ApiError::invalid_argument("class member", format!("@{}", name)));
+    }
+    validate_members(font, members)?;
+    let code = font
+        .features
+        .classes
+        .get_mut(name)
+        .ok_or_else(|| ApiError::ClassNotFound { class: name.to_string() })?;
+    let mut current = class_members(&code.to_string());
+    for member in members {
+        if !current.contains(member) {
+            current.push(member.clone());
+        }
+    }
+    let updated = current.join(" ");
+    *code = updated.clone().into();
+    Ok(class_json(font, name, &updated))
+}
+
+/// Remove members from a class
+///
+/// # Returns
+/// * `JsonValue` - The class, as in list_classes()
+pub fn remove_class_members(font: &mut babelfont::Font, name: &str, members: &[String]) -> Result<serde_json::Value, ApiError> {
+    let name = name.trim_start_matches('@');
+    let code = font
+        .features
+        .classes
+        .get_mut(name)
+        .ok_or_else(|| ApiError::ClassNotFound { class: name.to_string() })?;
+    let current = class_members(&code.to_string());
+    let updated = current.into_iter().filter(|member| !members.contains(member)).collect::<Vec<_>>().join(" ");
+    *code = updated.clone().into();
+    Ok(class_json(font, name, &updated))
+}
+
+/// Delete a class
+///
+/// A class that feature code still refers to is only deleted with `force`,
+/// as the code would no longer compile.
+///
+/// # Returns
+/// * `JsonValue` - Result '{"deleted": "Uppercase", "usages": [...]}' with the references left behind
+pub fn delete_class(font: &mut babelfont::Font, name: &str, force: bool) -> Result<serde_json::Value, ApiError> {
+    let name = name.trim_start_matches('@');
+    if font.features.classes.get(name).is_none() {
+        return Err(ApiError::ClassNotFound { class: name.to_string() });
+    }
+    let usages = class_usages(font, name);
+    if !usages.is_empty() && !force {
+        return Err(ApiError::ClassInUse { class: name.to_string(), usages: usages.len() });
+    }
+    font.features.classes.shift_remove(name);
+    Ok(serde_json::json!({
+        "deleted": name,
+        "usages": usages,
+    }))
+}
@@ -0,0 +1,473 @@
+// Feature export module
+//
+// This module merges the Glyphs-style feature code of a source font (class
+// member lists, prefixes and feature bodies) into one standalone feature
+// file for external pipelines such as fontmake or makeotf. Glyphs tokens
+// are expanded on the way: `$[predicate]` becomes the glyphs matching the
+// predicate (`$[name endswith ".sc"]`) and `${expression}` becomes a number
+// computed from master metrics (`${xHeight / 2}`), as they would be at
+// compile time in Glyphs.
+
+use crate::errors::ApiError;
+use crate::feature_syntax;
+
+/// A comparison of a predicate
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Like,
+    BeginsWith,
+    EndsWith,
+    Contains,
+}
+
+impl Operator {
+    fn from_word(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "==" | "=" => Some(Operator::Equal),
+            "!=" | "<>" => Some(Operator::NotEqual),
+            "like" => Some(Operator::Like),
+            "beginswith" => Some(Operator::BeginsWith),
+            "endswith" => Some(Operator::EndsWith),
+            "contains" => Some(Operator::Contains),
+            _ => None,
+        }
+    }
+
+    fn matches(self, value: &str, pattern: &str) -> bool {
+        match self {
+            Operator::Equal => value == pattern,
+            Operator::NotEqual => value != pattern,
+            Operator::Like => wildcard_match(value, pattern),
+            Operator::BeginsWith => value.starts_with(pattern),
+            Operator::EndsWith => value.ends_with(pattern),
+            Operator::Contains => value.contains(pattern),
+        }
+    }
+}
+
+/// Match a `like` pattern with `*` (any characters) and `?` (one character)
+pub fn wildcard_match(value: &str, pattern: &str) -> bool {
+    let value: Vec<char> = value.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    // matched[j]: the value so far matches the first j pattern characters
+    let mut matched = vec![false; pattern.len() + 1];
+    matched[0] = true;
+    for (j, &p) in pattern.iter().enumerate() {
+        matched[j + 1] = matched[j] && p == '*';
+    }
+    for &c in &value {
+        let mut next = vec![false; pattern.len() + 1];
+        for (j, &p) in pattern.iter().enumerate() {
+            next[j + 1] = match p {
+                '*' => next[j] || matched[j + 1],
+                '?' => matched[j],
+                p => matched[j] && p == c,
+            };
+        }
+        matched = next;
+    }
+    matched[pattern.len()]
+}
+
+/// A parsed glyph predicate
+#[derive(Debug)]
+enum Predicate {
+    Compare(String, Operator, String),
+    Not(Box<Predicate>),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+}
+
+/// Split a predicate into words, quoted strings and parentheses
+fn predicate_tokens(predicate: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = predicate.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut string = String::new();
+            loop {
+                match chars.next() {
+                    Some(end) if end == c => break,
+                    Some(next) => string.push(next),
+                    None => return Err(format!("Unterminated string in predicate '{}'", predicate)),
+                }
+            }
+            // Quoted values keep their quote so they are not taken for keywords
+            tokens.push(format!("\"{}", string));
+        } else if c == '(' || c == ')' {
+            tokens.push(c.to_string());
+            chars.next();
+        } else if "=!<>".contains(c) {
+            let mut operator = String::new();
+            while let Some(&next) = chars.peek().filter(|next| "=!<>".contains(**next)) {
+                operator.push(next);
+                chars.next();
+            }
+            tokens.push(operator);
+        } else if c == '&' || c == '|' {
+            chars.next();
+            if chars.peek() == Some(&c) {
+                chars.next();
+            }
+            tokens.push(if c == '&' { "and" } else { "or" }.to_string());
+        } else {
+            let mut word = String::new();
+            while let Some(&next) = chars.peek().filter(|next| !next.is_whitespace() && !"()\"'=!<>&|".contains(**next)) {
+                word.push(next);
+                chars.next();
+            }
+            tokens.push(word);
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser of predicates: or < and < not < comparison
+struct PredicateParser {
+    tokens: Vec<String>,
+    position: usize,
+}
+
+impl PredicateParser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.tokens.get(self.position).is_some_and(|token| token.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Predicate, String> {
+        let mut predicate = self.and()?;
+        while self.peek_keyword("or") {
+            self.position += 1;
+            predicate = Predicate::Or(Box::new(predicate), Box::new(self.and()?));
+        }
+        Ok(predicate)
+    }
+
+    fn and(&mut self) -> Result<Predicate, String> {
+        let mut predicate = self.not()?;
+        while self.peek_keyword("and") {
+            self.position += 1;
+            predicate = Predicate::And(Box::new(predicate), Box::new(self.not()?));
+        }
+        Ok(predicate)
+    }
+
+    fn not(&mut self) -> Result<Predicate, String> {
+        if self.peek_keyword("not") || self.peek_keyword("!") {
+            self.position += 1;
+            return Ok(Predicate::Not(Box::new(self.not()?)));
+        }
+        if self.peek_keyword("(") {
+            self.position += 1;
+            let predicate = self.or()?;
+            if self.next().as_deref() != Some(")") {
+                return Err("Expected ')' in predicate".to_string());
+            }
+            return Ok(predicate);
+        }
+        let field = self.next().ok_or_else(|| "Predicate ends early".to_string())?;
+        let operator = self.next().ok_or_else(|| format!("Expected an operator after '{}'", field))?;
+        // String comparisons may carry a [c] or [cd] modifier
+        let operator = operator.split('[').next().unwrap_or_default().to_string();
+        let operator =
+            Operator::from_word(&operator).ok_or_else(|| format!("Unknown predicate operator '{}'", operator))?;
+        let value = self.next().ok_or_else(|| format!("Expected a value after '{}'", field))?;
+        let value = value.strip_prefix('"').unwrap_or(&value).to_string();
+        Ok(Predicate::Compare(field, operator, value))
+    }
+}
+
+/// Parse a Glyphs glyph predicate
+fn parse_predicate(predicate: &str) -> Result<Predicate, String> {
+    let mut parser = PredicateParser { tokens: predicate_tokens(predicate)?, position: 0 };
+    let parsed = parser.or()?;
+    if parser.position < parser.tokens.len() {
+        return Err(format!("Unexpected '{}' in predicate '{}'", parser.tokens[parser.position], predicate));
+    }
+    Ok(parsed)
+}
+
+/// Value of a predicate field for a glyph
+fn glyph_field(glyph: &babelfont::Glyph, field: &str) -> Result<String, String> {
+    let character = glyph.codepoints.first().and_then(|&codepoint| char::from_u32(codepoint));
+    Ok(match field {
+        "name" => glyph.name.to_string(),
+        "category" => serde_json::to_value(&glyph.category)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default(),
+        "unicode" => glyph.codepoints.first().map(|codepoint| format!("{:04X}", codepoint)).unwrap_or_default(),
+        "case" => match character {
+            Some(c) if c.is_uppercase() => "upper",
+            Some(c) if c.is_lowercase() => "lower",
+            _ => "noCase",
+        }
+        .to_string(),
+        other => {
+            return Err(format!("Unknown glyph property '{}': expected name, category, unicode or case", other));
+        }
+    })
+}
+
+fn evaluate(predicate: &Predicate, glyph: &babelfont::Glyph) -> Result<bool, String> {
+    Ok(match predicate {
+        Predicate::Compare(field, operator, value) => {
+            let actual = glyph_field(glyph, field)?;
+            // Categories are compared case-insensitively ("mark" and "Mark")
+            if field == "category" || field == "case" {
+                operator.matches(&actual.to_lowercase(), &value.to_lowercase())
+            } else {
+                operator.matches(&actual, value)
+            }
+        }
+        Predicate::Not(inner) => !evaluate(inner, glyph)?,
+        Predicate::And(left, right) => evaluate(left, glyph)? && evaluate(right, glyph)?,
+        Predicate::Or(left, right) => evaluate(left, glyph)? || evaluate(right, glyph)?,
+    })
+}
+
+/// Exported glyphs matching a predicate, in glyph order
+fn predicate_glyphs(font: &babelfont::Font, predicate: &str) -> Result<Vec<String>, String> {
+    let predicate = parse_predicate(predicate)?;
+    let mut glyphs = Vec::new();
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported) {
+        if evaluate(&predicate, glyph)? {
+            glyphs.push(glyph.name.to_string());
+        }
+    }
+    Ok(glyphs)
+}
+
+/// Value of a metric of a master in a number token
+fn metric_value(master: &babelfont::Master, name: &str) -> Result<f64, String> {
+    let metric = match name {
+        "xHeight" => babelfont::MetricType::XHeight,
+        "capHeight" => babelfont::MetricType::CapHeight,
+        "ascender" => babelfont::MetricType::Ascender,
+        "descender" => babelfont::MetricType::Descender,
+        "italicAngle" => babelfont::MetricType::ItalicAngle,
+        other => {
+            return Err(format!(
+                "Unknown number '{}': expected xHeight, capHeight, ascender, descender or italicAngle",
+                other
+            ))
+        }
+    };
+    master
+        .metrics
+        .get(&metric)
+        .map(|&value| value as f64)
+        .ok_or_else(|| format!("Master '{}' has no {} metric", master.id, name))
+}
+
+/// Evaluate a number token: metric names and numbers with + - * / and parentheses
+fn evaluate_number(expression: &str, master: &babelfont::Master) -> Result<f64, String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if "+-*/()".contains(c) {
+            tokens.push(c.to_string());
+            chars.next();
+        } else {
+            let mut word = String::new();
+            while let Some(&next) = chars.peek().filter(|next| next.is_ascii_alphanumeric() || **next == '.' || **next == '_') {
+                word.push(next);
+                chars.next();
+            }
+            if word.is_empty() {
+                return Err(format!("Unexpected '{}' in number '{}'", c, expression));
+            }
+            tokens.push(word);
+        }
+    }
+
+    fn sum(tokens: &[String], position: &mut usize, master: &babelfont::Master) -> Result<f64, String> {
+        let mut value = product(tokens, position, master)?;
+        while let Some(operator) = tokens.get(*position).filter(|token| *token == "+" || *token == "-") {
+            *position += 1;
+            let right = product(tokens, position, master)?;
+            value = if operator == "+" { value + right } else { value - right };
+        }
+        Ok(value)
+    }
+    fn product(tokens: &[String], position: &mut usize, master: &babelfont::Master) -> Result<f64, String> {
+        let mut value = factor(tokens, position, master)?;
+        while let Some(operator) = tokens.get(*position).filter(|token| *token == "*" || *token == "/") {
+            *position += 1;
+            let right = factor(tokens, position, master)?;
+            value = if operator == "*" { value * right } else { value / right };
+        }
+        Ok(value)
+    }
+    fn factor(tokens: &[String], position: &mut usize, master: &babelfont::Master) -> Result<f64, String> {
+        let token = tokens.get(*position).ok_or_else(|| "Number ends early".to_string())?;
+        *position += 1;
+        match token.as_str() {
+            "-" => Ok(-factor(tokens, position, master)?),
+            "(" => {
+                let value = sum(tokens, position, master)?;
+                if tokens.get(*position).map(String::as_str) != Some(")") {
+                    return Err("Expected ')' in number".to_string());
+                }
+                *position += 1;
+                Ok(value)
+            }
+            word => match word.parse::<f64>() {
+                Ok(number) => Ok(number),
+                Err(_) => metric_value(master, word),
+            },
+        }
+    }
+
+    let mut position = 0;
+    let value = sum(&tokens, &mut position, master)?;
+    if position < tokens.len() {
+        return Err(format!("Unexpected '{}' in number '{}'", tokens[position], expression));
+    }
+    Ok(value)
+}
+
+/// Expand `$[predicate]` and `${expression}` tokens in feature code
+///
+/// Tokens in comments are left as they are.
+fn expand_tokens(code: &str, font: &babelfont::Font, master: &babelfont::Master) -> Result<String, String> {
+    let mut expanded = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(index) = rest.find(['$', '#']) {
+        expanded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with('#') {
+            let end = rest.find('\n').unwrap_or(rest.len());
+            expanded.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let close = match rest[1..].chars().next() {
+            Some('[') => ']',
+            Some('{') => '}',
+            _ => {
+                expanded.push('$');
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let end = rest.find(close).ok_or_else(|| format!("Unterminated token '{}'", rest.lines().next().unwrap_or(rest)))?;
+        let body = &rest[2..end];
+        if close == ']' {
+            expanded.push_str(&predicate_glyphs(font, body)?.join(" "));
+        } else {
+            let value = evaluate_number(body, master)?;
+            expanded.push_str(&value.round().to_string());
+        }
+        rest = &rest[end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+/// Indent every non-empty line of code
+fn indent(code: &str) -> String {
+    code.trim_end()
+        .lines()
+        .map(|line| if line.trim().is_empty() { String::new() } else { format!("    {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Merge the feature code of a font into one feature file
+///
+/// Classes come first, as prefixes and features may use them, then
+/// prefixes (languagesystem statements, standalone lookups), then each
+/// feature body wrapped in a `feature` block.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `options_json` - Optional JSON object:
+///   - `master`: string - Master whose metrics number tokens use (default: the default master)
+///   - `expandIncludes`: bool - Replace include() statements with the registered files (default: false)
+///
+/// # Returns
+/// * `String` - Feature file
+pub fn export_features(font: &babelfont::Font, options_json: Option<&str>) -> Result<String, ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let master_id = match options.get("master").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => crate::source_checks::default_master_id(font)
+            .map(str::to_string)
+            .or_else(|| font.masters.first().map(|master| master.id.clone()))
+            .ok_or_else(|| "The font has no masters".to_string())?,
+    };
+    let master = font
+        .masters
+        .iter()
+        .find(|master| master.id == master_id)
+        .ok_or_else(|| ApiError::master_not_found(master_id))?;
+
+    let expanded_font;
+    let font = if options.get("expandIncludes").and_then(|v| v.as_bool()).unwrap_or(false) {
+        let mut copy = font.clone();
+        crate::feature_includes::expand_includes(&mut copy)?;
+        expanded_font = copy;
+        &expanded_font
+    } else {
+        font
+    };
+
+    let mut sections = Vec::new();
+    for snippet in feature_syntax::font_snippets(font) {
+        let code = expand_tokens(&snippet.code, font, master)
+            .map_err(|e| ApiError::compilation("Token expansion", format!("{}: {}", snippet.source, e)))?;
+        let section = match snippet.kind {
+            feature_syntax::SnippetKind::ClassMembers => {
+                let name = snippet.source.trim_start_matches("class:").trim_start_matches('@');
+                // Comments in the member list need their own lines
+                let members = if code.contains('#') {
+                    format!("\n{}\n", indent(&code))
+                } else {
+                    code.split_whitespace().collect::<Vec<_>>().join(" ")
+                };
+                (0, format!("@{} = [{}];", name, members))
+            }
+            feature_syntax::SnippetKind::TopLevel => {
+                let name = snippet.source.trim_start_matches("prefix:");
+                (1, format!("# Prefix: {}\n{}", name, code.trim_end()))
+            }
+            feature_syntax::SnippetKind::FeatureBody => {
+                let tag = snippet.source.trim_start_matches("feature:");
+                (2, format!("feature {} {{\n{}\n}} {};", tag, indent(&code), tag))
+            }
+        };
+        sections.push(section);
+    }
+    // Stable sort keeps the source order within classes, prefixes and features
+    sections.sort_by_key(|(order, _)| *order);
+
+    let mut fea = String::new();
+    let mut previous = None;
+    for (order, section) in sections {
+        if previous.is_some() {
+            fea.push_str(if previous == Some(order) && order == 0 { "\n" } else { "\n\n" });
+        }
+        fea.push_str(&section);
+        previous = Some(order);
+    }
+    fea.push('\n');
+    Ok(fea)
+}
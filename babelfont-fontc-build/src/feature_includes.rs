@@ -0,0 +1,228 @@
+// Feature include module
+//
+// This module resolves `include(path);` statements in source feature code
+// before compiling. There is no file system in the browser, so included
+// files come from an in-memory map registered by JS or, for files not in the
+// map, from a JS provider callback called with the path. Paths are resolved
+// relative to the including file; paths in the font's own feature code are
+// relative to the font. Included files may include further files.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::errors::ApiError;
+
+/// Deepest include nesting followed before giving up
+const MAX_INCLUDE_DEPTH: usize = 50;
+
+// Included files registered by JS, keyed by normalized path
+static INCLUDE_FILES: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+thread_local! {
+    // JS callback providing included files missing from INCLUDE_FILES
+    // (JS functions cannot be shared between threads, so it is kept per thread)
+    static INCLUDE_PROVIDER: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+}
+
+/// Replace the registered included files
+pub fn set_include_files(files: HashMap<String, String>) {
+    let files = files.into_iter().map(|(path, code)| (normalize_path(&path), code)).collect();
+    *INCLUDE_FILES.lock().unwrap() = Some(files);
+}
+
+/// Paths of the registered included files
+pub fn include_file_paths() -> Vec<String> {
+    let files = INCLUDE_FILES.lock().unwrap();
+    let mut paths: Vec<String> = files.iter().flat_map(|files| files.keys().cloned()).collect();
+    paths.sort();
+    paths
+}
+
+/// Set or remove the JS provider of included files
+pub fn set_include_provider(provider: Option<js_sys::Function>) {
+    INCLUDE_PROVIDER.with(|current| *current.borrow_mut() = provider);
+}
+
+/// Collapse `.` and `..` segments and backslashes of a relative path
+fn normalize_path(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|last| *last != "..") => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    let normalized = segments.join("/");
+    if path.starts_with('/') {
+        format!("/{}", normalized)
+    } else {
+        normalized
+    }
+}
+
+/// Path of an include relative to the directory of the including file
+fn join_path(directory: &str, path: &str) -> String {
+    if path.starts_with('/') || directory.is_empty() {
+        normalize_path(path)
+    } else {
+        normalize_path(&format!("{}/{}", directory, path))
+    }
+}
+
+/// Directory of a normalized path
+fn directory_of(path: &str) -> &str {
+    path.rsplit_once('/').map(|(directory, _)| directory).unwrap_or("")
+}
+
+/// Code of an included file, from the registered files or the provider
+fn read_include(path: &str) -> Result<String, String> {
+    if let Some(code) = INCLUDE_FILES.lock().unwrap().as_ref().and_then(|files| files.get(path)) {
+        return Ok(code.clone());
+    }
+    // Called outside the borrow of its cell, so it may replace itself
+    let provider = INCLUDE_PROVIDER.with(|provider| provider.borrow().clone());
+    let provided = match provider {
+        Some(provider) => provider
+            .call1(&JsValue::NULL, &JsValue::from_str(path))
+            .map(|code| code.as_string())
+            .map_err(|e| format!("Include provider failed for '{}': {}", path, js_error_message(&e)))?,
+        None => None,
+    };
+    provided.ok_or_else(|| {
+        format!(
+            "Included file '{}' not found; register it with set_feature_include_files() or set_feature_include_provider()",
+            path
+        )
+    })
+}
+
+/// Message of a value thrown by JS: an Error's message, or the value as a string
+fn js_error_message(error: &JsValue) -> String {
+    match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => error.as_string().unwrap_or_else(|| format!("{:?}", error)),
+    }
+}
+
+/// Byte range of the next `include(...)` statement at or after `from`, and its path
+///
+/// Comments and strings are skipped. The range covers a trailing `;`.
+fn next_include(code: &str, from: usize) -> Option<(usize, usize, &str)> {
+    let bytes = code.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'i' if code[i..].starts_with("include")
+                && (i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || matches!(bytes[i - 1], b'_' | b'.' | b'@' | b'\\'))) =>
+            {
+                let after = i + "include".len();
+                let rest = &code[after..];
+                let open = after + rest.len() - rest.trim_start().len();
+                if bytes.get(open) != Some(&b'(') {
+                    i = after;
+                    continue;
+                }
+                let close = open + code[open..].find(')')?;
+                let path = code[open + 1..close].trim();
+                let rest = &code[close + 1..];
+                let mut end = close + 1 + rest.len() - rest.trim_start().len();
+                if bytes.get(end) == Some(&b';') {
+                    end += 1;
+                } else {
+                    end = close + 1;
+                }
+                return Some((i, end, path));
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Replace include statements in code with the included code, recursively
+///
+/// `stack` holds the files being expanded, for cycle detection.
+fn expand_code(code: &str, directory: &str, stack: &mut Vec<String>) -> Result<String, String> {
+    let mut expanded = String::with_capacity(code.len());
+    let mut copied = 0;
+    while let Some((start, end, path)) = next_include(code, copied) {
+        let path = join_path(directory, path);
+        if stack.contains(&path) {
+            stack.push(path);
+            return Err(format!("Include cycle: {}", stack.join(" -> ")));
+        }
+        if stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(format!("Includes nested deeper than {} files at '{}'", MAX_INCLUDE_DEPTH, path));
+        }
+        let included = read_include(&path)?;
+        stack.push(path.clone());
+        let included = expand_code(&included, directory_of(&path), stack)?;
+        stack.pop();
+
+        expanded.push_str(&code[copied..start]);
+        expanded.push_str(&format!("# include({})\n", path));
+        expanded.push_str(&included);
+        if !included.ends_with('\n') {
+            expanded.push('\n');
+        }
+        copied = end;
+    }
+    expanded.push_str(&code[copied..]);
+    Ok(expanded)
+}
+
+/// Whether feature code has an include statement
+fn has_include(code: &str) -> bool {
+    code.contains("include") && next_include(code, 0).is_some()
+}
+
+/// Expand the include statements of one snippet, labelled `source` in errors
+fn expand_snippet<T: ToString + From<String>>(source: String, code: &mut T) -> Result<bool, ApiError> {
+    let current = code.to_string();
+    if !has_include(&current) {
+        return Ok(false);
+    }
+    let expanded = expand_code(&current, "", &mut Vec::new())
+        .map_err(|e| ApiError::compilation("Include expansion", format!("{}: {}", source, e)))?;
+    *code = T::from(expanded);
+    Ok(true)
+}
+
+/// Expand the include statements of all feature code of a font
+///
+/// Called on the copy of the font that is compiled. Fonts without include
+/// statements are left as they are.
+///
+/// # Returns
+/// * `usize` - Number of snippets with includes expanded
+pub fn expand_includes(font: &mut babelfont::Font) -> Result<usize, ApiError> {
+    let mut expanded = 0;
+    for (name, code) in font.features.prefixes.iter_mut() {
+        expanded += expand_snippet(format!("prefix:{}", name), code)? as usize;
+    }
+    for (name, code) in font.features.classes.iter_mut() {
+        expanded += expand_snippet(format!("class:{}", name), code)? as usize;
+    }
+    for (tag, code) in font.features.features.iter_mut() {
+        expanded += expand_snippet(format!("feature:{}", tag), code)? as usize;
+    }
+    Ok(expanded)
+}
@@ -0,0 +1,418 @@
+// Feature UI parameters module
+//
+// This module reads and writes the UI parameters of stylistic set (ssXX,
+// `featureNames` block) and character variant (cvXX, `cvParameters` block)
+// features in source feature code. The blocks are compiled by fontc into the
+// GSUB FeatureParams tables and name table entries.
+
+use crate::errors::ApiError;
+
+/// A feature file token
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Str(&'a str),
+    Open,
+    Close,
+    Semicolon,
+}
+
+/// Split feature code into tokens with their byte ranges, skipping comments and whitespace
+fn tokenize(code: &str) -> Vec<(Token<'_>, usize, usize)> {
+    let bytes = code.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += 1;
+                }
+                let end = (i + 1).min(bytes.len());
+                tokens.push((Token::Str(&code[start + 1..i.min(bytes.len())]), start, end));
+                i = end;
+            }
+            b'{' => {
+                tokens.push((Token::Open, i, i + 1));
+                i += 1;
+            }
+            b'}' => {
+                tokens.push((Token::Close, i, i + 1));
+                i += 1;
+            }
+            b';' => {
+                tokens.push((Token::Semicolon, i, i + 1));
+                i += 1;
+            }
+            c if c.is_ascii_whitespace() => i += 1,
+            _ => {
+                let start = i;
+                while i < bytes.len()
+                    && !bytes[i].is_ascii_whitespace()
+                    && !matches!(bytes[i], b'#' | b'"' | b'{' | b'}' | b';')
+                {
+                    i += 1;
+                }
+                tokens.push((Token::Word(&code[start..i]), start, i));
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Find a top-level `keyword { ... };` block
+///
+/// # Returns
+/// * `Option<(usize, usize)>` - Byte range of the block including the trailing semicolon
+fn find_block(code: &str, keyword: &str) -> Option<(usize, usize)> {
+    let tokens = tokenize(code);
+    let mut depth = 0usize;
+
+    for (index, (token, start, _)) in tokens.iter().enumerate() {
+        match token {
+            Token::Open => depth += 1,
+            Token::Close => depth = depth.saturating_sub(1),
+            Token::Word(word) if depth == 0 && *word == keyword => {
+                if tokens.get(index + 1).map(|t| &t.0) != Some(&Token::Open) {
+                    continue;
+                }
+                let mut inner_depth = 0usize;
+                for (offset, (inner, _, inner_end)) in tokens[index + 1..].iter().enumerate() {
+                    match inner {
+                        Token::Open => inner_depth += 1,
+                        Token::Close => {
+                            inner_depth -= 1;
+                            if inner_depth == 0 {
+                                let mut end = *inner_end;
+                                if let Some((Token::Semicolon, _, semicolon_end)) = tokens.get(index + offset + 2) {
+                                    end = *semicolon_end;
+                                }
+                                return Some((*start, end));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Decode a FEA name string (`\XXXX` escapes are Unicode code units)
+fn unescape(string: &str) -> String {
+    let mut units: Vec<u16> = Vec::new();
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let hex: String = chars.clone().take(4).collect();
+            if hex.len() == 4 {
+                if let Ok(unit) = u16::from_str_radix(&hex, 16) {
+                    units.push(unit);
+                    for _ in 0..4 {
+                        chars.next();
+                    }
+                    continue;
+                }
+            }
+        }
+        let mut buffer = [0u16; 2];
+        units.extend_from_slice(c.encode_utf16(&mut buffer));
+    }
+    String::from_utf16_lossy(&units)
+}
+
+/// Encode a string for a FEA name statement
+fn escape(string: &str) -> String {
+    string
+        .chars()
+        .map(|c| match c {
+            '"' => "\\0022".to_string(),
+            '\\' => "\\005C".to_string(),
+            c if c.is_control() => {
+                let mut buffer = [0u16; 2];
+                c.encode_utf16(&mut buffer)
+                    .iter()
+                    .map(|unit| format!("\\{:04X}", unit))
+                    .collect()
+            }
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// UI parameters of a stylistic set or character variant feature
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeatureUiNames {
+    /// Stylistic set name (ssXX) or feature UI label (cvXX)
+    pub label: Option<String>,
+    pub tooltip: Option<String>,
+    pub sample_text: Option<String>,
+    pub param_labels: Vec<String>,
+    /// Characters the character variant applies to
+    pub characters: Vec<u32>,
+}
+
+/// Whether a feature tag is a stylistic set (ss01–ss20)
+pub fn is_stylistic_set(tag: &str) -> bool {
+    tag.len() == 4 && tag.starts_with("ss") && tag[2..].parse::<u8>().is_ok_and(|n| (1..=20).contains(&n))
+}
+
+/// Whether a feature tag is a character variant (cv01–cv99)
+pub fn is_character_variant(tag: &str) -> bool {
+    tag.len() == 4 && tag.starts_with("cv") && tag[2..].parse::<u8>().is_ok_and(|n| (1..=99).contains(&n))
+}
+
+impl FeatureUiNames {
+    /// Serialize using the same keys as `get_feature_ui_names()`
+    pub fn to_json(&self, tag: &str) -> serde_json::Value {
+        if is_stylistic_set(tag) {
+            serde_json::json!({
+                "kind": "stylisticSet",
+                "uiName": self.label,
+            })
+        } else {
+            serde_json::json!({
+                "kind": "characterVariant",
+                "uiLabel": self.label,
+                "tooltip": self.tooltip,
+                "sampleText": self.sample_text,
+                "paramLabels": self.param_labels,
+                "characters": self.characters,
+            })
+        }
+    }
+
+    /// Parse from JSON (`uiName` or `uiLabel`, `tooltip`, `sampleText`, `paramLabels`, `characters`)
+    ///
+    /// Characters may be numbers or "U+XXXX" / single-character strings.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, ApiError> {
+        let string = |key: &str| {
+            value.get(key)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        };
+
+        let param_labels = value.get("paramLabels")
+            .and_then(|v| v.as_array())
+            .map(|labels| labels.iter().filter_map(|l| l.as_str()).map(|l| l.to_string()).collect())
+            .unwrap_or_default();
+
+        let characters = value.get("characters")
+            .and_then(|v| v.as_array())
+            .map(|chars| {
+                chars.iter()
+                    .map(|c| match c {
+                        serde_json::Value::Number(n) => n.as_u64()
+                            .map(|n| n as u32)
+                            .ok_or_else(|| ApiError::invalid_argument("character", n)),
+                        serde_json::Value::String(s) if s.chars().count() == 1 => Ok(s.chars().next().unwrap() as u32),
+                        serde_json::Value::String(s) => {
+                            let hex = s.trim_start_matches("U+").trim_start_matches("u+");
+                            u32::from_str_radix(hex, 16).map_err(|_| ApiError::invalid_argument("character", s))
+                        }
+                        other => Err(ApiError::invalid_argument("character", other)),
+                    })
+                    .collect::<Result<Vec<u32>, ApiError>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(FeatureUiNames {
+            label: string("uiName").or_else(|| string("uiLabel")),
+            tooltip: string("tooltip"),
+            sample_text: string("sampleText"),
+            param_labels,
+            characters,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == FeatureUiNames::default()
+    }
+}
+
+/// Read the first string of each `name` statement inside a block body
+fn name_strings(tokens: &[(Token<'_>, usize, usize)]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut in_name = false;
+    for (token, _, _) in tokens {
+        match token {
+            Token::Word("name") => in_name = true,
+            Token::Str(string) if in_name => {
+                names.push(unescape(string));
+                in_name = false;
+            }
+            Token::Semicolon => in_name = false,
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Read the UI parameters of a feature from its source code
+///
+/// # Arguments
+/// * `tag` - Feature tag (ssXX or cvXX)
+/// * `code` - Feature code (the body of the feature block)
+///
+/// # Returns
+/// * `Option<FeatureUiNames>` - The parameters, or None if the code has no parameter block
+pub fn read_ui_names(tag: &str, code: &str) -> Option<FeatureUiNames> {
+    if is_stylistic_set(tag) {
+        let (start, end) = find_block(code, "featureNames")?;
+        let label = name_strings(&tokenize(&code[start..end])).into_iter().next();
+        return Some(FeatureUiNames { label, ..Default::default() });
+    }
+
+    if !is_character_variant(tag) {
+        return None;
+    }
+
+    let (start, end) = find_block(code, "cvParameters")?;
+    let block = &code[start..end];
+    let tokens = tokenize(block);
+    let mut names = FeatureUiNames::default();
+
+    // Walk the statements directly inside `cvParameters { ... }`
+    let mut index = 2;
+    while index < tokens.len() {
+        match &tokens[index].0 {
+            Token::Word("Character") => {
+                if let Some((Token::Word(value), _, _)) = tokens.get(index + 1) {
+                    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => value.parse::<u32>().ok(),
+                    };
+                    names.characters.extend(parsed);
+                }
+                index += 2;
+            }
+            Token::Word(sub_block) => {
+                let sub_block = *sub_block;
+                // Collect the tokens of the nested `{ name ...; }` block
+                let mut depth = 0usize;
+                let mut end = index + 1;
+                while end < tokens.len() {
+                    match tokens[end].0 {
+                        Token::Open => depth += 1,
+                        Token::Close => {
+                            depth = depth.saturating_sub(1);
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    end += 1;
+                }
+                let strings = name_strings(&tokens[index + 1..end.min(tokens.len())]);
+                match sub_block {
+                    "FeatUILabelNameID" => names.label = strings.into_iter().next(),
+                    "FeatUITooltipTextNameID" => names.tooltip = strings.into_iter().next(),
+                    "SampleTextNameID" => names.sample_text = strings.into_iter().next(),
+                    "ParamUILabelNameID" => names.param_labels.extend(strings.into_iter().next()),
+                    _ => {}
+                }
+                index = end + 1;
+            }
+            _ => index += 1,
+        }
+    }
+
+    Some(names)
+}
+
+/// Format a parameter block for a feature
+fn format_block(tag: &str, names: &FeatureUiNames) -> String {
+    let name_block = |keyword: &str, name: &str| {
+        format!("    {} {{\n        name \"{}\";\n    }};\n", keyword, escape(name))
+    };
+
+    if is_stylistic_set(tag) {
+        let label = names.label.as_deref().unwrap_or_default();
+        return format!("featureNames {{\n    name \"{}\";\n}};", escape(label));
+    }
+
+    let mut block = String::from("cvParameters {\n");
+    if let Some(label) = &names.label {
+        block.push_str(&name_block("FeatUILabelNameID", label));
+    }
+    if let Some(tooltip) = &names.tooltip {
+        block.push_str(&name_block("FeatUITooltipTextNameID", tooltip));
+    }
+    if let Some(sample_text) = &names.sample_text {
+        block.push_str(&name_block("SampleTextNameID", sample_text));
+    }
+    for param_label in &names.param_labels {
+        block.push_str(&name_block("ParamUILabelNameID", param_label));
+    }
+    for character in &names.characters {
+        block.push_str(&format!("    Character 0x{:04X};\n", character));
+    }
+    block.push_str("};");
+    block
+}
+
+/// Replace (or insert, or remove) the UI parameter block in feature code
+///
+/// The block is inserted at the start of the feature code, where the
+/// feature file syntax requires it.
+///
+/// # Arguments
+/// * `tag` - Feature tag (ssXX or cvXX)
+/// * `code` - Feature code (the body of the feature block)
+/// * `names` - New parameters; None or empty parameters remove the block
+///
+/// # Returns
+/// * `Result<String, ApiError>` - The updated feature code
+pub fn write_ui_names(tag: &str, code: &str, names: Option<&FeatureUiNames>) -> Result<String, ApiError> {
+    let keyword = if is_stylistic_set(tag) {
+        "featureNames"
+    } else if is_character_variant(tag) {
+        "cvParameters"
+    } else {
+        return Err(ApiError::invalid_argument("feature tag", tag));
+    };
+
+    if let Some(names) = names.filter(|_| keyword == "featureNames") {
+        // FeatureParamsStylisticSet only has a UI name ID
+        if names.tooltip.is_some() || names.sample_text.is_some() || !names.param_labels.is_empty() || !names.characters.is_empty() {
+            return Err(ApiError::invalid_json(
+                "feature parameters",
+                format!(
+                    "Stylistic set '{}' only takes a UI name; tooltip, sample text, parameter labels and characters are for character variants",
+                    tag
+                ),
+            ));
+        }
+    }
+
+    let block = names.filter(|names| !names.is_empty()).map(|names| format_block(tag, names));
+
+    let updated = match (find_block(code, keyword), block) {
+        (Some((start, end)), Some(block)) => format!("{}{}{}", &code[..start], block, &code[end..]),
+        (Some((start, end)), None) => {
+            let rest = code[end..].strip_prefix('\n').unwrap_or(&code[end..]);
+            format!("{}{}", &code[..start], rest)
+        }
+        (None, Some(block)) => format!("{}\n{}", block, code),
+        (None, None) => code.to_string(),
+    };
+
+    Ok(updated)
+}
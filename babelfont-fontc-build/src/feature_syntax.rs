@@ -0,0 +1,801 @@
+// Feature code syntax validation module
+//
+// This module checks OpenType feature code (FEA) for syntax errors and
+// references to undefined glyphs, classes and lookups without compiling the
+// font, so the feature editor can show problems while the user types. It
+// understands complete feature files as well as the Glyphs-style snippets a
+// source font keeps: prefixes (top-level code), class member lists and
+// feature bodies. Code is parsed with fea-rs, the parser fontc compiles
+// with, so syntax errors match the ones of a build; glyph, class and lookup
+// references are then checked against the font and the other snippets.
+// Feature files can also be parsed into a syntax tree of blocks and
+// statements for folding and go-to-definition. A small tokenizer remains
+// for lexical work that must keep the code as written (glyph renames) or
+// read partial code (kerning import).
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use fea_rs::parse::SourceLoadError;
+use fea_rs::{DiagnosticSet, Kind, Node, NodeOrToken, ParseTree};
+
+use crate::font_validation::{findings_report, Finding};
+
+/// A feature code token
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Str,
+    Punct(char),
+}
+
+/// A token with its byte range in the source
+#[derive(Debug, Clone, Copy)]
+struct Spanned<'a> {
+    token: Token<'a>,
+    start: usize,
+    end: usize,
+}
+
+/// Split feature code into tokens, skipping comments and whitespace
+fn tokenize(code: &str) -> Vec<Spanned<'_>> {
+    let bytes = code.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += 1;
+                }
+                i = (i + 1).min(bytes.len());
+                tokens.push(Spanned { token: Token::Str, start, end: i });
+            }
+            b'{' | b'}' | b'[' | b']' | b'(' | b')' | b'<' | b'>' | b';' | b',' | b'\'' | b'=' => {
+                tokens.push(Spanned { token: Token::Punct(c as char), start: i, end: i + 1 });
+                i += 1;
+            }
+            c if c.is_ascii_whitespace() => i += 1,
+            _ => {
+                let start = i;
+                while i < bytes.len()
+                    && !bytes[i].is_ascii_whitespace()
+                    && !b"#\"{}[]()<>;,'=".contains(&bytes[i])
+                {
+                    i += 1;
+                }
+                tokens.push(Spanned { token: Token::Word(&code[start..i]), start, end: i });
+            }
+        }
+    }
+
+    tokens
+}
+
+/// How a piece of feature code is embedded in the font
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnippetKind {
+    /// A complete feature file or top-level prefix code
+    TopLevel,
+    /// The body of a feature block (Glyphs feature snippet)
+    FeatureBody,
+    /// The member list of a glyph class
+    ClassMembers,
+}
+
+/// A piece of feature code and where it comes from
+pub struct Snippet {
+    /// Source label reported with findings, e.g. "feature:liga", "class:Uppercase", "prefix:Languagesystems"
+    pub source: String,
+    pub kind: SnippetKind,
+    pub code: String,
+}
+
+/// Names that feature code can refer to
+struct Definitions<'a> {
+    glyphs: &'a dyn Fn(&str) -> bool,
+    classes: HashSet<String>,
+    lookups: HashSet<String>,
+}
+
+/// 1-based line and column (in characters) of a byte offset
+fn line_column(code: &str, offset: usize) -> (usize, usize) {
+    let before = &code[..offset.min(code.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Feature code snippets of a source font
+pub fn font_snippets(font: &babelfont::Font) -> Vec<Snippet> {
+    let mut snippets = Vec::new();
+    for (name, code) in font.features.prefixes.iter() {
+        snippets.push(Snippet {
+            source: format!("prefix:{}", name),
+            kind: SnippetKind::TopLevel,
+            code: code.to_string(),
+        });
+    }
+    for (name, code) in font.features.classes.iter() {
+        snippets.push(Snippet {
+            source: format!("class:{}", name),
+            kind: SnippetKind::ClassMembers,
+            code: code.to_string(),
+        });
+    }
+    for (tag, code) in font.features.features.iter() {
+        snippets.push(Snippet {
+            source: format!("feature:{}", tag),
+            kind: SnippetKind::FeatureBody,
+            code: code.to_string(),
+        });
+    }
+    snippets
+}
+
+/// Path of the parsed snippet in the parser's sources
+const SNIPPET_PATH: &str = "<snippet>";
+
+/// Tag of the block wrapping a feature body whose source has no valid tag
+const PLACEHOLDER_TAG: &str = "xxxx";
+
+/// Whether a token is whitespace, a comment or the backslash escaping a glyph name
+fn is_trivia(kind: Kind) -> bool {
+    matches!(kind, Kind::Whitespace | Kind::Comment | Kind::Backslash)
+}
+
+/// Tokens of a node, without trivia
+fn significant_tokens(node: &Node) -> impl Iterator<Item = &fea_rs::Token> {
+    node.iter_tokens().filter(|token| !is_trivia(token.kind))
+}
+
+/// What a name in feature code stands for
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SymbolKind {
+    Glyph,
+    Class,
+    MarkClass,
+    Lookup,
+    Feature,
+}
+
+/// A glyph, class, lookup or feature name with its byte range
+struct Symbol {
+    kind: SymbolKind,
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+/// Collect the names defined and referenced in a node, in text order
+///
+/// `previous` is the kind of the last token before the node that is not trivia.
+fn collect_symbols(node: &Node, previous: &mut Option<Kind>, definitions: &mut Vec<Symbol>, references: &mut Vec<Symbol>) {
+    let mut class_names = node
+        .iter_children()
+        .enumerate()
+        .filter(|(_, child)| child.kind() == Kind::NamedGlyphClass)
+        .map(|(index, _)| index);
+    // `@name = ...;` defines its first class, `markClass ... @name;` its last
+    let defined_class = match node.kind() {
+        Kind::GlyphClassDefNode => class_names.next().map(|index| (index, SymbolKind::Class)),
+        Kind::MarkClassNode => class_names.last().map(|index| (index, SymbolKind::MarkClass)),
+        _ => None,
+    };
+
+    for (index, child) in node.iter_children().enumerate() {
+        let token = match child {
+            NodeOrToken::Node(child) => {
+                collect_symbols(child, previous, definitions, references);
+                continue;
+            }
+            NodeOrToken::Token(token) => token,
+        };
+        if is_trivia(token.kind) {
+            continue;
+        }
+        let after = previous.replace(token.kind);
+        let (kind, defines) = match token.kind {
+            Kind::NamedGlyphClass => match defined_class {
+                Some((position, kind)) if position == index => (kind, true),
+                _ => (SymbolKind::Class, false),
+            },
+            Kind::GlyphName | Kind::GlyphNameOrRange => (SymbolKind::Glyph, false),
+            Kind::Ident if after == Some(Kind::LookupKw) => (SymbolKind::Lookup, false),
+            Kind::Label if after == Some(Kind::LookupKw) && node.kind() == Kind::LookupBlockNode => (SymbolKind::Lookup, true),
+            Kind::Tag if after == Some(Kind::FeatureKw) && node.kind() == Kind::FeatureNode => (SymbolKind::Feature, true),
+            _ => continue,
+        };
+        let range = token.range();
+        let symbol = Symbol {
+            kind,
+            name: token.as_str().trim_start_matches('@').to_string(),
+            start: range.start,
+            end: range.end,
+        };
+        if defines {
+            definitions.push(symbol);
+        } else {
+            references.push(symbol);
+        }
+    }
+}
+
+/// Whether a glyph name, or a range `a-z` of glyph names written without spaces, is in the font
+fn glyph_exists(exists: &dyn Fn(&str) -> bool, name: &str) -> bool {
+    exists(name)
+        || name
+            .match_indices('-')
+            .any(|(index, _)| exists(&name[..index]) && exists(&name[index + 1..]))
+}
+
+/// Block nodes and their type in the syntax tree
+fn block_type(kind: Kind) -> Option<&'static str> {
+    match kind {
+        Kind::FeatureNode => Some("feature"),
+        Kind::LookupBlockNode => Some("lookup"),
+        Kind::AnonBlockNode => Some("anonymous"),
+        Kind::ConditionSetNode => Some("conditionset"),
+        Kind::VariationNode => Some("variation"),
+        Kind::TableNode
+        | Kind::BaseTableNode
+        | Kind::GdefTableNode
+        | Kind::HeadTableNode
+        | Kind::HheaTableNode
+        | Kind::NameTableNode
+        | Kind::Os2TableNode
+        | Kind::StatTableNode
+        | Kind::VheaTableNode
+        | Kind::VmtxTableNode => Some("table"),
+        // `featureNames { ... };` and `cvParameters { ... };`
+        Kind::FeatureNamesKw | Kind::CvParametersKw => Some("block"),
+        _ => None,
+    }
+}
+
+/// Table of a substitution or positioning rule node
+fn rule_table(kind: Kind) -> Option<&'static str> {
+    match kind {
+        Kind::GsubNode
+        | Kind::GsubNodeNeedsRewrite
+        | Kind::GsubType1
+        | Kind::GsubType2
+        | Kind::GsubType3
+        | Kind::GsubType4
+        | Kind::GsubType5
+        | Kind::GsubType6
+        | Kind::GsubType7
+        | Kind::GsubType8
+        | Kind::GsubIgnore => Some("GSUB"),
+        Kind::GposNode
+        | Kind::GposNodeNeedsRewrite
+        | Kind::GposType1
+        | Kind::GposType2
+        | Kind::GposType3
+        | Kind::GposType4
+        | Kind::GposType5
+        | Kind::GposType6
+        | Kind::GposType7
+        | Kind::GposType8
+        | Kind::GposIgnore => Some("GPOS"),
+        _ => None,
+    }
+}
+
+/// A problem found in a snippet, with its byte range
+struct Diagnostic {
+    error: bool,
+    check: &'static str,
+    message: String,
+    start: usize,
+    end: usize,
+}
+
+impl Diagnostic {
+    /// Line, column, end line and end column (1-based, in characters)
+    fn position(&self, code: &str) -> (usize, usize, usize, usize) {
+        let (line, column) = line_column(code, self.start);
+        let (end_line, end_column) = line_column(code, self.end);
+        (line, column, end_line, end_column)
+    }
+
+    /// The diagnostic as a finding labeled with the snippet's source
+    fn to_finding(&self, snippet: &Snippet) -> Finding {
+        let (line, column, end_line, end_column) = self.position(&snippet.code);
+        let context = serde_json::json!({
+            "source": snippet.source,
+            "line": line,
+            "column": column,
+            "endLine": end_line,
+            "endColumn": end_column,
+        });
+        let message = format!("{} ({}:{}:{})", self.message, snippet.source, line, column);
+        if self.error {
+            Finding::error(self.check, message, context)
+        } else {
+            Finding::warning(self.check, message, context)
+        }
+    }
+}
+
+/// A snippet parsed with fea-rs, the parser fontc compiles with
+struct ParsedSnippet<'a> {
+    snippet: &'a Snippet,
+    tree: ParseTree,
+    diagnostics: DiagnosticSet,
+    /// Byte offset of the snippet's code in the parsed text
+    offset: usize,
+    /// Names defined and referenced in the snippet, with ranges in its code
+    definitions: Vec<Symbol>,
+    references: Vec<Symbol>,
+}
+
+impl<'a> ParsedSnippet<'a> {
+    /// Parse a snippet as part of a complete feature file
+    ///
+    /// Feature bodies are wrapped in a `feature` block and class member
+    /// lists in a class definition. Included files are not read: they are
+    /// resolved when compiling (see feature_includes), so here an include
+    /// parses as an empty file.
+    fn parse(snippet: &'a Snippet) -> Self {
+        let (prefix, suffix) = match snippet.kind {
+            SnippetKind::TopLevel => (String::new(), String::new()),
+            SnippetKind::FeatureBody => {
+                let tag = snippet
+                    .source
+                    .strip_prefix("feature:")
+                    .filter(|tag| (1..=4).contains(&tag.len()) && tag.bytes().all(|byte| byte.is_ascii_graphic()))
+                    .unwrap_or(PLACEHOLDER_TAG);
+                (format!("feature {} {{\n", tag), format!("\n}} {};\n", tag))
+            }
+            SnippetKind::ClassMembers => ("@members = [".to_string(), "\n];\n".to_string()),
+        };
+        let text: Arc<str> = format!("{}{}{}", prefix, snippet.code, suffix).into();
+        let resolver = move |path: &Path| -> Result<Arc<str>, SourceLoadError> {
+            Ok(if path == Path::new(SNIPPET_PATH) { text.clone() } else { Arc::from("") })
+        };
+        // The resolver loads every path, so the root source is always found
+        let (tree, diagnostics) = fea_rs::parse::parse_root(SNIPPET_PATH.into(), None, Box::new(resolver)).unwrap();
+
+        let mut definitions = Vec::new();
+        let mut references = Vec::new();
+        collect_symbols(tree.root(), &mut None, &mut definitions, &mut references);
+        // Names in the wrapper (the feature tag, the member list's class) are not the snippet's
+        let offset = prefix.len();
+        let end = offset + snippet.code.len();
+        let in_snippet = |symbols: Vec<Symbol>| -> Vec<Symbol> {
+            symbols
+                .into_iter()
+                .filter(|symbol| symbol.start >= offset && symbol.end <= end)
+                .map(|symbol| Symbol { start: symbol.start - offset, end: symbol.end - offset, ..symbol })
+                .collect()
+        };
+
+        ParsedSnippet {
+            snippet,
+            tree,
+            diagnostics,
+            offset,
+            definitions: in_snippet(definitions),
+            references: in_snippet(references),
+        }
+    }
+
+    /// Add the classes and lookups the snippet defines
+    fn collect_definitions(&self, classes: &mut HashSet<String>, lookups: &mut HashSet<String>) {
+        if self.snippet.kind == SnippetKind::ClassMembers {
+            if let Some(name) = self.snippet.source.strip_prefix("class:") {
+                classes.insert(name.trim_start_matches('@').to_string());
+            }
+        }
+        for symbol in &self.definitions {
+            match symbol.kind {
+                SymbolKind::Class | SymbolKind::MarkClass => {
+                    classes.insert(symbol.name.clone());
+                }
+                SymbolKind::Lookup => {
+                    lookups.insert(symbol.name.clone());
+                }
+                SymbolKind::Glyph | SymbolKind::Feature => {}
+            }
+        }
+    }
+
+    /// Byte offset in the snippet's code of an offset in the parsed text
+    fn snippet_offset(&self, offset: usize) -> usize {
+        offset.saturating_sub(self.offset).min(self.snippet.code.len())
+    }
+
+    /// The parser's syntax errors and the references to undefined names
+    fn diagnostics(&self, definitions: &Definitions) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = self
+            .diagnostics
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| {
+                let span = diagnostic.span();
+                Diagnostic {
+                    error: diagnostic.is_error(),
+                    check: "syntax",
+                    message: diagnostic.text().to_string(),
+                    start: self.snippet_offset(span.start),
+                    end: self.snippet_offset(span.end),
+                }
+            })
+            .collect();
+
+        for symbol in &self.references {
+            let (check, message) = match symbol.kind {
+                SymbolKind::Glyph if !glyph_exists(definitions.glyphs, &symbol.name) => {
+                    ("undefined-glyph", format!("Glyph '{}' is not in the font", symbol.name))
+                }
+                SymbolKind::Class if !definitions.classes.contains(&symbol.name) => {
+                    ("undefined-class", format!("Class '@{}' is not defined", symbol.name))
+                }
+                SymbolKind::Lookup if !definitions.lookups.contains(&symbol.name) => {
+                    ("undefined-lookup", format!("Lookup '{}' is not defined", symbol.name))
+                }
+                _ => continue,
+            };
+            diagnostics.push(Diagnostic { error: true, check, message, start: symbol.start, end: symbol.end });
+        }
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.start, diagnostic.end));
+        diagnostics
+    }
+
+    /// Syntax tree nodes of the blocks and statements among a node's children
+    fn ast(&self, node: &Node) -> Vec<serde_json::Value> {
+        node.iter_children()
+            .filter_map(NodeOrToken::as_node)
+            .filter_map(|child| self.ast_node(child))
+            .collect()
+    }
+
+    /// Syntax tree node of a block or statement
+    fn ast_node(&self, node: &Node) -> Option<serde_json::Value> {
+        let tokens: Vec<&fea_rs::Token> = significant_tokens(node).collect();
+        let (first, last) = (tokens.first()?, tokens.last()?);
+        let code = self.snippet.code.as_str();
+        let start = self.snippet_offset(first.range().start);
+        let end = self.snippet_offset(last.range().end);
+        let mut entry = serde_json::Map::new();
+
+        if let Some(kind) = block_type(node.kind()) {
+            let names: Vec<&fea_rs::Token> = node
+                .iter_children()
+                .filter_map(NodeOrToken::as_token)
+                .filter(|token| matches!(token.kind, Kind::Tag | Kind::Label | Kind::Ident))
+                .collect();
+            entry.insert("type".to_string(), kind.into());
+            if kind == "block" {
+                entry.insert("keyword".to_string(), first.as_str().into());
+            } else if let Some(name) = names.first() {
+                entry.insert("name".to_string(), name.as_str().into());
+            }
+            if node.kind() == Kind::LookupBlockNode {
+                let extension = node.iter_children().any(|child| child.kind() == Kind::UseExtensionKw);
+                entry.insert("useExtension".to_string(), extension.into());
+            }
+            if node.kind() == Kind::VariationNode {
+                if let Some(condition) = names.iter().find(|token| token.kind == Kind::Label) {
+                    entry.insert("conditionset".to_string(), condition.as_str().into());
+                }
+            }
+            entry.insert("children".to_string(), self.ast(node).into());
+            insert_range(&mut entry, code, start, end);
+            return Some(entry.into());
+        }
+
+        // Statement text and arguments leave out the terminating `;`
+        let statement = match tokens.split_last() {
+            Some((last, rest)) if last.kind == Kind::Semi && !rest.is_empty() => rest,
+            _ => tokens.as_slice(),
+        };
+        let texts: Vec<&str> = statement.iter().map(|token| token.as_str()).collect();
+        match node.kind() {
+            Kind::GlyphClassDefNode => {
+                let members: Vec<&str> = statement
+                    .iter()
+                    .skip_while(|token| token.kind != Kind::Eq)
+                    .skip(1)
+                    .filter(|token| !matches!(token.kind, Kind::LSquare | Kind::RSquare))
+                    .map(|token| token.as_str())
+                    .collect();
+                entry.insert("type".to_string(), "classDefinition".into());
+                entry.insert("name".to_string(), texts[0].trim_start_matches('@').into());
+                entry.insert("members".to_string(), members.into());
+            }
+            Kind::MarkClassNode => {
+                let mut glyphs = Vec::new();
+                let mut anchor = None;
+                let mut name = None;
+                for child in node.iter_children().skip_while(|child| child.kind() != Kind::MarkClassKw).skip(1) {
+                    match child {
+                        NodeOrToken::Node(child) if child.kind() == Kind::AnchorNode => {
+                            let texts: Vec<&str> = significant_tokens(child).map(|token| token.as_str()).collect();
+                            anchor = Some(texts.join(" "));
+                        }
+                        NodeOrToken::Node(child) => {
+                            glyphs.extend(
+                                significant_tokens(child)
+                                    .filter(|token| !matches!(token.kind, Kind::LSquare | Kind::RSquare))
+                                    .map(|token| token.as_str()),
+                            );
+                        }
+                        NodeOrToken::Token(token) if token.kind == Kind::NamedGlyphClass => {
+                            if anchor.is_some() {
+                                name = Some(token.as_str().trim_start_matches('@'));
+                            } else {
+                                glyphs.push(token.as_str());
+                            }
+                        }
+                        NodeOrToken::Token(token) if matches!(token.kind, Kind::GlyphName | Kind::Cid) => glyphs.push(token.as_str()),
+                        NodeOrToken::Token(_) => {}
+                    }
+                }
+                entry.insert("type".to_string(), "markClass".into());
+                if let Some(name) = name {
+                    entry.insert("name".to_string(), name.into());
+                }
+                entry.insert("glyphs".to_string(), glyphs.into());
+                if let Some(anchor) = anchor {
+                    entry.insert("anchor".to_string(), anchor.into());
+                }
+            }
+            Kind::LookupRefNode => {
+                entry.insert("type".to_string(), "lookupReference".into());
+                entry.insert("name".to_string(), texts.get(1).copied().unwrap_or_default().into());
+            }
+            kind => match rule_table(kind) {
+                Some(table) => {
+                    let mut classes: Vec<&str> = Vec::new();
+                    let mut lookups: Vec<&str> = Vec::new();
+                    for (position, token) in statement.iter().enumerate() {
+                        let text = token.as_str();
+                        if token.kind == Kind::NamedGlyphClass {
+                            let class = text.trim_start_matches('@');
+                            if !classes.contains(&class) {
+                                classes.push(class);
+                            }
+                        } else if position > 0 && statement[position - 1].kind == Kind::LookupKw && !lookups.contains(&text) {
+                            lookups.push(text);
+                        }
+                    }
+                    entry.insert("type".to_string(), "rule".into());
+                    entry.insert("keyword".to_string(), texts[0].into());
+                    entry.insert("table".to_string(), table.into());
+                    entry.insert("classes".to_string(), classes.into());
+                    entry.insert("lookups".to_string(), lookups.into());
+                }
+                None => {
+                    entry.insert("type".to_string(), "statement".into());
+                    entry.insert("keyword".to_string(), texts[0].into());
+                    entry.insert("arguments".to_string(), texts[1..].to_vec().into());
+                }
+            },
+        }
+        let text_end = self.snippet_offset(statement[statement.len() - 1].range().end);
+        entry.insert("text".to_string(), code[start..text_end].into());
+        insert_range(&mut entry, code, start, end);
+        Some(entry.into())
+    }
+}
+
+/// Validate feature code snippets without compiling
+///
+/// Snippets are parsed with fea-rs, the parser fontc compiles with, so the
+/// syntax errors are the ones a build would report. Definitions (classes,
+/// mark classes, lookups) are collected from `context` and `snippets`
+/// together, so a snippet may use a class defined in another one; findings
+/// are only reported for `snippets`.
+///
+/// Checks performed:
+/// - `syntax`: errors and warnings of the parser, such as unbalanced
+///   blocks, block names that do not match (`} lig;` closing
+///   `feature liga`), missing `;` and unknown statements
+/// - `undefined-glyph`: a rule or class uses a glyph that is not in the font
+/// - `undefined-class`: a `@class` is used but never defined
+/// - `undefined-lookup`: a `lookup NAME` reference to a missing lookup
+///
+/// Contexts have `source` (the snippet label), `line` and `column` (1-based,
+/// in characters) and `endLine`/`endColumn`.
+///
+/// # Arguments
+/// * `snippets` - Code to check
+/// * `context` - Further code whose definitions the checked code may use
+/// * `glyph_exists` - Whether a glyph name is in the font
+///
+/// # Returns
+/// * `JsonValue` - Findings report in the format of validate_compiled_font()
+pub fn validate_snippets(snippets: &[Snippet], context: &[Snippet], glyph_exists: &dyn Fn(&str) -> bool) -> serde_json::Value {
+    let parsed: Vec<ParsedSnippet> = snippets.iter().map(ParsedSnippet::parse).collect();
+    let mut classes = HashSet::new();
+    let mut lookups = HashSet::new();
+    for snippet in context {
+        ParsedSnippet::parse(snippet).collect_definitions(&mut classes, &mut lookups);
+    }
+    for snippet in &parsed {
+        snippet.collect_definitions(&mut classes, &mut lookups);
+    }
+    let definitions = Definitions { glyphs: glyph_exists, classes, lookups };
+
+    let mut findings = Vec::new();
+    for snippet in &parsed {
+        let diagnostics = snippet.diagnostics(&definitions);
+        findings.extend(diagnostics.iter().map(|diagnostic| diagnostic.to_finding(snippet.snippet)));
+    }
+    findings_report(&findings)
+}
+
+/// Add the line and column range of a byte range to a syntax tree node
+fn insert_range(node: &mut serde_json::Map<String, serde_json::Value>, code: &str, start: usize, end: usize) {
+    let (line, column) = line_column(code, start);
+    let (end_line, end_column) = line_column(code, end);
+    node.insert("line".to_string(), line.into());
+    node.insert("column".to_string(), column.into());
+    node.insert("endLine".to_string(), end_line.into());
+    node.insert("endColumn".to_string(), end_column.into());
+}
+
+/// Parse a feature file into a syntax tree
+///
+/// The tree is read from the fea-rs parse tree. It lists blocks
+/// (`feature`, `lookup`, `table`, ...) with their children and statements:
+/// class definitions, mark classes, lookup references, substitution and
+/// positioning rules (with the classes and lookups they use) and other
+/// statements with their arguments. Every node has its `text` (statements
+/// only), `line`, `column`, `endLine` and `endColumn` (1-based, in
+/// characters). Definitions are listed separately for go-to-definition, and
+/// the problems found by validate_snippets() as errors with their position
+/// and length. Glyph names are not checked.
+///
+/// # Arguments
+/// * `code` - A complete feature file
+///
+/// # Returns
+/// * `JsonValue` - '{"ast": [{"type": "feature", "name": "liga", "children": [{"type": "rule", "keyword": "sub", "table": "GSUB", "classes": [], "lookups": [], "text": "sub f i by f_i", ...}], ...}], "classes": [{"name": "Upper", "kind": "class", "line": 1, ...}], "lookups": [...], "features": [...], "errors": [{"severity": "error", "check": "syntax", "message": "Expected ';'", "line": 3, "column": 18, "endLine": 3, "endColumn": 18, "length": 0}]}'
+pub fn parse_features(code: &str) -> serde_json::Value {
+    let snippet = Snippet { source: "code".to_string(), kind: SnippetKind::TopLevel, code: code.to_string() };
+    let parsed = ParsedSnippet::parse(&snippet);
+    let ast = parsed.ast(parsed.tree.root());
+
+    let mut classes = Vec::new();
+    let mut lookups = Vec::new();
+    let mut features = Vec::new();
+    for symbol in &parsed.definitions {
+        let mut entry = serde_json::Map::new();
+        entry.insert("name".to_string(), symbol.name.as_str().into());
+        insert_range(&mut entry, code, symbol.start, symbol.end);
+        match symbol.kind {
+            SymbolKind::Lookup => lookups.push(entry),
+            SymbolKind::Feature => features.push(entry),
+            SymbolKind::Class | SymbolKind::MarkClass | SymbolKind::Glyph => {
+                let kind = if symbol.kind == SymbolKind::MarkClass { "markClass" } else { "class" };
+                entry.insert("kind".to_string(), kind.into());
+                classes.push(entry);
+            }
+        }
+    }
+
+    let mut defined_classes = HashSet::new();
+    let mut defined_lookups = HashSet::new();
+    parsed.collect_definitions(&mut defined_classes, &mut defined_lookups);
+    let any_glyph = |_: &str| true;
+    let definitions = Definitions { glyphs: &any_glyph, classes: defined_classes, lookups: defined_lookups };
+    let errors: Vec<serde_json::Value> = parsed
+        .diagnostics(&definitions)
+        .iter()
+        .map(|diagnostic| {
+            let (line, column, end_line, end_column) = diagnostic.position(code);
+            let end = diagnostic.end.min(code.len());
+            let start = diagnostic.start.min(end);
+            serde_json::json!({
+                "severity": if diagnostic.error { "error" } else { "warning" },
+                "check": diagnostic.check,
+                "message": diagnostic.message,
+                "line": line,
+                "column": column,
+                "endLine": end_line,
+                "endColumn": end_column,
+                "length": code[start..end].chars().count(),
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "ast": ast,
+        "classes": classes,
+        "lookups": lookups,
+        "features": features,
+        "errors": errors,
+    })
+}
+
+/// Lines and columns (1-based, in characters) where a snippet uses a class
+///
+/// Definitions of the class are not uses; mark classes are found as well.
+pub(crate) fn class_references(snippet: &Snippet, name: &str) -> Vec<(usize, usize)> {
+    let name = name.trim_start_matches('@');
+    ParsedSnippet::parse(snippet)
+        .references
+        .iter()
+        .filter(|symbol| symbol.kind == SymbolKind::Class && symbol.name == name)
+        .map(|symbol| line_column(&snippet.code, symbol.start))
+        .collect()
+}
+
+/// Replace glyph names in feature code
+///
+/// Words that are exactly a renamed glyph name, optionally escaped with a
+/// backslash, are replaced; comments, strings and class names are kept.
+/// Ranges (`a-z`) are rewritten when both of their ends are renamed.
+pub(crate) fn rename_glyphs(code: &str, renames: &HashMap<String, String>) -> String {
+    let tokens = tokenize(code);
+    let mut renamed = String::with_capacity(code.len());
+    let mut copied = 0;
+    for spanned in tokens {
+        let Token::Word(word) = spanned.token else {
+            continue;
+        };
+        let (escape, name) = match word.strip_prefix('\\') {
+            Some(name) => ("\\", name),
+            None => ("", word),
+        };
+        let replacement = match renames.get(name) {
+            Some(new_name) => format!("{}{}", escape, new_name),
+            None => {
+                let range = name.match_indices('-').find_map(|(index, _)| {
+                    let first = renames.get(&name[..index])?;
+                    let last = renames.get(&name[index + 1..])?;
+                    Some(format!("{}{}-{}", escape, first, last))
+                });
+                match range {
+                    Some(range) => range,
+                    None => continue,
+                }
+            }
+        };
+        renamed.push_str(&code[copied..spanned.start]);
+        renamed.push_str(&replacement);
+        copied = spanned.end;
+    }
+    renamed.push_str(&code[copied..]);
+    renamed
+}
+
+/// Split feature code into statements of plain tokens
+///
+/// Statements end at `;` and at block braces, so a block header
+/// (`feature kern`) forms a statement of its own. Words keep a leading
+/// backslash; strings are left out. Used to read simple rules, such as the
+/// kerning of exported feature files, without a full parser.
+pub(crate) fn statements(code: &str) -> Vec<Vec<&str>> {
+    let tokens = tokenize(code);
+    let mut statements = Vec::new();
+    let mut current = Vec::new();
+    for spanned in tokens {
+        match spanned.token {
+            Token::Punct(';' | '{' | '}') => {
+                if !current.is_empty() {
+                    statements.push(std::mem::take(&mut current));
+                }
+            }
+            Token::Str => {}
+            Token::Word(_) | Token::Punct(_) => current.push(&code[spanned.start..spanned.end]),
+        }
+    }
+    if !current.is_empty() {
+        statements.push(current);
+    }
+    statements
+}
@@ -0,0 +1,193 @@
+// Automatic feature module
+//
+// This module infers default features from glyph naming conventions, like
+// Glyphs does when compiling: ligatures named after their components
+// ("f_f_i") make `liga`, dotless variants ("idotless") make a `ccmp` rule
+// replacing the dotted letter before top marks, and `.numr`/`.dnom` variants
+// make `numr` and `dnom`. The generated code is feature body code, in the
+// form stored in the source, so it can be previewed or written into the font
+// and edited from there.
+
+use crate::errors::ApiError;
+
+/// Features this module can generate, in output order
+const AUTOMATIC_FEATURES: [&str; 4] = ["ccmp", "liga", "numr", "dnom"];
+
+/// Dotted letters and the names their dotless forms can have
+const DOTLESS: [(&str, [&str; 2]); 2] = [("i", ["idotless", "dotlessi"]), ("j", ["jdotless", "dotlessj"])];
+
+/// A generated feature
+struct AutomaticFeature {
+    tag: &'static str,
+    code: String,
+    rules: usize,
+}
+
+/// Exported glyph names of a font
+fn exported_glyphs(font: &babelfont::Font) -> Vec<String> {
+    font.glyphs.iter().filter(|glyph| glyph.exported).map(|glyph| glyph.name.to_string()).collect()
+}
+
+/// `liga` rules: "f_f_i" for exported "f", "f" and "i"; also "f_i.liga"
+fn ligature_rules(glyphs: &[String]) -> Vec<String> {
+    let exists = |name: &str| glyphs.iter().any(|glyph| glyph == name);
+    let mut ligatures: Vec<(Vec<&str>, &str)> = glyphs
+        .iter()
+        .filter_map(|glyph| {
+            let base = match glyph.split_once('.') {
+                None => glyph.as_str(),
+                Some((base, "liga")) => base,
+                Some(_) => return None,
+            };
+            let components: Vec<&str> = base.split('_').collect();
+            (components.len() > 1 && components.iter().all(|component| !component.is_empty() && exists(component)))
+                .then_some((components, glyph.as_str()))
+        })
+        .collect();
+    // Longer ligatures first, so "f f i" is not taken by "f f"
+    ligatures.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.1.cmp(b.1)));
+    ligatures
+        .into_iter()
+        .map(|(components, ligature)| format!("sub {} by {};", components.join(" "), ligature))
+        .collect()
+}
+
+/// Marks with a `_top` anchor in any master layer
+fn top_marks(font: &babelfont::Font) -> Vec<String> {
+    font.glyphs
+        .iter()
+        .filter(|glyph| glyph.exported)
+        .filter(|glyph| {
+            glyph.layers.iter().filter(|layer| !layer.is_background).any(|layer| {
+                layer.anchors.iter().any(|anchor| anchor.name == "_top")
+            })
+        })
+        .map(|glyph| glyph.name.to_string())
+        .collect()
+}
+
+/// `ccmp` rules replacing "i" and "j" (and their suffixed variants) by their dotless forms before top marks
+fn dotless_rules(font: &babelfont::Font, glyphs: &[String]) -> Vec<String> {
+    let marks = top_marks(font);
+    if marks.is_empty() {
+        return Vec::new();
+    }
+    let exists = |name: &str| glyphs.iter().any(|glyph| glyph == name);
+    let mut rules = vec![format!("@CombiningTopAccents = [{}];", marks.join(" "))];
+    for glyph in glyphs {
+        let (base, suffix) = match glyph.split_once('.') {
+            Some((base, suffix)) => (base, format!(".{}", suffix)),
+            None => (glyph.as_str(), String::new()),
+        };
+        let Some((_, dotless)) = DOTLESS.iter().find(|(letter, _)| *letter == base) else {
+            continue;
+        };
+        if let Some(target) = dotless.iter().map(|name| format!("{}{}", name, suffix)).find(|name| exists(name)) {
+            rules.push(format!("sub {}' @CombiningTopAccents by {};", glyph, target));
+        }
+    }
+    if rules.len() == 1 {
+        return Vec::new();
+    }
+    rules
+}
+
+/// Single substitutions to the `.suffix` variants of exported glyphs ("one" by "one.numr")
+fn suffix_rules(glyphs: &[String], suffix: &str) -> Vec<String> {
+    let exists = |name: &str| glyphs.iter().any(|glyph| glyph == name);
+    glyphs
+        .iter()
+        .filter_map(|glyph| {
+            let base = glyph.strip_suffix(suffix)?.strip_suffix('.')?;
+            exists(base).then(|| format!("sub {} by {};", base, glyph))
+        })
+        .collect()
+}
+
+/// Indent rules as a feature body
+fn feature_body(rules: &[String]) -> String {
+    rules.iter().map(|rule| format!("    {}\n", rule)).collect()
+}
+
+/// Generate the features that follow from the glyph names of a font
+///
+/// Features without rules (no ligatures, no `.numr` glyphs, ...) are left
+/// out. `ccmp` needs marks with a `_top` anchor to substitute before.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `tags` - Features to generate, from AUTOMATIC_FEATURES
+///
+/// # Returns
+/// * `Vec<AutomaticFeature>` - Generated features in the order of AUTOMATIC_FEATURES
+fn generate(font: &babelfont::Font, tags: &[&str]) -> Vec<AutomaticFeature> {
+    let glyphs = exported_glyphs(font);
+    let mut features = Vec::new();
+    for tag in AUTOMATIC_FEATURES.into_iter().filter(|tag| tags.contains(tag)) {
+        let rules = match tag {
+            "ccmp" => dotless_rules(font, &glyphs),
+            "liga" => ligature_rules(&glyphs),
+            suffix => suffix_rules(&glyphs, suffix),
+        };
+        // The class definition of ccmp is not a rule
+        let count = rules.iter().filter(|rule| rule.starts_with("sub ")).count();
+        if count > 0 {
+            features.push(AutomaticFeature { tag, code: feature_body(&rules), rules: count });
+        }
+    }
+    features
+}
+
+/// Generate automatic features and optionally write them into the font
+///
+/// Features already defined in the source are not replaced unless
+/// `replace` is set, so hand-written code always wins.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `options_json` - Optional JSON object:
+///   - `exclude`: array of strings - Features not to generate, e.g. ["liga"] (default: none)
+///   - `apply`: bool - Write the generated features into the font (default: false)
+///   - `replace`: bool - Replace features that are already in the source (default: false)
+///
+/// # Returns
+/// * `JsonValue` - Result '{"features": [{"tag": "liga", "code": "    sub f i by f_i;\n", "rules": 1, "inSource": false, "applied": true}]}'
+pub fn automatic_features(font: &mut babelfont::Font, options_json: Option<&str>) -> Result<serde_json::Value, ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let exclude: Vec<&str> = options
+        .get("exclude")
+        .and_then(|v| v.as_array())
+        .map(|tags| tags.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    if let Some(tag) = exclude.iter().find(|tag| !AUTOMATIC_FEATURES.contains(tag)) {
+        return Err(ApiError::invalid_argument("excluded feature", tag));
+    }
+    let apply = options.get("apply").and_then(|v| v.as_bool()).unwrap_or(false);
+    let replace = options.get("replace").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    let tags: Vec<&str> = AUTOMATIC_FEATURES.into_iter().filter(|tag| !exclude.contains(tag)).collect();
+    let mut report = Vec::new();
+    for feature in generate(font, &tags) {
+        let defined = font.features.features.iter().any(|(tag, _)| tag.to_string() == feature.tag);
+        let applied = apply && (!defined || replace);
+        if applied {
+            match font.features.features.iter_mut().find(|(tag, _)| tag.to_string() == feature.tag) {
+                Some((_, code)) => *code = feature.code.clone().into(),
+                None => font.features.features.push((feature.tag.into(), feature.code.clone().into())),
+            }
+        }
+        report.push(serde_json::json!({
+            "tag": feature.tag,
+            "code": feature.code,
+            "rules": feature.rules,
+            "inSource": defined,
+            "applied": applied,
+        }));
+    }
+    Ok(serde_json::json!({ "features": report }))
+}
@@ -0,0 +1,272 @@
+// Font diff module
+//
+// This module compares two source fonts for review workflows and "what
+// changed since the last save" views. Glyphs are matched by name, layers by
+// id, masters by id, axes by tag and feature code by tag or name. Glyphs and
+// axes are compared on their serialized form, so any edit shows up; the
+// report names the changed fields so the UI can say what kind of edit it
+// was. Kerning and metrics are compared value by value.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::{json, Value as JsonValue};
+
+/// Added, removed and changed keys of two keyed collections
+fn keyed_changes<V: PartialEq>(old: &BTreeMap<String, V>, new: &BTreeMap<String, V>) -> JsonValue {
+    let added: Vec<&String> = new.keys().filter(|key| !old.contains_key(*key)).collect();
+    let removed: Vec<&String> = old.keys().filter(|key| !new.contains_key(*key)).collect();
+    let changed: Vec<&String> = old
+        .iter()
+        .filter(|(key, value)| new.get(*key).is_some_and(|other| other != *value))
+        .map(|(key, _)| key)
+        .collect();
+    json!({ "added": added, "removed": removed, "changed": changed })
+}
+
+/// Whether a report of keyed_changes() is empty
+fn is_unchanged(changes: &JsonValue) -> bool {
+    ["added", "removed", "changed"]
+        .iter()
+        .all(|key| changes[key].as_array().is_none_or(|list| list.is_empty()))
+}
+
+/// Top-level fields whose values differ between two JSON objects
+fn changed_fields(old: &JsonValue, new: &JsonValue, skip: &[&str]) -> Vec<String> {
+    let empty = serde_json::Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    keys.into_iter()
+        .filter(|key| !skip.contains(&key.as_str()))
+        .filter(|key| old.get(*key) != new.get(*key))
+        .cloned()
+        .collect()
+}
+
+/// Serialized layers of a glyph, by layer id (or position for layers without one)
+fn layers_by_id(glyph: &babelfont::Glyph) -> BTreeMap<String, JsonValue> {
+    glyph
+        .layers
+        .iter()
+        .enumerate()
+        .map(|(index, layer)| {
+            let id = layer.id.clone().unwrap_or_else(|| format!("#{}", index));
+            (id, serde_json::to_value(layer).unwrap_or(JsonValue::Null))
+        })
+        .collect()
+}
+
+fn diff_glyphs(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let old_glyphs: BTreeMap<String, &babelfont::Glyph> =
+        old.glyphs.iter().map(|glyph| (glyph.name.to_string(), glyph)).collect();
+    let new_glyphs: BTreeMap<String, &babelfont::Glyph> =
+        new.glyphs.iter().map(|glyph| (glyph.name.to_string(), glyph)).collect();
+
+    let added: Vec<&String> = new_glyphs.keys().filter(|name| !old_glyphs.contains_key(*name)).collect();
+    let removed: Vec<&String> = old_glyphs.keys().filter(|name| !new_glyphs.contains_key(*name)).collect();
+    let mut changed = Vec::new();
+    for (name, old_glyph) in &old_glyphs {
+        let Some(new_glyph) = new_glyphs.get(name) else {
+            continue;
+        };
+        let old_json = serde_json::to_value(old_glyph).unwrap_or(JsonValue::Null);
+        let new_json = serde_json::to_value(new_glyph).unwrap_or(JsonValue::Null);
+        if old_json == new_json {
+            continue;
+        }
+        let layers = keyed_changes(&layers_by_id(old_glyph), &layers_by_id(new_glyph));
+        changed.push(json!({
+            "name": name,
+            "fields": changed_fields(&old_json, &new_json, &["layers"]),
+            "layers": layers,
+        }));
+    }
+    json!({ "added": added, "removed": removed, "changed": changed })
+}
+
+/// Kerning pairs of a master, as (left, right) -> value
+fn master_pairs(master: &babelfont::Master) -> BTreeMap<(String, String), f64> {
+    master
+        .kerning
+        .iter()
+        .map(|((left, right), value)| ((left.to_string(), right.to_string()), f64::from(*value)))
+        .collect()
+}
+
+/// Kerning groups of one side, as name -> members
+fn kern_groups(font: &babelfont::Font, first: bool) -> BTreeMap<String, Vec<String>> {
+    let groups = if first { &font.first_kern_groups } else { &font.second_kern_groups };
+    groups
+        .iter()
+        .map(|(name, members)| (name.to_string(), members.iter().map(|m| m.to_string()).collect()))
+        .collect()
+}
+
+/// Pairs added, removed or changed in masters present in both fonts
+fn diff_kerning(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let mut pairs = Vec::new();
+    for old_master in &old.masters {
+        let Some(new_master) = new.masters.iter().find(|master| master.id == old_master.id) else {
+            continue;
+        };
+        let old_pairs = master_pairs(old_master);
+        let new_pairs = master_pairs(new_master);
+        let sides: BTreeSet<&(String, String)> = old_pairs.keys().chain(new_pairs.keys()).collect();
+        for side in sides {
+            let (old_value, new_value) = (old_pairs.get(side), new_pairs.get(side));
+            if old_value != new_value {
+                pairs.push(json!({
+                    "master": old_master.id,
+                    "left": side.0,
+                    "right": side.1,
+                    "old": old_value,
+                    "new": new_value,
+                }));
+            }
+        }
+    }
+    json!({
+        "pairs": pairs,
+        "firstGroups": keyed_changes(&kern_groups(old, true), &kern_groups(new, true)),
+        "secondGroups": keyed_changes(&kern_groups(old, false), &kern_groups(new, false)),
+    })
+}
+
+/// Name of a metric as in the .babelfont format
+fn metric_name(metric: &babelfont::MetricType) -> String {
+    match serde_json::to_value(metric) {
+        Ok(JsonValue::String(name)) => name,
+        _ => format!("{:?}", metric),
+    }
+}
+
+/// Metric values changed, added or removed in masters present in both fonts
+fn diff_metrics(old: &babelfont::Font, new: &babelfont::Font) -> Vec<JsonValue> {
+    let metrics = |master: &babelfont::Master| -> BTreeMap<String, f64> {
+        master.metrics.iter().map(|(metric, value)| (metric_name(metric), *value as f64)).collect()
+    };
+    let mut changes = Vec::new();
+    for old_master in &old.masters {
+        let Some(new_master) = new.masters.iter().find(|master| master.id == old_master.id) else {
+            continue;
+        };
+        let (old_metrics, new_metrics) = (metrics(old_master), metrics(new_master));
+        let names: BTreeSet<&String> = old_metrics.keys().chain(new_metrics.keys()).collect();
+        for name in names {
+            let (old_value, new_value) = (old_metrics.get(name), new_metrics.get(name));
+            if old_value != new_value {
+                changes.push(json!({ "master": old_master.id, "metric": name, "old": old_value, "new": new_value }));
+            }
+        }
+    }
+    changes
+}
+
+fn diff_masters(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let masters = |font: &babelfont::Font| -> BTreeMap<String, JsonValue> {
+        font.masters
+            .iter()
+            .map(|master| {
+                // Kerning and metrics are reported on their own
+                let mut value = serde_json::to_value(master).unwrap_or(JsonValue::Null);
+                if let Some(object) = value.as_object_mut() {
+                    object.remove("kerning");
+                    object.remove("metrics");
+                }
+                (master.id.to_string(), value)
+            })
+            .collect()
+    };
+    keyed_changes(&masters(old), &masters(new))
+}
+
+fn diff_axes(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let axes = |font: &babelfont::Font| -> BTreeMap<String, JsonValue> {
+        font.axes
+            .iter()
+            .map(|axis| (axis.tag.to_string(), serde_json::to_value(axis).unwrap_or(JsonValue::Null)))
+            .collect()
+    };
+    let (old_axes, new_axes) = (axes(old), axes(new));
+    let mut changes = keyed_changes(&old_axes, &new_axes);
+    // Name the changed fields of each changed axis (e.g. "max", "map")
+    let changed: Vec<JsonValue> = changes["changed"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|tag| tag.as_str())
+        .map(|tag| json!({ "tag": tag, "fields": changed_fields(&old_axes[tag], &new_axes[tag], &[]) }))
+        .collect();
+    changes["changed"] = JsonValue::Array(changed);
+    changes
+}
+
+fn diff_features(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let features = |font: &babelfont::Font| -> BTreeMap<String, String> {
+        font.features.features.iter().map(|(tag, code)| (tag.to_string(), code.to_string())).collect()
+    };
+    let classes = |font: &babelfont::Font| -> BTreeMap<String, String> {
+        font.features.classes.iter().map(|(name, code)| (name.to_string(), code.to_string())).collect()
+    };
+    let prefixes = |font: &babelfont::Font| -> BTreeMap<String, String> {
+        font.features.prefixes.iter().map(|(name, code)| (name.to_string(), code.to_string())).collect()
+    };
+    json!({
+        "features": keyed_changes(&features(old), &features(new)),
+        "classes": keyed_changes(&classes(old), &classes(new)),
+        "prefixes": keyed_changes(&prefixes(old), &prefixes(new)),
+    })
+}
+
+/// Top-level font fields (names, dates, version, ...) that differ
+fn diff_info(old: &babelfont::Font, new: &babelfont::Font) -> Vec<String> {
+    // Everything compared in detail elsewhere is left out
+    const DETAILED: [&str; 6] = ["glyphs", "masters", "axes", "features", "first_kern_groups", "second_kern_groups"];
+    let info = |font: &babelfont::Font| -> JsonValue {
+        let mut value = serde_json::to_value(font).unwrap_or(JsonValue::Null);
+        if let Some(object) = value.as_object_mut() {
+            for key in DETAILED {
+                object.remove(key);
+            }
+        }
+        value
+    };
+    changed_fields(&info(old), &info(new), &[])
+}
+
+/// Compare two fonts
+///
+/// # Returns
+/// * `JsonValue` - Report with `identical`, `glyphs` ({added, removed, changed: [{name, fields, layers: {added, removed, changed}}]}),
+///   `kerning` ({pairs: [{master, left, right, old, new}], firstGroups, secondGroups}), `metrics` ([{master, metric, old, new}]),
+///   `masters`, `axes` ({added, removed, changed: [{tag, fields}]}), `features` ({features, classes, prefixes}) and `info` (changed font fields)
+pub fn diff_fonts(old: &babelfont::Font, new: &babelfont::Font) -> JsonValue {
+    let glyphs = diff_glyphs(old, new);
+    let kerning = diff_kerning(old, new);
+    let metrics = diff_metrics(old, new);
+    let masters = diff_masters(old, new);
+    let axes = diff_axes(old, new);
+    let features = diff_features(old, new);
+    let info = diff_info(old, new);
+
+    let identical = is_unchanged(&glyphs)
+        && kerning["pairs"].as_array().is_none_or(|pairs| pairs.is_empty())
+        && is_unchanged(&kerning["firstGroups"])
+        && is_unchanged(&kerning["secondGroups"])
+        && metrics.is_empty()
+        && is_unchanged(&masters)
+        && is_unchanged(&axes)
+        && ["features", "classes", "prefixes"].iter().all(|key| is_unchanged(&features[key]))
+        && info.is_empty();
+
+    json!({
+        "identical": identical,
+        "glyphs": glyphs,
+        "kerning": kerning,
+        "metrics": metrics,
+        "masters": masters,
+        "axes": axes,
+        "features": features,
+        "info": info,
+    })
+}
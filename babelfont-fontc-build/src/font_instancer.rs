@@ -0,0 +1,715 @@
+// Font instancing module
+//
+// This module makes static fonts from a compiled variable font, so users
+// can derive statics from a finished binary in the browser. Glyph outlines
+// get their gvar deltas at the location (with inferred deltas for untouched
+// points), advance widths come from HVAR or the phantom points, MVAR
+// deltas go into OS/2, hhea and post, and GPOS values and anchors get
+// their deltas from the GDEF variation store. The variation tables are
+// dropped. Only TrueType outlines are supported; CFF2 fonts are rejected.
+//
+// Axes are always pinned. Keeping some axes variable or narrowing their
+// ranges would mean rewriting the regions of every variation table (gvar,
+// HVAR, MVAR, GDEF); avar alone cannot do it, as it must keep mapping
+// -1, 0 and 1 to themselves.
+
+use std::collections::{BTreeMap, HashMap};
+
+use read_fonts::tables::glyf::{Anchor as ReadAnchor, CompositeGlyphFlags, Glyph as ReadGlyph};
+use read_fonts::tables::variations::{DeltaSetIndex, ItemVariationStore};
+use read_fonts::types::{F2Dot14, GlyphId, GlyphId16, Tag};
+use read_fonts::{FontRef, TableProvider};
+use serde_json::Value as JsonValue;
+use skrifa::instance::Size;
+use skrifa::MetadataProvider;
+use wasm_bindgen::prelude::*;
+use write_fonts::from_obj::ToOwnedTable;
+use write_fonts::tables::glyf::{
+    Anchor, Bbox, Component, ComponentFlags, CompositeGlyph, Contour, CurvePoint, GlyfLocaBuilder, Glyph, SimpleGlyph,
+    Transform,
+};
+use write_fonts::tables::gpos::{AnchorTable, Gpos, PairPos, PositionLookup, SinglePos, ValueRecord};
+use write_fonts::tables::hmtx::{Hmtx, LongMetric};
+use write_fonts::tables::layout::DeviceOrVariationIndex;
+use write_fonts::tables::loca::LocaFormat;
+use write_fonts::types::FWord;
+use write_fonts::{FontBuilder, NullableOffsetMarker};
+
+use crate::errors::ApiError;
+use crate::logging;
+
+/// Tables that only describe variations, dropped from static instances
+const VARIATION_TABLES: [&[u8; 4]; 7] = [b"fvar", b"avar", b"gvar", b"cvar", b"HVAR", b"VVAR", b"MVAR"];
+
+/// Parse the location argument into user space values by axis tag
+///
+/// Axes that are not mentioned are pinned at their default.
+fn axis_pins(font: &FontRef, location_json: &str) -> Result<BTreeMap<Tag, f32>, ApiError> {
+    let fvar = font.fvar().map_err(|e| ApiError::missing_table("fvar", e))?;
+    let axes = fvar.axes().map_err(|e| ApiError::missing_table("fvar", e))?;
+    let location_json = location_json.trim();
+    let location: serde_json::Map<String, JsonValue> = if location_json.is_empty() {
+        serde_json::Map::new()
+    } else {
+        serde_json::from_str(location_json).map_err(ApiError::invalid_location)?
+    };
+
+    let mut pins = BTreeMap::new();
+    for (tag, value) in &location {
+        let axis = Tag::new_checked(tag.as_bytes())
+            .ok()
+            .and_then(|tag| axes.iter().find(|axis| axis.axis_tag() == tag))
+            .ok_or_else(|| ApiError::invalid_location(format!("The font has no axis '{}'", tag)))?;
+        let value = match value {
+            JsonValue::Number(n) => n.as_f64().unwrap_or_default() as f32,
+            JsonValue::Array(_) => {
+                return Err(ApiError::Unsupported {
+                    format: "fvar".to_string(),
+                    detail: format!("Limiting the range of '{}' is not supported; give a single value to pin it", tag),
+                })
+            }
+            other => return Err(ApiError::invalid_location(format!("Expected a number for '{}', got {}", tag, other))),
+        };
+        let (min, max) = (axis.min_value().to_f32(), axis.max_value().to_f32());
+        if value < min || value > max {
+            return Err(ApiError::invalid_location(format!("{} is outside the range {}..{} of '{}'", value, min, max, tag)));
+        }
+        pins.insert(axis.axis_tag(), value);
+    }
+    for axis in axes.iter() {
+        pins.entry(axis.axis_tag()).or_insert(axis.default_value().to_f32());
+    }
+    Ok(pins)
+}
+
+/// Deltas of a glyph's points at a location, phantom points included
+///
+/// Points a tuple leaves out get inferred deltas (IUP) for simple glyphs
+/// and none for composites.
+fn point_deltas(
+    gvar: &read_fonts::tables::gvar::Gvar,
+    gid: GlyphId,
+    coords: &[F2Dot14],
+    original: &[(f64, f64)],
+    contour_ends: &[usize],
+) -> Result<Vec<(f64, f64)>, ApiError> {
+    let count = original.len() + 4;
+    let mut total = vec![(0.0, 0.0); count];
+    let Some(data) = gvar.glyph_variation_data(gid).map_err(|e| ApiError::missing_table("gvar", e))? else {
+        return Ok(total);
+    };
+    for tuple in data.tuples() {
+        let Some(scalar) = tuple.compute_scalar(coords) else {
+            continue;
+        };
+        let scalar = scalar.to_f64();
+        if scalar == 0.0 {
+            continue;
+        }
+        let mut deltas: Vec<Option<(f64, f64)>> = vec![None; count];
+        for delta in tuple.deltas() {
+            if let Some(slot) = deltas.get_mut(delta.position as usize) {
+                *slot = Some((delta.x_delta as f64, delta.y_delta as f64));
+            }
+        }
+        let mut start = 0;
+        for &end in contour_ends {
+            infer_deltas(&original[start..=end], &mut deltas[start..=end]);
+            start = end + 1;
+        }
+        for (sum, delta) in total.iter_mut().zip(deltas) {
+            let (dx, dy) = delta.unwrap_or_default();
+            sum.0 += dx * scalar;
+            sum.1 += dy * scalar;
+        }
+    }
+    Ok(total)
+}
+
+/// Fill in the deltas of untouched points of a contour (IUP)
+///
+/// Each untouched point takes its delta from the touched points before and
+/// after it, per coordinate: interpolated if it lies between them,
+/// otherwise the delta of the nearer one.
+fn infer_deltas(points: &[(f64, f64)], deltas: &mut [Option<(f64, f64)>]) {
+    let touched: Vec<usize> = (0..points.len()).filter(|&index| deltas[index].is_some()).collect();
+    match touched.as_slice() {
+        [] => return,
+        [only] => {
+            let delta = deltas[*only];
+            deltas.iter_mut().for_each(|slot| *slot = delta);
+            return;
+        }
+        _ => {}
+    }
+    let interpolate = |value: f64, (a, da): (f64, f64), (b, db): (f64, f64)| -> f64 {
+        if a == b {
+            return if da == db { da } else { 0.0 };
+        }
+        let ((low, dlow), (high, dhigh)) = if a < b { ((a, da), (b, db)) } else { ((b, db), (a, da)) };
+        if value <= low {
+            dlow
+        } else if value >= high {
+            dhigh
+        } else {
+            dlow + (value - low) * (dhigh - dlow) / (high - low)
+        }
+    };
+    for (position, &previous) in touched.iter().enumerate() {
+        let next = touched[(position + 1) % touched.len()];
+        let (Some(previous_delta), Some(next_delta)) = (deltas[previous], deltas[next]) else {
+            continue;
+        };
+        let mut index = (previous + 1) % points.len();
+        while index != next {
+            let (x, y) = points[index];
+            let dx = interpolate(x, (points[previous].0, previous_delta.0), (points[next].0, next_delta.0));
+            let dy = interpolate(y, (points[previous].1, previous_delta.1), (points[next].1, next_delta.1));
+            deltas[index] = Some((dx, dy));
+            index = (index + 1) % points.len();
+        }
+    }
+}
+
+/// A glyph at the pinned location, with its points in font units for bounds
+struct InstancedGlyph {
+    glyph: Glyph,
+    points: Vec<(f64, f64)>,
+}
+
+fn bounds(points: &[(f64, f64)]) -> Option<Bbox> {
+    let first = points.first()?;
+    let (mut x_min, mut y_min, mut x_max, mut y_max) = (first.0, first.1, first.0, first.1);
+    for &(x, y) in points {
+        x_min = x_min.min(x);
+        y_min = y_min.min(y);
+        x_max = x_max.max(x);
+        y_max = y_max.max(y);
+    }
+    Some(Bbox {
+        x_min: x_min.round() as i16,
+        y_min: y_min.round() as i16,
+        x_max: x_max.round() as i16,
+        y_max: y_max.round() as i16,
+    })
+}
+
+/// Glyph outlines at a location, by glyph id
+///
+/// Composites are instanced after their components, so their bounds can be
+/// taken from the instanced component points.
+struct OutlineInstancer<'a> {
+    glyf: read_fonts::tables::glyf::Glyf<'a>,
+    loca: read_fonts::tables::loca::Loca<'a>,
+    gvar: Option<read_fonts::tables::gvar::Gvar<'a>>,
+    coords: &'a [F2Dot14],
+    done: HashMap<u32, InstancedGlyph>,
+}
+
+impl<'a> OutlineInstancer<'a> {
+    fn new(font: &'a FontRef<'a>, coords: &'a [F2Dot14]) -> Result<Self, ApiError> {
+        Ok(OutlineInstancer {
+            glyf: font.glyf().map_err(|e| ApiError::missing_table("glyf", e))?,
+            loca: font.loca(None).map_err(|e| ApiError::missing_table("loca", e))?,
+            gvar: font.gvar().ok(),
+            coords,
+            done: HashMap::new(),
+        })
+    }
+
+    fn deltas(&self, gid: u32, original: &[(f64, f64)], contour_ends: &[usize]) -> Result<Vec<(f64, f64)>, ApiError> {
+        match &self.gvar {
+            Some(gvar) => point_deltas(gvar, GlyphId::new(gid), self.coords, original, contour_ends),
+            None => Ok(vec![(0.0, 0.0); original.len() + 4]),
+        }
+    }
+
+    /// Instance a glyph and, first, the glyphs it uses as components
+    fn instance(&mut self, gid: u32, depth: usize) -> Result<(), ApiError> {
+        if self.done.contains_key(&gid) {
+            return Ok(());
+        }
+        if depth > 64 {
+            return Err(ApiError::invalid_font(format!("Component nesting too deep at glyph {}", gid)));
+        }
+        let read_glyph = self
+            .loca
+            .get_glyf(GlyphId::new(gid), &self.glyf)
+            .map_err(|e| ApiError::missing_table("glyf", e))?;
+        let instanced = match read_glyph {
+            None => InstancedGlyph { glyph: Glyph::Empty, points: Vec::new() },
+            Some(ReadGlyph::Simple(simple)) => {
+                let original: Vec<(f64, f64)> = simple.points().map(|point| (point.x as f64, point.y as f64)).collect();
+                let contour_ends: Vec<usize> = simple.end_pts_of_contours().iter().map(|end| end.get() as usize).collect();
+                let on_curve: Vec<bool> = simple.points().map(|point| point.on_curve).collect();
+                if contour_ends.last().is_some_and(|&end| end >= original.len()) {
+                    return Err(ApiError::invalid_font(format!("Glyph {} has contours past its points", gid)));
+                }
+                let deltas = self.deltas(gid, &original, &contour_ends)?;
+                let points: Vec<(f64, f64)> = original
+                    .iter()
+                    .zip(&deltas)
+                    .map(|((x, y), (dx, dy))| ((x + dx).round(), (y + dy).round()))
+                    .collect();
+                let mut contours = Vec::with_capacity(contour_ends.len());
+                let mut start = 0;
+                for &end in &contour_ends {
+                    let contour: Vec<CurvePoint> = (start..=end)
+                        .map(|index| CurvePoint::new(points[index].0 as i16, points[index].1 as i16, on_curve[index]))
+                        .collect();
+                    contours.push(Contour::from(contour));
+                    start = end + 1;
+                }
+                let glyph = SimpleGlyph {
+                    bbox: bounds(&points).unwrap_or_default(),
+                    contours,
+                    instructions: simple.instructions().to_vec(),
+                };
+                InstancedGlyph { glyph: Glyph::Simple(glyph), points }
+            }
+            Some(ReadGlyph::Composite(composite)) => {
+                let components: Vec<_> = composite.components().collect();
+                let original: Vec<(f64, f64)> = components
+                    .iter()
+                    .map(|component| match component.anchor {
+                        ReadAnchor::Offset { x, y } => (x as f64, y as f64),
+                        ReadAnchor::Point { .. } => (0.0, 0.0),
+                    })
+                    .collect();
+                let deltas = self.deltas(gid, &original, &[])?;
+
+                let mut points: Vec<(f64, f64)> = Vec::new();
+                let mut instanced_components = Vec::with_capacity(components.len());
+                for (index, component) in components.iter().enumerate() {
+                    let child = component.glyph.to_u32();
+                    self.instance(child, depth + 1)?;
+                    let child_points = &self.done[&child].points;
+                    let transform = &component.transform;
+                    let scale = |value: F2Dot14| value.to_f32() as f64;
+                    let (xx, yx, xy, yy) = (scale(transform.xx), scale(transform.yx), scale(transform.xy), scale(transform.yy));
+                    let transformed: Vec<(f64, f64)> =
+                        child_points.iter().map(|(x, y)| (xx * x + xy * y, yx * x + yy * y)).collect();
+                    let (anchor, offset) = match component.anchor {
+                        ReadAnchor::Offset { .. } => {
+                            let x = (original[index].0 + deltas[index].0).round();
+                            let y = (original[index].1 + deltas[index].1).round();
+                            (Anchor::Offset { x: x as i16, y: y as i16 }, (x, y))
+                        }
+                        ReadAnchor::Point { base, component: point } => {
+                            // Aligned points move with the outlines, so the offset follows them
+                            let base_point = points.get(base as usize).copied().unwrap_or_default();
+                            let own_point = transformed.get(point as usize).copied().unwrap_or_default();
+                            (
+                                Anchor::Point { base, component: point },
+                                (base_point.0 - own_point.0, base_point.1 - own_point.1),
+                            )
+                        }
+                    };
+                    points.extend(transformed.iter().map(|(x, y)| (x + offset.0, y + offset.1)));
+
+                    let flags = component.flags;
+                    let component_flags = ComponentFlags {
+                        round_xy_to_grid: flags.contains(CompositeGlyphFlags::ROUND_XY_TO_GRID),
+                        use_my_metrics: flags.contains(CompositeGlyphFlags::USE_MY_METRICS),
+                        scaled_component_offset: flags.contains(CompositeGlyphFlags::SCALED_COMPONENT_OFFSET),
+                        unscaled_component_offset: flags.contains(CompositeGlyphFlags::UNSCALED_COMPONENT_OFFSET),
+                        overlap_compound: flags.contains(CompositeGlyphFlags::OVERLAP_COMPOUND),
+                    };
+                    let transform = Transform { xx: transform.xx, yx: transform.yx, xy: transform.xy, yy: transform.yy };
+                    instanced_components.push(Component::new(GlyphId16::new(child as u16), anchor, transform, component_flags));
+                }
+
+                let bbox = bounds(&points).unwrap_or_default();
+                let mut instanced_components = instanced_components.into_iter();
+                let glyph = match instanced_components.next() {
+                    Some(first) => {
+                        let mut glyph = CompositeGlyph::new(first, bbox);
+                        for component in instanced_components {
+                            glyph.add_component(component, bbox);
+                        }
+                        Glyph::Composite(glyph)
+                    }
+                    None => Glyph::Empty,
+                };
+                InstancedGlyph { glyph, points }
+            }
+        };
+        self.done.insert(gid, instanced);
+        Ok(())
+    }
+}
+
+/// Write MVAR deltas into the OS/2, hhea and post tables
+fn apply_metric_deltas(
+    font: &FontRef,
+    coords: &[F2Dot14],
+    os2: &mut Option<write_fonts::tables::os2::Os2>,
+    hhea: &mut write_fonts::tables::hhea::Hhea,
+    post: &mut Option<write_fonts::tables::post::Post>,
+) {
+    let Ok(mvar) = font.mvar() else {
+        return;
+    };
+    let delta = |tag: &[u8; 4]| -> i16 {
+        mvar.metric_delta(Tag::new(tag), coords).map(|delta| delta.to_f64().round() as i16).unwrap_or(0)
+    };
+    if let Some(os2) = os2.as_mut() {
+        os2.s_typo_ascender += delta(b"hasc");
+        os2.s_typo_descender += delta(b"hdsc");
+        os2.s_typo_line_gap += delta(b"hlgp");
+        os2.us_win_ascent = (os2.us_win_ascent as i32 + delta(b"hcla") as i32).max(0) as u16;
+        os2.us_win_descent = (os2.us_win_descent as i32 + delta(b"hcld") as i32).max(0) as u16;
+        if let Some(x_height) = os2.sx_height.as_mut() {
+            *x_height += delta(b"xhgt");
+        }
+        if let Some(cap_height) = os2.s_cap_height.as_mut() {
+            *cap_height += delta(b"cpht");
+        }
+        os2.y_subscript_x_size += delta(b"sbxs");
+        os2.y_subscript_y_size += delta(b"sbys");
+        os2.y_subscript_x_offset += delta(b"sbxo");
+        os2.y_subscript_y_offset += delta(b"sbyo");
+        os2.y_superscript_x_size += delta(b"spxs");
+        os2.y_superscript_y_size += delta(b"spys");
+        os2.y_superscript_x_offset += delta(b"spxo");
+        os2.y_superscript_y_offset += delta(b"spyo");
+        os2.y_strikeout_size += delta(b"strs");
+        os2.y_strikeout_position += delta(b"stro");
+    }
+    hhea.caret_slope_rise += delta(b"hcrs");
+    hhea.caret_slope_run += delta(b"hcrn");
+    hhea.caret_offset += delta(b"hcof");
+    if let Some(post) = post.as_mut() {
+        post.underline_position = FWord::new(post.underline_position.to_i16() + delta(b"undo"));
+        post.underline_thickness = FWord::new(post.underline_thickness.to_i16() + delta(b"unds"));
+    }
+}
+
+/// OS/2 width class of a wdth value (percent of normal width)
+fn width_class(width: f32) -> u16 {
+    const WIDTHS: [f32; 9] = [50.0, 62.5, 75.0, 87.5, 100.0, 112.5, 125.0, 150.0, 200.0];
+    WIDTHS
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - width).abs().total_cmp(&(*b - width).abs()))
+        .map(|(index, _)| index as u16 + 1)
+        .unwrap_or(5)
+}
+
+/// Apply the variation deltas of GPOS values and anchors
+///
+/// Device tables referring to the GDEF variation store are replaced by their
+/// delta at the location; classic hinting device tables are left alone.
+struct PositionInstancer<'a> {
+    store: ItemVariationStore<'a>,
+    coords: &'a [F2Dot14],
+}
+
+impl PositionInstancer<'_> {
+    /// Delta of a variation index device table, or None for other device tables
+    fn delta(&self, device: &NullableOffsetMarker<DeviceOrVariationIndex>) -> Option<i16> {
+        match device.as_ref()? {
+            DeviceOrVariationIndex::VariationIndex(index) => {
+                let index = DeltaSetIndex { outer: index.delta_set_outer_index, inner: index.delta_set_inner_index };
+                Some(self.store.compute_delta(index, self.coords).unwrap_or(0) as i16)
+            }
+            DeviceOrVariationIndex::Device(_) => None,
+        }
+    }
+
+    fn value_record(&self, record: &mut ValueRecord) {
+        let fields = [
+            (&mut record.x_placement, &mut record.x_placement_device),
+            (&mut record.y_placement, &mut record.y_placement_device),
+            (&mut record.x_advance, &mut record.x_advance_device),
+            (&mut record.y_advance, &mut record.y_advance_device),
+        ];
+        for (value, device) in fields {
+            if let Some(delta) = self.delta(device) {
+                if delta != 0 || value.is_some() {
+                    *value = Some(value.unwrap_or(0) + delta);
+                }
+                *device = NullableOffsetMarker::new(None);
+            }
+        }
+    }
+
+    fn anchor(&self, anchor: &mut AnchorTable) {
+        if let AnchorTable::Format3(format3) = anchor {
+            let dx = self.delta(&format3.x_device);
+            let dy = self.delta(&format3.y_device);
+            if dx.is_some() || dy.is_some() {
+                *anchor = AnchorTable::format_1(
+                    format3.x_coordinate + dx.unwrap_or(0),
+                    format3.y_coordinate + dy.unwrap_or(0),
+                );
+            }
+        }
+    }
+
+    fn anchors<'b>(&self, anchors: impl Iterator<Item = &'b mut NullableOffsetMarker<AnchorTable>>) {
+        for anchor in anchors {
+            if let Some(anchor) = anchor.as_mut() {
+                self.anchor(anchor);
+            }
+        }
+    }
+
+    /// Apply the deltas to every positioning lookup
+    ///
+    /// Lookups wrapped in extension subtables are left as they are.
+    fn apply(&self, gpos: &mut Gpos) {
+        for lookup in gpos.lookup_list.lookups.iter_mut() {
+            match &mut **lookup {
+                PositionLookup::Single(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        match &mut **subtable {
+                            SinglePos::Format1(format1) => self.value_record(&mut format1.value_record),
+                            SinglePos::Format2(format2) => {
+                                format2.value_records.iter_mut().for_each(|record| self.value_record(record))
+                            }
+                        }
+                    }
+                }
+                PositionLookup::Pair(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        match &mut **subtable {
+                            PairPos::Format1(format1) => {
+                                for pair_set in format1.pair_sets.iter_mut() {
+                                    for record in pair_set.pair_value_records.iter_mut() {
+                                        self.value_record(&mut record.value_record1);
+                                        self.value_record(&mut record.value_record2);
+                                    }
+                                }
+                            }
+                            PairPos::Format2(format2) => {
+                                for class1 in format2.class1_records.iter_mut() {
+                                    for record in class1.class2_records.iter_mut() {
+                                        self.value_record(&mut record.value_record1);
+                                        self.value_record(&mut record.value_record2);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                PositionLookup::Cursive(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        for record in subtable.entry_exit_record.iter_mut() {
+                            self.anchors([&mut record.entry_anchor, &mut record.exit_anchor].into_iter());
+                        }
+                    }
+                }
+                PositionLookup::MarkToBase(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        for record in subtable.mark_array.mark_records.iter_mut() {
+                            self.anchor(&mut record.mark_anchor);
+                        }
+                        for record in subtable.base_array.base_records.iter_mut() {
+                            self.anchors(record.base_anchors.iter_mut());
+                        }
+                    }
+                }
+                PositionLookup::MarkToLig(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        for record in subtable.mark_array.mark_records.iter_mut() {
+                            self.anchor(&mut record.mark_anchor);
+                        }
+                        for attach in subtable.ligature_array.ligature_attaches.iter_mut() {
+                            for record in attach.component_records.iter_mut() {
+                                self.anchors(record.ligature_anchors.iter_mut());
+                            }
+                        }
+                    }
+                }
+                PositionLookup::MarkToMark(lookup) => {
+                    for subtable in lookup.subtables.iter_mut() {
+                        for record in subtable.mark1_array.mark_records.iter_mut() {
+                            self.anchor(&mut record.mark_anchor);
+                        }
+                        for record in subtable.mark2_array.mark2_records.iter_mut() {
+                            self.anchors(record.mark2_anchors.iter_mut());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn table_error(table: &str, error: impl std::fmt::Debug) -> ApiError {
+    ApiError::compilation(&format!("Writing {}", table), format!("{:?}", error))
+}
+
+/// Make a static font at a location given by user space values
+fn pin_font(font_bytes: &[u8], font: &FontRef, pins: &BTreeMap<Tag, f32>, update_classes: bool) -> Result<Vec<u8>, ApiError> {
+    if font.table_data(Tag::new(b"CFF2")).is_some() {
+        return Err(ApiError::Unsupported {
+            format: "CFF2".to_string(),
+            detail: "Only variable fonts with TrueType outlines can be instanced".to_string(),
+        });
+    }
+    let skrifa_font = skrifa::FontRef::new(font_bytes).map_err(ApiError::invalid_font)?;
+    let tags: Vec<(String, f32)> = pins.iter().map(|(tag, value)| (tag.to_string(), *value)).collect();
+    let location = skrifa_font.axes().location(tags.iter().map(|(tag, value)| (tag.as_str(), *value)));
+    // Normalized coordinates, avar applied
+    let coords: Vec<F2Dot14> = location.coords().iter().map(|coord| F2Dot14::from_bits(coord.to_bits())).collect();
+
+    let glyph_count = font.maxp().map_err(|e| ApiError::missing_table("maxp", e))?.num_glyphs() as u32;
+    let mut outlines = OutlineInstancer::new(font, &coords)?;
+    for gid in 0..glyph_count {
+        outlines.instance(gid, 0)?;
+    }
+
+    let glyph_metrics = skrifa_font.glyph_metrics(Size::unscaled(), &location);
+    let mut builder = GlyfLocaBuilder::new();
+    let mut h_metrics = Vec::with_capacity(glyph_count as usize);
+    let mut font_bounds: Option<Bbox> = None;
+    let (mut advance_max, mut min_lsb, mut min_rsb, mut max_extent) = (0u16, i16::MAX, i16::MAX, i16::MIN);
+    for gid in 0..glyph_count {
+        let instanced = &outlines.done[&gid];
+        builder
+            .add_glyph(&instanced.glyph)
+            .map_err(|e| table_error("glyf", e))?;
+        let advance = glyph_metrics.advance_width(GlyphId::new(gid)).unwrap_or(0.0).round().max(0.0) as u16;
+        advance_max = advance_max.max(advance);
+        let lsb = match bounds(&instanced.points) {
+            Some(bbox) => {
+                min_lsb = min_lsb.min(bbox.x_min);
+                min_rsb = min_rsb.min(advance as i16 - bbox.x_max);
+                max_extent = max_extent.max(bbox.x_max);
+                font_bounds = Some(match font_bounds {
+                    Some(all) => Bbox {
+                        x_min: all.x_min.min(bbox.x_min),
+                        y_min: all.y_min.min(bbox.y_min),
+                        x_max: all.x_max.max(bbox.x_max),
+                        y_max: all.y_max.max(bbox.y_max),
+                    },
+                    None => bbox,
+                });
+                bbox.x_min
+            }
+            None => 0,
+        };
+        h_metrics.push(LongMetric::new(advance, lsb));
+    }
+    let (glyf, loca, loca_format) = builder.build();
+
+    let mut head: write_fonts::tables::head::Head =
+        font.head().map_err(|e| ApiError::missing_table("head", e))?.to_owned_table();
+    head.index_to_loc_format = match loca_format {
+        LocaFormat::Short => 0,
+        LocaFormat::Long => 1,
+    };
+    if let Some(bbox) = font_bounds {
+        (head.x_min, head.y_min, head.x_max, head.y_max) = (bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max);
+    }
+
+    let mut hhea: write_fonts::tables::hhea::Hhea =
+        font.hhea().map_err(|e| ApiError::missing_table("hhea", e))?.to_owned_table();
+    hhea.number_of_h_metrics = glyph_count as u16;
+    hhea.advance_width_max = advance_max.into();
+    if font_bounds.is_some() {
+        hhea.min_left_side_bearing = FWord::new(min_lsb);
+        hhea.min_right_side_bearing = FWord::new(min_rsb);
+        hhea.x_max_extent = FWord::new(max_extent);
+    }
+    let hmtx = Hmtx::new(h_metrics, Vec::new());
+
+    let mut os2: Option<write_fonts::tables::os2::Os2> = font.os2().ok().map(|os2| os2.to_owned_table());
+    let mut post: Option<write_fonts::tables::post::Post> = font.post().ok().map(|post| post.to_owned_table());
+    apply_metric_deltas(font, &coords, &mut os2, &mut hhea, &mut post);
+    if let Some(os2) = os2.as_mut().filter(|_| update_classes) {
+        if let Some(weight) = pins.get(&Tag::new(b"wght")) {
+            os2.us_weight_class = weight.round().clamp(1.0, 1000.0) as u16;
+        }
+        if let Some(width) = pins.get(&Tag::new(b"wdth")) {
+            os2.us_width_class = width_class(*width);
+        }
+    }
+
+    let gpos = match (font.gpos(), font.gdef().ok().and_then(|gdef| gdef.item_var_store())) {
+        (Ok(gpos), Some(Ok(store))) => {
+            let mut gpos: Gpos = gpos.to_owned_table();
+            PositionInstancer { store, coords: &coords }.apply(&mut gpos);
+            Some(gpos)
+        }
+        _ => None,
+    };
+
+    let mut font_builder = FontBuilder::new();
+    font_builder.add_table(&glyf).map_err(|e| table_error("glyf", e))?;
+    font_builder.add_table(&loca).map_err(|e| table_error("loca", e))?;
+    font_builder.add_table(&head).map_err(|e| table_error("head", e))?;
+    font_builder.add_table(&hhea).map_err(|e| table_error("hhea", e))?;
+    font_builder.add_table(&hmtx).map_err(|e| table_error("hmtx", e))?;
+    if let Some(os2) = &os2 {
+        font_builder.add_table(os2).map_err(|e| table_error("OS/2", e))?;
+    }
+    if let Some(post) = &post {
+        font_builder.add_table(post).map_err(|e| table_error("post", e))?;
+    }
+    if let Some(gpos) = &gpos {
+        font_builder.add_table(gpos).map_err(|e| table_error("GPOS", e))?;
+    }
+    for record in font.table_directory.table_records() {
+        let tag = record.tag();
+        if VARIATION_TABLES.iter().any(|dropped| Tag::new(dropped) == tag) || font_builder.contains(tag) {
+            continue;
+        }
+        if let Some(data) = font.table_data(tag) {
+            font_builder.add_raw(tag, data.as_bytes());
+        }
+    }
+    Ok(font_builder.build())
+}
+
+/// Make a static font at a location of a compiled variable font
+///
+/// Outlines get their gvar deltas, advance widths come from HVAR or gvar,
+/// MVAR deltas go into OS/2, hhea and post, GPOS values and anchors get
+/// their variation deltas, and the variation tables (fvar, avar, gvar,
+/// cvar, HVAR, VVAR, MVAR) are dropped; STAT stays. Names are not
+/// changed. GSUB feature variations (e.g. bracket glyph swaps) are not
+/// applied. Only fonts with TrueType outlines are supported.
+///
+/// Every axis is pinned; limiting axis ranges is not supported.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled variable TTF font bytes
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 700}'. Axes left out are pinned at their default.
+/// * `options_json` - Optional JSON object:
+///   - `updateClasses`: bool - Set usWeightClass and usWidthClass from the wght and wdth values (default: true)
+///
+/// # Returns
+/// * `Vec<u8>` - The static font
+#[wasm_bindgen]
+pub fn instance_compiled_font(font_bytes: &[u8], location_json: &str, options_json: Option<String>) -> Result<Vec<u8>, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let options_json = options_json.as_deref().map(str::trim).unwrap_or("");
+    let options: JsonValue = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("options_json", e))?
+    };
+    let update_classes = options.get("updateClasses").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    let pins = axis_pins(&font, location_json)?;
+    let started = js_sys::Date::now();
+    let instanced = pin_font(font_bytes, &font, &pins, update_classes)?;
+
+    logging::info(
+        "instance",
+        &format!("Instanced font: {} -> {} bytes", font_bytes.len(), instanced.len()),
+        serde_json::json!({
+            "location": pins.iter().map(|(tag, value)| (tag.to_string(), *value)).collect::<BTreeMap<_, _>>(),
+            "bytes": instanced.len(),
+            "ms": js_sys::Date::now() - started,
+        }),
+    );
+    Ok(instanced)
+}
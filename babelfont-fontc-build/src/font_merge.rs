@@ -0,0 +1,422 @@
+// Font merge module
+//
+// This module copies glyphs from one source font into another, for
+// assembling families from shared component libraries: the requested
+// glyphs come with the glyphs they use as components (nested), their
+// layers with anchors, and the kerning between copied glyphs. Masters are
+// matched by id, then by design location; layers of source masters without
+// a match are left out. Glyphs that already exist in the target are
+// skipped, overwritten or copied under a new name, and references to
+// renamed glyphs follow them.
+
+use babelfont::{Layer, LayerType, Shape};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::errors::ApiError;
+
+/// What to do with a copied glyph whose name exists in the target font
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conflict {
+    /// Keep the target's glyph; copied composites use it
+    Skip,
+    /// Replace the target's glyph
+    Overwrite,
+    /// Copy the glyph under its name with a suffix
+    Rename,
+}
+
+impl Conflict {
+    pub fn from_name(name: &str) -> Result<Self, ApiError> {
+        match name {
+            "skip" => Ok(Conflict::Skip),
+            "overwrite" => Ok(Conflict::Overwrite),
+            "rename" => Ok(Conflict::Rename),
+            other => Err(ApiError::invalid_argument("conflict policy", other)),
+        }
+    }
+}
+
+/// Whether two masters sit at the same design location
+fn same_location(a: &babelfont::Master, b: &babelfont::Master) -> bool {
+    let coordinates = |master: &babelfont::Master| -> BTreeMap<String, f64> {
+        master.location.iter().map(|(tag, coord)| (tag.to_string(), coord.to_f64())).collect()
+    };
+    let (a, b) = (coordinates(a), coordinates(b));
+    a.len() == b.len()
+        && a.iter().all(|(tag, value)| b.get(tag).is_some_and(|other| (other - value).abs() < 1e-6))
+}
+
+/// Target master of each source master
+///
+/// Masters match by id, then by design location. Fonts with a single
+/// master each (e.g. a component library) always match.
+fn master_map(source: &babelfont::Font, target: &babelfont::Font) -> HashMap<String, String> {
+    if let ([source_master], [target_master]) = (source.masters.as_slice(), target.masters.as_slice()) {
+        return HashMap::from([(source_master.id.to_string(), target_master.id.to_string())]);
+    }
+    source
+        .masters
+        .iter()
+        .filter_map(|source_master| {
+            let target_master = target
+                .masters
+                .iter()
+                .find(|master| master.id == source_master.id)
+                .or_else(|| target.masters.iter().find(|master| same_location(master, source_master)))?;
+            Some((source_master.id.to_string(), target_master.id.to_string()))
+        })
+        .collect()
+}
+
+/// Master a layer belongs to, if any
+fn layer_master(layer: &Layer) -> Option<&str> {
+    match &layer.master {
+        LayerType::DefaultForMaster(id) | LayerType::AssociatedWithMaster(id) => Some(id.as_str()),
+        LayerType::FreeFloating => None,
+    }
+}
+
+/// A source layer as it is copied into the target, or None if its master has no match
+///
+/// Master ids used as layer ids move to the matching target master, and
+/// component references follow renamed glyphs.
+fn copied_layer(layer: &Layer, masters: &HashMap<String, String>, names: &HashMap<String, String>) -> Option<Layer> {
+    let mut layer = layer.clone();
+    layer.master = match &layer.master {
+        LayerType::DefaultForMaster(id) => LayerType::DefaultForMaster(masters.get(id.as_str())?.as_str().into()),
+        LayerType::AssociatedWithMaster(id) => LayerType::AssociatedWithMaster(masters.get(id.as_str())?.as_str().into()),
+        LayerType::FreeFloating => LayerType::FreeFloating,
+    };
+    if let Some(target_id) = layer.id.as_deref().and_then(|id| masters.get(id)) {
+        layer.id = Some(target_id.clone());
+    }
+    if let Some(target_id) = layer.background_layer_id.as_deref().and_then(|id| masters.get(id)) {
+        layer.background_layer_id = Some(target_id.clone());
+    }
+    for shape in layer.shapes.iter_mut() {
+        if let Shape::Component(component) = shape {
+            if let Some(name) = names.get(component.reference.as_str()) {
+                component.reference = name.as_str().into();
+            }
+        }
+    }
+    Some(layer)
+}
+
+/// First free name of the form `{name}{suffix}`, `{name}{suffix}2`, ...
+fn free_name(target: &babelfont::Font, taken: &HashSet<String>, name: &str, suffix: &str) -> String {
+    let is_free = |candidate: &str| target.glyphs.get(candidate).is_none() && !taken.contains(candidate);
+    let base = format!("{}{}", name, suffix);
+    if is_free(&base) {
+        return base;
+    }
+    (2..).map(|index| format!("{}{}", base, index)).find(|candidate| is_free(candidate)).unwrap_or(base)
+}
+
+/// Decide the target name of each glyph to copy
+///
+/// Starts from the requested glyphs and follows the components of every
+/// glyph that is copied; a skipped glyph's components are not needed.
+///
+/// # Returns
+/// * Source name -> target name of the copied glyphs in copy order, and the skipped glyphs with reasons
+fn plan_copies(
+    source: &babelfont::Font,
+    target: &babelfont::Font,
+    requested: Vec<String>,
+    with_components: bool,
+    conflict: Conflict,
+    suffix: &str,
+) -> (Vec<(String, String)>, Vec<serde_json::Value>) {
+    let mut copies: Vec<(String, String)> = Vec::new();
+    let mut skipped = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut pending: VecDeque<(String, Option<String>)> = requested.into_iter().map(|name| (name, None)).collect();
+
+    while let Some((name, used_by)) = pending.pop_front() {
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        let Some(glyph) = source.glyphs.get(&name) else {
+            let reason = match used_by {
+                Some(composite) => format!("Component of '{}' is missing in the source font", composite),
+                None => "Glyph not found in the source font".to_string(),
+            };
+            skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+            continue;
+        };
+        // Names given to renamed glyphs count as taken too
+        let exists = target.glyphs.get(&name).is_some() || taken.contains(&name);
+        let target_name = match (exists, conflict) {
+            (false, _) | (true, Conflict::Overwrite) => name.clone(),
+            (true, Conflict::Skip) => {
+                skipped.push(serde_json::json!({ "glyph": name, "reason": "Glyph already exists in the target font" }));
+                continue;
+            }
+            (true, Conflict::Rename) => free_name(target, &taken, &name, suffix),
+        };
+        taken.insert(target_name.clone());
+        copies.push((name.clone(), target_name));
+
+        if with_components {
+            for layer in &glyph.layers {
+                for shape in &layer.shapes {
+                    if let Shape::Component(component) = shape {
+                        pending.push_back((component.reference.to_string(), Some(name.clone())));
+                    }
+                }
+            }
+        }
+    }
+    (copies, skipped)
+}
+
+/// Copy kerning groups and pairs between copied glyphs
+///
+/// Copied glyphs join the target group of the same name as in the source
+/// (created if missing), unless they already belong to another group on
+/// that side. Pairs are copied when both sides are copied glyphs or groups
+/// with copied members; existing pairs are only replaced by `overwrite`.
+///
+/// # Returns
+/// * Number of groups and pairs added or changed, and glyphs left out of groups with reasons
+fn merge_kerning(
+    target: &mut babelfont::Font,
+    source: &babelfont::Font,
+    masters: &HashMap<String, String>,
+    names: &HashMap<String, String>,
+    conflict: Conflict,
+) -> (usize, usize, Vec<serde_json::Value>) {
+    let mut warnings = Vec::new();
+    let mut group_count = 0;
+    let mut merged_groups: [HashSet<String>; 2] = [HashSet::new(), HashSet::new()];
+
+    for (side, first) in [(0, true), (1, false)] {
+        let source_groups = if first { &source.first_kern_groups } else { &source.second_kern_groups };
+        let target_groups = if first { &mut target.first_kern_groups } else { &mut target.second_kern_groups };
+        let mut memberships: HashMap<String, String> = HashMap::new();
+        for (group, members) in target_groups.iter() {
+            for member in members.iter() {
+                memberships.insert(member.to_string(), group.to_string());
+            }
+        }
+
+        for (group, members) in source_groups.iter() {
+            let copied: Vec<&String> = members.iter().filter_map(|member| names.get(member.as_str())).collect();
+            if copied.is_empty() {
+                continue;
+            }
+            let group = group.to_string();
+            let mut added = Vec::new();
+            for member in copied {
+                match memberships.get(member) {
+                    Some(existing) if *existing == group => {}
+                    Some(existing) => {
+                        let reason = format!("Already in kerning group '{}'; not added to '{}'", existing, group);
+                        warnings.push(serde_json::json!({ "glyph": member, "reason": reason }));
+                    }
+                    None => {
+                        memberships.insert(member.clone(), group.clone());
+                        added.push(member.clone());
+                    }
+                }
+            }
+            if !added.is_empty() {
+                match target_groups.get_mut(group.as_str()) {
+                    Some(members) => members.extend(added.iter().map(|member| member.as_str().into())),
+                    None => {
+                        target_groups.insert(group.as_str().into(), added.iter().map(|member| member.as_str().into()).collect());
+                    }
+                }
+                group_count += 1;
+            }
+            merged_groups[side].insert(group);
+        }
+    }
+
+    let resolve = |side: &str, first: bool| -> Option<String> {
+        match side.strip_prefix('@') {
+            Some(group) => merged_groups[if first { 0 } else { 1 }].contains(group).then(|| side.to_string()),
+            None => names.get(side).cloned(),
+        }
+    };
+    let mut pair_count = 0;
+    for source_master in &source.masters {
+        let Some(target_id) = masters.get(source_master.id.as_str()) else {
+            continue;
+        };
+        let Some(target_master) = target.masters.iter_mut().find(|master| master.id == *target_id) else {
+            continue;
+        };
+        let existing: HashSet<(String, String)> = target_master
+            .kerning
+            .keys()
+            .map(|(left, right)| (left.to_string(), right.to_string()))
+            .collect();
+        for ((left, right), value) in source_master.kerning.iter() {
+            let (Some(left), Some(right)) = (resolve(left.as_str(), true), resolve(right.as_str(), false)) else {
+                continue;
+            };
+            if existing.contains(&(left.clone(), right.clone())) && conflict != Conflict::Overwrite {
+                continue;
+            }
+            target_master.kerning.insert((left.as_str().into(), right.as_str().into()), *value);
+            pair_count += 1;
+        }
+    }
+    (group_count, pair_count, warnings)
+}
+
+/// Copy glyphs of a source font into a font
+///
+/// # Arguments
+/// * `target` - The font to copy into
+/// * `source` - The font to copy from
+/// * `options_json` - Optional JSON object:
+///   - `glyphs`: [string] - Glyphs to copy (default: all glyphs of the source)
+///   - `components`: bool - Also copy the glyphs used as components, nested (default: true)
+///   - `kerning`: bool - Copy kerning groups and pairs between copied glyphs (default: true)
+///   - `conflict`: "skip" | "overwrite" | "rename" - Glyphs that exist in the target are kept, replaced or copied under a new name (default: "skip")
+///   - `suffix`: string - Suffix of renamed glyphs; a number is added if the name is taken too (default: ".merged")
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Report '{"added": ["Aacute"], "overwritten": [], "renamed": {"acutecomb": "acutecomb.merged"}, "skipped": [{"glyph": "A", "reason": "..."}], "warnings": [{"glyph": "...", "reason": "..."}], "masters": {"m01": "master-regular"}, "unmatchedMasters": [], "kerning": {"groups": 1, "pairs": 12}}' and the target names of the copied glyphs
+pub fn merge_font(
+    target: &mut babelfont::Font,
+    source: &babelfont::Font,
+    options_json: Option<&str>,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let requested: Vec<String> = match options.get("glyphs") {
+        Some(glyphs) => serde_json::from_value(glyphs.clone()).map_err(|e| ApiError::invalid_json("glyphs", e))?,
+        None => source.glyphs.iter().map(|glyph| glyph.name.to_string()).collect(),
+    };
+    let with_components = options.get("components").and_then(|v| v.as_bool()).unwrap_or(true);
+    let with_kerning = options.get("kerning").and_then(|v| v.as_bool()).unwrap_or(true);
+    let conflict = Conflict::from_name(options.get("conflict").and_then(|v| v.as_str()).unwrap_or("skip"))?;
+    let suffix = options.get("suffix").and_then(|v| v.as_str()).unwrap_or(".merged");
+    if conflict == Conflict::Rename && suffix.is_empty() {
+        return Err(ApiError::invalid_argument("suffix", suffix));
+    }
+
+    let masters = master_map(source, target);
+    if masters.is_empty() {
+        return Err("No master of the source font matches a master of the target font".to_string().into());
+    }
+    let unmatched: Vec<String> = source
+        .masters
+        .iter()
+        .filter(|master| !masters.contains_key(master.id.as_str()))
+        .map(|master| master.id.to_string())
+        .collect();
+
+    let (copies, skipped) = plan_copies(source, target, requested, with_components, conflict, suffix);
+    let names: HashMap<String, String> = copies.iter().cloned().collect();
+    let target_masters: Vec<String> = target.masters.iter().map(|master| master.id.to_string()).collect();
+
+    let mut encoded: HashMap<u32, String> = HashMap::new();
+    for glyph in target.glyphs.iter() {
+        for codepoint in &glyph.codepoints {
+            encoded.entry(*codepoint).or_insert_with(|| glyph.name.to_string());
+        }
+    }
+
+    let mut added = Vec::new();
+    let mut overwritten = Vec::new();
+    let mut renamed = BTreeMap::new();
+    let mut warnings = Vec::new();
+    for (source_name, target_name) in &copies {
+        let Some(source_glyph) = source.glyphs.get(source_name) else {
+            continue;
+        };
+        let mut glyph = source_glyph.clone();
+        glyph.name = target_name.as_str().into();
+        glyph.layers = source_glyph
+            .layers
+            .iter()
+            .filter_map(|layer| copied_layer(layer, &masters, &names))
+            .collect();
+
+        let existing = target.glyphs.get(target_name);
+        if let Some(existing) = existing {
+            // Layers of masters the source cannot provide stay
+            let provided: HashSet<&str> = glyph.layers.iter().filter_map(layer_master).collect();
+            let kept: Vec<Layer> = existing
+                .layers
+                .iter()
+                .filter(|layer| layer_master(layer).is_some_and(|master| !provided.contains(master)))
+                .cloned()
+                .collect();
+            glyph.layers.extend(kept);
+            encoded.retain(|_, owner| *owner != *target_name);
+        }
+
+        let has_layer = |master: &str| {
+            glyph.layers.iter().any(|layer| !layer.is_background && layer_master(layer) == Some(master))
+        };
+        let missing: Vec<String> = target_masters
+            .iter()
+            .filter(|master| !has_layer(master))
+            .map(|master| format!("'{}'", master))
+            .collect();
+        if !missing.is_empty() {
+            let reason = format!("No layer for master(s) {}", missing.join(", "));
+            warnings.push(serde_json::json!({ "glyph": target_name, "reason": reason }));
+        }
+
+        let mut codepoints = Vec::new();
+        for codepoint in glyph.codepoints.iter().copied() {
+            match encoded.get(&codepoint) {
+                Some(owner) => {
+                    let reason = format!("U+{:04X} is already encoded by '{}'; left out", codepoint, owner);
+                    warnings.push(serde_json::json!({ "glyph": target_name, "reason": reason }));
+                }
+                None => {
+                    encoded.insert(codepoint, target_name.clone());
+                    codepoints.push(codepoint);
+                }
+            }
+        }
+        glyph.codepoints = codepoints;
+
+        if existing.is_some() {
+            if let Some(slot) = target.glyphs.get_mut(target_name) {
+                *slot = glyph;
+            }
+            overwritten.push(target_name.clone());
+        } else {
+            target.glyphs.push(glyph);
+            if source_name == target_name {
+                added.push(target_name.clone());
+            } else {
+                renamed.insert(source_name.clone(), target_name.clone());
+            }
+        }
+    }
+
+    let (group_count, pair_count) = if with_kerning {
+        let (groups, pairs, kerning_warnings) = merge_kerning(target, source, &masters, &names, conflict);
+        warnings.extend(kerning_warnings);
+        (groups, pairs)
+    } else {
+        (0, 0)
+    };
+
+    let report = serde_json::json!({
+        "added": added,
+        "overwritten": overwritten,
+        "renamed": renamed,
+        "skipped": skipped,
+        "warnings": warnings,
+        "masters": masters.iter().collect::<BTreeMap<_, _>>(),
+        "unmatchedMasters": unmatched,
+        "kerning": { "groups": group_count, "pairs": pair_count },
+    });
+    Ok((report, names.into_values().collect()))
+}
@@ -4,11 +4,19 @@
 // Uses the read-fonts crate (part of Google Fonts fontations project).
 
 use read_fonts::{FontRef, TableProvider};
-use read_fonts::tables::layout::FeatureParams;
+use read_fonts::tables::layout::{FeatureList, FeatureParams, LangSys, ScriptList};
+use read_fonts::tables::gdef::CaretValue;
+use read_fonts::tables::stat::{AxisValue, AxisValueTableFlags};
+use read_fonts::types::{GlyphId, GlyphId16, NameId};
 use serde_json;
+use skrifa::instance::{Location, Size};
+use skrifa::outline::{DrawSettings, OutlinePen};
+use skrifa::MetadataProvider;
 use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::*;
 
+use crate::errors::ApiError;
+
 /// Get glyph name by ID from compiled font bytes
 ///
 /// # Arguments
@@ -20,7 +28,7 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen]
 pub fn get_glyph_name(font_bytes: &[u8], glyph_id: u16) -> Result<String, JsValue> {
     let font = FontRef::new(font_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse font: {:?}", e)))?;
+        .map_err(ApiError::invalid_font)?;
     
     // Try post table first for glyph names
     if let Ok(post) = font.post() {
@@ -43,16 +51,23 @@ pub fn get_glyph_name(font_bytes: &[u8], glyph_id: u16) -> Result<String, JsValu
 #[wasm_bindgen]
 pub fn get_glyph_order(font_bytes: &[u8]) -> Result<Vec<String>, JsValue> {
     let font = FontRef::new(font_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse font: {:?}", e)))?;
+        .map_err(ApiError::invalid_font)?;
     
+    Ok(read_glyph_order(&font)?)
+}
+
+/// Read the glyph order from a parsed font, falling back to generated names
+/// for glyphs without a post table entry
+pub(crate) fn read_glyph_order(font: &FontRef) -> Result<Vec<String>, ApiError> {
     let glyph_count = font.maxp()
-        .map_err(|e| JsValue::from_str(&format!("Failed to read maxp table: {:?}", e)))?
+        .map_err(|e| ApiError::missing_table("maxp", e))?
         .num_glyphs();
     
+    let post = font.post().ok();
     let mut glyph_order = Vec::with_capacity(glyph_count as usize);
     
     for gid in 0..glyph_count {
-        if let Ok(post) = font.post() {
+        if let Some(ref post) = post {
             if let Some(name) = post.glyph_name(read_fonts::types::GlyphId16::new(gid)) {
                 glyph_order.push(name.to_string());
                 continue;
@@ -84,7 +99,7 @@ pub fn get_glyph_order(font_bytes: &[u8]) -> Result<Vec<String>, JsValue> {
 #[wasm_bindgen]
 pub fn get_stylistic_set_names(font_bytes: &[u8]) -> Result<String, JsValue> {
     let font = FontRef::new(font_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse font: {:?}", e)))?;
+        .map_err(ApiError::invalid_font)?;
     
     let mut feature_names: HashMap<String, String> = HashMap::new();
     
@@ -178,7 +193,101 @@ pub fn get_stylistic_set_names(font_bytes: &[u8]) -> Result<String, JsValue> {
     }
     
     serde_json::to_string(&feature_names)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize feature names: {}", e)))
+        .map_err(|e| ApiError::serialization("feature names", e).into())
+}
+
+/// Get the UI names of stylistic set and character variant features from compiled font bytes
+///
+/// Generalizes `get_stylistic_set_names()`: ssXX features report their UI
+/// name, cvXX features report the decoded CharacterVariant parameters.
+///
+/// Returns a JSON string with structure:
+/// ```json
+/// {
+///   "ss01": { "kind": "stylisticSet", "uiName": "Alternate a" },
+///   "cv01": {
+///     "kind": "characterVariant",
+///     "uiLabel": "Single-storey a",
+///     "tooltip": "Replaces a with a single-storey form",
+///     "sampleText": "banana",
+///     "paramLabels": ["Round", "Flat"],
+///     "characters": [97]
+///   }
+/// }
+/// ```
+///
+/// Names missing from the name table are reported as `null`.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON object mapping feature tags to their UI parameters
+#[wasm_bindgen]
+pub fn get_feature_ui_names(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let name_table = font.name().ok();
+    let lookup_name = |name_id: NameId| -> Option<String> {
+        if name_id.to_u16() == 0 {
+            return None;
+        }
+        name_table.as_ref().and_then(|table| english_name(table, name_id))
+    };
+
+    let mut feature_names = serde_json::Map::new();
+
+    // Stylistic sets and character variants are typically in GSUB, but check GPOS too
+    let feature_lists = [
+        font.gsub().ok().and_then(|gsub| gsub.feature_list().ok()),
+        font.gpos().ok().and_then(|gpos| gpos.feature_list().ok()),
+    ];
+
+    for feature_list in feature_lists.into_iter().flatten() {
+        for record in feature_list.feature_records().iter() {
+            let tag_str = record.feature_tag().to_string();
+            if feature_names.contains_key(&tag_str) {
+                continue;
+            }
+
+            let Ok(feature_table) = record.feature(feature_list.offset_data()) else { continue };
+            let Some(Ok(params)) = feature_table.feature_params() else { continue };
+
+            let entry = match params {
+                FeatureParams::StylisticSet(ss_params) => serde_json::json!({
+                    "kind": "stylisticSet",
+                    "uiName": lookup_name(ss_params.ui_name_id()),
+                }),
+                FeatureParams::CharacterVariant(cv_params) => {
+                    let first_label = cv_params.first_param_ui_label_name_id().to_u16();
+                    let param_labels: Vec<Option<String>> = (0..cv_params.num_named_parameters())
+                        .map(|i| lookup_name(NameId::new(first_label.saturating_add(i))))
+                        .collect();
+                    let characters: Vec<u32> = cv_params
+                        .character()
+                        .iter()
+                        .map(|c| c.get().to_u32())
+                        .collect();
+
+                    serde_json::json!({
+                        "kind": "characterVariant",
+                        "uiLabel": lookup_name(cv_params.feat_ui_label_name_id()),
+                        "tooltip": lookup_name(cv_params.feat_ui_tooltip_text_name_id()),
+                        "sampleText": lookup_name(cv_params.sample_text_name_id()),
+                        "paramLabels": param_labels,
+                        "characters": characters,
+                    })
+                }
+                _ => continue,
+            };
+
+            feature_names.insert(tag_str, entry);
+        }
+    }
+
+    serde_json::to_string(&feature_names)
+        .map_err(|e| ApiError::serialization("feature names", e).into())
 }
 
 /// Get all available features from compiled font bytes
@@ -196,7 +305,7 @@ pub fn get_stylistic_set_names(font_bytes: &[u8]) -> Result<String, JsValue> {
 #[wasm_bindgen]
 pub fn get_font_features(font_bytes: &[u8]) -> Result<String, JsValue> {
     let font = FontRef::new(font_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse font: {:?}", e)))?;
+        .map_err(ApiError::invalid_font)?;
     
     let mut features: HashSet<String> = HashSet::new();
     
@@ -223,7 +332,7 @@ pub fn get_font_features(font_bytes: &[u8]) -> Result<String, JsValue> {
     features_vec.sort();
     
     serde_json::to_string(&features_vec)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize features: {}", e)))
+        .map_err(|e| ApiError::serialization("features", e).into())
 }
 
 /// Get variation axes from compiled font bytes
@@ -244,15 +353,15 @@ pub fn get_font_features(font_bytes: &[u8]) -> Result<String, JsValue> {
 #[wasm_bindgen]
 pub fn get_font_axes(font_bytes: &[u8]) -> Result<String, JsValue> {
     let font = FontRef::new(font_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse font: {:?}", e)))?;
+        .map_err(ApiError::invalid_font)?;
     
     let fvar = font.fvar()
-        .map_err(|e| JsValue::from_str(&format!("No fvar table found: {:?}", e)))?;
+        .map_err(|e| ApiError::missing_table("fvar", e))?;
     
     let name_table = font.name().ok();
     
     let axes_array = fvar.axes()
-        .map_err(|e| JsValue::from_str(&format!("Failed to read axes: {:?}", e)))?;
+        .map_err(|e| ApiError::missing_table("fvar", e))?;
     
     let mut axes = Vec::new();
     
@@ -286,5 +395,764 @@ pub fn get_font_axes(font_bytes: &[u8]) -> Result<String, JsValue> {
     }
     
     serde_json::to_string(&axes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize axes: {}", e)))
+        .map_err(|e| ApiError::serialization("axes", e).into())
+}
+
+/// Look up an English (Windows, Unicode BMP, en-US) string in the name table
+pub(crate) fn english_name(name_table: &read_fonts::tables::name::Name, name_id: NameId) -> Option<String> {
+    name_table.name_record()
+        .iter()
+        .find(|record| {
+            record.name_id() == name_id &&
+            record.platform_id() == 3 &&
+            record.encoding_id() == 1 &&
+            record.language_id() == 0x0409
+        })
+        .and_then(|record| record.string(name_table.string_data()).ok())
+        .map(|s| s.to_string())
+}
+
+/// Serialize STAT axis value flags
+fn axis_value_flags_json(flags: AxisValueTableFlags) -> serde_json::Value {
+    serde_json::json!({
+        "olderSiblingFontAttribute": flags.contains(AxisValueTableFlags::OLDER_SIBLING_FONT_ATTRIBUTE),
+        "elidableAxisValueName": flags.contains(AxisValueTableFlags::ELIDABLE_AXIS_VALUE_NAME),
+    })
+}
+
+/// Get the STAT (style attributes) table from compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "version": "1.2",
+///   "elidedFallbackName": { "nameId": 2, "name": "Regular" },
+///   "axes": [
+///     { "tag": "wght", "name": "Weight", "nameId": 256, "ordering": 0 }
+///   ],
+///   "axisValues": [
+///     { "format": 1, "axisIndex": 0, "axisTag": "wght", "nameId": 257, "name": "Regular",
+///       "flags": { "olderSiblingFontAttribute": false, "elidableAxisValueName": true },
+///       "value": 400 }
+///   ],
+///   "combinations": [
+///     { "nameId": 260, "name": "Bold Condensed", "flags": { ... },
+///       "values": [{ "axisIndex": 0, "axisTag": "wght", "value": 700 }, ...] }
+///   ]
+/// }
+/// ```
+///
+/// Format 1-3 axis values are listed in `axisValues` (format 2 adds `min`/`max`,
+/// format 3 adds `linkedValue`); format 4 records are listed in `combinations`.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON object describing the STAT table
+#[wasm_bindgen]
+pub fn get_stat_table(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let stat = font.stat()
+        .map_err(|e| ApiError::missing_table("STAT", e))?;
+
+    let name_table = font.name().ok();
+    let lookup_name = |name_id: NameId| -> serde_json::Value {
+        name_table
+            .as_ref()
+            .and_then(|name| english_name(name, name_id))
+            .map(serde_json::Value::String)
+            .unwrap_or(serde_json::Value::Null)
+    };
+
+    let design_axes = stat.design_axes()
+        .map_err(|e| ApiError::missing_table("STAT", e))?;
+
+    let axis_tag = |axis_index: u16| -> serde_json::Value {
+        design_axes
+            .get(axis_index as usize)
+            .map(|axis| serde_json::Value::String(axis.axis_tag().to_string()))
+            .unwrap_or(serde_json::Value::Null)
+    };
+
+    let axes: Vec<serde_json::Value> = design_axes
+        .iter()
+        .map(|axis| {
+            serde_json::json!({
+                "tag": axis.axis_tag().to_string(),
+                "name": lookup_name(axis.axis_name_id()),
+                "nameId": axis.axis_name_id().to_u16(),
+                "ordering": axis.axis_ordering(),
+            })
+        })
+        .collect();
+
+    let mut axis_values = Vec::new();
+    let mut combinations = Vec::new();
+
+    if let Some(Ok(value_array)) = stat.offset_to_axis_values() {
+        for axis_value in value_array.axis_values().iter().filter_map(|v| v.ok()) {
+            match axis_value {
+                AxisValue::Format1(v) => axis_values.push(serde_json::json!({
+                    "format": 1,
+                    "axisIndex": v.axis_index(),
+                    "axisTag": axis_tag(v.axis_index()),
+                    "nameId": v.value_name_id().to_u16(),
+                    "name": lookup_name(v.value_name_id()),
+                    "flags": axis_value_flags_json(v.flags()),
+                    "value": v.value().to_f64(),
+                })),
+                AxisValue::Format2(v) => axis_values.push(serde_json::json!({
+                    "format": 2,
+                    "axisIndex": v.axis_index(),
+                    "axisTag": axis_tag(v.axis_index()),
+                    "nameId": v.value_name_id().to_u16(),
+                    "name": lookup_name(v.value_name_id()),
+                    "flags": axis_value_flags_json(v.flags()),
+                    "value": v.nominal_value().to_f64(),
+                    "min": v.range_min_value().to_f64(),
+                    "max": v.range_max_value().to_f64(),
+                })),
+                AxisValue::Format3(v) => axis_values.push(serde_json::json!({
+                    "format": 3,
+                    "axisIndex": v.axis_index(),
+                    "axisTag": axis_tag(v.axis_index()),
+                    "nameId": v.value_name_id().to_u16(),
+                    "name": lookup_name(v.value_name_id()),
+                    "flags": axis_value_flags_json(v.flags()),
+                    "value": v.value().to_f64(),
+                    "linkedValue": v.linked_value().to_f64(),
+                })),
+                AxisValue::Format4(v) => {
+                    let values: Vec<serde_json::Value> = v.axis_values()
+                        .iter()
+                        .map(|record| {
+                            serde_json::json!({
+                                "axisIndex": record.axis_index(),
+                                "axisTag": axis_tag(record.axis_index()),
+                                "value": record.value().to_f64(),
+                            })
+                        })
+                        .collect();
+                    combinations.push(serde_json::json!({
+                        "nameId": v.value_name_id().to_u16(),
+                        "name": lookup_name(v.value_name_id()),
+                        "flags": axis_value_flags_json(v.flags()),
+                        "values": values,
+                    }));
+                }
+            }
+        }
+    }
+
+    let elided_fallback_name = stat.elided_fallback_name_id().map(|name_id| {
+        serde_json::json!({
+            "nameId": name_id.to_u16(),
+            "name": lookup_name(name_id),
+        })
+    });
+
+    let version = stat.version();
+    let result = serde_json::json!({
+        "version": format!("{}.{}", version.major, version.minor),
+        "elidedFallbackName": elided_fallback_name,
+        "axes": axes,
+        "axisValues": axis_values,
+        "combinations": combinations,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("STAT table", e).into())
+}
+
+/// Look up a glyph name in a glyph order, falling back to a generated name
+pub(crate) fn glyph_name_for(glyph_order: &[String], gid: GlyphId16) -> String {
+    glyph_order
+        .get(gid.to_u16() as usize)
+        .cloned()
+        .unwrap_or_else(|| format!("glyph{:05}", gid.to_u16()))
+}
+
+/// Get GDEF (glyph definition) information from compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "glyphClasses": { "A": "base", "f_i": "ligature", "acutecomb": "mark" },
+///   "markAttachmentClasses": { "acutecomb": 1, "dotbelowcomb": 2 },
+///   "markGlyphSets": [["acutecomb", "gravecomb"]],
+///   "ligatureCarets": {
+///     "f_i": [{ "format": 1, "coordinate": 310 }],
+///     "f_f_i": [{ "format": 2, "pointIndex": 12 }, ...]
+///   }
+/// }
+/// ```
+///
+/// Glyph classes are one of `base`, `ligature`, `mark` or `component`.
+/// Glyphs not listed in a class definition are omitted.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON object describing the GDEF table
+#[wasm_bindgen]
+pub fn get_gdef_info(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let gdef = font.gdef()
+        .map_err(|e| ApiError::missing_table("GDEF", e))?;
+
+    let glyph_order = read_glyph_order(&font)?;
+
+    // Glyph class definitions
+    let mut glyph_classes = serde_json::Map::new();
+    if let Some(Ok(class_def)) = gdef.glyph_class_def() {
+        for (gid, class) in class_def.iter() {
+            let class_name = match class {
+                1 => "base",
+                2 => "ligature",
+                3 => "mark",
+                4 => "component",
+                _ => continue,
+            };
+            glyph_classes.insert(glyph_name_for(&glyph_order, gid), class_name.into());
+        }
+    }
+
+    // Mark attachment classes
+    let mut mark_attachment_classes = serde_json::Map::new();
+    if let Some(Ok(class_def)) = gdef.mark_attach_class_def() {
+        for (gid, class) in class_def.iter() {
+            if class != 0 {
+                mark_attachment_classes.insert(glyph_name_for(&glyph_order, gid), class.into());
+            }
+        }
+    }
+
+    // Mark glyph sets (GDEF 1.2+)
+    let mut mark_glyph_sets = Vec::new();
+    if let Some(Ok(sets)) = gdef.mark_glyph_sets_def() {
+        for coverage in sets.coverages().iter() {
+            let glyphs: Vec<String> = match coverage {
+                Ok(coverage) => coverage
+                    .iter()
+                    .map(|gid| glyph_name_for(&glyph_order, gid))
+                    .collect(),
+                Err(_) => Vec::new(),
+            };
+            mark_glyph_sets.push(glyphs);
+        }
+    }
+
+    // Ligature caret positions
+    let mut ligature_carets = serde_json::Map::new();
+    if let Some(Ok(lig_caret_list)) = gdef.lig_caret_list() {
+        if let Ok(coverage) = lig_caret_list.coverage() {
+            for (gid, lig_glyph) in coverage.iter().zip(lig_caret_list.lig_glyphs().iter()) {
+                let Ok(lig_glyph) = lig_glyph else { continue };
+                let carets: Vec<serde_json::Value> = lig_glyph
+                    .caret_values()
+                    .iter()
+                    .filter_map(|caret| caret.ok())
+                    .map(|caret| match caret {
+                        CaretValue::Format1(c) => serde_json::json!({
+                            "format": 1,
+                            "coordinate": c.coordinate(),
+                        }),
+                        CaretValue::Format2(c) => serde_json::json!({
+                            "format": 2,
+                            "pointIndex": c.caret_value_point_index(),
+                        }),
+                        CaretValue::Format3(c) => serde_json::json!({
+                            "format": 3,
+                            "coordinate": c.coordinate(),
+                        }),
+                    })
+                    .collect();
+                ligature_carets.insert(glyph_name_for(&glyph_order, gid), carets.into());
+            }
+        }
+    }
+
+    let result = serde_json::json!({
+        "glyphClasses": glyph_classes,
+        "markAttachmentClasses": mark_attachment_classes,
+        "markGlyphSets": mark_glyph_sets,
+        "ligatureCarets": ligature_carets,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("GDEF info", e).into())
+}
+
+/// Resolve an optional JSON array of glyph names to glyph IDs
+///
+/// Returns every glyph in the font when `glyph_names_json` is `None` or empty.
+/// Names that are not present in the font are skipped.
+fn resolve_glyph_ids(glyph_order: &[String], glyph_names_json: Option<&str>) -> Result<Vec<GlyphId16>, JsValue> {
+    let names_json = glyph_names_json.map(str::trim).unwrap_or("");
+    if names_json.is_empty() {
+        return Ok((0..glyph_order.len() as u16).map(GlyphId16::new).collect());
+    }
+
+    let glyph_names: Vec<String> = serde_json::from_str(names_json)
+        .map_err(|e| ApiError::invalid_json("glyph names", e))?;
+
+    let name_to_gid: HashMap<&str, u16> = glyph_order
+        .iter()
+        .enumerate()
+        .map(|(gid, name)| (name.as_str(), gid as u16))
+        .collect();
+
+    Ok(glyph_names
+        .iter()
+        .filter_map(|name| name_to_gid.get(name.as_str()).copied())
+        .map(GlyphId16::new)
+        .collect())
+}
+
+/// Get advance widths and sidebearings for many glyphs from compiled font bytes
+///
+/// Returns a JSON array with structure:
+/// ```json
+/// [
+///   { "name": "A", "gid": 1, "advance": 600, "lsb": 10, "rsb": 12,
+///     "bounds": { "xMin": 10, "yMin": 0, "xMax": 588, "yMax": 700 } },
+///   { "name": "space", "gid": 2, "advance": 250, "lsb": 0, "rsb": null, "bounds": null }
+/// ]
+/// ```
+///
+/// Advances and LSBs come from `hmtx`; bounds (and therefore RSB) come from the
+/// `glyf` table. Glyphs without outlines have `null` bounds and RSB.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF font bytes
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+///
+/// # Returns
+/// * `String` - JSON array of per-glyph metrics
+#[wasm_bindgen]
+pub fn get_glyph_metrics(font_bytes: &[u8], glyph_names_json: Option<String>) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let hmtx = font.hmtx()
+        .map_err(|e| ApiError::missing_table("hmtx", e))?;
+
+    // glyf/loca are optional (CFF-flavoured fonts have neither)
+    let glyf = font.glyf().ok();
+    let loca = font.loca(None).ok();
+
+    let glyph_order = read_glyph_order(&font)?;
+    let glyph_ids = resolve_glyph_ids(&glyph_order, glyph_names_json.as_deref())?;
+
+    let mut metrics = Vec::with_capacity(glyph_ids.len());
+
+    for gid16 in glyph_ids {
+        let gid: GlyphId = gid16.into();
+        let advance = hmtx.advance(gid).unwrap_or(0);
+        let lsb = hmtx.side_bearing(gid).unwrap_or(0);
+
+        let bounds = match (&glyf, &loca) {
+            (Some(glyf), Some(loca)) => match loca.get_glyf(gid, glyf) {
+                Ok(Some(glyph)) => Some((glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max())),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let (bounds_json, rsb) = match bounds {
+            Some((x_min, y_min, x_max, y_max)) => (
+                serde_json::json!({
+                    "xMin": x_min,
+                    "yMin": y_min,
+                    "xMax": x_max,
+                    "yMax": y_max,
+                }),
+                serde_json::json!(advance as i32 - lsb as i32 - (x_max as i32 - x_min as i32)),
+            ),
+            None => (serde_json::Value::Null, serde_json::Value::Null),
+        };
+
+        metrics.push(serde_json::json!({
+            "name": glyph_name_for(&glyph_order, gid16),
+            "gid": gid16.to_u16(),
+            "advance": advance,
+            "lsb": lsb,
+            "rsb": rsb,
+            "bounds": bounds_json,
+        }));
+    }
+
+    serde_json::to_string(&metrics)
+        .map_err(|e| ApiError::serialization("glyph metrics", e).into())
+}
+
+/// Outline pen that collects skrifa drawing commands into a kurbo path
+#[derive(Default)]
+pub(crate) struct BezPathPen {
+    pub path: kurbo::BezPath,
+}
+
+impl OutlinePen for BezPathPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.path.move_to((x as f64, y as f64));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.path.line_to((x as f64, y as f64));
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.path.quad_to((cx0 as f64, cy0 as f64), (x as f64, y as f64));
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        self.path.curve_to(
+            (cx0 as f64, cy0 as f64),
+            (cx1 as f64, cy1 as f64),
+            (x as f64, y as f64),
+        );
+    }
+
+    fn close(&mut self) {
+        self.path.close_path();
+    }
+}
+
+/// Build a skrifa location from a JSON object of user space axis values
+///
+/// An empty or missing object yields the default location.
+pub(crate) fn skrifa_location(font: &skrifa::FontRef, location_json: Option<&str>) -> Result<Location, ApiError> {
+    let location_json = location_json.map(str::trim).unwrap_or("");
+    if location_json.is_empty() || location_json == "{}" {
+        return Ok(Location::default());
+    }
+
+    let location_map: HashMap<String, f32> = serde_json::from_str(location_json)
+        .map_err(ApiError::invalid_location)?;
+
+    Ok(font.axes().location(
+        location_map
+            .iter()
+            .map(|(tag, value)| (tag.as_str(), *value)),
+    ))
+}
+
+/// Get glyph bounding boxes at a variation location from compiled font bytes
+///
+/// Outlines are drawn with skrifa at the requested location (applying `gvar`
+/// deltas), so the bounds reflect the final binary rather than the source.
+///
+/// Returns a JSON array with structure:
+/// ```json
+/// [
+///   { "name": "A", "gid": 1, "advance": 612,
+///     "bounds": { "xMin": 8, "yMin": 0, "xMax": 604, "yMax": 712 } },
+///   { "name": "space", "gid": 2, "advance": 250, "bounds": null }
+/// ]
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `location_json` - Optional JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'. Omit for the default location.
+///
+/// # Returns
+/// * `String` - JSON array of per-glyph bounds
+#[wasm_bindgen]
+pub fn get_glyph_bounds(font_bytes: &[u8], location_json: Option<String>) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+
+    let scaler_font = skrifa::FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let location = skrifa_location(&scaler_font, location_json.as_deref())?;
+
+    let glyph_metrics = scaler_font.glyph_metrics(Size::unscaled(), &location);
+    let outlines = scaler_font.outline_glyphs();
+
+    let mut results = Vec::with_capacity(glyph_order.len());
+
+    for (gid, name) in glyph_order.iter().enumerate() {
+        let glyph_id = skrifa::GlyphId::new(gid as u32);
+
+        let bounds = outlines.get(glyph_id).and_then(|outline| {
+            let mut pen = BezPathPen::default();
+            outline
+                .draw(DrawSettings::unhinted(Size::unscaled(), &location), &mut pen)
+                .ok()?;
+            if pen.path.elements().is_empty() {
+                return None;
+            }
+            Some(kurbo::Shape::bounding_box(&pen.path))
+        });
+
+        let bounds_json = match bounds {
+            Some(rect) => serde_json::json!({
+                "xMin": rect.x0,
+                "yMin": rect.y0,
+                "xMax": rect.x1,
+                "yMax": rect.y1,
+            }),
+            None => serde_json::Value::Null,
+        };
+
+        results.push(serde_json::json!({
+            "name": name,
+            "gid": gid,
+            "advance": glyph_metrics.advance_width(glyph_id),
+            "bounds": bounds_json,
+        }));
+    }
+
+    serde_json::to_string(&results)
+        .map_err(|e| ApiError::serialization("glyph bounds", e).into())
+}
+
+/// Describe a language system: its required feature and registered feature tags
+fn lang_sys_json(lang_sys: &LangSys, feature_list: &FeatureList) -> serde_json::Value {
+    let feature_records = feature_list.feature_records();
+    let feature_tag = |index: u16| -> Option<String> {
+        feature_records
+            .get(index as usize)
+            .map(|record| record.feature_tag().to_string())
+    };
+
+    let required_feature = match lang_sys.required_feature_index() {
+        0xFFFF => None,
+        index => feature_tag(index),
+    };
+
+    let features: Vec<String> = lang_sys
+        .feature_indices()
+        .iter()
+        .filter_map(|index| feature_tag(index.get()))
+        .collect();
+
+    serde_json::json!({
+        "requiredFeature": required_feature,
+        "features": features,
+    })
+}
+
+/// Walk a GSUB/GPOS script list and list the features registered under each langsys
+fn script_list_json(script_list: &ScriptList, feature_list: &FeatureList) -> Vec<serde_json::Value> {
+    let mut scripts = Vec::new();
+
+    for script_record in script_list.script_records().iter() {
+        let Ok(script) = script_record.script(script_list.offset_data()) else { continue };
+        let mut languages = Vec::new();
+
+        if let Some(Ok(default_lang_sys)) = script.default_lang_sys() {
+            let mut entry = lang_sys_json(&default_lang_sys, feature_list);
+            entry["language"] = "dflt".into();
+            languages.push(entry);
+        }
+
+        for lang_sys_record in script.lang_sys_records().iter() {
+            if let Ok(lang_sys) = lang_sys_record.lang_sys(script.offset_data()) {
+                let mut entry = lang_sys_json(&lang_sys, feature_list);
+                entry["language"] = lang_sys_record.lang_sys_tag().to_string().into();
+                languages.push(entry);
+            }
+        }
+
+        scripts.push(serde_json::json!({
+            "script": script_record.script_tag().to_string(),
+            "languages": languages,
+        }));
+    }
+
+    scripts
+}
+
+/// Get the script/language coverage of the layout tables from compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "GSUB": [
+///     { "script": "latn", "languages": [
+///       { "language": "dflt", "requiredFeature": null, "features": ["liga", "ss01"] },
+///       { "language": "TRK ", "requiredFeature": null, "features": ["liga", "locl", "ss01"] }
+///     ] }
+///   ],
+///   "GPOS": [...]
+/// }
+/// ```
+///
+/// A table that is missing from the font is reported as an empty array.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON object describing GSUB and GPOS script/langsys coverage
+#[wasm_bindgen]
+pub fn get_layout_coverage(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let mut gsub_scripts = Vec::new();
+    if let Ok(gsub) = font.gsub() {
+        if let (Ok(script_list), Ok(feature_list)) = (gsub.script_list(), gsub.feature_list()) {
+            gsub_scripts = script_list_json(&script_list, &feature_list);
+        }
+    }
+
+    let mut gpos_scripts = Vec::new();
+    if let Ok(gpos) = font.gpos() {
+        if let (Ok(script_list), Ok(feature_list)) = (gpos.script_list(), gpos.feature_list()) {
+            gpos_scripts = script_list_json(&script_list, &feature_list);
+        }
+    }
+
+    let result = serde_json::json!({
+        "GSUB": gsub_scripts,
+        "GPOS": gpos_scripts,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("layout coverage", e).into())
+}
+
+/// Get the coverage of a character set in compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "charset": "GF Latin Core",
+///   "total": 331,
+///   "coveredCount": 320,
+///   "missingCount": 11,
+///   "covered": [32, 33, ...],
+///   "missing": [{ "codepoint": 7838, "hex": "U+1E9E", "char": "ẞ" }, ...]
+/// }
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `charset` - A named character set ("GF Latin Core", "Latin Plus", "Adobe Latin 3", ...)
+///   or a JSON array of codepoints, e.g., '[65, 66, "U+00C5"]'
+///
+/// # Returns
+/// * `String` - JSON object describing covered and missing codepoints
+#[wasm_bindgen]
+pub fn get_character_coverage(font_bytes: &[u8], charset: &str) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let cmap = font.cmap()
+        .map_err(|e| ApiError::missing_table("cmap", e))?;
+
+    let (charset_name, codepoints) = crate::charsets::resolve_charset(charset)?;
+
+    let mut covered = Vec::new();
+    let mut missing = Vec::new();
+
+    for codepoint in &codepoints {
+        match cmap.map_codepoint(*codepoint) {
+            Some(gid) if gid.to_u32() != 0 => covered.push(*codepoint),
+            _ => missing.push(serde_json::json!({
+                "codepoint": codepoint,
+                "hex": format!("U+{:04X}", codepoint),
+                "char": char::from_u32(*codepoint).map(|c| c.to_string()),
+            })),
+        }
+    }
+
+    let result = serde_json::json!({
+        "charset": charset_name,
+        "total": codepoints.len(),
+        "coveredCount": covered.len(),
+        "missingCount": missing.len(),
+        "covered": covered,
+        "missing": missing,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("character coverage", e).into())
+}
+
+/// Get Unicode Variation Sequences (cmap format 14) from compiled font bytes
+///
+/// Returns a JSON array with structure:
+/// ```json
+/// [
+///   { "base": 33883, "selector": 917760, "default": false, "glyph": "uni845B.jp78" },
+///   { "base": 9728, "selector": 65039, "default": true, "glyph": "uni2600" }
+/// ]
+/// ```
+///
+/// Default sequences resolve to the glyph the base character maps to in the
+/// regular cmap subtables; `glyph` is `null` if the base character is unmapped.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON array of variation sequences
+#[wasm_bindgen]
+pub fn get_variation_sequences(font_bytes: &[u8]) -> Result<String, JsValue> {
+    use read_fonts::tables::cmap::CmapSubtable;
+
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let cmap = font.cmap()
+        .map_err(|e| ApiError::missing_table("cmap", e))?;
+
+    let glyph_order = read_glyph_order(&font)?;
+    let name_for = |gid: u32| -> serde_json::Value {
+        glyph_order
+            .get(gid as usize)
+            .map(|name| serde_json::Value::String(name.clone()))
+            .unwrap_or(serde_json::Value::Null)
+    };
+
+    let mut sequences = Vec::new();
+
+    for record in cmap.encoding_records() {
+        let Ok(CmapSubtable::Format14(cmap14)) = record.subtable(cmap.offset_data()) else { continue };
+
+        for selector_record in cmap14.var_selector() {
+            let selector = selector_record.var_selector().to_u32();
+
+            if let Some(Ok(default_uvs)) = selector_record.default_uvs(cmap14.offset_data()) {
+                for range in default_uvs.ranges() {
+                    let start = range.start_unicode_value().to_u32();
+                    for base in start..=start + range.additional_count() as u32 {
+                        let glyph = cmap.map_codepoint(base)
+                            .map(|gid| name_for(gid.to_u32()))
+                            .unwrap_or(serde_json::Value::Null);
+                        sequences.push(serde_json::json!({
+                            "base": base,
+                            "selector": selector,
+                            "default": true,
+                            "glyph": glyph,
+                        }));
+                    }
+                }
+            }
+
+            if let Some(Ok(non_default_uvs)) = selector_record.non_default_uvs(cmap14.offset_data()) {
+                for mapping in non_default_uvs.uvs_mapping() {
+                    sequences.push(serde_json::json!({
+                        "base": mapping.unicode_value().to_u32(),
+                        "selector": selector,
+                        "default": false,
+                        "glyph": name_for(mapping.glyph_id().to_u32()),
+                    }));
+                }
+            }
+        }
+    }
+
+    serde_json::to_string(&sequences)
+        .map_err(|e| ApiError::serialization("variation sequences", e).into())
 }
@@ -0,0 +1,269 @@
+// Font session module
+//
+// This module keeps the source fonts opened by the editor, so several can be
+// open at once (e.g. the Roman and Italic sources of a family). Each font is
+// identified by the handle returned when it is stored. Functions on source
+// fonts take an optional handle and otherwise use the current font: the one
+// stored or selected last. The outline, layer and shaping caches are keyed
+// by glyph name and serve one font at a time; when a call uses a different
+// font than the previous one, the caches of the previous font are put aside
+// under its handle and those of the requested font are put back. Each font
+// can also have a snapshot, against which autosave deltas are computed.
+// Edits report what they changed through font_changed(), which invalidates
+// every cache of the edited font.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::errors::ApiError;
+use crate::font_snapshots::FontSnapshot;
+use crate::{feature_cache, glyph_outlines, glyph_query, interpolation, shaping};
+
+static NEXT_FONT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// What an edit changed in a font, for invalidating its caches
+pub enum FontChange<'a> {
+    /// Features, classes, kerning or other inputs of layout compilation
+    Layout,
+    /// Glyphs, including the composites using them; nothing if empty
+    Glyphs(&'a HashSet<String>),
+    /// Any glyph (e.g. renames, merges or scaling)
+    AllGlyphs,
+}
+
+/// The open source fonts, by handle
+pub struct FontSessions {
+    fonts: BTreeMap<u32, babelfont::Font>,
+    /// Font used by calls without a handle
+    current: Option<u32>,
+    /// Font the global outline, layer and shaping caches were built for
+    cached: Option<u32>,
+    /// Caches of the other fonts, by handle
+    stashed: BTreeMap<u32, FontCaches>,
+    /// Last snapshot of each font, by handle
+    snapshots: BTreeMap<u32, FontSnapshot>,
+}
+
+impl FontSessions {
+    pub const fn new() -> Self {
+        FontSessions {
+            fonts: BTreeMap::new(),
+            current: None,
+            cached: None,
+            stashed: BTreeMap::new(),
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// Store a new font and make it the current font
+    ///
+    /// # Returns
+    /// * `u32` - Handle of the font
+    pub fn insert(&mut self, font: babelfont::Font) -> u32 {
+        let id = NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed);
+        self.fonts.insert(id, font);
+        self.current = Some(id);
+        self.switch_caches(id);
+        id
+    }
+
+    /// Replace the font of a handle and make it the current font
+    pub fn replace(&mut self, id: u32, font: babelfont::Font) -> Result<(), ApiError> {
+        let slot = self.fonts.get_mut(&id).ok_or(ApiError::UnknownFont { id })?;
+        *slot = font;
+        self.current = Some(id);
+        self.drop_caches(id);
+        self.switch_caches(id);
+        Ok(())
+    }
+
+    /// Make a font the current font
+    pub fn select(&mut self, id: u32) -> Result<(), ApiError> {
+        if !self.fonts.contains_key(&id) {
+            return Err(ApiError::UnknownFont { id });
+        }
+        self.current = Some(id);
+        Ok(())
+    }
+
+    /// Close a font
+    ///
+    /// If it was the current font, there is no current font afterwards.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the handle referred to an open font
+    pub fn remove(&mut self, id: u32) -> bool {
+        if self.current == Some(id) {
+            self.current = None;
+        }
+        self.drop_caches(id);
+        self.snapshots.remove(&id);
+        feature_cache::remove_feature_cache(id);
+        interpolation::release_font_contexts(id);
+        self.fonts.remove(&id).is_some()
+    }
+
+    /// Close all fonts
+    pub fn clear(&mut self) {
+        for id in self.fonts.keys() {
+            interpolation::release_font_contexts(*id);
+        }
+        self.fonts.clear();
+        self.snapshots.clear();
+        self.stashed.clear();
+        self.current = None;
+        self.cached = None;
+    }
+
+    /// Handle of the current font
+    pub fn current(&self) -> Option<u32> {
+        self.current
+    }
+
+    /// Handles of the open fonts, with glyph and master counts
+    pub fn list(&self) -> serde_json::Value {
+        let fonts: Vec<serde_json::Value> = self
+            .fonts
+            .iter()
+            .map(|(id, font)| {
+                serde_json::json!({
+                    "id": id,
+                    "glyphs": font.glyphs.len(),
+                    "masters": font.masters.len(),
+                    "current": self.current == Some(*id),
+                })
+            })
+            .collect();
+        serde_json::Value::Array(fonts)
+    }
+
+    /// The font of a handle, or the current font
+    pub fn font(&mut self, id: Option<u32>) -> Result<&babelfont::Font, ApiError> {
+        let id = self.resolve(id)?;
+        Ok(&self.fonts[&id])
+    }
+
+    /// The font of a handle, or the current font, without switching the caches
+    ///
+    /// For reading several fonts at once (e.g. to compare them); functions
+    /// using the outline, layer or shaping caches must use font().
+    pub fn get(&self, id: Option<u32>) -> Result<&babelfont::Font, ApiError> {
+        let id = match id {
+            Some(id) => id,
+            None => self.current.ok_or(ApiError::NoFont)?,
+        };
+        self.fonts.get(&id).ok_or(ApiError::UnknownFont { id })
+    }
+
+    /// The font of a handle, or the current font, for editing
+    pub fn font_mut(&mut self, id: Option<u32>) -> Result<&mut babelfont::Font, ApiError> {
+        let id = self.resolve(id)?;
+        Ok(self.fonts.get_mut(&id).unwrap())
+    }
+
+    /// Handle of a font, or of the current font
+    pub fn id(&self, id: Option<u32>) -> Result<u32, ApiError> {
+        match id {
+            Some(id) if self.fonts.contains_key(&id) => Ok(id),
+            Some(id) => Err(ApiError::UnknownFont { id }),
+            None => self.current.ok_or(ApiError::NoFont),
+        }
+    }
+
+    /// Invalidate the caches of a font after an edit
+    ///
+    /// Outlines, layers, interpolation context layers and compiled shaping
+    /// fonts are dropped as far as the change reaches, and changed glyphs get
+    /// the current time as their last change. Compiled layout tables are keyed
+    /// by a hash of their inputs and need no invalidation.
+    pub fn font_changed(&mut self, id: Option<u32>, change: FontChange) -> Result<(), ApiError> {
+        let id = self.resolve(id)?;
+        match change {
+            FontChange::Layout => {}
+            FontChange::Glyphs(glyph_names) if glyph_names.is_empty() => return Ok(()),
+            FontChange::Glyphs(glyph_names) => {
+                glyph_outlines::invalidate_glyphs(glyph_names);
+                interpolation::forget_context_glyphs(id, glyph_names);
+                let glyphs = self.fonts.get_mut(&id).unwrap().glyphs.iter_mut();
+                glyph_query::stamp_last_change(glyphs.filter(|glyph| glyph_names.contains(&glyph.name)));
+            }
+            FontChange::AllGlyphs => {
+                glyph_outlines::clear_outline_cache();
+                interpolation::clear_context_layers(id);
+                glyph_query::stamp_last_change(self.fonts.get_mut(&id).unwrap().glyphs.iter_mut());
+            }
+        }
+        shaping::clear_source_shaping_cache();
+        Ok(())
+    }
+
+    /// Drop the compiled shaping fonts of all fonts (call when included feature files change)
+    pub fn includes_changed(&mut self) {
+        shaping::clear_source_shaping_cache();
+        for caches in self.stashed.values_mut() {
+            caches.shaping = shaping::SourceShapingCaches::default();
+        }
+    }
+
+    /// Record the snapshot of a font, replacing the previous one
+    pub fn set_snapshot(&mut self, id: u32, snapshot: FontSnapshot) {
+        self.snapshots.insert(id, snapshot);
+    }
+
+    /// The last snapshot of a font
+    pub fn snapshot(&self, id: u32) -> Result<&FontSnapshot, ApiError> {
+        self.snapshots.get(&id).ok_or(ApiError::NoSnapshot { id })
+    }
+
+    /// Check a handle (or pick the current font) and point the caches at its font
+    fn resolve(&mut self, id: Option<u32>) -> Result<u32, ApiError> {
+        let id = self.id(id)?;
+        if self.cached != Some(id) {
+            self.switch_caches(id);
+        }
+        Ok(id)
+    }
+
+    /// Put the caches of the previous font aside and point the caches at a font
+    ///
+    /// The font gets back the caches it had when another font was used; a
+    /// font without caches starts with its component graph.
+    fn switch_caches(&mut self, id: u32) {
+        let caches = FontCaches {
+            outlines: glyph_outlines::take_outline_caches(),
+            shaping: shaping::take_source_shaping_caches(),
+        };
+        if let Some(previous) = self.cached.filter(|previous| self.fonts.contains_key(previous)) {
+            self.stashed.insert(previous, caches);
+        }
+        match self.stashed.remove(&id) {
+            Some(caches) => {
+                glyph_outlines::restore_outline_caches(caches.outlines);
+                shaping::restore_source_shaping_caches(caches.shaping);
+            }
+            None => {
+                if let Some(font) = self.fonts.get(&id) {
+                    glyph_outlines::build_component_graph(font);
+                }
+            }
+        }
+        self.cached = Some(id);
+    }
+
+    /// Drop the caches of a font, whether they are in use or put aside
+    fn drop_caches(&mut self, id: u32) {
+        self.stashed.remove(&id);
+        interpolation::clear_context_layers(id);
+        if self.cached == Some(id) {
+            self.cached = None;
+            glyph_outlines::clear_outline_cache();
+            shaping::clear_source_shaping_cache();
+        }
+    }
+}
+
+/// Outline, layer and shaping caches of a font other than the cached font
+struct FontCaches {
+    outlines: glyph_outlines::OutlineCaches,
+    shaping: shaping::SourceShapingCaches,
+}
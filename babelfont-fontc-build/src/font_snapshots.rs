@@ -0,0 +1,142 @@
+// Font snapshot module
+//
+// This module records what a source font looked like at a point in time, so
+// the host app can autosave the changes made since then as a small patch
+// instead of serializing the whole font every few seconds. A snapshot keeps
+// only hashes: one per glyph (of its serialized form) and one per top-level
+// font field, plus the glyph order. A delta then carries the glyphs and
+// fields whose hashes changed, in full, and the names of removed glyphs.
+//
+// Applying a delta to the font JSON the snapshot was taken of gives the
+// current font JSON: replace or append the glyphs of `glyphs.changed`,
+// drop those of `glyphs.removed`, reorder by `glyphs.order` if given, and
+// set the top-level `fields`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hasher;
+
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::feature_cache::HashWriter;
+
+/// Hashes of a font's glyphs and top-level fields at a point in time
+#[derive(Clone, Debug, Default)]
+pub struct FontSnapshot {
+    /// Glyph name -> hash of the serialized glyph
+    glyphs: HashMap<String, u64>,
+    glyph_order: Vec<String>,
+    /// Top-level field (masters, axes, features, names, ...) -> hash of its value
+    fields: BTreeMap<String, u64>,
+}
+
+/// Hash of a serialized glyph
+fn glyph_hash(glyph: &babelfont::Glyph) -> Result<u64, String> {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    serde_json::to_writer(HashWriter(&mut hasher), glyph)
+        .map_err(|e| format!("Failed to serialize glyph '{}': {}", glyph.name, e))?;
+    Ok(hasher.finish())
+}
+
+/// Hash of a JSON value
+fn value_hash(value: &JsonValue) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    // Writing to a hasher cannot fail
+    let _ = serde_json::to_writer(HashWriter(&mut hasher), value);
+    hasher.finish()
+}
+
+/// Top-level fields of a font other than its glyphs
+///
+/// The glyphs are hashed one by one, so they are moved out of the font
+/// while the rest is serialized rather than serialized twice.
+fn font_fields(font: &mut babelfont::Font) -> Result<Map<String, JsonValue>, String> {
+    let glyphs = std::mem::take(&mut font.glyphs);
+    let value = serde_json::to_value(&*font);
+    font.glyphs = glyphs;
+    match value.map_err(|e| format!("Failed to serialize font: {}", e))? {
+        JsonValue::Object(mut fields) => {
+            fields.remove("glyphs");
+            Ok(fields)
+        }
+        other => Err(format!("Expected the font to serialize to an object, got {}", other)),
+    }
+}
+
+impl FontSnapshot {
+    /// Take a snapshot of a font
+    pub fn capture(font: &mut babelfont::Font) -> Result<Self, String> {
+        let fields = font_fields(font)?
+            .iter()
+            .map(|(key, value)| (key.clone(), value_hash(value)))
+            .collect();
+        let glyphs = font
+            .glyphs
+            .iter()
+            .map(|glyph| Ok((glyph.name.to_string(), glyph_hash(glyph)?)))
+            .collect::<Result<_, String>>()?;
+        let glyph_order = font.glyphs.iter().map(|glyph| glyph.name.to_string()).collect();
+        Ok(FontSnapshot { glyphs, glyph_order, fields })
+    }
+
+    /// Number of glyphs in the snapshot
+    pub fn glyph_count(&self) -> usize {
+        self.glyph_order.len()
+    }
+}
+
+/// Changes of a font since a snapshot
+///
+/// # Returns
+/// * `(JsonValue, FontSnapshot)` - The delta, '{"unchanged": false, "glyphs": {"changed": {"a": {...}}, "removed": ["b"], "order": null}, "fields": {"masters": [...]}}',
+///   and a snapshot of the font as it is now. `order` is only given if the glyph order changed other than by
+///   appending added glyphs; fields removed from the font are null.
+pub fn diff_since(snapshot: &FontSnapshot, font: &mut babelfont::Font) -> Result<(JsonValue, FontSnapshot), String> {
+    let current_fields = font_fields(font)?;
+    let mut field_hashes = BTreeMap::new();
+    let mut fields = Map::new();
+    for (key, value) in current_fields {
+        let hash = value_hash(&value);
+        if snapshot.fields.get(&key) != Some(&hash) {
+            fields.insert(key.clone(), value);
+        }
+        field_hashes.insert(key, hash);
+    }
+    for key in snapshot.fields.keys() {
+        if !field_hashes.contains_key(key) {
+            fields.insert(key.clone(), JsonValue::Null);
+        }
+    }
+
+    let mut glyph_hashes = HashMap::new();
+    let mut changed = Map::new();
+    for glyph in font.glyphs.iter() {
+        let name = glyph.name.to_string();
+        let hash = glyph_hash(glyph)?;
+        if snapshot.glyphs.get(&name) != Some(&hash) {
+            let value = serde_json::to_value(glyph).map_err(|e| format!("Failed to serialize glyph '{}': {}", name, e))?;
+            changed.insert(name.clone(), value);
+        }
+        glyph_hashes.insert(name, hash);
+    }
+    let removed: Vec<&String> = snapshot
+        .glyph_order
+        .iter()
+        .filter(|name| !glyph_hashes.contains_key(*name))
+        .collect();
+
+    // Added glyphs are appended when the delta is applied, so the order is
+    // only needed if the remaining glyphs moved or glyphs were inserted
+    let glyph_order: Vec<String> = font.glyphs.iter().map(|glyph| glyph.name.to_string()).collect();
+    let kept: Vec<&String> = snapshot.glyph_order.iter().filter(|name| glyph_hashes.contains_key(*name)).collect();
+    let appended = glyph_order.len() >= kept.len() && glyph_order.iter().zip(&kept).all(|(name, kept)| name == *kept);
+    let order = if appended { JsonValue::Null } else { json!(glyph_order) };
+
+    let unchanged = fields.is_empty() && changed.is_empty() && removed.is_empty() && order.is_null();
+    let delta = json!({
+        "unchanged": unchanged,
+        "glyphs": { "changed": changed, "removed": removed, "order": order },
+        "fields": fields,
+    });
+    let current = FontSnapshot { glyphs: glyph_hashes, glyph_order, fields: field_hashes };
+    Ok((delta, current))
+}
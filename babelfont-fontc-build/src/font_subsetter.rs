@@ -0,0 +1,243 @@
+// Font subsetting module
+//
+// This module cuts compiled fonts down to a set of characters or glyphs, so
+// the publish flow can make language-specific webfont slices from a finished
+// binary without recompiling the source. Subsetting is done by klippa, the
+// fontations port of the HarfBuzz subsetter: the glyph set is closed over
+// composite glyphs, GSUB substitutions and COLR paints, glyf/loca, cmap and
+// the metrics tables are rewritten, and GSUB/GPOS/GDEF are pruned to the
+// retained glyphs, features and scripts. Defaults follow hb-subset.
+//
+// klippa works on the font types of its own fontations revision, which are
+// not those of the read-fonts release used elsewhere; only bytes and plain
+// numbers cross between the two.
+
+use std::collections::BTreeSet;
+
+use klippa::{subset_font, Plan, SubsetFlags, DEFAULT_LAYOUT_FEATURES};
+use klippa_write_fonts::read::collections::IntSet;
+use klippa_write_fonts::read::FontRef as SubsetFontRef;
+use klippa_write_fonts::types::{GlyphId, NameId, Tag};
+use serde_json::Value as JsonValue;
+use wasm_bindgen::prelude::*;
+
+use crate::errors::ApiError;
+use crate::logging;
+
+/// Tables dropped unless `dropTables` is given (hb-subset's defaults)
+const DEFAULT_DROP_TABLES: [&[u8; 4]; 18] = [
+    // AAT and legacy kerning, not subset
+    b"morx", b"mort", b"kerx", b"kern",
+    // Tables fontTools drops as well
+    b"BASE", b"JSTF", b"DSIG", b"EBDT", b"EBLC", b"EBSC", b"SVG ", b"PCLT", b"LTSH",
+    // Graphite
+    b"Feat", b"Glat", b"Gloc", b"Silf", b"Sill",
+];
+
+/// Name IDs kept unless `nameIds` is given: family, style, unique ID, full
+/// name, version and PostScript name
+const DEFAULT_NAME_IDS: std::ops::RangeInclusive<u16> = 0..=6;
+
+/// Name record language kept unless `nameLanguages` is given (English, US)
+const DEFAULT_NAME_LANGUAGE: u16 = 0x0409;
+
+/// Characters and glyphs a subset keeps
+///
+/// Glyphs are given by name or glyph ID; names are looked up in the glyph
+/// order of the compiled font.
+fn subset_input(font_bytes: &[u8], subset: &JsonValue) -> Result<(IntSet<u32>, IntSet<GlyphId>), ApiError> {
+    let mut unicodes = IntSet::empty();
+    match subset.get("unicodes") {
+        None | Some(JsonValue::Null) => {}
+        Some(JsonValue::String(ranges)) => {
+            unicodes.extend(
+                klippa::parse_unicodes(ranges)
+                    .map_err(|_| ApiError::invalid_argument("unicodes", ranges))?
+                    .iter(),
+            );
+        }
+        Some(JsonValue::Array(codepoints)) => {
+            for codepoint in codepoints {
+                let value = codepoint
+                    .as_u64()
+                    .and_then(|value| u32::try_from(value).ok())
+                    .filter(|&value| char::from_u32(value).is_some())
+                    .ok_or_else(|| ApiError::invalid_argument("unicodes", codepoint))?;
+                unicodes.insert(value);
+            }
+        }
+        Some(other) => return Err(ApiError::invalid_argument("unicodes", other)),
+    }
+    if let Some(text) = subset.get("text").filter(|text| !text.is_null()) {
+        let text = text.as_str().ok_or_else(|| ApiError::invalid_argument("text", text))?;
+        unicodes.extend(text.chars().map(u32::from));
+    }
+
+    let mut glyphs = IntSet::empty();
+    if let Some(requested) = subset.get("glyphs").filter(|glyphs| !glyphs.is_null()) {
+        let requested = requested.as_array().ok_or_else(|| ApiError::invalid_argument("glyphs", requested))?;
+        let font = read_fonts::FontRef::new(font_bytes).map_err(ApiError::invalid_font)?;
+        let glyph_order = crate::font_reader::read_glyph_order(&font)?;
+        for glyph in requested {
+            let glyph_id = match glyph {
+                JsonValue::String(name) => glyph_order
+                    .iter()
+                    .position(|candidate| candidate == name)
+                    .ok_or_else(|| ApiError::glyph_not_found(name))?,
+                JsonValue::Number(number) => number
+                    .as_u64()
+                    .map(|glyph_id| glyph_id as usize)
+                    .filter(|&glyph_id| glyph_id < glyph_order.len())
+                    .ok_or_else(|| ApiError::glyph_not_found(number.to_string()))?,
+                other => return Err(ApiError::invalid_argument("glyphs", other)),
+            };
+            glyphs.insert(GlyphId::new(glyph_id as u32));
+        }
+    }
+    Ok((unicodes, glyphs))
+}
+
+/// Parse an option holding a list of table, feature or script tags
+///
+/// "*" selects every tag; a missing option gives the default.
+fn tag_option(options: &JsonValue, key: &str, default: impl IntoIterator<Item = Tag>) -> Result<IntSet<Tag>, ApiError> {
+    match options.get(key) {
+        None | Some(JsonValue::Null) => Ok(default.into_iter().collect()),
+        Some(JsonValue::String(all)) if all == "*" => Ok(IntSet::all()),
+        Some(JsonValue::Array(tags)) => tags
+            .iter()
+            .map(|tag| {
+                tag.as_str()
+                    .and_then(|tag| Tag::new_checked(tag.as_bytes()).ok())
+                    .ok_or_else(|| ApiError::invalid_argument(key, tag))
+            })
+            .collect(),
+        Some(other) => Err(ApiError::invalid_argument(key, other)),
+    }
+}
+
+/// Parse an option holding a list of numbers (name IDs, language IDs)
+///
+/// "*" selects every number; a missing option gives the default.
+fn number_option(options: &JsonValue, key: &str, default: impl IntoIterator<Item = u16>) -> Result<IntSet<u16>, ApiError> {
+    match options.get(key) {
+        None | Some(JsonValue::Null) => Ok(default.into_iter().collect()),
+        Some(JsonValue::String(all)) if all == "*" => Ok(IntSet::all()),
+        Some(JsonValue::Array(numbers)) => numbers
+            .iter()
+            .map(|number| {
+                number
+                    .as_u64()
+                    .and_then(|number| u16::try_from(number).ok())
+                    .ok_or_else(|| ApiError::invalid_argument(key, number))
+            })
+            .collect(),
+        Some(other) => Err(ApiError::invalid_argument(key, other)),
+    }
+}
+
+/// Subset font bytes with parsed arguments
+fn subset_bytes(font_bytes: &[u8], subset: &JsonValue, options: &JsonValue) -> Result<Vec<u8>, ApiError> {
+    let (unicodes, glyphs) = subset_input(font_bytes, subset)?;
+
+    let flag = |key: &str, default: bool| options.get(key).and_then(|v| v.as_bool()).unwrap_or(default);
+    let mut flags = SubsetFlags::default();
+    for (key, default, value) in [
+        ("dropHinting", false, SubsetFlags::SUBSET_FLAGS_NO_HINTING),
+        ("retainGids", false, SubsetFlags::SUBSET_FLAGS_RETAIN_GIDS),
+        ("notdefOutline", false, SubsetFlags::SUBSET_FLAGS_NOTDEF_OUTLINE),
+        ("setOverlapsFlag", false, SubsetFlags::SUBSET_FLAGS_SET_OVERLAPS_FLAG),
+    ] {
+        if flag(key, default) {
+            flags |= value;
+        }
+    }
+
+    let drop_tables = tag_option(options, "dropTables", DEFAULT_DROP_TABLES.iter().map(|tag| Tag::new(tag)))?;
+    let layout_features = tag_option(options, "layoutFeatures", DEFAULT_LAYOUT_FEATURES.iter().copied())?;
+    let layout_scripts = match options.get("layoutScripts") {
+        None | Some(JsonValue::Null) => IntSet::all(),
+        Some(_) => tag_option(options, "layoutScripts", [])?,
+    };
+    let name_ids: IntSet<NameId> = number_option(options, "nameIds", DEFAULT_NAME_IDS)?
+        .iter()
+        .map(NameId::new)
+        .collect();
+    let name_languages = number_option(options, "nameLanguages", [DEFAULT_NAME_LANGUAGE])?;
+
+    let font = SubsetFontRef::new(font_bytes).map_err(ApiError::invalid_font)?;
+    let plan = Plan::new(
+        &glyphs,
+        &unicodes,
+        &font,
+        flags,
+        &drop_tables,
+        &layout_scripts,
+        &layout_features,
+        &name_ids,
+        &name_languages,
+    );
+    subset_font(&font, &plan).map_err(|e| ApiError::compilation("Subsetting", e))
+}
+
+/// Subset a compiled font to a set of characters or glyphs
+///
+/// The glyph set is closed over composite glyphs, GSUB substitutions and
+/// COLR paints, so ligatures and alternates of the kept characters stay.
+/// glyf/loca, cmap, hmtx and the layout tables are rewritten for the
+/// retained glyphs; glyph IDs are renumbered unless `retainGids` is set.
+/// Only TrueType (glyf) outlines are subset; CFF fonts are not supported
+/// by klippa yet.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF font bytes
+/// * `subset_json` - JSON object with any of:
+///   - `unicodes`: array of codepoints, or a string of hex codepoints and ranges ("U+0041-005A,U+00C0")
+///   - `text`: string whose characters are kept
+///   - `glyphs`: array of glyph names or glyph IDs to keep
+/// * `options_json` - Optional JSON object:
+///   - `layoutFeatures`: array of feature tags to keep, or "*" (default: hb-subset's default features)
+///   - `layoutScripts`: array of script tags to keep, or "*" (default: all)
+///   - `dropTables`: array of table tags to drop (default: hb-subset's list, e.g. kern, morx, DSIG)
+///   - `nameIds`: array of name IDs to keep, or "*" (default: 0-6)
+///   - `nameLanguages`: array of name language IDs to keep, or "*" (default: 0x0409)
+///   - `dropHinting`: bool - Drop TrueType instructions (default: false)
+///   - `retainGids`: bool - Keep glyph IDs, emptying dropped glyphs (default: false)
+///   - `notdefOutline`: bool - Keep the .notdef outline (default: false)
+///   - `setOverlapsFlag`: bool - Set OVERLAP_SIMPLE on simple glyphs (default: false)
+///
+/// # Returns
+/// * `Vec<u8>` - The subset font
+#[wasm_bindgen]
+pub fn subset_compiled_font(font_bytes: &[u8], subset_json: &str, options_json: Option<String>) -> Result<Vec<u8>, JsValue> {
+    let subset: JsonValue = serde_json::from_str(subset_json)
+        .map_err(|e| ApiError::invalid_json("subset_json", e))?;
+    if !subset.is_object() {
+        return Err(ApiError::invalid_argument("subset_json", subset).into());
+    }
+    let options_json = options_json.as_deref().map(str::trim).unwrap_or("");
+    let options: JsonValue = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("options_json", e))?
+    };
+
+    let started = js_sys::Date::now();
+    let subset_font = subset_bytes(font_bytes, &subset, &options)?;
+
+    let requested: BTreeSet<&str> = ["unicodes", "text", "glyphs"]
+        .into_iter()
+        .filter(|key| subset.get(key).is_some_and(|value| !value.is_null()))
+        .collect();
+    logging::info(
+        "subset",
+        &format!("Subset font: {} -> {} bytes", font_bytes.len(), subset_font.len()),
+        serde_json::json!({
+            "input": requested,
+            "bytes": subset_font.len(),
+            "ms": js_sys::Date::now() - started,
+        }),
+    );
+    Ok(subset_font)
+}
@@ -0,0 +1,1076 @@
+// Compiled font validation
+//
+// This module provides structural sanity checks for compiled font binaries,
+// run before the user downloads a font so broken output is caught early,
+// fontbakery-style QA checks grouped into profiles, and a check of the
+// vertical metrics against the glyph extremes. Checks report structured
+// findings instead of failing on the first problem.
+
+use read_fonts::types::{NameId, Tag};
+use read_fonts::{FontRef, TableProvider};
+use serde_json;
+use skrifa::instance::Location;
+use skrifa::MetadataProvider;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+use crate::errors::ApiError;
+
+/// A single validation finding
+pub(crate) struct Finding {
+    pub severity: &'static str,
+    pub check: &'static str,
+    pub message: String,
+    pub context: serde_json::Value,
+}
+
+impl Finding {
+    pub fn error(check: &'static str, message: String, context: serde_json::Value) -> Self {
+        Finding { severity: "error", check, message, context }
+    }
+
+    pub fn warning(check: &'static str, message: String, context: serde_json::Value) -> Self {
+        Finding { severity: "warning", check, message, context }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "severity": self.severity,
+            "check": self.check,
+            "message": self.message,
+            "context": self.context,
+        })
+    }
+}
+
+/// Serialize a list of findings into a report object
+pub(crate) fn findings_report(findings: &[Finding]) -> serde_json::Value {
+    let errors = findings.iter().filter(|f| f.severity == "error").count();
+    let warnings = findings.iter().filter(|f| f.severity == "warning").count();
+    serde_json::json!({
+        "valid": errors == 0,
+        "errorCount": errors,
+        "warningCount": warnings,
+        "findings": findings.iter().map(Finding::to_json).collect::<Vec<_>>(),
+    })
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn read_i16(bytes: &[u8], offset: usize) -> Option<i16> {
+    read_u16(bytes, offset).map(|value| value as i16)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// OpenType table checksum: sum of big-endian u32 words, zero padded
+fn table_checksum(bytes: &[u8]) -> u32 {
+    bytes.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Check that the tables every font needs are present
+fn check_required_tables(font: &FontRef, findings: &mut Vec<Finding>) {
+    for tag in [b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post"] {
+        if font.table_data(Tag::new(tag)).is_none() {
+            findings.push(Finding::error(
+                "required-tables",
+                format!("Required table '{}' is missing", Tag::new(tag)),
+                serde_json::json!({ "table": Tag::new(tag).to_string() }),
+            ));
+        }
+    }
+
+    let has = |tag: &[u8; 4]| font.table_data(Tag::new(tag)).is_some();
+    let has_truetype = has(b"glyf") && has(b"loca");
+    let has_cff = has(b"CFF ") || has(b"CFF2");
+    if !has_truetype && !has_cff {
+        findings.push(Finding::error(
+            "required-tables",
+            "Font has no outline tables (glyf/loca or CFF/CFF2)".to_string(),
+            serde_json::json!({}),
+        ));
+    } else if has(b"glyf") != has(b"loca") {
+        findings.push(Finding::error(
+            "required-tables",
+            "Tables 'glyf' and 'loca' must be present together".to_string(),
+            serde_json::json!({ "glyf": has(b"glyf"), "loca": has(b"loca") }),
+        ));
+    }
+}
+
+/// Check that hhea/hmtx metric counts agree with maxp
+fn check_metrics_counts(font: &FontRef, num_glyphs: u16, findings: &mut Vec<Finding>) {
+    let Some(hhea) = font.table_data(Tag::new(b"hhea")) else { return };
+    let Some(number_of_h_metrics) = read_u16(hhea.as_bytes(), 34) else {
+        findings.push(Finding::error(
+            "hmtx-maxp",
+            "hhea table is truncated".to_string(),
+            serde_json::json!({ "length": hhea.len() }),
+        ));
+        return;
+    };
+
+    if number_of_h_metrics == 0 || number_of_h_metrics > num_glyphs {
+        findings.push(Finding::error(
+            "hmtx-maxp",
+            format!(
+                "hhea.numberOfHMetrics ({}) must be between 1 and maxp.numGlyphs ({})",
+                number_of_h_metrics, num_glyphs
+            ),
+            serde_json::json!({ "numberOfHMetrics": number_of_h_metrics, "numGlyphs": num_glyphs }),
+        ));
+        return;
+    }
+
+    if let Some(hmtx) = font.table_data(Tag::new(b"hmtx")) {
+        let expected = 4 * number_of_h_metrics as usize + 2 * (num_glyphs - number_of_h_metrics) as usize;
+        if hmtx.len() < expected {
+            findings.push(Finding::error(
+                "hmtx-maxp",
+                format!("hmtx table is {} bytes, expected at least {}", hmtx.len(), expected),
+                serde_json::json!({ "length": hmtx.len(), "expected": expected }),
+            ));
+        } else if hmtx.len() > expected + 3 {
+            findings.push(Finding::warning(
+                "hmtx-maxp",
+                format!("hmtx table is {} bytes, expected {}", hmtx.len(), expected),
+                serde_json::json!({ "length": hmtx.len(), "expected": expected }),
+            ));
+        }
+    }
+}
+
+/// Check that cmap subtables map to valid glyphs and agree with each other
+fn check_cmap(font: &FontRef, num_glyphs: u16, findings: &mut Vec<Finding>) {
+    use read_fonts::tables::cmap::CmapSubtable;
+
+    let Ok(cmap) = font.cmap() else { return };
+    let mut mappings: HashMap<u32, u32> = HashMap::new();
+    let mut out_of_range = Vec::new();
+    let mut conflicts = Vec::new();
+    let mut notdef = Vec::new();
+
+    for record in cmap.encoding_records() {
+        let Ok(subtable) = record.subtable(cmap.offset_data()) else {
+            findings.push(Finding::error(
+                "cmap",
+                format!(
+                    "cmap subtable for platform {:?} encoding {} could not be read",
+                    record.platform_id(),
+                    record.encoding_id()
+                ),
+                serde_json::json!({}),
+            ));
+            continue;
+        };
+
+        let pairs: Vec<(u32, u32)> = match subtable {
+            CmapSubtable::Format4(table) => table.iter().map(|(cp, gid)| (cp, gid.to_u32())).collect(),
+            CmapSubtable::Format12(table) => table.iter().map(|(cp, gid)| (cp, gid.to_u32())).collect(),
+            _ => continue,
+        };
+
+        for (codepoint, gid) in pairs {
+            if codepoint == 0xFFFF {
+                continue;
+            }
+            if gid >= num_glyphs as u32 {
+                out_of_range.push(serde_json::json!({ "codepoint": codepoint, "gid": gid }));
+            } else if gid == 0 {
+                notdef.push(codepoint);
+            }
+            match mappings.get(&codepoint) {
+                Some(&existing) if existing != gid => {
+                    conflicts.push(serde_json::json!({ "codepoint": codepoint, "gids": [existing, gid] }));
+                }
+                Some(_) => {}
+                None => {
+                    mappings.insert(codepoint, gid);
+                }
+            }
+        }
+    }
+
+    // Round trip: the preferred subtable must resolve every collected mapping
+    let mut round_trip_failures = Vec::new();
+    for (&codepoint, &gid) in &mappings {
+        let mapped = cmap.map_codepoint(codepoint).map(|g| g.to_u32());
+        if mapped != Some(gid) {
+            round_trip_failures.push(serde_json::json!({
+                "codepoint": codepoint,
+                "expected": gid,
+                "actual": mapped,
+            }));
+        }
+    }
+
+    if !out_of_range.is_empty() {
+        findings.push(Finding::error(
+            "cmap",
+            format!("{} codepoints map to glyph IDs beyond maxp.numGlyphs", out_of_range.len()),
+            serde_json::json!({ "mappings": out_of_range }),
+        ));
+    }
+    if !conflicts.is_empty() {
+        findings.push(Finding::error(
+            "cmap",
+            format!("{} codepoints are mapped differently by different subtables", conflicts.len()),
+            serde_json::json!({ "mappings": conflicts }),
+        ));
+    }
+    if !round_trip_failures.is_empty() {
+        findings.push(Finding::error(
+            "cmap",
+            format!("{} codepoints do not round-trip through the preferred cmap subtable", round_trip_failures.len()),
+            serde_json::json!({ "mappings": round_trip_failures }),
+        ));
+    }
+    if !notdef.is_empty() {
+        findings.push(Finding::warning(
+            "cmap",
+            format!("{} codepoints are explicitly mapped to .notdef", notdef.len()),
+            serde_json::json!({ "codepoints": notdef }),
+        ));
+    }
+}
+
+/// Check table checksums and the head checksum adjustment
+fn check_checksums(font: &FontRef, font_bytes: &[u8], findings: &mut Vec<Finding>) {
+    for record in font.table_directory.table_records() {
+        let start = record.offset() as usize;
+        let length = record.length() as usize;
+        // An end past usize::MAX (on 32-bit wasm) is beyond the end of the file too
+        let table_bytes = start.checked_add(length).and_then(|end| font_bytes.get(start..end));
+        let Some(table_bytes) = table_bytes else {
+            findings.push(Finding::error(
+                "checksums",
+                format!("Table '{}' extends beyond the end of the file", record.tag()),
+                serde_json::json!({ "table": record.tag().to_string(), "offset": start, "length": length }),
+            ));
+            continue;
+        };
+
+        let mut computed = table_checksum(table_bytes);
+        if record.tag() == Tag::new(b"head") {
+            // checksumAdjustment is treated as zero when computing the head checksum
+            computed = computed.wrapping_sub(read_u32(table_bytes, 8).unwrap_or(0));
+        }
+
+        if computed != record.checksum() {
+            findings.push(Finding::warning(
+                "checksums",
+                format!("Checksum mismatch for table '{}'", record.tag()),
+                serde_json::json!({
+                    "table": record.tag().to_string(),
+                    "stored": record.checksum(),
+                    "computed": computed,
+                }),
+            ));
+        }
+    }
+
+    if let Some(head) = font.table_data(Tag::new(b"head")) {
+        let stored = read_u32(head.as_bytes(), 8).unwrap_or(0);
+        let whole_font = table_checksum(font_bytes).wrapping_sub(stored);
+        let expected = 0xB1B0AFBAu32.wrapping_sub(whole_font);
+        if stored != expected {
+            findings.push(Finding::warning(
+                "checksums",
+                "head.checksumAdjustment does not match the font checksum".to_string(),
+                serde_json::json!({ "stored": stored, "expected": expected }),
+            ));
+        }
+    }
+}
+
+/// Check that loca offsets are monotonic and stay within glyf
+fn check_loca(font: &FontRef, num_glyphs: u16, findings: &mut Vec<Finding>) {
+    let (Some(loca), Some(glyf), Some(head)) = (
+        font.table_data(Tag::new(b"loca")),
+        font.table_data(Tag::new(b"glyf")),
+        font.table_data(Tag::new(b"head")),
+    ) else {
+        return;
+    };
+
+    let Some(index_to_loc_format) = read_u16(head.as_bytes(), 50) else { return };
+    let loca_bytes = loca.as_bytes();
+    let offset_at = |index: usize| -> Option<u32> {
+        if index_to_loc_format == 0 {
+            read_u16(loca_bytes, index * 2).map(|o| o as u32 * 2)
+        } else {
+            read_u32(loca_bytes, index * 4)
+        }
+    };
+
+    let entry_count = num_glyphs as usize + 1;
+    if offset_at(entry_count - 1).is_none() {
+        findings.push(Finding::error(
+            "loca",
+            format!("loca table is too short for {} glyphs", num_glyphs),
+            serde_json::json!({ "length": loca_bytes.len(), "indexToLocFormat": index_to_loc_format }),
+        ));
+        return;
+    }
+
+    let mut decreasing = Vec::new();
+    let mut previous = 0u32;
+    for index in 0..entry_count {
+        let offset = offset_at(index).unwrap_or(previous);
+        if offset < previous {
+            decreasing.push(serde_json::json!({ "gid": index, "offset": offset, "previous": previous }));
+        }
+        previous = offset;
+    }
+
+    if !decreasing.is_empty() {
+        findings.push(Finding::error(
+            "loca",
+            format!("loca offsets decrease at {} glyphs", decreasing.len()),
+            serde_json::json!({ "entries": decreasing }),
+        ));
+    }
+
+    if previous as usize > glyf.len() {
+        findings.push(Finding::error(
+            "loca",
+            format!("Last loca offset ({}) points beyond the glyf table ({} bytes)", previous, glyf.len()),
+            serde_json::json!({ "lastOffset": previous, "glyfLength": glyf.len() }),
+        ));
+    }
+}
+
+/// Run structural sanity checks on compiled font bytes
+///
+/// Checks performed:
+/// - `required-tables`: cmap, head, hhea, hmtx, maxp, name, OS/2, post and outlines
+/// - `hmtx-maxp`: hhea.numberOfHMetrics and hmtx length agree with maxp.numGlyphs
+/// - `cmap`: mapped glyph IDs exist, subtables agree and mappings round-trip
+/// - `checksums`: per-table checksums and head.checksumAdjustment
+/// - `loca`: offsets are monotonic and stay within glyf
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "valid": false,
+///   "errorCount": 1,
+///   "warningCount": 0,
+///   "findings": [
+///     { "severity": "error", "check": "loca", "message": "...", "context": { ... } }
+///   ]
+/// }
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON validation report
+#[wasm_bindgen]
+pub fn validate_compiled_font(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let mut findings = Vec::new();
+
+    check_required_tables(&font, &mut findings);
+
+    if let Ok(maxp) = font.maxp() {
+        let num_glyphs = maxp.num_glyphs();
+        check_metrics_counts(&font, num_glyphs, &mut findings);
+        check_cmap(&font, num_glyphs, &mut findings);
+        check_loca(&font, num_glyphs, &mut findings);
+    }
+
+    check_checksums(&font, font_bytes, &mut findings);
+
+    serde_json::to_string(&findings_report(&findings))
+        .map_err(|e| ApiError::serialization("validation report", e).into())
+}
+
+// ============================================================================
+// QA checks
+// ============================================================================
+
+/// A QA check: identifier, title, profiles it belongs to, and the check itself
+struct QaCheck {
+    id: &'static str,
+    title: &'static str,
+    profiles: &'static [&'static str],
+    run: fn(&FontRef, &mut Vec<Finding>),
+}
+
+const QA_PROFILES: &[&str] = &["universal", "googlefonts"];
+
+const QA_CHECKS: &[QaCheck] = &[
+    QaCheck {
+        id: "required-tables",
+        title: "Font has all required tables",
+        profiles: &["universal", "googlefonts"],
+        run: check_required_tables,
+    },
+    QaCheck {
+        id: "monotonic-fvar",
+        title: "Variation axes and avar mappings are monotonic",
+        profiles: &["universal", "googlefonts"],
+        run: check_monotonic_fvar,
+    },
+    QaCheck {
+        id: "name-consistency",
+        title: "Name table entries are present and consistent",
+        profiles: &["universal", "googlefonts"],
+        run: check_name_consistency,
+    },
+    QaCheck {
+        id: "win-typo-metrics",
+        title: "Windows and typographic vertical metrics are sane",
+        profiles: &["universal", "googlefonts"],
+        run: check_win_typo_metrics,
+    },
+    QaCheck {
+        id: "fs-selection",
+        title: "OS/2 fsSelection agrees with head.macStyle and the weight class",
+        profiles: &["universal", "googlefonts"],
+        run: check_fs_selection,
+    },
+    QaCheck {
+        id: "gf-vertical-metrics",
+        title: "USE_TYPO_METRICS is set and hhea metrics match the typographic metrics",
+        profiles: &["googlefonts"],
+        run: check_gf_vertical_metrics,
+    },
+    QaCheck {
+        id: "gf-fstype",
+        title: "Font is installable (OS/2 fsType is 0)",
+        profiles: &["googlefonts"],
+        run: check_gf_fstype,
+    },
+];
+
+/// Check that axis ranges are ordered and avar segment maps are monotonic
+fn check_monotonic_fvar(font: &FontRef, findings: &mut Vec<Finding>) {
+    let Ok(fvar) = font.fvar() else { return };
+    let Ok(axes) = fvar.axes() else { return };
+
+    let tags: Vec<String> = axes.iter().map(|axis| axis.axis_tag().to_string()).collect();
+    for axis in axes.iter() {
+        let (min, default, max) = (
+            axis.min_value().to_f64(),
+            axis.default_value().to_f64(),
+            axis.max_value().to_f64(),
+        );
+        if !(min <= default && default <= max) {
+            findings.push(Finding::error(
+                "monotonic-fvar",
+                format!("Axis '{}' range is not ordered: min {}, default {}, max {}", axis.axis_tag(), min, default, max),
+                serde_json::json!({ "axis": axis.axis_tag().to_string(), "min": min, "default": default, "max": max }),
+            ));
+        }
+    }
+
+    // avar: version (4), reserved (2), axisCount (2), then per axis a
+    // positionMapCount followed by (fromCoordinate, toCoordinate) F2Dot14 pairs
+    let Some(avar) = font.table_data(Tag::new(b"avar")) else { return };
+    let bytes = avar.as_bytes();
+    let mut offset = 8;
+    for tag in &tags {
+        let Some(count) = read_u16(bytes, offset) else {
+            findings.push(Finding::error(
+                "monotonic-fvar",
+                "avar table is truncated".to_string(),
+                serde_json::json!({ "axis": tag }),
+            ));
+            return;
+        };
+        offset += 2;
+        let mut maps = Vec::with_capacity(count as usize);
+        for index in 0..count as usize {
+            let (Some(from), Some(to)) = (read_i16(bytes, offset + index * 4), read_i16(bytes, offset + index * 4 + 2)) else {
+                findings.push(Finding::error(
+                    "monotonic-fvar",
+                    "avar table is truncated".to_string(),
+                    serde_json::json!({ "axis": tag }),
+                ));
+                return;
+            };
+            maps.push((from as f64 / 16384.0, to as f64 / 16384.0));
+        }
+        offset += count as usize * 4;
+
+        if maps.is_empty() {
+            continue;
+        }
+        let increasing = maps.windows(2).all(|pair| pair[1].0 > pair[0].0 && pair[1].1 >= pair[0].1);
+        if !increasing {
+            findings.push(Finding::error(
+                "monotonic-fvar",
+                format!("avar mapping of axis '{}' is not monotonic", tag),
+                serde_json::json!({ "axis": tag, "mappings": &maps }),
+            ));
+        }
+        for required in [-1.0, 0.0, 1.0] {
+            if !maps.iter().any(|&(from, to)| from == required && to == required) {
+                findings.push(Finding::error(
+                    "monotonic-fvar",
+                    format!("avar mapping of axis '{}' does not map {} to {}", tag, required, required),
+                    serde_json::json!({ "axis": tag, "mappings": &maps }),
+                ));
+            }
+        }
+    }
+}
+
+/// Whether a PostScript name only uses printable ASCII without delimiters
+fn valid_postscript_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 63
+        && name.bytes().all(|b| (33..=126).contains(&b) && !b"[](){}<>/%".contains(&b))
+}
+
+/// Check that the basic name IDs exist and agree with each other
+fn check_name_consistency(font: &FontRef, findings: &mut Vec<Finding>) {
+    let Ok(name) = font.name() else { return };
+    let get = |id: u16| crate::font_reader::english_name(&name, NameId::new(id));
+
+    for (id, label) in [(1, "family"), (2, "subfamily"), (4, "full name"), (6, "PostScript name")] {
+        if get(id).is_none_or(|value| value.trim().is_empty()) {
+            findings.push(Finding::error(
+                "name-consistency",
+                format!("Name ID {} ({}) is missing", id, label),
+                serde_json::json!({ "nameId": id }),
+            ));
+        }
+    }
+
+    if let Some(postscript) = get(6) {
+        if !valid_postscript_name(&postscript) {
+            findings.push(Finding::error(
+                "name-consistency",
+                format!("PostScript name '{}' must be at most 63 printable ASCII characters without spaces or []{{}}()<>/%", postscript),
+                serde_json::json!({ "nameId": 6, "value": postscript }),
+            ));
+        }
+    }
+
+    if let Some(subfamily) = get(2) {
+        if !["Regular", "Italic", "Bold", "Bold Italic"].contains(&subfamily.as_str()) {
+            findings.push(Finding::warning(
+                "name-consistency",
+                format!("Subfamily name '{}' is not one of Regular, Italic, Bold, Bold Italic", subfamily),
+                serde_json::json!({ "nameId": 2, "value": subfamily }),
+            ));
+        }
+    }
+
+    // Full name: typographic family and subfamily, "Regular" optional
+    let family = get(16).or_else(|| get(1));
+    let subfamily = get(17).or_else(|| get(2));
+    if let (Some(family), Some(subfamily), Some(full_name)) = (family, subfamily, get(4)) {
+        let expected = format!("{} {}", family, subfamily);
+        let matches = full_name == expected || (subfamily == "Regular" && full_name == family);
+        if !matches {
+            findings.push(Finding::warning(
+                "name-consistency",
+                format!("Full name '{}' does not match family and subfamily ('{}')", full_name, expected),
+                serde_json::json!({ "nameId": 4, "value": full_name, "expected": expected }),
+            ));
+        }
+    }
+}
+
+/// Check that OS/2 win and typo metrics have the right signs and avoid clipping
+fn check_win_typo_metrics(font: &FontRef, findings: &mut Vec<Finding>) {
+    let (Some(os2), Some(head)) = (font.table_data(Tag::new(b"OS/2")), font.table_data(Tag::new(b"head"))) else {
+        return;
+    };
+    let (os2, head) = (os2.as_bytes(), head.as_bytes());
+    let (Some(typo_ascender), Some(typo_descender), Some(typo_line_gap), Some(win_ascent), Some(win_descent)) = (
+        read_i16(os2, 68),
+        read_i16(os2, 70),
+        read_i16(os2, 72),
+        read_u16(os2, 74),
+        read_u16(os2, 76),
+    ) else {
+        findings.push(Finding::error(
+            "win-typo-metrics",
+            "OS/2 table is truncated".to_string(),
+            serde_json::json!({ "length": os2.len() }),
+        ));
+        return;
+    };
+
+    if typo_ascender <= 0 || typo_descender > 0 {
+        findings.push(Finding::error(
+            "win-typo-metrics",
+            format!(
+                "sTypoAscender ({}) must be positive and sTypoDescender ({}) zero or negative",
+                typo_ascender, typo_descender
+            ),
+            serde_json::json!({ "typoAscender": typo_ascender, "typoDescender": typo_descender }),
+        ));
+    }
+    if typo_line_gap < 0 {
+        findings.push(Finding::warning(
+            "win-typo-metrics",
+            format!("sTypoLineGap ({}) is negative", typo_line_gap),
+            serde_json::json!({ "typoLineGap": typo_line_gap }),
+        ));
+    }
+
+    if let (Some(y_min), Some(y_max)) = (read_i16(head, 38), read_i16(head, 42)) {
+        if (win_ascent as i32) < y_max as i32 {
+            findings.push(Finding::error(
+                "win-typo-metrics",
+                format!("usWinAscent ({}) is below the highest glyph (yMax {}), which clips on Windows", win_ascent, y_max),
+                serde_json::json!({ "winAscent": win_ascent, "yMax": y_max }),
+            ));
+        }
+        if (win_descent as i32) < -(y_min as i32) {
+            findings.push(Finding::error(
+                "win-typo-metrics",
+                format!("usWinDescent ({}) is above the lowest glyph (yMin {}), which clips on Windows", win_descent, y_min),
+                serde_json::json!({ "winDescent": win_descent, "yMin": y_min }),
+            ));
+        }
+    }
+}
+
+/// Check that fsSelection, macStyle and usWeightClass describe the same style
+fn check_fs_selection(font: &FontRef, findings: &mut Vec<Finding>) {
+    let (Some(os2), Some(head)) = (font.table_data(Tag::new(b"OS/2")), font.table_data(Tag::new(b"head"))) else {
+        return;
+    };
+    let (Some(fs_selection), Some(weight_class), Some(mac_style)) =
+        (read_u16(os2.as_bytes(), 62), read_u16(os2.as_bytes(), 4), read_u16(head.as_bytes(), 44))
+    else {
+        return;
+    };
+
+    let italic = fs_selection & 0x0001 != 0;
+    let bold = fs_selection & 0x0020 != 0;
+    let regular = fs_selection & 0x0040 != 0;
+    let context = serde_json::json!({ "fsSelection": fs_selection, "macStyle": mac_style, "usWeightClass": weight_class });
+
+    if bold != (mac_style & 0x0001 != 0) {
+        findings.push(Finding::error(
+            "fs-selection",
+            "fsSelection BOLD and head.macStyle bold bit disagree".to_string(),
+            context.clone(),
+        ));
+    }
+    if italic != (mac_style & 0x0002 != 0) {
+        findings.push(Finding::error(
+            "fs-selection",
+            "fsSelection ITALIC and head.macStyle italic bit disagree".to_string(),
+            context.clone(),
+        ));
+    }
+    if regular && (bold || italic) {
+        findings.push(Finding::error(
+            "fs-selection",
+            "fsSelection REGULAR is set together with BOLD or ITALIC".to_string(),
+            context.clone(),
+        ));
+    }
+    if !regular && !bold && !italic {
+        findings.push(Finding::warning(
+            "fs-selection",
+            "fsSelection has none of REGULAR, BOLD and ITALIC set".to_string(),
+            context.clone(),
+        ));
+    }
+    if bold && weight_class < 600 {
+        findings.push(Finding::warning(
+            "fs-selection",
+            format!("fsSelection BOLD is set but usWeightClass is {}", weight_class),
+            context,
+        ));
+    }
+}
+
+/// Check the Google Fonts vertical metrics requirements
+fn check_gf_vertical_metrics(font: &FontRef, findings: &mut Vec<Finding>) {
+    let (Some(os2), Some(hhea)) = (font.table_data(Tag::new(b"OS/2")), font.table_data(Tag::new(b"hhea"))) else {
+        return;
+    };
+    let (os2, hhea) = (os2.as_bytes(), hhea.as_bytes());
+
+    if read_u16(os2, 62).is_some_and(|fs_selection| fs_selection & 0x0080 == 0) {
+        findings.push(Finding::warning(
+            "gf-vertical-metrics",
+            "fsSelection USE_TYPO_METRICS is not set".to_string(),
+            serde_json::json!({}),
+        ));
+    }
+
+    let typo = (read_i16(os2, 68), read_i16(os2, 70), read_i16(os2, 72));
+    let hhea_metrics = (read_i16(hhea, 4), read_i16(hhea, 6), read_i16(hhea, 8));
+    if typo != hhea_metrics {
+        findings.push(Finding::warning(
+            "gf-vertical-metrics",
+            "hhea ascender, descender and line gap differ from the OS/2 typographic metrics".to_string(),
+            serde_json::json!({
+                "hhea": [hhea_metrics.0, hhea_metrics.1, hhea_metrics.2],
+                "typo": [typo.0, typo.1, typo.2],
+            }),
+        ));
+    }
+}
+
+/// Check that the font has no embedding restrictions
+fn check_gf_fstype(font: &FontRef, findings: &mut Vec<Finding>) {
+    let Some(os2) = font.table_data(Tag::new(b"OS/2")) else { return };
+    if let Some(fs_type) = read_u16(os2.as_bytes(), 8).filter(|&fs_type| fs_type != 0) {
+        findings.push(Finding::error(
+            "gf-fstype",
+            format!("OS/2 fsType is {}, but fonts must be installable (0)", fs_type),
+            serde_json::json!({ "fsType": fs_type }),
+        ));
+    }
+}
+
+/// Run the QA checks of a profile on compiled font bytes
+///
+/// Profiles:
+/// - `universal`: required tables, monotonic fvar/avar, name table
+///   consistency, win/typo metric sanity, fsSelection/macStyle agreement
+/// - `googlefonts`: universal checks plus USE_TYPO_METRICS with hhea
+///   metrics matching the typographic ones, and fsType 0
+///
+/// Each check fails if it has an error finding, warns if it has a warning
+/// finding and passes otherwise.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "profile": "universal",
+///   "status": "warn",
+///   "passCount": 4,
+///   "warnCount": 1,
+///   "failCount": 0,
+///   "results": [
+///     { "check": "name-consistency", "title": "...", "status": "warn",
+///       "findings": [{ "severity": "warning", "check": "name-consistency", "message": "...", "context": { ... } }] }
+///   ]
+/// }
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `profile` - "universal" or "googlefonts"
+///
+/// # Returns
+/// * `String` - JSON QA report
+#[wasm_bindgen]
+pub fn run_qa_checks(font_bytes: &[u8], profile: &str) -> Result<String, JsValue> {
+    if !QA_PROFILES.contains(&profile) {
+        return Err(ApiError::invalid_argument("QA profile", profile).into());
+    }
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let mut counts = [0usize; 3];
+    let results: Vec<serde_json::Value> = QA_CHECKS
+        .iter()
+        .filter(|check| check.profiles.contains(&profile))
+        .map(|check| {
+            let mut findings = Vec::new();
+            (check.run)(&font, &mut findings);
+            let status = if findings.iter().any(|f| f.severity == "error") {
+                counts[2] += 1;
+                "fail"
+            } else if findings.is_empty() {
+                counts[0] += 1;
+                "pass"
+            } else {
+                counts[1] += 1;
+                "warn"
+            };
+            serde_json::json!({
+                "check": check.id,
+                "title": check.title,
+                "status": status,
+                "findings": findings.iter().map(Finding::to_json).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    let status = if counts[2] > 0 { "fail" } else if counts[1] > 0 { "warn" } else { "pass" };
+    let report = serde_json::json!({
+        "profile": profile,
+        "status": status,
+        "passCount": counts[0],
+        "warnCount": counts[1],
+        "failCount": counts[2],
+        "results": results,
+    });
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("QA report", e).into())
+}
+
+// ============================================================================
+// Vertical metrics
+// ============================================================================
+
+/// Vertical metrics strategies accepted by `check_vertical_metrics`
+const VERTICAL_METRICS_STRATEGIES: &[&str] = &["googlefonts", "typo", "win"];
+
+/// The highest or lowest point of any glyph, and where it was found
+struct GlyphExtreme {
+    value: f64,
+    glyph: String,
+    instance: String,
+}
+
+impl GlyphExtreme {
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "value": self.value, "glyph": self.glyph, "instance": self.instance })
+    }
+}
+
+/// Find the tallest and deepest glyph at the default location and every named instance
+fn glyph_extremes(font_bytes: &[u8], glyph_order: &[String]) -> Result<Option<(GlyphExtreme, GlyphExtreme)>, String> {
+    let font = skrifa::FontRef::new(font_bytes).map_err(|e| format!("Failed to parse font: {:?}", e))?;
+    let outlines = font.outline_glyphs();
+
+    let mut locations = vec![("default".to_string(), Location::default())];
+    for instance in font.named_instances().iter() {
+        let name = font
+            .localized_strings(instance.subfamily_name_id())
+            .english_or_first()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("instance {}", locations.len()));
+        locations.push((name, instance.location()));
+    }
+
+    let mut extremes: Option<(GlyphExtreme, GlyphExtreme)> = None;
+    for (instance, location) in &locations {
+        for (gid, name) in glyph_order.iter().enumerate() {
+            let Some(path) = crate::text_render::glyph_path(&outlines, gid as u32, location) else {
+                continue;
+            };
+            let bounds = kurbo::Shape::bounding_box(&path);
+            let extreme = |value| GlyphExtreme { value, glyph: name.clone(), instance: instance.clone() };
+            match &mut extremes {
+                None => extremes = Some((extreme(bounds.y1), extreme(bounds.y0))),
+                Some((tallest, deepest)) => {
+                    if bounds.y1 > tallest.value {
+                        *tallest = extreme(bounds.y1);
+                    }
+                    if bounds.y0 < deepest.value {
+                        *deepest = extreme(bounds.y0);
+                    }
+                }
+            }
+        }
+    }
+    Ok(extremes)
+}
+
+/// Check the vertical metrics of a compiled font against its glyph extremes
+///
+/// The tallest and deepest glyphs are measured at the default location and
+/// at every named instance. Windows clips glyphs outside usWinAscent and
+/// usWinDescent, so those must cover the extremes under every strategy.
+///
+/// Strategies:
+/// - `googlefonts`: USE_TYPO_METRICS set, hhea equal to the typo metrics,
+///   both line gaps 0
+/// - `typo`: USE_TYPO_METRICS set, hhea equal to the typo metrics
+/// - `win`: USE_TYPO_METRICS not set, hhea ascender/descender equal to the
+///   win metrics with a line gap of 0 (the legacy strategy)
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "valid": false,
+///   "errorCount": 1,
+///   "warningCount": 0,
+///   "strategy": "googlefonts",
+///   "metrics": {
+///     "hhea": { "ascender": 950, "descender": -250, "lineGap": 0 },
+///     "typo": { "ascender": 950, "descender": -250, "lineGap": 0, "useTypoMetrics": true },
+///     "win": { "ascent": 1000, "descent": 250 },
+///     "upm": 1000
+///   },
+///   "tallest": { "value": 1043, "glyph": "Aring", "instance": "Black" },
+///   "deepest": { "value": -240, "glyph": "g", "instance": "default" },
+///   "findings": [
+///     { "severity": "error", "check": "win-clipping", "message": "...", "context": { ... } }
+///   ]
+/// }
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `strategy` - "googlefonts", "typo" or "win"
+///
+/// # Returns
+/// * `String` - JSON vertical metrics report
+#[wasm_bindgen]
+pub fn check_vertical_metrics(font_bytes: &[u8], strategy: &str) -> Result<String, JsValue> {
+    if !VERTICAL_METRICS_STRATEGIES.contains(&strategy) {
+        return Err(ApiError::invalid_argument("vertical metrics strategy", strategy).into());
+    }
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let (Some(os2), Some(hhea), Some(head)) = (
+        font.table_data(Tag::new(b"OS/2")),
+        font.table_data(Tag::new(b"hhea")),
+        font.table_data(Tag::new(b"head")),
+    ) else {
+        return Err(ApiError::MissingTable { table: "OS/2, hhea or head".to_string(), detail: "absent".to_string() }.into());
+    };
+    let (os2, hhea, head) = (os2.as_bytes(), hhea.as_bytes(), head.as_bytes());
+    let (
+        Some(hhea_ascender),
+        Some(hhea_descender),
+        Some(hhea_line_gap),
+        Some(typo_ascender),
+        Some(typo_descender),
+        Some(typo_line_gap),
+        Some(win_ascent),
+        Some(win_descent),
+        Some(fs_selection),
+        Some(upm),
+    ) = (
+        read_i16(hhea, 4),
+        read_i16(hhea, 6),
+        read_i16(hhea, 8),
+        read_i16(os2, 68),
+        read_i16(os2, 70),
+        read_i16(os2, 72),
+        read_u16(os2, 74),
+        read_u16(os2, 76),
+        read_u16(os2, 62),
+        read_u16(head, 18),
+    )
+    else {
+        return Err(ApiError::MissingTable { table: "OS/2, hhea or head".to_string(), detail: "truncated".to_string() }.into());
+    };
+    let use_typo_metrics = fs_selection & 0x0080 != 0;
+
+    let glyph_order = crate::font_reader::read_glyph_order(&font)?;
+    let extremes = glyph_extremes(font_bytes, &glyph_order).map_err(ApiError::from)?;
+
+    let mut findings = Vec::new();
+
+    // Clipping: Windows cuts off everything outside the win metrics
+    if let Some((tallest, deepest)) = &extremes {
+        if (win_ascent as f64) < tallest.value {
+            findings.push(Finding::error(
+                "win-clipping",
+                format!(
+                    "usWinAscent ({}) is below '{}' ({} at {}), which clips on Windows",
+                    win_ascent, tallest.glyph, tallest.value, tallest.instance
+                ),
+                serde_json::json!({ "winAscent": win_ascent, "tallest": tallest.to_json() }),
+            ));
+        }
+        if (win_descent as f64) < -deepest.value {
+            findings.push(Finding::error(
+                "win-clipping",
+                format!(
+                    "usWinDescent ({}) is above '{}' ({} at {}), which clips on Windows",
+                    win_descent, deepest.glyph, deepest.value, deepest.instance
+                ),
+                serde_json::json!({ "winDescent": win_descent, "deepest": deepest.to_json() }),
+            ));
+        }
+    }
+
+    let hhea_json = serde_json::json!({ "ascender": hhea_ascender, "descender": hhea_descender, "lineGap": hhea_line_gap });
+    let typo_json = serde_json::json!({
+        "ascender": typo_ascender,
+        "descender": typo_descender,
+        "lineGap": typo_line_gap,
+        "useTypoMetrics": use_typo_metrics,
+    });
+    let win_json = serde_json::json!({ "ascent": win_ascent, "descent": win_descent });
+
+    match strategy {
+        "googlefonts" | "typo" => {
+            if !use_typo_metrics {
+                findings.push(Finding::error(
+                    "strategy",
+                    format!("The '{}' strategy requires fsSelection USE_TYPO_METRICS", strategy),
+                    serde_json::json!({ "fsSelection": fs_selection }),
+                ));
+            }
+            if (hhea_ascender, hhea_descender, hhea_line_gap) != (typo_ascender, typo_descender, typo_line_gap) {
+                findings.push(Finding::warning(
+                    "strategy",
+                    "hhea metrics differ from the typo metrics, so line spacing differs between macOS and Windows".to_string(),
+                    serde_json::json!({ "hhea": hhea_json, "typo": typo_json }),
+                ));
+            }
+            if strategy == "googlefonts" && (hhea_line_gap != 0 || typo_line_gap != 0) {
+                findings.push(Finding::warning(
+                    "strategy",
+                    "The 'googlefonts' strategy expects hhea and typo line gaps of 0".to_string(),
+                    serde_json::json!({ "hhea": hhea_json, "typo": typo_json }),
+                ));
+            }
+        }
+        _ => {
+            if use_typo_metrics {
+                findings.push(Finding::warning(
+                    "strategy",
+                    "fsSelection USE_TYPO_METRICS is set, but the 'win' strategy spaces lines with the win metrics".to_string(),
+                    serde_json::json!({ "fsSelection": fs_selection }),
+                ));
+            }
+            if hhea_ascender as i32 != win_ascent as i32 || hhea_descender as i32 != -(win_descent as i32) || hhea_line_gap != 0 {
+                findings.push(Finding::warning(
+                    "strategy",
+                    "hhea metrics should equal the win metrics with a line gap of 0 under the 'win' strategy".to_string(),
+                    serde_json::json!({ "hhea": hhea_json, "win": win_json }),
+                ));
+            }
+        }
+    }
+
+    // Line height far from the em hints at metrics in the wrong units or sign
+    let line_height = if use_typo_metrics {
+        typo_ascender as f64 - typo_descender as f64 + typo_line_gap as f64
+    } else {
+        win_ascent as f64 + win_descent as f64
+    };
+    if line_height < upm as f64 * 0.8 || line_height > upm as f64 * 2.0 {
+        findings.push(Finding::warning(
+            "line-height",
+            format!("Line height ({}) is unusual for {} units per em", line_height, upm),
+            serde_json::json!({ "lineHeight": line_height, "upm": upm }),
+        ));
+    }
+
+    let mut report = findings_report(&findings);
+    if let Some(report) = report.as_object_mut() {
+        report.insert("strategy".to_string(), serde_json::json!(strategy));
+        report.insert(
+            "metrics".to_string(),
+            serde_json::json!({ "hhea": hhea_json, "typo": typo_json, "win": win_json, "upm": upm }),
+        );
+        let (tallest, deepest) = match &extremes {
+            Some((tallest, deepest)) => (tallest.to_json(), deepest.to_json()),
+            None => (serde_json::Value::Null, serde_json::Value::Null),
+        };
+        report.insert("tallest".to_string(), tallest);
+        report.insert("deepest".to_string(), deepest);
+    }
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("vertical metrics report", e).into())
+}
@@ -2,37 +2,60 @@
 //
 // This module provides functions for extracting glyph outlines with component flattening
 // for efficient batch rendering in the overview.
-// Optimized with persistent caching across requests for the same location.
+// Optimized with persistent LRU caches keyed by glyph and (quantized) location, bounded
+// by a configurable byte budget.
 
-use babelfont::{Layer, Shape, Node};
+use babelfont::{Layer, Shape, Node, NodeType};
 use fontdrasil::coords::{DesignCoord, DesignLocation, UserCoord};
 use serde_json::Value as JsonValue;
 use std::collections::{HashMap, HashSet};
 use std::cell::RefCell;
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use wasm_bindgen::prelude::*;
 use write_fonts::types::Tag;
-use kurbo::{Affine, Point};
+use kurbo::{Affine, BezPath, Point};
 
+use crate::component_graph::ComponentGraph;
+use crate::errors::ApiError;
 use crate::interpolation::serialize_layer_with_components_cached;
+use crate::lru_cache::ByteLru;
 
 // Global persistent cache for glyph outline results
-// Key: glyph_name, Value: complete result JSON object
-static OUTLINE_CACHE: Mutex<Option<OutlineCache>> = Mutex::new(None);
+// Key: glyph name, location and flattening mode, Value: complete result JSON object
+static OUTLINE_CACHE: Mutex<Option<ByteLru<OutlineKey, JsonValue>>> = Mutex::new(None);
 
 // Global persistent cache for interpolated layers (components)
-// This dramatically speeds up composite glyphs that share base components
-static LAYER_CACHE: Mutex<Option<LayerCache>> = Mutex::new(None);
+// This dramatically speeds up composite glyphs that share base components.
+// Layers are shared (Arc) with the per-request maps instead of cloned.
+static LAYER_CACHE: Mutex<Option<ByteLru<LayerKey, Arc<Layer>>>> = Mutex::new(None);
 
-struct OutlineCache {
-    location_json: String,
-    results: HashMap<String, JsonValue>,
+// Component references of the cached font, built when the font is stored
+static COMPONENT_GRAPH: Mutex<Option<Arc<ComponentGraph>>> = Mutex::new(None);
+
+// Byte budgets of the persistent caches
+static CACHE_LIMITS: Mutex<CacheLimits> = Mutex::new(CacheLimits {
+    outline_bytes: 32 * 1024 * 1024,
+    layer_bytes: 32 * 1024 * 1024,
+});
+
+#[derive(Clone, Copy, Debug)]
+struct CacheLimits {
+    outline_bytes: usize,
+    layer_bytes: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct OutlineKey {
+    glyph: String,
+    location: String,
+    flatten: bool,
 }
 
-struct LayerCache {
-    location_json: String,
-    layers: HashMap<String, Layer>,
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct LayerKey {
+    glyph: String,
+    location: String,
 }
 
 /// Clear all caches (call when font changes)
@@ -45,6 +68,282 @@ pub fn clear_outline_cache() {
         let mut cache = LAYER_CACHE.lock().unwrap();
         *cache = None;
     }
+    {
+        let mut graph = COMPONENT_GRAPH.lock().unwrap();
+        *graph = None;
+    }
+}
+
+/// Outline and layer caches and component graph of a font that is not the cached font
+///
+/// Kept by the font sessions while another font is cached, so switching back
+/// and forth between open fonts does not start over.
+pub struct OutlineCaches {
+    outlines: Option<ByteLru<OutlineKey, JsonValue>>,
+    layers: Option<ByteLru<LayerKey, Arc<Layer>>>,
+    graph: Option<Arc<ComponentGraph>>,
+}
+
+/// Take the caches of the cached font out, leaving them empty (call when switching fonts)
+pub fn take_outline_caches() -> OutlineCaches {
+    OutlineCaches {
+        outlines: OUTLINE_CACHE.lock().unwrap().take(),
+        layers: LAYER_CACHE.lock().unwrap().take(),
+        graph: COMPONENT_GRAPH.lock().unwrap().take(),
+    }
+}
+
+/// Make caches taken by take_outline_caches() the caches of the cached font
+///
+/// Budgets changed since the caches were taken apply to them now.
+pub fn restore_outline_caches(caches: OutlineCaches) {
+    let limits = *CACHE_LIMITS.lock().unwrap();
+    let mut outlines = caches.outlines;
+    if let Some(cache) = outlines.as_mut() {
+        cache.set_budget(limits.outline_bytes);
+    }
+    let mut layers = caches.layers;
+    if let Some(cache) = layers.as_mut() {
+        cache.set_budget(limits.layer_bytes);
+    }
+    *OUTLINE_CACHE.lock().unwrap() = outlines;
+    *LAYER_CACHE.lock().unwrap() = layers;
+    *COMPONENT_GRAPH.lock().unwrap() = caches.graph;
+}
+
+/// Build the component graph of a newly stored font
+pub fn build_component_graph(font: &babelfont::Font) {
+    let mut graph = COMPONENT_GRAPH.lock().unwrap();
+    *graph = Some(Arc::new(ComponentGraph::build(font)));
+}
+
+/// Update the component graph after a glyph of the cached font was replaced
+pub fn update_component_graph(font: &babelfont::Font, glyph: &babelfont::Glyph) {
+    let mut graph = COMPONENT_GRAPH.lock().unwrap();
+    match graph.as_mut() {
+        Some(graph) => Arc::make_mut(graph).update_glyph(glyph),
+        None => *graph = Some(Arc::new(ComponentGraph::build(font))),
+    }
+}
+
+/// The component graph of the cached font, built on first use if needed
+pub(crate) fn component_graph(font: &babelfont::Font) -> Arc<ComponentGraph> {
+    let mut graph = COMPONENT_GRAPH.lock().unwrap();
+    Arc::clone(graph.get_or_insert_with(|| Arc::new(ComponentGraph::build(font))))
+}
+
+/// Names of a glyph and every composite glyph that uses it, directly or nested
+pub fn with_component_dependents(font: &babelfont::Font, glyph_name: &str) -> HashSet<String> {
+    component_graph(font).with_dependents(glyph_name)
+}
+
+/// Evict glyphs from the outline and layer caches at all locations
+pub fn invalidate_glyphs(glyph_names: &HashSet<String>) {
+    {
+        let mut cache = OUTLINE_CACHE.lock().unwrap();
+        if let Some(cache) = cache.as_mut() {
+            cache.retain(|key| !glyph_names.contains(&key.glyph));
+        }
+    }
+    {
+        let mut cache = LAYER_CACHE.lock().unwrap();
+        if let Some(cache) = cache.as_mut() {
+            cache.retain(|key| !glyph_names.contains(&key.glyph));
+        }
+    }
+}
+
+/// Set the byte budgets of the outline and layer caches
+///
+/// Budgets left as None are unchanged. Caches over their new budget evict
+/// their least recently used entries immediately.
+///
+/// # Returns
+/// * `JsonValue` - Current budgets and usage: '{"outlineBytes": ..., "layerBytes": ..., "outlineUsed": ..., "layerUsed": ..., "outlineEntries": ..., "layerEntries": ...}'
+pub fn set_cache_limits(outline_bytes: Option<usize>, layer_bytes: Option<usize>) -> JsonValue {
+    let limits = {
+        let mut limits = CACHE_LIMITS.lock().unwrap();
+        if let Some(outline_bytes) = outline_bytes {
+            limits.outline_bytes = outline_bytes;
+        }
+        if let Some(layer_bytes) = layer_bytes {
+            limits.layer_bytes = layer_bytes;
+        }
+        *limits
+    };
+
+    let mut outlines = OUTLINE_CACHE.lock().unwrap();
+    let (outline_used, outline_entries) = match outlines.as_mut() {
+        Some(cache) => {
+            cache.set_budget(limits.outline_bytes);
+            (cache.bytes(), cache.entry_count())
+        }
+        None => (0, 0),
+    };
+    let mut layers = LAYER_CACHE.lock().unwrap();
+    let (layer_used, layer_entries) = match layers.as_mut() {
+        Some(cache) => {
+            cache.set_budget(limits.layer_bytes);
+            (cache.bytes(), cache.entry_count())
+        }
+        None => (0, 0),
+    };
+
+    serde_json::json!({
+        "outlineBytes": limits.outline_bytes,
+        "layerBytes": limits.layer_bytes,
+        "outlineUsed": outline_used,
+        "layerUsed": layer_used,
+        "outlineEntries": outline_entries,
+        "layerEntries": layer_entries,
+    })
+}
+
+/// Cache key for a design location
+///
+/// Coordinates are quantized to 1/100 of a design unit so that locations
+/// differing only by floating point noise share cache entries.
+fn location_key(location: &DesignLocation) -> String {
+    let mut coords: Vec<(String, i64)> = location
+        .iter()
+        .map(|(tag, coord)| (tag.to_string(), (coord.to_f64() * 100.0).round() as i64))
+        .collect();
+    coords.sort();
+    coords
+        .iter()
+        .map(|(tag, value)| format!("{}={}", tag, value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Estimated heap size of an interpolated layer in bytes
+fn layer_weight(layer: &Layer) -> usize {
+    let shapes: usize = layer
+        .shapes
+        .iter()
+        .map(|shape| match shape {
+            Shape::Path(path) => std::mem::size_of::<Shape>() + path.nodes.len() * std::mem::size_of::<Node>(),
+            Shape::Component(component) => std::mem::size_of::<Shape>() + component.reference.len(),
+        })
+        .sum();
+    std::mem::size_of::<Layer>() + shapes + layer.anchors.len() * std::mem::size_of::<babelfont::Anchor>()
+}
+
+/// Estimated heap size of a JSON value in bytes
+fn json_weight(value: &JsonValue) -> usize {
+    let own = std::mem::size_of::<JsonValue>();
+    match value {
+        JsonValue::String(string) => own + string.len(),
+        JsonValue::Array(items) => own + items.iter().map(json_weight).sum::<usize>(),
+        JsonValue::Object(map) => own + map.iter().map(|(key, value)| key.len() + json_weight(value)).sum::<usize>(),
+        _ => own,
+    }
+}
+
+/// Interpolated layers of a font at one location
+///
+/// Layers are looked up in a per-request map first, then (for persistent
+/// sources) in the global layer cache, and are interpolated on a miss.
+pub(crate) struct LayerSource<'a> {
+    font: &'a babelfont::Font,
+    location: &'a DesignLocation,
+    // Cache key of the location; None if layers are not persisted
+    location_key: Option<String>,
+    layers: RefCell<HashMap<String, Arc<Layer>>>,
+    graph: Arc<ComponentGraph>,
+    // Flattened shapes of composite glyphs used as components
+    flattened: RefCell<HashMap<String, Arc<Vec<Shape>>>>,
+}
+
+impl<'a> LayerSource<'a> {
+    /// A layer source that only caches for its own lifetime
+    pub fn new(font: &'a babelfont::Font, location: &'a DesignLocation) -> Self {
+        LayerSource {
+            font,
+            location,
+            location_key: None,
+            layers: RefCell::new(HashMap::new()),
+            graph: component_graph(font),
+            flattened: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// A layer source backed by the global layer cache
+    pub fn persistent(font: &'a babelfont::Font, location: &'a DesignLocation) -> Self {
+        LayerSource {
+            location_key: Some(location_key(location)),
+            ..LayerSource::new(font, location)
+        }
+    }
+
+    /// A persistent layer source starting from previously interpolated layers
+    pub fn with_layers(
+        font: &'a babelfont::Font,
+        location: &'a DesignLocation,
+        layers: HashMap<String, Arc<Layer>>,
+    ) -> Self {
+        LayerSource {
+            layers: RefCell::new(layers),
+            ..LayerSource::persistent(font, location)
+        }
+    }
+
+    /// The font layers are interpolated from
+    pub fn font(&self) -> &'a babelfont::Font {
+        self.font
+    }
+
+    /// The design location layers are interpolated at
+    pub fn location(&self) -> &'a DesignLocation {
+        self.location
+    }
+
+    /// The layers interpolated so far, to keep them for later requests
+    pub fn into_layers(self) -> HashMap<String, Arc<Layer>> {
+        self.layers.into_inner()
+    }
+
+    /// Get the interpolated layer of a glyph
+    ///
+    /// The layer is shared with the caches, so repeated lookups do not copy
+    /// it. Errors are the interpolation error of babelfont, formatted for
+    /// display.
+    pub fn layer(&self, glyph_name: &str) -> Result<Arc<Layer>, String> {
+        if let Some(layer) = self.layers.borrow().get(glyph_name) {
+            return Ok(Arc::clone(layer));
+        }
+
+        let key = self.location_key.as_ref().map(|location| LayerKey {
+            glyph: glyph_name.to_string(),
+            location: location.clone(),
+        });
+
+        let cached = key.as_ref().and_then(|key| {
+            let mut cache = LAYER_CACHE.lock().unwrap();
+            cache.as_mut().and_then(|cache| cache.get(key).cloned())
+        });
+
+        let layer = match cached {
+            Some(layer) => layer,
+            None => {
+                let interpolated = Arc::new(
+                    self.font.interpolate_glyph(glyph_name, self.location)
+                        .map_err(|e| format!("{:?}", e))?,
+                );
+                if let Some(key) = key {
+                    let budget = CACHE_LIMITS.lock().unwrap().layer_bytes;
+                    let mut cache = LAYER_CACHE.lock().unwrap();
+                    cache
+                        .get_or_insert_with(|| ByteLru::new(budget))
+                        .insert(key, Arc::clone(&interpolated), layer_weight(&interpolated));
+                }
+                interpolated
+            }
+        };
+
+        self.layers.borrow_mut().insert(glyph_name.to_string(), Arc::clone(&layer));
+        Ok(layer)
+    }
 }
 
 /// Get outlines for multiple glyphs with optional component flattening
@@ -54,163 +353,84 @@ pub fn clear_outline_cache() {
 /// * `glyph_names` - List of glyph names to process
 /// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
 /// * `flatten_components` - If true, resolves and flattens all components into paths
+/// * `include_background` - Add each glyph's background at the location as `background` (not cached)
+///
+/// Color glyphs get their paint graph at the location as `colorPaint`
+/// (see color_paints.rs); it is null for other glyphs.
 ///
 /// # Returns
-/// * `String` - JSON array of glyph outline data: '[{"name": "A", "width": 600, "shapes": [...], "bounds": {...}}, ...]'
+/// * `String` - JSON array of glyph outline data: '[{"name": "A", "width": 600, "shapes": [...], "bounds": {...}, "colorPaint": null}, ...]'
 pub fn get_glyphs_outlines(
     font: &babelfont::Font,
     glyph_names: &[String],
     location_json: &str,
     flatten_components: bool,
+    include_background: bool,
 ) -> Result<String, JsValue> {
-    // Normalize location for cache key comparison
-    let normalized_location = if location_json.trim().is_empty() { "{}" } else { location_json };
+    let design_location = design_location(font, location_json)?;
+    let location = location_key(&design_location);
+    let outline_key = |glyph_name: &str| OutlineKey {
+        glyph: glyph_name.to_string(),
+        location: location.clone(),
+        flatten: flatten_components,
+    };
     
-    // Check if location changed - clear both caches if so
-    {
+    // Look up glyphs in the persistent cache
+    let mut results: Vec<Option<JsonValue>> = {
         let mut cache_guard = OUTLINE_CACHE.lock().unwrap();
-        if let Some(ref cache) = *cache_guard {
-            if cache.location_json != normalized_location {
-                // Location changed, clear cache
-                *cache_guard = None;
-            }
-        }
-    }
-    {
-        let mut cache_guard = LAYER_CACHE.lock().unwrap();
-        if let Some(ref cache) = *cache_guard {
-            if cache.location_json != normalized_location {
-                // Location changed, clear layer cache too
-                *cache_guard = None;
-            }
+        match cache_guard.as_mut() {
+            Some(cache) => glyph_names
+                .iter()
+                .map(|glyph_name| cache.get(&outline_key(glyph_name)).cloned())
+                .collect(),
+            None => vec![None; glyph_names.len()],
         }
-    }
-    
-    // Check how many glyphs are already in persistent cache
-    let mut cached_results: Vec<JsonValue> = Vec::new();
-    let mut glyphs_to_process: Vec<String> = Vec::new();
-    {
-        let cache_guard = OUTLINE_CACHE.lock().unwrap();
-        if let Some(ref cache) = *cache_guard {
-            for glyph_name in glyph_names {
-                if let Some(cached) = cache.results.get(glyph_name) {
-                    cached_results.push(cached.clone());
-                } else {
-                    glyphs_to_process.push(glyph_name.clone());
-                }
-            }
-        } else {
-            glyphs_to_process = glyph_names.to_vec();
-        }
-    }
+    };
     
     // If all glyphs are cached, return immediately
-    if glyphs_to_process.is_empty() {
-        return serde_json::to_string(&cached_results)
-            .map_err(|e| JsValue::from_str(&format!("Failed to serialize results: {}", e)));
+    if results.iter().all(Option::is_some) {
+        let mut results: Vec<JsonValue> = results.into_iter().flatten().collect();
+        if include_background {
+            add_backgrounds(font, &design_location, flatten_components, &mut results)?;
+        }
+        return serde_json::to_string(&results)
+            .map_err(|e| ApiError::serialization("results", e).into());
     }
     
-    // Parse location
-    let location_map: HashMap<String, f64> = if location_json.trim().is_empty() || location_json == "{}" {
-        HashMap::new()
-    } else {
-        serde_json::from_str(location_json)
-            .map_err(|e| JsValue::from_str(&format!("Location parse error: {}", e)))?
-    };
-    
-    // Convert to design space
-    let design_location: DesignLocation = if location_map.is_empty() {
-        // Use default location (all axes at default)
-        font.axes
-            .iter()
-            .filter_map(|axis| {
-                axis.default.map(|default_val| {
-                    (axis.tag, DesignCoord::new(default_val.to_f64()))
-                })
-            })
-            .collect()
-    } else {
-        location_map
-            .iter()
-            .map(|(tag_str, user_value)| {
-                let tag = Tag::from_str(tag_str)
-                    .map_err(|e| JsValue::from_str(&format!("Invalid tag '{}': {}", tag_str, e)))?;
-                
-                let design_value = if let Some(axis) = font.axes.iter().find(|a| a.tag == tag) {
-                    match axis.userspace_to_designspace(UserCoord::new(*user_value)) {
-                        Ok(design_coord) => design_coord,
-                        Err(_) => DesignCoord::new(*user_value),
-                    }
-                } else {
-                    DesignCoord::new(*user_value)
-                };
-                
-                Ok((tag, design_value))
-            })
-            .collect::<Result<Vec<_>, JsValue>>()?
-            .into_iter()
-            .collect()
-    };
-    
-    // Get or create persistent layer cache
-    // This cache persists across requests for the same location
-    let layer_cache: RefCell<HashMap<String, Layer>> = {
-        let mut cache_guard = LAYER_CACHE.lock().unwrap();
-        if let Some(ref cache) = *cache_guard {
-            // Return existing layers from persistent cache
-            RefCell::new(cache.layers.clone())
-        } else {
-            // Initialize new cache
-            *cache_guard = Some(LayerCache {
-                location_json: normalized_location.to_string(),
-                layers: HashMap::new(),
-            });
-            RefCell::new(HashMap::new())
-        }
-    };
+    // Interpolated layers, shared with the persistent layer cache
+    let layers = LayerSource::persistent(font, &design_location);
     
     // Per-request JSON cache (not persisted, just for this batch)
     let json_cache: RefCell<HashMap<String, JsonValue>> = RefCell::new(HashMap::new());
     
-    let mut new_results: Vec<(String, JsonValue)> = Vec::with_capacity(glyphs_to_process.len());
+    let mut new_results: Vec<(OutlineKey, JsonValue)> = Vec::new();
     
-    for glyph_name in &glyphs_to_process {
-        // Get glyph
-        let _glyph = match font.glyphs.get(glyph_name) {
-            Some(g) => g,
-            None => {
-                continue; // Skip missing glyphs
-            }
-        };
+    for (glyph_name, slot) in glyph_names.iter().zip(results.iter_mut()) {
+        if slot.is_some() {
+            continue;
+        }
         
-        // Check cache first, then interpolate
-        let layer = {
-            let cache = layer_cache.borrow();
-            if let Some(cached) = cache.get(glyph_name) {
-                cached.clone()
-            } else {
-                drop(cache);
-                let interpolated = font.interpolate_glyph(glyph_name, &design_location)
-                    .map_err(|e| JsValue::from_str(&format!("Interpolation failed for '{}': {:?}", glyph_name, e)))?;
-                layer_cache.borrow_mut().insert(glyph_name.clone(), interpolated.clone());
-                interpolated
-            }
-        };
+        // Skip missing glyphs
+        if font.glyphs.get(glyph_name).is_none() {
+            continue;
+        }
+        
+        let layer = layers.layer(glyph_name)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?;
         
         let (shapes, shapes_json) = if flatten_components {
             // For flattened mode, use cached flattening
-            let (flattened, _, _) = flatten_layer_components_cached(font, &layer, &design_location, &layer_cache)?;
+            let flattened = flatten_layer_components_cached(&layer, &layers)?;
             let json = serde_json::to_value(&flattened)
-                .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))?;
+                .map_err(|e| ApiError::serialization("layer", e))?;
             (flattened, json)
         } else {
             // For non-flattened mode, use cached serialization
-            let shapes_json = serialize_layer_with_components_cached(
-                &layer, font, &design_location, &layer_cache, &json_cache
-            ).map_err(|e| JsValue::from_str(&e))?;
+            let shapes_json = serialize_layer_with_components_cached(&layer, &layers, &json_cache)
+                .map_err(ApiError::from)?;
             
             // For bounds calculation, we need flattened shapes
-            let (flattened_for_bounds, _, _) = flatten_layer_components_cached(font, &layer, &design_location, &layer_cache)?;
+            let flattened_for_bounds = flatten_layer_components_cached(&layer, &layers)?;
             
             (flattened_for_bounds, shapes_json)
         };
@@ -218,83 +438,241 @@ pub fn get_glyphs_outlines(
         // Calculate bounds from the actual shapes (flattened paths)
         let bounds = calculate_bounds(&shapes);
         
+        // Paint graph of color glyphs, interpolated like the outlines
+        let color_paint = font
+            .glyphs
+            .get(glyph_name)
+            .and_then(|glyph| crate::color_paints::paint_at(font, glyph, &design_location))
+            .map(|paint| paint.to_json());
+        
         // Build result object with the appropriate shapes JSON
         let result = serde_json::json!({
             "name": glyph_name,
             "width": layer.width,
             "shapes": shapes_json,
             "bounds": bounds,
+            "colorPaint": color_paint,
         });
         
-        // Store in new_results for adding to persistent cache
-        new_results.push((glyph_name.clone(), result));
+        *slot = Some(result.clone());
+        new_results.push((outline_key(glyph_name), result));
     }
     
     // Add new results to persistent cache
     {
+        let budget = CACHE_LIMITS.lock().unwrap().outline_bytes;
         let mut cache_guard = OUTLINE_CACHE.lock().unwrap();
-        if cache_guard.is_none() {
-            *cache_guard = Some(OutlineCache {
-                location_json: normalized_location.to_string(),
-                results: HashMap::new(),
-            });
-        }
-        if let Some(ref mut cache) = *cache_guard {
-            for (name, result) in &new_results {
-                cache.results.insert(name.clone(), result.clone());
-            }
+        let cache = cache_guard.get_or_insert_with(|| ByteLru::new(budget));
+        for (key, result) in new_results {
+            let weight = json_weight(&result);
+            cache.insert(key, result, weight);
         }
     }
     
-    // Save layer cache back to persistent storage
-    {
-        let layer_map = layer_cache.borrow();
-        if !layer_map.is_empty() {
-            let mut cache_guard = LAYER_CACHE.lock().unwrap();
-            if cache_guard.is_none() {
-                *cache_guard = Some(LayerCache {
-                    location_json: normalized_location.to_string(),
-                    layers: HashMap::new(),
-                });
-            }
-            if let Some(ref mut cache) = *cache_guard {
-                for (name, layer) in layer_map.iter() {
-                    cache.layers.insert(name.clone(), layer.clone());
-                }
+    // Results in original order (missing glyphs are skipped)
+    let mut final_results: Vec<JsonValue> = results.into_iter().flatten().collect();
+    if include_background {
+        add_backgrounds(font, &design_location, flatten_components, &mut final_results)?;
+    }
+    
+    let result_json = serde_json::to_string(&final_results)
+        .map_err(|e| ApiError::serialization("results", e))?;
+    
+    Ok(result_json)
+}
+
+/// Add each glyph's background at a location to its outline result
+///
+/// Backgrounds are given as `{"width", "shapes", "bounds"}` like the
+/// glyph itself, or null if the glyph has no background there.
+fn add_backgrounds(
+    font: &babelfont::Font,
+    design_location: &DesignLocation,
+    flatten_components: bool,
+    results: &mut [JsonValue],
+) -> Result<(), JsValue> {
+    let layers = LayerSource::persistent(font, design_location);
+    for result in results.iter_mut() {
+        let Some(glyph) = result.get("name").and_then(|name| name.as_str()).and_then(|name| font.glyphs.get(name)) else {
+            continue;
+        };
+        let background = match crate::backgrounds::interpolated_background(font, glyph, design_location) {
+            Some(layer) => {
+                let flattened = flatten_layer_components_cached(&layer, &layers)?;
+                let shapes = if flatten_components { &flattened } else { &layer.shapes };
+                let shapes_json = serde_json::to_value(shapes)
+                    .map_err(|e| ApiError::serialization("layer", e))?;
+                serde_json::json!({
+                    "width": layer.width,
+                    "shapes": shapes_json,
+                    "bounds": calculate_bounds(&flattened),
+                })
             }
+            None => JsonValue::Null,
+        };
+        if let Some(object) = result.as_object_mut() {
+            object.insert("background".to_string(), background);
         }
     }
-    
-    // Combine cached results with new results in original order
-    let mut final_results = Vec::with_capacity(glyph_names.len());
-    {
-        let cache_guard = OUTLINE_CACHE.lock().unwrap();
-        if let Some(ref cache) = *cache_guard {
-            for glyph_name in glyph_names {
-                if let Some(result) = cache.results.get(glyph_name) {
-                    final_results.push(result.clone());
-                }
+    Ok(())
+}
+
+/// Node type codes used in packed outlines
+pub(crate) fn node_type_code(nodetype: NodeType) -> u8 {
+    match nodetype {
+        NodeType::Move => 0,
+        NodeType::Line => 1,
+        NodeType::OffCurve => 2,
+        NodeType::Curve => 3,
+        NodeType::QCurve => 4,
+    }
+}
+
+/// Get flattened outlines for multiple glyphs as packed typed arrays
+///
+/// All contours of all glyphs are written into flat buffers instead of
+/// nested JSON, so the overview can draw thousands of glyphs without
+/// per-node parsing. Interpolated layers share the persistent layer cache
+/// with get_glyphs_outlines(). Missing glyphs are skipped.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names` - List of glyph names to process
+/// * `location_json` - JSON object with axis tags and values in USER SPACE. Empty object '{}' uses default location.
+///
+/// # Returns
+/// * `js_sys::Object` - Object with fields:
+///   - `names`: string[] - Names of the returned glyphs
+///   - `widths`: Float32Array - Advance width per glyph
+///   - `bounds`: Float32Array - xMin, yMin, xMax, yMax per glyph
+///   - `glyphOffsets`: Uint32Array - First contour of each glyph, plus the total contour count
+///   - `contourOffsets`: Uint32Array - First node of each contour, plus the total node count
+///   - `contourClosed`: Uint8Array - 1 if the contour is closed
+///   - `coords`: Float32Array - x, y per node
+///   - `nodeTypes`: Uint8Array - Per node: 0 move, 1 line, 2 off-curve, 3 curve, 4 qcurve; bit 0x80 marks smooth nodes
+pub fn get_glyphs_outlines_packed(
+    font: &babelfont::Font,
+    glyph_names: &[String],
+    location_json: &str,
+) -> Result<js_sys::Object, JsValue> {
+    let design_location = design_location(font, location_json)?;
+    let layers = LayerSource::persistent(font, &design_location);
+
+    let names = js_sys::Array::new();
+    let mut widths: Vec<f32> = Vec::with_capacity(glyph_names.len());
+    let mut bounds: Vec<f32> = Vec::with_capacity(glyph_names.len() * 4);
+    let mut glyph_offsets: Vec<u32> = Vec::with_capacity(glyph_names.len() + 1);
+    let mut contour_offsets: Vec<u32> = Vec::new();
+    let mut contour_closed: Vec<u8> = Vec::new();
+    let mut coords: Vec<f32> = Vec::new();
+    let mut node_types: Vec<u8> = Vec::new();
+
+    for glyph_name in glyph_names {
+        if font.glyphs.get(glyph_name).is_none() {
+            continue;
+        }
+
+        let layer = layers.layer(glyph_name)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?;
+        let shapes = flatten_layer_components_cached(&layer, &layers)?;
+
+        names.push(&JsValue::from_str(glyph_name));
+        widths.push(layer.width);
+        glyph_offsets.push(contour_offsets.len() as u32);
+
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for shape in &shapes {
+            let Shape::Path(path) = shape else { continue };
+            contour_offsets.push(node_types.len() as u32);
+            contour_closed.push(path.closed as u8);
+            for node in &path.nodes {
+                coords.push(node.x as f32);
+                coords.push(node.y as f32);
+                node_types.push(node_type_code(node.nodetype) | if node.smooth { 0x80 } else { 0 });
+                min = Point::new(min.x.min(node.x), min.y.min(node.y));
+                max = Point::new(max.x.max(node.x), max.y.max(node.y));
             }
         }
+        if min.x.is_finite() {
+            bounds.extend([min.x as f32, min.y as f32, max.x as f32, max.y as f32]);
+        } else {
+            bounds.extend([0.0; 4]);
+        }
     }
+    glyph_offsets.push(contour_offsets.len() as u32);
+    contour_offsets.push(node_types.len() as u32);
+
+    let result = js_sys::Object::new();
+    let set = |key: &str, value: &JsValue| -> Result<(), JsValue> {
+        js_sys::Reflect::set(&result, &JsValue::from_str(key), value).map(|_| ())
+    };
+    set("names", &names)?;
+    set("widths", &js_sys::Float32Array::from(widths.as_slice()))?;
+    set("bounds", &js_sys::Float32Array::from(bounds.as_slice()))?;
+    set("glyphOffsets", &js_sys::Uint32Array::from(glyph_offsets.as_slice()))?;
+    set("contourOffsets", &js_sys::Uint32Array::from(contour_offsets.as_slice()))?;
+    set("contourClosed", &js_sys::Uint8Array::from(contour_closed.as_slice()))?;
+    set("coords", &js_sys::Float32Array::from(coords.as_slice()))?;
+    set("nodeTypes", &js_sys::Uint8Array::from(node_types.as_slice()))?;
+    Ok(result)
+}
+
+/// Convert a JSON object of user space axis values to a design space location
+///
+/// An empty string or empty object yields the default location.
+pub(crate) fn design_location(font: &babelfont::Font, location_json: &str) -> Result<DesignLocation, ApiError> {
+    // Parse location
+    let location_map: HashMap<String, f64> = if location_json.trim().is_empty() || location_json == "{}" {
+        HashMap::new()
+    } else {
+        serde_json::from_str(location_json)
+            .map_err(ApiError::invalid_location)?
+    };
     
-    let result_json = serde_json::to_string(&final_results)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize results: {}", e)))?;
+    // Convert to design space
+    let design_location: DesignLocation = if location_map.is_empty() {
+        // Use default location (all axes at default)
+        font.axes
+            .iter()
+            .filter_map(|axis| {
+                axis.default.map(|default_val| {
+                    (axis.tag, DesignCoord::new(default_val.to_f64()))
+                })
+            })
+            .collect()
+    } else {
+        location_map
+            .iter()
+            .map(|(tag_str, user_value)| {
+                let tag = Tag::from_str(tag_str)
+                    .map_err(|_| ApiError::invalid_argument("axis tag", tag_str))?;
+                
+                let design_value = if let Some(axis) = font.axes.iter().find(|a| a.tag == tag) {
+                    match axis.userspace_to_designspace(UserCoord::new(*user_value)) {
+                        Ok(design_coord) => design_coord,
+                        Err(_) => DesignCoord::new(*user_value),
+                    }
+                } else {
+                    DesignCoord::new(*user_value)
+                };
+                
+                Ok((tag, design_value))
+            })
+            .collect::<Result<Vec<_>, ApiError>>()?
+            .into_iter()
+            .collect()
+    };
     
-    Ok(result_json)
+    Ok(design_location)
 }
 
 /// Flatten all components in a layer into paths, using a cache for interpolated layers
-/// Returns (flattened_shapes, component_cache_hits, component_cache_misses)
-fn flatten_layer_components_cached(
-    font: &babelfont::Font,
+pub(crate) fn flatten_layer_components_cached(
     layer: &Layer,
-    location: &DesignLocation,
-    layer_cache: &RefCell<HashMap<String, Layer>>,
-) -> Result<(Vec<Shape>, usize, usize), JsValue> {
+    layers: &LayerSource,
+) -> Result<Vec<Shape>, ApiError> {
     let mut flattened_shapes = Vec::new();
-    let mut comp_hits = 0usize;
-    let mut comp_misses = 0usize;
     
     for shape in &layer.shapes {
         match shape {
@@ -302,32 +680,28 @@ fn flatten_layer_components_cached(
                 flattened_shapes.push(shape.clone());
             }
             Shape::Component(component) => {
-                // Check cache first (convert SmolStr to String for cache key)
-                let ref_key = component.reference.to_string();
-                let ref_layer = {
-                    let cache = layer_cache.borrow();
-                    if let Some(cached) = cache.get(&ref_key) {
-                        comp_hits += 1;
-                        cached.clone()
-                    } else {
-                        drop(cache);
-                        comp_misses += 1;
-                        let interpolated = font.interpolate_glyph(&component.reference, location)
-                            .map_err(|e| JsValue::from_str(&format!("Failed to interpolate component '{}': {:?}", component.reference, e)))?;
-                        layer_cache.borrow_mut().insert(ref_key.clone(), interpolated.clone());
-                        interpolated
-                    }
-                };
+                let reference = component.reference.to_string();
+                if layers.graph.is_cyclic(&reference) {
+                    return Err(ApiError::OperationFailed { message: format!("Cyclic component reference to '{}'", reference) });
+                }
                 
-                // Recursively flatten components in the referenced glyph
-                let (ref_shapes, sub_hits, sub_misses) = flatten_layer_components_cached(font, &ref_layer, location, layer_cache)?;
-                comp_hits += sub_hits;
-                comp_misses += sub_misses;
+                let ref_layer = layers.layer(&reference)
+                    .map_err(|e| ApiError::interpolation(&reference, e))?;
                 
-                // Apply component transformation to each shape
+                // Plain glyphs are used as they are; nested composites are
+                // flattened once per request and shared by all their users
+                let nested;
+                let ref_shapes: &[Shape] = if layers.graph.is_composite(&reference) {
+                    nested = flattened_component(&reference, &ref_layer, layers)?;
+                    &nested
+                } else {
+                    &ref_layer.shapes
+                };
+                
+                // Apply component transformation to each path
                 for ref_shape in ref_shapes {
-                    if let Shape::Path(mut path) = ref_shape {
-                        // Apply transformation to path nodes
+                    if let Shape::Path(path) = ref_shape {
+                        let mut path = path.clone();
                         path.nodes = transform_nodes(&path.nodes, &component.transform);
                         flattened_shapes.push(Shape::Path(path));
                     }
@@ -336,7 +710,21 @@ fn flatten_layer_components_cached(
         }
     }
     
-    Ok((flattened_shapes, comp_hits, comp_misses))
+    Ok(flattened_shapes)
+}
+
+/// Flattened shapes of a composite glyph used as a component, memoized per layer source
+fn flattened_component(
+    glyph_name: &str,
+    layer: &Layer,
+    layers: &LayerSource,
+) -> Result<Arc<Vec<Shape>>, ApiError> {
+    if let Some(shapes) = layers.flattened.borrow().get(glyph_name) {
+        return Ok(Arc::clone(shapes));
+    }
+    let shapes = Arc::new(flatten_layer_components_cached(layer, layers)?);
+    layers.flattened.borrow_mut().insert(glyph_name.to_string(), Arc::clone(&shapes));
+    Ok(shapes)
 }
 
 /// Transform path nodes by a transformation matrix
@@ -387,3 +775,211 @@ fn calculate_bounds(shapes: &[Shape]) -> serde_json::Value {
         })
     }
 }
+
+/// Preview marks attached to a base glyph using source anchors
+///
+/// Marks attach in order: each mark's `_name` anchor is matched with the most
+/// recent `name` anchor, which is the base's own anchor or one added by an
+/// earlier mark (mark-to-mark stacking). Marks without a matching anchor are
+/// reported as unattached and left at the origin.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `base_glyph` - Name of the base glyph
+/// * `mark_glyphs` - Names of the marks to attach, in order
+/// * `location_json` - JSON object with axis tags and values in USER SPACE. Empty object '{}' uses default location.
+///
+/// # Returns
+/// * `String` - JSON object: '{"advance": 560, "glyphs": [{"name": "a", "role": "base", "offset": {"x": 0, "y": 0}, "attachedTo": null, "anchor": null, "shapes": [...]}, {"name": "acutecomb", "role": "mark", "offset": {"x": 142, "y": 20}, "attachedTo": "a", "anchor": "top", "shapes": [...]}], "unattached": []}'
+pub fn preview_mark_attachment(
+    font: &babelfont::Font,
+    base_glyph: &str,
+    mark_glyphs: &[String],
+    location_json: &str,
+) -> Result<String, JsValue> {
+    let location = design_location(font, location_json)?;
+
+    let interpolate = |name: &str| -> Result<Layer, JsValue> {
+        if font.glyphs.get(name).is_none() {
+            return Err(ApiError::glyph_not_found(name).into());
+        }
+        font.interpolate_glyph(name, &location)
+            .map_err(|e| ApiError::interpolation(name, format!("{:?}", e)).into())
+    };
+
+    let base_layer = interpolate(base_glyph)?;
+
+    // Anchor name -> (position, glyph that provides it)
+    let mut available: HashMap<String, (Point, String)> = base_layer
+        .anchors
+        .iter()
+        .filter(|anchor| !anchor.name.starts_with('_'))
+        .map(|anchor| (anchor.name.to_string(), (Point::new(anchor.x, anchor.y), base_glyph.to_string())))
+        .collect();
+
+    let mut glyphs = vec![serde_json::json!({
+        "name": base_glyph,
+        "role": "base",
+        "offset": { "x": 0.0, "y": 0.0 },
+        "attachedTo": null,
+        "anchor": null,
+        "shapes": serde_json::to_value(flatten_layer(font, &base_layer, &location)?)
+            .map_err(|e| ApiError::serialization("layer", e))?,
+    })];
+    let mut unattached = Vec::new();
+
+    for mark_name in mark_glyphs {
+        let mark_layer = interpolate(mark_name)?;
+
+        let attachment = mark_layer.anchors.iter().find_map(|anchor| {
+            let name = anchor.name.strip_prefix('_')?;
+            let (target, provider) = available.get(name)?;
+            Some((name.to_string(), *target - Point::new(anchor.x, anchor.y), provider.clone()))
+        });
+
+        let (anchor_name, offset, attached_to) = match attachment {
+            Some((name, offset, provider)) => (Some(name), offset, Some(provider)),
+            None => {
+                unattached.push(mark_name.clone());
+                (None, kurbo::Vec2::ZERO, None)
+            }
+        };
+
+        // Anchors on the mark become available to following marks
+        if attached_to.is_some() {
+            for anchor in mark_layer.anchors.iter().filter(|anchor| !anchor.name.starts_with('_')) {
+                available.insert(
+                    anchor.name.to_string(),
+                    (Point::new(anchor.x, anchor.y) + offset, mark_name.clone()),
+                );
+            }
+        }
+
+        let transform = Affine::translate(offset);
+        let shapes: Vec<Shape> = flatten_layer(font, &mark_layer, &location)?
+            .into_iter()
+            .map(|shape| match shape {
+                Shape::Path(mut path) => {
+                    path.nodes = transform_nodes(&path.nodes, &transform);
+                    Shape::Path(path)
+                }
+                other => other,
+            })
+            .collect();
+
+        glyphs.push(serde_json::json!({
+            "name": mark_name,
+            "role": "mark",
+            "offset": { "x": offset.x, "y": offset.y },
+            "attachedTo": attached_to,
+            "anchor": anchor_name,
+            "shapes": serde_json::to_value(&shapes)
+                .map_err(|e| ApiError::serialization("layer", e))?,
+        }));
+    }
+
+    let result = serde_json::json!({
+        "advance": base_layer.width,
+        "glyphs": glyphs,
+        "unattached": unattached,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("results", e).into())
+}
+
+/// Design location of a layer: its master's location, else its own
+pub(crate) fn layer_location(font: &babelfont::Font, layer: &Layer) -> Option<DesignLocation> {
+    font.masters
+        .iter()
+        .find(|master| Some(&master.id) == layer.id.as_ref())
+        .map(|master| master.location.clone())
+        .or_else(|| layer.location.clone())
+}
+
+/// Flatten all components in a layer into paths
+pub(crate) fn flatten_layer(
+    font: &babelfont::Font,
+    layer: &Layer,
+    location: &DesignLocation,
+) -> Result<Vec<Shape>, ApiError> {
+    flatten_layer_components_cached(layer, &LayerSource::new(font, location))
+}
+
+/// Convert a source path to a kurbo BezPath
+///
+/// Closed paths start at their last on-curve node; off-curve nodes precede
+/// the curve node they belong to. Runs of quadratic off-curves get implied
+/// on-curve points between them.
+pub(crate) fn path_to_bezpath(path: &babelfont::Path) -> BezPath {
+    let mut bez = BezPath::new();
+    let nodes = &path.nodes;
+    if nodes.is_empty() {
+        return bez;
+    }
+
+    let point = |node: &Node| Point::new(node.x, node.y);
+    let is_on_curve = |node: &Node| !matches!(node.nodetype, NodeType::OffCurve);
+
+    // Order the nodes so the contour starts at an on-curve point
+    let (start, sequence): (Point, Vec<&Node>) = if !path.closed {
+        (point(&nodes[0]), nodes[1..].iter().collect())
+    } else if let Some(last_on) = nodes.iter().rposition(is_on_curve) {
+        let sequence = nodes[last_on + 1..].iter().chain(nodes[..=last_on].iter()).collect();
+        (point(&nodes[last_on]), sequence)
+    } else {
+        // All off-curve quadratic contour: start at an implied on-curve point
+        let first = point(&nodes[0]);
+        let last = point(&nodes[nodes.len() - 1]);
+        let start = first.midpoint(last);
+        for (index, node) in nodes.iter().enumerate() {
+            let control = point(node);
+            let next = point(&nodes[(index + 1) % nodes.len()]);
+            if index == 0 {
+                bez.move_to(start);
+            }
+            let end = if index + 1 == nodes.len() { start } else { control.midpoint(next) };
+            bez.quad_to(control, end);
+        }
+        bez.close_path();
+        return bez;
+    };
+
+    bez.move_to(start);
+    let mut off_curves: Vec<Point> = Vec::new();
+    for node in sequence {
+        if !is_on_curve(node) {
+            off_curves.push(point(node));
+            continue;
+        }
+        let on = point(node);
+        match (node.nodetype, off_curves.len()) {
+            (_, 0) => bez.line_to(on),
+            (NodeType::QCurve, _) => {
+                for pair in off_curves.windows(2) {
+                    bez.quad_to(pair[0], pair[0].midpoint(pair[1]));
+                }
+                bez.quad_to(off_curves[off_curves.len() - 1], on);
+            }
+            (_, 1) => bez.quad_to(off_curves[0], on),
+            _ => bez.curve_to(off_curves[0], off_curves[off_curves.len() - 1], on),
+        }
+        off_curves.clear();
+    }
+
+    if path.closed {
+        bez.close_path();
+    }
+    bez
+}
+
+/// Convert flattened shapes to a single kurbo BezPath (components are ignored)
+pub(crate) fn shapes_to_bezpath(shapes: &[Shape]) -> BezPath {
+    let mut bez = BezPath::new();
+    for shape in shapes {
+        if let Shape::Path(path) = shape {
+            bez.extend(path_to_bezpath(path));
+        }
+    }
+    bez
+}
@@ -0,0 +1,303 @@
+// Glyph query module
+//
+// This module finds the glyphs of a source font matching a query, for the
+// smart filters of the glyph overview. A query combines predicates on the
+// glyph name (glob or regular expression), its characters (codepoint
+// ranges, Unicode blocks, scripts), its category, the components it uses,
+// its kerning and the time of its last change; a glyph matches if it
+// satisfies all of them. Glyphs without codepoints take the character of
+// their base name ("a.sc" counts as "a"), as in the overview's grouping.
+
+use std::collections::HashSet;
+
+use regex_lite::Regex;
+use serde_json::{json, Value as JsonValue};
+use unicode_script::{Script, UnicodeScript};
+
+use crate::charsets::glyph_name_codepoint;
+use crate::errors::ApiError;
+use crate::feature_export::wildcard_match;
+use crate::glyph_outlines;
+use crate::kerning;
+use crate::unicode_data;
+
+/// Keys of the last change time of a glyph, as kept by the source formats
+///
+/// Edits stamp the first key (see stamp_last_change()).
+const LAST_CHANGE_KEYS: [&str; 2] = ["lastChange", "com.schriftgestaltung.Glyphs.lastChange"];
+
+/// Parsed query predicates
+#[derive(Default)]
+struct Query {
+    name: Option<Vec<String>>,
+    regex: Option<Regex>,
+    ranges: Option<Vec<(u32, u32)>>,
+    scripts: Option<Vec<Script>>,
+    categories: Option<Vec<String>>,
+    /// Glyphs using the component, directly or nested
+    component_users: Option<HashSet<String>>,
+    /// Whether matching glyphs have kerning, with the kerned glyphs
+    kerning: Option<(bool, HashSet<String>)>,
+    modified_since: Option<String>,
+}
+
+/// A string or a list of strings (any of which may match)
+fn string_list(query: &JsonValue, key: &str) -> Result<Option<Vec<String>>, ApiError> {
+    match query.get(key) {
+        None => Ok(None),
+        Some(JsonValue::String(value)) => Ok(Some(vec![value.clone()])),
+        Some(JsonValue::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| ApiError::invalid_argument(key, value))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        Some(other) => Err(ApiError::invalid_argument(key, other)),
+    }
+}
+
+/// Parse a codepoint given as a "U+XXXX" or bare hexadecimal string
+fn parse_codepoint(value: &str) -> Option<u32> {
+    let hex = value.trim().trim_start_matches("U+").trim_start_matches("u+");
+    u32::from_str_radix(hex, 16).ok().filter(|codepoint| *codepoint <= 0x10FFFF)
+}
+
+/// Inclusive codepoint ranges of a `unicodes` list
+///
+/// Entries are numbers, "U+XXXX" strings or "U+XXXX-YYYY" ranges.
+fn codepoint_ranges(values: &JsonValue) -> Result<Vec<(u32, u32)>, ApiError> {
+    let values = values
+        .as_array()
+        .ok_or_else(|| ApiError::invalid_argument("unicodes", values))?;
+    values
+        .iter()
+        .map(|value| {
+            let invalid = || ApiError::invalid_argument("codepoint or range", value);
+            match value {
+                JsonValue::Number(n) => n.as_u64().map(|n| (n as u32, n as u32)).ok_or_else(invalid),
+                JsonValue::String(s) => match s.split_once('-') {
+                    Some((first, last)) => {
+                        let first = parse_codepoint(first).ok_or_else(invalid)?;
+                        let last = parse_codepoint(last).filter(|last| *last >= first).ok_or_else(invalid)?;
+                        Ok((first, last))
+                    }
+                    None => parse_codepoint(s).map(|codepoint| (codepoint, codepoint)).ok_or_else(invalid),
+                },
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Normalize a date or date-time to "YYYY-MM-DD HH:MM:SS" for comparison
+///
+/// Accepts "2024-05-01", "2024-05-01 12:30:00" and ISO 8601 forms such as
+/// "2024-05-01T12:30:00Z". Time zones are ignored.
+fn normalize_timestamp(value: &str) -> Option<String> {
+    let value = value.trim().replacen('T', " ", 1);
+    let date = value.get(..10)?;
+    let valid_date = date
+        .char_indices()
+        .all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() });
+    if !valid_date {
+        return None;
+    }
+    match value.get(11..19) {
+        Some(time) if time.char_indices().all(|(i, c)| if i == 2 || i == 5 { c == ':' } else { c.is_ascii_digit() }) => {
+            Some(format!("{} {}", date, time))
+        }
+        Some(_) => None,
+        None => Some(format!("{} 00:00:00", date)),
+    }
+}
+
+/// Record the current time as the last change of glyphs (call after editing them)
+///
+/// The time goes under the first key, which is read first, so edits made in
+/// the editor count as well as the change times imported from the source.
+pub fn stamp_last_change<'a>(glyphs: impl IntoIterator<Item = &'a mut babelfont::Glyph>) {
+    let now = JsonValue::String(js_sys::Date::new_0().to_iso_string().into());
+    for glyph in glyphs {
+        glyph.format_specific.insert(LAST_CHANGE_KEYS[0].to_string(), now.clone());
+    }
+}
+
+/// Time of the last change of a glyph, if it was edited or the source recorded one
+fn last_change(glyph: &babelfont::Glyph) -> Option<String> {
+    LAST_CHANGE_KEYS
+        .iter()
+        .find_map(|key| glyph.format_specific.get(*key))
+        .and_then(|value| value.as_str())
+        .and_then(normalize_timestamp)
+}
+
+/// Codepoints of a glyph, or the codepoint of its base name if it has none
+fn glyph_codepoints(glyph: &babelfont::Glyph) -> Vec<u32> {
+    if !glyph.codepoints.is_empty() {
+        return glyph.codepoints.clone();
+    }
+    let base = glyph.name.split('.').next().unwrap_or_default();
+    glyph_name_codepoint(base).into_iter().collect()
+}
+
+/// Glyphs kerned in any master, directly or through their groups
+fn kerned_glyphs(font: &babelfont::Font) -> Result<HashSet<String>, ApiError> {
+    let mut glyphs = HashSet::new();
+    for (left, right) in kerning::kerned_sides(font, None)? {
+        glyphs.extend(kerning::side_members(font, &left, true));
+        glyphs.extend(kerning::side_members(font, &right, false));
+    }
+    Ok(glyphs)
+}
+
+fn parse_query(font: &babelfont::Font, query: &JsonValue) -> Result<Query, ApiError> {
+    if !query.is_object() {
+        return Err(ApiError::invalid_argument("query", query));
+    }
+    let mut parsed = Query {
+        name: string_list(query, "name")?,
+        categories: string_list(query, "category")?
+            .map(|categories| categories.iter().map(|category| category.to_lowercase()).collect()),
+        ..Default::default()
+    };
+
+    if let Some(pattern) = query.get("regex") {
+        let pattern = pattern
+            .as_str()
+            .ok_or_else(|| ApiError::invalid_argument("regex", pattern))?;
+        parsed.regex = Some(Regex::new(pattern).map_err(|_| ApiError::invalid_argument("regex", pattern))?);
+    }
+
+    // Blocks add to the codepoint ranges
+    let mut ranges = query.get("unicodes").map(codepoint_ranges).transpose()?;
+    if let Some(blocks) = string_list(query, "block")? {
+        let ranges = ranges.get_or_insert_with(Vec::new);
+        for block in blocks {
+            let (first, last, _) =
+                unicode_data::find_block(&block).ok_or_else(|| ApiError::invalid_argument("Unicode block", &block))?;
+            ranges.push((first, last));
+        }
+    }
+    parsed.ranges = ranges;
+
+    if let Some(scripts) = string_list(query, "script")? {
+        parsed.scripts = Some(
+            scripts
+                .iter()
+                .map(|name| {
+                    Script::from_full_name(name)
+                        .or_else(|| Script::from_short_name(name))
+                        .ok_or_else(|| ApiError::invalid_argument("script", name))
+                })
+                .collect::<Result<_, _>>()?,
+        );
+    }
+
+    if let Some(component) = query.get("usesComponent") {
+        let component = component
+            .as_str()
+            .ok_or_else(|| ApiError::invalid_argument("usesComponent", component))?;
+        let mut users = glyph_outlines::with_component_dependents(font, component);
+        users.remove(component);
+        parsed.component_users = Some(users);
+    }
+
+    if let Some(has_kerning) = query.get("hasKerning") {
+        let has_kerning = has_kerning
+            .as_bool()
+            .ok_or_else(|| ApiError::invalid_argument("hasKerning", has_kerning))?;
+        parsed.kerning = Some((has_kerning, kerned_glyphs(font)?));
+    }
+
+    if let Some(since) = query.get("modifiedSince") {
+        parsed.modified_since = Some(
+            since
+                .as_str()
+                .and_then(normalize_timestamp)
+                .ok_or_else(|| ApiError::invalid_argument("modifiedSince", since))?,
+        );
+    }
+    Ok(parsed)
+}
+
+fn matches(query: &Query, glyph: &babelfont::Glyph) -> bool {
+    let name = glyph.name.as_str();
+    if let Some(patterns) = &query.name {
+        if !patterns.iter().any(|pattern| wildcard_match(name, pattern)) {
+            return false;
+        }
+    }
+    if query.regex.as_ref().is_some_and(|regex| !regex.is_match(name)) {
+        return false;
+    }
+
+    let codepoints = glyph_codepoints(glyph);
+    if let Some(ranges) = &query.ranges {
+        let in_range = codepoints
+            .iter()
+            .any(|codepoint| ranges.iter().any(|&(first, last)| (first..=last).contains(codepoint)));
+        if !in_range {
+            return false;
+        }
+    }
+    if let Some(scripts) = &query.scripts {
+        let in_script = codepoints
+            .iter()
+            .filter_map(|&codepoint| char::from_u32(codepoint))
+            .any(|c| scripts.contains(&c.script()));
+        if !in_script {
+            return false;
+        }
+    }
+
+    if let Some(categories) = &query.categories {
+        let category = serde_json::to_value(&glyph.category)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_lowercase))
+            .unwrap_or_default();
+        if !categories.contains(&category) {
+            return false;
+        }
+    }
+    if query.component_users.as_ref().is_some_and(|users| !users.contains(name)) {
+        return false;
+    }
+    if let Some((has_kerning, kerned)) = &query.kerning {
+        if kerned.contains(name) != *has_kerning {
+            return false;
+        }
+    }
+    if let Some(since) = &query.modified_since {
+        // Glyphs without a recorded change time do not match
+        if last_change(glyph).is_none_or(|changed| changed < *since) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Find the glyphs of a font matching a query
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `query_json` - JSON object of predicates, all of which must match (see find_glyphs() in lib.rs)
+///
+/// # Returns
+/// * `JsonValue` - '{"glyphs": ["A", "Aacute"], "count": 2}', in glyph order
+pub fn find_glyphs(font: &babelfont::Font, query_json: &str) -> Result<JsonValue, ApiError> {
+    let query: JsonValue = serde_json::from_str(query_json)
+        .map_err(|e| ApiError::invalid_json("query", e))?;
+    let query = parse_query(font, &query)?;
+
+    let glyphs: Vec<&str> = font
+        .glyphs
+        .iter()
+        .filter(|glyph| matches(&query, glyph))
+        .map(|glyph| glyph.name.as_str())
+        .collect();
+    Ok(json!({ "glyphs": glyphs, "count": glyphs.len() }))
+}
@@ -0,0 +1,615 @@
+// GSUB preview module
+//
+// This module applies the substitution lookups of a compiled GSUB table to a
+// sequence of glyph names, without shaping text. Feature edits can be tried
+// on glyphs that have no code point (alternates, ligatures) and the result
+// is read as names, so the feature editor can test rules instantly. Only
+// GSUB is applied: single, multiple, alternate, ligature, contextual,
+// chained contextual and reverse chained substitutions, honoring lookup
+// flags through the GDEF glyph classes.
+
+use read_fonts::tables::gdef::Gdef;
+use read_fonts::tables::gsub::{SingleSubst, SubstitutionLookupList, SubstitutionSubtables};
+use read_fonts::tables::layout::{ChainedSequenceContext, ClassDef, CoverageTable, SequenceContext, SequenceLookupRecord};
+use read_fonts::types::{GlyphId16, Tag};
+use read_fonts::{FontRef, ReadError, TableProvider};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::errors::ApiError;
+use crate::font_reader::read_glyph_order;
+
+/// Deepest nesting of lookups called from contextual lookups
+const MAX_NESTING: usize = 16;
+
+/// Lookup flag bits (OpenType LookupFlag)
+const IGNORE_BASE_GLYPHS: u16 = 0x0002;
+const IGNORE_LIGATURES: u16 = 0x0004;
+const IGNORE_MARKS: u16 = 0x0008;
+const USE_MARK_FILTERING_SET: u16 = 0x0010;
+const MARK_ATTACHMENT_TYPE: u16 = 0xFF00;
+
+/// Applies GSUB lookups to a glyph buffer
+struct Applier<'a> {
+    lookups: SubstitutionLookupList<'a>,
+    gdef: Option<Gdef<'a>>,
+}
+
+/// Flags of the lookup being applied, for skipping glyphs
+#[derive(Clone, Copy)]
+struct Skip {
+    flags: u16,
+    mark_filtering_set: Option<u16>,
+}
+
+impl<'a> Applier<'a> {
+    fn glyph_class(&self, gid: GlyphId16) -> u16 {
+        self.gdef
+            .as_ref()
+            .and_then(|gdef| gdef.glyph_class_def())
+            .and_then(|class_def| class_def.ok())
+            .map_or(0, |class_def| class_def.get(gid))
+    }
+
+    /// Whether a lookup with these flags skips a glyph
+    fn skips(&self, skip: Skip, gid: GlyphId16) -> bool {
+        match self.glyph_class(gid) {
+            1 => skip.flags & IGNORE_BASE_GLYPHS != 0,
+            2 => skip.flags & IGNORE_LIGATURES != 0,
+            3 => {
+                if skip.flags & IGNORE_MARKS != 0 {
+                    return true;
+                }
+                let Some(gdef) = self.gdef.as_ref() else { return false };
+                if skip.flags & USE_MARK_FILTERING_SET != 0 {
+                    let in_set = skip.mark_filtering_set.and_then(|set| {
+                        let sets = gdef.mark_glyph_sets_def()?.ok()?;
+                        let coverage = sets.coverages().get(set as usize).ok()?;
+                        Some(coverage.get(gid).is_some())
+                    });
+                    return !in_set.unwrap_or(false);
+                }
+                let attachment_type = (skip.flags & MARK_ATTACHMENT_TYPE) >> 8;
+                if attachment_type != 0 {
+                    let class = gdef
+                        .mark_attach_class_def()
+                        .and_then(|class_def| class_def.ok())
+                        .map_or(0, |class_def| class_def.get(gid));
+                    return class != attachment_type;
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Positions of `count` glyphs after `start` that the lookup does not skip
+    fn following(&self, skip: Skip, buffer: &[GlyphId16], start: usize, count: usize) -> Option<Vec<usize>> {
+        let mut positions = Vec::with_capacity(count);
+        let mut index = start + 1;
+        while positions.len() < count {
+            let gid = *buffer.get(index)?;
+            if !self.skips(skip, gid) {
+                positions.push(index);
+            }
+            index += 1;
+        }
+        Some(positions)
+    }
+
+    /// Positions of `count` glyphs before `start` that the lookup does not skip, nearest first
+    fn preceding(&self, skip: Skip, buffer: &[GlyphId16], start: usize, count: usize) -> Option<Vec<usize>> {
+        let mut positions = Vec::with_capacity(count);
+        let mut index = start;
+        while positions.len() < count {
+            index = index.checked_sub(1)?;
+            if !self.skips(skip, buffer[index]) {
+                positions.push(index);
+            }
+        }
+        Some(positions)
+    }
+
+    /// Apply every subtable of a lookup along the buffer
+    fn apply_lookup(&self, lookup_index: u16, buffer: &mut Vec<GlyphId16>, alternate: u32) -> bool {
+        let Ok(lookup) = self.lookups.lookups().get(lookup_index as usize) else { return false };
+        let reverse = matches!(lookup.subtables(), Ok(SubstitutionSubtables::Reverse(_)));
+        let skip = Skip { flags: lookup.lookup_flag().to_bits(), mark_filtering_set: lookup.mark_filtering_set() };
+        let mut changed = false;
+        if reverse {
+            for position in (0..buffer.len()).rev() {
+                if !self.skips(skip, buffer[position]) {
+                    changed |= self.apply_at(lookup_index, buffer, position, alternate, 0).is_some();
+                }
+            }
+            return changed;
+        }
+        let mut position = 0;
+        while position < buffer.len() {
+            if self.skips(skip, buffer[position]) {
+                position += 1;
+                continue;
+            }
+            match self.apply_at(lookup_index, buffer, position, alternate, 0) {
+                Some(advance) => {
+                    changed = true;
+                    position += advance;
+                }
+                None => position += 1,
+            }
+        }
+        changed
+    }
+
+    /// Apply a lookup at one position of the buffer
+    ///
+    /// Returns how many positions the buffer was advanced by, or None if no
+    /// subtable applied.
+    fn apply_at(
+        &self,
+        lookup_index: u16,
+        buffer: &mut Vec<GlyphId16>,
+        position: usize,
+        alternate: u32,
+        depth: usize,
+    ) -> Option<usize> {
+        let lookup = self.lookups.lookups().get(lookup_index as usize).ok()?;
+        let skip = Skip { flags: lookup.lookup_flag().to_bits(), mark_filtering_set: lookup.mark_filtering_set() };
+        let gid = *buffer.get(position)?;
+        let covered = |coverage: Result<CoverageTable, ReadError>| coverage.ok().and_then(|coverage| coverage.get(gid));
+
+        match lookup.subtables().ok()? {
+            SubstitutionSubtables::Single(subtables) => {
+                for subtable in subtables.iter().filter_map(|s| s.ok()) {
+                    let output = match subtable {
+                        SingleSubst::Format1(table) => covered(table.coverage()).map(|_| {
+                            GlyphId16::new((gid.to_u16() as i32 + table.delta_glyph_id() as i32).rem_euclid(0x10000) as u16)
+                        }),
+                        SingleSubst::Format2(table) => covered(table.coverage())
+                            .and_then(|index| table.substitute_glyph_ids().get(index as usize).map(|g| g.get())),
+                    };
+                    if let Some(output) = output {
+                        buffer[position] = output;
+                        return Some(1);
+                    }
+                }
+                None
+            }
+            SubstitutionSubtables::Multiple(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Some(index) = covered(table.coverage()) else { continue };
+                    let sequence = table.sequences().get(index as usize).ok()?;
+                    let output: Vec<GlyphId16> = sequence.substitute_glyph_ids().iter().map(|g| g.get()).collect();
+                    let advance = output.len();
+                    buffer.splice(position..position + 1, output);
+                    return Some(advance);
+                }
+                None
+            }
+            SubstitutionSubtables::Alternate(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Some(index) = covered(table.coverage()) else { continue };
+                    let set = table.alternate_sets().get(index as usize).ok()?;
+                    // Feature value n selects the nth alternate
+                    let output = set.alternate_glyph_ids().get(alternate.max(1) as usize - 1)?.get();
+                    buffer[position] = output;
+                    return Some(1);
+                }
+                None
+            }
+            SubstitutionSubtables::Ligature(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Some(index) = covered(table.coverage()) else { continue };
+                    let set = table.ligature_sets().get(index as usize).ok()?;
+                    for ligature in set.ligatures().iter().filter_map(|l| l.ok()) {
+                        let components = ligature.component_glyph_ids();
+                        let Some(positions) = self.following(skip, buffer, position, components.len()) else {
+                            continue;
+                        };
+                        let matches = positions.iter().zip(components).all(|(&at, component)| buffer[at] == component.get());
+                        if !matches {
+                            continue;
+                        }
+                        // Skipped glyphs between the components stay after the ligature
+                        for &at in positions.iter().rev() {
+                            buffer.remove(at);
+                        }
+                        buffer[position] = ligature.ligature_glyph();
+                        return Some(1);
+                    }
+                }
+                None
+            }
+            SubstitutionSubtables::Contextual(subtables) => {
+                for context in subtables.iter().filter_map(|s| s.ok()) {
+                    if let Some(advance) = self.apply_context(&context, skip, buffer, position, alternate, depth) {
+                        return Some(advance);
+                    }
+                }
+                None
+            }
+            SubstitutionSubtables::ChainContextual(subtables) => {
+                for context in subtables.iter().filter_map(|s| s.ok()) {
+                    if let Some(advance) = self.apply_chained_context(&context, skip, buffer, position, alternate, depth) {
+                        return Some(advance);
+                    }
+                }
+                None
+            }
+            SubstitutionSubtables::Reverse(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Some(index) = covered(table.coverage()) else { continue };
+                    let backtrack = table.backtrack_coverages();
+                    let lookahead = table.lookahead_coverages();
+                    let before = self.preceding(skip, buffer, position, backtrack.len());
+                    let after = self.following(skip, buffer, position, lookahead.len());
+                    let (Some(before), Some(after)) = (before, after) else { continue };
+                    let matches = before.iter().zip(backtrack.iter()).all(|(&at, coverage)| {
+                        coverage.ok().and_then(|coverage| coverage.get(buffer[at])).is_some()
+                    }) && after.iter().zip(lookahead.iter()).all(|(&at, coverage)| {
+                        coverage.ok().and_then(|coverage| coverage.get(buffer[at])).is_some()
+                    });
+                    if !matches {
+                        continue;
+                    }
+                    buffer[position] = table.substitute_glyph_ids().get(index as usize)?.get();
+                    return Some(1);
+                }
+                None
+            }
+        }
+    }
+
+    /// Apply a contextual subtable at a position
+    fn apply_context(
+        &self,
+        context: &SequenceContext,
+        skip: Skip,
+        buffer: &mut Vec<GlyphId16>,
+        position: usize,
+        alternate: u32,
+        depth: usize,
+    ) -> Option<usize> {
+        let gid = buffer[position];
+        match context {
+            SequenceContext::Format1(table) => {
+                let index = table.coverage().ok()?.get(gid)?;
+                let rule_set = table.seq_rule_sets().get(index as usize)?.ok()?;
+                for rule in rule_set.seq_rules().iter().filter_map(|r| r.ok()) {
+                    let input = rule.input_sequence();
+                    let Some(positions) = self.following(skip, buffer, position, input.len()) else { continue };
+                    if positions.iter().zip(input).all(|(&at, glyph)| buffer[at] == glyph.get()) {
+                        let records = lookup_records(rule.seq_lookup_records());
+                        return Some(self.apply_records(&records, buffer, position, positions, alternate, depth));
+                    }
+                }
+                None
+            }
+            SequenceContext::Format2(table) => {
+                table.coverage().ok()?.get(gid)?;
+                let class_def = table.class_def().ok()?;
+                let rule_set = table.class_seq_rule_sets().get(class_def.get(gid) as usize)?.ok()?;
+                for rule in rule_set.class_seq_rules().iter().filter_map(|r| r.ok()) {
+                    let input = rule.input_sequence();
+                    let Some(positions) = self.following(skip, buffer, position, input.len()) else { continue };
+                    if positions.iter().zip(input).all(|(&at, class)| class_def.get(buffer[at]) == class.get()) {
+                        let records = lookup_records(rule.seq_lookup_records());
+                        return Some(self.apply_records(&records, buffer, position, positions, alternate, depth));
+                    }
+                }
+                None
+            }
+            SequenceContext::Format3(table) => {
+                let coverages = table.coverages();
+                let positions = self.following(skip, buffer, position, coverages.len().saturating_sub(1))?;
+                let matched = std::iter::once(position)
+                    .chain(positions.iter().copied())
+                    .zip(coverages.iter())
+                    .all(|(at, coverage)| coverage.ok().and_then(|coverage| coverage.get(buffer[at])).is_some());
+                if !matched {
+                    return None;
+                }
+                let records = lookup_records(table.seq_lookup_records());
+                Some(self.apply_records(&records, buffer, position, positions, alternate, depth))
+            }
+        }
+    }
+
+    /// Apply a chained contextual subtable at a position
+    fn apply_chained_context(
+        &self,
+        context: &ChainedSequenceContext,
+        skip: Skip,
+        buffer: &mut Vec<GlyphId16>,
+        position: usize,
+        alternate: u32,
+        depth: usize,
+    ) -> Option<usize> {
+        let gid = buffer[position];
+        // Positions of backtrack, input (after the first) and lookahead glyphs
+        let context_positions = |backtrack: usize, input: usize, lookahead: usize| {
+            let before = self.preceding(skip, buffer, position, backtrack)?;
+            let input_positions = self.following(skip, buffer, position, input)?;
+            let after = self.following(skip, buffer, input_positions.last().copied().unwrap_or(position), lookahead)?;
+            Some((before, input_positions, after))
+        };
+        match context {
+            ChainedSequenceContext::Format1(table) => {
+                let index = table.coverage().ok()?.get(gid)?;
+                let rule_set = table.chained_seq_rule_sets().get(index as usize)?.ok()?;
+                for rule in rule_set.chained_seq_rules().iter().filter_map(|r| r.ok()) {
+                    let (backtrack, input, lookahead) =
+                        (rule.backtrack_sequence(), rule.input_sequence(), rule.lookahead_sequence());
+                    let Some((before, positions, after)) = context_positions(backtrack.len(), input.len(), lookahead.len())
+                    else {
+                        continue;
+                    };
+                    let matches = before.iter().zip(backtrack).all(|(&at, glyph)| buffer[at] == glyph.get())
+                        && positions.iter().zip(input).all(|(&at, glyph)| buffer[at] == glyph.get())
+                        && after.iter().zip(lookahead).all(|(&at, glyph)| buffer[at] == glyph.get());
+                    if matches {
+                        let records = lookup_records(rule.seq_lookup_records());
+                        return Some(self.apply_records(&records, buffer, position, positions, alternate, depth));
+                    }
+                }
+                None
+            }
+            ChainedSequenceContext::Format2(table) => {
+                table.coverage().ok()?.get(gid)?;
+                let backtrack_classes = table.backtrack_class_def().ok()?;
+                let input_classes = table.input_class_def().ok()?;
+                let lookahead_classes = table.lookahead_class_def().ok()?;
+                let rule_set = table.chained_class_seq_rule_sets().get(input_classes.get(gid) as usize)?.ok()?;
+                for rule in rule_set.chained_class_seq_rules().iter().filter_map(|r| r.ok()) {
+                    let (backtrack, input, lookahead) =
+                        (rule.backtrack_sequence(), rule.input_sequence(), rule.lookahead_sequence());
+                    let Some((before, positions, after)) = context_positions(backtrack.len(), input.len(), lookahead.len())
+                    else {
+                        continue;
+                    };
+                    let in_classes = |positions: &[usize], classes: &[read_fonts::types::BigEndian<u16>], class_def: &ClassDef| {
+                        positions.iter().zip(classes).all(|(&at, class)| class_def.get(buffer[at]) == class.get())
+                    };
+                    if in_classes(&before, backtrack, &backtrack_classes)
+                        && in_classes(&positions, input, &input_classes)
+                        && in_classes(&after, lookahead, &lookahead_classes)
+                    {
+                        let records = lookup_records(rule.seq_lookup_records());
+                        return Some(self.apply_records(&records, buffer, position, positions, alternate, depth));
+                    }
+                }
+                None
+            }
+            ChainedSequenceContext::Format3(table) => {
+                let (backtrack, input, lookahead) =
+                    (table.backtrack_coverages(), table.input_coverages(), table.lookahead_coverages());
+                let (before, positions, after) =
+                    context_positions(backtrack.len(), input.len().saturating_sub(1), lookahead.len())?;
+                let covered = |at: usize, coverage: Result<CoverageTable, ReadError>| {
+                    coverage.ok().and_then(|coverage| coverage.get(buffer[at])).is_some()
+                };
+                let matches = before.iter().zip(backtrack.iter()).all(|(&at, coverage)| covered(at, coverage))
+                    && std::iter::once(position)
+                        .chain(positions.iter().copied())
+                        .zip(input.iter())
+                        .all(|(at, coverage)| covered(at, coverage))
+                    && after.iter().zip(lookahead.iter()).all(|(&at, coverage)| covered(at, coverage));
+                if !matches {
+                    return None;
+                }
+                let records = lookup_records(table.seq_lookup_records());
+                Some(self.apply_records(&records, buffer, position, positions, alternate, depth))
+            }
+        }
+    }
+
+    /// Apply the nested lookups of a matched context
+    ///
+    /// `following` holds the positions of the input glyphs after the first.
+    /// Returns how far to advance past the match.
+    fn apply_records(
+        &self,
+        records: &[(u16, u16)],
+        buffer: &mut Vec<GlyphId16>,
+        position: usize,
+        following: Vec<usize>,
+        alternate: u32,
+        depth: usize,
+    ) -> usize {
+        let mut positions: Vec<usize> = std::iter::once(position).chain(following).collect();
+        if depth < MAX_NESTING {
+            for &(sequence_index, lookup_index) in records {
+                let Some(&at) = positions.get(sequence_index as usize) else { continue };
+                let length = buffer.len();
+                if self.apply_at(lookup_index, buffer, at, alternate, depth + 1).is_none() {
+                    continue;
+                }
+                // Later input positions move with glyphs added or removed
+                let change = buffer.len() as isize - length as isize;
+                if change != 0 {
+                    for later in positions.iter_mut().filter(|later| **later > at) {
+                        *later = (*later as isize + change).max(at as isize) as usize;
+                    }
+                }
+            }
+        }
+        let end = positions.last().copied().unwrap_or(position) + 1;
+        end.saturating_sub(position).max(1)
+    }
+}
+
+/// Sequence index and lookup index of sequence lookup records
+fn lookup_records(records: &[SequenceLookupRecord]) -> Vec<(u16, u16)> {
+    records
+        .iter()
+        .map(|record| (record.sequence_index(), record.lookup_list_index()))
+        .collect()
+}
+
+/// Feature settings of a preview: tag and value, where 0 disables
+fn parse_feature_settings(features_json: &str) -> Result<Vec<(String, u32)>, ApiError> {
+    let features_json = features_json.trim();
+    if features_json.is_empty() {
+        return Ok(Vec::new());
+    }
+    let features: serde_json::Value =
+        serde_json::from_str(features_json).map_err(|e| ApiError::invalid_json("features", e))?;
+    match features {
+        serde_json::Value::Array(tags) => tags
+            .iter()
+            .map(|tag| {
+                tag.as_str()
+                    .map(|tag| (tag.to_string(), 1))
+                    .ok_or_else(|| ApiError::invalid_argument("feature tag", tag))
+            })
+            .collect(),
+        serde_json::Value::Object(settings) => settings
+            .iter()
+            .map(|(tag, value)| {
+                let value = match value {
+                    serde_json::Value::Bool(enabled) => *enabled as u32,
+                    other => other
+                        .as_u64()
+                        .ok_or_else(|| ApiError::invalid_argument("feature setting", format!("{}={}", tag, other)))?
+                        as u32,
+                };
+                Ok((tag.clone(), value))
+            })
+            .collect(),
+        other => Err(ApiError::invalid_argument("features", other)),
+    }
+}
+
+/// Apply GSUB features of compiled font bytes to a glyph name sequence
+///
+/// The features' lookups are applied in lookup list order, as a shaper
+/// does; the lookups come from the language system of `script` and
+/// `language` (falling back to the script's default and to `DFLT`).
+/// For alternate substitutions, a feature value of n picks the nth
+/// alternate.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled font bytes with a GSUB table
+/// * `glyph_names` - Glyph names to substitute
+/// * `features_json` - JSON array of feature tags, e.g., '["liga", "ss01"]', or an object of settings, e.g., '{"liga": 1, "salt": 2}'
+/// * `options_json` - Optional JSON object:
+///   - `script`: string - OpenType script tag (default: "DFLT")
+///   - `language`: string - OpenType language system tag (default: the script's default)
+///
+/// # Returns
+/// * `JsonValue` - '{"glyphs": ["f_i", "a.ss01"], "steps": [{"lookup": 4, "features": ["liga"], "glyphs": ["f_i", "a"]}], "missingFeatures": ["smcp"]}'
+pub fn apply_features(
+    font_bytes: &[u8],
+    glyph_names: &[String],
+    features_json: &str,
+    options_json: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let tag = |key: &str, default: &str| -> Result<Tag, ApiError> {
+        let value = options.get(key).and_then(|v| v.as_str()).unwrap_or(default);
+        Tag::from_str(value).map_err(|_| ApiError::invalid_argument(&format!("{} tag", key), value))
+    };
+    let script_tag = tag("script", "DFLT")?;
+    let language_tag = options.get("language").and_then(|v| v.as_str()).map(|_| tag("language", "dflt")).transpose()?;
+    let settings = parse_feature_settings(features_json)?;
+
+    let font = FontRef::new(font_bytes).map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+    let mut buffer: Vec<GlyphId16> = glyph_names
+        .iter()
+        .map(|name| {
+            glyph_order
+                .iter()
+                .position(|candidate| candidate == name)
+                .map(|gid| GlyphId16::new(gid as u16))
+                .ok_or_else(|| ApiError::glyph_not_found(name))
+        })
+        .collect::<Result<_, _>>()?;
+    let names = |buffer: &[GlyphId16]| -> Vec<String> {
+        buffer
+            .iter()
+            .map(|gid| glyph_order.get(gid.to_u16() as usize).cloned().unwrap_or_else(|| format!("gid{}", gid.to_u16())))
+            .collect()
+    };
+
+    let Ok(gsub) = font.gsub() else {
+        let missing: Vec<&String> = settings.iter().filter(|(_, value)| *value > 0).map(|(tag, _)| tag).collect();
+        return Ok(serde_json::json!({ "glyphs": names(&buffer), "steps": [], "missingFeatures": missing }));
+    };
+    let script_list = gsub.script_list().map_err(|e| ApiError::missing_table("GSUB", e))?;
+    let feature_list = gsub.feature_list().map_err(|e| ApiError::missing_table("GSUB", e))?;
+    let lookup_list = gsub.lookup_list().map_err(|e| ApiError::missing_table("GSUB", e))?;
+
+    // Feature indices of the language system, with the required feature first
+    let script = script_list
+        .script_records()
+        .iter()
+        .find(|record| record.script_tag() == script_tag)
+        .or_else(|| script_list.script_records().iter().find(|record| record.script_tag() == Tag::new(b"DFLT")))
+        .and_then(|record| record.script(script_list.offset_data()).ok());
+    let lang_sys = script.as_ref().and_then(|script| {
+        let specific = language_tag.and_then(|language_tag| {
+            script
+                .lang_sys_records()
+                .iter()
+                .find(|record| record.lang_sys_tag() == language_tag)
+                .and_then(|record| record.lang_sys(script.offset_data()).ok())
+        });
+        specific.or_else(|| script.default_lang_sys().and_then(|lang_sys| lang_sys.ok()))
+    });
+    let mut feature_indices: Vec<u16> = Vec::new();
+    if let Some(lang_sys) = &lang_sys {
+        if lang_sys.required_feature_index() != 0xFFFF {
+            feature_indices.push(lang_sys.required_feature_index());
+        }
+        feature_indices.extend(lang_sys.feature_indices().iter().map(|index| index.get()));
+    }
+
+    // Lookups of the enabled features, with the setting of the first feature using them
+    let mut lookups: BTreeMap<u16, (u32, Vec<String>)> = BTreeMap::new();
+    let mut found = Vec::new();
+    for index in feature_indices {
+        let Some(record) = feature_list.feature_records().get(index as usize) else { continue };
+        let feature_tag = record.feature_tag().to_string();
+        let Some(&(_, value)) = settings.iter().find(|(tag, _)| *tag == feature_tag) else { continue };
+        found.push(feature_tag.clone());
+        if value == 0 {
+            continue;
+        }
+        let Ok(feature) = record.feature(feature_list.offset_data()) else { continue };
+        for lookup_index in feature.lookup_list_indices().iter() {
+            let entry = lookups.entry(lookup_index.get()).or_insert((value, Vec::new()));
+            if !entry.1.contains(&feature_tag) {
+                entry.1.push(feature_tag.clone());
+            }
+        }
+    }
+    let missing: Vec<&String> = settings
+        .iter()
+        .filter(|(tag, value)| *value > 0 && !found.contains(tag))
+        .map(|(tag, _)| tag)
+        .collect();
+
+    let applier = Applier { lookups: lookup_list, gdef: font.gdef().ok() };
+    let mut steps = Vec::new();
+    for (lookup_index, (value, features)) in lookups {
+        if applier.apply_lookup(lookup_index, &mut buffer, value) {
+            steps.push(serde_json::json!({
+                "lookup": lookup_index,
+                "features": features,
+                "glyphs": names(&buffer),
+            }));
+        }
+    }
+
+    Ok(serde_json::json!({
+        "glyphs": names(&buffer),
+        "steps": steps,
+        "missingFeatures": missing,
+    }))
+}
@@ -2,48 +2,64 @@
 //
 // This module provides functions for interpolating glyphs at specific locations
 // in the design space, with special handling for glyphs containing components.
-// Optimized with per-request caching for batch operations.
+// Optimized with per-request caching for batch operations, and with
+// interpolation contexts that keep a location's layers across calls.
 
 use babelfont::{Layer, Shape};
-use fontdrasil::coords::{DesignCoord, DesignLocation, UserCoord};
+use fontdrasil::coords::{DesignCoord, DesignLocation, NormalizedCoord, NormalizedLocation, UserCoord};
+use fontdrasil::variations::{RoundingBehaviour, VariationModel};
 use serde_json::Value as JsonValue;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 use wasm_bindgen::prelude::*;
 use write_fonts::types::Tag;
 
-/// Interpolate a glyph at a specific location in design space
-///
-/// # Arguments
-/// * `font` - Reference to the font
-/// * `glyph_name` - Name of the glyph to interpolate
-/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0, "wdth": 100.0}'
+use crate::errors::ApiError;
+use crate::glyph_outlines::LayerSource;
+use crate::logging;
+
+// Interpolation contexts created by create_interpolation_context(), by handle
+static INTERPOLATION_CONTEXTS: Mutex<Option<HashMap<u32, InterpolationContext>>> = Mutex::new(None);
+static NEXT_CONTEXT_HANDLE: AtomicU32 = AtomicU32::new(1);
+
+/// A location prepared for repeated glyph interpolation
 ///
-/// # Returns
-/// * `String` - JSON representation of the interpolated Layer
-pub fn interpolate_glyph(
-    font: &babelfont::Font,
-    glyph_name: &str,
-    location_json: &str,
-) -> Result<String, JsValue> {
-    // Parse location from JSON (user space coordinates)
-    let location_map: HashMap<String, f64> = serde_json::from_str(location_json)
-        .map_err(|e| JsValue::from_str(&format!("Location parse error: {}", e)))?;
+/// Keeps the converted design location and every layer interpolated at it,
+/// so later glyphs sharing components skip their interpolation. A context
+/// belongs to the font it was created for.
+struct InterpolationContext {
+    /// Handle of the font the layers are interpolated from
+    font_id: u32,
+    /// Location as given, in user space
+    location_map: HashMap<String, f64>,
+    location: DesignLocation,
+    layers: HashMap<String, Arc<Layer>>,
+}
 
-    // Convert user space to design space using axis mappings
-    let design_location: DesignLocation = location_map
+/// Convert a user space location to design space using the axis mappings
+fn user_to_design_location(
+    font: &babelfont::Font,
+    location_map: &HashMap<String, f64>,
+) -> Result<DesignLocation, JsValue> {
+    Ok(location_map
         .iter()
         .map(|(tag_str, user_value)| {
             let tag = Tag::from_str(tag_str)
-                .map_err(|e| JsValue::from_str(&format!("Invalid tag '{}': {}", tag_str, e)))?;
+                .map_err(|_| ApiError::invalid_argument("axis tag", tag_str))?;
 
             // Find the axis and convert user space to design space
             let design_value = if let Some(axis) = font.axes.iter().find(|a| a.tag == tag) {
                 match axis.userspace_to_designspace(UserCoord::new(*user_value)) {
                     Ok(design_coord) => design_coord,
                     Err(e) => {
-                        web_sys::console::warn_1(&format!("[Rust] Warning: Could not convert user space value {} for axis {}: {:?}. Using value as-is.", user_value, tag_str, e).into());
+                        logging::warn(
+                            "interpolation",
+                            &format!("Could not convert user space value {} for axis {}: {:?}. Using value as-is.", user_value, tag_str, e),
+                            serde_json::json!({ "axis": tag_str, "value": user_value }),
+                        );
                         DesignCoord::new(*user_value)
                     }
                 }
@@ -56,13 +72,47 @@ pub fn interpolate_glyph(
         })
         .collect::<Result<Vec<_>, JsValue>>()?
         .into_iter()
-        .collect();
+        .collect())
+}
+
+/// Interpolate a glyph at a specific location in design space
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_name` - Name of the glyph to interpolate
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0, "wdth": 100.0}'
+/// * `include_background` - Add the glyph's background at the location as `background` (null if there is none)
+///
+/// # Returns
+/// * `String` - JSON representation of the interpolated Layer
+pub fn interpolate_glyph(
+    font: &babelfont::Font,
+    glyph_name: &str,
+    location_json: &str,
+    include_background: bool,
+) -> Result<String, JsValue> {
+    // Parse location from JSON (user space coordinates)
+    let location_map: HashMap<String, f64> = serde_json::from_str(location_json)
+        .map_err(ApiError::invalid_location)?;
+    let design_location = user_to_design_location(font, &location_map)?;
+
+    interpolate_with_layers(glyph_name, &location_map, &LayerSource::new(font, &design_location), include_background)
+}
+
+/// Interpolate a glyph and serialize it with its component layers
+fn interpolate_with_layers(
+    glyph_name: &str,
+    location_map: &HashMap<String, f64>,
+    layers: &LayerSource,
+    include_background: bool,
+) -> Result<String, JsValue> {
+    let font = layers.font();
 
     // Get the glyph to check if it has components
     let glyph = font
         .glyphs
         .get(glyph_name)
-        .ok_or_else(|| JsValue::from_str(&format!("Glyph '{}' not found", glyph_name)))?;
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
 
     // Check if any master layer has components
     let has_components = glyph.layers.iter().any(|layer| {
@@ -74,75 +124,370 @@ pub fn interpolate_glyph(
 
     let interpolated_layer = if has_components {
         // For glyphs with components, manually interpolate to preserve component transforms
-        manually_interpolate_layer(font, glyph, &design_location)
-            .map_err(|e| JsValue::from_str(&format!("Manual interpolation failed: {}", e)))?
+        Arc::new(
+            manually_interpolate_layer(font, glyph, layers.location())
+                .map_err(|e| ApiError::interpolation(glyph_name, e))?,
+        )
     } else {
         // For glyphs without components, use babelfont's fast interpolation
-        font.interpolate_glyph(glyph_name, &design_location)
-            .map_err(|e| JsValue::from_str(&format!("Interpolation failed: {:?}", e)))?
+        layers.layer(glyph_name)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?
     };
 
     // Serialize to JSON and recursively add component layer data
-    let layer_json_with_components =
-        serialize_layer_with_components(&interpolated_layer, font, &design_location)
-            .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+    let layer_json_with_components = serialize_layer_with_components(&interpolated_layer, layers)
+        .map_err(|e| ApiError::serialization("layer", e))?;
 
     // Parse the layer JSON to add location data
     let mut result: serde_json::Value = serde_json::from_str(&layer_json_with_components)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse layer JSON: {}", e)))?;
+        .map_err(|e| ApiError::invalid_json("layer JSON", e))?;
 
     // Add the location (user space) to the result
     if let Some(obj) = result.as_object_mut() {
         obj.insert(
             "_interpolationLocation".to_string(),
-            serde_json::to_value(&location_map)
-                .map_err(|e| JsValue::from_str(&format!("Failed to serialize location: {}", e)))?,
+            serde_json::to_value(location_map)
+                .map_err(|e| ApiError::serialization("location", e))?,
         );
+        if include_background {
+            let background = crate::backgrounds::interpolated_background(font, glyph, layers.location());
+            obj.insert(
+                "background".to_string(),
+                serde_json::to_value(&background)
+                    .map_err(|e| ApiError::serialization("background", e))?,
+            );
+        }
     }
 
     // Serialize back to string
     let result_json = serde_json::to_string(&result)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))?;
+        .map_err(|e| ApiError::serialization("result", e))?;
 
     Ok(result_json)
 }
 
+/// Interpolate a glyph at a location and add the result to it as an intermediate layer
+///
+/// Axes missing from the location are taken at their default. The layer is
+/// named in brace style ("{550, 100}", design coordinates in axis order)
+/// and associated with the nearest master. Glyphs with components keep
+/// their component transforms, as in interpolate_glyph().
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Name of the glyph
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'
+///
+/// # Returns
+/// * `Layer` - The added layer
+pub fn add_intermediate_layer(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    location_json: &str,
+) -> Result<Layer, JsValue> {
+    let mut location_map: HashMap<String, f64> = serde_json::from_str(location_json)
+        .map_err(ApiError::invalid_location)?;
+    for axis in font.axes.iter() {
+        let tag = axis.tag.to_string();
+        if !location_map.contains_key(&tag) {
+            let default = axis.default
+                .ok_or_else(|| ApiError::invalid_location(format!("axis '{}' has no default; give it in the location", tag)))?;
+            location_map.insert(tag, default.to_f64());
+        }
+    }
+    let location = user_to_design_location(font, &location_map)?;
+    let coordinate = |location: &DesignLocation, tag: &Tag| {
+        location.iter().find(|(candidate, _)| *candidate == tag).map(|(_, coord)| coord.to_f64())
+    };
+    let same_location = |other: &DesignLocation| {
+        font.axes.iter().all(|axis| match (coordinate(other, &axis.tag), coordinate(&location, &axis.tag)) {
+            (Some(a), Some(b)) => (a - b).abs() < 1e-6,
+            _ => false,
+        })
+    };
+
+    let glyph = font
+        .glyphs
+        .get(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let occupied = glyph.layers.iter().filter(|layer| !layer.is_background).any(|layer| {
+        layer
+            .location
+            .clone()
+            .or_else(|| crate::glyph_outlines::layer_location(font, layer))
+            .is_some_and(|other| same_location(&other))
+    });
+    if occupied {
+        return Err(ApiError::LayerExists { glyph: glyph_name.to_string() }.into());
+    }
+
+    let has_components = glyph.layers.iter().any(|layer| {
+        layer.shapes.iter().any(|shape| matches!(shape, Shape::Component(_)))
+    });
+    let interpolated = if has_components {
+        manually_interpolate_layer(font, glyph, &location)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?
+    } else {
+        let layer = LayerSource::new(font, &location).layer(glyph_name)
+            .map_err(|e| ApiError::interpolation(glyph_name, e))?;
+        (*layer).clone()
+    };
+
+    // Associate the layer with the nearest master
+    let distance = |master: &babelfont::Master| -> f64 {
+        font.axes
+            .iter()
+            .map(|axis| {
+                let master_value = coordinate(&master.location, &axis.tag).unwrap_or_default();
+                let value = coordinate(&location, &axis.tag).unwrap_or_default();
+                (master_value - value).powi(2)
+            })
+            .sum()
+    };
+    let nearest = font
+        .masters
+        .iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .ok_or_else(|| ApiError::OperationFailed { message: "The font has no masters".to_string() })?
+        .id
+        .clone();
+
+    let values: Vec<String> = font
+        .axes
+        .iter()
+        .map(|axis| coordinate(&location, &axis.tag).unwrap_or_default().to_string())
+        .collect();
+    let mut number = 1;
+    while glyph.layers.iter().any(|layer| layer.id.as_deref() == Some(format!("intermediate-{}", number).as_str())) {
+        number += 1;
+    }
+
+    let mut layer = interpolated;
+    layer.id = Some(format!("intermediate-{}", number));
+    layer.name = Some(format!("{{{}}}", values.join(", ")).into());
+    layer.guides = Vec::new();
+    layer.location = Some(location);
+    layer.is_background = false;
+    layer.background_layer_id = None;
+    layer.layer_index = None;
+    layer.master = babelfont::LayerType::AssociatedWithMaster(nearest);
+    layer.format_specific = Default::default();
+
+    if let Some(glyph) = font.glyphs.get_mut(glyph_name) {
+        glyph.layers.push(layer.clone());
+    }
+    Ok(layer)
+}
+
+/// Create an interpolation context for a location
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `font_id` - Handle of the font
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'
+///
+/// # Returns
+/// * `u32` - Handle of the context
+pub fn create_interpolation_context(font: &babelfont::Font, font_id: u32, location_json: &str) -> Result<u32, JsValue> {
+    let location_map: HashMap<String, f64> = serde_json::from_str(location_json)
+        .map_err(ApiError::invalid_location)?;
+    let location = user_to_design_location(font, &location_map)?;
+
+    let handle = NEXT_CONTEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    contexts.get_or_insert_with(HashMap::new).insert(
+        handle,
+        InterpolationContext {
+            font_id,
+            location_map,
+            location,
+            layers: HashMap::new(),
+        },
+    );
+    Ok(handle)
+}
+
+/// Interpolate a glyph at the location of an interpolation context
+///
+/// Returns the same JSON as interpolate_glyph(); layers interpolated on the
+/// way (the glyph and its components) are kept in the context. The font must
+/// be the one the context was created for.
+pub fn interpolate_with_context(font: &babelfont::Font, font_id: u32, handle: u32, glyph_name: &str) -> Result<String, JsValue> {
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    let context = contexts
+        .as_mut()
+        .and_then(|contexts| contexts.get_mut(&handle))
+        .ok_or(ApiError::UnknownHandle { kind: "interpolation context", handle })?;
+    if context.font_id != font_id {
+        return Err(ApiError::invalid_argument("font handle", font_id).into());
+    }
+
+    let layers = LayerSource::with_layers(font, &context.location, std::mem::take(&mut context.layers));
+    let result = interpolate_with_layers(glyph_name, &context.location_map, &layers, false);
+    context.layers = layers.into_layers();
+    result
+}
+
+/// Handle of the font an interpolation context was created for
+pub fn context_font(handle: u32) -> Option<u32> {
+    let contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    contexts
+        .as_ref()
+        .and_then(|contexts| contexts.get(&handle))
+        .map(|context| context.font_id)
+}
+
+/// Release an interpolation context
+///
+/// # Returns
+/// * `bool` - Whether the handle referred to a context
+pub fn release_interpolation_context(handle: u32) -> bool {
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    contexts
+        .as_mut()
+        .is_some_and(|contexts| contexts.remove(&handle).is_some())
+}
+
+/// Release the interpolation contexts of a font (call when the font is closed)
+pub fn release_font_contexts(font_id: u32) {
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    if let Some(contexts) = contexts.as_mut() {
+        contexts.retain(|_, context| context.font_id != font_id);
+    }
+}
+
+/// Drop the layers of the interpolation contexts of a font (call when the font changes)
+///
+/// Contexts stay valid and interpolate again on their next use.
+pub fn clear_context_layers(font_id: u32) {
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    for context in contexts.iter_mut().flat_map(|contexts| contexts.values_mut()) {
+        if context.font_id == font_id {
+            context.layers.clear();
+        }
+    }
+}
+
+/// Drop edited glyphs from the layers of the interpolation contexts of a font
+pub fn forget_context_glyphs(font_id: u32, glyph_names: &HashSet<String>) {
+    let mut contexts = INTERPOLATION_CONTEXTS.lock().unwrap();
+    for context in contexts.iter_mut().flat_map(|contexts| contexts.values_mut()) {
+        if context.font_id == font_id {
+            context.layers.retain(|name, _| !glyph_names.contains(name));
+        }
+    }
+}
+
+/// Normalized location of a design location
+///
+/// Axes missing from the location are at their default; coordinates are
+/// clamped to the axis range, as variation models do not extrapolate.
+fn normalized_location(font: &babelfont::Font, location: &DesignLocation) -> Result<NormalizedLocation, String> {
+    font.axes
+        .iter()
+        .map(|axis| {
+            let to_design = |value: Option<UserCoord>| {
+                value
+                    .and_then(|value| axis.userspace_to_designspace(value).ok())
+                    .map(|coord| coord.to_f64())
+                    .ok_or_else(|| format!("Axis '{}' lacks a minimum, default or maximum", axis.tag))
+            };
+            let (min, default, max) = (to_design(axis.min)?, to_design(axis.default)?, to_design(axis.max)?);
+            let value = location.get(axis.tag).map_or(default, |coord| coord.to_f64());
+            let normalized = if value < default && default > min {
+                (value - default) / (default - min)
+            } else if value > default && max > default {
+                (value - default) / (max - default)
+            } else {
+                0.0
+            };
+            Ok((axis.tag, NormalizedCoord::new(normalized.clamp(-1.0, 1.0))))
+        })
+        .collect()
+}
+
+/// Weights of per-master values at a design location
+///
+/// Builds a variation model (as fontc and fontTools do) over the normalized
+/// locations of the given masters, so any per-master quantity interpolates
+/// across all axes as the sum of its master values times these weights. At
+/// a master's location its own weight is 1 and the others are 0. Of masters
+/// sharing a location, the first one is used.
+///
+/// # Returns
+/// * `Vec<f64>` - Weight of each master, in the order of `master_ids`
+pub(crate) fn master_weights(
+    font: &babelfont::Font,
+    master_ids: &[&str],
+    location: &DesignLocation,
+) -> Result<Vec<f64>, String> {
+    let locations: Vec<NormalizedLocation> = master_ids
+        .iter()
+        .map(|master_id| {
+            let master = font
+                .masters
+                .iter()
+                .find(|master| master.id == *master_id)
+                .ok_or_else(|| format!("Master '{}' not found", master_id))?;
+            normalized_location(font, &master.location)
+        })
+        .collect::<Result<_, _>>()?;
+    if !locations.iter().any(|location| location.is_default()) {
+        return Err("No master at the default location".to_string());
+    }
+
+    // Interpolating a unit vector per master yields the weight of each master
+    let mut unit_vectors: HashMap<NormalizedLocation, Vec<f64>> = HashMap::new();
+    for (index, master_location) in locations.iter().enumerate() {
+        unit_vectors.entry(master_location.clone()).or_insert_with(|| {
+            let mut unit = vec![0.0; master_ids.len()];
+            unit[index] = 1.0;
+            unit
+        });
+    }
+    let axis_order: Vec<Tag> = font.axes.iter().map(|axis| axis.tag).collect();
+    let model = VariationModel::new(locations.into_iter().collect(), axis_order);
+    let deltas = model
+        .deltas_with_rounding(&unit_vectors, RoundingBehaviour::None)
+        .map_err(|e| format!("Failed to build the variation model: {}", e))?;
+    let weights = model.interpolate_from_deltas(&normalized_location(font, location)?, &deltas);
+    Ok(if weights.is_empty() { vec![0.0; master_ids.len()] } else { weights })
+}
+
 /// Manually interpolate a layer that contains components, preserving their transforms
-fn manually_interpolate_layer(
+///
+/// Shapes, component transforms, anchors and the width are interpolated
+/// across all axes from the master layers of the glyph.
+pub(crate) fn manually_interpolate_layer(
     font: &babelfont::Font,
     glyph: &babelfont::Glyph,
     target_location: &DesignLocation,
 ) -> Result<Layer, String> {
-    // Get master layers with their locations by matching layer IDs to master IDs
-    let masters: Vec<(&Layer, f64)> = glyph
+    // Get master layers by matching layer IDs to master IDs
+    let master_layers: Vec<(&Layer, &str)> = glyph
         .layers
         .iter()
+        .filter(|layer| !layer.is_background)
         .filter_map(|layer| {
-            // Find the master that this layer belongs to
             font.masters
                 .iter()
                 .find(|m| Some(&m.id) == layer.id.as_ref())
-                .and_then(|master| {
-                    // Get the location value for the first axis
-                    master
-                        .location
-                        .iter()
-                        .next()
-                        .map(|(_, coord)| (layer, coord.to_f64()))
-                })
+                .map(|master| (layer, master.id.as_str()))
         })
         .collect();
 
-    if masters.is_empty() {
+    if master_layers.is_empty() {
         return Err("No master layers found with locations".to_string());
     }
 
-    // Get target value for first axis
-    let target_value = target_location
+    // Weight of each master layer at the target location
+    let master_ids: Vec<&str> = master_layers.iter().map(|(_, master_id)| *master_id).collect();
+    let weights = master_weights(font, &master_ids, target_location)?;
+    let masters: Vec<(&Layer, f64)> = master_layers
         .iter()
-        .next()
-        .map(|(_, coord)| coord.to_f64())
-        .ok_or("No axis in target location")?;
+        .zip(weights)
+        .map(|((layer, _), weight)| (*layer, weight))
+        .collect();
 
     // Use the first master as template for structure
     let reference_layer = masters[0].0;
@@ -155,10 +500,10 @@ fn manually_interpolate_layer(
                 // Collect transforms from all masters for this component
                 let master_transforms: Vec<(kurbo::Affine, f64)> = masters
                     .iter()
-                    .filter_map(|(layer, loc_value)| {
+                    .filter_map(|(layer, weight)| {
                         layer.shapes.get(shape_idx).and_then(|s| {
                             if let Shape::Component(comp) = s {
-                                Some((comp.transform, *loc_value))
+                                Some((comp.transform, *weight))
                             } else {
                                 None
                             }
@@ -166,11 +511,9 @@ fn manually_interpolate_layer(
                     })
                     .collect();
 
-                // Interpolate the transform
-                let interpolated_transform = if master_transforms.len() >= 2 {
-                    interpolate_affine(&master_transforms, target_value)?
-                } else if !master_transforms.is_empty() {
-                    master_transforms[0].0
+                // Interpolate the transform if every master has the component
+                let interpolated_transform = if master_transforms.len() == masters.len() {
+                    interpolate_affine(&master_transforms)?
                 } else {
                     ref_comp.transform
                 };
@@ -186,29 +529,25 @@ fn manually_interpolate_layer(
                 // Collect paths from all masters for this shape
                 let master_paths: Vec<(&Shape, f64)> = masters
                     .iter()
-                    .filter_map(|(layer, loc_value)| {
-                        layer.shapes.get(shape_idx).map(|s| (s, *loc_value))
+                    .filter_map(|(layer, weight)| {
+                        layer.shapes.get(shape_idx).map(|s| (s, *weight))
                     })
                     .collect();
 
-                if master_paths.len() >= 2 {
+                if master_paths.len() == masters.len() && masters.len() >= 2 {
                     // Interpolate the path nodes
-                    let interpolated_path = interpolate_path_shape(&master_paths, target_value)?;
+                    let interpolated_path = interpolate_path_shape(&master_paths)?;
                     interpolated_shapes.push(interpolated_path);
                 } else {
-                    // If only one master or error, use reference
+                    // If only one master or a missing shape, use reference
                     interpolated_shapes.push(reference_shape.clone());
                 }
             }
-            _ => {
-                interpolated_shapes.push(reference_shape.clone());
-            }
         }
     }
 
     // Interpolate width
-    let width =
-        interpolate_scalar_values(&masters, target_value, |layer| layer.width as f64)? as f32;
+    let width = interpolate_scalar_values(&masters, |layer| layer.width as f64)? as f32;
 
     // Interpolate anchors
     let mut interpolated_anchors = Vec::new();
@@ -216,28 +555,28 @@ fn manually_interpolate_layer(
         // Collect x, y values for this anchor from all masters
         let x_values: Vec<(f64, f64)> = masters
             .iter()
-            .filter_map(|(layer, loc_value)| {
+            .filter_map(|(layer, weight)| {
                 layer
                     .anchors
                     .get(anchor_idx)
-                    .map(|anchor| (anchor.x as f64, *loc_value))
+                    .map(|anchor| (anchor.x as f64, *weight))
             })
             .collect();
 
         let y_values: Vec<(f64, f64)> = masters
             .iter()
-            .filter_map(|(layer, loc_value)| {
+            .filter_map(|(layer, weight)| {
                 layer
                     .anchors
                     .get(anchor_idx)
-                    .map(|anchor| (anchor.y as f64, *loc_value))
+                    .map(|anchor| (anchor.y as f64, *weight))
             })
             .collect();
 
         // Only interpolate if we have matching anchors in all masters
         if x_values.len() == masters.len() && y_values.len() == masters.len() {
-            let interp_x = interpolate_values(&x_values, target_value)?;
-            let interp_y = interpolate_values(&y_values, target_value)?;
+            let interp_x = interpolate_values(&x_values)?;
+            let interp_y = interpolate_values(&y_values)?;
 
             interpolated_anchors.push(babelfont::Anchor {
                 name: reference_anchor.name.clone(),
@@ -268,49 +607,49 @@ fn manually_interpolate_layer(
     })
 }
 
-/// Interpolate a Path shape across masters
-fn interpolate_path_shape(master_paths: &[(&Shape, f64)], target_value: f64) -> Result<Shape, String> {
+/// Interpolate a Path shape across weighted masters
+fn interpolate_path_shape(master_paths: &[(&Shape, f64)]) -> Result<Shape, String> {
     // Extract the Path from each shape
-    let paths_with_locations: Vec<(&babelfont::Path, f64)> = master_paths
+    let paths_with_weights: Vec<(&babelfont::Path, f64)> = master_paths
         .iter()
-        .filter_map(|(shape, loc)| {
+        .filter_map(|(shape, weight)| {
             if let Shape::Path(path) = shape {
-                Some((path, *loc))
+                Some((path, *weight))
             } else {
                 None
             }
         })
         .collect();
 
-    if paths_with_locations.len() < 2 {
-        return Err("Need at least 2 master paths to interpolate".to_string());
+    if paths_with_weights.len() != master_paths.len() {
+        return Err("Shape is a path in some masters only".to_string());
     }
 
-    let reference_path = paths_with_locations[0].0;
+    let reference_path = paths_with_weights[0].0;
     let node_count = reference_path.nodes.len();
 
     // Interpolate each node
     let mut interpolated_nodes = Vec::with_capacity(node_count);
     for node_idx in 0..node_count {
         // Collect x, y values for this node from all masters
-        let x_values: Vec<(f64, f64)> = paths_with_locations
+        let x_values: Vec<(f64, f64)> = paths_with_weights
             .iter()
-            .filter_map(|(path, loc)| path.nodes.get(node_idx).map(|node| (node.x as f64, *loc)))
+            .filter_map(|(path, weight)| path.nodes.get(node_idx).map(|node| (node.x as f64, *weight)))
             .collect();
 
-        let y_values: Vec<(f64, f64)> = paths_with_locations
+        let y_values: Vec<(f64, f64)> = paths_with_weights
             .iter()
-            .filter_map(|(path, loc)| path.nodes.get(node_idx).map(|node| (node.y as f64, *loc)))
+            .filter_map(|(path, weight)| path.nodes.get(node_idx).map(|node| (node.y as f64, *weight)))
             .collect();
 
-        if x_values.len() != paths_with_locations.len()
-            || y_values.len() != paths_with_locations.len()
+        if x_values.len() != paths_with_weights.len()
+            || y_values.len() != paths_with_weights.len()
         {
             return Err(format!("Node count mismatch at index {}", node_idx));
         }
 
-        let interp_x = interpolate_values(&x_values, target_value)?;
-        let interp_y = interpolate_values(&y_values, target_value)?;
+        let interp_x = interpolate_values(&x_values)?;
+        let interp_y = interpolate_values(&y_values)?;
 
         let reference_node = &reference_path.nodes[node_idx];
         interpolated_nodes.push(babelfont::Node {
@@ -328,108 +667,58 @@ fn interpolate_path_shape(master_paths: &[(&Shape, f64)], target_value: f64) ->
     }))
 }
 
-/// Interpolate a scalar value across masters
+/// Interpolate a scalar value across weighted masters
 fn interpolate_scalar_values(
     masters: &[(&Layer, f64)],
-    target_value: f64,
     extract_value: impl Fn(&Layer) -> f64,
 ) -> Result<f64, String> {
-    if masters.is_empty() {
-        return Err("No masters to interpolate".to_string());
-    }
-
     let values: Vec<(f64, f64)> = masters
         .iter()
-        .map(|(layer, loc)| (extract_value(layer), *loc))
+        .map(|(layer, weight)| (extract_value(layer), *weight))
         .collect();
 
-    interpolate_values(&values, target_value)
+    interpolate_values(&values)
 }
 
-/// Simple linear interpolation between values
-fn interpolate_values(values: &[(f64, f64)], target_value: f64) -> Result<f64, String> {
+/// Weighted sum of master values, with weights from master_weights()
+fn interpolate_values(values: &[(f64, f64)]) -> Result<f64, String> {
     if values.is_empty() {
         return Err("No values to interpolate".to_string());
     }
 
-    if values.len() == 1 {
-        return Ok(values[0].0);
-    }
-
-    // Sort by location
-    let mut sorted = values.to_vec();
-    sorted.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-
-    // Find bracketing values
-    let mut lower = &sorted[0];
-    let mut upper = &sorted[sorted.len() - 1];
-
-    for i in 0..sorted.len() - 1 {
-        if sorted[i].1 <= target_value && sorted[i + 1].1 >= target_value {
-            lower = &sorted[i];
-            upper = &sorted[i + 1];
-            break;
-        }
-    }
-
-    // Linear interpolation
-    if (upper.1 - lower.1).abs() < 1e-10 {
-        return Ok(lower.0);
-    }
+    Ok(values.iter().map(|(value, weight)| value * weight).sum())
+}
 
-    let t = (target_value - lower.1) / (upper.1 - lower.1);
-    Ok(lower.0 + t * (upper.0 - lower.0))
+/// Interpolate a per-master value at a design location
+///
+/// At a master's location the master's value is used as is. Elsewhere the
+/// values are interpolated across all axes, like the shapes in
+/// manually_interpolate_layer(). Masters without a value are left out.
+pub(crate) fn interpolate_master_values(
+    font: &babelfont::Font,
+    values: &[(&str, f64)],
+    location: &DesignLocation,
+) -> Result<f64, String> {
+    let master_ids: Vec<&str> = values.iter().map(|(master_id, _)| *master_id).collect();
+    let weights = master_weights(font, &master_ids, location)?;
+    let weighted: Vec<(f64, f64)> = values.iter().zip(weights).map(|((_, value), weight)| (*value, weight)).collect();
+    interpolate_values(&weighted)
 }
 
-/// Interpolate an Affine transform
-fn interpolate_affine(
-    master_transforms: &[(kurbo::Affine, f64)],
-    target_value: f64,
-) -> Result<kurbo::Affine, String> {
+/// Interpolate an Affine transform across weighted masters
+fn interpolate_affine(master_transforms: &[(kurbo::Affine, f64)]) -> Result<kurbo::Affine, String> {
     if master_transforms.is_empty() {
         return Err("No transforms to interpolate".to_string());
     }
 
-    if master_transforms.len() == 1 {
-        return Ok(master_transforms[0].0);
-    }
-
-    // Sort by location value
-    let mut sorted = master_transforms.to_vec();
-    sorted.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-
-    // Find bracketing masters
-    let mut lower = &sorted[0];
-    let mut upper = &sorted[sorted.len() - 1];
-
-    for i in 0..sorted.len() - 1 {
-        if sorted[i].1 <= target_value && sorted[i + 1].1 >= target_value {
-            lower = &sorted[i];
-            upper = &sorted[i + 1];
-            break;
+    // Interpolate each coefficient (a, b, c, d, tx, ty)
+    let mut interpolated_coeffs = [0.0; 6];
+    for (transform, weight) in master_transforms {
+        for (interpolated, coefficient) in interpolated_coeffs.iter_mut().zip(transform.as_coeffs()) {
+            *interpolated += coefficient * weight;
         }
     }
 
-    // Linear interpolation factor
-    let t = if (upper.1 - lower.1).abs() < 1e-10 {
-        0.0
-    } else {
-        (target_value - lower.1) / (upper.1 - lower.1)
-    };
-
-    // Interpolate each coefficient
-    let lower_coeffs = lower.0.as_coeffs();
-    let upper_coeffs = upper.0.as_coeffs();
-
-    let interpolated_coeffs = [
-        lower_coeffs[0] + t * (upper_coeffs[0] - lower_coeffs[0]), // a
-        lower_coeffs[1] + t * (upper_coeffs[1] - lower_coeffs[1]), // b
-        lower_coeffs[2] + t * (upper_coeffs[2] - lower_coeffs[2]), // c
-        lower_coeffs[3] + t * (upper_coeffs[3] - lower_coeffs[3]), // d
-        lower_coeffs[4] + t * (upper_coeffs[4] - lower_coeffs[4]), // tx
-        lower_coeffs[5] + t * (upper_coeffs[5] - lower_coeffs[5]), // ty
-    ];
-
     Ok(kurbo::Affine::new(interpolated_coeffs))
 }
 
@@ -438,35 +727,30 @@ fn interpolate_affine(
 /// includes its interpolated layer data in a `layerData` field
 pub fn serialize_layer_with_components(
     layer: &Layer,
-    font: &babelfont::Font,
-    location: &DesignLocation,
+    layers: &LayerSource,
 ) -> Result<String, String> {
     // Track visited glyphs to prevent infinite recursion
     let mut visited = HashSet::new();
-    serialize_layer_recursive(layer, font, location, &mut visited)
+    serialize_layer_recursive(layer, layers, &mut visited)
 }
 
 /// Serialize a layer with cached interpolation - for batch operations
 /// Uses shared caches for interpolated layers and serialized JSON to avoid redundant work
 pub fn serialize_layer_with_components_cached(
     layer: &Layer,
-    font: &babelfont::Font,
-    location: &DesignLocation,
-    layer_cache: &RefCell<HashMap<String, Layer>>,
+    layers: &LayerSource,
     json_cache: &RefCell<HashMap<String, JsonValue>>,
 ) -> Result<JsonValue, String> {
     // Track visited glyphs to prevent infinite recursion in this call
     let mut visited = HashSet::new();
-    serialize_layer_recursive_cached(layer, font, location, &mut visited, layer_cache, json_cache)
+    serialize_layer_recursive_cached(layer, &mut visited, layers, json_cache)
 }
 
 /// Recursive helper with caching
 fn serialize_layer_recursive_cached(
     layer: &Layer,
-    font: &babelfont::Font,
-    location: &DesignLocation,
     visited: &mut HashSet<String>,
-    layer_cache: &RefCell<HashMap<String, Layer>>,
+    layers: &LayerSource,
     json_cache: &RefCell<HashMap<String, JsonValue>>,
 ) -> Result<JsonValue, String> {
     // First serialize the layer to JSON
@@ -506,32 +790,19 @@ fn serialize_layer_recursive_cached(
                         visited.insert(reference.clone());
 
                         // Get interpolated layer from cache or interpolate
-                        let component_layer = {
-                            let cache = layer_cache.borrow();
-                            if let Some(cached) = cache.get(&reference) {
-                                cached.clone()
-                            } else {
-                                drop(cache);
-                                match font.interpolate_glyph(&reference, location) {
-                                    Ok(interpolated) => {
-                                        layer_cache.borrow_mut().insert(reference.clone(), interpolated.clone());
-                                        interpolated
-                                    }
-                                    Err(_) => {
-                                        visited.remove(&reference);
-                                        continue;
-                                    }
-                                }
+                        let component_layer = match layers.layer(&reference) {
+                            Ok(layer) => layer,
+                            Err(_) => {
+                                visited.remove(&reference);
+                                continue;
                             }
                         };
 
                         // Recursively serialize - this returns the shapes array
                         match serialize_layer_recursive_cached(
                             &component_layer,
-                            font,
-                            location,
                             visited,
-                            layer_cache,
+                            layers,
                             json_cache,
                         ) {
                             Ok(component_shapes_json) => {
@@ -563,8 +834,7 @@ fn serialize_layer_recursive_cached(
 /// Recursive helper that serializes a layer and adds layerData to components
 fn serialize_layer_recursive(
     layer: &Layer,
-    font: &babelfont::Font,
-    location: &DesignLocation,
+    layers: &LayerSource,
     visited: &mut HashSet<String>,
 ) -> Result<String, String> {
     // First serialize the layer to JSON
@@ -587,12 +857,10 @@ fn serialize_layer_recursive(
                     if let Some(reference) = reference_opt {
                         // Prevent infinite recursion
                         if visited.contains(&reference) {
-                            web_sys::console::warn_1(
-                                &format!(
-                                    "[Rust] Circular component reference detected: {}",
-                                    reference
-                                )
-                                .into(),
+                            logging::warn(
+                                "interpolation",
+                                &format!("Circular component reference detected: {}", reference),
+                                serde_json::json!({ "component": reference }),
                             );
                             continue;
                         }
@@ -601,13 +869,12 @@ fn serialize_layer_recursive(
 
                         // Interpolate the component's glyph to get its untransformed layer data
                         // We want the raw interpolated geometry without the parent transform applied
-                        match font.interpolate_glyph(&reference, location) {
+                        match layers.layer(&reference) {
                             Ok(component_layer) => {
                                 // Recursively serialize with nested components
                                 match serialize_layer_recursive(
                                     &component_layer,
-                                    font,
-                                    location,
+                                    layers,
                                     visited,
                                 ) {
                                     Ok(component_layer_json) => {
@@ -624,34 +891,28 @@ fn serialize_layer_recursive(
                                                     .insert("layerData".to_string(), component_json);
                                             }
                                             Err(e) => {
-                                                web_sys::console::warn_1(
-                                                    &format!(
-                                                        "[Rust] Failed to parse component JSON for {}: {}",
-                                                        reference, e
-                                                    )
-                                                    .into(),
+                                                logging::warn(
+                                                    "interpolation",
+                                                    &format!("Failed to parse component JSON for {}: {}", reference, e),
+                                                    serde_json::json!({ "component": reference, "error": e.to_string() }),
                                                 );
                                             }
                                         }
                                     }
                                     Err(e) => {
-                                        web_sys::console::warn_1(
-                                            &format!(
-                                                "[Rust] Failed to serialize component {}: {}",
-                                                reference, e
-                                            )
-                                            .into(),
+                                        logging::warn(
+                                            "interpolation",
+                                            &format!("Failed to serialize component {}: {}", reference, e),
+                                            serde_json::json!({ "component": reference, "error": e.to_string() }),
                                         );
                                     }
                                 }
                             }
                             Err(e) => {
-                                web_sys::console::warn_1(
-                                    &format!(
-                                        "[Rust] Failed to interpolate component {}: {:?}",
-                                        reference, e
-                                    )
-                                    .into(),
+                                logging::warn(
+                                    "interpolation",
+                                    &format!("Failed to interpolate component {}: {}", reference, e),
+                                    serde_json::json!({ "component": reference, "error": e.to_string() }),
                                 );
                             }
                         }
@@ -0,0 +1,241 @@
+// Text itemization module
+//
+// This module splits preview text into runs of a single script and direction
+// and infers a language for each run, mirroring the segmentation a shaping
+// engine performs before shaping. For each run it reports the OpenType script
+// and language system tags and the complex shaper that will handle it.
+
+use unicode_bidi::BidiInfo;
+use unicode_script::{Script, UnicodeScript};
+use wasm_bindgen::prelude::*;
+
+use crate::errors::ApiError;
+
+/// A run of text with a single script and embedding level
+#[derive(Clone, Debug)]
+pub struct TextRun {
+    /// Byte range of the run in the input text
+    pub start: usize,
+    pub end: usize,
+    pub script: Script,
+    /// Bidi embedding level (odd levels are right-to-left)
+    pub level: u8,
+}
+
+/// Split text into script and direction runs
+///
+/// Common characters (spaces, punctuation, digits) and inherited characters
+/// (combining marks) take the script of the preceding run, or of the first
+/// run when they start the text, as HarfBuzz does. Text made only of common
+/// characters forms a single Common run.
+pub fn itemize(text: &str) -> Vec<TextRun> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let bidi = BidiInfo::new(text, None);
+    let first_script = text
+        .chars()
+        .map(|c| c.script())
+        .find(|script| !matches!(script, Script::Common | Script::Inherited | Script::Unknown))
+        .unwrap_or(Script::Common);
+
+    let mut runs: Vec<TextRun> = Vec::new();
+    let mut previous_script = first_script;
+
+    for (index, c) in text.char_indices() {
+        let script = match c.script() {
+            Script::Common | Script::Inherited | Script::Unknown => previous_script,
+            script => script,
+        };
+        previous_script = script;
+        let level = bidi.levels[index].number();
+        let end = index + c.len_utf8();
+
+        match runs.last_mut() {
+            Some(run) if run.script == script && run.level == level => run.end = end,
+            _ => runs.push(TextRun { start: index, end, script, level }),
+        }
+    }
+
+    runs
+}
+
+/// OpenType script tags for a script, preferred tag first
+///
+/// Indic scripts list the new (v2) shaping tag before the old one.
+fn ot_script_tags(script: Script) -> Vec<String> {
+    let tags: &[&str] = match script {
+        Script::Common | Script::Inherited | Script::Unknown => &["DFLT"],
+        Script::Bengali => &["bng2", "beng"],
+        Script::Devanagari => &["dev2", "deva"],
+        Script::Gujarati => &["gjr2", "gujr"],
+        Script::Gurmukhi => &["gur2", "guru"],
+        Script::Kannada => &["knd2", "knda"],
+        Script::Malayalam => &["mlm2", "mlym"],
+        Script::Myanmar => &["mym2", "mymr"],
+        Script::Oriya => &["ory2", "orya"],
+        Script::Tamil => &["tml2", "taml"],
+        Script::Telugu => &["tel2", "telu"],
+        Script::Hiragana | Script::Katakana => &["kana"],
+        Script::Lao => &["lao "],
+        Script::Yi => &["yi  "],
+        Script::Nko => &["nko "],
+        Script::Vai => &["vai "],
+        Script::Han => &["hani"],
+        _ => return vec![script.short_name().to_lowercase()],
+    };
+    tags.iter().map(|tag| tag.to_string()).collect()
+}
+
+/// Language inferred from a script, as (BCP 47 tag, OpenType language system tag)
+///
+/// Scripts shared by many languages with no clear majority (e.g. Latin)
+/// return None; other scripts infer their majority language (Cyrillic is
+/// inferred as Russian, Arabic as Arabic).
+fn inferred_language(script: Script) -> Option<(&'static str, &'static str)> {
+    Some(match script {
+        Script::Arabic => ("ar", "ARA"),
+        Script::Armenian => ("hy", "HYE"),
+        Script::Bengali => ("bn", "BEN"),
+        Script::Cyrillic => ("ru", "RUS"),
+        Script::Devanagari => ("hi", "HIN"),
+        Script::Ethiopic => ("am", "AMH"),
+        Script::Georgian => ("ka", "KAT"),
+        Script::Greek => ("el", "ELL"),
+        Script::Gujarati => ("gu", "GUJ"),
+        Script::Gurmukhi => ("pa", "PAN"),
+        Script::Han => ("zh", "ZHS"),
+        Script::Hangul => ("ko", "KOR"),
+        Script::Hebrew => ("he", "IWR"),
+        Script::Hiragana | Script::Katakana => ("ja", "JAN"),
+        Script::Kannada => ("kn", "KAN"),
+        Script::Khmer => ("km", "KHM"),
+        Script::Lao => ("lo", "LAO"),
+        Script::Malayalam => ("ml", "MAL"),
+        Script::Myanmar => ("my", "BRM"),
+        Script::Oriya => ("or", "ORI"),
+        Script::Sinhala => ("si", "SNH"),
+        Script::Tamil => ("ta", "TAM"),
+        Script::Telugu => ("te", "TEL"),
+        Script::Thai => ("th", "THA"),
+        Script::Tibetan => ("bo", "TIB"),
+        _ => return None,
+    })
+}
+
+/// The HarfBuzz shaper that handles a script
+///
+/// Follows HarfBuzz's shaper selection for the common complex scripts;
+/// scripts not listed use the default shaper.
+fn shaper_name(script: Script) -> &'static str {
+    match script {
+        Script::Arabic
+        | Script::Syriac
+        | Script::Mongolian
+        | Script::Nko
+        | Script::Phags_Pa
+        | Script::Mandaic
+        | Script::Manichaean
+        | Script::Psalter_Pahlavi
+        | Script::Adlam
+        | Script::Hanifi_Rohingya
+        | Script::Sogdian => "arabic",
+        Script::Hebrew => "hebrew",
+        Script::Bengali
+        | Script::Devanagari
+        | Script::Gujarati
+        | Script::Gurmukhi
+        | Script::Kannada
+        | Script::Malayalam
+        | Script::Oriya
+        | Script::Tamil
+        | Script::Telugu => "indic",
+        Script::Khmer => "khmer",
+        Script::Myanmar => "myanmar",
+        Script::Thai | Script::Lao => "thai",
+        Script::Hangul => "hangul",
+        Script::Balinese
+        | Script::Batak
+        | Script::Brahmi
+        | Script::Buginese
+        | Script::Buhid
+        | Script::Chakma
+        | Script::Cham
+        | Script::Grantha
+        | Script::Javanese
+        | Script::Kaithi
+        | Script::Kharoshthi
+        | Script::Khojki
+        | Script::Lepcha
+        | Script::Limbu
+        | Script::Meetei_Mayek
+        | Script::Modi
+        | Script::Newa
+        | Script::Sharada
+        | Script::Siddham
+        | Script::Sinhala
+        | Script::Sundanese
+        | Script::Tagalog
+        | Script::Tagbanwa
+        | Script::Tai_Le
+        | Script::Tai_Tham
+        | Script::Tai_Viet
+        | Script::Takri
+        | Script::Tibetan
+        | Script::Tirhuta => "use",
+        _ => "default",
+    }
+}
+
+/// Segment text into script, direction and language runs
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "runs": [
+///     { "start": 0, "end": 6, "text": "Hello ", "script": "Latn",
+///       "otScripts": ["latn"], "direction": "ltr", "level": 0,
+///       "language": null, "otLanguage": null, "shaper": "default" },
+///     { "start": 6, "end": 14, "text": "שלום", "script": "Hebr",
+///       "otScripts": ["hebr"], "direction": "rtl", "level": 1,
+///       "language": "he", "otLanguage": "IWR", "shaper": "hebrew" }
+///   ]
+/// }
+/// ```
+///
+/// Runs are in logical order and `start`/`end` are byte offsets into the
+/// UTF-8 text, matching shaping clusters. Languages are inferred from the
+/// script only; Latin and other scripts shared by many languages have no
+/// inferred language, so their `locl` behavior depends on the language set
+/// in the shaping options.
+///
+/// # Arguments
+/// * `text` - Text to itemize
+///
+/// # Returns
+/// * `String` - JSON object with the runs
+#[wasm_bindgen]
+pub fn itemize_text(text: &str) -> Result<String, JsValue> {
+    let runs: Vec<serde_json::Value> = itemize(text)
+        .into_iter()
+        .map(|run| {
+            let language = inferred_language(run.script);
+            serde_json::json!({
+                "start": run.start,
+                "end": run.end,
+                "text": &text[run.start..run.end],
+                "script": run.script.short_name(),
+                "otScripts": ot_script_tags(run.script),
+                "direction": if run.level % 2 == 1 { "rtl" } else { "ltr" },
+                "level": run.level,
+                "language": language.map(|(bcp47, _)| bcp47),
+                "otLanguage": language.map(|(_, ot)| ot),
+                "shaper": shaper_name(run.script),
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&serde_json::json!({ "runs": runs }))
+        .map_err(|e| ApiError::serialization("text runs", e).into())
+}
@@ -0,0 +1,773 @@
+// Kerning utilities module
+//
+// This module provides helpers for working with the kerning of source
+// masters: resolving `@group` sides to their member glyphs, collecting
+// the kerned pairs of one or all masters, checking kerning and groups
+// for conflicts, flattening group kerning to glyph pairs, resolving the
+// kerning of a pair at a location, editing a pair across masters, and
+// exchanging kerning with other tools as feature code or AFM data.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::errors::ApiError;
+use crate::font_validation::{findings_report, Finding};
+
+/// Resolve a kerning side to its glyphs
+///
+/// Group sides (`@name`) resolve to the members of the first (left side) or
+/// second (right side) kerning group; glyph sides resolve to themselves.
+/// Unknown groups resolve to no glyphs.
+pub fn side_members(font: &babelfont::Font, side: &str, first: bool) -> Vec<String> {
+    let Some(group_name) = side.strip_prefix('@') else {
+        return vec![side.to_string()];
+    };
+    let groups = if first { &font.first_kern_groups } else { &font.second_kern_groups };
+    groups
+        .get(group_name)
+        .map(|members| members.iter().map(|m| m.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Collect the kerned (left, right) sides of a master, or of all masters
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `master_id` - Master to read, or None for the union of all masters
+pub fn kerned_sides(font: &babelfont::Font, master_id: Option<&str>) -> Result<BTreeSet<(String, String)>, ApiError> {
+    if let Some(master_id) = master_id {
+        if !font.masters.iter().any(|m| m.id == master_id) {
+            return Err(ApiError::master_not_found(master_id));
+        }
+    }
+
+    Ok(font
+        .masters
+        .iter()
+        .filter(|master| master_id.is_none_or(|id| master.id == id))
+        .flat_map(|master| {
+            master
+                .kerning
+                .keys()
+                .map(|(left, right)| (left.to_string(), right.to_string()))
+        })
+        .collect())
+}
+
+/// Groups each glyph belongs to on one side
+fn glyph_groups(font: &babelfont::Font, first: bool) -> BTreeMap<String, Vec<String>> {
+    let groups = if first { &font.first_kern_groups } else { &font.second_kern_groups };
+    let mut memberships: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (group, members) in groups.iter() {
+        for member in members.iter() {
+            memberships.entry(member.to_string()).or_default().push(group.to_string());
+        }
+    }
+    for groups in memberships.values_mut() {
+        groups.sort();
+    }
+    memberships
+}
+
+/// Check kerning groups and pairs for conflicts
+///
+/// Checks performed:
+/// - `multiple-groups`: a glyph is a member of several groups on the same side (error)
+/// - `missing-group-member`: a group lists a glyph that does not exist (warning)
+/// - `missing-kerning-side`: a pair references a glyph or group that does not exist (error)
+/// - `redundant-exception`: a glyph exception has the same value as the
+///   pair it overrides (glyph/group, group/glyph or group/group), so it
+///   can be removed (warning)
+///
+/// Groups are shared by all masters in babelfont sources, so group
+/// membership cannot differ between masters.
+///
+/// # Returns
+/// * `JsonValue` - Findings report in the format of validate_compiled_font()
+pub fn check_kerning(font: &babelfont::Font) -> serde_json::Value {
+    let mut findings = Vec::new();
+    let left_groups = glyph_groups(font, true);
+    let right_groups = glyph_groups(font, false);
+
+    // Group memberships
+    for (memberships, side) in [(&left_groups, "left"), (&right_groups, "right")] {
+        for (glyph, groups) in memberships {
+            if font.glyphs.get(glyph).is_none() {
+                for group in groups {
+                    findings.push(Finding::warning(
+                        "missing-group-member",
+                        format!("{} kerning group '{}' lists missing glyph '{}'", capitalize(side), group, glyph),
+                        serde_json::json!({ "glyph": glyph, "group": group, "side": side }),
+                    ));
+                }
+            }
+            if groups.len() > 1 {
+                findings.push(Finding::error(
+                    "multiple-groups",
+                    format!("'{}' is in several {} kerning groups: {}", glyph, side, groups.join(", ")),
+                    serde_json::json!({ "glyph": glyph, "groups": groups, "side": side }),
+                ));
+            }
+        }
+    }
+
+    let side_exists = |side: &str, first: bool| match side.strip_prefix('@') {
+        Some(group) => {
+            let groups = if first { &font.first_kern_groups } else { &font.second_kern_groups };
+            groups.get(group).is_some()
+        }
+        None => font.glyphs.get(side).is_some(),
+    };
+    // The group a glyph side falls back to, as a kerning side
+    let fallback_group = |side: &str, memberships: &BTreeMap<String, Vec<String>>| {
+        if side.starts_with('@') {
+            return None;
+        }
+        memberships.get(side).and_then(|groups| groups.first()).map(|group| format!("@{}", group))
+    };
+
+    for master in font.masters.iter() {
+        let pairs: HashMap<(String, String), f64> = master
+            .kerning
+            .iter()
+            .map(|((left, right), value)| ((left.to_string(), right.to_string()), f64::from(*value)))
+            .collect();
+
+        let mut sorted: Vec<(&(String, String), &f64)> = pairs.iter().collect();
+        sorted.sort_by(|a, b| a.0.cmp(b.0));
+        for ((left, right), value) in sorted {
+            for (side, first) in [(left, true), (right, false)] {
+                if !side_exists(side, first) {
+                    let kind = if side.starts_with('@') { "group" } else { "glyph" };
+                    findings.push(Finding::error(
+                        "missing-kerning-side",
+                        format!("Pair {} {} in master '{}' references missing {} '{}'", left, right, master.id, kind, side),
+                        serde_json::json!({ "master": master.id, "left": left, "right": right, "missing": side }),
+                    ));
+                }
+            }
+
+            // The value that applies without this pair, in lookup order:
+            // glyph/group, group/glyph, group/group
+            let left_group = fallback_group(left, &left_groups);
+            let right_group = fallback_group(right, &right_groups);
+            let candidates = [
+                right_group.clone().map(|right| (left.clone(), right)),
+                left_group.clone().map(|left| (left, right.clone())),
+                left_group.zip(right_group),
+            ];
+            let overridden = candidates
+                .into_iter()
+                .flatten()
+                .find_map(|pair| pairs.get(&pair).map(|value| (pair, *value)));
+
+            if let Some(((group_left, group_right), group_value)) = overridden {
+                if group_value == *value {
+                    findings.push(Finding::warning(
+                        "redundant-exception",
+                        format!(
+                            "Exception {} {} in master '{}' repeats the value {} of {} {}",
+                            left, right, master.id, value, group_left, group_right
+                        ),
+                        serde_json::json!({
+                            "master": master.id,
+                            "left": left,
+                            "right": right,
+                            "value": value,
+                            "overrides": [group_left, group_right],
+                        }),
+                    ));
+                }
+            }
+        }
+    }
+
+    findings_report(&findings)
+}
+
+/// Expand the group kerning of masters into glyph pairs
+///
+/// Each glyph pair gets the value that applies to it at shaping time: a
+/// glyph/glyph pair first, then glyph/group and group/glyph exceptions,
+/// then the group/group pair. Glyphs in several groups on one side kern
+/// with the first group by name, as reported by check_kerning(). Pairs
+/// resolving to zero are left out, as they don't kern; group members that
+/// are not in the font are skipped.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `master_id` - Master to flatten, or None for all masters
+///
+/// # Returns
+/// * `JsonValue` - '{"masters": [{"master": "m01", "pairs": [["A", "V", -80], ...]}], "pairs": 1234}' with pairs sorted by glyph names
+pub fn flatten_kerning(font: &babelfont::Font, master_id: Option<&str>) -> Result<serde_json::Value, ApiError> {
+    if let Some(master_id) = master_id {
+        if !font.masters.iter().any(|m| m.id == master_id) {
+            return Err(ApiError::master_not_found(master_id));
+        }
+    }
+
+    let mut masters = Vec::new();
+    let mut total = 0;
+    for master in font.masters.iter().filter(|master| master_id.is_none_or(|id| master.id == id)) {
+        let pairs: Vec<serde_json::Value> = flat_pairs(font, master)
+            .into_iter()
+            .map(|(left, right, value)| serde_json::json!([left, right, value]))
+            .collect();
+        total += pairs.len();
+        masters.push(serde_json::json!({
+            "master": master.id,
+            "pairs": pairs,
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "masters": masters,
+        "pairs": total,
+    }))
+}
+
+/// Non-zero glyph pairs of a master's kerning, sorted by glyph names
+fn flat_pairs(font: &babelfont::Font, master: &babelfont::Master) -> Vec<(String, String, f64)> {
+    let left_groups = glyph_groups(font, true);
+    let right_groups = glyph_groups(font, false);
+    // Members of a group side that kern with it: glyphs whose first group it is
+    let members = |side: &str, first: bool| -> Vec<String> {
+        let memberships = if first { &left_groups } else { &right_groups };
+        side_members(font, side, first)
+            .into_iter()
+            .filter(|glyph| font.glyphs.get(glyph).is_some())
+            .filter(|glyph| match side.strip_prefix('@') {
+                Some(group) => memberships.get(glyph).and_then(|groups| groups.first()).is_some_and(|first| first == group),
+                None => true,
+            })
+            .collect()
+    };
+
+    // Most specific pair wins: glyph/glyph (0), glyph/group (1), group/glyph (2), group/group (3)
+    let mut resolved: BTreeMap<(String, String), (u8, f64)> = BTreeMap::new();
+    for ((left, right), value) in master.kerning.iter() {
+        let (left, right) = (left.to_string(), right.to_string());
+        let rank = match (left.starts_with('@'), right.starts_with('@')) {
+            (false, false) => 0,
+            (false, true) => 1,
+            (true, false) => 2,
+            (true, true) => 3,
+        };
+        let right_members = members(&right, false);
+        for left_glyph in members(&left, true) {
+            for right_glyph in &right_members {
+                let entry = resolved.entry((left_glyph.clone(), right_glyph.clone())).or_insert((rank, f64::from(*value)));
+                if rank < entry.0 {
+                    *entry = (rank, f64::from(*value));
+                }
+            }
+        }
+    }
+
+    resolved
+        .into_iter()
+        .filter(|(_, (_, value))| *value != 0.0)
+        .map(|((left, right), (_, value))| (left, right, value))
+        .collect()
+}
+
+/// Kinds of kerning rules, from the most to the least specific
+const RULE_KINDS: [&str; 4] = ["glyph-glyph", "glyph-group", "group-glyph", "group-group"];
+
+/// Resolve the kerning of a glyph pair at a location
+///
+/// In each master the most specific rule wins: the glyph pair, then the
+/// left glyph with the right glyph's group, then the left group with the
+/// right glyph, then both groups. A glyph kerns with the first of its
+/// groups (by name) on each side. Masters without a rule kern by 0, and
+/// the masters' values are interpolated at the location.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `left` - Left glyph name
+/// * `right` - Right glyph name
+/// * `location_json` - JSON object with axis tags and values in USER SPACE; empty for the default location
+///
+/// # Returns
+/// * `JsonValue` - '{"left": "A", "right": "V", "value": -75.0, "rule": {"left": "@A", "right": "@V", "kind": "group-group"}, "groups": {"left": "A", "right": "V"}, "masters": [{"master": "m01", "value": -80, "rule": {...}}]}'
+///   with `rule` the most specific rule of any master, or null if no master kerns the pair
+pub fn get_pair_kerning(
+    font: &babelfont::Font,
+    left: &str,
+    right: &str,
+    location_json: &str,
+) -> Result<serde_json::Value, ApiError> {
+    for glyph in [left, right] {
+        if font.glyphs.get(glyph).is_none() {
+            return Err(ApiError::glyph_not_found(glyph));
+        }
+    }
+    let location = crate::glyph_outlines::design_location(font, location_json)?;
+
+    let left_group = glyph_groups(font, true).remove(left).and_then(|groups| groups.into_iter().next());
+    let right_group = glyph_groups(font, false).remove(right).and_then(|groups| groups.into_iter().next());
+    let left_sides: Vec<String> = std::iter::once(left.to_string())
+        .chain(left_group.iter().map(|group| format!("@{}", group)))
+        .collect();
+    let right_sides: Vec<String> = std::iter::once(right.to_string())
+        .chain(right_group.iter().map(|group| format!("@{}", group)))
+        .collect();
+    // Candidate rules in order of precedence
+    let mut candidates = Vec::new();
+    for (kind, (left_index, right_index)) in RULE_KINDS.iter().zip([(0, 0), (0, 1), (1, 0), (1, 1)]) {
+        if let (Some(left_side), Some(right_side)) = (left_sides.get(left_index), right_sides.get(right_index)) {
+            candidates.push((left_side.as_str(), right_side.as_str(), *kind));
+        }
+    }
+
+    let rule_json = |(left, right, kind): (&str, &str, &str)| {
+        serde_json::json!({ "left": left, "right": right, "kind": kind })
+    };
+    let mut masters = Vec::new();
+    let mut values = Vec::new();
+    let mut best: Option<usize> = None;
+    for master in font.masters.iter() {
+        let found = candidates.iter().enumerate().find_map(|(index, (left_side, right_side, _))| {
+            master
+                .kerning
+                .iter()
+                .find(|((left, right), _)| left.as_str() == *left_side && right.as_str() == *right_side)
+                .map(|(_, value)| (index, f64::from(*value)))
+        });
+        let value = found.map(|(_, value)| value).unwrap_or(0.0);
+        if let Some((index, _)) = found {
+            best = Some(best.map_or(index, |best| best.min(index)));
+        }
+        values.push((master.id.as_str(), value));
+        masters.push(serde_json::json!({
+            "master": master.id,
+            "value": value,
+            "rule": found.map(|(index, _)| rule_json(candidates[index])),
+        }));
+    }
+    let value = if values.is_empty() {
+        0.0
+    } else {
+        crate::interpolation::interpolate_master_values(font, &values, &location)?
+    };
+
+    Ok(serde_json::json!({
+        "left": left,
+        "right": right,
+        "value": value,
+        "rule": best.map(|index| rule_json(candidates[index])),
+        "groups": { "left": left_group, "right": right_group },
+        "masters": masters,
+    }))
+}
+
+/// Set the kerning of a pair in one master and carry the change over to the others
+///
+/// Sides are glyph names or `@group` names. The pair gets `value` in the
+/// master `master_id`; the other masters get a value derived from the
+/// change by `propagation`, with masters not kerning the pair counting as
+/// 0. Values are rounded. Other masters only get a new pair if its value is
+/// not 0, so propagating does not add exceptions that override groups.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `left` - Left side
+/// * `right` - Right side
+/// * `master_id` - Master the value is given for
+/// * `value` - New kerning value in font units
+/// * `propagation` - How the other masters follow
+///
+/// # Returns
+/// * `JsonValue` - '{"left": "A", "right": "@V", "masters": [{"master": "m01", "value": -80, "previous": -60}]}' listing the masters whose value changed
+pub fn set_linked_kerning(
+    font: &mut babelfont::Font,
+    left: &str,
+    right: &str,
+    master_id: &str,
+    value: f64,
+    propagation: crate::spacing::Propagation,
+) -> Result<serde_json::Value, ApiError> {
+    for (side, first) in [(left, true), (right, false)] {
+        let exists = match side.strip_prefix('@') {
+            Some(group) => {
+                let groups = if first { &font.first_kern_groups } else { &font.second_kern_groups };
+                groups.get(group).is_some()
+            }
+            None => font.glyphs.get(side).is_some(),
+        };
+        if !exists {
+            return Err(match side.strip_prefix('@') {
+                Some(group) => ApiError::invalid_argument("kerning group", group),
+                None => ApiError::glyph_not_found(side),
+            });
+        }
+    }
+    let current = |master: &babelfont::Master| {
+        master
+            .kerning
+            .iter()
+            .find(|((candidate_left, candidate_right), _)| {
+                candidate_left.as_str() == left && candidate_right.as_str() == right
+            })
+            .map(|(_, value)| f64::from(*value))
+    };
+    let source = font
+        .masters
+        .iter()
+        .find(|master| master.id == master_id)
+        .ok_or_else(|| ApiError::master_not_found(master_id))?;
+    let old = current(source).unwrap_or(0.0);
+    let new = value.round();
+
+    let mut changes = Vec::new();
+    for master in font.masters.iter() {
+        let previous = current(master);
+        let target = if master.id == master_id {
+            Some(new)
+        } else {
+            propagation
+                .propagate(old, new, previous.unwrap_or(0.0))
+                .map(f64::round)
+                .filter(|target| previous.is_some() || *target != 0.0)
+        };
+        if let Some(target) = target.filter(|target| previous != Some(*target)) {
+            changes.push((master.id.clone(), previous, target));
+        }
+    }
+
+    let mut masters = Vec::new();
+    for (id, previous, target) in changes {
+        if let Some(master) = font.masters.iter_mut().find(|master| master.id == id) {
+            master.kerning.insert((left.into(), right.into()), target as _);
+        }
+        masters.push(serde_json::json!({
+            "master": id,
+            "value": target,
+            "previous": previous,
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "left": left,
+        "right": right,
+        "masters": masters,
+    }))
+}
+
+/// Kerning exchange formats
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KerningFormat {
+    /// Feature file `pos` rules with kerning group classes
+    Fea,
+    /// AFM `KPX` lines (glyph pairs only)
+    Afm,
+}
+
+impl KerningFormat {
+    /// Parse a format name: "fea" or "afm"
+    pub fn from_name(name: &str) -> Result<Self, ApiError> {
+        match name.to_ascii_lowercase().as_str() {
+            "fea" => Ok(KerningFormat::Fea),
+            "afm" => Ok(KerningFormat::Afm),
+            other => Err(ApiError::invalid_argument("kerning format", other)),
+        }
+    }
+}
+
+/// Class name prefixes of first (left) and second (right) kerning groups in feature code
+const FIRST_CLASS_PREFIXES: [&str; 3] = ["kern1.", "public.kern1.", "MMK_L_"];
+const SECOND_CLASS_PREFIXES: [&str; 3] = ["kern2.", "public.kern2.", "MMK_R_"];
+
+/// Feature code class name of a kerning group
+fn group_class(group: &str, first: bool) -> String {
+    let prefix = if first { FIRST_CLASS_PREFIXES[0] } else { SECOND_CLASS_PREFIXES[0] };
+    let name: String = group
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') { c } else { '_' })
+        .collect();
+    format!("@{}{}", prefix, name)
+}
+
+/// The master to exchange kerning with: the given one, else the default master
+fn kerning_master<'a>(font: &'a babelfont::Font, master_id: Option<&str>) -> Result<&'a babelfont::Master, ApiError> {
+    match master_id {
+        Some(id) => font.masters.iter().find(|master| master.id == id).ok_or_else(|| ApiError::master_not_found(id)),
+        None => crate::source_checks::default_master_id(font)
+            .and_then(|id| font.masters.iter().find(|master| master.id == id))
+            .or_else(|| font.masters.first())
+            .ok_or_else(|| ApiError::OperationFailed { message: "The font has no masters".to_string() }),
+    }
+}
+
+/// Export the kerning of a master as feature code or AFM data
+///
+/// Feature code defines a class per kerning group (`@kern1.A`, `@kern2.V`)
+/// and lists glyph pairs, `enum` exceptions and group pairs in that order,
+/// so more specific pairs take precedence. AFM has no groups, so its `KPX`
+/// lines hold the flattened glyph pairs (see flatten_kerning()).
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `format` - Format to write
+/// * `master_id` - Master to export; the default master if None
+pub fn export_kerning(font: &babelfont::Font, format: KerningFormat, master_id: Option<&str>) -> Result<String, ApiError> {
+    let master = kerning_master(font, master_id)?;
+    let mut output = String::new();
+    match format {
+        KerningFormat::Afm => {
+            let pairs = flat_pairs(font, master);
+            output.push_str("StartKernData\n");
+            output.push_str(&format!("StartKernPairs {}\n", pairs.len()));
+            for (left, right, value) in pairs {
+                output.push_str(&format!("KPX {} {} {}\n", left, right, value.round()));
+            }
+            output.push_str("EndKernPairs\nEndKernData\n");
+        }
+        KerningFormat::Fea => {
+            output.push_str(&format!("# Kerning of master '{}'\n", master.id));
+            for (groups, first) in [(&font.first_kern_groups, true), (&font.second_kern_groups, false)] {
+                let mut sorted: Vec<(String, Vec<String>)> = groups
+                    .iter()
+                    .map(|(name, members)| (name.to_string(), members.iter().map(|m| m.to_string()).collect()))
+                    .collect();
+                sorted.sort();
+                for (name, members) in sorted {
+                    output.push_str(&format!("{} = [{}];\n", group_class(&name, first), members.join(" ")));
+                }
+            }
+
+            let side = |side: &str, first: bool| match side.strip_prefix('@') {
+                Some(group) => group_class(group, first),
+                None => side.to_string(),
+            };
+            let mut rules: Vec<(u8, String, String, f64)> = master
+                .kerning
+                .iter()
+                .map(|((left, right), value)| {
+                    let rank = match (left.starts_with('@'), right.starts_with('@')) {
+                        (false, false) => 0,
+                        (false, true) => 1,
+                        (true, false) => 2,
+                        (true, true) => 3,
+                    };
+                    (rank, side(left.as_str(), true), side(right.as_str(), false), f64::from(*value))
+                })
+                .collect();
+            rules.sort_by(|a, b| (a.0, &a.1, &a.2).cmp(&(b.0, &b.1, &b.2)));
+
+            output.push_str("\nfeature kern {\n");
+            for (rank, left, right, value) in rules {
+                let keyword = if rank == 1 || rank == 2 { "enum pos" } else { "pos" };
+                output.push_str(&format!("    {} {} {} {};\n", keyword, left, right, value));
+            }
+            output.push_str("} kern;\n");
+        }
+    }
+    Ok(output)
+}
+
+/// Kerning group name of a feature code class, if it is one for the side
+fn class_group(class: &str, first: bool) -> String {
+    let prefixes = if first { FIRST_CLASS_PREFIXES } else { SECOND_CLASS_PREFIXES };
+    prefixes
+        .iter()
+        .find_map(|prefix| class.strip_prefix(prefix))
+        .unwrap_or(class)
+        .to_string()
+}
+
+/// Kerning value of a feature code value record: a number, `<x>` or `<x y xAdvance yAdvance>`
+fn fea_value(tokens: &[&str]) -> Option<f64> {
+    match tokens {
+        [value] => value.parse().ok(),
+        ["<", value, ">"] => value.parse().ok(),
+        ["<", _, _, advance, _, ">"] => advance.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Import kerning from feature code or AFM data into a master
+///
+/// Imported pairs are added to the master's kerning, replacing pairs with
+/// the same sides. From feature code, `pos` and `enum pos` rules between
+/// glyphs, classes and inline classes are read; classes become kerning
+/// groups (named without a `kern1.`/`kern2.`, `public.kern1.`/... or
+/// `MMK_L_`/`MMK_R_` prefix), replacing groups of the same name, and
+/// inline classes are expanded to glyph pairs. Other rules (contextual or
+/// with lookups) are counted as unsupported. Glyphs missing from the font
+/// are skipped.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `format` - Format of the text
+/// * `text` - Feature code or AFM data
+/// * `master_id` - Master to import into; the default master if None
+///
+/// # Returns
+/// * `JsonValue` - '{"pairs": 120, "groups": 14, "unsupported": 2, "skipped": ["Adieresis.alt"]}'
+pub fn import_kerning(
+    font: &mut babelfont::Font,
+    format: KerningFormat,
+    text: &str,
+    master_id: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let master_id = kerning_master(font, master_id)?.id.clone();
+    let mut pairs: Vec<(String, String, f64)> = Vec::new();
+    let mut groups: Vec<(String, bool, Vec<String>)> = Vec::new();
+    let mut unsupported = 0;
+
+    match format {
+        KerningFormat::Afm => {
+            for line in text.lines() {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                match parts.as_slice() {
+                    ["KPX", left, right, value, ..] | ["KP", left, right, value, ..] => {
+                        let value: f64 = value.parse().map_err(|_| ApiError::invalid_argument("kerning value", line.trim()))?;
+                        pairs.push((left.to_string(), right.to_string(), value));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        KerningFormat::Fea => {
+            let mut classes: HashMap<String, Vec<String>> = HashMap::new();
+            // Glyphs of a class body, expanding classes defined before
+            let expand = |tokens: &[&str], classes: &HashMap<String, Vec<String>>| -> Vec<String> {
+                tokens
+                    .iter()
+                    .flat_map(|token| match token.strip_prefix('@') {
+                        Some(class) => classes.get(class).cloned().unwrap_or_default(),
+                        None => vec![token.trim_start_matches('\\').to_string()],
+                    })
+                    .collect()
+            };
+            let mut used_classes: Vec<(String, bool)> = Vec::new();
+            for statement in crate::feature_syntax::statements(text) {
+                match statement.as_slice() {
+                    [class, "=", "[", members @ .., "]"] if class.starts_with('@') => {
+                        let members = expand(members, &classes);
+                        classes.insert(class[1..].to_string(), members);
+                    }
+                    [first_word, rest @ ..] if matches!(*first_word, "pos" | "position" | "enum" | "enumerate") => {
+                        let rest = match (*first_word, rest) {
+                            ("enum" | "enumerate", [keyword, rest @ ..]) if matches!(*keyword, "pos" | "position") => rest,
+                            ("pos" | "position", rest) => rest,
+                            _ => {
+                                unsupported += 1;
+                                continue;
+                            }
+                        };
+                        // A side: a glyph, a class, or an inline class
+                        let side = |tokens: &[&str]| -> Option<(Vec<String>, usize)> {
+                            match tokens.first()? {
+                                &"[" => {
+                                    let end = tokens.iter().position(|token| *token == "]")?;
+                                    Some((expand(&tokens[1..end], &classes), end + 1))
+                                }
+                                word if word.starts_with('@') || !word.starts_with(['<', '\'']) => {
+                                    Some((vec![word.trim_start_matches('\\').to_string()], 1))
+                                }
+                                _ => None,
+                            }
+                        };
+                        let parsed = side(rest).and_then(|(left, used)| {
+                            let (right, used_right) = side(&rest[used..])?;
+                            let value = fea_value(&rest[used + used_right..])?;
+                            Some((left, right, value))
+                        });
+                        let Some((left, right, value)) = parsed else {
+                            unsupported += 1;
+                            continue;
+                        };
+                        for (sides, first) in [(&left, true), (&right, false)] {
+                            if let [class] = sides.as_slice() {
+                                if let Some(class) = class.strip_prefix('@') {
+                                    used_classes.push((class.to_string(), first));
+                                }
+                            }
+                        }
+                        for left in &left {
+                            for right in &right {
+                                pairs.push((left.clone(), right.clone(), value));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            // Classes used as a side become kerning groups
+            used_classes.sort();
+            used_classes.dedup();
+            for (class, first) in used_classes {
+                let members = classes
+                    .get(&class)
+                    .cloned()
+                    .ok_or_else(|| ApiError::ClassNotFound { class: class.clone() })?;
+                groups.push((class, first, members));
+            }
+        }
+    }
+
+    let mut skipped: BTreeSet<String> = BTreeSet::new();
+    let mut renamed_classes: HashMap<(String, bool), String> = HashMap::new();
+    let mut group_count = 0;
+    for (class, first, members) in groups {
+        let group = class_group(&class, first);
+        let members: Vec<String> = members
+            .into_iter()
+            .filter(|member| {
+                let exists = font.glyphs.get(member).is_some();
+                if !exists {
+                    skipped.insert(member.clone());
+                }
+                exists
+            })
+            .collect();
+        let groups = if first { &mut font.first_kern_groups } else { &mut font.second_kern_groups };
+        groups.insert(group.as_str().into(), members.iter().map(|member| member.as_str().into()).collect());
+        renamed_classes.insert((class, first), group);
+        group_count += 1;
+    }
+
+    let mut pair_count = 0;
+    let mut resolved = Vec::new();
+    for (left, right, value) in pairs {
+        let mut sides = Vec::new();
+        for (side, first) in [(left, true), (right, false)] {
+            let resolved_side = match side.strip_prefix('@') {
+                Some(class) => renamed_classes.get(&(class.to_string(), first)).map(|group| format!("@{}", group)),
+                None if font.glyphs.get(&side).is_some() => Some(side),
+                None => {
+                    skipped.insert(side);
+                    None
+                }
+            };
+            sides.extend(resolved_side);
+        }
+        if let [left, right] = sides.as_slice() {
+            resolved.push((left.clone(), right.clone(), value));
+        }
+    }
+    if let Some(master) = font.masters.iter_mut().find(|master| master.id == master_id) {
+        for (left, right, value) in resolved {
+            master.kerning.insert((left.as_str().into(), right.as_str().into()), value.round() as _);
+            pair_count += 1;
+        }
+    }
+
+    Ok(serde_json::json!({
+        "pairs": pair_count,
+        "groups": group_count,
+        "unsupported": unsupported,
+        "skipped": skipped,
+    }))
+}
+
+pub(crate) fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
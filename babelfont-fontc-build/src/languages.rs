@@ -0,0 +1,243 @@
+// Language support module
+//
+// This module judges which languages a font supports from its codepoint
+// coverage, in the manner of Hyperglot: each language lists the base
+// characters of its orthography, and a font supports the language if it
+// encodes all of them. The data covers the major languages written in
+// Latin, Cyrillic, Greek, Armenian, Georgian, Hebrew, Arabic and
+// Devanagari. Letters are listed in lowercase; uppercase forms are added
+// for the bicameral scripts.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use serde_json::{json, Value as JsonValue};
+
+use crate::charsets::suggested_glyph_name;
+use crate::errors::ApiError;
+
+/// Character requirements of a language
+pub struct Language {
+    /// ISO 639-3 code, with a script suffix where a language has several, e.g. "srp-Latn"
+    pub code: &'static str,
+    /// English name
+    pub name: &'static str,
+    /// Script name, e.g. "Latin"
+    pub script: &'static str,
+    /// Required letters (lowercase in bicameral scripts)
+    pub base: &'static str,
+    /// Further required characters, taken as given (e.g. Turkish "İ")
+    pub extra: &'static str,
+}
+
+impl Language {
+    /// All required codepoints, with the uppercase forms of bicameral scripts
+    pub fn codepoints(&self) -> BTreeSet<u32> {
+        let bicameral = BICAMERAL_SCRIPTS.contains(&self.script);
+        let mut codepoints = BTreeSet::new();
+        for c in self.base.chars() {
+            codepoints.insert(c as u32);
+            // Letters whose uppercase is a sequence (ß, ΐ) need no uppercase glyph
+            let mut upper = c.to_uppercase();
+            if let (true, Some(u), None) = (bicameral, upper.next(), upper.next()) {
+                codepoints.insert(u as u32);
+            }
+        }
+        codepoints.extend(self.extra.chars().map(|c| c as u32));
+        codepoints
+    }
+}
+
+/// Scripts whose letters have uppercase forms
+const BICAMERAL_SCRIPTS: [&str; 4] = ["Latin", "Cyrillic", "Greek", "Armenian"];
+
+const VIETNAMESE_BASE: &str = concat!(
+    "aăâbcdđeêghiklmnoôơpqrstuưvxy",
+    "àáảãạằắẳẵặầấẩẫậèéẻẽẹềếểễệìíỉĩịòóỏõọồốổỗộờớởỡợùúủũụừứửữựỳýỷỹỵ",
+);
+
+pub const LANGUAGES: &[Language] = &[
+    // Latin
+    Language { code: "afr", name: "Afrikaans", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáäâéèëêíîïóôöúûüý", extra: "" },
+    Language { code: "sqi", name: "Albanian", script: "Latin", base: "abcdefghijklmnopqrstuvxyzçë", extra: "" },
+    Language { code: "aze", name: "Azerbaijani", script: "Latin", base: "abcçdeəfgğhxıijkqlmnoöprsştuüvyz", extra: "İ" },
+    Language { code: "eus", name: "Basque", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzñ", extra: "" },
+    Language { code: "bos", name: "Bosnian", script: "Latin", base: "abcčćdđefghijklmnoprsštuvzž", extra: "" },
+    Language { code: "cat", name: "Catalan", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzàçèéíïòóúü", extra: "·" },
+    Language { code: "hrv", name: "Croatian", script: "Latin", base: "abcčćdđefghijklmnoprsštuvzž", extra: "" },
+    Language { code: "ces", name: "Czech", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáčďéěíňóřšťúůýž", extra: "" },
+    Language { code: "dan", name: "Danish", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzæøå", extra: "" },
+    Language { code: "nld", name: "Dutch", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzëï", extra: "" },
+    Language { code: "eng", name: "English", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    Language { code: "epo", name: "Esperanto", script: "Latin", base: "abcĉdefgĝhĥijĵklmnoprsŝtuŭvz", extra: "" },
+    Language { code: "est", name: "Estonian", script: "Latin", base: "abdefghijklmnoprsšzžtuvõäöü", extra: "" },
+    Language { code: "fao", name: "Faroese", script: "Latin", base: "aábdðefghiíjklmnoóprstuúvyýæø", extra: "" },
+    Language { code: "fin", name: "Finnish", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzåäö", extra: "" },
+    Language { code: "fra", name: "French", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzàâæçéèêëîïôœùûüÿ", extra: "" },
+    Language { code: "glg", name: "Galician", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáéíñóú", extra: "" },
+    Language { code: "deu", name: "German", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzäöüß", extra: "" },
+    Language { code: "hau", name: "Hausa", script: "Latin", base: "abɓcdɗefghijkƙlmnorstuwyƴz", extra: "" },
+    Language { code: "hun", name: "Hungarian", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáéíóöőúüű", extra: "" },
+    Language { code: "isl", name: "Icelandic", script: "Latin", base: "aábdðeéfghiíjklmnoóprstuúvxyýþæö", extra: "" },
+    Language { code: "ind", name: "Indonesian", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    Language { code: "gle", name: "Irish", script: "Latin", base: "abcdefghilmnoprstuáéíóú", extra: "" },
+    Language { code: "ita", name: "Italian", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzàèéìòù", extra: "" },
+    Language { code: "kmr", name: "Kurdish (Kurmanji)", script: "Latin", base: "abcçdeêfghiîjklmnopqrsştuûvwxyz", extra: "" },
+    Language { code: "lat", name: "Latin", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    Language { code: "lav", name: "Latvian", script: "Latin", base: "aābcčdeēfgģhiījkķlļmnņoprsštuūvzž", extra: "" },
+    Language { code: "lit", name: "Lithuanian", script: "Latin", base: "aąbcčdeęėfghiįyjklmnoprsštuųūvzž", extra: "" },
+    Language { code: "ltz", name: "Luxembourgish", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzäëé", extra: "" },
+    Language { code: "msa", name: "Malay", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    Language { code: "mlt", name: "Maltese", script: "Latin", base: "abċdefġghħijklmnopqrstuvwxżzàèìòù", extra: "" },
+    Language { code: "nob", name: "Norwegian Bokmål", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzæøå", extra: "" },
+    Language { code: "pol", name: "Polish", script: "Latin", base: "aąbcćdeęfghijklłmnńoóprsśtuwyzźż", extra: "" },
+    Language { code: "por", name: "Portuguese", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzàáâãçéêíóôõú", extra: "" },
+    Language { code: "ron", name: "Romanian", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzăâîșț", extra: "" },
+    Language { code: "gla", name: "Scottish Gaelic", script: "Latin", base: "abcdefghilmnoprstuàèìòù", extra: "" },
+    Language { code: "srp-Latn", name: "Serbian (Latin)", script: "Latin", base: "abcčćdđefghijklmnoprsštuvzž", extra: "" },
+    Language { code: "slk", name: "Slovak", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáäčďéíĺľňóôŕšťúýž", extra: "" },
+    Language { code: "slv", name: "Slovenian", script: "Latin", base: "abcčdefghijklmnoprsštuvzž", extra: "" },
+    Language { code: "spa", name: "Spanish", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzáéíñóúü", extra: "" },
+    Language { code: "swa", name: "Swahili", script: "Latin", base: "abcdefghijklmnoprstuvwyz", extra: "" },
+    Language { code: "swe", name: "Swedish", script: "Latin", base: "abcdefghijklmnopqrstuvwxyzåäö", extra: "" },
+    Language { code: "tur", name: "Turkish", script: "Latin", base: "abcçdefgğhıijklmnoöprsştuüvyz", extra: "İ" },
+    Language { code: "tuk", name: "Turkmen", script: "Latin", base: "abçdeäfghijžklmnňoöprsştuüwyýz", extra: "" },
+    Language { code: "uzb", name: "Uzbek", script: "Latin", base: "abdefghijklmnopqrstuvxyz", extra: "ʻʼ" },
+    Language { code: "vie", name: "Vietnamese", script: "Latin", base: VIETNAMESE_BASE, extra: "" },
+    Language { code: "cym", name: "Welsh", script: "Latin", base: "abcdefghijlmnoprstuwyâêîôûŵŷ", extra: "" },
+    Language { code: "xho", name: "Xhosa", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    Language { code: "yor", name: "Yoruba", script: "Latin", base: "abdeẹfghijklmnoọprsṣtuwy", extra: "" },
+    Language { code: "zul", name: "Zulu", script: "Latin", base: "abcdefghijklmnopqrstuvwxyz", extra: "" },
+    // Cyrillic
+    Language { code: "bak", name: "Bashkir", script: "Cyrillic", base: "аәбвгғдҙеёжзийклмнңоөпрсҫтуүфхһцчшщъыьэюя", extra: "" },
+    Language { code: "bel", name: "Belarusian", script: "Cyrillic", base: "абвгдеёжзійклмнопрстуўфхцчшыьэюя", extra: "" },
+    Language { code: "bul", name: "Bulgarian", script: "Cyrillic", base: "абвгдежзийклмнопрстуфхцчшщъьюя", extra: "" },
+    Language { code: "kaz", name: "Kazakh", script: "Cyrillic", base: "аәбвгғдеёжзийкқлмнңоөпрстуұүфхһцчшщъыіьэюя", extra: "" },
+    Language { code: "kir", name: "Kyrgyz", script: "Cyrillic", base: "абвгдеёжзийклмнңоөпрстуүфхцчшщъыьэюя", extra: "" },
+    Language { code: "mkd", name: "Macedonian", script: "Cyrillic", base: "абвгдѓежзѕијклљмнњопрстќуфхцчџш", extra: "" },
+    Language { code: "mon", name: "Mongolian", script: "Cyrillic", base: "абвгдеёжзийклмноөпрстуүфхцчшщъыьэюя", extra: "" },
+    Language { code: "rus", name: "Russian", script: "Cyrillic", base: "абвгдеёжзийклмнопрстуфхцчшщъыьэюя", extra: "" },
+    Language { code: "srp", name: "Serbian", script: "Cyrillic", base: "абвгдђежзијклљмнњопрстћуфхцчџш", extra: "" },
+    Language { code: "tgk", name: "Tajik", script: "Cyrillic", base: "абвгғдеёжзиӣйкқлмнопрстуӯфхҳчҷшъэюя", extra: "" },
+    Language { code: "tat", name: "Tatar", script: "Cyrillic", base: "аәбвгдеёжҗзийклмнңоөпрстуүфхһцчшщъыьэюя", extra: "" },
+    Language { code: "ukr", name: "Ukrainian", script: "Cyrillic", base: "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя", extra: "" },
+    // Greek
+    Language { code: "ell", name: "Greek", script: "Greek", base: "αβγδεζηθικλμνξοπρσςτυφχψωάέήίόύώϊϋΐΰ", extra: "" },
+    // Armenian
+    Language { code: "hye", name: "Armenian", script: "Armenian", base: "աբգդեզէըթժիլխծկհձղճմյնշոչպջռսվտրցւփքօֆև", extra: "" },
+    // Georgian (Mkhedruli has no case in running text)
+    Language { code: "kat", name: "Georgian", script: "Georgian", base: "აბგდევზთიკლმნოპჟრსტუფქღყშჩცძწჭხჯჰ", extra: "" },
+    // Hebrew
+    Language { code: "heb", name: "Hebrew", script: "Hebrew", base: "אבגדהוזחטיכךלמםנןסעפףצץקרשת", extra: "" },
+    // Arabic
+    Language { code: "arb", name: "Arabic", script: "Arabic", base: "ءآأؤإئابةتثجحخدذرزسشصضطظعغفقكلمنهوىي", extra: "" },
+    Language { code: "fas", name: "Persian", script: "Arabic", base: "ءآأؤئابپتثجچحخدذرزژسشصضطظعغفقکگلمنوهی", extra: "" },
+    // Devanagari
+    Language { code: "hin", name: "Hindi", script: "Devanagari", base: "अआइईउऊऋएऐओऔकखगघङचछजझञटठडढणतथदधनपफबभमयरलवशषसहािीुूृेैोौंःँ्", extra: "" },
+];
+
+/// Languages supported by a font and the codepoints missing for the others
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `options_json` - Optional JSON object:
+///   - `scripts`: [string] - Only report languages of these scripts, e.g., ["Latin", "Cyrillic"]
+///   - `decomposed`: bool - Count characters as covered if their canonical decomposition is (base glyph + combining marks) (default: false)
+///
+/// # Returns
+/// * `JsonValue` - Report with `supported`, `unsupported` (closest to supported first, with missing codepoints) and `suggestions` (missing codepoints by number of languages they block)
+pub fn language_support(font: &babelfont::Font, options_json: Option<&str>) -> Result<JsonValue, ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: JsonValue = if options_json.is_empty() {
+        json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let scripts: Option<Vec<String>> = match options.get("scripts") {
+        None => None,
+        Some(value) => Some(
+            serde_json::from_value(value.clone()).map_err(|e| ApiError::invalid_json("scripts", e))?,
+        ),
+    };
+    let decomposed = options.get("decomposed").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    let encoded: HashSet<u32> = font
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.exported)
+        .flat_map(|glyph| glyph.codepoints.iter().copied())
+        .collect();
+    let covered = |codepoint: u32| {
+        if encoded.contains(&codepoint) {
+            return true;
+        }
+        if !decomposed {
+            return false;
+        }
+        let mut parts = Vec::new();
+        if let Some(c) = char::from_u32(codepoint) {
+            unicode_normalization::char::decompose_canonical(c, |part| parts.push(part as u32));
+        }
+        parts.len() > 1 && parts.iter().all(|part| encoded.contains(part))
+    };
+
+    let mut supported = Vec::new();
+    let mut unsupported = Vec::new();
+    // Missing codepoint -> languages it blocks
+    let mut blocking: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for language in LANGUAGES {
+        if scripts
+            .as_ref()
+            .is_some_and(|scripts| !scripts.iter().any(|script| script.eq_ignore_ascii_case(language.script)))
+        {
+            continue;
+        }
+        let missing: Vec<u32> = language.codepoints().into_iter().filter(|&codepoint| !covered(codepoint)).collect();
+        let entry = json!({ "code": language.code, "name": language.name, "script": language.script });
+        if missing.is_empty() {
+            supported.push(entry);
+            continue;
+        }
+        for &codepoint in &missing {
+            blocking.entry(codepoint).or_default().push(language.code);
+        }
+        unsupported.push((missing, entry));
+    }
+    unsupported.sort_by_key(|(missing, _)| missing.len());
+
+    let describe = |codepoint: u32| {
+        json!({
+            "codepoint": codepoint,
+            "hex": format!("U+{:04X}", codepoint),
+            "char": char::from_u32(codepoint).map(|c| c.to_string()),
+            "suggestedName": suggested_glyph_name(codepoint),
+        })
+    };
+    let unsupported: Vec<JsonValue> = unsupported
+        .into_iter()
+        .map(|(missing, mut entry)| {
+            entry["missingCount"] = json!(missing.len());
+            entry["missing"] = missing.into_iter().map(describe).collect();
+            entry
+        })
+        .collect();
+
+    let mut suggestions: Vec<(u32, Vec<&str>)> = blocking.into_iter().collect();
+    suggestions.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+    let suggestions: Vec<JsonValue> = suggestions
+        .into_iter()
+        .map(|(codepoint, languages)| {
+            let mut entry = describe(codepoint);
+            let existing = font.glyphs.get(&suggested_glyph_name(codepoint)).map(|glyph| glyph.name.to_string());
+            entry["existingGlyph"] = json!(existing);
+            entry["languages"] = json!(languages);
+            entry
+        })
+        .collect();
+
+    Ok(json!({
+        "supportedCount": supported.len(),
+        "supported": supported,
+        "unsupported": unsupported,
+        "suggestions": suggestions,
+    }))
+}
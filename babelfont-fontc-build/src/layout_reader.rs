@@ -0,0 +1,808 @@
+// Layout table inspection using read-fonts
+//
+// This module provides functions for inspecting the lookups of compiled GSUB
+// and GPOS tables: which lookups and features reference a glyph, which glyphs
+// a substitution produces, and where marks attach.
+
+use read_fonts::tables::gpos::{AnchorTable, MarkArray, PositionSubtables};
+use read_fonts::tables::gsub::SubstitutionSubtables;
+use read_fonts::tables::layout::{ChainedSequenceContext, CoverageTable, FeatureList, SequenceContext};
+use read_fonts::types::GlyphId16;
+use read_fonts::{FontRef, ReadError, TableProvider};
+use serde_json;
+use std::collections::{BTreeSet, HashMap};
+use wasm_bindgen::prelude::*;
+
+use crate::errors::ApiError;
+use crate::font_reader::read_glyph_order;
+
+/// Map each lookup index to the feature tags that reference it directly
+pub(crate) fn feature_lookup_map(feature_list: &FeatureList) -> HashMap<u16, Vec<String>> {
+    let mut map: HashMap<u16, Vec<String>> = HashMap::new();
+
+    for record in feature_list.feature_records().iter() {
+        if let Ok(feature) = record.feature(feature_list.offset_data()) {
+            let tag = record.feature_tag().to_string();
+            for index in feature.lookup_list_indices().iter() {
+                let features = map.entry(index.get()).or_default();
+                if !features.contains(&tag) {
+                    features.push(tag.clone());
+                }
+            }
+        }
+    }
+
+    map
+}
+
+/// Check whether a coverage table contains a glyph
+fn coverage_contains(coverage: Result<CoverageTable, ReadError>, gid: GlyphId16) -> bool {
+    coverage
+        .map(|coverage| coverage.iter().any(|g| g == gid))
+        .unwrap_or(false)
+}
+
+/// Check whether any coverage table in a list contains a glyph
+fn coverages_contain<'a>(
+    coverages: impl Iterator<Item = Result<CoverageTable<'a>, ReadError>>,
+    gid: GlyphId16,
+) -> bool {
+    coverages.into_iter().any(|coverage| coverage_contains(coverage, gid))
+}
+
+/// Roles of a glyph within a (non-chained) contextual subtable
+fn sequence_context_roles(context: &SequenceContext, gid: GlyphId16, roles: &mut BTreeSet<&'static str>) {
+    let matched = match context {
+        SequenceContext::Format1(table) => coverage_contains(table.coverage(), gid),
+        SequenceContext::Format2(table) => coverage_contains(table.coverage(), gid),
+        SequenceContext::Format3(table) => coverages_contain(table.coverages().iter(), gid),
+    };
+    if matched {
+        roles.insert("input");
+    }
+}
+
+/// Roles of a glyph within a chained contextual subtable
+fn chained_context_roles(context: &ChainedSequenceContext, gid: GlyphId16, roles: &mut BTreeSet<&'static str>) {
+    match context {
+        ChainedSequenceContext::Format1(table) => {
+            if coverage_contains(table.coverage(), gid) {
+                roles.insert("input");
+            }
+        }
+        ChainedSequenceContext::Format2(table) => {
+            if coverage_contains(table.coverage(), gid) {
+                roles.insert("input");
+            }
+        }
+        ChainedSequenceContext::Format3(table) => {
+            if coverages_contain(table.input_coverages().iter(), gid) {
+                roles.insert("input");
+            }
+            if coverages_contain(table.backtrack_coverages().iter(), gid)
+                || coverages_contain(table.lookahead_coverages().iter(), gid)
+            {
+                roles.insert("context");
+            }
+        }
+    }
+}
+
+/// Roles of a glyph within the subtables of a GSUB lookup
+fn gsub_roles(subtables: &SubstitutionSubtables, gid: GlyphId16) -> BTreeSet<&'static str> {
+    let mut roles = BTreeSet::new();
+
+    match subtables {
+        SubstitutionSubtables::Single(subtables) => {
+            for subtable in subtables.iter().filter_map(|s| s.ok()) {
+                use read_fonts::tables::gsub::SingleSubst;
+                match subtable {
+                    SingleSubst::Format1(table) => {
+                        if let Ok(coverage) = table.coverage() {
+                            let delta = table.delta_glyph_id() as i32;
+                            for input in coverage.iter() {
+                                if input == gid {
+                                    roles.insert("input");
+                                }
+                                let output = (input.to_u16() as i32 + delta).rem_euclid(0x10000) as u16;
+                                if output == gid.to_u16() {
+                                    roles.insert("output");
+                                }
+                            }
+                        }
+                    }
+                    SingleSubst::Format2(table) => {
+                        if coverage_contains(table.coverage(), gid) {
+                            roles.insert("input");
+                        }
+                        if table.substitute_glyph_ids().iter().any(|g| g.get() == gid) {
+                            roles.insert("output");
+                        }
+                    }
+                }
+            }
+        }
+        SubstitutionSubtables::Multiple(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.coverage(), gid) {
+                    roles.insert("input");
+                }
+                let produces = table.sequences().iter().filter_map(|s| s.ok()).any(|sequence| {
+                    sequence.substitute_glyph_ids().iter().any(|g| g.get() == gid)
+                });
+                if produces {
+                    roles.insert("output");
+                }
+            }
+        }
+        SubstitutionSubtables::Alternate(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.coverage(), gid) {
+                    roles.insert("input");
+                }
+                let produces = table.alternate_sets().iter().filter_map(|s| s.ok()).any(|set| {
+                    set.alternate_glyph_ids().iter().any(|g| g.get() == gid)
+                });
+                if produces {
+                    roles.insert("output");
+                }
+            }
+        }
+        SubstitutionSubtables::Ligature(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.coverage(), gid) {
+                    roles.insert("input");
+                }
+                for set in table.ligature_sets().iter().filter_map(|s| s.ok()) {
+                    for ligature in set.ligatures().iter().filter_map(|l| l.ok()) {
+                        if ligature.ligature_glyph() == gid {
+                            roles.insert("output");
+                        }
+                        if ligature.component_glyph_ids().iter().any(|g| g.get() == gid) {
+                            roles.insert("input");
+                        }
+                    }
+                }
+            }
+        }
+        SubstitutionSubtables::Contextual(subtables) => {
+            for context in subtables.iter().filter_map(|s| s.ok()) {
+                sequence_context_roles(&context, gid, &mut roles);
+            }
+        }
+        SubstitutionSubtables::ChainContextual(subtables) => {
+            for context in subtables.iter().filter_map(|s| s.ok()) {
+                chained_context_roles(&context, gid, &mut roles);
+            }
+        }
+        SubstitutionSubtables::Reverse(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.coverage(), gid) {
+                    roles.insert("input");
+                }
+                if table.substitute_glyph_ids().iter().any(|g| g.get() == gid) {
+                    roles.insert("output");
+                }
+                if coverages_contain(table.backtrack_coverages().iter(), gid)
+                    || coverages_contain(table.lookahead_coverages().iter(), gid)
+                {
+                    roles.insert("context");
+                }
+            }
+        }
+    }
+
+    roles
+}
+
+/// Roles of a glyph within the subtables of a GPOS lookup
+fn gpos_roles(subtables: &PositionSubtables, gid: GlyphId16) -> BTreeSet<&'static str> {
+    let mut roles = BTreeSet::new();
+
+    match subtables {
+        PositionSubtables::Single(subtables) => {
+            for subtable in subtables.iter().filter_map(|s| s.ok()) {
+                use read_fonts::tables::gpos::SinglePos;
+                let coverage = match &subtable {
+                    SinglePos::Format1(table) => table.coverage(),
+                    SinglePos::Format2(table) => table.coverage(),
+                };
+                if coverage_contains(coverage, gid) {
+                    roles.insert("input");
+                }
+            }
+        }
+        PositionSubtables::Pair(subtables) => {
+            for subtable in subtables.iter().filter_map(|s| s.ok()) {
+                use read_fonts::tables::gpos::PairPos;
+                match &subtable {
+                    PairPos::Format1(table) => {
+                        if coverage_contains(table.coverage(), gid) {
+                            roles.insert("first");
+                        }
+                        let is_second = table.pair_sets().iter().filter_map(|s| s.ok()).any(|set| {
+                            set.pair_value_records()
+                                .iter()
+                                .filter_map(|r| r.ok())
+                                .any(|record| record.second_glyph() == gid)
+                        });
+                        if is_second {
+                            roles.insert("second");
+                        }
+                    }
+                    PairPos::Format2(table) => {
+                        if coverage_contains(table.coverage(), gid) {
+                            roles.insert("first");
+                        }
+                        if let Ok(class_def) = table.class_def2() {
+                            if class_def.get(gid) != 0 {
+                                roles.insert("second");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        PositionSubtables::Cursive(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.coverage(), gid) {
+                    roles.insert("cursive");
+                }
+            }
+        }
+        PositionSubtables::MarkToBase(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.mark_coverage(), gid) {
+                    roles.insert("mark");
+                }
+                if coverage_contains(table.base_coverage(), gid) {
+                    roles.insert("base");
+                }
+            }
+        }
+        PositionSubtables::MarkToLig(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.mark_coverage(), gid) {
+                    roles.insert("mark");
+                }
+                if coverage_contains(table.ligature_coverage(), gid) {
+                    roles.insert("ligature");
+                }
+            }
+        }
+        PositionSubtables::MarkToMark(subtables) => {
+            for table in subtables.iter().filter_map(|s| s.ok()) {
+                if coverage_contains(table.mark1_coverage(), gid) {
+                    roles.insert("mark");
+                }
+                if coverage_contains(table.mark2_coverage(), gid) {
+                    roles.insert("baseMark");
+                }
+            }
+        }
+        PositionSubtables::Contextual(subtables) => {
+            for context in subtables.iter().filter_map(|s| s.ok()) {
+                sequence_context_roles(&context, gid, &mut roles);
+            }
+        }
+        PositionSubtables::ChainContextual(subtables) => {
+            for context in subtables.iter().filter_map(|s| s.ok()) {
+                chained_context_roles(&context, gid, &mut roles);
+            }
+        }
+    }
+
+    roles
+}
+
+/// OpenType lookup type number for a set of GSUB subtables
+pub(crate) fn gsub_lookup_type(subtables: &SubstitutionSubtables) -> u16 {
+    match subtables {
+        SubstitutionSubtables::Single(_) => 1,
+        SubstitutionSubtables::Multiple(_) => 2,
+        SubstitutionSubtables::Alternate(_) => 3,
+        SubstitutionSubtables::Ligature(_) => 4,
+        SubstitutionSubtables::Contextual(_) => 5,
+        SubstitutionSubtables::ChainContextual(_) => 6,
+        SubstitutionSubtables::Reverse(_) => 8,
+    }
+}
+
+/// OpenType lookup type number for a set of GPOS subtables
+pub(crate) fn gpos_lookup_type(subtables: &PositionSubtables) -> u16 {
+    match subtables {
+        PositionSubtables::Single(_) => 1,
+        PositionSubtables::Pair(_) => 2,
+        PositionSubtables::Cursive(_) => 3,
+        PositionSubtables::MarkToBase(_) => 4,
+        PositionSubtables::MarkToLig(_) => 5,
+        PositionSubtables::MarkToMark(_) => 6,
+        PositionSubtables::Contextual(_) => 7,
+        PositionSubtables::ChainContextual(_) => 8,
+    }
+}
+
+/// Get every GSUB/GPOS lookup that references a glyph, with the features using it
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "glyph": "a.ss01",
+///   "gid": 84,
+///   "lookups": [
+///     { "table": "GSUB", "lookupIndex": 3, "lookupType": 1,
+///       "features": ["ss01"], "roles": ["output"] },
+///     { "table": "GPOS", "lookupIndex": 0, "lookupType": 2,
+///       "features": ["kern"], "roles": ["first", "second"] }
+///   ]
+/// }
+/// ```
+///
+/// Roles describe how the glyph takes part in the lookup: `input`, `output`
+/// and `context` for substitutions, `first`/`second` for pair positioning and
+/// `mark`/`base`/`ligature`/`baseMark`/`cursive` for attachment. Lookups only
+/// reachable from contextual lookups have an empty `features` list. For
+/// format 1/2 contextual subtables only the first input glyph is inspected.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `glyph_name` - Name of the glyph to trace
+///
+/// # Returns
+/// * `String` - JSON object listing the lookups referencing the glyph
+#[wasm_bindgen]
+pub fn get_lookups_for_glyph(font_bytes: &[u8], glyph_name: &str) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let glyph_order = read_glyph_order(&font)?;
+    let gid = glyph_order
+        .iter()
+        .position(|name| name == glyph_name)
+        .map(|gid| GlyphId16::new(gid as u16))
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+
+    let mut lookups = Vec::new();
+
+    if let Ok(gsub) = font.gsub() {
+        let features = gsub.feature_list().map(|list| feature_lookup_map(&list)).unwrap_or_default();
+        if let Ok(lookup_list) = gsub.lookup_list() {
+            for (index, lookup) in lookup_list.lookups().iter().enumerate() {
+                let Ok(subtables) = lookup.and_then(|lookup| lookup.subtables()) else { continue };
+                let roles = gsub_roles(&subtables, gid);
+                if !roles.is_empty() {
+                    lookups.push(serde_json::json!({
+                        "table": "GSUB",
+                        "lookupIndex": index,
+                        "lookupType": gsub_lookup_type(&subtables),
+                        "features": features.get(&(index as u16)).cloned().unwrap_or_default(),
+                        "roles": roles,
+                    }));
+                }
+            }
+        }
+    }
+
+    if let Ok(gpos) = font.gpos() {
+        let features = gpos.feature_list().map(|list| feature_lookup_map(&list)).unwrap_or_default();
+        if let Ok(lookup_list) = gpos.lookup_list() {
+            for (index, lookup) in lookup_list.lookups().iter().enumerate() {
+                let Ok(subtables) = lookup.and_then(|lookup| lookup.subtables()) else { continue };
+                let roles = gpos_roles(&subtables, gid);
+                if !roles.is_empty() {
+                    lookups.push(serde_json::json!({
+                        "table": "GPOS",
+                        "lookupIndex": index,
+                        "lookupType": gpos_lookup_type(&subtables),
+                        "features": features.get(&(index as u16)).cloned().unwrap_or_default(),
+                        "roles": roles,
+                    }));
+                }
+            }
+        }
+    }
+
+    let result = serde_json::json!({
+        "glyph": glyph_name,
+        "gid": gid.to_u16(),
+        "lookups": lookups,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("lookups", e).into())
+}
+
+/// A substitution performed by a GSUB lookup, used to find related glyphs
+pub(crate) struct SubstitutionEdge {
+    pub lookup_index: u16,
+    /// "single", "multiple", "alternate" or "ligature"
+    pub kind: &'static str,
+    pub input: Vec<GlyphId16>,
+    pub output: Vec<GlyphId16>,
+}
+
+/// Collect every single, multiple, alternate and ligature substitution in a GSUB table
+pub(crate) fn gsub_substitution_edges(font: &FontRef) -> Vec<SubstitutionEdge> {
+    use read_fonts::tables::gsub::SingleSubst;
+
+    let mut edges = Vec::new();
+    let Ok(gsub) = font.gsub() else { return edges };
+    let Ok(lookup_list) = gsub.lookup_list() else { return edges };
+
+    for (index, lookup) in lookup_list.lookups().iter().enumerate() {
+        let lookup_index = index as u16;
+        let Ok(subtables) = lookup.and_then(|lookup| lookup.subtables()) else { continue };
+
+        match subtables {
+            SubstitutionSubtables::Single(subtables) => {
+                for subtable in subtables.iter().filter_map(|s| s.ok()) {
+                    match subtable {
+                        SingleSubst::Format1(table) => {
+                            let Ok(coverage) = table.coverage() else { continue };
+                            let delta = table.delta_glyph_id() as i32;
+                            for input in coverage.iter() {
+                                let output = (input.to_u16() as i32 + delta).rem_euclid(0x10000) as u16;
+                                edges.push(SubstitutionEdge {
+                                    lookup_index,
+                                    kind: "single",
+                                    input: vec![input],
+                                    output: vec![GlyphId16::new(output)],
+                                });
+                            }
+                        }
+                        SingleSubst::Format2(table) => {
+                            let Ok(coverage) = table.coverage() else { continue };
+                            for (input, output) in coverage.iter().zip(table.substitute_glyph_ids().iter()) {
+                                edges.push(SubstitutionEdge {
+                                    lookup_index,
+                                    kind: "single",
+                                    input: vec![input],
+                                    output: vec![output.get()],
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            SubstitutionSubtables::Multiple(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Ok(coverage) = table.coverage() else { continue };
+                    for (input, sequence) in coverage.iter().zip(table.sequences().iter()) {
+                        let Ok(sequence) = sequence else { continue };
+                        edges.push(SubstitutionEdge {
+                            lookup_index,
+                            kind: "multiple",
+                            input: vec![input],
+                            output: sequence.substitute_glyph_ids().iter().map(|g| g.get()).collect(),
+                        });
+                    }
+                }
+            }
+            SubstitutionSubtables::Alternate(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Ok(coverage) = table.coverage() else { continue };
+                    for (input, set) in coverage.iter().zip(table.alternate_sets().iter()) {
+                        let Ok(set) = set else { continue };
+                        for alternate in set.alternate_glyph_ids().iter() {
+                            edges.push(SubstitutionEdge {
+                                lookup_index,
+                                kind: "alternate",
+                                input: vec![input],
+                                output: vec![alternate.get()],
+                            });
+                        }
+                    }
+                }
+            }
+            SubstitutionSubtables::Ligature(subtables) => {
+                for table in subtables.iter().filter_map(|s| s.ok()) {
+                    let Ok(coverage) = table.coverage() else { continue };
+                    for (first, set) in coverage.iter().zip(table.ligature_sets().iter()) {
+                        let Ok(set) = set else { continue };
+                        for ligature in set.ligatures().iter().filter_map(|l| l.ok()) {
+                            let mut input = vec![first];
+                            input.extend(ligature.component_glyph_ids().iter().map(|g| g.get()));
+                            edges.push(SubstitutionEdge {
+                                lookup_index,
+                                kind: "ligature",
+                                input,
+                                output: vec![ligature.ligature_glyph()],
+                            });
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    edges
+}
+
+/// Get ligatures, alternates and stylistic variants related to a glyph
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "glyph": "a",
+///   "ligatures": [{ "glyph": "f_i", "components": ["f", "i"], "features": ["liga"] }],
+///   "ligatureComponentOf": [{ "glyph": "a_t", "components": ["a", "t"], "features": ["dlig"] }],
+///   "alternates": [
+///     { "glyph": "a.ss01", "kind": "single", "features": ["ss01", "salt"] },
+///     { "glyph": "a.swsh", "kind": "alternate", "features": ["aalt"] }
+///   ],
+///   "alternateOf": [{ "glyph": "a", "kind": "single", "features": ["ss01"] }]
+/// }
+/// ```
+///
+/// `ligatures` lists ligatures that produce the glyph, `ligatureComponentOf`
+/// ligatures the glyph is a component of. `alternates` lists glyphs the glyph
+/// can be substituted with (single and alternate substitutions, e.g. aalt,
+/// salt, ssXX, cvXX), `alternateOf` the glyphs it is an alternate of.
+/// Substitutions only reachable from contextual lookups have empty `features`.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `glyph_name` - Name of the glyph
+///
+/// # Returns
+/// * `String` - JSON object listing related glyphs
+#[wasm_bindgen]
+pub fn get_glyph_alternates(font_bytes: &[u8], glyph_name: &str) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let glyph_order = read_glyph_order(&font)?;
+    let gid = glyph_order
+        .iter()
+        .position(|name| name == glyph_name)
+        .map(|gid| GlyphId16::new(gid as u16))
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+
+    let features = font
+        .gsub()
+        .ok()
+        .and_then(|gsub| gsub.feature_list().ok())
+        .map(|list| feature_lookup_map(&list))
+        .unwrap_or_default();
+
+    let name = |gid: GlyphId16| crate::font_reader::glyph_name_for(&glyph_order, gid);
+    let names = |gids: &[GlyphId16]| gids.iter().map(|g| name(*g)).collect::<Vec<_>>();
+
+    // Merge entries for the same related glyph, accumulating their features
+    fn add_related(
+        list: &mut Vec<serde_json::Value>,
+        glyph: String,
+        kind: &str,
+        components: Option<Vec<String>>,
+        lookup_features: &[String],
+    ) {
+        let existing = list.iter_mut().find(|entry| {
+            entry["glyph"] == glyph.as_str()
+                && entry.get("kind").map_or(true, |k| k == kind)
+                && components.as_ref().map_or(true, |c| entry["components"] == serde_json::json!(c))
+        });
+        match existing {
+            Some(entry) => {
+                if let Some(entry_features) = entry["features"].as_array_mut() {
+                    for feature in lookup_features {
+                        if !entry_features.iter().any(|f| f == feature.as_str()) {
+                            entry_features.push(feature.as_str().into());
+                        }
+                    }
+                }
+            }
+            None => {
+                let mut entry = serde_json::json!({ "glyph": glyph, "features": lookup_features });
+                match components {
+                    Some(components) => entry["components"] = serde_json::json!(components),
+                    None => entry["kind"] = kind.into(),
+                }
+                list.push(entry);
+            }
+        }
+    }
+
+    let mut ligatures = Vec::new();
+    let mut ligature_component_of = Vec::new();
+    let mut alternates = Vec::new();
+    let mut alternate_of = Vec::new();
+
+    for edge in gsub_substitution_edges(&font) {
+        let lookup_features = features.get(&edge.lookup_index).cloned().unwrap_or_default();
+
+        match edge.kind {
+            "ligature" => {
+                if edge.output.contains(&gid) {
+                    add_related(&mut ligatures, name(edge.output[0]), "ligature", Some(names(&edge.input)), &lookup_features);
+                }
+                if edge.input.contains(&gid) {
+                    add_related(&mut ligature_component_of, name(edge.output[0]), "ligature", Some(names(&edge.input)), &lookup_features);
+                }
+            }
+            "single" | "alternate" => {
+                if edge.input[0] == gid && edge.output[0] != gid {
+                    add_related(&mut alternates, name(edge.output[0]), edge.kind, None, &lookup_features);
+                }
+                if edge.output[0] == gid && edge.input[0] != gid {
+                    add_related(&mut alternate_of, name(edge.input[0]), edge.kind, None, &lookup_features);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let result = serde_json::json!({
+        "glyph": glyph_name,
+        "ligatures": ligatures,
+        "ligatureComponentOf": ligature_component_of,
+        "alternates": alternates,
+        "alternateOf": alternate_of,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("glyph alternates", e).into())
+}
+
+/// Serialize an anchor table (coordinates in font units)
+fn anchor_json(anchor: &AnchorTable) -> serde_json::Value {
+    match anchor {
+        AnchorTable::Format1(a) => serde_json::json!({
+            "x": a.x_coordinate(),
+            "y": a.y_coordinate(),
+        }),
+        AnchorTable::Format2(a) => serde_json::json!({
+            "x": a.x_coordinate(),
+            "y": a.y_coordinate(),
+            "pointIndex": a.anchor_point(),
+        }),
+        AnchorTable::Format3(a) => serde_json::json!({
+            "x": a.x_coordinate(),
+            "y": a.y_coordinate(),
+            "hasDevice": a.x_device().is_some() || a.y_device().is_some(),
+        }),
+    }
+}
+
+/// Serialize the mark records of a mark array keyed by glyph name
+fn mark_array_json(
+    coverage: Result<CoverageTable, ReadError>,
+    mark_array: Result<MarkArray, ReadError>,
+    glyph_order: &[String],
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut marks = serde_json::Map::new();
+    let (Ok(coverage), Ok(mark_array)) = (coverage, mark_array) else { return marks };
+
+    for (gid, record) in coverage.iter().zip(mark_array.mark_records().iter()) {
+        let Ok(anchor) = record.mark_anchor(mark_array.offset_data()) else { continue };
+        let mut entry = anchor_json(&anchor);
+        entry["class"] = record.mark_class().into();
+        marks.insert(crate::font_reader::glyph_name_for(glyph_order, gid), entry);
+    }
+
+    marks
+}
+
+/// Get mark attachment anchors from the GPOS table of compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "markToBase": [
+///     { "lookupIndex": 4, "features": ["mark"], "markClassCount": 2,
+///       "marks": { "acutecomb": { "class": 0, "x": 120, "y": 510 } },
+///       "bases": { "a": [{ "class": 0, "x": 270, "y": 540 }, null] } }
+///   ],
+///   "markToMark": [
+///     { "lookupIndex": 5, "features": ["mkmk"], "markClassCount": 1,
+///       "marks": { "acutecomb": { "class": 0, "x": 120, "y": 700 } },
+///       "baseMarks": { "dieresiscomb": [{ "class": 0, "x": 150, "y": 720 }] } }
+///   ]
+/// }
+/// ```
+///
+/// Base and base-mark anchors are listed per mark class; `null` means the
+/// glyph has no anchor for that class.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+///
+/// # Returns
+/// * `String` - JSON object describing mark-to-base and mark-to-mark attachment
+#[wasm_bindgen]
+pub fn get_mark_attachment(font_bytes: &[u8]) -> Result<String, JsValue> {
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+
+    let glyph_order = read_glyph_order(&font)?;
+    let name = |gid: GlyphId16| crate::font_reader::glyph_name_for(&glyph_order, gid);
+
+    let mut mark_to_base = Vec::new();
+    let mut mark_to_mark = Vec::new();
+
+    if let Ok(gpos) = font.gpos() {
+        let features = gpos.feature_list().map(|list| feature_lookup_map(&list)).unwrap_or_default();
+        if let Ok(lookup_list) = gpos.lookup_list() {
+            for (index, lookup) in lookup_list.lookups().iter().enumerate() {
+                let Ok(subtables) = lookup.and_then(|lookup| lookup.subtables()) else { continue };
+                let lookup_features = features.get(&(index as u16)).cloned().unwrap_or_default();
+
+                match subtables {
+                    PositionSubtables::MarkToBase(subtables) => {
+                        for table in subtables.iter().filter_map(|s| s.ok()) {
+                            let marks = mark_array_json(table.mark_coverage(), table.mark_array(), &glyph_order);
+                            let mut bases = serde_json::Map::new();
+                            if let (Ok(coverage), Ok(base_array)) = (table.base_coverage(), table.base_array()) {
+                                for (gid, record) in coverage.iter().zip(base_array.base_records().iter()) {
+                                    let Ok(record) = record else { continue };
+                                    let anchors: Vec<serde_json::Value> = record
+                                        .base_anchors(base_array.offset_data())
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(class, anchor)| match anchor {
+                                            Some(Ok(anchor)) => {
+                                                let mut entry = anchor_json(&anchor);
+                                                entry["class"] = class.into();
+                                                entry
+                                            }
+                                            _ => serde_json::Value::Null,
+                                        })
+                                        .collect();
+                                    bases.insert(name(gid), anchors.into());
+                                }
+                            }
+                            mark_to_base.push(serde_json::json!({
+                                "lookupIndex": index,
+                                "features": lookup_features,
+                                "markClassCount": table.mark_class_count(),
+                                "marks": marks,
+                                "bases": bases,
+                            }));
+                        }
+                    }
+                    PositionSubtables::MarkToMark(subtables) => {
+                        for table in subtables.iter().filter_map(|s| s.ok()) {
+                            let marks = mark_array_json(table.mark1_coverage(), table.mark1_array(), &glyph_order);
+                            let mut base_marks = serde_json::Map::new();
+                            if let (Ok(coverage), Ok(mark2_array)) = (table.mark2_coverage(), table.mark2_array()) {
+                                for (gid, record) in coverage.iter().zip(mark2_array.mark2_records().iter()) {
+                                    let Ok(record) = record else { continue };
+                                    let anchors: Vec<serde_json::Value> = record
+                                        .mark2_anchors(mark2_array.offset_data())
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(class, anchor)| match anchor {
+                                            Some(Ok(anchor)) => {
+                                                let mut entry = anchor_json(&anchor);
+                                                entry["class"] = class.into();
+                                                entry
+                                            }
+                                            _ => serde_json::Value::Null,
+                                        })
+                                        .collect();
+                                    base_marks.insert(name(gid), anchors.into());
+                                }
+                            }
+                            mark_to_mark.push(serde_json::json!({
+                                "lookupIndex": index,
+                                "features": lookup_features,
+                                "markClassCount": table.mark_class_count(),
+                                "marks": marks,
+                                "baseMarks": base_marks,
+                            }));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let result = serde_json::json!({
+        "markToBase": mark_to_base,
+        "markToMark": mark_to_mark,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("mark attachment", e).into())
+}
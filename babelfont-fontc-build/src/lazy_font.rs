@@ -0,0 +1,189 @@
+// Lazy font loading module
+//
+// This module opens .babelfont sources without materializing every glyph:
+// the file is scanned once, top-level font data is kept as raw JSON and each
+// glyph record is stored as its raw JSON text together with the few fields
+// the glyph list needs (name, codepoints, category, export flag). Glyphs are
+// deserialized on first access, and the whole font only when it is
+// materialized into the font cache.
+
+use serde_json::value::RawValue;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+use crate::errors::ApiError;
+
+/// A glyph record of a lazily loaded font
+struct LazyGlyph {
+    name: String,
+    codepoints: Vec<u32>,
+    category: String,
+    exported: bool,
+    /// Raw JSON of the glyph record
+    raw: String,
+    /// The deserialized glyph, once accessed
+    parsed: Option<babelfont::Glyph>,
+}
+
+impl LazyGlyph {
+    /// Read the summary fields of a raw glyph record, skipping its layers
+    fn from_raw(raw: &RawValue) -> Result<Self, String> {
+        let fields: HashMap<String, &RawValue> = serde_json::from_str(raw.get())
+            .map_err(|e| format!("Invalid glyph record: {}", e))?;
+        let field = |key: &str| fields.get(key).map(|value| value.get());
+
+        let name: String = field("name")
+            .ok_or("Glyph record without a name")
+            .and_then(|value| serde_json::from_str(value).map_err(|_| "Invalid glyph name"))?;
+        let codepoints: Vec<u32> = match field("codepoints") {
+            Some(value) => serde_json::from_str::<Option<Vec<u32>>>(value)
+                .map_err(|e| format!("Invalid codepoints of glyph '{}': {}", name, e))?
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+        let category = field("category")
+            .and_then(|value| serde_json::from_str::<String>(value).ok())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let exported = field("exported")
+            .and_then(|value| serde_json::from_str::<bool>(value).ok())
+            .unwrap_or(true);
+
+        Ok(LazyGlyph {
+            name,
+            codepoints,
+            category,
+            exported,
+            raw: raw.get().to_string(),
+            parsed: None,
+        })
+    }
+
+    fn summary_json(&self) -> JsonValue {
+        serde_json::json!({
+            "name": self.name,
+            "codepoints": self.codepoints,
+            "category": self.category,
+            "exported": self.exported,
+        })
+    }
+}
+
+/// A .babelfont source whose glyphs are deserialized on demand
+pub struct LazyFont {
+    /// Raw JSON of every top-level field except the glyphs
+    fields: Vec<(String, String)>,
+    glyphs: Vec<LazyGlyph>,
+    index: HashMap<String, usize>,
+}
+
+impl LazyFont {
+    /// Scan .babelfont JSON without deserializing glyph layers
+    pub fn from_babelfont_json(contents: &str) -> Result<Self, String> {
+        let top: HashMap<String, &RawValue> = serde_json::from_str(contents)
+            .map_err(|e| format!("Failed to parse .babelfont JSON: {}", e))?;
+
+        let mut fields = Vec::with_capacity(top.len());
+        let mut glyphs = Vec::new();
+        for (key, value) in top {
+            if key == "glyphs" {
+                let records: Vec<&RawValue> = serde_json::from_str(value.get())
+                    .map_err(|e| format!("Failed to parse glyph list: {}", e))?;
+                glyphs = records
+                    .into_iter()
+                    .map(LazyGlyph::from_raw)
+                    .collect::<Result<_, _>>()?;
+            } else {
+                fields.push((key, value.get().to_string()));
+            }
+        }
+
+        let index = glyphs
+            .iter()
+            .enumerate()
+            .map(|(index, glyph)| (glyph.name.clone(), index))
+            .collect();
+
+        Ok(LazyFont { fields, glyphs, index })
+    }
+
+    /// Font information and the glyph list, without glyph layers
+    ///
+    /// Top-level font fields (axes, masters, names, features, ...) are
+    /// included as in the source; `glyphs` lists each glyph's name,
+    /// codepoints, category and export flag.
+    pub fn info_json(&self) -> Result<JsonValue, String> {
+        let mut info = serde_json::Map::new();
+        for (key, raw) in &self.fields {
+            let value = serde_json::from_str(raw).map_err(|e| format!("Invalid field '{}': {}", key, e))?;
+            info.insert(key.clone(), value);
+        }
+        info.insert(
+            "glyphs".to_string(),
+            JsonValue::Array(self.glyphs.iter().map(LazyGlyph::summary_json).collect()),
+        );
+        Ok(JsonValue::Object(info))
+    }
+
+    /// Deserialize a glyph, keeping the result for later accesses
+    pub fn glyph(&mut self, name: &str) -> Result<&babelfont::Glyph, ApiError> {
+        let index = *self.index.get(name).ok_or_else(|| ApiError::glyph_not_found(name))?;
+        let glyph = &mut self.glyphs[index];
+        if glyph.parsed.is_none() {
+            let parsed = serde_json::from_str(&glyph.raw)
+                .map_err(|e| ApiError::InvalidSource {
+                    format: "babelfont".to_string(),
+                    detail: format!("Failed to parse glyph '{}': {}", name, e),
+                })?;
+            glyph.parsed = Some(parsed);
+        }
+        Ok(glyph.parsed.as_ref().unwrap())
+    }
+
+    /// Deserialize the complete font
+    pub fn materialize(&self) -> Result<babelfont::Font, String> {
+        let mut json = String::with_capacity(
+            self.fields.iter().map(|(key, raw)| key.len() + raw.len() + 4).sum::<usize>()
+                + self.glyphs.iter().map(|glyph| glyph.raw.len() + 1).sum::<usize>()
+                + 16,
+        );
+        json.push('{');
+        for (key, raw) in &self.fields {
+            json.push_str(&JsonValue::String(key.clone()).to_string());
+            json.push(':');
+            json.push_str(raw);
+            json.push(',');
+        }
+        json.push_str("\"glyphs\":[");
+        for (index, glyph) in self.glyphs.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(&glyph.raw);
+        }
+        json.push_str("]}");
+
+        serde_json::from_str(&json).map_err(|e| format!("JSON parse error: {}", e))
+    }
+}
+
+/// Font information and glyph list of a fully loaded font, in the same
+/// format as `LazyFont::info_json`
+pub fn font_info_json(font: &babelfont::Font) -> Result<JsonValue, String> {
+    let mut info = serde_json::to_value(font).map_err(|e| format!("Failed to serialize font: {}", e))?;
+    let glyphs: Vec<JsonValue> = font
+        .glyphs
+        .iter()
+        .map(|glyph| {
+            serde_json::json!({
+                "name": glyph.name.to_string(),
+                "codepoints": glyph.codepoints,
+                "category": serde_json::to_value(&glyph.category).unwrap_or(JsonValue::Null),
+                "exported": glyph.exported,
+            })
+        })
+        .collect();
+    if let Some(info) = info.as_object_mut() {
+        info.insert("glyphs".to_string(), JsonValue::Array(glyphs));
+    }
+    Ok(info)
+}
@@ -5,25 +5,165 @@ use babelfont::{
 };
 use wasm_bindgen::prelude::*;
 use std::sync::Mutex;
-use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use fontdrasil::coords::{DesignCoord, DesignLocation};
 use write_fonts::types::Tag;
 use std::str::FromStr;
 use serde_json::Value as JsonValue;
 
+use crate::errors::ApiError;
+use crate::font_sessions::FontChange;
+
 // Font reading module (using read-fonts/skrifa)
 mod font_reader;
-pub use font_reader::{get_font_axes, get_font_features, get_glyph_name, get_glyph_order, get_stylistic_set_names};
+pub use font_reader::{get_character_coverage, get_font_axes, get_feature_ui_names, get_font_features, get_gdef_info, get_glyph_bounds, get_glyph_metrics, get_glyph_name, get_glyph_order, get_layout_coverage, get_stat_table, get_stylistic_set_names, get_variation_sequences};
+
+// Layout table inspection module (GSUB/GPOS lookups)
+mod layout_reader;
+pub use layout_reader::{get_glyph_alternates, get_lookups_for_glyph, get_mark_attachment};
+
+// Text shaping module (using harfrust)
+mod shaping;
+pub use shaping::{diff_shaping, shape_text, shape_text_trace};
+
+// GSUB-only substitution previews on glyph name sequences
+mod gsub_preview;
+
+// Script, direction and language itemization of text
+mod itemize;
+pub use itemize::itemize_text;
+
+// Multi-line text layout module (line breaking and wrapping)
+mod text_layout;
+pub use text_layout::layout_text;
+
+// Text rendering module (SVG output from compiled fonts)
+mod text_render;
+pub use text_render::render_text_svg;
+
+// Glyph and text rasterization module
+mod raster;
+pub use raster::rasterize;
+
+// Static instances of compiled variable fonts
+mod font_instancer;
+pub use font_instancer::instance_compiled_font;
+
+// Subsetting of compiled fonts (webfont slices)
+mod font_subsetter;
+pub use font_subsetter::subset_compiled_font;
+
+// Compiled font validation module
+mod font_validation;
+pub use font_validation::{check_vertical_metrics, run_qa_checks, validate_compiled_font};
 
 // Interpolation module
 mod interpolation;
 
+// Named character sets (GF glyphsets, Adobe Latin)
+mod charsets;
+// Embedded Unicode character data (blocks, categories, names) and character lookups
+mod unicode_data;
+pub use unicode_data::{get_char_info, suggest_glyph_name};
+// Glyph queries for the smart filters of the glyph overview
+mod glyph_query;
+// Per-language character requirements and language support of a font
+mod languages;
+
+// Source font QA checks (path directions, contours, components, transforms, anchors, Unicode, masters, designspace, outline stats, glyphset coverage)
+mod source_checks;
+
 // Glyph outlines module
 mod glyph_outlines;
 
+// Byte-budgeted LRU cache used by the outline and layer caches
+mod lru_cache;
+
+// Forward and reverse component references of the cached font
+mod component_graph;
+
+// Lazy .babelfont loading (glyphs deserialized on demand)
+mod lazy_font;
+
+// Unicode Variation Sequences (cmap format 14) module
+mod variation_sequences;
+
+// COLRv1 paint graphs on source layers, compiled into COLR/CPAL
+mod color_paints;
+// Flattened color glyph previews (paint graphs as filled SVG paths)
+mod color_preview;
+
+// PNG bitmap strikes on source glyphs, compiled into sbix or CBDT/CBLC
+mod bitmap_strikes;
+
+// Stylistic set / character variant UI parameters in feature code
+mod feature_params;
+// Feature code parsing, syntax and reference validation without compiling
+mod feature_syntax;
+// Mark and mark-to-mark feature code generated from source anchors
+mod mark_features;
+// Default features inferred from glyph naming (ccmp, liga, numr, dnom)
+mod feature_writer;
+// Named glyph classes of the feature code (create, edit members, usages)
+mod feature_classes;
+// include() resolution in feature code from registered files or a JS provider
+mod feature_includes;
+// Standalone feature file export with Glyphs tokens expanded
+mod feature_export;
+// Boolean path operations on source outlines (overlap removal, path combination, offsetting)
+mod path_ops;
+
+// In-place outline filters (coordinate rounding, path simplification, curve harmonization, slanting, corner rounding, curve conversion, UPM scaling)
+mod outline_filters;
+// Creation of required glyphs (.notdef, .null, spaces, CR)
+mod standard_glyphs;
+// Background layers (copy, swap, clear, interpolation at a location)
+mod backgrounds;
+// Sidebearing and advance width operations on source layers (auto spacing, metrics commands)
+mod spacing;
+// Glyphs-style metrics keys linking sidebearings and widths between glyphs
+mod metrics_keys;
+// Composite glyph construction from Unicode decompositions and anchors
+mod composites;
+// Production glyph names and renaming between naming conventions
+mod production_names;
+// Copying and pasting shapes through a compact clipboard payload
+mod clipboard;
+
+// Kerning utilities (group resolution, conflict checks, flattening, pair lookup and editing, FEA/AFM exchange)
+mod kerning;
+// Kerning suggestions from outline geometry (area between profiles)
+mod auto_kerning;
+
+// Cache of compiled GSUB/GPOS/GDEF tables reused across recompiles
+mod feature_cache;
+
+// Comparison of two source fonts (glyphs, kerning, metrics, axes, features)
+mod font_diff;
+// Copying glyphs with their components and kerning between fonts
+mod font_merge;
+// Snapshots of source fonts and deltas since them, for autosave
+mod font_snapshots;
+
+// Proof text and layout generation (kerning strings, spacing strings, waterfalls, glyph grids)
+mod proofs;
+
+// Structured errors of the exported functions (code, message, context)
+mod errors;
+// Log messages routed to a JS handler or the console
+mod logging;
+
+// Open source fonts by handle, for several concurrent font sessions
+mod font_sessions;
+
 // Global storage for cached fonts
 // Use a Mutex to allow safe mutable access from multiple calls
-static FONT_CACHE: Mutex<Option<babelfont::Font>> = Mutex::new(None);
+static FONT_CACHE: Mutex<font_sessions::FontSessions> = Mutex::new(font_sessions::FontSessions::new());
+
+// Fonts opened with open_font_file_lazy() that have not been materialized yet, by handle
+static LAZY_FONTS: Mutex<BTreeMap<u32, lazy_font::LazyFont>> = Mutex::new(BTreeMap::new());
+static NEXT_LAZY_ID: AtomicU32 = AtomicU32::new(1);
 
 // Set up panic hook for better error messages
 #[wasm_bindgen(start)]
@@ -31,6 +171,22 @@ pub fn init() {
     console_error_panic_hook::set_once();
 }
 
+/// Set the handler of log messages
+///
+/// Font loading, compile stages and interpolation warnings are reported to
+/// the handler instead of the browser console, so the host app can route
+/// them into its own telemetry and show progress. Each call receives one
+/// record: '{"level": "info" | "warn", "module": "fonts" | "compile" | "interpolation", "message": "...", "payload": {...}}',
+/// where the payload holds the values of the message (e.g. '{"stage": "done", "glyphs": 512, "bytes": 80412, "ms": 950}').
+/// Pass null to log to the console again.
+///
+/// # Arguments
+/// * `callback` - Function called with (record: object), or null
+#[wasm_bindgen]
+pub fn set_log_handler(callback: Option<js_sys::Function>) {
+    logging::set_handler(callback);
+}
+
 fn get_option(options: &JsValue, key: &str, default: bool) -> bool {
     if options.is_undefined() || options.is_null() {
         return default;
@@ -41,6 +197,14 @@ fn get_option(options: &JsValue, key: &str, default: bool) -> bool {
         .unwrap_or(default)
 }
 
+/// Read a string option from a JS object
+fn get_string_option(options: &JsValue, key: &str) -> Option<String> {
+    if options.is_undefined() || options.is_null() {
+        return None;
+    }
+    js_sys::Reflect::get(options, &JsValue::from_str(key)).ok()?.as_string()
+}
+
 /// Compile a font from babelfont JSON directly to TTF
 ///
 /// This is the main entry point that takes a .babelfont JSON string
@@ -55,13 +219,14 @@ fn get_option(options: &JsValue, key: &str, default: bool) -> bool {
 ///  - `skip_outlines`: bool - Skip `glyf`/`gvar` table creation
 ///  - `dont_use_production_names`: bool - Don't use production names for glyphs
 ///  - `subset_glyphs`: String[] - List of glyph names to include
+///  - `bitmap_format`: "sbix" | "cbdt" - Tables for glyph bitmap strikes (default: "sbix")
 ///
 /// # Returns
 /// * `Vec<u8>` - Compiled TTF font bytes
 #[wasm_bindgen]
 pub fn compile_babelfont(babelfont_json: &str, options: &JsValue) -> Result<Vec<u8>, JsValue> {
     let mut font: babelfont::Font = serde_json::from_str(babelfont_json)
-        .map_err(|e| JsValue::from_str(&format!("JSON parse error: {}", e)))?;
+        .map_err(|e| ApiError::invalid_json("font JSON", e))?;
 
     // Handle subset_glyphs option if present
     if !options.is_undefined() && !options.is_null() {
@@ -77,13 +242,17 @@ pub fn compile_babelfont(babelfont_json: &str, options: &JsValue) -> Result<Vec<
                         let subsetter = babelfont::filters::RetainGlyphs::new(subset_glyphs);
                         subsetter
                             .apply(&mut font)
-                            .map_err(|e| JsValue::from_str(&format!("Subsetting failed: {:?}", e)))?;
+                            .map_err(|e| ApiError::compilation("Subsetting", format!("{:?}", e)))?;
                     }
                 }
             }
         }
     }
 
+    let bitmap_format = bitmap_strikes::BitmapFormat::parse(
+        &get_string_option(options, "bitmap_format").unwrap_or_else(|| "sbix".to_string()),
+    )?;
+
     let options = CompilationOptions {
         skip_kerning: get_option(options, "skip_kerning", false),
         skip_features: get_option(options, "skip_features", false),
@@ -92,16 +261,28 @@ pub fn compile_babelfont(babelfont_json: &str, options: &JsValue) -> Result<Vec<
         dont_use_production_names: get_option(options, "dont_use_production_names", false),
     };
 
+    feature_includes::expand_includes(&mut font)?;
+
+    // Collect variation sequences, color glyphs and bitmaps before the font is consumed by the compiler
+    let sequences = variation_sequences::collect_sequences(&font);
+    let color_glyphs = color_paints::collect_color_glyphs(&font);
+    let bitmaps = bitmap_strikes::collect_bitmaps(&font);
+
     let compiled_font = BabelfontIrSource::compile(font, options)
-        .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
+        .map_err(|e| ApiError::compilation("Compilation", format!("{:?}", e)))?;
 
-    Ok(compiled_font)
+    let compiled_font = variation_sequences::add_to_compiled(compiled_font, &sequences)
+        .map_err(|e| ApiError::compilation("Adding variation sequences", e))?;
+    let compiled_font = color_paints::add_to_compiled(compiled_font, &color_glyphs)
+        .map_err(|e| ApiError::compilation("Adding color glyphs", e))?;
+    bitmap_strikes::add_to_compiled(compiled_font, &bitmaps, bitmap_format)
+        .map_err(|e| ApiError::compilation("Adding bitmap strikes", e).into())
 }
 
 /// Legacy function for compatibility
 #[wasm_bindgen]
 pub fn compile_glyphs(_glyphs_json: &str) -> Result<Vec<u8>, JsValue> {
-    Err(JsValue::from_str("Please use compile_babelfont() instead."))
+    Err(ApiError::OperationFailed { message: "Please use compile_babelfont() instead.".to_string() }.into())
 }
 
 /// Get version information
@@ -113,52 +294,253 @@ pub fn version() -> String {
 /// Store a font in memory from babelfont JSON
 ///
 /// This caches the deserialized font for fast access by interpolation
-/// and other operations without re-parsing JSON every time.
+/// and other operations without re-parsing JSON every time. Several fonts
+/// can be stored at once; the stored font becomes the current font, used
+/// by functions called without a font handle. Release fonts that are no
+/// longer needed with close_font().
 ///
 /// # Arguments
 /// * `babelfont_json` - JSON string in .babelfont format
+/// * `font_id` - Handle of a stored font to replace; a new font is stored if omitted
 ///
 /// # Returns
-/// * `Result<(), JsValue>` - Success or error
+/// * `u32` - Handle of the font
 #[wasm_bindgen]
-pub fn store_font(babelfont_json: &str) -> Result<(), JsValue> {
+pub fn store_font(babelfont_json: &str, font_id: Option<u32>) -> Result<u32, JsValue> {
     let font: babelfont::Font = serde_json::from_str(babelfont_json)
-        .map_err(|e| JsValue::from_str(&format!("JSON parse error: {}", e)))?;
-    
-    let mut cache = FONT_CACHE.lock().unwrap();
-    *cache = Some(font);
+        .map_err(|e| ApiError::invalid_json("font JSON", e))?;
     
-    // Clear the outline cache since font changed
-    glyph_outlines::clear_outline_cache();
+    // Storing clears the outline and shaping caches, as the font changed
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font_id = match font_id {
+        Some(id) => {
+            fonts.replace(id, font)?;
+            id
+        }
+        None => fonts.insert(font),
+    };
     
+    Ok(font_id)
+}
+
+/// Close a stored font and release its memory
+///
+/// If it was the current font, functions called without a font handle fail
+/// until another font is stored or selected.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font
+///
+/// # Returns
+/// * `bool` - Whether the handle referred to an open font
+#[wasm_bindgen]
+pub fn close_font(font_id: u32) -> bool {
+    FONT_CACHE.lock().unwrap().remove(font_id)
+}
+
+/// Make a stored font the current font
+///
+/// The current font is used by functions called without a font handle.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font
+///
+/// # Returns
+/// * `Result<(), JsValue>` - Success or error
+#[wasm_bindgen]
+pub fn select_font(font_id: u32) -> Result<(), JsValue> {
+    FONT_CACHE.lock().unwrap().select(font_id)?;
     Ok(())
 }
 
-/// Clear the cached font from memory
+/// Get the handle of the current font
+///
+/// # Returns
+/// * `Option<u32>` - Handle of the current font, or undefined if there is none
+#[wasm_bindgen]
+pub fn get_current_font_id() -> Option<u32> {
+    FONT_CACHE.lock().unwrap().current()
+}
+
+/// List the stored fonts
+///
+/// # Returns
+/// * `String` - JSON array '[{"id": 1, "glyphs": 812, "masters": 2, "current": true}]'
+#[wasm_bindgen]
+pub fn list_fonts() -> Result<String, JsValue> {
+    serde_json::to_string(&FONT_CACHE.lock().unwrap().list())
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Close all stored fonts and clear the caches
 #[wasm_bindgen]
 pub fn clear_font_cache() {
-    let mut cache = FONT_CACHE.lock().unwrap();
-    *cache = None;
+    FONT_CACHE.lock().unwrap().clear();
+    LAZY_FONTS.lock().unwrap().clear();
     
-    // Also clear the outline cache
+    // Also clear the outline, shaping and layout table caches
     glyph_outlines::clear_outline_cache();
+    shaping::clear_source_shaping_cache();
+    feature_cache::clear_feature_cache();
 }
 
-/// Open a font file from various formats
+/// A font given to a function as a handle (number) or as .babelfont JSON (string)
 ///
-/// Supports .glyphs, .glyphspackage, .ufo, .designspace, .vfj, and .babelfont formats.
-/// Loads the font, stores it in cache, and returns the babelfont JSON representation.
+/// Undefined or null is the current font.
+fn font_argument<'a>(
+    fonts: &'a font_sessions::FontSessions,
+    value: &JsValue,
+    argument: &str,
+) -> Result<std::borrow::Cow<'a, babelfont::Font>, ApiError> {
+    if value.is_undefined() || value.is_null() {
+        return fonts.get(None).map(std::borrow::Cow::Borrowed);
+    }
+    if let Some(id) = value.as_f64() {
+        if id.fract() != 0.0 || !(0.0..=u32::MAX as f64).contains(&id) {
+            return Err(ApiError::invalid_argument(argument, format!("{} is not a font handle", id)));
+        }
+        return fonts.get(Some(id as u32)).map(std::borrow::Cow::Borrowed);
+    }
+    match value.as_string() {
+        Some(json) => serde_json::from_str(&json)
+            .map(std::borrow::Cow::Owned)
+            .map_err(|e| ApiError::invalid_json(argument, e)),
+        None => Err(ApiError::invalid_argument(argument, "expected a font handle or .babelfont JSON")),
+    }
+}
+
+/// Compare two source fonts
+///
+/// Glyphs are matched by name, layers and masters by id, axes by tag and
+/// feature code by tag or name. Use it for review workflows and "what
+/// changed since the last save" views, e.g. by comparing the current font
+/// with the JSON it was last saved as.
 ///
 /// # Arguments
-/// * `filename` - The name of the font file (used to determine format)
-/// * `contents` - The file contents as a string (for text formats) or JSON (for .babelfont)
+/// * `font_a` - The old font: a handle from store_font(), .babelfont JSON, or undefined for the current font
+/// * `font_b` - The new font, given the same way
+///
+/// # Returns
+/// * `String` - JSON report: '{"identical": false, "glyphs": {"added": [...], "removed": [...], "changed": [{"name": "a", "fields": ["codepoints"], "layers": {"added": [], "removed": [], "changed": ["m01"]}}]}, "kerning": {"pairs": [{"master": "m01", "left": "@A", "right": "V", "old": -40, "new": -50}], "firstGroups": {...}, "secondGroups": {...}}, "metrics": [{"master": "m01", "metric": "XHeight", "old": 500, "new": 510}], "masters": {...}, "axes": {"added": [], "removed": [], "changed": [{"tag": "wght", "fields": ["max"]}]}, "features": {"features": {...}, "classes": {...}, "prefixes": {...}}, "info": ["version"]}'. Added and removed kerning pairs and metrics have null as old or new value.
+#[wasm_bindgen]
+pub fn diff_fonts(font_a: &JsValue, font_b: &JsValue) -> Result<String, JsValue> {
+    let fonts = FONT_CACHE.lock().unwrap();
+    let old = font_argument(&fonts, font_a, "font_a")?;
+    let new = font_argument(&fonts, font_b, "font_b")?;
+    
+    let report = font_diff::diff_fonts(&old, &new);
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Take a snapshot of the cached font for autosave deltas
+///
+/// Call after the font was saved in full; diff_since_snapshot() then
+/// returns what changed since. The snapshot keeps hashes of the glyphs and
+/// font fields, not a copy of the font. A new snapshot replaces the
+/// previous one of the same font.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
 ///
 /// # Returns
-/// * `String` - Babelfont JSON representation
+/// * `u32` - Number of glyphs in the snapshot
 #[wasm_bindgen]
-pub fn open_font_file(filename: &str, contents: &str) -> Result<String, JsValue> {
-    web_sys::console::log_1(&format!("[Rust] Opening font file: {}", filename).into());
+pub fn create_snapshot(font_id: Option<u32>) -> Result<u32, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let id = fonts.id(font_id)?;
+    let font = fonts.font_mut(Some(id))?;
     
+    let snapshot = font_snapshots::FontSnapshot::capture(font)
+        .map_err(ApiError::from)?;
+    let glyphs = snapshot.glyph_count() as u32;
+    fonts.set_snapshot(id, snapshot);
+    Ok(glyphs)
+}
+
+/// Get the changes of the cached font since its last snapshot
+///
+/// Returns a compact delta for autosaving small patches instead of the
+/// whole font: changed and added glyphs in full, removed glyph names, the
+/// glyph order if it changed other than by appending, and the changed
+/// top-level fields (masters, axes, features, names, ...) in full. Applied
+/// to the font JSON the snapshot was taken of, in that order, it gives the
+/// current font.
+///
+/// By default every delta is relative to the snapshot, so the host only
+/// needs to keep the latest one; with `advance` the current state becomes
+/// the snapshot and deltas have to be applied in sequence.
+///
+/// Requires that a snapshot was taken with create_snapshot() first.
+///
+/// # Arguments
+/// * `advance` - Make the current state the new snapshot (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"unchanged": false, "glyphs": {"changed": {"a": {...}}, "removed": ["a.old"], "order": null}, "fields": {"masters": [...]}}'. Fields removed from the font are null.
+#[wasm_bindgen]
+pub fn diff_since_snapshot(advance: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let id = fonts.id(font_id)?;
+    let snapshot = fonts.snapshot(id)?.clone();
+    let font = fonts.font_mut(Some(id))?;
+    
+    let (delta, current) = font_snapshots::diff_since(&snapshot, font)
+        .map_err(ApiError::from)?;
+    if advance.unwrap_or(false) {
+        fonts.set_snapshot(id, current);
+    }
+    serde_json::to_string(&delta)
+        .map_err(|e| ApiError::serialization("delta", e).into())
+}
+
+/// Copy glyphs of another font into the cached font
+///
+/// For assembling families from shared component libraries: the requested
+/// glyphs come with the glyphs they use as components (nested), their
+/// layers and anchors, and the kerning groups and pairs between copied
+/// glyphs. Source masters match target masters by id, then by design
+/// location; fonts with one master each always match. Glyphs that exist
+/// in the cached font are skipped, overwritten or copied under a new name
+/// with a suffix; copied composites follow renamed components.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `source` - The font to copy from: a handle from store_font() or .babelfont JSON
+/// * `options_json` - Optional JSON object:
+///   - `glyphs`: [string] - Glyphs to copy (default: all glyphs of the source)
+///   - `components`: bool - Also copy the glyphs used as components, nested (default: true)
+///   - `kerning`: bool - Copy kerning groups and pairs between copied glyphs (default: true)
+///   - `conflict`: "skip" | "overwrite" | "rename" - What to do with glyphs that exist in the cached font (default: "skip")
+///   - `suffix`: string - Suffix of renamed glyphs; a number is added if the name is taken too (default: ".merged")
+/// * `font_id` - Handle of the font to copy into; the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"added": ["Aacute"], "overwritten": [], "renamed": {"acutecomb": "acutecomb.merged"}, "skipped": [{"glyph": "A", "reason": "..."}], "warnings": [{"glyph": "...", "reason": "..."}], "masters": {"m01": "master-regular"}, "unmatchedMasters": [], "kerning": {"groups": 1, "pairs": 12}}'
+#[wasm_bindgen]
+pub fn merge_font(source: &JsValue, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    // The source may be another stored font, so it is copied before the target is borrowed
+    let source = font_argument(&fonts, source, "source")?.into_owned();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, copied) = font_merge::merge_font(font, &source, options_json.as_deref())?;
+    
+    if !copied.is_empty() {
+        // Overwritten glyphs may be components anywhere, so start over
+        fonts.font_changed(font_id, FontChange::AllGlyphs)?;
+    }
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Load a font file, choosing the loader by file extension
+fn load_font_file(filename: &str, contents: &str) -> Result<babelfont::Font, JsValue> {
     let path = std::path::PathBuf::from(filename);
     let extension = path.extension()
         .and_then(|e| e.to_str())
@@ -169,61 +551,196 @@ pub fn open_font_file(filename: &str, contents: &str) -> Result<String, JsValue>
         "babelfont" => {
             // For .babelfont, just parse the JSON directly
             serde_json::from_str(contents)
-                .map_err(|e| JsValue::from_str(&format!("Failed to parse .babelfont JSON: {}", e)))?
+                .map_err(|e| ApiError::invalid_json(".babelfont JSON", e))?
         },
         
         "glyphs" => {
             // Load Glyphs 2/3 format
             babelfont::convertors::glyphs3::load_str(contents, path.clone())
-                .map_err(|e| JsValue::from_str(&format!("Failed to load .glyphs file: {:?}", e)))?
+                .map_err(|e| ApiError::InvalidSource { format: "glyphs".to_string(), detail: format!("{:?}", e) })?
         },
         
         "vfj" => {
             // Load FontLab VFJ format
             let font_json: serde_json::Value = serde_json::from_str(contents)
-                .map_err(|e| JsValue::from_str(&format!("Failed to parse VFJ JSON: {}", e)))?;
+                .map_err(|e| ApiError::invalid_json("VFJ JSON", e))?;
             babelfont::convertors::fontlab::load(path.clone())
-                .map_err(|e| JsValue::from_str(&format!("Failed to load .vfj file: {:?}", e)))?
+                .map_err(|e| ApiError::InvalidSource { format: "vfj".to_string(), detail: format!("{:?}", e) })?
         },
         
         "ufo" => {
             // Load UFO format - note: this requires file system access which may not work in WASM
-            return Err(JsValue::from_str("UFO format requires file system access and is not yet supported in browser"));
+            return Err(ApiError::Unsupported {
+                format: "ufo".to_string(),
+                detail: "UFO format requires file system access and is not yet supported in browser".to_string(),
+            }.into());
         },
         
         "designspace" => {
             // Load DesignSpace format - note: this requires file system access which may not work in WASM
-            return Err(JsValue::from_str("DesignSpace format requires file system access and is not yet supported in browser"));
+            return Err(ApiError::Unsupported {
+                format: "designspace".to_string(),
+                detail: "DesignSpace format requires file system access and is not yet supported in browser".to_string(),
+            }.into());
         },
         
         _ => {
-            return Err(JsValue::from_str(&format!(
-                "Unsupported file format: .{}. Supported formats: .babelfont, .glyphs, .vfj",
-                extension
-            )));
+            return Err(ApiError::Unsupported {
+                format: extension.to_string(),
+                detail: format!("Unsupported file format: .{}. Supported formats: .babelfont, .glyphs, .vfj", extension),
+            }.into());
         }
     };
     
-    web_sys::console::log_1(&format!(
-        "[Rust] Successfully loaded font with {} glyphs",
-        font.glyphs.len()
-    ).into());
+    logging::info(
+        "fonts",
+        &format!("Successfully loaded font with {} glyphs", font.glyphs.len()),
+        serde_json::json!({ "glyphs": font.glyphs.len(), "format": extension }),
+    );
+    
+    Ok(font)
+}
+
+/// Open a font file from various formats
+///
+/// Supports .glyphs, .glyphspackage, .ufo, .designspace, .vfj, and .babelfont formats.
+/// Loads the font, stores it in cache as the current font (see get_current_font_id()),
+/// and returns its handle with the babelfont JSON representation.
+///
+/// # Arguments
+/// * `filename` - The name of the font file (used to determine format)
+/// * `contents` - The file contents as a string (for text formats) or JSON (for .babelfont)
+///
+/// # Returns
+/// * `js_sys::Object` - Object with fields:
+///   - `fontId`: number - Handle of the stored font
+///   - `font`: string - Babelfont JSON representation
+#[wasm_bindgen]
+pub fn open_font_file(filename: &str, contents: &str) -> Result<js_sys::Object, JsValue> {
+    logging::info("fonts", &format!("Opening font file: {}", filename), serde_json::json!({ "filename": filename }));
     
-    // Store in cache
-    let mut cache = FONT_CACHE.lock().unwrap();
-    *cache = Some(font.clone());
-    drop(cache);
+    let font = load_font_file(filename, contents)?;
     
     // Serialize to JSON for JavaScript
     let json = serde_json::to_string(&font)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize font to JSON: {}", e)))?;
+        .map_err(|e| ApiError::serialization("font to JSON", e))?;
+    
+    logging::info(
+        "fonts",
+        &format!("Serialized to JSON ({} bytes)", json.len()),
+        serde_json::json!({ "bytes": json.len() }),
+    );
+    
+    // Store as a new current font (see get_current_font_id())
+    let font_id = FONT_CACHE.lock().unwrap().insert(font);
+    
+    let result = js_sys::Object::new();
+    js_sys::Reflect::set(&result, &JsValue::from_str("fontId"), &JsValue::from(font_id))?;
+    js_sys::Reflect::set(&result, &JsValue::from_str("font"), &JsValue::from_str(&json))?;
+    Ok(result)
+}
+
+/// Open a font file, deferring glyph deserialization
+///
+/// For .babelfont files the JSON is only scanned: font information and the
+/// glyph list are returned immediately, while glyph records are kept as raw
+/// JSON and deserialized by get_lazy_glyph() or materialize_lazy_font().
+/// Other formats are loaded completely (as by open_font_file()) and stored
+/// in the cache right away.
+///
+/// # Arguments
+/// * `filename` - The name of the font file (used to determine format)
+/// * `contents` - The file contents as a string
+///
+/// # Returns
+/// * `String` - JSON object with the top-level font fields (axes, masters, names, features, ...), `glyphs` as a list of '{"name": "a", "codepoints": [97], "category": "Base", "exported": true}' summaries, `lazy`: true if materialize_lazy_font() must be called before using the font cache, `lazyId`: the handle of lazily opened fonts for get_lazy_glyph() and materialize_lazy_font(), and `fontId`: the handle of fonts stored right away
+#[wasm_bindgen]
+pub fn open_font_file_lazy(filename: &str, contents: &str) -> Result<String, JsValue> {
+    logging::info("fonts", &format!("Opening font file lazily: {}", filename), serde_json::json!({ "filename": filename }));
+    
+    let is_babelfont = std::path::Path::new(filename)
+        .extension()
+        .is_some_and(|extension| extension == "babelfont");
+    
+    let mut info = if is_babelfont {
+        let lazy = lazy_font::LazyFont::from_babelfont_json(contents)
+            .map_err(|e| ApiError::InvalidSource { format: "babelfont".to_string(), detail: e })?;
+        let mut info = lazy.info_json().map_err(ApiError::from)?;
+        
+        let lazy_id = NEXT_LAZY_ID.fetch_add(1, Ordering::Relaxed);
+        LAZY_FONTS.lock().unwrap().insert(lazy_id, lazy);
+        if let Some(info) = info.as_object_mut() {
+            info.insert("lazyId".to_string(), lazy_id.into());
+        }
+        info
+    } else {
+        let font = load_font_file(filename, contents)?;
+        let mut info = lazy_font::font_info_json(&font).map_err(ApiError::from)?;
+        
+        let font_id = FONT_CACHE.lock().unwrap().insert(font);
+        if let Some(info) = info.as_object_mut() {
+            info.insert("fontId".to_string(), font_id.into());
+        }
+        info
+    };
+    
+    if let Some(info) = info.as_object_mut() {
+        info.insert("lazy".to_string(), JsonValue::Bool(is_babelfont));
+    }
+    serde_json::to_string(&info)
+        .map_err(|e| ApiError::serialization("font info", e).into())
+}
+
+/// Get a glyph of a lazily opened font, deserializing it on first access
+///
+/// # Arguments
+/// * `lazy_id` - Handle of the font from open_font_file_lazy()
+/// * `glyph_name` - Name of the glyph
+///
+/// # Returns
+/// * `String` - JSON of the glyph in .babelfont format
+#[wasm_bindgen]
+pub fn get_lazy_glyph(lazy_id: u32, glyph_name: &str) -> Result<String, JsValue> {
+    let mut lazy_fonts = LAZY_FONTS.lock().unwrap();
+    let lazy = lazy_fonts.get_mut(&lazy_id)
+        .ok_or(ApiError::UnknownHandle { kind: "lazy font", handle: lazy_id })?;
+    
+    let glyph = lazy.glyph(glyph_name)?;
+    serde_json::to_string(glyph)
+        .map_err(|e| ApiError::serialization("glyph", e).into())
+}
+
+/// Deserialize a lazily opened font completely and store it in the cache
+///
+/// After this call all functions working on the cached font can be used,
+/// as after store_font(); the font becomes the current font. The lazy
+/// handle is released.
+///
+/// # Arguments
+/// * `lazy_id` - Handle of the font from open_font_file_lazy()
+///
+/// # Returns
+/// * `u32` - Handle of the stored font
+#[wasm_bindgen]
+pub fn materialize_lazy_font(lazy_id: u32) -> Result<u32, JsValue> {
+    let lazy = LAZY_FONTS.lock().unwrap().remove(&lazy_id)
+        .ok_or(ApiError::UnknownHandle { kind: "lazy font", handle: lazy_id })?;
     
-    web_sys::console::log_1(&format!(
-        "[Rust] Serialized to JSON ({} bytes)",
-        json.len()
-    ).into());
+    let font = lazy.materialize().map_err(ApiError::from)?;
     
-    Ok(json)
+    Ok(FONT_CACHE.lock().unwrap().insert(font))
+}
+
+/// Release a lazily opened font without materializing it
+///
+/// # Arguments
+/// * `lazy_id` - Handle of the font from open_font_file_lazy()
+///
+/// # Returns
+/// * `bool` - Whether the handle referred to a lazily opened font
+#[wasm_bindgen]
+pub fn release_lazy_font(lazy_id: u32) -> bool {
+    LAZY_FONTS.lock().unwrap().remove(&lazy_id).is_some()
 }
 
 /// Interpolate a glyph at a specific location in design space
@@ -233,17 +750,117 @@ pub fn open_font_file(filename: &str, contents: &str) -> Result<String, JsValue>
 /// # Arguments
 /// * `glyph_name` - Name of the glyph to interpolate
 /// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0, "wdth": 100.0}'
+/// * `include_background` - Add the glyph's background layer at the location as `background` (default: false). At a master's location this is the master's background; elsewhere the backgrounds of all masters are interpolated. null if there is none.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
 ///
 /// # Returns
 /// * `String` - JSON representation of the interpolated Layer
 #[wasm_bindgen]
-pub fn interpolate_glyph(glyph_name: &str, location_json: &str) -> Result<String, JsValue> {
-    let cache = FONT_CACHE.lock().unwrap();
-    let font = cache.as_ref()
-        .ok_or_else(|| JsValue::from_str("No font cached. Call store_font() first."))?;
+pub fn interpolate_glyph(glyph_name: &str, location_json: &str, include_background: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
     
     // Call the interpolation module function
-    interpolation::interpolate_glyph(font, glyph_name, location_json)
+    interpolation::interpolate_glyph(font, glyph_name, location_json, include_background.unwrap_or(false))
+}
+
+/// Interpolate a glyph and add the result as an intermediate layer
+///
+/// The glyph is interpolated at the location and stored in the source as a
+/// brace layer ("{550, 100}") associated with the nearest master, so
+/// interpolation artifacts can be corrected where they appear. Axes missing
+/// from the location are taken at their default. Fails if the glyph already
+/// has a layer at the location. Outline caches of the glyph and its
+/// dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Name of the glyph
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON '{"layerId": "intermediate-1", "layer": {...}}'
+#[wasm_bindgen]
+pub fn add_intermediate_layer(glyph_name: &str, location_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let layer = interpolation::add_intermediate_layer(font, glyph_name, location_json)?;
+    
+    if let Some(glyph) = font.glyphs.get(glyph_name) {
+        glyph_outlines::update_component_graph(font, glyph);
+    }
+    let affected = glyph_outlines::with_component_dependents(font, glyph_name);
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let result = serde_json::json!({
+        "layerId": layer.id,
+        "layer": layer,
+    });
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Create a reusable interpolation context for a location
+///
+/// The context keeps the converted design space location and every layer
+/// interpolated at it, so repeated interpolate_with_context() calls (e.g.
+/// all glyphs of a text at one instance) skip location conversion and reuse
+/// shared component layers. A context belongs to the font it is created
+/// for and stays valid when that font is edited; its layers are dropped and
+/// re-interpolated. Closing the font releases its contexts; release contexts
+/// that are no longer needed with release_interpolation_context().
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0, "wdth": 100.0}'
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `u32` - Handle of the context
+#[wasm_bindgen]
+pub fn create_interpolation_context(location_json: &str, font_id: Option<u32>) -> Result<u32, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font_id = fonts.id(font_id)?;
+    let font = fonts.font(Some(font_id))?;
+    
+    interpolation::create_interpolation_context(font, font_id, location_json)
+}
+
+/// Interpolate a glyph at the location of an interpolation context
+///
+/// The glyph is interpolated from the font the context was created for; a
+/// context used with another font handle is rejected.
+///
+/// # Arguments
+/// * `handle` - Handle returned by create_interpolation_context()
+/// * `glyph_name` - Name of the glyph to interpolate
+/// * `font_id` - Handle of the font from store_font(); the context's font if omitted
+///
+/// # Returns
+/// * `String` - JSON representation of the interpolated Layer, as returned by interpolate_glyph()
+#[wasm_bindgen]
+pub fn interpolate_with_context(handle: u32, glyph_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font_id = fonts.id(font_id.or_else(|| interpolation::context_font(handle)))?;
+    let font = fonts.font(Some(font_id))?;
+    
+    interpolation::interpolate_with_context(font, font_id, handle, glyph_name)
+}
+
+/// Release an interpolation context and its layers
+///
+/// # Arguments
+/// * `handle` - Handle returned by create_interpolation_context()
+///
+/// # Returns
+/// * `bool` - Whether the handle referred to a context
+#[wasm_bindgen]
+pub fn release_interpolation_context(handle: u32) -> bool {
+    interpolation::release_interpolation_context(handle)
 }
 
 /// Get outlines for multiple glyphs with optional component flattening
@@ -254,6 +871,8 @@ pub fn interpolate_glyph(glyph_name: &str, location_json: &str) -> Result<String
 /// * `glyph_names_json` - JSON array of glyph names, e.g., '["A", "B", "C"]'
 /// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
 /// * `flatten_components` - If true, resolves and flattens all components into paths
+/// * `include_background` - Add each glyph's background at the location as `background`: '{"width": 600, "shapes": [...], "bounds": {...}}' or null (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
 ///
 /// # Returns
 /// * `String` - JSON array of glyph outline data: '[{"name": "A", "width": 600, "shapes": [...], "bounds": {...}}, ...]'
@@ -262,69 +881,2825 @@ pub fn get_glyphs_outlines(
     glyph_names_json: &str,
     location_json: &str,
     flatten_components: bool,
+    include_background: Option<bool>,
+    font_id: Option<u32>,
 ) -> Result<String, JsValue> {
-    let cache = FONT_CACHE.lock().unwrap();
-    let font = cache.as_ref()
-        .ok_or_else(|| JsValue::from_str("No font cached. Call store_font() first."))?;
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
     
     // Parse glyph names array
     let glyph_names: Vec<String> = serde_json::from_str(glyph_names_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse glyph names: {}", e)))?;
+        .map_err(|e| ApiError::invalid_json("glyph names", e))?;
     
     // Call the glyph outlines module function
-    glyph_outlines::get_glyphs_outlines(font, &glyph_names, location_json, flatten_components)
+    glyph_outlines::get_glyphs_outlines(font, &glyph_names, location_json, flatten_components, include_background.unwrap_or(false))
 }
 
-/// Compile the cached font to TTF
+/// Evict a glyph and the composites that use it from the outline caches
 ///
-/// This is a convenience function that compiles the currently cached font
-/// without needing to pass the JSON again.
+/// Use after editing a glyph so the next get_glyphs_outlines() call only
+/// re-interpolates the affected glyphs instead of the whole glyph set.
+/// Composite glyphs referencing the glyph (directly or through nested
+/// components) are evicted too. When the glyph is replaced, auto-aligned
+/// composites using it are re-aligned (see enable_auto_alignment()).
+///
+/// Requires that a font has been stored via store_font() first.
 ///
 /// # Arguments
-/// * `options` - Compilation options (same as compile_babelfont)
+/// * `glyph_name` - Name of the edited glyph
+/// * `glyph_json` - Optional JSON of the edited glyph in .babelfont format; if given, it replaces the glyph in the cached font first
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
 ///
 /// # Returns
-/// * `Vec<u8>` - Compiled TTF font bytes
+/// * `String` - JSON array of the evicted glyph names, e.g., '["a", "aacute", "adieresis"]'
 #[wasm_bindgen]
-pub fn compile_cached_font(options: &JsValue) -> Result<Vec<u8>, JsValue> {
-    let cache = FONT_CACHE.lock().unwrap();
-    let font = cache.as_ref()
-        .ok_or_else(|| JsValue::from_str("No font cached. Call store_font() first."))?;
-    
-    // Clone the font for compilation (in case we need to apply filters)
-    let mut font_clone = font.clone();
+pub fn invalidate_glyph(glyph_name: &str, glyph_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
     
-    // Handle subset_glyphs option if present
-    if !options.is_undefined() && !options.is_null() {
-        if let Ok(subset_val) = js_sys::Reflect::get(options, &JsValue::from_str("subset_glyphs")) {
-            if !subset_val.is_undefined() && !subset_val.is_null() {
-                if let Ok(array) = subset_val.dyn_into::<js_sys::Array>() {
-                    let subset_glyphs: Vec<String> = array
-                        .iter()
-                        .filter_map(|v| v.as_string())
-                        .collect();
-                    
-                    if !subset_glyphs.is_empty() {
-                        let subsetter = babelfont::filters::RetainGlyphs::new(subset_glyphs);
-                        subsetter
-                            .apply(&mut font_clone)
-                            .map_err(|e| JsValue::from_str(&format!("Subsetting failed: {:?}", e)))?;
-                    }
-                }
-            }
+    if let Some(glyph_json) = glyph_json {
+        let updated: babelfont::Glyph = serde_json::from_str(&glyph_json)
+            .map_err(|e| ApiError::invalid_json("glyph JSON", e))?;
+        let glyph = font.glyphs
+            .get_mut(glyph_name)
+            .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+        *glyph = updated;
+        
+        if let Some(glyph) = font.glyphs.get(glyph_name) {
+            glyph_outlines::update_component_graph(font, glyph);
         }
+        composites::realign_dependents(font, &HashSet::from([glyph_name.to_string()]));
     }
     
-    let compilation_options = CompilationOptions {
-        skip_kerning: get_option(options, "skip_kerning", false),
-        skip_features: get_option(options, "skip_features", false),
-        skip_metrics: get_option(options, "skip_metrics", false),
+    let affected = glyph_outlines::with_component_dependents(font, glyph_name);
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let mut names: Vec<&String> = affected.iter().collect();
+    names.sort();
+    serde_json::to_string(&names)
+        .map_err(|e| ApiError::serialization("glyph names", e).into())
+}
+
+/// Set memory limits of the glyph outline and interpolated layer caches
+///
+/// Both caches are LRU caches keyed by glyph and location, so switching
+/// locations keeps recently used results. When a cache exceeds its budget,
+/// its least recently used entries are evicted.
+///
+/// # Arguments
+/// * `limits_json` - JSON object, e.g., '{"outlineBytes": 33554432, "layerBytes": 33554432}'. Omitted keys are unchanged; "{}" only reports usage. Defaults are 32 MB each.
+///
+/// # Returns
+/// * `String` - JSON object with the budgets and current usage: '{"outlineBytes": ..., "layerBytes": ..., "outlineUsed": ..., "layerUsed": ..., "outlineEntries": ..., "layerEntries": ...}'
+#[wasm_bindgen]
+pub fn set_cache_limits(limits_json: &str) -> Result<String, JsValue> {
+    let limits: JsonValue = serde_json::from_str(limits_json)
+        .map_err(|e| ApiError::invalid_json("cache limits", e))?;
+    
+    let budget = |key: &str| -> Result<Option<usize>, JsValue> {
+        match limits.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(value) => value
+                .as_u64()
+                .map(|bytes| Some(bytes as usize))
+                .ok_or_else(|| ApiError::invalid_argument(key, value).into()),
+        }
+    };
+    
+    let usage = glyph_outlines::set_cache_limits(budget("outlineBytes")?, budget("layerBytes")?);
+    serde_json::to_string(&usage)
+        .map_err(|e| ApiError::serialization("cache usage", e).into())
+}
+
+/// Get outlines for multiple glyphs in chunks, reporting each chunk to a callback
+///
+/// A streaming variant of get_glyphs_outlines() for the first paint of large
+/// fonts: glyphs are processed `chunk_size` at a time and after each chunk
+/// `callback(chunkJson, processedCount, totalCount)` is invoked with the
+/// chunk's results, in the same format as get_glyphs_outlines(). Returning
+/// `false` from the callback stops processing. The font cache is not locked
+/// while the callback runs, so it may call other functions of this module.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - JSON array of glyph names, e.g., '["A", "B", "C"]'
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
+/// * `flatten_components` - If true, resolves and flattens all components into paths
+/// * `chunk_size` - Number of glyphs per chunk (0 is treated as 1)
+/// * `callback` - Function called with (chunkJson: string, processed: number, total: number)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `u32` - Number of glyphs processed (less than the total if the callback stopped processing)
+#[wasm_bindgen]
+pub fn get_glyphs_outlines_chunked(
+    glyph_names_json: &str,
+    location_json: &str,
+    flatten_components: bool,
+    chunk_size: u32,
+    callback: &js_sys::Function,
+    font_id: Option<u32>,
+) -> Result<u32, JsValue> {
+    let glyph_names: Vec<String> = serde_json::from_str(glyph_names_json)
+        .map_err(|e| ApiError::invalid_json("glyph names", e))?;
+    let total = glyph_names.len() as u32;
+    let mut processed = 0u32;
+    
+    for chunk in glyph_names.chunks(chunk_size.max(1) as usize) {
+        let chunk_json = {
+            let mut fonts = FONT_CACHE.lock().unwrap();
+            let font = fonts.font(font_id)?;
+            glyph_outlines::get_glyphs_outlines(font, chunk, location_json, flatten_components, false)?
+        };
+        processed += chunk.len() as u32;
+        
+        let result = callback.call3(
+            &JsValue::NULL,
+            &JsValue::from_str(&chunk_json),
+            &JsValue::from(processed),
+            &JsValue::from(total),
+        )?;
+        if result.as_bool() == Some(false) {
+            break;
+        }
+    }
+    
+    Ok(processed)
+}
+
+/// Get flattened outlines for multiple glyphs as packed typed arrays
+///
+/// A faster alternative to get_glyphs_outlines() with flattening for bulk
+/// drawing: all contours are returned in flat Float32Array/Uint32Array
+/// buffers (one bulk copy each) instead of per-node JSON. Contours of glyph
+/// `i` are `glyphOffsets[i]..glyphOffsets[i + 1]`; nodes of contour `c` are
+/// `contourOffsets[c]..contourOffsets[c + 1]`, with coordinates at
+/// `coords[2 * n]`, `coords[2 * n + 1]`.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - JSON array of glyph names, e.g., '["A", "B", "C"]'
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Object` - { names, widths, bounds, glyphOffsets, contourOffsets, contourClosed, coords, nodeTypes } (see glyph_outlines::get_glyphs_outlines_packed)
+#[wasm_bindgen]
+pub fn get_glyphs_outlines_packed(glyph_names_json: &str, location_json: &str, font_id: Option<u32>) -> Result<js_sys::Object, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let glyph_names: Vec<String> = serde_json::from_str(glyph_names_json)
+        .map_err(|e| ApiError::invalid_json("glyph names", e))?;
+    
+    glyph_outlines::get_glyphs_outlines_packed(font, &glyph_names, location_json)
+}
+
+/// Compile the cached font to TTF
+///
+/// This is a convenience function that compiles the currently cached font
+/// without needing to pass the JSON again.
+///
+/// # Arguments
+/// * `options` - Compilation options (same as compile_babelfont)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Vec<u8>` - Compiled TTF font bytes
+#[wasm_bindgen]
+pub fn compile_cached_font(options: &JsValue, font_id: Option<u32>) -> Result<Vec<u8>, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let handle = fonts.id(font_id)?;
+    let font = fonts.font(Some(handle))?;
+    
+    // Clone the font for compilation (in case we need to apply filters)
+    let mut font_clone = font.clone();
+    
+    // Handle subset_glyphs option if present
+    if !options.is_undefined() && !options.is_null() {
+        if let Ok(subset_val) = js_sys::Reflect::get(options, &JsValue::from_str("subset_glyphs")) {
+            if !subset_val.is_undefined() && !subset_val.is_null() {
+                if let Ok(array) = subset_val.dyn_into::<js_sys::Array>() {
+                    let subset_glyphs: Vec<String> = array
+                        .iter()
+                        .filter_map(|v| v.as_string())
+                        .collect();
+                    
+                    if !subset_glyphs.is_empty() {
+                        let subsetter = babelfont::filters::RetainGlyphs::new(subset_glyphs);
+                        subsetter
+                            .apply(&mut font_clone)
+                            .map_err(|e| ApiError::compilation("Subsetting", format!("{:?}", e)))?;
+                    }
+                }
+            }
+        }
+    }
+    
+    let bitmap_format = bitmap_strikes::BitmapFormat::parse(
+        &get_string_option(options, "bitmap_format").unwrap_or_else(|| "sbix".to_string()),
+    )?;
+    
+    let compilation_options = CompilationOptions {
+        skip_kerning: get_option(options, "skip_kerning", false),
+        skip_features: get_option(options, "skip_features", false),
+        skip_metrics: get_option(options, "skip_metrics", false),
         skip_outlines: get_option(options, "skip_outlines", false),
         dont_use_production_names: get_option(options, "dont_use_production_names", false),
     };
     
-    let compiled_font = BabelfontIrSource::compile(font_clone, compilation_options)
-        .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
+    feature_includes::expand_includes(&mut font_clone)?;
+    
+    let sequences = variation_sequences::collect_sequences(&font_clone);
+    let color_glyphs = color_paints::collect_color_glyphs(&font_clone);
+    let bitmaps = bitmap_strikes::collect_bitmaps(&font_clone);
+    
+    // Reuse the layout tables of the previous compile when feature code,
+    // kerning and anchors are unchanged
+    let layout_hash = if compilation_options.skip_features || compilation_options.skip_kerning {
+        None
+    } else {
+        feature_cache::layout_inputs_hash(&font_clone)
+    };
+    let cached_tables = layout_hash.and_then(|hash| feature_cache::cached_layout_tables(handle, hash));
+    
+    let compilation_options = if cached_tables.is_some() {
+        CompilationOptions {
+            skip_features: true,
+            skip_kerning: true,
+            ..compilation_options
+        }
+    } else {
+        compilation_options
+    };
+    
+    let started = js_sys::Date::now();
+    let glyph_count = font_clone.glyphs.len();
+    let reuse_layout = cached_tables.is_some();
+    logging::info(
+        "compile",
+        &format!("Compiling {} glyphs", glyph_count),
+        serde_json::json!({ "stage": "start", "glyphs": glyph_count, "reuseLayoutTables": reuse_layout }),
+    );
+    
+    let mut compiled_font = BabelfontIrSource::compile(font_clone, compilation_options)
+        .map_err(|e| ApiError::compilation("Compilation", format!("{:?}", e)))?;
+    
+    match (layout_hash, cached_tables) {
+        (Some(_), Some(tables)) => {
+            compiled_font = feature_cache::replace_layout_tables(compiled_font, &tables)
+                .map_err(|e| ApiError::compilation("Reusing layout tables", e))?;
+        }
+        (Some(hash), None) => {
+            feature_cache::store_layout_tables(handle, hash, &compiled_font)
+                .map_err(|e| ApiError::compilation("Caching layout tables", e))?;
+        }
+        _ => {}
+    }
     
+    let compiled_font = variation_sequences::add_to_compiled(compiled_font, &sequences)
+        .map_err(|e| ApiError::compilation("Adding variation sequences", e))?;
+    let compiled_font = color_paints::add_to_compiled(compiled_font, &color_glyphs)
+        .map_err(|e| ApiError::compilation("Adding color glyphs", e))?;
+    let compiled_font = bitmap_strikes::add_to_compiled(compiled_font, &bitmaps, bitmap_format)
+        .map_err(|e| ApiError::compilation("Adding bitmap strikes", e))?;
+    
+    let elapsed = js_sys::Date::now() - started;
+    logging::info(
+        "compile",
+        &format!("Compiled {} glyphs in {:.0} ms ({} bytes)", glyph_count, elapsed, compiled_font.len()),
+        serde_json::json!({ "stage": "done", "glyphs": glyph_count, "bytes": compiled_font.len(), "ms": elapsed.round() }),
+    );
     Ok(compiled_font)
 }
+
+/// Get the Unicode Variation Sequences stored on glyphs of the cached font
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON array: '[{"glyph": "uni845B.jp78", "base": 33883, "selector": 917760}, ...]'
+#[wasm_bindgen]
+pub fn get_source_variation_sequences(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let sequences: Vec<JsonValue> = font.glyphs
+        .iter()
+        .flat_map(|glyph| {
+            variation_sequences::glyph_sequences(glyph)
+                .into_iter()
+                .map(move |sequence| serde_json::json!({
+                    "glyph": glyph.name.to_string(),
+                    "base": sequence.base,
+                    "selector": sequence.selector,
+                }))
+        })
+        .collect();
+    
+    serde_json::to_string(&sequences)
+        .map_err(|e| ApiError::serialization("variation sequences", e).into())
+}
+
+/// Set the Unicode Variation Sequences that map to a glyph in the cached font
+///
+/// The sequences are compiled into a cmap format 14 subtable by
+/// compile_cached_font() and compile_babelfont().
+///
+/// # Arguments
+/// * `glyph_name` - Name of the glyph the sequences map to
+/// * `sequences_json` - JSON array of sequences, e.g., '[{"base": 33883, "selector": 917760}]'. An empty array removes all sequences.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Result<(), JsValue>` - Success or error
+#[wasm_bindgen]
+pub fn set_glyph_variation_sequences(glyph_name: &str, sequences_json: &str, font_id: Option<u32>) -> Result<(), JsValue> {
+    let values: Vec<JsonValue> = serde_json::from_str(sequences_json)
+        .map_err(|e| ApiError::invalid_json("variation sequences", e))?;
+    let sequences = values
+        .iter()
+        .map(|value| {
+            variation_sequences::VariationSequence::from_json(value)
+                .ok_or_else(|| ApiError::invalid_argument("variation sequence", value).into())
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let glyph = font.glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    
+    variation_sequences::set_glyph_sequences(glyph, sequences)?;
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    Ok(())
+}
+
+/// Attach a PNG bitmap to a glyph of the cached font
+///
+/// The bitmap replaces the glyph's bitmap of the same strike size. Bitmaps
+/// are compiled into sbix or CBDT/CBLC tables by compile_cached_font() and
+/// compile_babelfont() (see the `bitmap_format` option).
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to change
+/// * `ppem` - Strike size in pixels per em
+/// * `png` - PNG image data
+/// * `origin_x` - Horizontal offset of the bitmap's left edge from the glyph origin in pixels (default: 0)
+/// * `origin_y` - Vertical offset of the bitmap's bottom edge from the baseline in pixels (default: 0)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Result<(), JsValue>` - Success or error
+#[wasm_bindgen]
+pub fn set_glyph_bitmap(
+    glyph_name: &str,
+    ppem: u16,
+    png: &[u8],
+    origin_x: Option<i16>,
+    origin_y: Option<i16>,
+    font_id: Option<u32>,
+) -> Result<(), JsValue> {
+    let bitmap = bitmap_strikes::GlyphBitmap::new(ppem, png.to_vec(), origin_x.unwrap_or(0), origin_y.unwrap_or(0))?;
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    bitmap_strikes::set_glyph_bitmap(font, glyph_name, bitmap)
+        .map_err(|e| e.into())
+}
+
+/// Remove bitmaps from a glyph of the cached font
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to change
+/// * `ppem` - Strike size to remove; all of the glyph's bitmaps if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `usize` - Number of bitmaps removed
+#[wasm_bindgen]
+pub fn remove_glyph_bitmap(glyph_name: &str, ppem: Option<u16>, font_id: Option<u32>) -> Result<usize, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    bitmap_strikes::remove_glyph_bitmaps(font, glyph_name, ppem)
+        .map_err(|e| e.into())
+}
+
+/// Get the bitmaps of a glyph in the cached font
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON array '[{"ppem": 136, "width": 136, "height": 128, "originX": 0, "originY": -10, "dataUrl": "data:image/png;base64,..."}]'
+#[wasm_bindgen]
+pub fn get_glyph_bitmaps(glyph_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let bitmaps = bitmap_strikes::describe_glyph_bitmaps(font, glyph_name)?;
+    serde_json::to_string(&bitmaps)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// List the bitmap strike sizes of the cached font
+///
+/// Use get_bitmap_strikes() for the strikes of a compiled font.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON array '[{"ppem": 136, "glyphs": ["u1F600", ...]}]'
+#[wasm_bindgen]
+pub fn get_source_bitmap_strikes(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&bitmap_strikes::list_strikes(font))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Get the COLRv1 paint graph of a glyph in the cached font
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to read
+/// * `layer_id` - Optional master layer; the default master's layer if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON paint, e.g. '{"type": "layers", "layers": [{"type": "glyph", "glyph": "A", "paint": {"type": "solid", "color": "#ff0000ff"}}]}', or "null" if the layer has none
+#[wasm_bindgen]
+pub fn get_color_glyph(glyph_name: &str, layer_id: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let paint = color_paints::get_color_paint(font, glyph_name, layer_id.as_deref())?;
+    let value = paint.map(|paint| paint.to_json()).unwrap_or(JsonValue::Null);
+    serde_json::to_string(&value)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Set the COLRv1 paint graph of a glyph in the cached font
+///
+/// Paint types are "solid" (`color`), "linearGradient" (`p0`, `p1`,
+/// optional `p2`), "radialGradient" (`c0`, `r0`, `c1`, `r1`),
+/// "sweepGradient" (`center`, `startAngle`, `endAngle` in degrees),
+/// "glyph" (`glyph` outline filled with `paint`), "colrGlyph" (`glyph`),
+/// "transform" (`transform` [a, b, c, d, e, f] applied to `paint`),
+/// "composite" (`mode`, `source`, `backdrop`) and "layers" (`layers`).
+/// Gradients take `stops` ([{"offset": 0, "color": "#rrggbbaa"}]) and
+/// `extend` ("pad", "repeat" or "reflect"). Paint graphs are compiled into
+/// COLR and CPAL tables by compile_cached_font() and compile_babelfont(),
+/// from the default master's layer.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to change
+/// * `paint_json` - JSON paint; "null" removes the paint graph
+/// * `layer_id` - Optional master layer; the default master's layer if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Result<(), JsValue>` - Success or error
+#[wasm_bindgen]
+pub fn set_color_glyph(glyph_name: &str, paint_json: &str, layer_id: Option<String>, font_id: Option<u32>) -> Result<(), JsValue> {
+    let value: JsonValue = serde_json::from_str(paint_json)
+        .map_err(|e| ApiError::invalid_json("paint", e))?;
+    let paint = if value.is_null() {
+        None
+    } else {
+        Some(color_paints::ColorPaint::from_json(&value).map_err(|e| ApiError::invalid_json("paint", e))?)
+    };
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    color_paints::set_color_paint(font, glyph_name, layer_id.as_deref(), paint)?;
+    
+    // Cached outlines carry the paint graph
+    fonts.font_changed(font_id, FontChange::Glyphs(&HashSet::from([glyph_name.to_string()])))?;
+    Ok(())
+}
+
+/// List the color glyphs of the cached font
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object '{"glyphs": [{"glyph": "A.color", "layers": ["m01"]}], "palette": ["#ff0000ff"]}' with the distinct colors of all paint graphs
+#[wasm_bindgen]
+pub fn get_color_glyphs(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&color_paints::list_color_glyphs(font))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Flatten a color glyph of the cached font into an SVG preview
+///
+/// The glyph's paint graph is interpolated at the location and drawn as
+/// filled paths with SVG gradients, in font units. Glyphs without a paint
+/// graph are filled black.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to flatten
+/// * `location_json` - JSON object with axis tags and values in user space, e.g. '{"wght": 400}'; '{}' is the default location
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - SVG document
+#[wasm_bindgen]
+pub fn flatten_color_glyph_svg(glyph_name: &str, location_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    color_preview::color_glyph_svg(font, glyph_name, location_json)
+        .map_err(|e| e.into())
+}
+
+/// Get the UI parameters of stylistic set and character variant features in the cached font
+///
+/// Parameters are read from the `featureNames` (ssXX) and `cvParameters`
+/// (cvXX) blocks of the source feature code. Requires that a font has been
+/// stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object mapping feature tags to parameters, in the same format as get_feature_ui_names()
+#[wasm_bindgen]
+pub fn get_source_feature_ui_names(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let mut result = serde_json::Map::new();
+    for (tag, code) in font.features.features.iter() {
+        let tag = tag.to_string();
+        if let Some(names) = feature_params::read_ui_names(&tag, &code.to_string()) {
+            let value = names.to_json(&tag);
+            result.insert(tag, value);
+        }
+    }
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("feature names", e).into())
+}
+
+/// Set the UI parameters of a stylistic set or character variant feature in the cached font
+///
+/// Rewrites the `featureNames` (ssXX) or `cvParameters` (cvXX) block of the
+/// feature's source code, which fontc compiles into the GSUB FeatureParams
+/// and name table. A feature that is not in the source yet is added with
+/// only the parameter block. Stylistic sets only take a UI name; tooltip,
+/// sample text, parameter labels and characters are for character variants.
+///
+/// # Arguments
+/// * `feature_tag` - Feature tag, e.g. "ss01" or "cv01"
+/// * `params_json` - JSON object, e.g. '{"uiLabel": "Single-storey a", "tooltip": "...", "sampleText": "banana", "paramLabels": [], "characters": [97]}'. "null" removes the parameters.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Result<(), JsValue>` - Success or error
+#[wasm_bindgen]
+pub fn set_feature_ui_names(feature_tag: &str, params_json: &str, font_id: Option<u32>) -> Result<(), JsValue> {
+    let value: JsonValue = serde_json::from_str(params_json)
+        .map_err(|e| ApiError::invalid_json("feature parameters", e))?;
+    let names = if value.is_null() {
+        None
+    } else {
+        Some(feature_params::FeatureUiNames::from_json(&value)?)
+    };
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let existing = font.features.features
+        .iter_mut()
+        .find(|(tag, _)| tag.to_string() == feature_tag);
+    match existing {
+        Some((_, code)) => {
+            let updated = feature_params::write_ui_names(feature_tag, &code.to_string(), names.as_ref())?;
+            *code = updated.into();
+        }
+        // Naming a set before it has rules starts the feature with the parameter block
+        None if names.is_some() => {
+            let code = feature_params::write_ui_names(feature_tag, "", names.as_ref())?;
+            font.features.features.push((feature_tag.into(), code.into()));
+        }
+        None => return Err(ApiError::FeatureNotFound { feature: feature_tag.to_string() }.into()),
+    }
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    Ok(())
+}
+
+/// Generate mark and mkmk feature code from the anchors of the cached font
+///
+/// Writes a markClass per mark anchor and mark-to-base, mark-to-ligature
+/// and mark-to-mark lookups as plain feature code, so the attachment rules
+/// can be inspected, edited and stored in the source instead of being
+/// written at compile time. The font is not changed.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `options_json` - Optional JSON object with `master` (master whose anchors are used, default: the default master) and `mkmk` (bool, default: true)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - Feature code
+#[wasm_bindgen]
+pub fn generate_anchor_features(options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    mark_features::generate_anchor_features(font, options_json.as_deref())
+        .map_err(|e| e.into())
+}
+
+/// Generate default features of the cached font from its glyph names
+///
+/// Infers `liga` from ligature names ("f_f_i"), a `ccmp` rule replacing "i"
+/// and "j" by their dotless forms before top marks, and `numr`/`dnom` from
+/// `.numr`/`.dnom` variants, as Glyphs does automatically. Features can be
+/// left out one by one with `exclude`. With `apply`, the generated features
+/// are written into the source; features the source already defines are
+/// kept unless `replace` is set.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `options_json` - Optional JSON object with `exclude` (array of feature tags), `apply` (bool, default: false) and `replace` (bool, default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object '{"features": [{"tag": "liga", "code": "...", "rules": 3, "inSource": false, "applied": true}]}'
+#[wasm_bindgen]
+pub fn generate_automatic_features(options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = feature_writer::automatic_features(font, options_json.as_deref())?;
+    let applied = result["features"]
+        .as_array()
+        .is_some_and(|features| features.iter().any(|feature| feature["applied"] == true));
+    if applied {
+        fonts.font_changed(font_id, FontChange::Layout)?;
+    }
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Get the glyph classes of the cached font's feature code
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON array '[{"name": "Uppercase", "members": ["A", "B"], "missing": [], "usages": [{"source": "feature:case", "line": 1, "column": 5}]}]'; `missing` lists members that are neither glyphs nor classes
+#[wasm_bindgen]
+pub fn get_feature_classes(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&feature_classes::list_classes(font))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Create a glyph class in the cached font's feature code
+///
+/// Fails if the class exists or a member is neither a glyph of the font
+/// nor a defined class.
+///
+/// # Arguments
+/// * `name` - Class name, without `@`
+/// * `members_json` - JSON array of glyph names and class references, e.g. '["A", "B", "@Ligatures"]'
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object of the class, as in get_feature_classes()
+#[wasm_bindgen]
+pub fn create_feature_class(name: &str, members_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let members = feature_classes::parse_members(members_json)?;
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = feature_classes::create_class(font, name, &members)?;
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Add members to a glyph class of the cached font
+///
+/// # Arguments
+/// * `name` - Class name, without `@`
+/// * `members_json` - JSON array of glyph names and class references; all must exist
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object of the class, as in get_feature_classes()
+#[wasm_bindgen]
+pub fn add_feature_class_members(name: &str, members_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let members = feature_classes::parse_members(members_json)?;
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = feature_classes::add_class_members(font, name, &members)?;
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Remove members from a glyph class of the cached font
+///
+/// # Arguments
+/// * `name` - Class name, without `@`
+/// * `members_json` - JSON array of members to remove
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object of the class, as in get_feature_classes()
+#[wasm_bindgen]
+pub fn remove_feature_class_members(name: &str, members_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let members = feature_classes::parse_members(members_json)?;
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = feature_classes::remove_class_members(font, name, &members)?;
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Delete a glyph class of the cached font
+///
+/// A class that is still referred to in feature code is only deleted with
+/// `force`.
+///
+/// # Arguments
+/// * `name` - Class name, without `@`
+/// * `force` - Delete even if the class is used (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object '{"deleted": "Uppercase", "usages": [...]}'
+#[wasm_bindgen]
+pub fn delete_feature_class(name: &str, force: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = feature_classes::delete_class(font, name, force.unwrap_or(false))?;
+    fonts.font_changed(font_id, FontChange::Layout)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Register files that feature code can include
+///
+/// `include(path)` statements in the cached font's feature code are
+/// replaced with these files when compiling. Paths are resolved relative to
+/// the including file, and relative to the font for the font's own code.
+/// Replaces the previously registered files.
+///
+/// # Arguments
+/// * `files_json` - JSON object mapping paths to feature code, e.g. '{"../shared/kern.fea": "pos A V -80;"}'
+#[wasm_bindgen]
+pub fn set_feature_include_files(files_json: &str) -> Result<(), JsValue> {
+    let files: HashMap<String, String> = serde_json::from_str(files_json)
+        .map_err(|e| ApiError::invalid_json("include files", e))?;
+    feature_includes::set_include_files(files);
+    FONT_CACHE.lock().unwrap().includes_changed();
+    Ok(())
+}
+
+/// Get the paths of the registered include files
+///
+/// # Returns
+/// * `String` - JSON array of normalized paths
+#[wasm_bindgen]
+pub fn get_feature_include_files() -> Result<String, JsValue> {
+    serde_json::to_string(&feature_includes::include_file_paths())
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Set the provider of included feature files that are not registered
+///
+/// The callback is called with the normalized path of an included file and
+/// returns its feature code, or null/undefined if there is no such file.
+/// It must be synchronous. Pass null to remove the provider.
+///
+/// # Arguments
+/// * `callback` - Function called with (path: string) returning a string, or null
+#[wasm_bindgen]
+pub fn set_feature_include_provider(callback: Option<js_sys::Function>) {
+    feature_includes::set_include_provider(callback);
+    FONT_CACHE.lock().unwrap().includes_changed();
+}
+
+/// Export the feature code of the cached font as one standalone feature file
+///
+/// Merges the glyph classes, prefixes and feature snippets of the source
+/// into a feature file that external tools can compile. Glyphs tokens are
+/// expanded: `$[predicate]` (e.g. `$[name endswith ".sc"]`, with the glyph
+/// properties name, category, unicode and case) becomes the matching
+/// exported glyphs, and `${expression}` (e.g. `${xHeight / 2}`) becomes a
+/// number from the master's metrics.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `options_json` - Optional JSON object with `master` (master for number tokens, default: the default master) and `expandIncludes` (bool, default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - Feature file
+#[wasm_bindgen]
+pub fn export_features_fea(options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    feature_export::export_features(font, options_json.as_deref())
+        .map_err(|e| e.into())
+}
+
+/// Validate feature code of the cached font without compiling
+///
+/// Reports syntax errors (unbalanced blocks and brackets, mismatched block
+/// names, missing semicolons, unknown statements) and references to glyphs,
+/// classes and lookups that are not defined, with line and column, so the
+/// feature editor can mark them while the user types.
+///
+/// Without `code`, all feature code of the font (prefixes, classes and
+/// feature snippets) is checked. With `code` and `source`, the code replaces
+/// that snippet of the font and only it is checked; definitions from the
+/// other snippets stay visible. With `code` alone, it is checked as a
+/// complete feature file.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `code` - Optional feature code being edited
+/// * `source` - Optional snippet the code belongs to: "feature:liga", "class:Uppercase" or "prefix:Languagesystems"
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "syntax", "undefined-glyph", "undefined-class" or "undefined-lookup" and contexts with `source`, `line`, `column`, `endLine` and `endColumn`
+#[wasm_bindgen]
+pub fn validate_features(code: Option<String>, source: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let font_snippets = feature_syntax::font_snippets(font);
+    let (snippets, context) = match (code, source) {
+        (None, _) => (font_snippets, Vec::new()),
+        (Some(code), None) => (
+            vec![feature_syntax::Snippet { source: "code".to_string(), kind: feature_syntax::SnippetKind::TopLevel, code }],
+            Vec::new(),
+        ),
+        (Some(code), Some(source)) => {
+            let kind = match source.split_once(':').map(|(kind, _)| kind) {
+                Some("feature") => feature_syntax::SnippetKind::FeatureBody,
+                Some("class") => feature_syntax::SnippetKind::ClassMembers,
+                Some("prefix") => feature_syntax::SnippetKind::TopLevel,
+                _ => {
+                    return Err(ApiError::invalid_argument("feature source", &source).into())
+                }
+            };
+            let context = font_snippets.into_iter().filter(|snippet| snippet.source != source).collect();
+            (vec![feature_syntax::Snippet { source, kind, code }], context)
+        }
+    };
+    
+    let glyph_exists = |name: &str| font.glyphs.get(name).is_some();
+    let report = feature_syntax::validate_snippets(&snippets, &context, &glyph_exists);
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Parse a feature file into a syntax tree
+///
+/// Returns the blocks and statements of the code with their line and column
+/// ranges, the classes, lookups and features it defines, and its problems
+/// with line, column and length, so the feature editor can offer folding,
+/// go-to-definition and inline diagnostics. Does not need a cached font;
+/// glyph names are not checked (see validate_features()).
+///
+/// # Arguments
+/// * `code` - Feature code
+///
+/// # Returns
+/// * `String` - JSON object: '{"ast": [{"type": "feature", "name": "liga", "children": [...], "line": 1, "column": 1, "endLine": 3, "endColumn": 7}], "classes": [...], "lookups": [...], "features": [...], "errors": [{"severity": "error", "check": "syntax", "message": "...", "line": 2, "column": 5, "endLine": 2, "endColumn": 9, "length": 4}]}'
+#[wasm_bindgen]
+pub fn parse_features(code: &str) -> Result<String, JsValue> {
+    serde_json::to_string(&feature_syntax::parse_features(code))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Generate kerning proof strings for the cached font
+///
+/// Produces permutation strings per category pair (each left glyph followed
+/// by every right glyph, framed by flat control glyphs: "HAVH HAWH ...") and
+/// one string per explicitly kerned pair, with groups represented by their
+/// first member. Glyphs without a codepoint are written as "/glyphname ".
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `options_json` - Optional JSON object:
+///   - `categories`: array of [left, right] category pairs from "uppercase", "lowercase", "figures", "punctuation" (default: common pairs)
+///   - `includeKernedPairs`: bool - Add a group with all explicitly kerned pairs (default: true)
+///   - `masterId`: string - Only use the kerning of this master (default: all masters)
+///   - `maxLineLength`: number - Wrap lines at this many characters (default: 64)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"groups": [{"kind": "categories", "title": "uppercase / lowercase", "lines": [...]}, {"kind": "kerned", "title": "Kerned pairs", "lines": [...], "pairs": [...]}]}'
+#[wasm_bindgen]
+pub fn generate_kerning_proof(options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    proofs::generate_kerning_proof(font, options_json.as_deref())
+}
+
+/// Generate spacing control strings for a glyph of the cached font
+///
+/// Sets the glyph between flat and round control glyphs of its script and
+/// case ("HHXHH", "OOXOO", ..., "HHXHOHOXOO" for uppercase Latin, "nnXnn"
+/// for lowercase, "11X11" for figures, Cyrillic, Greek and Hebrew
+/// equivalents). With "auto", the scheme follows the script and category
+/// of the glyph's character (or of the glyph it is a variant of, for
+/// "a.ss01"); punctuation gets both uppercase and lowercase strings.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to space
+/// * `scheme` - "auto" (default), "latin-uppercase", "latin-lowercase", "cyrillic-uppercase", "cyrillic-lowercase", "greek-uppercase", "greek-lowercase", "hebrew" or "figures"
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyph": "A", "character": "A", "groups": [{"scheme": "latin-uppercase", "controls": ["H", "O"], "strings": ["HHAHH", "OOAOO", "HHAOO", "OOAHH", "HHAHOHOAOO"], "missing": []}]}'; `missing` lists control characters the font lacks
+#[wasm_bindgen]
+pub fn get_spacing_strings(glyph_name: &str, scheme: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    proofs::get_spacing_strings(font, glyph_name, scheme.as_deref().unwrap_or("auto"))
+}
+
+/// Preview mark attachment on source data without compiling
+///
+/// Interpolates the base and mark glyphs at the location and positions each
+/// mark by matching its `_name` anchor to the base's (or a preceding mark's)
+/// `name` anchor. Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `base_glyph` - Name of the base glyph
+/// * `mark_glyphs_json` - JSON array of mark glyph names in attachment order, e.g., '["dotbelowcomb", "acutecomb"]'
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with positioned, component-flattened outlines for the base and each mark
+#[wasm_bindgen]
+pub fn preview_mark_attachment(
+    base_glyph: &str,
+    mark_glyphs_json: &str,
+    location_json: &str,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let mark_glyphs: Vec<String> = serde_json::from_str(mark_glyphs_json)
+        .map_err(|e| ApiError::invalid_json("mark glyph names", e))?;
+    
+    glyph_outlines::preview_mark_attachment(font, base_glyph, &mark_glyphs, location_json)
+}
+
+/// Compile the cached font for shaping only
+///
+/// Builds cmap, metrics, GSUB and GPOS but skips outlines, and keeps the
+/// result until the cached font changes (store_font(), clear_font_cache() or
+/// an edit through this module), so repeated calls are free. The bytes can be
+/// passed to shape_text(), layout_text() or shape_text_trace() while editing
+/// features; they contain no outlines and cannot be rendered.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `Vec<u8>` - Compiled font bytes without `glyf`/`gvar`
+#[wasm_bindgen]
+pub fn compile_shaping_font(font_id: Option<u32>) -> Result<Vec<u8>, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    shaping::source_shaping_font(font).map_err(|e| e.into())
+}
+
+/// Shape text against the cached source font without a full compile
+///
+/// Uses the outline-free shaping build of compile_shaping_font(), compiling
+/// it on first use, so feature edits can be tested live in the preview.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `text` - Text to shape
+/// * `options_json` - Optional JSON shaping options, as for shape_text()
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with the shaped glyphs, in the same format as shape_text()
+#[wasm_bindgen]
+pub fn shape_source_text(text: &str, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let font_bytes = compile_shaping_font(font_id)?;
+    shaping::shape_to_json(&font_bytes, text, options_json.as_deref())
+}
+
+/// Apply the substitution features of the cached font to a glyph sequence
+///
+/// Compiles the font without outlines and kerning (reusing the shaping
+/// build of compile_shaping_font() if there is one) and applies the GSUB
+/// lookups of the given features to glyph names rather than text, so
+/// feature edits can be tested instantly, also on glyphs without a code
+/// point. Each lookup that changed the sequence is listed as a step.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_sequence` - JSON array of glyph names, e.g., '["f", "f", "i", "a"]'
+/// * `features` - JSON array of feature tags, e.g., '["liga", "ss01"]', or an object of settings, e.g., '{"liga": 1, "salt": 2}' (n picks the nth alternate of alternate substitutions)
+/// * `options_json` - Optional JSON object:
+///   - `script`: string - OpenType script tag (default: "DFLT")
+///   - `language`: string - OpenType language system tag (default: the script's default)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyphs": ["f_f_i", "a.ss01"], "steps": [{"lookup": 4, "features": ["liga"], "glyphs": ["f_f_i", "a"]}], "missingFeatures": []}'
+#[wasm_bindgen]
+pub fn apply_features_preview(glyph_sequence: &str, features: &str, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let glyph_names: Vec<String> = serde_json::from_str(glyph_sequence)
+        .map_err(|e| ApiError::invalid_json("glyph sequence", e))?;
+    let font_bytes = {
+        let mut fonts = FONT_CACHE.lock().unwrap();
+        let font = fonts.font(font_id)?;
+        shaping::source_substitution_font(font)?
+    };
+    
+    let result = gsub_preview::apply_features(&font_bytes, &glyph_names, features, options_json.as_deref())?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Generate a proof layout for the cached font
+///
+/// Produces structured layout data that the UI or the PDF exporter can
+/// render directly:
+/// - "waterfall": the sample text shaped and laid out at each size, stacked
+///   top to bottom. Glyph IDs refer to the shaping build of
+///   compile_shaping_font(), which has the same glyph order as a full build;
+///   glyph names are included as well.
+/// - "glyphSet": glyphs arranged in a grid of `columns` × `rows` cells per page.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `kind` - "waterfall" or "glyphSet"
+/// * `options_json` - Optional JSON object:
+///   - waterfall: `text` (default: "Hamburgefonstiv 0123456789"), `sizes`: number[] (default: 72 down to 8), `width`: number - wrap width (default: no wrapping), `gap`: number - space between sizes as a multiple of the size (default: 0.25), plus `lineHeight`, `align` and shaping options as for layout_text()
+///   - glyphSet: `columns` (default: 12), `rows` (default: 10), `glyphs`: string[] - glyph names (default: all glyphs), `exportedOnly`: bool (default: true), `sort`: "glyphOrder" or "codepoint" (default: "glyphOrder")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: waterfall '{"kind": "waterfall", "width": ..., "height": ..., "steps": [{"fontSize": 72, "y": 0, "block": {...layout_text() result...}}]}', glyphSet '{"kind": "glyphSet", "pageCount": 3, "pages": [{"page": 0, "cells": [{"index": 0, "row": 0, "column": 0, "name": "A", "codepoints": [65], "text": "A"}]}]}'
+#[wasm_bindgen]
+pub fn generate_proof_layout(kind: &str, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    proofs::generate_proof_layout(font, kind, options_json.as_deref())
+}
+
+/// Check the cached font for contours with the wrong winding direction
+///
+/// (TrueType convention, reversed, in fonts with quadratic curves).
+/// (TrueType convention, reversed, for contours with quadratic curves).
+/// Background layers are skipped.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "path-direction" and context '{"glyph": "o", "layer": "Regular", "path": 1, "direction": "counterClockwise", "depth": 1}'
+#[wasm_bindgen]
+pub fn check_path_directions(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let report = source_checks::check_path_directions(font, glyph_names_json.as_deref())?;
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Reverse contours with the wrong winding direction in all layers of the cached font
+///
+/// Uses the same rules as check_path_directions(). Outline caches of the
+/// changed glyphs and the composites using them are invalidated.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"reversed": 3, "glyphs": ["a", "o"]}'
+#[wasm_bindgen]
+pub fn correct_path_directions(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (reversed, changed) = source_checks::correct_path_directions(font, glyph_names_json.as_deref())?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    serde_json::to_string(&serde_json::json!({ "reversed": reversed, "glyphs": glyphs }))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Remove overlaps in the source outlines of glyphs in the cached font
+///
+/// Merges the closed paths of each layer in scope with a boolean union and
+/// writes the result back into the cached font, keeping the layer's curve
+/// type and correcting contour directions. Outline caches of the changed
+/// glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `layer_scope` - "all" (all non-background layers), "masters" (master layers) or a layer/master ID
+/// * `flatten_components` - Decompose components first so they are merged too (default false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 4, "glyphs": ["A", "B"], "warnings": [{"glyph": "B", "message": "...", "contours": {"Regular": 2, "Bold": 3}}]}'; warnings list glyphs whose masters ended up with different contour counts
+#[wasm_bindgen]
+pub fn remove_overlap(
+    glyph_names_json: Option<String>,
+    layer_scope: &str,
+    flatten_components: Option<bool>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = path_ops::remove_overlap(
+        font,
+        glyph_names_json.as_deref(),
+        layer_scope,
+        flatten_components.unwrap_or(false),
+    )?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        if let Some(glyph) = font.glyphs.get(name) {
+            glyph_outlines::update_component_graph(font, glyph);
+        }
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Round coordinates of glyphs in the cached font to a grid
+///
+/// Rounds node positions, component offsets and anchors in all
+/// non-background layers. With `keep_compatible`, layers are rounded as
+/// offsets from the rounded default master, so parts that only move
+/// between masters keep their exact shape in each of them. Outline caches
+/// of changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `grid` - Grid size in font units (default: 1)
+/// * `keep_compatible` - Round other layers relative to the default master (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 12, "glyphs": ["a", "b"]}'
+#[wasm_bindgen]
+pub fn round_coordinates(
+    glyph_names_json: Option<String>,
+    grid: Option<f64>,
+    keep_compatible: Option<bool>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::round_coordinates(
+        font,
+        glyph_names_json.as_deref(),
+        grid.unwrap_or(1.0),
+        keep_compatible.unwrap_or(false),
+    )?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Remove redundant nodes from outlines of glyphs in the cached font
+///
+/// Drops zero-length segments, turns straight curves into lines, merges
+/// collinear lines and refits runs of smooth curves with fewer curves, all
+/// within `tolerance` of the original outline. Useful after tracing or
+/// boolean operations. Outline caches of changed glyphs and their
+/// dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `tolerance` - Largest allowed deviation in font units (default: 1)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 3, "glyphs": ["a"], "removedNodes": 42, "warnings": [{"glyph": "a", "message": "...", "nodes": {"Regular": [24], "Bold": [26]}}]}'
+#[wasm_bindgen]
+pub fn simplify_paths(glyph_names_json: Option<String>, tolerance: Option<f64>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::simplify_paths(
+        font,
+        glyph_names_json.as_deref(),
+        tolerance.unwrap_or(1.0),
+    )?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Harmonize curves at smooth nodes of glyphs in the cached font
+///
+/// Adjusts handle lengths at smooth nodes between cubic curves for
+/// continuous curvature (G2), keeping nodes and handle directions in
+/// place. With `balance`, the handles of each curve are balanced first.
+/// Outline caches of changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `balance` - Balance the handles of each curve before harmonizing (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 3, "glyphs": ["o"], "nodes": 24}'
+#[wasm_bindgen]
+pub fn harmonize(glyph_names_json: Option<String>, balance: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::harmonize(font, glyph_names_json.as_deref(), balance.unwrap_or(false))?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Round the corners of glyphs in the cached font
+///
+/// Replaces sharp corners with arcs of `radius` font units (smaller where
+/// segments are short), e.g. to derive a rounded style. Corners are picked
+/// in the default master and rounded alike in all compatible layers, so
+/// masters keep interpolating. Outline caches of changed glyphs and their
+/// dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `radius` - Corner radius in font units
+/// * `options_json` - Optional JSON object:
+///   - `concave`: bool - Also round inside corners (default: true)
+///   - `minAngle`: number - Leave corners that turn by fewer degrees sharp (default: 10)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 3, "glyphs": ["E"], "corners": 36, "warnings": [{"glyph": "E", "message": "...", "nodes": {...}}]}'
+#[wasm_bindgen]
+pub fn round_corners(
+    glyph_names_json: Option<String>,
+    radius: f64,
+    options_json: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::round_corners(font, glyph_names_json.as_deref(), radius, options_json.as_deref())?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Convert all cubic curves of the cached font to quadratic curves
+///
+/// Commits the source to TrueType-style outlines. Curves are converted in
+/// all compatible layers of a glyph together, with the same number of
+/// quadratic pieces each, so masters keep interpolating. Clears all outline
+/// and shaping caches.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `tolerance` - Largest allowed deviation from the cubic curves in font units (default: 1)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 1200, "glyphs": ["a", ...], "segments": 9000, "skipped": [{"glyph": "s", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn convert_to_quadratic(tolerance: Option<f64>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::convert_to_quadratic(font, None, tolerance.unwrap_or(1.0))?;
+    
+    fonts.font_changed(font_id, FontChange::Glyphs(&changed))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Convert all quadratic curves of the cached font to cubic curves
+///
+/// The conversion is exact, e.g. to get editable cubic curves back from an
+/// imported TrueType font; implied on-curve points become smooth nodes.
+/// Clears all outline and shaping caches.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 1200, "glyphs": ["a", ...], "paths": 2400}'
+#[wasm_bindgen]
+pub fn convert_to_cubic(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::convert_to_cubic(font, None)?;
+    
+    fonts.font_changed(font_id, FontChange::Glyphs(&changed))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Slant all glyphs of the cached font, e.g. to derive an italic
+///
+/// Shears every layer about half the x-height and half the advance width
+/// so glyphs stay centred, adjusts component transforms so composites
+/// follow their bases, and sets the italic angle of all masters. Clears
+/// all outline and shaping caches.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `angle` - Slant angle in degrees, positive leans right (e.g., 12)
+/// * `options_json` - Optional JSON object:
+///   - `origin`: number - Pivot height in font units (default: half the x-height of each master)
+///   - `rotation`: number - Share of the angle applied by rotation instead of shearing, 0 to 1; keeps round strokes from thinning but tilts horizontals (default: 0)
+///   - `updateItalicAngle`: bool - Set the italic angle metric of all masters (default: true)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 1200, "glyphs": 600, "italicAngle": -12}'
+#[wasm_bindgen]
+pub fn slant_font(angle: f64, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = outline_filters::slant_font(font, angle, options_json.as_deref())?;
+    
+    fonts.font_changed(font_id, FontChange::Glyphs(&changed))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Scale the cached font to a new units-per-em value
+///
+/// Rescales outlines, anchors, guides, advance widths, component offsets,
+/// kerning and master metrics consistently, e.g. to convert a 2048-unit
+/// import to 1000 units. Feature code is not changed. Clears all outline
+/// and shaping caches.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `new_upm` - Units per em to scale to
+/// * `round` - Round coordinates and widths to integers afterwards, keeping masters compatible (default: true)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"upm": 1000, "factor": 0.48828125, "layers": 1200, "glyphs": 600}'
+#[wasm_bindgen]
+pub fn scale_to_upm(new_upm: u16, round: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, _) = outline_filters::scale_to_upm(font, new_upm, round.unwrap_or(true))?;
+    
+    fonts.font_changed(font_id, FontChange::AllGlyphs)?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Set sidebearings of glyphs in the cached font from their ink bounds
+///
+/// Measures each glyph's ink within a vertical zone and sets its
+/// sidebearings in every layer from rules: fixed values or the
+/// sidebearings of reference glyphs, optionally depending on whether a
+/// side is straight or round. Composites keep following their base glyph.
+/// Outline caches of changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `rules_json` - JSON object of spacing rules. Each side value is a number of units or a reference glyph name:
+///   - `left`, `right`: side values for both sides or for one side
+///   - `straight`, `round`: side values for sides that run straight (stems) or touch the extreme (bowls)
+///   - `zone`: "full", "xHeight" or "capHeight" - Heights to measure ink in (default: "full")
+///   - `overshoot`: number - Extend xHeight/capHeight zones by this much below the baseline and above the top (default: 0)
+///   - `categories`: object of "uppercase", "lowercase", "figures", "punctuation", "other" to rule objects with the keys above
+///   - `glyphs`: object of glyph names to rule objects with the keys above
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+///   e.g., '{"left": 50, "right": 50, "overshoot": 12, "categories": {"lowercase": {"zone": "xHeight", "straight": "n", "round": "o"}}}'
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 8, "glyphs": ["A", "Aacute", "n"], "skipped": [{"glyph": "space", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn auto_space(glyph_names_json: Option<String>, rules_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, mut changed) = spacing::auto_space(font, glyph_names_json.as_deref(), rules_json)?;
+    changed.extend(composites::realign_dependents(font, &changed));
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Apply a metrics command to the cached font and clear the affected caches
+fn run_metrics_op(
+    glyph_names_json: Option<String>,
+    layer_scope: Option<String>,
+    op: spacing::MetricsOp,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, mut changed) = spacing::apply_metrics(
+        font,
+        glyph_names_json.as_deref(),
+        layer_scope.as_deref().unwrap_or("all"),
+        op,
+    )?;
+    changed.extend(composites::realign_dependents(font, &changed));
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Center glyphs of the cached font in their advance width
+///
+/// Equalizes the left and right sidebearings (measured on the ink,
+/// including components) without changing the width. Composites using a
+/// changed glyph as their base follow it.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 2, "glyphs": ["A", "Aacute"], "skipped": [{"glyph": "space", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn center_glyph(glyph_names_json: Option<String>, layer_scope: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_metrics_op(glyph_names_json, layer_scope, spacing::MetricsOp::Center, font_id)
+}
+
+/// Set the sidebearings of glyphs in the cached font
+///
+/// Moves the contents to reach the left sidebearing and adjusts the width
+/// for the right one; omitted sides keep their value. Composites using a
+/// changed glyph as their base follow it.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `left` - Left sidebearing in font units, or None to keep it
+/// * `right` - Right sidebearing in font units, or None to keep it
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object as for center_glyph()
+#[wasm_bindgen]
+pub fn set_sidebearings(
+    glyph_names_json: Option<String>,
+    left: Option<f64>,
+    right: Option<f64>,
+    layer_scope: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    run_metrics_op(glyph_names_json, layer_scope, spacing::MetricsOp::SetSidebearings { left, right }, font_id)
+}
+
+/// Set the advance width of glyphs in the cached font
+///
+/// Composites using a changed glyph as their base follow it.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `width` - New advance width in font units
+/// * `alignment` - "left" keeps the left sidebearing, "right" the right one, "center" splits the change (default: "left")
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object as for center_glyph()
+#[wasm_bindgen]
+pub fn set_width(
+    glyph_names_json: Option<String>,
+    width: f64,
+    alignment: Option<String>,
+    layer_scope: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let alignment = spacing::WidthAlignment::from_name(alignment.as_deref().unwrap_or("left"))?;
+    run_metrics_op(glyph_names_json, layer_scope, spacing::MetricsOp::SetWidth { width, alignment }, font_id)
+}
+
+/// Set sidebearings in one master of the cached font and propagate them
+///
+/// The sidebearings are set in the given master as with set_sidebearings();
+/// the other masters follow in the same call, for designers maintaining
+/// many masters. Composites using a changed glyph as their base follow it.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `master_id` - Master the sidebearings are given for
+/// * `left` - Left sidebearing in font units, or None to keep it
+/// * `right` - Right sidebearing in font units, or None to keep it
+/// * `propagation` - How other masters follow (default: "proportional"):
+///   - "proportional": their sidebearings change by the same factor
+///   - "offset": their sidebearings change by the same amount
+///   - "equal": they take the same sidebearings
+///   - "none": only the given master changes
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object as for center_glyph()
+#[wasm_bindgen]
+pub fn set_linked_sidebearings(
+    glyph_names_json: Option<String>,
+    master_id: &str,
+    left: Option<f64>,
+    right: Option<f64>,
+    propagation: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let propagation = spacing::Propagation::from_name(propagation.as_deref().unwrap_or("proportional"))?;
+    let (result, mut changed) = spacing::set_linked_sidebearings(
+        font,
+        glyph_names_json.as_deref(),
+        master_id,
+        left,
+        right,
+        propagation,
+    )?;
+    changed.extend(composites::realign_dependents(font, &changed));
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Set or remove the metrics keys of a glyph in the cached font
+///
+/// Metrics keys link a sidebearing or the width to another glyph, as in
+/// Glyphs: "=n" (same side of "n"), "=|n" (opposite side of "n"), "=|"
+/// (the glyph's own opposite side, for symmetric glyphs), "=H+20", "=o*1.1"
+/// or a fixed "=600". Keys are stored in the glyph's format-specific data
+/// under the Glyphs names, so they survive saving and round-trip with
+/// Glyphs sources. Setting keys does not change the outlines; call
+/// resolve_metrics_keys() to apply them.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to set keys for
+/// * `keys_json` - JSON object with "left", "right" and/or "width" keys, e.g., '{"left": "=n", "right": "=|"}'; null or "" removes a key, omitted sides are unchanged
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with the glyph's keys: '{"left": "=n", "right": "=|"}'
+#[wasm_bindgen]
+pub fn set_metrics_keys(glyph_name: &str, keys_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let keys = metrics_keys::set_metrics_keys(font, glyph_name, keys_json)?;
+    serde_json::to_string(&keys)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Recompute sidebearings and widths of the cached font from metrics keys
+///
+/// Call after editing glyphs that other glyphs are keyed to: the keyed
+/// glyphs that depend on them (directly or through chains of keys) are
+/// re-spaced in every master, references first, and composites follow
+/// their bases. Also reports stale keys (metrics that differ from what the
+/// key gives) and keys that cannot be resolved: missing references, cycles,
+/// invalid keys and contradictory width and right keys. Outline caches of
+/// changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of changed glyph names, e.g., '["n"]'; all keyed glyphs if omitted
+/// * `apply` - Re-space the glyphs (default: true); false only reports
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 2, "glyphs": ["m"], "stale": [{"glyph": "m", "master": "m01", "side": "left", "key": "=n", "value": 42, "expected": 40}], "problems": [{"glyph": "x", "master": "m01", "side": "left", "key": "=foo", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn resolve_metrics_keys(glyph_names_json: Option<String>, apply: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, mut changed) = metrics_keys::resolve_metrics_keys(
+        font,
+        glyph_names_json.as_deref(),
+        apply.unwrap_or(true),
+    )?;
+    changed.extend(composites::realign_dependents(font, &changed));
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Apply a background layer edit to glyphs of the cached font
+fn run_background_op(
+    glyph_names_json: Option<String>,
+    layer_scope: Option<String>,
+    op: backgrounds::BackgroundOp,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = backgrounds::edit_backgrounds(
+        font,
+        glyph_names_json.as_deref(),
+        layer_scope.as_deref().unwrap_or("all"),
+        op,
+    )?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        if let Some(glyph) = font.glyphs.get(name) {
+            glyph_outlines::update_component_graph(font, glyph);
+        }
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Copy layers of the cached font to their backgrounds
+///
+/// Replaces the shapes and anchors of each layer's background by those of
+/// the layer, creating the background where needed, e.g. to keep the
+/// current state for comparison before revising a glyph.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 2, "glyphs": ["a"]}'
+#[wasm_bindgen]
+pub fn copy_to_background(glyph_names_json: Option<String>, layer_scope: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_background_op(glyph_names_json, layer_scope, backgrounds::BackgroundOp::CopyToBackground, font_id)
+}
+
+/// Swap layers of the cached font with their backgrounds
+///
+/// Exchanges shapes and anchors between each layer and its background,
+/// creating an empty background where needed. Outline caches of changed
+/// glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object as for copy_to_background()
+#[wasm_bindgen]
+pub fn swap_background(glyph_names_json: Option<String>, layer_scope: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_background_op(glyph_names_json, layer_scope, backgrounds::BackgroundOp::Swap, font_id)
+}
+
+/// Remove the background layers of layers of the cached font
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object as for copy_to_background(), counting the layers whose background was removed
+#[wasm_bindgen]
+pub fn clear_backgrounds(glyph_names_json: Option<String>, layer_scope: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_background_op(glyph_names_json, layer_scope, backgrounds::BackgroundOp::Clear, font_id)
+}
+
+/// Create the standard glyphs the cached font is missing
+///
+/// Adds .notdef (a box), .null, CR, space and nbspace where missing, with a
+/// layer per master; existing ones get layers for masters they lack. The
+/// spaces and CR take the width of each master's space. Fonts without
+/// these glyphs fail validators and misbehave on some platforms.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"created": [".notdef", "CR"], "completed": [{"glyph": "space", "masters": ["m02"]}]}'
+#[wasm_bindgen]
+pub fn generate_standard_glyphs(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = standard_glyphs::generate_standard_glyphs(font)
+        .map_err(ApiError::from)?;
+    
+    fonts.font_changed(font_id, FontChange::Glyphs(&changed))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Get the production glyph names of the cached font
+///
+/// These are the names the compiler writes to the font unless
+/// `dont_use_production_names` is set: a glyph's explicit production name,
+/// else its name if it is an AGL name, else "uniXXXX"/"uXXXXX" from its
+/// codepoint, converting suffixed and ligature names part by part.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"names": {"A-cy": "uni0410", "a": "a"}, "conflicts": [{"name": "uni0410", "glyphs": ["A-cy", "uni0410"]}]}'
+#[wasm_bindgen]
+pub fn get_production_names(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = production_names::get_production_names(font);
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Rename the glyphs of the cached font with a naming function and rebuild the caches
+fn run_rename(rename: fn(&mut babelfont::Font) -> JsonValue, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let result = rename(font);
+    
+    // Names are cache keys everywhere, so start over
+    fonts.font_changed(font_id, FontChange::AllGlyphs)?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Rename all glyphs of the cached font to their production names
+///
+/// Components, kerning groups, kerning pairs and feature code follow the
+/// renamed glyphs. Renames that would give two glyphs the same name are
+/// skipped and reported.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"renamed": {"A-cy": "uni0410"}, "skipped": [{"glyph": "...", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn rename_to_production(font_id: Option<u32>) -> Result<String, JsValue> {
+    run_rename(production_names::rename_to_production, font_id)
+}
+
+/// Rename all glyphs of the cached font from production to customary names
+///
+/// "uniXXXX" names become customary names ("uni0410" is "A-cy"), also in
+/// suffixed and ligature names. Glyphs keep their old name as explicit
+/// production name where it would not be derived from the new one.
+/// References are updated as by rename_to_production().
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"renamed": {"uni0410": "A-cy"}, "skipped": [{"glyph": "...", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn rename_to_friendly(font_id: Option<u32>) -> Result<String, JsValue> {
+    run_rename(production_names::rename_to_friendly, font_id)
+}
+
+/// Build composite glyphs in the cached font from Unicode decompositions and anchors
+///
+/// For each requested glyph (e.g. "Aacute"), the character's canonical
+/// decomposition names the base and marks; every master gets a component
+/// of the base and of each mark, placed by matching the mark's `_name`
+/// anchor with the base's `name` anchor (marks stack on earlier marks).
+/// Uppercase bases use ".case" marks and "i"/"j" use dotless forms where
+/// available. Missing glyphs are created and encoded.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyphs_json` - JSON array of glyph names and/or codepoints, e.g., '["Aacute", 7865, "aacute.sc"]'
+/// * `replace` - Rebuild glyphs that already have outlines or components (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"built": [{"glyph": "Aacute", "base": "A", "marks": ["acutecomb.case"], "created": true}], "skipped": [{"glyph": "Lslash", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn build_composites(glyphs_json: &str, replace: Option<bool>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = composites::build_composites(font, glyphs_json, replace.unwrap_or(false))?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        if let Some(glyph) = font.glyphs.get(name) {
+            glyph_outlines::update_component_graph(font, glyph);
+        }
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Turn automatic alignment of a glyph in the cached font on or off
+fn run_auto_alignment(glyph_name: &str, enabled: bool, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = composites::set_auto_alignment(font, glyph_name, enabled)?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Make a composite glyph of the cached font follow its base automatically
+///
+/// Like automatic alignment in Glyphs: the composite takes the advance
+/// width and anchors of its base (first) component, and each further
+/// component is placed on the matching anchor of the base or of earlier
+/// marks. The glyph is aligned right away and again whenever a component
+/// glyph is edited through invalidate_glyph() or the metrics commands, so
+/// composites don't drift when spacing changes. The glyph must consist of
+/// components only.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Name of the composite glyph
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyph": "Aacute", "autoAligned": true, "layers": 2}'
+#[wasm_bindgen]
+pub fn enable_auto_alignment(glyph_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_auto_alignment(glyph_name, true, font_id)
+}
+
+/// Stop automatic alignment of a composite glyph in the cached font
+///
+/// The components keep their current positions.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Name of the composite glyph
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyph": "Aacute", "autoAligned": false, "layers": 0}'
+#[wasm_bindgen]
+pub fn disable_auto_alignment(glyph_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    run_auto_alignment(glyph_name, false, font_id)
+}
+
+/// Find where a glyph of the cached font is used as a component
+///
+/// Lists every composite referencing the glyph, directly or through other
+/// composites, for warnings before a deletion or rename and for refreshing
+/// the dependents of an edited base glyph.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Name of the glyph
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyph": "acutecomb", "direct": [{"glyph": "aacute", "layers": ["m01", "m02"]}], "nested": [{"glyph": "aacute.sc", "depth": 2, "via": "aacute"}], "all": ["aacute", "aacute.sc"]}'. Direct users list the layers referencing the glyph; nested users are ordered by depth.
+#[wasm_bindgen]
+pub fn get_component_usages(glyph_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = composites::component_usages(font, glyph_name)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Expand or contract the outlines of glyphs in the cached font
+///
+/// Offsets the closed paths of each selected layer: positive distances make
+/// outlines heavier, negative ones lighter, for quick weight and contrast
+/// experiments. Components follow their offset base glyphs. Outline caches
+/// of changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `distance` - Horizontal offset in font units
+/// * `options_json` - Optional JSON object:
+///   - `vertical`: number - Vertical offset in font units, for contrast changes (default: distance)
+///   - `join`: "miter", "round" or "bevel" - Corner shape (default: "miter")
+///   - `miterLimit`: number - Longest miter as a multiple of the offset before beveling (default: 4)
+///   - `layers`: "all", "masters" or a layer/master ID (default: "all")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"layers": 4, "glyphs": ["A"], "warnings": [{"glyph": "A", "message": "...", "contours": {"Regular": 1, "Bold": 2}}]}'
+#[wasm_bindgen]
+pub fn offset_paths(
+    glyph_names_json: Option<String>,
+    distance: f64,
+    options_json: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (result, changed) = path_ops::offset_paths(
+        font,
+        glyph_names_json.as_deref(),
+        distance,
+        options_json.as_deref(),
+    )?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Combine selected paths of a layer in the cached font with a boolean operation
+///
+/// Runs union, subtract, intersect or xor between two sets of closed paths
+/// (e.g. punching a counter out of a bowl) and replaces them in the layer
+/// by the result. Outline caches of the glyph and its dependents are
+/// cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to edit
+/// * `layer_id` - ID of the layer to edit
+/// * `paths_a_json` - JSON array of shape indices of the first operand, e.g., '[0]'
+/// * `paths_b_json` - JSON array of shape indices of the second operand, e.g., '[2, 3]'
+/// * `operation` - "union", "subtract", "intersect" or "xor"
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with the edited layer in .babelfont format and the shape indices of the resulting paths: '{"layer": {...}, "paths": [0, 1]}'
+#[wasm_bindgen]
+pub fn boolean_paths(
+    glyph_name: &str,
+    layer_id: &str,
+    paths_a_json: &str,
+    paths_b_json: &str,
+    operation: &str,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let op = path_ops::BooleanOp::from_name(operation)?;
+    let paths_a: Vec<usize> = serde_json::from_str(paths_a_json)
+        .map_err(|e| ApiError::invalid_json("path indices", e))?;
+    let paths_b: Vec<usize> = serde_json::from_str(paths_b_json)
+        .map_err(|e| ApiError::invalid_json("path indices", e))?;
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let paths = path_ops::boolean_layer_paths(font, glyph_name, layer_id, &paths_a, &paths_b, op)?;
+    
+    let affected = glyph_outlines::with_component_dependents(font, glyph_name);
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let font = fonts.font(font_id)?;
+    let layer = font.glyphs
+        .get(glyph_name)
+        .and_then(|glyph| glyph.layers.iter().find(|layer| layer.id.as_deref() == Some(layer_id)));
+    serde_json::to_string(&serde_json::json!({ "layer": layer, "paths": paths }))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Reverse the direction of paths of a layer in the cached font
+///
+/// Flips the winding of the selected paths (or all paths of the layer),
+/// keeping every segment's type, e.g. to fix contours of imported outlines.
+/// Outline caches of the glyph and its dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to edit
+/// * `layer_id` - ID of the layer to edit
+/// * `path_indices_json` - Optional JSON array of shape indices, e.g., '[0, 2]'. Omit for all paths.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with the edited layer in .babelfont format and the shape indices of the reversed paths: '{"layer": {...}, "paths": [0, 2]}'
+#[wasm_bindgen]
+pub fn reverse_contours(
+    glyph_name: &str,
+    layer_id: &str,
+    path_indices_json: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let path_indices: Option<Vec<usize>> = path_indices_json
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| ApiError::invalid_json("path indices", e))?;
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let paths = source_checks::reverse_contours(font, glyph_name, layer_id, path_indices.as_deref())?;
+    
+    if !paths.is_empty() {
+        let affected = glyph_outlines::with_component_dependents(font, glyph_name);
+        fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    }
+    
+    let font = fonts.font(font_id)?;
+    let layer = font.glyphs
+        .get(glyph_name)
+        .and_then(|glyph| glyph.layers.iter().find(|layer| layer.id.as_deref() == Some(layer_id)));
+    serde_json::to_string(&serde_json::json!({ "layer": layer, "paths": paths }))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Copy shapes of a layer in the cached font to a clipboard payload
+///
+/// The payload is a compact JSON string (paths as node strings, components
+/// by name and transform) that records the font's units per em, so it can
+/// be kept on the system clipboard and pasted into this or another font,
+/// also in a later session, with paste_shapes().
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to copy from
+/// * `layer_id` - ID of the layer to copy from
+/// * `shape_indices_json` - Optional JSON array of shape indices, e.g., '[0, 2]'. Omit for all shapes.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - Clipboard payload, e.g., '{"format": "counterpunch-shapes", "version": 1, "upm": 1000, "glyph": "a", "layer": "m01", "shapes": [{"closed": true, "nodes": "100 0 l 200 0 l 150 300 l"}]}'
+#[wasm_bindgen]
+pub fn copy_shapes(
+    glyph_name: &str,
+    layer_id: &str,
+    shape_indices_json: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let shape_indices: Option<Vec<usize>> = shape_indices_json
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| ApiError::invalid_json("shape indices", e))?;
+    
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let payload = clipboard::copy_shapes(font, glyph_name, layer_id, shape_indices.as_deref())?;
+    serde_json::to_string(&payload)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Paste a clipboard payload into a layer of the cached font
+///
+/// Shapes from copy_shapes() are added to the layer (or replace its
+/// shapes). Payloads from fonts with other units per em are scaled to fit.
+/// Components of glyphs this font lacks, or that would make the glyph
+/// refer to itself, are skipped and reported. Outline caches of the glyph
+/// and its dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_name` - Glyph to paste into
+/// * `layer_id` - ID of the layer to paste into
+/// * `payload` - Clipboard payload from copy_shapes()
+/// * `options_json` - Optional JSON object:
+///   - `replace`: bool - Replace the layer's shapes instead of adding to them (default: false)
+///   - `dx`, `dy`: number - Offset of the pasted shapes in font units (default: 0)
+///   - `scale`: bool - Scale to this font's units per em (default: true)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object with the edited layer in .babelfont format, the indices of the pasted shapes and skipped components: '{"layer": {...}, "shapes": [3, 4], "skipped": [{"ref": "acutecomb", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn paste_shapes(
+    glyph_name: &str,
+    layer_id: &str,
+    payload: &str,
+    options_json: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let mut result = clipboard::paste_shapes(font, glyph_name, layer_id, payload, options_json.as_deref())?;
+    
+    if let Some(glyph) = font.glyphs.get(glyph_name) {
+        glyph_outlines::update_component_graph(font, glyph);
+    }
+    let mut changed = HashSet::from([glyph_name.to_string()]);
+    changed.extend(composites::realign_dependents(font, &changed));
+    let affected = glyph_outlines::with_component_dependents(font, glyph_name);
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let font = fonts.font(font_id)?;
+    let layer = font.glyphs
+        .get(glyph_name)
+        .and_then(|glyph| glyph.layers.iter().find(|layer| layer.id.as_deref() == Some(layer_id)));
+    result["layer"] = serde_json::to_value(layer)
+        .map_err(|e| ApiError::serialization("result", e))?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Check the cached font for open contours, stray points and zero-length segments
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "open-contour", "stray-point" or "zero-length-segment" and context '{"glyph": "a", "layer": "Regular", "path": 0, "node": 12}'
+#[wasm_bindgen]
+pub fn check_contours(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let report = source_checks::check_contours(font, glyph_names_json.as_deref())?;
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check the components of the cached font for broken references
+///
+/// Reports components pointing at missing glyphs, glyphs using themselves
+/// as a component and circular reference loops, which otherwise fail
+/// compilation without pointing at the source.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "missing-component", "self-reference" or "component-cycle"
+#[wasm_bindgen]
+pub fn check_component_references(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&source_checks::check_component_references(font))
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check the cached font for components with suspicious transforms
+///
+/// Flags mirrored components, components scaled to almost nothing, and
+/// components offset more than two em or by fractional units, which are
+/// usually copy-paste accidents. Mirrored components interact badly with
+/// overlap removal and hinting.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "component-transform" and context '{"glyph": "b", "layer": "Regular", "component": 0, "reference": "d", "reason": "flipped", "transform": [-1, 0, 0, 1, 500, 0]}'
+#[wasm_bindgen]
+pub fn check_component_transforms(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let report = source_checks::check_component_transforms(font, glyph_names_json.as_deref())?;
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Decompose the components flagged by check_component_transforms()
+///
+/// Each flagged component is replaced by its outlines in its layer; contours
+/// of mirrored components are reversed to keep the winding direction.
+/// Outline caches of the changed glyphs and their dependents are cleared.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"decomposed": 3, "glyphs": ["b", "q"]}'
+#[wasm_bindgen]
+pub fn decompose_flagged(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let (decomposed, changed) = source_checks::decompose_flagged_components(font, glyph_names_json.as_deref())?;
+    
+    let mut affected = HashSet::new();
+    for name in &changed {
+        if let Some(glyph) = font.glyphs.get(name) {
+            glyph_outlines::update_component_graph(font, glyph);
+        }
+        affected.extend(glyph_outlines::with_component_dependents(font, name));
+    }
+    fonts.font_changed(font_id, FontChange::Glyphs(&affected))?;
+    
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    serde_json::to_string(&serde_json::json!({ "decomposed": decomposed, "glyphs": glyphs }))
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Check mark and base anchors of the cached font for consistency
+///
+/// Reports marks whose `_name` anchors have no matching `name` anchor on
+/// any glyph of a compatible script, bases missing an anchor that marks
+/// attach to and most other bases of their script have, and anchors that
+/// are present in some masters of a glyph but not in others.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "unattachable-mark", "missing-base-anchor" or "anchor-master-mismatch"
+#[wasm_bindgen]
+pub fn check_anchors(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&source_checks::check_anchors(font))
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check Unicode assignments of the cached font
+///
+/// Reports codepoints assigned to more than one exported glyph, which makes
+/// the compiled cmap nondeterministic, and glyphs whose name implies a
+/// codepoint other than the ones they have.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "duplicate-codepoint" or "name-codepoint-mismatch"
+#[wasm_bindgen]
+pub fn check_unicode_assignments(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&source_checks::check_unicode_assignments(font))
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check that glyphs of the cached font are drawn consistently in all masters
+///
+/// Reports glyphs missing a layer for some master, layers whose width
+/// differs from what their width metrics key gives, and empty master layers
+/// of exported glyphs that are not spaces.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names to check; omit to check all glyphs
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "missing-master-layer", "metrics-key-width" or "empty-layer"
+#[wasm_bindgen]
+pub fn check_master_consistency(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let report = source_checks::check_master_consistency(font, glyph_names_json.as_deref())?;
+    serde_json::to_string(&report)
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check axis mappings and master/instance locations of the cached font
+///
+/// Reports axes whose ranges are incomplete or unordered, user to design
+/// mappings that are not monotonic or do not cover min/default/max, and
+/// master or instance locations outside the designspace, so that problems
+/// surface before fontc fails on them.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "axis-range", "axis-mapping" or "location-outside-designspace"
+#[wasm_bindgen]
+pub fn check_axis_mappings(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&source_checks::check_axis_mappings(font))
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Check kerning groups and pairs of the cached font for conflicts
+///
+/// Reports glyphs assigned to several groups on one side, group members
+/// and pair sides that do not exist, and glyph exceptions that repeat the
+/// value of the group pair they override.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON report in the format of validate_compiled_font(); findings have check "multiple-groups", "missing-group-member", "missing-kerning-side" or "redundant-exception"
+#[wasm_bindgen]
+pub fn check_kerning(font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    serde_json::to_string(&kerning::check_kerning(font))
+        .map_err(|e| ApiError::serialization("report", e).into())
+}
+
+/// Expand the group kerning of the cached font into glyph pairs
+///
+/// Resolves every kerned glyph pair to the value it gets at shaping time,
+/// with glyph exceptions overriding group pairs, for inspecting kerning or
+/// exporting it to tools that only read flat pairs. Pairs resolving to zero
+/// are left out.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `master_id` - Optional master ID; all masters if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"masters": [{"master": "m01", "pairs": [["A", "V", -80], ["A", "W", -60]]}], "pairs": 2}'
+#[wasm_bindgen]
+pub fn flatten_kerning(master_id: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = kerning::flatten_kerning(font, master_id.as_deref())?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Get the kerning of a glyph pair of the cached font at a location
+///
+/// Resolves groups and glyph exceptions in each master, interpolates the
+/// masters' values and reports which rule supplies the value, so the editor
+/// can show where a pair's kerning comes from while scrubbing the location.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `left` - Left glyph name
+/// * `right` - Right glyph name
+/// * `location_json` - JSON object with axis tags and values in USER SPACE; empty for the default location
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"left": "A", "right": "V", "value": -75.0, "rule": {"left": "@A", "right": "@V", "kind": "group-group"}, "groups": {"left": "A", "right": "V"}, "masters": [...]}'
+#[wasm_bindgen]
+pub fn get_pair_kerning(left: &str, right: &str, location_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = kerning::get_pair_kerning(font, left, right, location_json)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Set the kerning of a pair in one master of the cached font and propagate it
+///
+/// The other masters follow in the same call, so a kerning edit does not
+/// have to be repeated in every master.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `left` - Left glyph or `@group`
+/// * `right` - Right glyph or `@group`
+/// * `master_id` - Master the value is given for
+/// * `value` - Kerning value in font units
+/// * `propagation` - How other masters follow, as for set_linked_sidebearings() (default: "proportional")
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"left": "A", "right": "@V", "masters": [{"master": "m01", "value": -80, "previous": -60}]}'
+#[wasm_bindgen]
+pub fn set_linked_kerning(
+    left: &str,
+    right: &str,
+    master_id: &str,
+    value: f64,
+    propagation: Option<String>,
+    font_id: Option<u32>,
+) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let propagation = spacing::Propagation::from_name(propagation.as_deref().unwrap_or("proportional"))?;
+    let result = kerning::set_linked_kerning(font, left, right, master_id, value, propagation)?;
+    if result.get("masters").and_then(|v| v.as_array()).is_some_and(|masters| !masters.is_empty()) {
+        fonts.font_changed(font_id, FontChange::Layout)?;
+    }
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Export the kerning of the cached font as feature code or AFM data
+///
+/// Feature code keeps the kerning groups as classes and lists glyph pairs,
+/// exceptions and group pairs as `pos` rules; AFM data lists the flattened
+/// glyph pairs as `KPX` lines. Either can be read by other font tools or
+/// imported again with import_kerning().
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `format` - "fea" or "afm"
+/// * `master_id` - Optional master ID; the default master if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - Feature code or AFM data
+#[wasm_bindgen]
+pub fn export_kerning(format: &str, master_id: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let format = kerning::KerningFormat::from_name(format)?;
+    kerning::export_kerning(font, format, master_id.as_deref())
+        .map_err(|e| e.into())
+}
+
+/// Import kerning from feature code or AFM data into the cached font
+///
+/// Pairs are merged into the kerning of a master, replacing pairs with the
+/// same sides. Classes used by `pos` rules become kerning groups. Glyphs
+/// missing from the font are skipped and reported; contextual and other
+/// unsupported rules are counted.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `format` - "fea" or "afm"
+/// * `text` - Feature code or AFM data
+/// * `master_id` - Optional master ID; the default master if omitted
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"pairs": 120, "groups": 14, "unsupported": 2, "skipped": ["Adieresis.alt"]}'
+#[wasm_bindgen]
+pub fn import_kerning(format: &str, text: &str, master_id: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font_mut(font_id)?;
+    
+    let format = kerning::KerningFormat::from_name(format)?;
+    let result = kerning::import_kerning(font, format, text, master_id.as_deref())?;
+    if result.get("pairs").and_then(|v| v.as_u64()).unwrap_or(0) > 0
+        || result.get("groups").and_then(|v| v.as_u64()).unwrap_or(0) > 0
+    {
+        fonts.font_changed(font_id, FontChange::Layout)?;
+    }
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Suggest kerning values for pairs of the cached font from their outlines
+///
+/// Measures the white space between the outlines of each pair at a
+/// location (the area between the facing profiles, with deep counters
+/// capped) and proposes the kerning that gives it the white space of a
+/// reference pair like "HH". The values are a starting point for the
+/// designer to accept or reject; the font is not changed.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `pairs_json` - JSON array of pairs, e.g., '[["A", "V"], ["T", "o"]]', or an object with sides, each a list of glyph names or a category ("uppercase", "lowercase", "figures", "punctuation", "other"), e.g., '{"left": "uppercase", "right": "lowercase"}'
+/// * `location_json` - JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 400.0}'. Empty object '{}' uses default location.
+/// * `options_json` - Optional JSON object:
+///   - `reference`: [string, string] - Pair whose white space is the target (default: ["H", "H"], else ["n", "n"])
+///   - `depth`: number - Deepest counter that counts as white space, in font units (default: 15% of the UPM)
+///   - `minGap`: number - Closest the outlines may come, in font units (default: 2% of the UPM)
+///   - `round`: number - Round values to multiples of this (default: 5)
+///   - `threshold`: number - Leave out suggestions smaller than this (default: the rounding step)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"suggestions": [{"left": "A", "right": "V", "value": -80, "gap": 132.5}], "reference": {"left": "H", "right": "H", "gap": 180.0}, "skipped": [{"glyph": "space", "reason": "..."}]}'
+#[wasm_bindgen]
+pub fn suggest_kerning(pairs_json: &str, location_json: &str, options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = auto_kerning::suggest_kerning(font, pairs_json, location_json, options_json.as_deref())?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Get outline complexity statistics of glyphs in the cached font
+///
+/// Reports contour, node, component and segment counts of each glyph's
+/// default master layer, with an estimate of its compiled size (glyf and
+/// gvar), largest first, to find glyphs that bloat the font.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `glyph_names_json` - Optional JSON array of glyph names, e.g., '["A", "B"]'. Omit for all glyphs.
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyphs": [{"glyph": "ampersand", "layers": 2, "contours": 3, "nodes": 148, "onCurveNodes": 52, "offCurveNodes": 96, "components": 0, "segments": {"line": 4, "curve": 48, "qcurve": 0}, "estimatedBytes": 1210}], "totals": {"contours": 1520, "nodes": 30112, "estimatedBytes": 184000}}'
+#[wasm_bindgen]
+pub fn get_outline_stats(glyph_names_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let stats = source_checks::outline_stats(font, glyph_names_json.as_deref())?;
+    serde_json::to_string(&stats)
+        .map_err(|e| ApiError::serialization("outline statistics", e).into())
+}
+
+/// Check the coverage of a glyphset by the cached source font
+///
+/// Intended for designers targeting a Google Fonts submission: lists the
+/// codepoints of the set that no exported glyph carries, with a suggested
+/// glyph name for each.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `set_name` - A named character set ("GF Latin Core", "GF Latin Plus", "GF Cyrillic Core", "GF Cyrillic Plus", "GF Greek Core", "GF Greek Plus", "Adobe Latin 3") or a JSON array of codepoints
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"charset": "GF Latin Core", "total": 331, "coveredCount": 329, "missingCount": 2, "missing": [{"codepoint": 7838, "hex": "U+1E9E", "char": "ẞ", "suggestedName": "Germandbls", "existingGlyph": null}]}'
+#[wasm_bindgen]
+pub fn check_glyphset_coverage(set_name: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let coverage = source_checks::glyphset_coverage(font, set_name)?;
+    serde_json::to_string(&coverage)
+        .map_err(|e| ApiError::serialization("glyphset coverage", e).into())
+}
+
+/// Find the glyphs of the cached font matching a query
+///
+/// For the smart filters of the glyph overview. All predicates given must
+/// match; predicates taking a list match any of its entries. Glyphs
+/// without codepoints are judged by the character of their base name, so
+/// "a.sc" is a Latin glyph in "Basic Latin".
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `query_json` - JSON object with any of:
+///   - `name`: string | [string] - Glob on the glyph name, with `*` and `?`, e.g., "*.sc"
+///   - `regex`: string - Regular expression on the glyph name, e.g., "^uni0[0-9A-F]{3}$"
+///   - `unicodes`: [number | string] - Codepoints as numbers, "U+XXXX" or ranges "U+0400-04FF"
+///   - `block`: string | [string] - Unicode block names, e.g., "Latin Extended-A"
+///   - `script`: string | [string] - Script names or ISO 15924 codes, e.g., "Cyrillic" or "Cyrl"
+///   - `category`: string | [string] - Glyph categories, e.g., "mark"
+///   - `usesComponent`: string - Glyphs using this glyph as a component, directly or nested
+///   - `hasKerning`: bool - Whether the glyph is kerned in any master, directly or through its groups
+///   - `modifiedSince`: string - Glyphs last changed at or after this date, e.g., "2024-05-01" or "2024-05-01T12:00:00"; edits stamp the change time in UTC, and glyphs neither edited nor given a change time by their source do not match
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"glyphs": ["A", "Aacute"], "count": 2}', in glyph order
+#[wasm_bindgen]
+pub fn find_glyphs(query_json: &str, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = glyph_query::find_glyphs(font, query_json)?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Report the languages supported by the cached font
+///
+/// Judges support from the encoded codepoints of exported glyphs against
+/// the base characters of each language's orthography (Hyperglot-style
+/// data for the major languages of the Latin, Cyrillic, Greek, Armenian,
+/// Georgian, Hebrew, Arabic and Devanagari scripts). Unsupported languages
+/// list the characters blocking them, and the suggestions rank missing
+/// characters by the number of languages they would unblock.
+///
+/// Requires that a font has been stored via store_font() first.
+///
+/// # Arguments
+/// * `options_json` - Optional JSON object:
+///   - `scripts`: [string] - Only report languages of these scripts, e.g., ["Latin", "Cyrillic"]
+///   - `decomposed`: bool - Count a character as covered if the glyphs of its canonical decomposition are, for fonts composing accents with mark positioning (default: false)
+/// * `font_id` - Handle of the font from store_font(); the current font if omitted
+///
+/// # Returns
+/// * `String` - JSON object: '{"supportedCount": 41, "supported": [{"code": "deu", "name": "German", "script": "Latin"}], "unsupported": [{"code": "ron", "name": "Romanian", "script": "Latin", "missingCount": 2, "missing": [{"codepoint": 537, "hex": "U+0219", "char": "ș", "suggestedName": "scommaaccent"}]}], "suggestions": [{"codepoint": 537, "hex": "U+0219", "char": "ș", "suggestedName": "scommaaccent", "existingGlyph": null, "languages": ["ron"]}]}'
+#[wasm_bindgen]
+pub fn get_language_support(options_json: Option<String>, font_id: Option<u32>) -> Result<String, JsValue> {
+    let mut fonts = FONT_CACHE.lock().unwrap();
+    let font = fonts.font(font_id)?;
+    
+    let result = languages::language_support(font, options_json.as_deref())?;
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
@@ -0,0 +1,79 @@
+// Logging module
+//
+// Diagnostics of the Rust code (font loading times, interpolation warnings,
+// compile stages) go through this module instead of straight to the browser
+// console, so the host app can route them into its own telemetry and show
+// progress to the user. A handler registered from JS receives one record per
+// message:
+//
+//   { "level": "info", "module": "compile", "message": "...", "payload": {...} }
+//
+// The payload holds the values of the message (names, counts, durations) in
+// structured form. Without a handler, messages go to the console as before.
+
+use std::cell::RefCell;
+
+use serde_json::Value as JsonValue;
+use wasm_bindgen::JsValue;
+
+thread_local! {
+    // JS callback receiving log records
+    // (JS functions cannot be shared between threads, so it is kept per thread)
+    static LOG_HANDLER: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Info,
+    Warn,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Warn => "warn",
+        }
+    }
+}
+
+/// Set or remove the JS handler of log records
+pub fn set_handler(handler: Option<js_sys::Function>) {
+    LOG_HANDLER.with(|current| *current.borrow_mut() = handler);
+}
+
+/// Log an informational message
+pub fn info(module: &str, message: &str, payload: JsonValue) {
+    log(Level::Info, module, message, payload);
+}
+
+/// Log a warning
+pub fn warn(module: &str, message: &str, payload: JsonValue) {
+    log(Level::Warn, module, message, payload);
+}
+
+/// Pass a record to the handler, or write the message to the console
+///
+/// A handler that throws does not interrupt the caller; the message goes to
+/// the console instead. The handler is called outside the borrow of its cell,
+/// so it may log or replace itself.
+fn log(level: Level, module: &str, message: &str, payload: JsonValue) {
+    let handler = LOG_HANDLER.with(|handler| handler.borrow().clone());
+    let handled = handler.is_some_and(|handler| {
+        let record = js_sys::Object::new();
+        let payload = js_sys::JSON::parse(&payload.to_string()).unwrap_or(JsValue::NULL);
+        let _ = js_sys::Reflect::set(&record, &JsValue::from_str("level"), &JsValue::from_str(level.name()));
+        let _ = js_sys::Reflect::set(&record, &JsValue::from_str("module"), &JsValue::from_str(module));
+        let _ = js_sys::Reflect::set(&record, &JsValue::from_str("message"), &JsValue::from_str(message));
+        let _ = js_sys::Reflect::set(&record, &JsValue::from_str("payload"), &payload);
+        handler.call1(&JsValue::NULL, &record).is_ok()
+    });
+    if handled {
+        return;
+    }
+    let line = JsValue::from_str(&format!("[Rust] {}", message));
+    match level {
+        Level::Info => web_sys::console::log_1(&line),
+        Level::Warn => web_sys::console::warn_1(&line),
+    }
+}
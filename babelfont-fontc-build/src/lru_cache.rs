@@ -0,0 +1,108 @@
+// Byte-budgeted LRU cache module
+//
+// This module provides a least-recently-used cache whose capacity is a byte
+// budget rather than an entry count. Callers pass an estimated size with each
+// value; the least recently used entries are evicted when the total exceeds
+// the budget.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+struct Entry<V> {
+    value: V,
+    weight: usize,
+    tick: u64,
+}
+
+/// Least-recently-used cache with a byte budget
+pub struct ByteLru<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    // Access tick -> key, oldest first
+    order: BTreeMap<u64, K>,
+    tick: u64,
+    bytes: usize,
+    budget: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> ByteLru<K, V> {
+    /// Create an empty cache holding at most `budget` bytes
+    pub fn new(budget: usize) -> Self {
+        ByteLru {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            bytes: 0,
+            budget,
+        }
+    }
+
+    /// Look up a value, marking it as most recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let tick = self.tick;
+        let entry = self.entries.get_mut(key)?;
+        if let Some(key) = self.order.remove(&entry.tick) {
+            self.order.insert(tick, key);
+        }
+        entry.tick = tick;
+        Some(&entry.value)
+    }
+
+    /// Insert a value with its estimated size in bytes
+    ///
+    /// Values larger than the whole budget are not stored.
+    pub fn insert(&mut self, key: K, value: V, weight: usize) {
+        self.remove(&key);
+        if weight > self.budget {
+            return;
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, Entry { value, weight, tick: self.tick });
+        self.bytes += weight;
+        self.evict();
+    }
+
+    /// Remove a value
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.tick);
+        self.bytes -= entry.weight;
+        Some(entry.value)
+    }
+
+    /// Keep only the entries whose key matches the predicate
+    pub fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+        let removed: Vec<K> = self.entries.keys().filter(|key| !keep(key)).cloned().collect();
+        for key in removed {
+            self.remove(&key);
+        }
+    }
+
+    /// Change the byte budget, evicting entries if the cache is now too large
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    /// Estimated size of all cached values in bytes
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Number of cached values
+    pub fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Evict least recently used entries until the cache fits its budget
+    fn evict(&mut self) {
+        while self.bytes > self.budget {
+            let Some((_, key)) = self.order.pop_first() else { break };
+            if let Some(entry) = self.entries.remove(&key) {
+                self.bytes -= entry.weight;
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Mark feature generation module
+//
+// This module writes `mark` and `mkmk` feature code from the anchors of the
+// source glyphs, so users can inspect and edit the attachment rules and keep
+// them in the source instead of relying on the compiler's anchor writer.
+// Marks (with `_top` anchors) form one mark class per anchor; bases, ligatures
+// (`top_1`, `top_2`, ... per component) and marks with a `top` anchor get
+// mark-to-base, mark-to-ligature and mark-to-mark lookups for it. Anchor
+// positions are taken from one master, as plain feature code has no
+// per-master values.
+
+use std::collections::BTreeMap;
+
+use crate::errors::ApiError;
+use crate::source_checks::attachment_name;
+
+/// Anchor position in font units
+type Position = (f64, f64);
+
+/// Glyphs taking part in the attachment of one anchor name
+#[derive(Default)]
+struct Attachment {
+    /// Marks with the `_name` anchor
+    marks: Vec<(String, Position)>,
+    /// Non-mark glyphs with the `name` anchor
+    bases: Vec<(String, Position)>,
+    /// Ligatures with `name_1`, `name_2`, ... anchors, by component
+    ligatures: Vec<(String, Vec<Option<Position>>)>,
+    /// Marks with the `name` anchor, for stacking
+    mark_bases: Vec<(String, Position)>,
+}
+
+/// Feature code name for an anchor: letters, digits, `.` and `_`
+fn code_name(anchor: &str) -> String {
+    anchor
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_') { c } else { '_' })
+        .collect()
+}
+
+fn anchor_code((x, y): Position) -> String {
+    format!("<anchor {} {}>", x.round(), y.round())
+}
+
+/// Generate `mark` and `mkmk` feature code from the anchors of a master
+///
+/// Glyphs that are not exported are left out. A glyph is a mark if its
+/// category is Mark or it has a `_name` anchor. Ligature components
+/// without an anchor get `<anchor NULL>`.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `options_json` - Optional JSON object:
+///   - `master`: string - Master whose anchors are used (default: the default master)
+///   - `mkmk`: bool - Write the mkmk feature (default: true)
+///
+/// # Returns
+/// * `String` - Feature code with mark classes, `feature mark` and `feature mkmk`
+pub fn generate_anchor_features(font: &babelfont::Font, options_json: Option<&str>) -> Result<String, ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("options", e))?
+    };
+    let with_mkmk = options.get("mkmk").and_then(|v| v.as_bool()).unwrap_or(true);
+    let master_id = match options.get("master").and_then(|v| v.as_str()) {
+        Some(id) => font
+            .masters
+            .iter()
+            .find(|master| master.id == id)
+            .map(|master| master.id.clone())
+            .ok_or_else(|| ApiError::master_not_found(id))?,
+        None => crate::source_checks::default_master_id(font)
+            .map(str::to_string)
+            .or_else(|| font.masters.first().map(|master| master.id.clone()))
+            .ok_or_else(|| "The font has no masters".to_string())?,
+    };
+
+    let mut attachments: BTreeMap<String, Attachment> = BTreeMap::new();
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported) {
+        let Some(layer) = glyph
+            .layers
+            .iter()
+            .find(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id.as_str()))
+        else {
+            continue;
+        };
+        let name = glyph.name.to_string();
+        let is_mark = matches!(glyph.category, babelfont::GlyphCategory::Mark)
+            || layer.anchors.iter().any(|anchor| anchor.name.starts_with('_'));
+        let mut components: BTreeMap<String, Vec<Option<Position>>> = BTreeMap::new();
+        for anchor in &layer.anchors {
+            let position = (anchor.x, anchor.y);
+            if let Some(mark) = anchor.name.strip_prefix('_') {
+                attachments.entry(mark.to_string()).or_default().marks.push((name.clone(), position));
+                continue;
+            }
+            let base = attachment_name(&anchor.name);
+            if base != anchor.name.as_str() && !is_mark {
+                // Ligature anchor "top_2" is component 2
+                let index: usize = anchor.name[base.len() + 1..].parse().unwrap_or(0);
+                if index == 0 {
+                    continue;
+                }
+                let slots = components.entry(base.to_string()).or_default();
+                if slots.len() < index {
+                    slots.resize(index, None);
+                }
+                slots[index - 1] = Some(position);
+                continue;
+            }
+            let attachment = attachments.entry(anchor.name.to_string()).or_default();
+            if is_mark {
+                attachment.mark_bases.push((name.clone(), position));
+            } else {
+                attachment.bases.push((name.clone(), position));
+            }
+        }
+        for (base, slots) in components {
+            attachments.entry(base).or_default().ligatures.push((name.clone(), slots));
+        }
+    }
+    attachments.retain(|_, attachment| !attachment.marks.is_empty());
+
+    let mut code = format!("# Mark attachment generated from the anchors of master '{}'\n\n", master_id);
+    for (anchor, attachment) in &attachments {
+        for (mark, position) in &attachment.marks {
+            code.push_str(&format!("markClass {} {} @MC_{};\n", mark, anchor_code(*position), code_name(anchor)));
+        }
+    }
+
+    code.push_str("\nfeature mark {\n");
+    for (anchor, attachment) in &attachments {
+        let name = code_name(anchor);
+        if !attachment.bases.is_empty() {
+            code.push_str(&format!("    lookup mark_{} {{\n", name));
+            for (base, position) in &attachment.bases {
+                code.push_str(&format!("        pos base {} {} mark @MC_{};\n", base, anchor_code(*position), name));
+            }
+            code.push_str(&format!("    }} mark_{};\n", name));
+        }
+        if !attachment.ligatures.is_empty() {
+            code.push_str(&format!("    lookup mark_{}_ligature {{\n", name));
+            for (ligature, slots) in &attachment.ligatures {
+                let components: Vec<String> = slots
+                    .iter()
+                    .map(|slot| match slot {
+                        Some(position) => format!("{} mark @MC_{}", anchor_code(*position), name),
+                        None => "<anchor NULL>".to_string(),
+                    })
+                    .collect();
+                code.push_str(&format!(
+                    "        pos ligature {} {};\n",
+                    ligature,
+                    components.join("\n            ligComponent ")
+                ));
+            }
+            code.push_str(&format!("    }} mark_{}_ligature;\n", name));
+        }
+    }
+    code.push_str("} mark;\n");
+
+    let stacking: Vec<(&String, &Attachment)> =
+        attachments.iter().filter(|(_, attachment)| !attachment.mark_bases.is_empty()).collect();
+    if with_mkmk && !stacking.is_empty() {
+        code.push('\n');
+        for (anchor, attachment) in &stacking {
+            let marks: Vec<&str> = attachment.marks.iter().map(|(mark, _)| mark.as_str()).collect();
+            code.push_str(&format!("@MFS_{} = [{}];\n", code_name(anchor), marks.join(" ")));
+        }
+        code.push_str("\nfeature mkmk {\n");
+        for (anchor, attachment) in &stacking {
+            let name = code_name(anchor);
+            code.push_str(&format!("    lookup mkmk_{} {{\n", name));
+            // Only marks of this class count as the preceding mark
+            code.push_str(&format!("        lookupflag UseMarkFilteringSet @MFS_{};\n", name));
+            for (mark, position) in &attachment.mark_bases {
+                code.push_str(&format!("        pos mark {} {} mark @MC_{};\n", mark, anchor_code(*position), name));
+            }
+            code.push_str(&format!("    }} mkmk_{};\n", name));
+        }
+        code.push_str("} mkmk;\n");
+    }
+    Ok(code)
+}
@@ -0,0 +1,439 @@
+// Metrics keys module
+//
+// This module supports Glyphs-style metrics keys, which link a glyph's
+// left sidebearing, right sidebearing or advance width to another glyph
+// ("=n", "=H+20", "=o*1.1", "=|n" for the opposite side of "n", "=|" for
+// the glyph's own opposite side) or to a fixed value ("=600"). Keys are
+// kept in the format-specific data of glyphs or layers under the Glyphs 3
+// names (`metricLeft`, `metricRight`, `metricWidth`), with the Glyphs 2
+// names (`leftMetricsKey`, ...) read as well, so keys of imported sources
+// work. Resolving keys re-spaces the master layers of keyed glyphs from
+// their references, in dependency order.
+
+use babelfont::Layer;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::errors::ApiError;
+use crate::source_checks::selected_glyphs;
+use crate::spacing::{follow_base_metrics, layer_polylines, measure_zone, shift_layer_contents};
+
+/// A metric a key can be set for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MetricSide {
+    Left,
+    Right,
+    Width,
+}
+
+impl MetricSide {
+    pub const ALL: [MetricSide; 3] = [MetricSide::Left, MetricSide::Right, MetricSide::Width];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MetricSide::Left => "left",
+            MetricSide::Right => "right",
+            MetricSide::Width => "width",
+        }
+    }
+
+    /// Format-specific keys holding the metrics key (Glyphs 3, Glyphs 2)
+    fn storage_keys(self) -> [&'static str; 2] {
+        match self {
+            MetricSide::Left => ["metricLeft", "leftMetricsKey"],
+            MetricSide::Right => ["metricRight", "rightMetricsKey"],
+            MetricSide::Width => ["metricWidth", "widthMetricsKey"],
+        }
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            MetricSide::Left => MetricSide::Right,
+            MetricSide::Right => MetricSide::Left,
+            MetricSide::Width => MetricSide::Width,
+        }
+    }
+}
+
+/// A parsed metrics key: `=H`, `=H+20`, `=H-20`, `=H*1.5`, `=|H`, `=|` or `=600`
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum MetricsKey {
+    /// The metric of a glyph (the keyed glyph itself if `name` is empty), or its opposite side
+    Glyph { name: String, opposite: bool, op: char, operand: f64 },
+    Fixed(f64),
+}
+
+impl MetricsKey {
+    pub(crate) fn parse(key: &str) -> Option<Self> {
+        let key = key.trim().strip_prefix('=')?.trim();
+        if key.is_empty() {
+            return None;
+        }
+        if let Ok(value) = key.parse::<f64>() {
+            return Some(MetricsKey::Fixed(value));
+        }
+        let (key, opposite) = match key.strip_prefix('|') {
+            Some(rest) => (rest.trim(), true),
+            None => (key, false),
+        };
+        // Glyph names may contain '-', so only a numeric tail is an operand
+        let operation = key.rfind(['+', '-', '*']).filter(|&index| index > 0).and_then(|index| {
+            let operand = key[index + 1..].trim().parse::<f64>().ok()?;
+            Some((&key[..index], key.as_bytes()[index] as char, operand))
+        });
+        let (name, op, operand) = operation.unwrap_or((key, '+', 0.0));
+        if name.is_empty() && !opposite {
+            return None;
+        }
+        Some(MetricsKey::Glyph { name: name.trim().to_string(), opposite, op, operand })
+    }
+
+    /// Value the key gives, from the referenced metric
+    pub(crate) fn expected(&self, reference: Option<f64>) -> Option<f64> {
+        match self {
+            MetricsKey::Fixed(value) => Some(*value),
+            MetricsKey::Glyph { op, operand, .. } => {
+                let value = reference?;
+                Some(match op {
+                    '-' => value - operand,
+                    '*' => value * operand,
+                    _ => value + operand,
+                })
+            }
+        }
+    }
+
+    /// Referenced glyph, with the keyed glyph standing in for an empty name
+    fn reference<'a>(&'a self, keyed_glyph: &'a str) -> Option<&'a str> {
+        match self {
+            MetricsKey::Glyph { name, .. } if name.is_empty() => Some(keyed_glyph),
+            MetricsKey::Glyph { name, .. } => Some(name),
+            MetricsKey::Fixed(_) => None,
+        }
+    }
+}
+
+/// Metrics key of a layer for a side, falling back to the glyph's
+pub(crate) fn metrics_key(glyph: &babelfont::Glyph, layer: &Layer, side: MetricSide) -> Option<String> {
+    let keys = side.storage_keys();
+    keys.iter()
+        .find_map(|key| layer.format_specific.get(*key))
+        .or_else(|| keys.iter().find_map(|key| glyph.format_specific.get(*key)))
+        .and_then(|value| value.as_str())
+        .filter(|value| !value.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Metrics keys of a glyph itself, by side
+fn glyph_keys(glyph: &babelfont::Glyph) -> Vec<(MetricSide, String)> {
+    MetricSide::ALL
+        .into_iter()
+        .filter_map(|side| {
+            let key = side.storage_keys().iter().find_map(|key| glyph.format_specific.get(*key))?;
+            Some((side, key.as_str()?.to_string()))
+        })
+        .collect()
+}
+
+/// Set or remove the metrics keys of a glyph
+///
+/// Keys are stored under the Glyphs 3 names; Glyphs 2 entries for the same
+/// side are removed, as are layer-level keys, so the glyph's key applies
+/// to all its layers.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Glyph to set keys for
+/// * `keys_json` - JSON object with "left", "right" and/or "width" keys, e.g., '{"left": "=n", "right": "=|", "width": null}'; null or "" removes a key, omitted sides are unchanged
+///
+/// # Returns
+/// * `JsonValue` - The glyph's keys: '{"left": "=n", "right": "=|"}'
+pub fn set_metrics_keys(font: &mut babelfont::Font, glyph_name: &str, keys_json: &str) -> Result<serde_json::Value, ApiError> {
+    let keys: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(keys_json).map_err(|e| ApiError::invalid_json("metrics keys", e))?;
+    let mut updates = Vec::new();
+    for (name, value) in &keys {
+        let side = MetricSide::ALL
+            .into_iter()
+            .find(|side| side.name() == name)
+            .ok_or_else(|| ApiError::invalid_argument("metric", name))?;
+        let key = match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(key) if key.trim().is_empty() => None,
+            serde_json::Value::String(key) => {
+                let parsed = MetricsKey::parse(key).ok_or_else(|| ApiError::invalid_argument("metrics key", key))?;
+                // A width key cannot refer to an opposite side
+                if side == MetricSide::Width && matches!(parsed, MetricsKey::Glyph { opposite: true, .. }) {
+                    return Err(ApiError::invalid_argument("width key", key));
+                }
+                Some(key.trim().to_string())
+            }
+            other => return Err(ApiError::invalid_argument("metrics key", other)),
+        };
+        updates.push((side, key));
+    }
+
+    let glyph = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    for (side, key) in updates {
+        for storage_key in side.storage_keys() {
+            glyph.format_specific.remove(storage_key);
+            for layer in glyph.layers.iter_mut() {
+                layer.format_specific.remove(storage_key);
+            }
+        }
+        if let Some(key) = key {
+            glyph.format_specific.insert(side.storage_keys()[0].to_string(), serde_json::Value::String(key));
+        }
+    }
+
+    let keys: serde_json::Map<String, serde_json::Value> = glyph_keys(glyph)
+        .into_iter()
+        .map(|(side, key)| (side.name().to_string(), serde_json::Value::String(key)))
+        .collect();
+    Ok(serde_json::Value::Object(keys))
+}
+
+/// Ink-based metrics of a layer: left sidebearing, right sidebearing and width
+fn layer_metrics(font: &babelfont::Font, layer: &Layer) -> Result<Option<(f64, f64, f64)>, String> {
+    let width = layer.width as f64;
+    let polylines = layer_polylines(font, layer)?;
+    Ok(measure_zone(&polylines, f64::NEG_INFINITY, f64::INFINITY)
+        .map(|profile| (profile.left, width - profile.right, width)))
+}
+
+/// Master layer of a glyph
+fn master_layer_index(glyph: &babelfont::Glyph, master_id: &str) -> Option<usize> {
+    glyph
+        .layers
+        .iter()
+        .position(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id))
+}
+
+/// Glyphs referenced by the keys of a glyph, in any master
+fn key_references(glyph: &babelfont::Glyph) -> BTreeSet<String> {
+    let name = glyph.name.to_string();
+    glyph
+        .layers
+        .iter()
+        .filter(|layer| !layer.is_background)
+        .flat_map(|layer| MetricSide::ALL.into_iter().filter_map(move |side| metrics_key(glyph, layer, side)))
+        .filter_map(|key| MetricsKey::parse(&key))
+        .filter_map(|key| key.reference(&name).map(str::to_string))
+        .filter(|reference| *reference != name)
+        .collect()
+}
+
+/// Recompute sidebearings and widths from metrics keys
+///
+/// Keyed glyphs are resolved after the glyphs they refer to, so chains like
+/// "ntilde" → "n" → "h" settle in one call. In each master, a left key moves
+/// the outlines, a width key sets the advance width and otherwise a right
+/// key does; composites using a changed glyph as their base follow it.
+/// Values are rounded to whole units. Keys that differ from the current
+/// metrics by more than half a unit are reported as stale (and fixed when
+/// `apply` is set); keys that cannot be resolved are reported as problems:
+/// a missing or empty reference, a reference cycle, an invalid key, or both
+/// a width and a right key (the width key wins).
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of changed glyph names: the keyed glyphs among them and all glyphs whose keys depend on them are resolved; all keyed glyphs if None
+/// * `apply` - Change the layers; otherwise only report
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 2, "glyphs": ["m"], "stale": [{"glyph": "m", "master": "m01", "side": "left", "key": "=n", "value": 42, "expected": 40}], "problems": [{"glyph": "x", "key": "=foo", "reason": "..."}]}' and the changed glyph names
+pub fn resolve_metrics_keys(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    apply: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let references: HashMap<String, BTreeSet<String>> = font
+        .glyphs
+        .iter()
+        .map(|glyph| (glyph.name.to_string(), key_references(glyph)))
+        .filter(|(name, references)| {
+            !references.is_empty() || font.glyphs.get(name).is_some_and(|glyph| {
+                glyph.layers.iter().any(|layer| MetricSide::ALL.iter().any(|side| metrics_key(glyph, layer, *side).is_some()))
+            })
+        })
+        .collect();
+
+    // Keyed glyphs to resolve: all, or those depending on the given glyphs
+    let mut pending: HashSet<String> = match glyph_names_json {
+        None => references.keys().cloned().collect(),
+        Some(_) => {
+            let changed: Vec<String> = selected_glyphs(font, glyph_names_json)?
+                .iter()
+                .map(|glyph| glyph.name.to_string())
+                .collect();
+            let mut pending: HashSet<String> =
+                changed.iter().filter(|name| references.contains_key(*name)).cloned().collect();
+            let mut frontier = changed;
+            while let Some(name) = frontier.pop() {
+                for (dependent, uses) in &references {
+                    if uses.contains(&name) && pending.insert(dependent.clone()) {
+                        frontier.push(dependent.clone());
+                    }
+                }
+            }
+            pending
+        }
+    };
+
+    let masters: Vec<String> = font.masters.iter().map(|master| master.id.clone()).collect();
+    let mut stale = Vec::new();
+    let mut problems = Vec::new();
+    let mut moved = HashMap::new();
+    let mut changed = HashSet::new();
+    let mut layer_count = 0;
+
+    while !pending.is_empty() {
+        // Resolve glyphs whose references are settled; break cycles by reporting them
+        let mut ready: Vec<String> = pending
+            .iter()
+            .filter(|name| references[*name].iter().all(|reference| !pending.contains(reference)))
+            .cloned()
+            .collect();
+        if ready.is_empty() {
+            let mut cyclic: Vec<String> = pending.drain().collect();
+            cyclic.sort();
+            for name in cyclic {
+                problems.push(serde_json::json!({
+                    "glyph": name,
+                    "reason": "Metrics keys refer to each other in a cycle",
+                }));
+            }
+            break;
+        }
+        ready.sort();
+
+        for name in ready {
+            pending.remove(&name);
+            let Some(glyph) = font.glyphs.get(name.as_str()) else { continue };
+            let mut updates: Vec<(usize, f64, f32)> = Vec::new();
+            for master_id in &masters {
+                let Some(index) = master_layer_index(glyph, master_id) else { continue };
+                let layer = &glyph.layers[index];
+                let keys: Vec<(MetricSide, String)> = MetricSide::ALL
+                    .into_iter()
+                    .filter_map(|side| metrics_key(glyph, layer, side).map(|key| (side, key)))
+                    .collect();
+                if keys.is_empty() {
+                    continue;
+                }
+                let Some((lsb, rsb, width)) = layer_metrics(font, layer)? else {
+                    problems.push(serde_json::json!({
+                        "glyph": name,
+                        "master": master_id,
+                        "reason": "Glyph has no outlines to space",
+                    }));
+                    continue;
+                };
+
+                let mut targets: HashMap<MetricSide, f64> = HashMap::new();
+                for (side, key) in &keys {
+                    let problem = |reason: String| serde_json::json!({ "glyph": name, "master": master_id, "side": side.name(), "key": key, "reason": reason });
+                    let Some(parsed) = MetricsKey::parse(key) else {
+                        problems.push(problem("Invalid metrics key".to_string()));
+                        continue;
+                    };
+                    let reference = match &parsed {
+                        MetricsKey::Fixed(_) => None,
+                        MetricsKey::Glyph { opposite, .. } => {
+                            let reference_name = parsed.reference(&name).unwrap_or_default();
+                            let measured_side = if *opposite { side.opposite() } else { *side };
+                            let metrics = match font.glyphs.get(reference_name) {
+                                None => {
+                                    problems.push(problem(format!("References missing glyph '{}'", reference_name)));
+                                    continue;
+                                }
+                                Some(reference) => match master_layer_index(reference, master_id) {
+                                    Some(reference_index) => layer_metrics(font, &reference.layers[reference_index])?,
+                                    None => None,
+                                },
+                            };
+                            let Some((reference_left, reference_right, reference_width)) = metrics else {
+                                problems.push(problem(format!("'{}' has no outlines in this master", reference_name)));
+                                continue;
+                            };
+                            Some(match measured_side {
+                                MetricSide::Left => reference_left,
+                                MetricSide::Right => reference_right,
+                                MetricSide::Width => reference_width,
+                            })
+                        }
+                    };
+                    if let Some(expected) = parsed.expected(reference) {
+                        targets.insert(*side, expected.round());
+                    }
+                }
+                if targets.contains_key(&MetricSide::Width) && targets.contains_key(&MetricSide::Right) {
+                    problems.push(serde_json::json!({
+                        "glyph": name,
+                        "master": master_id,
+                        "reason": "Both a width and a right key are set; the width key is used",
+                    }));
+                    targets.remove(&MetricSide::Right);
+                }
+
+                for (side, key) in &keys {
+                    let Some(&expected) = targets.get(side) else { continue };
+                    let value = match side {
+                        MetricSide::Left => lsb,
+                        MetricSide::Right => rsb,
+                        MetricSide::Width => width,
+                    };
+                    if (value - expected).abs() > 0.5 {
+                        stale.push(serde_json::json!({
+                            "glyph": name,
+                            "master": master_id,
+                            "side": side.name(),
+                            "key": key,
+                            "value": value,
+                            "expected": expected,
+                        }));
+                    }
+                }
+
+                let dx = targets.get(&MetricSide::Left).map_or(0.0, |left| left - lsb.round());
+                let new_width = match (targets.get(&MetricSide::Width), targets.get(&MetricSide::Right)) {
+                    (Some(width), _) => *width,
+                    (None, Some(right)) => (width - rsb).round() + dx + right,
+                    (None, None) => width + dx,
+                };
+                if dx != 0.0 || (new_width - width).abs() > f64::EPSILON {
+                    updates.push((index, dx, new_width.max(0.0) as f32));
+                }
+            }
+
+            if !apply || updates.is_empty() {
+                continue;
+            }
+            let Some(glyph) = font.glyphs.get_mut(name.as_str()) else { continue };
+            for (index, dx, width) in updates {
+                let layer = &mut glyph.layers[index];
+                let dw = width - layer.width;
+                shift_layer_contents(layer, dx, 0);
+                layer.width = width;
+                moved.insert((name.clone(), layer.id.clone()), (dx, dw));
+                layer_count += 1;
+            }
+            changed.insert(name.clone());
+        }
+    }
+    if apply {
+        layer_count += follow_base_metrics(font, &mut moved, &mut changed);
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "stale": stale,
+        "problems": problems,
+    });
+    Ok((result, changed))
+}
@@ -0,0 +1,1402 @@
+// Outline filters module
+//
+// This module holds filters that clean up source outlines in place across
+// all layers of the selected glyphs: rounding coordinates to a grid,
+// simplifying paths, harmonizing curves, slanting, rounding corners,
+// converting between cubic and quadratic curves and scaling to a new UPM.
+// Rounding, harmonizing, slanting and scaling keep the node structure
+// intact; filters that add or remove nodes keep compatible masters
+// compatible or report glyphs whose masters stop matching.
+
+use babelfont::{Layer, NodeType, Path, Shape};
+use kurbo::simplify::{simplify_bezpath, SimplifyOptions};
+use kurbo::{Affine, BezPath, CubicBez, Line, ParamCurve, ParamCurveArclen, PathSeg, Point, Shape as _, Vec2};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::errors::ApiError;
+use crate::glyph_outlines::node_type_code;
+use crate::source_checks::{default_master_id, layer_label, selected_glyphs};
+
+/// Round a value to a grid, halves upwards as in OpenType compilers
+fn round_to_grid(value: f64, grid: f64) -> f64 {
+    (value / grid + 0.5).floor() * grid
+}
+
+/// Shape of a layer's coordinates: node counts of paths (None for components) and anchor names
+fn layer_structure(layer: &Layer) -> (Vec<Option<usize>>, Vec<&str>) {
+    let shapes = layer
+        .shapes
+        .iter()
+        .map(|shape| match shape {
+            Shape::Path(path) => Some(path.nodes.len()),
+            Shape::Component(_) => None,
+        })
+        .collect();
+    let anchors = layer.anchors.iter().map(|anchor| anchor.name.as_str()).collect();
+    (shapes, anchors)
+}
+
+/// Coordinates of a layer in a fixed order: nodes, component offsets, then anchors
+fn layer_coordinates(layer: &Layer) -> Vec<f64> {
+    let mut values = Vec::new();
+    for shape in &layer.shapes {
+        match shape {
+            Shape::Path(path) => values.extend(path.nodes.iter().flat_map(|node| [node.x, node.y])),
+            Shape::Component(component) => {
+                let [_, _, _, _, dx, dy] = component.transform.as_coeffs();
+                values.extend([dx, dy]);
+            }
+        }
+    }
+    values.extend(layer.anchors.iter().flat_map(|anchor| [anchor.x, anchor.y]));
+    values
+}
+
+/// Write coordinates in the order of layer_coordinates() back to a layer
+fn set_layer_coordinates(layer: &mut Layer, values: &[f64]) {
+    let mut values = values.iter().copied();
+    let mut next = move || values.next().unwrap_or_default();
+    for shape in &mut layer.shapes {
+        match shape {
+            Shape::Path(path) => {
+                for node in &mut path.nodes {
+                    node.x = next();
+                    node.y = next();
+                }
+            }
+            Shape::Component(component) => {
+                let [a, b, c, d, _, _] = component.transform.as_coeffs();
+                component.transform = Affine::new([a, b, c, d, next(), next()]);
+            }
+        }
+    }
+    for anchor in &mut layer.anchors {
+        anchor.x = next();
+        anchor.y = next();
+    }
+}
+
+/// Round node, component offset and anchor coordinates to a grid
+///
+/// All non-background layers are rounded. With `compatible`, layers with
+/// the same structure as the glyph's default master layer are rounded as
+/// offsets from the rounded default master instead of on their own: points
+/// that move by the same amount between masters keep moving by the same
+/// rounded amount, so parts that only shift between masters (dots, accents,
+/// parallel stem edges) keep their exact shape in every master. Layers of
+/// a different structure are rounded on their own.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `grid` - Grid size in font units (1 rounds to integers)
+/// * `compatible` - Round other layers relative to the default master
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 12, "glyphs": ["a", "b"]}' and the changed glyph names
+pub fn round_coordinates(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    grid: f64,
+    compatible: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    if !(grid.is_finite() && grid > 0.0) {
+        return Err(ApiError::invalid_argument("grid", grid));
+    }
+    let default_master = default_master_id(font).map(str::to_string);
+
+    // Compute the new layers while the font is borrowed immutably
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        let reference = default_master.as_deref().and_then(|master_id| {
+            glyph
+                .layers
+                .iter()
+                .find(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id))
+        });
+        let reference_values = reference.map(layer_coordinates);
+        let rounded_reference: Option<Vec<f64>> = reference_values
+            .as_ref()
+            .map(|values| values.iter().map(|&value| round_to_grid(value, grid)).collect());
+
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            let values = layer_coordinates(layer);
+            let relative = match (reference, &reference_values, &rounded_reference) {
+                (Some(reference), Some(original), Some(rounded))
+                    if compatible && layer_structure(layer) == layer_structure(reference) =>
+                {
+                    Some((original, rounded))
+                }
+                _ => None,
+            };
+            let rounded: Vec<f64> = match relative {
+                Some((original, rounded)) => values
+                    .iter()
+                    .zip(original.iter().zip(rounded))
+                    .map(|(&value, (&base, &rounded_base))| rounded_base + round_to_grid(value - base, grid))
+                    .collect(),
+                None => values.iter().map(|&value| round_to_grid(value, grid)).collect(),
+            };
+            if rounded != values {
+                let mut updated = layer.clone();
+                set_layer_coordinates(&mut updated, &rounded);
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+    });
+    Ok((result, changed))
+}
+
+/// Largest angle between tangents, in radians, at which a join counts as smooth
+const SMOOTH_ANGLE: f64 = 0.035;
+
+/// Warnings for glyphs whose master layers no longer have matching node structures
+fn structure_warnings(font: &babelfont::Font, glyphs: &[&String], action: &str) -> Vec<serde_json::Value> {
+    let mut warnings = Vec::new();
+    for name in glyphs {
+        let Some(glyph) = font.glyphs.get(name.as_str()) else { continue };
+        let nodes: BTreeMap<String, Vec<usize>> = glyph
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| {
+                !layer.is_background && font.masters.iter().any(|master| Some(&master.id) == layer.id.as_ref())
+            })
+            .map(|(index, layer)| {
+                let counts = layer
+                    .shapes
+                    .iter()
+                    .filter_map(|shape| match shape {
+                        Shape::Path(path) => Some(path.nodes.len()),
+                        Shape::Component(_) => None,
+                    })
+                    .collect();
+                (layer_label(layer, index), counts)
+            })
+            .collect();
+        let mut distinct: Vec<&Vec<usize>> = nodes.values().collect();
+        distinct.sort();
+        distinct.dedup();
+        if distinct.len() > 1 {
+            warnings.push(serde_json::json!({
+                "glyph": name,
+                "message": format!("Masters of '{}' have different node counts after {} and are no longer compatible", name, action),
+                "nodes": nodes,
+            }));
+        }
+    }
+    warnings
+}
+
+/// Distance of a point from the infinite line through two points
+fn line_distance(point: Point, start: Point, end: Point) -> f64 {
+    let chord = end - start;
+    let length = chord.hypot();
+    if length < 1e-9 {
+        return (point - start).hypot();
+    }
+    chord.cross(point - start).abs() / length
+}
+
+/// Control and end points of a segment after its start point
+fn segment_points(segment: &PathSeg) -> Vec<Point> {
+    match *segment {
+        PathSeg::Line(line) => vec![line.p1],
+        PathSeg::Quad(quad) => vec![quad.p1, quad.p2],
+        PathSeg::Cubic(cubic) => vec![cubic.p1, cubic.p2, cubic.p3],
+    }
+}
+
+/// Direction in which a segment leaves its start point
+fn start_tangent(segment: &PathSeg) -> Vec2 {
+    let start = segment.start();
+    segment_points(segment)
+        .into_iter()
+        .map(|point| point - start)
+        .find(|tangent| tangent.hypot() > 1e-9)
+        .unwrap_or(Vec2::ZERO)
+}
+
+/// Direction in which a segment arrives at its end point
+fn end_tangent(segment: &PathSeg) -> Vec2 {
+    let end = segment.end();
+    let mut points = vec![segment.start()];
+    points.extend(segment_points(segment));
+    points
+        .into_iter()
+        .rev()
+        .map(|point| end - point)
+        .find(|tangent| tangent.hypot() > 1e-9)
+        .unwrap_or(Vec2::ZERO)
+}
+
+/// Whether two segments meet without a corner
+fn is_smooth_join(incoming: &PathSeg, outgoing: &PathSeg) -> bool {
+    let (a, b) = (end_tangent(incoming), start_tangent(outgoing));
+    let (length_a, length_b) = (a.hypot(), b.hypot());
+    if length_a < 1e-9 || length_b < 1e-9 {
+        return false;
+    }
+    a.dot(b) > 0.0 && (a.cross(b) / (length_a * length_b)).abs() < SMOOTH_ANGLE.sin()
+}
+
+/// Remove degenerate segments, flatten straight curves and merge collinear lines
+fn simplify_lines(segments: Vec<PathSeg>, tolerance: f64) -> Vec<PathSeg> {
+    let mut result: Vec<PathSeg> = Vec::new();
+    // Joints merged into the last line, which must stay within tolerance of it
+    let mut merged: Vec<Point> = Vec::new();
+    for segment in segments {
+        let (start, end) = (segment.start(), segment.end());
+        let points = segment_points(&segment);
+        if points.iter().all(|point| (*point - start).hypot() < 1e-9) {
+            continue;
+        }
+        let straight = !matches!(segment, PathSeg::Line(_))
+            && (end - start).hypot() > tolerance
+            && points.iter().all(|point| line_distance(*point, start, end) <= tolerance)
+            && {
+                // Handles must not reach beyond the ends of the chord
+                let chord = end - start;
+                let length = chord.hypot2();
+                points.iter().all(|point| {
+                    let t = (*point - start).dot(chord) / length;
+                    (-1e-9..=1.0 + 1e-9).contains(&t)
+                })
+            };
+        let segment = if straight { PathSeg::Line(Line::new(start, end)) } else { segment };
+
+        // Extend the previous line if all joints lie on the combined line
+        if let (PathSeg::Line(line), Some(PathSeg::Line(previous))) = (segment, result.last().copied()) {
+            let direction = (line.p1 - line.p0).dot(previous.p1 - previous.p0);
+            let on_line = merged
+                .iter()
+                .chain(std::iter::once(&line.p0))
+                .all(|joint| line_distance(*joint, previous.p0, line.p1) <= tolerance);
+            if direction > 0.0 && on_line {
+                *result.last_mut().unwrap() = PathSeg::Line(Line::new(previous.p0, line.p1));
+                merged.push(line.p0);
+                continue;
+            }
+        }
+        merged.clear();
+        result.push(segment);
+    }
+    result
+}
+
+/// Refit runs of smoothly joined curves with as few curves as the tolerance allows
+fn simplify_curves(segments: Vec<PathSeg>, tolerance: f64) -> Vec<PathSeg> {
+    let mut result = Vec::new();
+    let mut run: Vec<PathSeg> = Vec::new();
+    let flush = |run: &mut Vec<PathSeg>, result: &mut Vec<PathSeg>| {
+        if run.len() > 1 {
+            let fitted = simplify_bezpath(
+                BezPath::from_path_segments(run.iter().copied()),
+                tolerance,
+                &SimplifyOptions::default(),
+            );
+            let fitted: Vec<PathSeg> = fitted.segments().collect();
+            if !fitted.is_empty() && fitted.len() < run.len() {
+                result.extend(fitted);
+                run.clear();
+            }
+        }
+        result.append(run);
+    };
+    for segment in segments {
+        let continues = matches!(segment, PathSeg::Quad(_) | PathSeg::Cubic(_))
+            && run.last().is_some_and(|previous| is_smooth_join(previous, &segment));
+        if !continues {
+            flush(&mut run, &mut result);
+        }
+        if matches!(segment, PathSeg::Line(_)) {
+            result.push(segment);
+        } else {
+            run.push(segment);
+        }
+    }
+    flush(&mut run, &mut result);
+    result
+}
+
+/// Simplify a path, returning the new path if it has fewer nodes
+///
+/// Closed contours are rotated to start at a corner first, so runs of
+/// curves are not split at the arbitrary start node.
+fn simplify_path(path: &Path, tolerance: f64) -> Option<Path> {
+    let quadratic = path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve));
+    let mut segments: Vec<PathSeg> = crate::glyph_outlines::path_to_bezpath(path).segments().collect();
+    if segments.len() < 2 {
+        return None;
+    }
+    if path.closed {
+        let count = segments.len();
+        if let Some(corner) =
+            (0..count).find(|&index| !is_smooth_join(&segments[(index + count - 1) % count], &segments[index]))
+        {
+            segments.rotate_left(corner);
+        }
+    }
+
+    let segments = simplify_curves(simplify_lines(segments, tolerance), tolerance);
+    let mut bez = BezPath::from_path_segments(segments.into_iter());
+    if path.closed {
+        bez.close_path();
+    }
+    let mut simplified = crate::path_ops::bezpath_to_paths(&bez, quadratic);
+    if simplified.len() != 1 {
+        return None;
+    }
+    let mut simplified = simplified.remove(0);
+    if simplified.nodes.len() >= path.nodes.len() {
+        return None;
+    }
+    simplified.format_specific = path.format_specific.clone();
+    Some(simplified)
+}
+
+/// Remove redundant nodes from source outlines
+///
+/// Zero-length segments are dropped, curves whose handles lie on their
+/// chord become lines, collinear lines are merged and runs of smoothly
+/// joined curves are refitted with fewer curves, all within `tolerance`
+/// font units of the original outline. Corners and the start of open paths
+/// stay in place. A path is only replaced if it loses nodes; masters
+/// simplified differently are reported as warnings.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `tolerance` - Largest allowed deviation from the original outline, in font units
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 3, "glyphs": ["a"], "removedNodes": 42, "warnings": [{"glyph": "a", "message": "...", "nodes": {"Regular": [24], "Bold": [26]}}]}' and the changed glyph names
+pub fn simplify_paths(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    tolerance: f64,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(ApiError::invalid_argument("tolerance", tolerance));
+    }
+
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    let mut removed_nodes = 0;
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            let mut updated = layer.clone();
+            let mut simplified_any = false;
+            for shape in &mut updated.shapes {
+                if let Shape::Path(path) = shape {
+                    if let Some(simplified) = simplify_path(path, tolerance) {
+                        removed_nodes += path.nodes.len() - simplified.nodes.len();
+                        *path = simplified;
+                        simplified_any = true;
+                    }
+                }
+            }
+            if simplified_any {
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let warnings = structure_warnings(font, &glyphs, "simplifying");
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "removedNodes": removed_nodes,
+        "warnings": warnings,
+    });
+    Ok((result, changed))
+}
+
+/// Passes over a contour when harmonizing, as adjusting one node changes its neighbours
+const HARMONIZE_PASSES: usize = 16;
+
+/// Intersection of the lines through `a` in direction `u` and through `b` in direction `v`
+fn intersect_lines(a: Point, u: Vec2, b: Point, v: Vec2) -> Option<Point> {
+    let denominator = u.cross(v);
+    if denominator.abs() < 1e-9 {
+        return None;
+    }
+    Some(a + u * ((b - a).cross(v) / denominator))
+}
+
+/// Index of a node `offset` places away in a path, wrapping around closed paths
+fn node_index(path: &Path, index: usize, offset: isize) -> Option<usize> {
+    let count = path.nodes.len() as isize;
+    let target = index as isize + offset;
+    if path.closed {
+        Some(target.rem_euclid(count) as usize)
+    } else {
+        (0..count).contains(&target).then_some(target as usize)
+    }
+}
+
+/// Indices of the four points of the cubic segment that ends at an on-curve node
+fn cubic_ending_at(path: &Path, index: usize) -> Option<[usize; 4]> {
+    if !matches!(path.nodes[index].nodetype, NodeType::Curve) {
+        return None;
+    }
+    let [start, first, second] = [-3, -2, -1].map(|offset| node_index(path, index, offset));
+    let (start, first, second) = (start?, first?, second?);
+    let off_curve = |i: usize| matches!(path.nodes[i].nodetype, NodeType::OffCurve);
+    (off_curve(first) && off_curve(second) && !off_curve(start)).then_some([start, first, second, index])
+}
+
+/// Point of a node
+fn node_point(path: &Path, index: usize) -> Point {
+    Point::new(path.nodes[index].x, path.nodes[index].y)
+}
+
+/// Move a node to a point
+fn set_node_point(path: &mut Path, index: usize, point: Point) {
+    path.nodes[index].x = point.x;
+    path.nodes[index].y = point.y;
+}
+
+/// Give both handles of a cubic segment the same length relative to their intersection
+///
+/// Returns whether the handles moved.
+fn balance_segment(path: &mut Path, [p0, p1, p2, p3]: [usize; 4]) -> bool {
+    let (start, first, second, end) = (
+        node_point(path, p0),
+        node_point(path, p1),
+        node_point(path, p2),
+        node_point(path, p3),
+    );
+    let (out_handle, in_handle) = (first - start, second - end);
+    if out_handle.hypot() < 1e-9 || in_handle.hypot() < 1e-9 {
+        return false;
+    }
+    let Some(corner) = intersect_lines(start, out_handle, end, in_handle) else { return false };
+    let (out_reach, in_reach) = (corner - start, corner - end);
+    // The handles must point towards their intersection
+    if out_reach.dot(out_handle) <= 0.0 || in_reach.dot(in_handle) <= 0.0 {
+        return false;
+    }
+    let ratio = (out_handle.hypot() / out_reach.hypot() + in_handle.hypot() / in_reach.hypot()) / 2.0;
+    let (balanced_first, balanced_second) = (start + out_reach * ratio, end + in_reach * ratio);
+    let moved = (balanced_first - first).hypot() > 1e-6 || (balanced_second - second).hypot() > 1e-6;
+    set_node_point(path, p1, balanced_first);
+    set_node_point(path, p2, balanced_second);
+    moved
+}
+
+/// Adjust the handles around a smooth node for equal curvature on both sides
+///
+/// The node and its tangent stay fixed. The curvature at the end of a cubic
+/// is 2/3 * d / h^2, with h the handle length at the node and d the
+/// distance of the far handle from the tangent, so matching curvatures
+/// needs h_in / h_out = sqrt(d_in / d_out). The sum of both handle lengths
+/// is kept. Inflections and flat sides are left alone. Returns how far a
+/// handle moved.
+fn harmonize_node(path: &mut Path, incoming: [usize; 4], outgoing: [usize; 4]) -> f64 {
+    let node = node_point(path, incoming[3]);
+    let (in_far, in_near) = (node_point(path, incoming[1]), node_point(path, incoming[2]));
+    let (out_near, out_far) = (node_point(path, outgoing[1]), node_point(path, outgoing[2]));
+    let (in_length, out_length) = ((node - in_near).hypot(), (out_near - node).hypot());
+    if in_length < 1e-9 || out_length < 1e-9 {
+        return 0.0;
+    }
+    let tangent = (out_near - in_near).normalize();
+    // Both handles must lie on one line through the node
+    if ((node - in_near).normalize().cross(tangent)).abs() > SMOOTH_ANGLE.sin()
+        || ((out_near - node).normalize().cross(tangent)).abs() > SMOOTH_ANGLE.sin()
+    {
+        return 0.0;
+    }
+
+    let in_distance = tangent.cross(in_far - node);
+    let out_distance = tangent.cross(out_far - node);
+    if in_distance * out_distance <= 1e-9 {
+        return 0.0;
+    }
+    let ratio = (in_distance / out_distance).sqrt();
+    let total = in_length + out_length;
+    let harmonized_in = node - tangent * (total * ratio / (1.0 + ratio));
+    let harmonized_out = node + tangent * (total / (1.0 + ratio));
+    let moved = (harmonized_in - in_near).hypot().max((harmonized_out - out_near).hypot());
+    set_node_point(path, incoming[2], harmonized_in);
+    set_node_point(path, outgoing[1], harmonized_out);
+    moved
+}
+
+/// Harmonize the smooth nodes of a cubic path, balancing its segments first if asked
+///
+/// Returns the number of nodes whose handles changed.
+fn harmonize_path(path: &mut Path, balance: bool) -> usize {
+    if path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve)) {
+        return 0;
+    }
+    let original = path.nodes.clone();
+    let segments: Vec<[usize; 4]> = (0..path.nodes.len()).filter_map(|index| cubic_ending_at(path, index)).collect();
+    if balance {
+        for &segment in &segments {
+            balance_segment(path, segment);
+        }
+    }
+
+    // Smooth nodes with a cubic segment on both sides
+    let joins: Vec<([usize; 4], [usize; 4])> = segments
+        .iter()
+        .filter(|incoming| path.nodes[incoming[3]].smooth)
+        .filter_map(|&incoming| {
+            let outgoing = segments.iter().find(|outgoing| outgoing[0] == incoming[3])?;
+            Some((incoming, *outgoing))
+        })
+        .collect();
+    for _ in 0..HARMONIZE_PASSES {
+        let mut largest_move: f64 = 0.0;
+        for &(incoming, outgoing) in &joins {
+            largest_move = largest_move.max(harmonize_node(path, incoming, outgoing));
+        }
+        if largest_move < 0.01 {
+            break;
+        }
+    }
+
+    // Count on-curve nodes next to a moved handle
+    let moved: HashSet<usize> = (0..path.nodes.len())
+        .filter(|&index| {
+            let (before, after) = (&original[index], &path.nodes[index]);
+            (before.x - after.x).abs() > 1e-6 || (before.y - after.y).abs() > 1e-6
+        })
+        .collect();
+    segments
+        .iter()
+        .flat_map(|segment| [segment[0], segment[3]])
+        .collect::<HashSet<usize>>()
+        .into_iter()
+        .filter(|&node| {
+            [-1, 1]
+                .iter()
+                .filter_map(|&offset| node_index(path, node, offset))
+                .any(|handle| moved.contains(&handle))
+        })
+        .count()
+}
+
+/// Harmonize curves at smooth nodes of source outlines
+///
+/// At every smooth node between two cubic segments, the handle lengths
+/// are adjusted along the tangent so the curvature is continuous (G2)
+/// across the node; nodes and handle directions do not move. With
+/// `balance`, the two handles of each segment are first given the same
+/// length relative to their intersection. Quadratic paths are skipped.
+/// Node structures stay unchanged, so masters remain compatible.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `balance` - Balance segment handles before harmonizing
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 3, "glyphs": ["o"], "nodes": 24}' and the changed glyph names
+pub fn harmonize(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    balance: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    let mut node_count = 0;
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            let mut updated = layer.clone();
+            let mut adjusted = 0;
+            for shape in &mut updated.shapes {
+                if let Shape::Path(path) = shape {
+                    adjusted += harmonize_path(path, balance);
+                }
+            }
+            if adjusted > 0 {
+                node_count += adjusted;
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "nodes": node_count,
+    });
+    Ok((result, changed))
+}
+
+/// Slant transform of a layer: shear (and partial rotation) about a pivot
+///
+/// `rotation` of the angle is applied by rotating clockwise about the pivot,
+/// with heights restored by a vertical scale, and the rest by shearing, so
+/// vertical stems end at `angle` either way.
+fn slant_transform(angle: f64, rotation: f64, pivot: Point) -> Affine {
+    let rotated = angle * rotation;
+    let shear = angle.tan() - rotated.sin();
+    Affine::translate(pivot.to_vec2())
+        * Affine::skew(shear, 0.0)
+        * Affine::scale_non_uniform(1.0, 1.0 / rotated.cos())
+        * Affine::rotate(-rotated)
+        * Affine::translate(-pivot.to_vec2())
+}
+
+/// Slant all glyphs of a font for deriving an italic
+///
+/// Every non-background layer is sheared by `angle_degrees` (positive leans
+/// right) about a pivot at half the x-height of its master (or `origin`)
+/// and half its advance width, so glyphs stay centred in their advance.
+/// Nodes, anchors and components are transformed; component transforms are
+/// adjusted so composites match their slanted bases. `rotation` (0 to 1) is
+/// the share of the angle applied by rotating instead of shearing, which
+/// keeps round strokes from thinning at the cost of tilting horizontals.
+/// Masters get the italic angle metric (negative for right leaning, as in
+/// post.italicAngle), from which the compiler derives the caret slope.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `angle_degrees` - Slant angle in degrees, positive leans right
+/// * `options_json` - Optional JSON options (see `slant_font` in lib.rs)
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 1200, "glyphs": 600, "italicAngle": -12}' and the changed glyph names
+pub fn slant_font(
+    font: &mut babelfont::Font,
+    angle_degrees: f64,
+    options_json: Option<&str>,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("slant options", e))?
+    };
+    let origin = options.get("origin").and_then(|v| v.as_f64());
+    let rotation = options.get("rotation").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let update_italic_angle = options.get("updateItalicAngle").and_then(|v| v.as_bool()).unwrap_or(true);
+    // Slant angles must be between -45 and 45 degrees, rotation between 0 and 1
+    if !(angle_degrees.is_finite() && angle_degrees.abs() < 45.0) {
+        return Err(ApiError::invalid_argument("slant angle", angle_degrees));
+    }
+    if !(0.0..=1.0).contains(&rotation) {
+        return Err(ApiError::invalid_argument("rotation", rotation));
+    }
+    let angle = angle_degrees.to_radians();
+
+    // Pivot height per master: half the x-height
+    let x_height = |master: &babelfont::Master| {
+        master.metrics.get(&babelfont::MetricType::XHeight).map(|&value| value as f64)
+    };
+    let fallback_height = font.masters.iter().find_map(x_height).unwrap_or(0.0);
+    let pivot_heights: HashMap<String, f64> = font
+        .masters
+        .iter()
+        .map(|master| (master.id.clone(), origin.unwrap_or(x_height(master).unwrap_or(fallback_height) / 2.0)))
+        .collect();
+    let pivot_height = |layer: &Layer| {
+        layer
+            .id
+            .as_ref()
+            .and_then(|id| pivot_heights.get(id))
+            .copied()
+            .unwrap_or(origin.unwrap_or(fallback_height / 2.0))
+    };
+    let transform_of = |layer: &Layer| {
+        slant_transform(angle, rotation, Point::new(layer.width as f64 / 2.0, pivot_height(layer)))
+    };
+
+    // Slant transforms of every layer, to conjugate component transforms with
+    let transforms: HashMap<(String, Option<String>), Affine> = font
+        .glyphs
+        .iter()
+        .flat_map(|glyph| {
+            glyph
+                .layers
+                .iter()
+                .filter(|layer| !layer.is_background)
+                .map(|layer| ((glyph.name.to_string(), layer.id.clone()), transform_of(layer)))
+        })
+        .collect();
+
+    let mut layer_count = 0;
+    let mut changed = HashSet::new();
+    for glyph in font.glyphs.iter_mut() {
+        let name = glyph.name.to_string();
+        for layer in glyph.layers.iter_mut().filter(|layer| !layer.is_background) {
+            let transform = transforms[&(name.clone(), layer.id.clone())];
+            for shape in &mut layer.shapes {
+                match shape {
+                    Shape::Path(path) => {
+                        for node in &mut path.nodes {
+                            let point = transform * Point::new(node.x, node.y);
+                            node.x = point.x;
+                            node.y = point.y;
+                        }
+                    }
+                    Shape::Component(component) => {
+                        // The base is slanted with its own pivot, so undo that and apply ours
+                        let base = transforms
+                            .get(&(component.reference.to_string(), layer.id.clone()))
+                            .copied()
+                            .unwrap_or(transform);
+                        component.transform = transform * component.transform * base.inverse();
+                    }
+                }
+            }
+            for anchor in &mut layer.anchors {
+                let point = transform * Point::new(anchor.x, anchor.y);
+                anchor.x = point.x;
+                anchor.y = point.y;
+            }
+            layer_count += 1;
+            changed.insert(name.clone());
+        }
+    }
+
+    let italic_angle = -angle_degrees;
+    if update_italic_angle {
+        for master in font.masters.iter_mut() {
+            master.metrics.insert(babelfont::MetricType::ItalicAngle, italic_angle.round() as _);
+        }
+    }
+
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": changed.len(),
+        "italicAngle": update_italic_angle.then_some(italic_angle),
+    });
+    Ok((result, changed))
+}
+
+/// Accuracy of arc length measurements when cutting back segments at corners
+const CORNER_ACCURACY: f64 = 0.01;
+
+/// Which corners round_corners() rounds
+struct CornerOptions {
+    /// Also round inside (concave) corners
+    concave: bool,
+    /// Corners that turn less than this, in radians, stay sharp
+    min_angle: f64,
+}
+
+/// Angle by which the outline turns from one direction to another, in radians
+fn turn_angle(incoming: Vec2, outgoing: Vec2) -> f64 {
+    incoming.cross(outgoing).abs().atan2(incoming.dot(outgoing))
+}
+
+/// Segments of a path and whether it can be rounded (cubic or straight)
+fn corner_segments(path: &Path) -> Option<Vec<PathSeg>> {
+    if path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve)) {
+        return None;
+    }
+    let segments: Vec<PathSeg> = crate::glyph_outlines::path_to_bezpath(path).segments().collect();
+    (!segments.is_empty()).then_some(segments)
+}
+
+/// Corners of a path to round, as indices of the segments that start at them
+fn path_corners(path: &Path, options: &CornerOptions) -> Vec<usize> {
+    let Some(segments) = corner_segments(path) else { return Vec::new() };
+    let count = segments.len();
+    let area = crate::glyph_outlines::path_to_bezpath(path).area();
+    let first = if path.closed { 0 } else { 1 };
+    (first..count)
+        .filter(|&index| {
+            let incoming = end_tangent(&segments[(index + count - 1) % count]);
+            let outgoing = start_tangent(&segments[index]);
+            if incoming.hypot() < 1e-9 || outgoing.hypot() < 1e-9 {
+                return false;
+            }
+            let angle = turn_angle(incoming, outgoing);
+            // Hairpins have no circle touching both sides
+            if angle < options.min_angle || angle > std::f64::consts::PI - 1e-3 {
+                return false;
+            }
+            // Turning with the contour's winding is an outside corner
+            let convex = incoming.cross(outgoing) * area >= 0.0;
+            convex || options.concave
+        })
+        .collect()
+}
+
+/// Cubic arc from the end of one segment to the start of the next, tangent to both
+fn corner_fillet(incoming: &PathSeg, outgoing: &PathSeg, fallback: (Vec2, Vec2)) -> PathSeg {
+    let (start, end) = (incoming.end(), outgoing.start());
+    let direction = |tangent: Vec2, fallback: Vec2| {
+        if tangent.hypot() > 1e-9 { tangent.normalize() } else { fallback.normalize() }
+    };
+    let entry = direction(end_tangent(incoming), fallback.0);
+    let exit = direction(start_tangent(outgoing), fallback.1);
+    let chord = (end - start).hypot();
+    let angle = turn_angle(entry, exit);
+    // Handles of a circular arc: 4/3 tan(angle / 4) of the radius
+    let handle = if angle < 1e-6 {
+        chord / 3.0
+    } else {
+        chord / (2.0 * (angle / 2.0).sin()) * 4.0 / 3.0 * (angle / 4.0).tan()
+    };
+    PathSeg::Cubic(CubicBez::new(start, start + entry * handle, end - exit * handle, end))
+}
+
+/// Round corners of a path with arcs of a radius
+///
+/// Segments on both sides of a corner are cut back to where a circle of
+/// `radius` touches them, at most half their length, and the gap is bridged
+/// with a cubic arc. Returns None if the path cannot be rounded.
+fn round_path_corners(path: &Path, corners: &[usize], radius: f64) -> Option<Path> {
+    let segments = corner_segments(path)?;
+    let count = segments.len();
+    let lengths: Vec<f64> = segments.iter().map(|segment| segment.arclen(CORNER_ACCURACY)).collect();
+    let mut start_cuts = vec![0.0; count];
+    let mut end_cuts = vec![0.0; count];
+    let mut tangents = HashMap::new();
+    for &corner in corners {
+        let previous = (corner + count - 1) % count;
+        let (incoming, outgoing) = (end_tangent(&segments[previous]), start_tangent(&segments[corner]));
+        let cut = (radius * (turn_angle(incoming, outgoing) / 2.0).tan())
+            .min(lengths[previous] / 2.0)
+            .min(lengths[corner] / 2.0);
+        end_cuts[previous] = cut;
+        start_cuts[corner] = cut;
+        tangents.insert(corner, (incoming, outgoing));
+    }
+
+    let trimmed: Vec<PathSeg> = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let start = if start_cuts[index] > 0.0 { segment.inv_arclen(start_cuts[index], CORNER_ACCURACY) } else { 0.0 };
+            let end = if end_cuts[index] > 0.0 {
+                segment.inv_arclen(lengths[index] - end_cuts[index], CORNER_ACCURACY)
+            } else {
+                1.0
+            };
+            segment.subsegment(start..end.max(start))
+        })
+        .collect();
+
+    let mut rounded = Vec::with_capacity(count + corners.len());
+    for (index, segment) in trimmed.iter().enumerate() {
+        if index > 0 {
+            if let Some(&fallback) = tangents.get(&index) {
+                rounded.push(corner_fillet(&trimmed[index - 1], segment, fallback));
+            }
+        }
+        rounded.push(*segment);
+    }
+    // The corner at the start of a closed path is bridged last
+    if let Some(&fallback) = tangents.get(&0) {
+        rounded.push(corner_fillet(&trimmed[count - 1], &trimmed[0], fallback));
+    }
+
+    let mut bez = BezPath::from_path_segments(rounded.into_iter());
+    if path.closed {
+        bez.close_path();
+    }
+    let mut paths = crate::path_ops::bezpath_to_paths(&bez, false);
+    if paths.len() != 1 {
+        return None;
+    }
+    let mut rounded = paths.remove(0);
+    rounded.format_specific = path.format_specific.clone();
+    Some(rounded)
+}
+
+/// Replace sharp corners of source outlines with round arcs
+///
+/// Each corner between two segments gets an arc of `radius` (or smaller,
+/// where the segments are short), drawn as one cubic curve. Masters are
+/// rounded compatibly: corners are chosen in the default master and the
+/// same corners are rounded in every layer with the same structure, so
+/// the result still interpolates. Paths with quadratic curves are skipped.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `radius` - Corner radius in font units
+/// * `options_json` - Optional JSON options (see `round_corners` in lib.rs)
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 3, "glyphs": ["E"], "corners": 36, "warnings": [...]}' and the changed glyph names
+pub fn round_corners(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    radius: f64,
+    options_json: Option<&str>,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("corner options", e))?
+    };
+    if !(radius.is_finite() && radius > 0.0) {
+        return Err(ApiError::invalid_argument("radius", radius));
+    }
+    let options = CornerOptions {
+        concave: options.get("concave").and_then(|v| v.as_bool()).unwrap_or(true),
+        min_angle: options.get("minAngle").and_then(|v| v.as_f64()).unwrap_or(10.0).to_radians(),
+    };
+    let default_master = default_master_id(font).map(str::to_string);
+
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    let mut corner_count = 0;
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        let reference = default_master.as_deref().and_then(|master_id| {
+            glyph
+                .layers
+                .iter()
+                .find(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id))
+        });
+        let corners_of = |layer: &Layer| -> Vec<Vec<usize>> {
+            layer
+                .shapes
+                .iter()
+                .map(|shape| match shape {
+                    Shape::Path(path) => path_corners(path, &options),
+                    Shape::Component(_) => Vec::new(),
+                })
+                .collect()
+        };
+        let reference_corners = reference.map(|layer| (layer_structure(layer), corners_of(layer)));
+
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            let corners = match &reference_corners {
+                Some((structure, corners)) if *structure == layer_structure(layer) => corners.clone(),
+                _ => corners_of(layer),
+            };
+            let mut updated = layer.clone();
+            let mut rounded_any = false;
+            for (shape, corners) in updated.shapes.iter_mut().zip(&corners) {
+                if corners.is_empty() {
+                    continue;
+                }
+                if let Shape::Path(path) = shape {
+                    if let Some(rounded) = round_path_corners(path, corners, radius) {
+                        *path = rounded;
+                        corner_count += corners.len();
+                        rounded_any = true;
+                    }
+                }
+            }
+            if rounded_any {
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let warnings = structure_warnings(font, &glyphs, "rounding corners");
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "corners": corner_count,
+        "warnings": warnings,
+    });
+    Ok((result, changed))
+}
+
+/// Kinds of the nodes of each shape of a layer (None for components), to match compatible layers
+fn node_kinds(layer: &Layer) -> Vec<Option<Vec<u8>>> {
+    layer
+        .shapes
+        .iter()
+        .map(|shape| match shape {
+            Shape::Path(path) => Some(path.nodes.iter().map(|node| node_type_code(node.nodetype)).collect()),
+            Shape::Component(_) => None,
+        })
+        .collect()
+}
+
+/// Replace the cubic segments of a path by quadratic splines
+///
+/// `splines` holds the interior points (off-curve controls) of the spline
+/// replacing the cubic that ends at each on-curve node.
+fn replace_cubics(path: &mut Path, splines: &HashMap<usize, Vec<Point>>) {
+    let replaced: HashSet<usize> = splines
+        .keys()
+        .filter_map(|&end| cubic_ending_at(path, end))
+        .flat_map(|[_, first, second, _]| [first, second])
+        .collect();
+    let mut nodes = Vec::with_capacity(path.nodes.len());
+    for (index, node) in path.nodes.iter().enumerate() {
+        if replaced.contains(&index) {
+            continue;
+        }
+        if let Some(controls) = splines.get(&index) {
+            nodes.extend(controls.iter().map(|control| babelfont::Node {
+                x: control.x,
+                y: control.y,
+                nodetype: NodeType::OffCurve,
+                smooth: false,
+            }));
+            let mut end = node.clone();
+            end.nodetype = NodeType::QCurve;
+            nodes.push(end);
+        } else {
+            nodes.push(node.clone());
+        }
+    }
+    path.nodes = nodes;
+}
+
+/// Convert cubic curves of compatible layers to quadratic splines together
+///
+/// Each cubic is approximated in all layers at once with the same number
+/// of quadratic pieces (the fewest that keep every layer within
+/// `tolerance`), so the layers stay compatible. Returns the number of
+/// converted segments per layer, or None if a curve cannot be approximated.
+fn quadratic_layers(layers: &mut [Layer], tolerance: f64) -> Option<usize> {
+    let Some(first) = layers.first() else { return Some(0) };
+    let shape_count = first.shapes.len();
+    let mut converted = 0;
+    for shape_index in 0..shape_count {
+        let Shape::Path(path) = &layers[0].shapes[shape_index] else { continue };
+        let ends: Vec<usize> = (0..path.nodes.len()).filter(|&index| cubic_ending_at(path, index).is_some()).collect();
+        if ends.is_empty() {
+            continue;
+        }
+
+        let mut splines: Vec<HashMap<usize, Vec<Point>>> = vec![HashMap::new(); layers.len()];
+        for &end in &ends {
+            let cubics: Vec<CubicBez> = layers
+                .iter()
+                .filter_map(|layer| match &layer.shapes[shape_index] {
+                    Shape::Path(path) => {
+                        let [p0, p1, p2, p3] = cubic_ending_at(path, end)?.map(|index| node_point(path, index));
+                        Some(CubicBez::new(p0, p1, p2, p3))
+                    }
+                    Shape::Component(_) => None,
+                })
+                .collect();
+            if cubics.len() != layers.len() {
+                return None;
+            }
+            let quadratic = kurbo::cubics_to_quadratic_splines(&cubics, tolerance)?;
+            for (layer_splines, spline) in splines.iter_mut().zip(quadratic) {
+                let points = spline.points();
+                layer_splines.insert(end, points[1..points.len() - 1].to_vec());
+            }
+        }
+
+        for (layer, layer_splines) in layers.iter_mut().zip(&splines) {
+            if let Shape::Path(path) = &mut layer.shapes[shape_index] {
+                replace_cubics(path, layer_splines);
+            }
+        }
+        converted += ends.len();
+    }
+    Some(converted)
+}
+
+/// Convert the cubic curves of source outlines to quadratic curves
+///
+/// Layers whose nodes match the glyph's default master layer are converted
+/// together, so every curve gets the same number of quadratic pieces in
+/// each of them and masters keep interpolating (as cu2qu does). Other
+/// layers are converted on their own. Lines and quadratic curves are kept.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `tolerance` - Largest allowed deviation from the cubic curves, in font units
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 6, "glyphs": ["o"], "segments": 16, "skipped": [{"glyph": "s", "reason": "..."}]}' and the changed glyph names
+pub fn convert_to_quadratic(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    tolerance: f64,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(ApiError::invalid_argument("tolerance", tolerance));
+    }
+    let default_master = default_master_id(font).map(str::to_string);
+
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    let mut segment_count = 0;
+    let mut skipped = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        let layers: Vec<(usize, &Layer)> = glyph
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| !layer.is_background)
+            .collect();
+        let reference_kinds = default_master.as_deref().and_then(|master_id| {
+            layers
+                .iter()
+                .find(|(_, layer)| layer.id.as_deref() == Some(master_id))
+                .map(|(_, layer)| node_kinds(layer))
+        });
+
+        // The layers compatible with the default master form one group, the others their own
+        let (compatible, others): (Vec<(usize, &Layer)>, Vec<(usize, &Layer)>) = layers
+            .into_iter()
+            .partition(|(_, layer)| reference_kinds.as_ref() == Some(&node_kinds(layer)));
+        let mut groups = vec![compatible];
+        groups.extend(others.into_iter().map(|layer| vec![layer]));
+
+        for group in groups.into_iter().filter(|group| !group.is_empty()) {
+            let mut converted: Vec<Layer> = group.iter().map(|(_, layer)| (*layer).clone()).collect();
+            match quadratic_layers(&mut converted, tolerance) {
+                Some(0) => {}
+                Some(segments) => {
+                    segment_count += segments * converted.len();
+                    for ((index, _), layer) in group.iter().zip(converted) {
+                        updates.push((glyph.name.to_string(), *index, layer));
+                    }
+                }
+                None => {
+                    let layers: Vec<String> = group.iter().map(|(index, layer)| layer_label(layer, *index)).collect();
+                    skipped.push(serde_json::json!({
+                        "glyph": glyph.name.to_string(),
+                        "reason": format!("Curves of {} cannot be approximated within {} units", layers.join(", "), tolerance),
+                    }));
+                }
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "segments": segment_count,
+        "skipped": skipped,
+    });
+    Ok((result, changed))
+}
+
+/// Convert the quadratic curves of source outlines to cubic curves
+///
+/// Every quadratic piece becomes the cubic curve of exactly the same
+/// shape; the on-curve points implied between consecutive off-curve
+/// points of a spline become smooth nodes. The conversion depends only on
+/// the node types, so compatible masters stay compatible. Paths without
+/// quadratic curves are left unchanged.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 6, "glyphs": ["o"], "paths": 4}' and the changed glyph names
+pub fn convert_to_cubic(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    let mut path_count = 0;
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            let mut updated = layer.clone();
+            let mut converted_any = false;
+            for shape in &mut updated.shapes {
+                let Shape::Path(path) = shape else { continue };
+                if !path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve)) {
+                    continue;
+                }
+                let mut paths = crate::path_ops::bezpath_to_paths(&crate::glyph_outlines::path_to_bezpath(path), false);
+                if paths.len() != 1 {
+                    continue;
+                }
+                let mut cubic = paths.remove(0);
+                cubic.format_specific = path.format_specific.clone();
+                *path = cubic;
+                path_count += 1;
+                converted_any = true;
+            }
+            if converted_any {
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "paths": path_count,
+    });
+    Ok((result, changed))
+}
+
+/// Scale a guide's position
+fn scale_guide(guide: &mut babelfont::Guide, factor: f64) {
+    guide.pos.x = (guide.pos.x as f64 * factor) as _;
+    guide.pos.y = (guide.pos.y as f64 * factor) as _;
+}
+
+/// Scale the font to a new units-per-em value
+///
+/// Multiplies everything measured in font units by new / old units per em:
+/// nodes, anchors, guides, advance widths and component offsets of every
+/// layer (backgrounds included), kerning values, master guides and
+/// vertical metrics. The italic angle and component scales are kept.
+/// Numbers in feature code are not changed. With `round`, coordinates are
+/// then rounded to integers compatibly (see round_coordinates()) and widths
+/// rounded; kerning and metrics are always rounded.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `new_upm` - Units per em to scale to (16 to 16384)
+/// * `round` - Round coordinates and widths to integers
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"upm": 1000, "factor": 0.48828125, "layers": 1200, "glyphs": 600}' and the changed glyph names
+pub fn scale_to_upm(
+    font: &mut babelfont::Font,
+    new_upm: u16,
+    round: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    if !(16..=16384).contains(&new_upm) {
+        return Err(ApiError::invalid_argument("units per em", new_upm));
+    }
+    let old_upm = font.upm as f64;
+    if old_upm <= 0.0 {
+        return Err("The font has no units per em to scale from".to_string().into());
+    }
+    let factor = new_upm as f64 / old_upm;
+    let scale = |value: f64| value * factor;
+
+    let mut layer_count = 0;
+    let mut changed = HashSet::new();
+    for glyph in font.glyphs.iter_mut() {
+        for layer in glyph.layers.iter_mut() {
+            let width = scale(layer.width as f64);
+            layer.width = if round { width.round() } else { width } as f32;
+            for shape in &mut layer.shapes {
+                match shape {
+                    Shape::Path(path) => {
+                        for node in &mut path.nodes {
+                            node.x = scale(node.x);
+                            node.y = scale(node.y);
+                        }
+                    }
+                    Shape::Component(component) => {
+                        let [a, b, c, d, dx, dy] = component.transform.as_coeffs();
+                        component.transform = Affine::new([a, b, c, d, scale(dx), scale(dy)]);
+                    }
+                }
+            }
+            for anchor in &mut layer.anchors {
+                anchor.x = scale(anchor.x);
+                anchor.y = scale(anchor.y);
+            }
+            for guide in &mut layer.guides {
+                scale_guide(guide, factor);
+            }
+            layer_count += 1;
+        }
+        changed.insert(glyph.name.to_string());
+    }
+
+    for master in font.masters.iter_mut() {
+        for value in master.kerning.values_mut() {
+            *value = scale(*value as f64).round() as _;
+        }
+        for (metric, value) in master.metrics.iter_mut() {
+            if *metric != babelfont::MetricType::ItalicAngle {
+                *value = scale(*value as f64).round() as _;
+            }
+        }
+        for guide in &mut master.guides {
+            scale_guide(guide, factor);
+        }
+    }
+    font.upm = new_upm as _;
+
+    if round {
+        round_coordinates(font, None, 1.0, true)?;
+    }
+
+    let result = serde_json::json!({
+        "upm": new_upm,
+        "factor": factor,
+        "layers": layer_count,
+        "glyphs": changed.len(),
+    });
+    Ok((result, changed))
+}
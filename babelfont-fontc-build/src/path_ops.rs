@@ -0,0 +1,590 @@
+// Boolean path operations module
+//
+// This module runs boolean operations (union, difference, intersection,
+// exclusive or) on source outlines with linesweeper, which works on the
+// curves directly instead of flattening them to polygons. Results are
+// converted back to source paths in the layer's curve type (cubic or
+// quadratic) with corrected winding directions. It removes overlaps in
+// source layers, combines selected paths of a layer for the editor and
+// offsets outlines (expanding or contracting them by stroking).
+
+use babelfont::{Layer, Node, NodeType, Path, Shape};
+use kurbo::{Affine, BezPath, CubicBez, Join, PathEl, Point, QuadBez, Stroke, StrokeOpts};
+use linesweeper::{binary_op, BinaryOp, FillRule};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::errors::ApiError;
+use crate::source_checks::{correct_layer_directions, layer_label, outer_is_clockwise, selected_glyphs};
+
+/// Maximum deviation when converting cubic results to quadratic curves, in font units
+const QUADRATIC_ACCURACY: f64 = 0.5;
+
+/// Maximum deviation of stroked offset curves from the exact offset, in font units
+const OFFSET_ACCURACY: f64 = 0.25;
+
+/// A boolean operation between two sets of paths
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BooleanOp {
+    Union,
+    Difference,
+    Intersection,
+    Xor,
+}
+
+impl BooleanOp {
+    /// Parse an operation name: "union", "subtract" (or "difference"), "intersect" (or "intersection"), "xor"
+    pub fn from_name(name: &str) -> Result<Self, ApiError> {
+        match name {
+            "union" => Ok(BooleanOp::Union),
+            "subtract" | "difference" => Ok(BooleanOp::Difference),
+            "intersect" | "intersection" => Ok(BooleanOp::Intersection),
+            "xor" => Ok(BooleanOp::Xor),
+            other => Err(ApiError::invalid_argument("boolean operation", other)),
+        }
+    }
+}
+
+/// Whether any of the paths uses quadratic curves
+fn is_quadratic<'a>(paths: impl IntoIterator<Item = &'a Path>) -> bool {
+    paths
+        .into_iter()
+        .any(|path| path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve)))
+}
+
+/// Closed paths as a single kurbo path
+fn closed_bezpath<'a>(paths: impl IntoIterator<Item = &'a Path>) -> BezPath {
+    let mut bez = BezPath::new();
+    for path in paths.into_iter().filter(|path| path.closed) {
+        bez.extend(crate::glyph_outlines::path_to_bezpath(path));
+    }
+    bez
+}
+
+/// Mark on-curve nodes between collinear handles as smooth
+fn mark_smooth(nodes: &mut [Node], closed: bool) {
+    let count = nodes.len();
+    let points: Vec<Point> = nodes.iter().map(|node| Point::new(node.x, node.y)).collect();
+    for index in 0..count {
+        if matches!(nodes[index].nodetype, NodeType::OffCurve | NodeType::Move) {
+            continue;
+        }
+        if !closed && (index == 0 || index + 1 == count) {
+            continue;
+        }
+        let previous = (index + count - 1) % count;
+        let next = (index + 1) % count;
+        let has_handle = matches!(nodes[previous].nodetype, NodeType::OffCurve)
+            || matches!(nodes[next].nodetype, NodeType::OffCurve);
+        let incoming = points[index] - points[previous];
+        let outgoing = points[next] - points[index];
+        let (length_in, length_out) = (incoming.hypot(), outgoing.hypot());
+        if !has_handle || length_in < 1e-9 || length_out < 1e-9 {
+            continue;
+        }
+        // Same direction within about half a degree
+        let cross = incoming.cross(outgoing) / (length_in * length_out);
+        nodes[index].smooth = cross.abs() < 0.01 && incoming.dot(outgoing) > 0.0;
+    }
+}
+
+/// Convert a kurbo path into source paths
+///
+/// Closed contours start at the node after their start point and end with
+/// it, as in source files. With `quadratic`, cubic curves are approximated
+/// by quadratic ones; otherwise quadratic curves are raised to cubic.
+pub(crate) fn bezpath_to_paths(bez: &BezPath, quadratic: bool) -> Vec<Path> {
+    let node = |point: Point, nodetype: NodeType| Node { x: point.x, y: point.y, nodetype, smooth: false };
+    let mut paths = Vec::new();
+    let mut nodes: Vec<Node> = Vec::new();
+    let (mut start, mut current) = (Point::ZERO, Point::ZERO);
+
+    let finish_open = |nodes: &mut Vec<Node>, paths: &mut Vec<Path>| {
+        if nodes.len() > 1 {
+            let mut open = std::mem::take(nodes);
+            mark_smooth(&mut open, false);
+            paths.push(Path { nodes: open, closed: false, format_specific: Default::default() });
+        }
+        nodes.clear();
+    };
+
+    for element in bez.elements() {
+        match *element {
+            PathEl::MoveTo(point) => {
+                finish_open(&mut nodes, &mut paths);
+                nodes.push(node(point, NodeType::Move));
+                start = point;
+                current = point;
+            }
+            PathEl::LineTo(point) => {
+                nodes.push(node(point, NodeType::Line));
+                current = point;
+            }
+            PathEl::QuadTo(control, point) => {
+                if quadratic {
+                    nodes.push(node(control, NodeType::OffCurve));
+                    nodes.push(node(point, NodeType::QCurve));
+                } else {
+                    let cubic = QuadBez::new(current, control, point).raise();
+                    nodes.push(node(cubic.p1, NodeType::OffCurve));
+                    nodes.push(node(cubic.p2, NodeType::OffCurve));
+                    nodes.push(node(point, NodeType::Curve));
+                }
+                current = point;
+            }
+            PathEl::CurveTo(control1, control2, point) => {
+                if quadratic {
+                    for (_, _, quad) in CubicBez::new(current, control1, control2, point).to_quads(QUADRATIC_ACCURACY) {
+                        nodes.push(node(quad.p1, NodeType::OffCurve));
+                        nodes.push(node(quad.p2, NodeType::QCurve));
+                    }
+                } else {
+                    nodes.push(node(control1, NodeType::OffCurve));
+                    nodes.push(node(control2, NodeType::OffCurve));
+                    nodes.push(node(point, NodeType::Curve));
+                }
+                current = point;
+            }
+            PathEl::ClosePath => {
+                let mut closed: Vec<Node> = nodes.drain(..).skip(1).collect();
+                let returns_to_start = closed
+                    .last()
+                    .is_some_and(|last| (Point::new(last.x, last.y) - start).hypot() < 1e-9);
+                if !returns_to_start {
+                    closed.push(node(start, NodeType::Line));
+                }
+                if closed.len() > 1 {
+                    mark_smooth(&mut closed, true);
+                    paths.push(Path { nodes: closed, closed: true, format_specific: Default::default() });
+                }
+                current = start;
+            }
+        }
+    }
+    finish_open(&mut nodes, &mut paths);
+    paths
+}
+
+/// Combine two sets of paths with a boolean operation
+///
+/// Only closed paths take part; the nonzero winding rule decides what is
+/// inside, so counters must run against their outer contours.
+pub fn combine_paths(a: &[&Path], b: &[&Path], op: BooleanOp) -> Result<Vec<Path>, String> {
+    let quadratic = is_quadratic(a.iter().chain(b.iter()).copied());
+    let op = match op {
+        BooleanOp::Union => BinaryOp::Union,
+        BooleanOp::Difference => BinaryOp::Difference,
+        BooleanOp::Intersection => BinaryOp::Intersection,
+        BooleanOp::Xor => BinaryOp::Xor,
+    };
+    let contours = binary_op(
+        &closed_bezpath(a.iter().copied()),
+        &closed_bezpath(b.iter().copied()),
+        FillRule::NonZero,
+        op,
+    )
+    .map_err(|e| format!("Boolean operation failed: {:?}", e))?;
+
+    Ok(contours
+        .contours()
+        .flat_map(|contour| bezpath_to_paths(&contour.path, quadratic))
+        .collect())
+}
+
+/// Remove overlaps between the closed paths of a layer
+///
+/// Open paths and components are kept after the merged contours.
+///
+/// # Arguments
+/// * `layer` - Layer to edit
+/// * `outer_clockwise` - Whether outer contours of the font run clockwise
+///
+/// # Returns
+/// * `Result<bool, String>` - Whether the layer had closed paths to merge
+pub fn remove_layer_overlaps(layer: &mut Layer, outer_clockwise: bool) -> Result<bool, String> {
+    let closed: Vec<&Path> = layer
+        .shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Path(path) if path.closed => Some(path),
+            _ => None,
+        })
+        .collect();
+    if closed.is_empty() {
+        return Ok(false);
+    }
+
+    let merged = combine_paths(&closed, &[], BooleanOp::Union)?;
+    let others: Vec<Shape> = layer
+        .shapes
+        .iter()
+        .filter(|shape| !matches!(shape, Shape::Path(path) if path.closed))
+        .cloned()
+        .collect();
+    layer.shapes = merged.into_iter().map(Shape::Path).chain(others).collect();
+    correct_layer_directions(layer, outer_clockwise);
+    Ok(true)
+}
+
+/// Whether a layer is included in a layer scope
+///
+/// Scopes: "all" (all non-background layers), "masters" (master layers) or
+/// a layer or master ID.
+pub(crate) fn in_scope(font: &babelfont::Font, layer: &Layer, scope: &str) -> bool {
+    if layer.is_background {
+        return false;
+    }
+    match scope {
+        "all" => true,
+        "masters" => font.masters.iter().any(|master| Some(&master.id) == layer.id.as_ref()),
+        id => layer.id.as_deref() == Some(id),
+    }
+}
+
+/// Warnings for glyphs whose master layers no longer have matching contour counts
+///
+/// Master layers must keep matching contour counts to interpolate. The
+/// `action` names the operation in the message, e.g. "removing overlaps".
+fn compatibility_warnings(font: &babelfont::Font, glyphs: &[&String], action: &str) -> Vec<serde_json::Value> {
+    let mut warnings = Vec::new();
+    for name in glyphs {
+        let Some(glyph) = font.glyphs.get(name.as_str()) else { continue };
+        let counts: BTreeMap<String, usize> = glyph
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| in_scope(font, layer, "masters"))
+            .map(|(index, layer)| {
+                let contours = layer.shapes.iter().filter(|shape| matches!(shape, Shape::Path(_))).count();
+                (layer_label(layer, index), contours)
+            })
+            .collect();
+        let mut distinct: Vec<usize> = counts.values().copied().collect();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() > 1 {
+            warnings.push(serde_json::json!({
+                "glyph": name,
+                "message": format!("Masters of '{}' have different contour counts after {} and are no longer compatible", name, action),
+                "contours": counts,
+            }));
+        }
+    }
+    warnings
+}
+
+/// Remove overlaps in the source outlines of glyphs
+///
+/// With `flatten_components`, components are decomposed first so their
+/// outlines are merged with the glyph's own paths. Afterwards the contour
+/// counts of each glyph's master layers are compared: overlap removal can
+/// merge contours in one master but not in another, which breaks
+/// interpolation.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID
+/// * `flatten_components` - Decompose components before merging
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 4, "glyphs": ["A"], "warnings": [{"glyph": "A", "message": "...", "contours": {"Regular": 1, "Bold": 2}}]}' and the changed glyph names
+pub fn remove_overlap(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    layer_scope: &str,
+    flatten_components: bool,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    // Compute the new layers while the font is borrowed immutably
+    let outer_clockwise = outer_is_clockwise(font);
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if !in_scope(font, layer, layer_scope) {
+                continue;
+            }
+            let mut updated = layer.clone();
+            let has_components = layer.shapes.iter().any(|shape| matches!(shape, Shape::Component(_)));
+            if flatten_components && has_components {
+                let location = crate::glyph_outlines::layer_location(font, layer).ok_or_else(|| {
+                    format!("Layer '{}' of '{}' has no location", layer_label(layer, index), glyph.name)
+                })?;
+                updated.shapes = crate::glyph_outlines::flatten_layer(font, layer, &location)?;
+            }
+            if remove_layer_overlaps(&mut updated, outer_clockwise)
+                .map_err(|e| format!("'{}' ({}): {}", glyph.name, layer_label(layer, index), e))?
+            {
+                updates.push((glyph.name.to_string(), index, updated));
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let warnings = compatibility_warnings(font, &glyphs, "removing overlaps");
+
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "warnings": warnings,
+    });
+    Ok((result, changed))
+}
+
+/// Combine selected paths of a layer with a boolean operation
+///
+/// The paths of both operands are removed from the layer and the result is
+/// inserted where the first of them was. For union and xor the two
+/// operands are interchangeable; subtract removes `b` from `a`.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Glyph to edit
+/// * `layer_id` - ID of the layer to edit
+/// * `paths_a` - Shape indices of the first operand
+/// * `paths_b` - Shape indices of the second operand
+/// * `op` - Boolean operation
+///
+/// # Returns
+/// * `Result<Vec<usize>, ApiError>` - Shape indices of the resulting paths in the edited layer
+pub fn boolean_layer_paths(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    layer_id: &str,
+    paths_a: &[usize],
+    paths_b: &[usize],
+    op: BooleanOp,
+) -> Result<Vec<usize>, ApiError> {
+    let outer_clockwise = outer_is_clockwise(font);
+    let glyph = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let layer = glyph
+        .layers
+        .iter_mut()
+        .find(|layer| layer.id.as_deref() == Some(layer_id))
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id))?;
+
+    let mut selected: Vec<usize> = paths_a.iter().chain(paths_b).copied().collect();
+    selected.sort_unstable();
+    selected.dedup();
+    // Path indices must be distinct and belong to one operand only
+    if selected.len() != paths_a.len() + paths_b.len() {
+        return Err(ApiError::invalid_argument("path indices", format!("{:?} and {:?}", paths_a, paths_b)));
+    }
+    let operand = |indices: &[usize]| -> Result<Vec<&Path>, ApiError> {
+        indices
+            .iter()
+            .map(|&index| match layer.shapes.get(index) {
+                Some(Shape::Path(path)) if path.closed => Ok(path),
+                Some(Shape::Path(_)) => Err(ApiError::invalid_argument("shape index", format!("{} (an open path)", index))),
+                Some(Shape::Component(_)) => Err(ApiError::invalid_argument("shape index", format!("{} (a component)", index))),
+                None => Err(ApiError::invalid_argument("shape index", index)),
+            })
+            .collect()
+    };
+    let result = combine_paths(&operand(paths_a)?, &operand(paths_b)?, op)?;
+
+    // Replace the operands by the result at the position of the first one
+    let Some(&insert_at) = selected.first() else {
+        return Ok(Vec::new());
+    };
+    for &index in selected.iter().rev() {
+        layer.shapes.remove(index);
+    }
+    let count = result.len();
+    layer.shapes.splice(insert_at..insert_at, result.into_iter().map(Shape::Path));
+
+    // Fix the winding of the new contours only, keeping other paths as drawn
+    let mut scratch = layer.clone();
+    scratch.shapes = layer.shapes[insert_at..insert_at + count].to_vec();
+    correct_layer_directions(&mut scratch, outer_clockwise);
+    layer.shapes.splice(insert_at..insert_at + count, scratch.shapes);
+
+    Ok((insert_at..insert_at + count).collect())
+}
+
+/// Parameters of an outline offset
+#[derive(Clone, Copy, Debug)]
+pub struct OffsetOptions {
+    /// Horizontal offset in font units; positive expands, negative contracts
+    pub horizontal: f64,
+    /// Vertical offset in font units, with the same sign as `horizontal`
+    pub vertical: f64,
+    /// Corner shape where offset segments meet
+    pub join: Join,
+    /// Longest miter, as a multiple of the offset, before it is beveled
+    pub miter_limit: f64,
+}
+
+impl OffsetOptions {
+    /// Parse offset options from the JSON options of offset_paths()
+    ///
+    /// Distances must be finite; horizontal and vertical offsets must both be
+    /// non-zero and expand or contract together. `miterLimit` is at least 1.
+    pub fn from_json(distance: f64, options: &serde_json::Value) -> Result<Self, ApiError> {
+        let vertical = options.get("vertical").and_then(|v| v.as_f64()).unwrap_or(distance);
+        let join = match options.get("join").and_then(|v| v.as_str()).unwrap_or("miter") {
+            "miter" => Join::Miter,
+            "round" => Join::Round,
+            "bevel" => Join::Bevel,
+            other => return Err(ApiError::invalid_argument("corner join", other)),
+        };
+        let miter_limit = options.get("miterLimit").and_then(|v| v.as_f64()).unwrap_or(4.0);
+        let both_finite = distance.is_finite() && vertical.is_finite();
+        let same_sign = (distance == 0.0 && vertical == 0.0) || distance * vertical > 0.0;
+        if !both_finite || !same_sign {
+            return Err(ApiError::invalid_argument("offset", format!("{} × {}", distance, vertical)));
+        }
+        if miter_limit < 1.0 {
+            return Err(ApiError::invalid_argument("miterLimit", miter_limit));
+        }
+        Ok(OffsetOptions { horizontal: distance, vertical, join, miter_limit })
+    }
+
+    fn is_noop(&self) -> bool {
+        self.horizontal == 0.0 && self.vertical == 0.0
+    }
+}
+
+/// Offset closed paths by stroking them
+///
+/// The outline is stroked with twice the offset and the stroke is united
+/// with the outline (expanding) or subtracted from it (contracting). For
+/// different horizontal and vertical offsets, the outline is stretched
+/// vertically so that a uniform stroke covers both, then scaled back.
+pub fn offset_closed_paths(paths: &[&Path], options: &OffsetOptions) -> Result<Vec<Path>, String> {
+    let quadratic = is_quadratic(paths.iter().copied());
+    let outline = closed_bezpath(paths.iter().copied());
+    let distance = options.horizontal.abs();
+    let stretch = Affine::scale_non_uniform(1.0, distance / options.vertical.abs());
+
+    let style = Stroke::new(2.0 * distance)
+        .with_join(options.join)
+        .with_miter_limit(options.miter_limit);
+    let mut stroke = kurbo::stroke(stretch * outline.clone(), &style, &StrokeOpts::default(), OFFSET_ACCURACY);
+    stroke.apply_affine(stretch.inverse());
+
+    let op = if options.horizontal > 0.0 { BinaryOp::Union } else { BinaryOp::Difference };
+    let contours = binary_op(&outline, &stroke, FillRule::NonZero, op)
+        .map_err(|e| format!("Offset failed: {:?}", e))?;
+    Ok(contours
+        .contours()
+        .flat_map(|contour| bezpath_to_paths(&contour.path, quadratic))
+        .collect())
+}
+
+/// Offset the closed paths of a layer
+///
+/// Open paths and components are kept after the offset contours; a
+/// component follows the offset of its base glyph.
+///
+/// # Arguments
+/// * `layer` - Layer to edit
+/// * `options` - Offset parameters
+/// * `outer_clockwise` - Whether outer contours of the font run clockwise
+///
+/// # Returns
+/// * `Result<bool, String>` - Whether the layer had closed paths to offset
+pub fn offset_layer(layer: &mut Layer, options: &OffsetOptions, outer_clockwise: bool) -> Result<bool, String> {
+    let closed: Vec<&Path> = layer
+        .shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Path(path) if path.closed => Some(path),
+            _ => None,
+        })
+        .collect();
+    if closed.is_empty() {
+        return Ok(false);
+    }
+
+    let offset = offset_closed_paths(&closed, options)?;
+    let others: Vec<Shape> = layer
+        .shapes
+        .iter()
+        .filter(|shape| !matches!(shape, Shape::Path(path) if path.closed))
+        .cloned()
+        .collect();
+    layer.shapes = offset.into_iter().map(Shape::Path).chain(others).collect();
+    correct_layer_directions(layer, outer_clockwise);
+    Ok(true)
+}
+
+/// Expand or contract the source outlines of glyphs
+///
+/// Positive distances make outlines heavier, negative ones lighter. Counters
+/// shrink as the outline grows, so one call changes stem weights by twice
+/// the distance. Offsetting can merge or drop contours differently in each
+/// master; such glyphs are reported as warnings.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `distance` - Horizontal offset in font units
+/// * `options_json` - Optional JSON options (see `offset_paths` in lib.rs)
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 4, "glyphs": ["A"], "warnings": [...]}' and the changed glyph names
+pub fn offset_paths(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    distance: f64,
+    options_json: Option<&str>,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json).map_err(|e| ApiError::invalid_json("offset options", e))?
+    };
+    let layer_scope = options.get("layers").and_then(|v| v.as_str()).unwrap_or("all");
+    let offset = OffsetOptions::from_json(distance, &options)?;
+
+    let outer_clockwise = outer_is_clockwise(font);
+    let mut updates: Vec<(String, usize, Layer)> = Vec::new();
+    if !offset.is_noop() {
+        for glyph in selected_glyphs(font, glyph_names_json)? {
+            for (index, layer) in glyph.layers.iter().enumerate() {
+                if !in_scope(font, layer, layer_scope) {
+                    continue;
+                }
+                let mut updated = layer.clone();
+                if offset_layer(&mut updated, &offset, outer_clockwise)
+                    .map_err(|e| format!("'{}' ({}): {}", glyph.name, layer_label(layer, index), e))?
+                {
+                    updates.push((glyph.name.to_string(), index, updated));
+                }
+            }
+        }
+    }
+
+    let layer_count = updates.len();
+    let mut changed = HashSet::new();
+    for (name, index, updated) in updates {
+        if let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) {
+            *layer = updated;
+            changed.insert(name);
+        }
+    }
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let warnings = compatibility_warnings(font, &glyphs, "offsetting");
+
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "warnings": warnings,
+    });
+    Ok((result, changed))
+}
@@ -0,0 +1,321 @@
+// Production glyph names module
+//
+// This module works out the production names the compiler gives glyphs
+// when production names are used: a glyph's explicit production name, else
+// its own name if that is an AGL name, else "uniXXXX"/"uXXXXX" from its
+// codepoint. Suffixes (".sc") and ligature parts ("f_i") are converted part
+// by part. It also renames the source between friendly and production names,
+// updating components, kerning, groups and feature code with the glyphs.
+
+use babelfont::Shape;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::charsets::{glyph_name_codepoint, suggested_glyph_name};
+use crate::feature_syntax::rename_glyphs;
+
+/// Customary glyph names that are not AGL names, so production uses "uniXXXX"
+const NON_AGL_NAMES: &[&str] = &[
+    "nbspace", "softhyphen", "guillemetleft", "guillemetright", "micro", "idotless",
+    "Tcommaaccent", "tcommaaccent", "circumflexcomb", "macroncomb", "brevecomb", "dotaccentcomb",
+    "dieresiscomb", "ringcomb", "hungarumlautcomb", "caroncomb", "commaaccentcomb", "cedillacomb",
+    "ogonekcomb", "Delta", "sigmafinal", "Germandbls", "numero",
+];
+
+/// "uniXXXX" or "uXXXXX" name of a codepoint
+fn uni_name(codepoint: u32) -> String {
+    if codepoint <= 0xFFFF {
+        format!("uni{:04X}", codepoint)
+    } else {
+        format!("u{:05X}", codepoint)
+    }
+}
+
+/// Whether a name is an AGL name the compiler keeps as it is
+fn is_agl_name(name: &str) -> bool {
+    !name.contains('-')
+        && !NON_AGL_NAMES.contains(&name)
+        && glyph_name_codepoint(name).is_some_and(|codepoint| suggested_glyph_name(codepoint) == name)
+}
+
+/// Split a name into its ligature parts and suffix ("f_i.ss01" is ["f", "i"] and ".ss01")
+fn split_name(name: &str) -> (Vec<&str>, &str) {
+    let (stem, suffix) = match name.find('.') {
+        Some(index) if index > 0 => name.split_at(index),
+        _ => (name, ""),
+    };
+    (stem.split('_').collect(), suffix)
+}
+
+/// Production name the compiler uses for a glyph
+///
+/// Names starting with a period (".notdef") are kept. Ligatures of
+/// characters all in the BMP are named "uniXXXXYYYY", others join their
+/// parts with underscores. A part whose character is unknown keeps the
+/// whole name unchanged.
+pub fn production_name(font: &babelfont::Font, glyph: &babelfont::Glyph) -> String {
+    if let Some(production_name) = &glyph.production_name {
+        return production_name.to_string();
+    }
+    let name = glyph.name.as_str();
+    if name.starts_with('.') {
+        return name.to_string();
+    }
+
+    let (parts, suffix) = split_name(name);
+    let own_codepoint = (parts.len() == 1 && suffix.is_empty())
+        .then(|| glyph.codepoints.first().copied())
+        .flatten();
+    let mut production_parts = Vec::new();
+    for part in &parts {
+        if is_agl_name(part) {
+            production_parts.push(part.to_string());
+            continue;
+        }
+        let codepoint = own_codepoint
+            .or_else(|| glyph_name_codepoint(part))
+            .or_else(|| font.glyphs.get(part).and_then(|glyph| glyph.codepoints.first().copied()));
+        match codepoint {
+            Some(codepoint) => production_parts.push(uni_name(codepoint)),
+            None => return name.to_string(),
+        }
+    }
+
+    let bmp_hex: Option<Vec<&str>> = production_parts
+        .iter()
+        .map(|part| part.strip_prefix("uni").filter(|hex| hex.len() == 4))
+        .collect();
+    let stem = match bmp_hex {
+        Some(hex) if hex.len() > 1 => format!("uni{}", hex.concat()),
+        _ => production_parts.join("_"),
+    };
+    format!("{}{}", stem, suffix)
+}
+
+/// Friendly name of a glyph with a production name
+///
+/// "uniXXXX", "uXXXXX" and "uniXXXXYYYY" parts become customary names
+/// ("uni0410" is "A-cy"); other parts are kept.
+fn friendly_name(name: &str) -> String {
+    if name.starts_with('.') {
+        return name.to_string();
+    }
+    let (parts, suffix) = split_name(name);
+    let mut friendly_parts = Vec::new();
+    for part in parts {
+        let ligature = part
+            .strip_prefix("uni")
+            .filter(|hex| hex.len() > 4 && hex.len() % 4 == 0)
+            .and_then(|hex| {
+                (0..hex.len())
+                    .step_by(4)
+                    .map(|start| glyph_name_codepoint(&format!("uni{}", &hex[start..start + 4])))
+                    .collect::<Option<Vec<u32>>>()
+            });
+        match ligature {
+            Some(codepoints) => friendly_parts.extend(codepoints.into_iter().map(suggested_glyph_name)),
+            None => friendly_parts.push(glyph_name_codepoint(part).map(suggested_glyph_name).unwrap_or_else(|| part.to_string())),
+        }
+    }
+    format!("{}{}", friendly_parts.join("_"), suffix)
+}
+
+/// Production names of all glyphs
+///
+/// # Arguments
+/// * `font` - Reference to the font
+///
+/// # Returns
+/// * `JsonValue` - '{"names": {"A-cy": "uni0410", "a": "a"}, "conflicts": [{"name": "uni0410", "glyphs": ["A-cy", "uni0410"]}]}'; conflicts are production names shared by several glyphs
+pub fn get_production_names(font: &babelfont::Font) -> serde_json::Value {
+    let mut names = serde_json::Map::new();
+    let mut users: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for glyph in font.glyphs.iter() {
+        let production = production_name(font, glyph);
+        users.entry(production.clone()).or_default().push(glyph.name.to_string());
+        names.insert(glyph.name.to_string(), production.into());
+    }
+    let conflicts: Vec<serde_json::Value> = users
+        .into_iter()
+        .filter(|(_, glyphs)| glyphs.len() > 1)
+        .map(|(name, glyphs)| serde_json::json!({ "name": name, "glyphs": glyphs }))
+        .collect();
+    serde_json::json!({
+        "names": names,
+        "conflicts": conflicts,
+    })
+}
+
+/// Keep the renames that leave every glyph with a unique name
+///
+/// A rename is skipped if another glyph is renamed to the same name, or if
+/// the new name belongs to a glyph that keeps its name. Skipping a rename
+/// can block others, so this repeats until no more are skipped.
+fn unique_renames(font: &babelfont::Font, wanted: Vec<(String, String)>) -> (HashMap<String, String>, Vec<serde_json::Value>) {
+    let mut targets: HashMap<&str, usize> = HashMap::new();
+    for (_, new_name) in &wanted {
+        *targets.entry(new_name.as_str()).or_default() += 1;
+    }
+
+    let mut skipped = Vec::new();
+    let mut accepted: Vec<&(String, String)> = Vec::new();
+    for rename in &wanted {
+        if targets[rename.1.as_str()] > 1 {
+            let reason = format!("Several glyphs would be named '{}'", rename.1);
+            skipped.push(serde_json::json!({ "glyph": rename.0, "reason": reason }));
+        } else {
+            accepted.push(rename);
+        }
+    }
+    loop {
+        let moving: HashSet<&str> = accepted.iter().map(|(old_name, _)| old_name.as_str()).collect();
+        let (keep, blocked): (Vec<_>, Vec<_>) = accepted
+            .into_iter()
+            .partition(|(_, new_name)| font.glyphs.get(new_name).is_none() || moving.contains(new_name.as_str()));
+        accepted = keep;
+        if blocked.is_empty() {
+            break;
+        }
+        for (old_name, new_name) in blocked {
+            let reason = format!("Glyph '{}' already exists", new_name);
+            skipped.push(serde_json::json!({ "glyph": old_name, "reason": reason }));
+        }
+    }
+
+    let renames = accepted.into_iter().cloned().collect();
+    (renames, skipped)
+}
+
+/// Rename glyphs and every reference to them
+///
+/// Updates component references, kerning group members, kerning pairs of
+/// all masters and glyph names in feature code (prefixes, classes and
+/// features).
+fn apply_renames(font: &mut babelfont::Font, renames: &HashMap<String, String>) {
+    let rename = |name: &str| renames.get(name).cloned();
+
+    for glyph in font.glyphs.iter_mut() {
+        if let Some(new_name) = rename(glyph.name.as_str()) {
+            glyph.name = new_name.as_str().into();
+        }
+        for layer in glyph.layers.iter_mut() {
+            for shape in layer.shapes.iter_mut() {
+                if let Shape::Component(component) = shape {
+                    if let Some(new_name) = rename(component.reference.as_str()) {
+                        component.reference = new_name.as_str().into();
+                    }
+                }
+            }
+        }
+    }
+
+    for groups in [&mut font.first_kern_groups, &mut font.second_kern_groups] {
+        for members in groups.values_mut() {
+            for member in members.iter_mut() {
+                if let Some(new_name) = rename(member.as_str()) {
+                    *member = new_name.as_str().into();
+                }
+            }
+        }
+    }
+
+    // Group sides ("@name") never match a glyph name
+    for master in font.masters.iter_mut() {
+        master.kerning = std::mem::take(&mut master.kerning)
+            .into_iter()
+            .map(|((left, right), value)| {
+                let left = rename(left.as_str()).map(|name| name.as_str().into()).unwrap_or(left);
+                let right = rename(right.as_str()).map(|name| name.as_str().into()).unwrap_or(right);
+                ((left, right), value)
+            })
+            .collect();
+    }
+
+    for (_, code) in font.features.prefixes.iter_mut() {
+        *code = rename_glyphs(&code.to_string(), renames).into();
+    }
+    for (_, code) in font.features.classes.iter_mut() {
+        *code = rename_glyphs(&code.to_string(), renames).into();
+    }
+    for (_, code) in font.features.features.iter_mut() {
+        *code = rename_glyphs(&code.to_string(), renames).into();
+    }
+}
+
+/// Result JSON of a batch rename
+fn rename_report(renames: &HashMap<String, String>, skipped: Vec<serde_json::Value>) -> serde_json::Value {
+    let renamed: BTreeMap<&String, &String> = renames.iter().collect();
+    serde_json::json!({
+        "renamed": renamed,
+        "skipped": skipped,
+    })
+}
+
+/// Rename all glyphs to their production names
+///
+/// Explicit production names equal to the new glyph names are cleared.
+/// Glyphs whose production name is unknown keep their names.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+///
+/// # Returns
+/// * `JsonValue` - '{"renamed": {"A-cy": "uni0410"}, "skipped": [{"glyph": "...", "reason": "..."}]}'
+pub fn rename_to_production(font: &mut babelfont::Font) -> serde_json::Value {
+    let wanted: Vec<(String, String)> = font
+        .glyphs
+        .iter()
+        .map(|glyph| (glyph.name.to_string(), production_name(font, glyph)))
+        .filter(|(name, production)| name != production)
+        .collect();
+    let (renames, skipped) = unique_renames(font, wanted);
+    apply_renames(font, &renames);
+    for glyph in font.glyphs.iter_mut() {
+        if glyph.production_name.as_deref() == Some(glyph.name.as_str()) {
+            glyph.production_name = None;
+        }
+    }
+    rename_report(&renames, skipped)
+}
+
+/// Rename all glyphs from production names to customary names
+///
+/// "uniXXXX" names become the customary names of their characters, in the
+/// style of suggested_glyph_name(); other names are kept. A renamed glyph
+/// keeps its old name as its explicit production name when the compiler
+/// would not derive it from the new name.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+///
+/// # Returns
+/// * `JsonValue` - '{"renamed": {"uni0410": "A-cy"}, "skipped": [{"glyph": "...", "reason": "..."}]}'
+pub fn rename_to_friendly(font: &mut babelfont::Font) -> serde_json::Value {
+    let wanted: Vec<(String, String)> = font
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.production_name.is_none())
+        .map(|glyph| (glyph.name.to_string(), friendly_name(glyph.name.as_str())))
+        .filter(|(name, friendly)| name != friendly)
+        .collect();
+    let (renames, skipped) = unique_renames(font, wanted);
+    apply_renames(font, &renames);
+
+    let old_names: HashMap<&str, &str> = renames
+        .iter()
+        .map(|(old_name, new_name)| (new_name.as_str(), old_name.as_str()))
+        .collect();
+    let explicit: Vec<(String, String)> = font
+        .glyphs
+        .iter()
+        .filter_map(|glyph| {
+            let old_name = *old_names.get(glyph.name.as_str())?;
+            (production_name(font, glyph) != old_name).then(|| (glyph.name.to_string(), old_name.to_string()))
+        })
+        .collect();
+    for (name, old_name) in explicit {
+        if let Some(glyph) = font.glyphs.get_mut(&name) {
+            glyph.production_name = Some(old_name.as_str().into());
+        }
+    }
+    rename_report(&renames, skipped)
+}
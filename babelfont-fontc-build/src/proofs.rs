@@ -0,0 +1,513 @@
+// Proof text generation module
+//
+// This module generates the text used by the proofing views from the source
+// font: kerning permutation strings per category pair and for explicitly
+// kerned pairs. Glyphs without a codepoint are written as `/glyphname `
+// (Glyphs app notation) so the proofing view can still address them.
+//
+// It also produces proof layouts (waterfalls and glyph-set grids) as
+// structured data, so the UI and the PDF exporter render the same proofs,
+// and the control strings the spacing view sets a glyph in.
+
+use crate::errors::ApiError;
+use crate::font_reader::read_glyph_order;
+use crate::kerning;
+use crate::text_layout::{layout_block, LayoutOptions};
+use serde_json::Value as JsonValue;
+use unicode_script::{Script, UnicodeScript};
+use wasm_bindgen::prelude::*;
+
+/// Broad glyph categories used to pair glyphs in proofs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofCategory {
+    Uppercase,
+    Lowercase,
+    Figures,
+    Punctuation,
+}
+
+impl ProofCategory {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "uppercase" => Some(ProofCategory::Uppercase),
+            "lowercase" => Some(ProofCategory::Lowercase),
+            "figures" => Some(ProofCategory::Figures),
+            "punctuation" => Some(ProofCategory::Punctuation),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ProofCategory::Uppercase => "uppercase",
+            ProofCategory::Lowercase => "lowercase",
+            ProofCategory::Figures => "figures",
+            ProofCategory::Punctuation => "punctuation",
+        }
+    }
+
+    /// Categorize a character
+    pub fn of_char(c: char) -> Option<Self> {
+        if c.is_uppercase() {
+            Some(ProofCategory::Uppercase)
+        } else if c.is_lowercase() {
+            Some(ProofCategory::Lowercase)
+        } else if c.is_numeric() {
+            Some(ProofCategory::Figures)
+        } else if !c.is_alphanumeric() && !c.is_whitespace() && !c.is_control() {
+            Some(ProofCategory::Punctuation)
+        } else {
+            None
+        }
+    }
+
+    /// Flat control glyph placed around glyphs of this category
+    pub fn control(self) -> &'static str {
+        match self {
+            ProofCategory::Uppercase | ProofCategory::Punctuation => "H",
+            ProofCategory::Lowercase => "n",
+            ProofCategory::Figures => "0",
+        }
+    }
+}
+
+/// Text for a glyph: its character, or `/name ` if it has no codepoint
+pub fn glyph_text(glyph: &babelfont::Glyph) -> String {
+    glyph
+        .codepoints
+        .first()
+        .and_then(|cp| char::from_u32(*cp))
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| format!("/{} ", glyph.name))
+}
+
+/// Category of a glyph, from its first codepoint
+pub fn glyph_category(glyph: &babelfont::Glyph) -> Option<ProofCategory> {
+    glyph
+        .codepoints
+        .first()
+        .and_then(|cp| char::from_u32(*cp))
+        .and_then(ProofCategory::of_char)
+}
+
+/// Exported glyphs with a codepoint in a category, ordered by codepoint
+fn category_glyphs(font: &babelfont::Font, category: ProofCategory) -> Vec<&babelfont::Glyph> {
+    let mut glyphs: Vec<&babelfont::Glyph> = font
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.exported && glyph_category(glyph) == Some(category))
+        .collect();
+    glyphs.sort_by_key(|glyph| glyph.codepoints.first().copied().unwrap_or(u32::MAX));
+    glyphs
+}
+
+/// Join proof items into lines of at most `max_length` characters
+fn wrap_items(items: &[String], max_length: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && line.chars().count() + 1 + item.chars().count() > max_length {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(item);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Default category pairs for kerning proofs
+const DEFAULT_CATEGORY_PAIRS: &[(ProofCategory, ProofCategory)] = &[
+    (ProofCategory::Uppercase, ProofCategory::Uppercase),
+    (ProofCategory::Uppercase, ProofCategory::Lowercase),
+    (ProofCategory::Lowercase, ProofCategory::Lowercase),
+    (ProofCategory::Figures, ProofCategory::Figures),
+    (ProofCategory::Uppercase, ProofCategory::Punctuation),
+    (ProofCategory::Lowercase, ProofCategory::Punctuation),
+    (ProofCategory::Punctuation, ProofCategory::Uppercase),
+];
+
+/// Generate kerning proof strings for the source font
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `options_json` - Optional JSON options (see `generate_kerning_proof` in lib.rs)
+///
+/// # Returns
+/// * `String` - JSON object with the proof groups
+pub fn generate_kerning_proof(font: &babelfont::Font, options_json: Option<&str>) -> Result<String, JsValue> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: JsonValue = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("proof options", e))?
+    };
+
+    let max_length = options.get("maxLineLength").and_then(|v| v.as_u64()).unwrap_or(64) as usize;
+    let include_kerned = options.get("includeKernedPairs").and_then(|v| v.as_bool()).unwrap_or(true);
+    let master_id = options.get("masterId").and_then(|v| v.as_str());
+
+    let category_pairs: Vec<(ProofCategory, ProofCategory)> = match options.get("categories").and_then(|v| v.as_array()) {
+        Some(pairs) => pairs
+            .iter()
+            .map(|pair| {
+                let names: Vec<&str> = pair.as_array().into_iter().flatten().filter_map(|v| v.as_str()).collect();
+                match names.as_slice() {
+                    [left, right] => match (ProofCategory::from_name(left), ProofCategory::from_name(right)) {
+                        (Some(left), Some(right)) => Ok((left, right)),
+                        _ => Err(ApiError::invalid_argument("category pair", pair)),
+                    },
+                    _ => Err(ApiError::invalid_argument("category pair", pair)),
+                }
+            })
+            .collect::<Result<_, _>>()?,
+        None => DEFAULT_CATEGORY_PAIRS.to_vec(),
+    };
+
+    let mut groups = Vec::new();
+
+    for (left_category, right_category) in category_pairs {
+        let lefts = category_glyphs(font, left_category);
+        let rights = category_glyphs(font, right_category);
+        if lefts.is_empty() || rights.is_empty() {
+            continue;
+        }
+
+        // One line (or more, when wrapped) per left glyph: HLRH per right glyph
+        let mut lines = Vec::new();
+        for left in &lefts {
+            let left_text = glyph_text(left);
+            let items: Vec<String> = rights
+                .iter()
+                .map(|right| {
+                    format!(
+                        "{}{}{}{}",
+                        left_category.control(),
+                        left_text,
+                        glyph_text(right),
+                        right_category.control()
+                    )
+                })
+                .collect();
+            lines.extend(wrap_items(&items, max_length));
+        }
+
+        groups.push(serde_json::json!({
+            "kind": "categories",
+            "title": format!("{} / {}", left_category.name(), right_category.name()),
+            "left": left_category.name(),
+            "right": right_category.name(),
+            "lines": lines,
+        }));
+    }
+
+    if include_kerned {
+        let sides = kerning::kerned_sides(font, master_id)?;
+        let mut items = Vec::new();
+        let mut pairs = Vec::new();
+
+        for (left_side, right_side) in sides {
+            // Groups are represented by their first member
+            let left = kerning::side_members(font, &left_side, true).into_iter().next();
+            let right = kerning::side_members(font, &right_side, false).into_iter().next();
+            let (Some(left), Some(right)) = (left, right) else { continue };
+            let (Some(left_glyph), Some(right_glyph)) = (font.glyphs.get(&left), font.glyphs.get(&right)) else {
+                continue;
+            };
+
+            let left_control = glyph_category(left_glyph).unwrap_or(ProofCategory::Uppercase).control();
+            let right_control = glyph_category(right_glyph).unwrap_or(ProofCategory::Uppercase).control();
+            let text = format!("{}{}{}{}", left_control, glyph_text(left_glyph), glyph_text(right_glyph), right_control);
+
+            pairs.push(serde_json::json!({
+                "left": left_side,
+                "right": right_side,
+                "leftGlyph": left,
+                "rightGlyph": right,
+                "text": text,
+            }));
+            items.push(text);
+        }
+
+        if !pairs.is_empty() {
+            groups.push(serde_json::json!({
+                "kind": "kerned",
+                "title": "Kerned pairs",
+                "lines": wrap_items(&items, max_length),
+                "pairs": pairs,
+            }));
+        }
+    }
+
+    serde_json::to_string(&serde_json::json!({ "groups": groups }))
+        .map_err(|e| ApiError::serialization("kerning proof", e).into())
+}
+
+/// Default sample text of waterfall proofs
+const DEFAULT_WATERFALL_TEXT: &str = "Hamburgefonstiv 0123456789";
+
+/// Default font sizes of waterfall proofs, in output units
+const DEFAULT_WATERFALL_SIZES: &[f64] = &[72.0, 48.0, 36.0, 24.0, 18.0, 14.0, 12.0, 10.0, 9.0, 8.0];
+
+/// Lay out a waterfall: the same text shaped at decreasing sizes
+fn waterfall_layout(font: &babelfont::Font, options: &JsonValue) -> Result<JsonValue, ApiError> {
+    let text = options.get("text").and_then(|v| v.as_str()).unwrap_or(DEFAULT_WATERFALL_TEXT);
+    let width = options.get("width").and_then(|v| v.as_f64()).unwrap_or(f64::INFINITY);
+    let gap = options.get("gap").and_then(|v| v.as_f64()).unwrap_or(0.25);
+    let sizes: Vec<f64> = match options.get("sizes").and_then(|v| v.as_array()) {
+        Some(sizes) => sizes
+            .iter()
+            .map(|size| {
+                size.as_f64()
+                    .filter(|size| *size > 0.0)
+                    .ok_or_else(|| ApiError::invalid_argument("font size", size))
+            })
+            .collect::<Result<_, _>>()?,
+        None => DEFAULT_WATERFALL_SIZES.to_vec(),
+    };
+
+    let font_bytes = crate::shaping::source_shaping_font(font)?;
+    let compiled = read_fonts::FontRef::new(&font_bytes).map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&compiled)?;
+    let mut layout_options = LayoutOptions::from_value(options)?;
+
+    let mut y = 0.0;
+    let mut width_used: f64 = 0.0;
+    let mut steps = Vec::with_capacity(sizes.len());
+    for size in sizes {
+        layout_options.font_size = Some(size);
+        let block = layout_block(&font_bytes, text, width, &layout_options)?;
+        width_used = width_used.max(block.width);
+        steps.push(serde_json::json!({
+            "fontSize": size,
+            "y": y,
+            "block": block.to_json(text, &glyph_order),
+        }));
+        y += block.height + gap * size;
+    }
+
+    Ok(serde_json::json!({
+        "kind": "waterfall",
+        "text": text,
+        "width": width_used,
+        "height": y,
+        "steps": steps,
+    }))
+}
+
+/// Lay out a paginated grid of glyph cells
+fn glyph_set_layout(font: &babelfont::Font, options: &JsonValue) -> Result<JsonValue, ApiError> {
+    let columns = options.get("columns").and_then(|v| v.as_u64()).unwrap_or(12).max(1) as usize;
+    let rows = options.get("rows").and_then(|v| v.as_u64()).unwrap_or(10).max(1) as usize;
+    let exported_only = options.get("exportedOnly").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    let mut glyphs: Vec<&babelfont::Glyph> = match options.get("glyphs").and_then(|v| v.as_array()) {
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                let name = name.as_str().ok_or_else(|| ApiError::invalid_argument("glyph name", name))?;
+                font.glyphs.get(name).ok_or_else(|| ApiError::glyph_not_found(name))
+            })
+            .collect::<Result<_, _>>()?,
+        None => font.glyphs.iter().filter(|glyph| !exported_only || glyph.exported).collect(),
+    };
+    match options.get("sort").and_then(|v| v.as_str()).unwrap_or("glyphOrder") {
+        "glyphOrder" => {}
+        "codepoint" => glyphs.sort_by_key(|glyph| glyph.codepoints.first().copied().unwrap_or(u32::MAX)),
+        other => return Err(ApiError::invalid_argument("sort order", other)),
+    }
+
+    let per_page = columns * rows;
+    let pages: Vec<JsonValue> = glyphs
+        .chunks(per_page)
+        .enumerate()
+        .map(|(page, chunk)| {
+            let cells: Vec<JsonValue> = chunk
+                .iter()
+                .enumerate()
+                .map(|(index, glyph)| {
+                    serde_json::json!({
+                        "index": page * per_page + index,
+                        "row": index / columns,
+                        "column": index % columns,
+                        "name": glyph.name.to_string(),
+                        "codepoints": glyph.codepoints,
+                        "text": glyph_text(glyph),
+                        "category": glyph_category(glyph).map(ProofCategory::name),
+                    })
+                })
+                .collect();
+            serde_json::json!({ "page": page, "cells": cells })
+        })
+        .collect();
+
+    Ok(serde_json::json!({
+        "kind": "glyphSet",
+        "columns": columns,
+        "rows": rows,
+        "glyphCount": glyphs.len(),
+        "pageCount": pages.len(),
+        "pages": pages,
+    }))
+}
+
+/// Generate a proof layout for the source font
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `kind` - "waterfall" or "glyphSet"
+/// * `options_json` - Optional JSON options (see `generate_proof_layout` in lib.rs)
+///
+/// # Returns
+/// * `String` - JSON object with the layout
+pub fn generate_proof_layout(font: &babelfont::Font, kind: &str, options_json: Option<&str>) -> Result<String, JsValue> {
+    let options_json = options_json.map(str::trim).unwrap_or("");
+    let options: JsonValue = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("proof options", e))?
+    };
+
+    let layout = match kind {
+        "waterfall" => waterfall_layout(font, &options),
+        "glyphSet" => glyph_set_layout(font, &options),
+        other => return Err(ApiError::invalid_argument("proof layout kind", other).into()),
+    }?;
+
+    serde_json::to_string(&layout)
+        .map_err(|e| ApiError::serialization("proof layout", e).into())
+}
+
+/// Spacing schemes: name, script, category, straight and round control characters
+const SPACING_SCHEMES: &[(&str, Script, ProofCategory, char, char)] = &[
+    ("latin-uppercase", Script::Latin, ProofCategory::Uppercase, 'H', 'O'),
+    ("latin-lowercase", Script::Latin, ProofCategory::Lowercase, 'n', 'o'),
+    ("cyrillic-uppercase", Script::Cyrillic, ProofCategory::Uppercase, 'Н', 'О'),
+    ("cyrillic-lowercase", Script::Cyrillic, ProofCategory::Lowercase, 'н', 'о'),
+    ("greek-uppercase", Script::Greek, ProofCategory::Uppercase, 'Η', 'Ο'),
+    ("greek-lowercase", Script::Greek, ProofCategory::Lowercase, 'π', 'ο'),
+    ("hebrew", Script::Hebrew, ProofCategory::Lowercase, 'ה', 'ס'),
+    ("figures", Script::Common, ProofCategory::Figures, '1', '0'),
+];
+
+/// Character a glyph stands for: its codepoint, else that of the glyph it is a variant of
+fn glyph_character(font: &babelfont::Font, glyph: &babelfont::Glyph) -> Option<char> {
+    glyph
+        .codepoints
+        .first()
+        .copied()
+        .or_else(|| {
+            let base_name = glyph.name.split('.').next().unwrap_or_default();
+            font.glyphs.get(base_name).and_then(|base| base.codepoints.first().copied())
+        })
+        .and_then(char::from_u32)
+}
+
+/// Spacing schemes suiting a glyph, from the script and category of its character
+///
+/// Letters of scripts without a scheme and glyphs of unknown script use the
+/// Latin schemes; punctuation and symbols are shown between both uppercase
+/// and lowercase controls.
+fn auto_spacing_schemes(character: Option<char>) -> Vec<&'static str> {
+    let category = character.and_then(ProofCategory::of_char);
+    if category == Some(ProofCategory::Figures) {
+        return vec!["figures"];
+    }
+    let script = character.map(|c| c.script()).unwrap_or(Script::Latin);
+    let in_script = |category: ProofCategory| {
+        SPACING_SCHEMES
+            .iter()
+            .find(|scheme| scheme.1 == script && scheme.2 == category)
+            .or_else(|| SPACING_SCHEMES.iter().find(|scheme| scheme.1 == script))
+            .or_else(|| SPACING_SCHEMES.iter().find(|scheme| scheme.1 == Script::Latin && scheme.2 == category))
+            .map(|scheme| scheme.0)
+    };
+    match category {
+        Some(category @ (ProofCategory::Uppercase | ProofCategory::Lowercase)) => in_script(category).into_iter().collect(),
+        _ => {
+            let mut schemes: Vec<&str> =
+                [ProofCategory::Uppercase, ProofCategory::Lowercase].into_iter().filter_map(in_script).collect();
+            schemes.dedup();
+            schemes
+        }
+    }
+}
+
+/// Generate spacing control strings for a glyph
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_name` - Glyph to space
+/// * `scheme` - Scheme name from SPACING_SCHEMES, or "auto"
+///
+/// # Returns
+/// * `String` - JSON object with the strings per scheme (see `get_spacing_strings` in lib.rs)
+pub fn get_spacing_strings(font: &babelfont::Font, glyph_name: &str, scheme: &str) -> Result<String, JsValue> {
+    let glyph = font
+        .glyphs
+        .get(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let character = glyph_character(font, glyph);
+
+    let schemes = if scheme == "auto" {
+        auto_spacing_schemes(character)
+    } else {
+        let known = SPACING_SCHEMES.iter().find(|candidate| candidate.0 == scheme).ok_or_else(|| {
+            let names: Vec<&str> = SPACING_SCHEMES.iter().map(|scheme| scheme.0).collect();
+            ApiError::OperationFailed {
+                message: format!("Unknown spacing scheme '{}'. Expected auto, {}", scheme, names.join(", ")),
+            }
+        })?;
+        vec![known.0]
+    };
+
+    // Text of a control character: its glyph in the font, else the character itself
+    let encoded = |c: char| {
+        font.glyphs
+            .iter()
+            .find(|glyph| glyph.codepoints.contains(&(c as u32)))
+            .map(glyph_text)
+            .unwrap_or_else(|| c.to_string())
+    };
+    let text = glyph_text(glyph);
+    let mut groups = Vec::new();
+    for name in schemes {
+        let Some(&(_, _, _, straight, round)) = SPACING_SCHEMES.iter().find(|scheme| scheme.0 == name) else {
+            continue;
+        };
+        let missing: Vec<String> = [straight, round]
+            .into_iter()
+            .filter(|c| !font.glyphs.iter().any(|glyph| glyph.codepoints.contains(&(*c as u32))))
+            .map(|c| c.to_string())
+            .collect();
+        let (s, r) = (encoded(straight), encoded(round));
+        let x = &text;
+        let strings = vec![
+            format!("{s}{s}{x}{s}{s}"),
+            format!("{r}{r}{x}{r}{r}"),
+            format!("{s}{s}{x}{r}{r}"),
+            format!("{r}{r}{x}{s}{s}"),
+            format!("{s}{s}{x}{s}{r}{s}{r}{x}{r}{r}"),
+        ];
+        groups.push(serde_json::json!({
+            "scheme": name,
+            "controls": [s, r],
+            "strings": strings,
+            "missing": missing,
+        }));
+    }
+
+    let result = serde_json::json!({
+        "glyph": glyph_name,
+        "character": character.map(|c| c.to_string()),
+        "groups": groups,
+    });
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("spacing strings", e).into())
+}
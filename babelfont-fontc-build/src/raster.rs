@@ -0,0 +1,350 @@
+// Rasterization module
+//
+// This module renders glyphs and text runs to anti-aliased bitmaps, for
+// small-size rendering previews and thumbnail sprites. Outlines come from
+// compiled font bytes (drawn with skrifa) or from the cached source font.
+// Coverage is computed with a signed-area accumulation rasterizer.
+
+use crate::errors::ApiError;
+use crate::font_reader::{read_glyph_order, skrifa_location};
+use crate::text_layout::{layout_block, LayoutOptions};
+use crate::text_render::glyph_path;
+use kurbo::{Affine, BezPath, PathEl, Point, Shape as _};
+use skrifa::instance::Size;
+use skrifa::MetadataProvider;
+use wasm_bindgen::prelude::*;
+
+/// Anti-aliased scanline rasterizer with non-zero-like coverage accumulation
+pub struct Rasterizer {
+    width: usize,
+    height: usize,
+    accumulation: Vec<f32>,
+}
+
+impl Rasterizer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Rasterizer {
+            width,
+            height,
+            accumulation: vec![0.0; width * height + 4],
+        }
+    }
+
+    /// Accumulate the signed area covered by a line segment (pixel space, y down)
+    fn draw_line(&mut self, p0: Point, p1: Point) {
+        if (p0.y - p1.y).abs() <= f64::EPSILON {
+            return;
+        }
+        let (direction, p0, p1) = if p0.y < p1.y { (1.0, p0, p1) } else { (-1.0, p1, p0) };
+        let (x0, y0, x1, y1) = (p0.x as f32, p0.y as f32, p1.x as f32, p1.y as f32);
+        let dxdy = (x1 - x0) / (y1 - y0);
+
+        let mut x = x0;
+        if y0 < 0.0 {
+            x -= y0 * dxdy;
+        }
+
+        let row_start = y0.max(0.0) as usize;
+        let row_end = self.height.min(y1.ceil().max(0.0) as usize);
+
+        for row in row_start..row_end {
+            let line_start = row * self.width;
+            let dy = ((row + 1) as f32).min(y1) - (row as f32).max(y0);
+            let x_next = x + dxdy * dy;
+            let d = dy * direction;
+            let (left, right) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let left_floor = left.floor();
+            let left_index = left_floor.max(0.0) as usize;
+            let right_ceil = right.ceil();
+            let right_index = right_ceil.max(0.0) as usize;
+
+            if right_index <= left_index + 1 {
+                // The segment stays within a single pixel column on this row
+                let x_mid = 0.5 * (x + x_next) - left_floor;
+                self.accumulation[line_start + left_index] += d - d * x_mid;
+                self.accumulation[line_start + left_index + 1] += d * x_mid;
+            } else {
+                let slope = (right - left).recip();
+                let left_fraction = left - left_floor;
+                let a0 = 0.5 * slope * (1.0 - left_fraction) * (1.0 - left_fraction);
+                let right_fraction = right - right_ceil + 1.0;
+                let a_end = 0.5 * slope * right_fraction * right_fraction;
+
+                self.accumulation[line_start + left_index] += d * a0;
+                if right_index == left_index + 2 {
+                    self.accumulation[line_start + left_index + 1] += d * (1.0 - a0 - a_end);
+                } else {
+                    let a1 = slope * (1.5 - left_fraction);
+                    self.accumulation[line_start + left_index + 1] += d * (a1 - a0);
+                    for column in left_index + 2..right_index - 1 {
+                        self.accumulation[line_start + column] += d * slope;
+                    }
+                    let a2 = a1 + (right_index - left_index - 3) as f32 * slope;
+                    self.accumulation[line_start + right_index - 1] += d * (1.0 - a2 - a_end);
+                }
+                self.accumulation[line_start + right_index] += d * a_end;
+            }
+
+            x = x_next;
+        }
+    }
+
+    /// Fill a path given in pixel space (y down); open subpaths are closed implicitly
+    pub fn fill(&mut self, path: &BezPath) {
+        let mut start = Point::ZERO;
+        let mut current = Point::ZERO;
+        path.flatten(0.05, |element| match element {
+            PathEl::MoveTo(p) => {
+                if current != start {
+                    self.draw_line(current, start);
+                }
+                start = p;
+                current = p;
+            }
+            PathEl::LineTo(p) => {
+                self.draw_line(current, p);
+                current = p;
+            }
+            PathEl::ClosePath => {
+                if current != start {
+                    self.draw_line(current, start);
+                }
+                current = start;
+            }
+            _ => {}
+        });
+        if current != start {
+            self.draw_line(current, start);
+        }
+    }
+
+    /// Coverage values (0–255), one byte per pixel, rows top to bottom
+    pub fn coverage(&self) -> Vec<u8> {
+        let mut accumulator = 0.0f32;
+        self.accumulation[..self.width * self.height]
+            .iter()
+            .map(|value| {
+                accumulator += value;
+                (accumulator.abs().min(1.0) * 255.0).round() as u8
+            })
+            .collect()
+    }
+}
+
+/// A rasterized bitmap with the position of the drawing origin
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    /// Pixel position of the origin (left edge, baseline) within the bitmap
+    pub origin_x: i64,
+    pub origin_y: i64,
+    pub coverage: Vec<u8>,
+}
+
+/// Rasterize a path given in pixel space (y down), cropped to its ink bounds
+pub fn rasterize_path(path: &BezPath) -> Bitmap {
+    let bounds = path.bounding_box();
+    if path.elements().is_empty() || !bounds.is_finite() {
+        return Bitmap { width: 0, height: 0, origin_x: 0, origin_y: 0, coverage: Vec::new() };
+    }
+
+    // One pixel of padding keeps the anti-aliased edges inside the bitmap
+    let left = bounds.x0.floor() as i64 - 1;
+    let top = bounds.y0.floor() as i64 - 1;
+    let width = (bounds.x1.ceil() as i64 - left + 1).max(1) as usize;
+    let height = (bounds.y1.ceil() as i64 - top + 1).max(1) as usize;
+
+    let mut rasterizer = Rasterizer::new(width, height);
+    rasterizer.fill(&(Affine::translate((-left as f64, -top as f64)) * path.clone()));
+
+    Bitmap {
+        width,
+        height,
+        origin_x: -left,
+        origin_y: -top,
+        coverage: rasterizer.coverage(),
+    }
+}
+
+/// Parse a CSS-style hex color ("#rgb", "#rrggbb") into RGB components
+fn parse_color(color: &str) -> Option<[u8; 3]> {
+    let hex = color.trim().trim_start_matches('#');
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => Some([
+            channel(&hex[0..1].repeat(2))?,
+            channel(&hex[1..2].repeat(2))?,
+            channel(&hex[2..3].repeat(2))?,
+        ]),
+        6 => Some([channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?]),
+        _ => None,
+    }
+}
+
+/// Rasterize a glyph or text run to a bitmap
+///
+/// With compiled font bytes, `glyph_or_text` is drawn as a single glyph if it
+/// is a glyph name in the font, otherwise it is shaped as text (override with
+/// the `mode` option). Without font bytes the glyph is taken from the cached
+/// source font, interpolated at the location.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "width": 14, "height": 19, "originX": 1, "originY": 15,
+///   "advance": 9.6, "format": "gray", "pixels": [0, 0, 37, 255, ...]
+/// }
+/// ```
+///
+/// `originX`/`originY` give the pixel position of the origin (left edge on the
+/// baseline) so bitmaps can be aligned; pixels are rows from top to bottom with
+/// one byte per pixel ("gray") or four ("rgba", straight alpha).
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes, or undefined/empty to use the cached source font
+/// * `glyph_or_text` - Glyph name or text to rasterize
+/// * `ppem` - Size in pixels per em
+/// * `location_json` - Optional JSON object with axis tags and values in USER SPACE, e.g., '{"wght": 550.0}'
+/// * `options_json` - Optional JSON object:
+///   - `mode`: "auto" | "glyph" | "text" (default: "auto")
+///   - `format`: "gray" | "rgba" (default: "gray")
+///   - `color`: string - Hex color for rgba output (default: "#000000")
+///   - shaping options (features, script, language, direction) for text
+///   - `fontId`: number - Handle of the cached source font to use without font bytes (default: the current font)
+///
+/// # Returns
+/// * `String` - JSON object with the bitmap
+#[wasm_bindgen]
+pub fn rasterize(
+    font_bytes: Option<Vec<u8>>,
+    glyph_or_text: &str,
+    ppem: f64,
+    location_json: Option<String>,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    if ppem.is_nan() || ppem <= 0.0 {
+        return Err(ApiError::invalid_argument("ppem", ppem).into());
+    }
+
+    let options_json = options_json.as_deref().map(str::trim).unwrap_or("");
+    let mut options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("raster options", e))?
+    };
+    let mode = options.get("mode").and_then(|v| v.as_str()).unwrap_or("auto").to_string();
+    let format = options.get("format").and_then(|v| v.as_str()).unwrap_or("gray").to_string();
+    let color = options
+        .get("color")
+        .and_then(|v| v.as_str())
+        .map(|c| parse_color(c).ok_or_else(|| ApiError::invalid_argument("color", c)))
+        .transpose()?
+        .unwrap_or([0, 0, 0]);
+    if format != "gray" && format != "rgba" {
+        return Err(ApiError::invalid_argument("format", format).into());
+    }
+
+    let (path, advance) = match font_bytes.filter(|bytes| !bytes.is_empty()) {
+        Some(font_bytes) => {
+            let font = skrifa::FontRef::new(&font_bytes)
+                .map_err(ApiError::invalid_font)?;
+            let location = skrifa_location(&font, location_json.as_deref())?;
+            let outlines = font.outline_glyphs();
+            let upm = font.metrics(Size::unscaled(), &location).units_per_em as f64;
+            let scale = ppem / upm;
+
+            let glyph_order = read_glyph_order(
+                &read_fonts::FontRef::new(&font_bytes)
+                    .map_err(ApiError::invalid_font)?,
+            )?;
+            let glyph_id = glyph_order.iter().position(|name| name == glyph_or_text);
+
+            match (mode.as_str(), glyph_id) {
+                ("glyph", None) => {
+                    return Err(ApiError::glyph_not_found(glyph_or_text).into());
+                }
+                ("glyph", Some(glyph_id)) | ("auto", Some(glyph_id)) => {
+                    let path = glyph_path(&outlines, glyph_id as u32, &location).unwrap_or_default();
+                    let advance = font
+                        .glyph_metrics(Size::unscaled(), &location)
+                        .advance_width(skrifa::GlyphId::new(glyph_id as u32))
+                        .unwrap_or(0.0) as f64;
+                    (Affine::scale_non_uniform(scale, -scale) * path, advance * scale)
+                }
+                ("text", _) | ("auto", None) => {
+                    if let Some(location) = location_json.as_deref().filter(|l| !l.trim().is_empty()) {
+                        options["location"] = serde_json::from_str(location)
+                            .map_err(ApiError::invalid_location)?;
+                    }
+                    options["fontSize"] = ppem.into();
+                    let layout_options = LayoutOptions::from_value(&options)?;
+                    let block = layout_block(&font_bytes, glyph_or_text, f64::INFINITY, &layout_options)
+                        .map_err(ApiError::from)?;
+
+                    // Place every glyph relative to the first line's baseline
+                    let first_baseline = block.lines.first().map_or(0.0, |line| line.baseline);
+                    let mut path = BezPath::new();
+                    for line in &block.lines {
+                        for glyph in &line.glyphs {
+                            if let Some(outline) = glyph_path(&outlines, glyph.glyph_id, &location) {
+                                let transform = Affine::new([
+                                    scale,
+                                    0.0,
+                                    0.0,
+                                    -scale,
+                                    glyph.x,
+                                    line.baseline - first_baseline - glyph.y,
+                                ]);
+                                path.extend(transform * outline);
+                            }
+                        }
+                    }
+                    (path, block.width)
+                }
+                (other, _) => return Err(ApiError::invalid_argument("mode", other).into()),
+            }
+        }
+        None => {
+            if mode == "text" {
+                return Err(ApiError::OperationFailed { message: "Text rasterization requires compiled font bytes".to_string() }.into());
+            }
+            let font_id = options.get("fontId").and_then(|v| v.as_u64()).map(|id| id as u32);
+            let mut fonts = crate::FONT_CACHE.lock().unwrap();
+            let font = fonts.font(font_id)?;
+
+            let location = crate::glyph_outlines::design_location(font, location_json.as_deref().unwrap_or(""))?;
+            let layer = font.interpolate_glyph(glyph_or_text, &location)
+                .map_err(|e| ApiError::interpolation(glyph_or_text, format!("{:?}", e)))?;
+            let shapes = crate::glyph_outlines::flatten_layer(font, &layer, &location)?;
+            let path = crate::glyph_outlines::shapes_to_bezpath(&shapes);
+
+            let scale = ppem / font.upm as f64;
+            (Affine::scale_non_uniform(scale, -scale) * path, layer.width as f64 * scale)
+        }
+    };
+
+    let bitmap = rasterize_path(&path);
+    let pixels: Vec<u8> = if format == "rgba" {
+        bitmap
+            .coverage
+            .iter()
+            .flat_map(|alpha| [color[0], color[1], color[2], *alpha])
+            .collect()
+    } else {
+        bitmap.coverage
+    };
+
+    let result = serde_json::json!({
+        "width": bitmap.width,
+        "height": bitmap.height,
+        "originX": bitmap.origin_x,
+        "originY": bitmap.origin_y,
+        "advance": advance,
+        "format": format,
+        "pixels": pixels,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("bitmap", e).into())
+}
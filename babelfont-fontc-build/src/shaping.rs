@@ -0,0 +1,799 @@
+// Text shaping module (using harfrust)
+//
+// This module shapes text against compiled font bytes so previews reflect the
+// real GSUB/GPOS behavior of the binary. Shaping options (feature settings,
+// script, language, direction, variation location) are passed as JSON.
+//
+// For live feature testing, the cached source font can be compiled without
+// outlines (cmap, metrics, GSUB and GPOS only), or also without kerning for
+// substitution previews; the result is kept until the source font changes.
+
+use crate::errors::ApiError;
+use crate::font_reader::{glyph_name_for, read_glyph_order};
+use babelfont::convertors::fontir::{BabelfontIrSource, CompilationOptions};
+use harfrust::{Direction, Feature, Language, Script, ShaperData, ShaperInstance, UnicodeBuffer, Variation};
+use read_fonts::types::Tag;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+use wasm_bindgen::prelude::*;
+use write_fonts::from_obj::ToOwnedTable;
+use write_fonts::tables::gpos::Gpos;
+use write_fonts::tables::gsub::Gsub;
+use write_fonts::tables::layout::{Feature as LayoutFeature, FeatureList, FeatureVariations};
+use write_fonts::FontBuilder;
+
+// Compiled bytes of the cached source font for shaping (outlines skipped)
+static SOURCE_SHAPING_CACHE: Mutex<Option<Vec<u8>>> = Mutex::new(None);
+
+// Compiled bytes of the cached source font for substitution previews (outlines and kerning skipped)
+static SOURCE_SUBSTITUTION_CACHE: Mutex<Option<Vec<u8>>> = Mutex::new(None);
+
+/// Clear the compiled shaping fonts (call when the source font changes)
+pub fn clear_source_shaping_cache() {
+    let mut cache = SOURCE_SHAPING_CACHE.lock().unwrap();
+    *cache = None;
+    let mut cache = SOURCE_SUBSTITUTION_CACHE.lock().unwrap();
+    *cache = None;
+}
+
+/// Compiled shaping fonts of a source font that is not the cached font
+#[derive(Default)]
+pub struct SourceShapingCaches {
+    shaping: Option<Vec<u8>>,
+    substitution: Option<Vec<u8>>,
+}
+
+/// Take the compiled shaping fonts out, leaving them empty (call when switching fonts)
+pub fn take_source_shaping_caches() -> SourceShapingCaches {
+    SourceShapingCaches {
+        shaping: SOURCE_SHAPING_CACHE.lock().unwrap().take(),
+        substitution: SOURCE_SUBSTITUTION_CACHE.lock().unwrap().take(),
+    }
+}
+
+/// Make compiled fonts taken by take_source_shaping_caches() those of the cached font
+pub fn restore_source_shaping_caches(caches: SourceShapingCaches) {
+    *SOURCE_SHAPING_CACHE.lock().unwrap() = caches.shaping;
+    *SOURCE_SUBSTITUTION_CACHE.lock().unwrap() = caches.substitution;
+}
+
+/// Compiled shaping font for the source font, compiling it on first use
+///
+/// Only tables needed for shaping are built: `glyf`/`gvar` are skipped, so
+/// compilation takes a fraction of a full build on large fonts. Variation
+/// sequences are added so cmap format 14 lookups behave as in the full build.
+///
+/// # Arguments
+/// * `font` - The cached source font
+///
+/// # Returns
+/// * `Result<Vec<u8>, ApiError>` - Compiled font bytes without outlines
+pub fn source_shaping_font(font: &babelfont::Font) -> Result<Vec<u8>, ApiError> {
+    let mut cache = SOURCE_SHAPING_CACHE.lock().unwrap();
+    if let Some(bytes) = cache.as_ref() {
+        return Ok(bytes.clone());
+    }
+
+    let options = CompilationOptions {
+        skip_kerning: false,
+        skip_features: false,
+        skip_metrics: false,
+        skip_outlines: true,
+        dont_use_production_names: false,
+    };
+    let mut font = font.clone();
+    crate::feature_includes::expand_includes(&mut font)?;
+    let sequences = crate::variation_sequences::collect_sequences(&font);
+    let compiled = BabelfontIrSource::compile(font, options)
+        .map_err(|e| ApiError::compilation("Compilation", format!("{:?}", e)))?;
+    let bytes = crate::variation_sequences::add_to_compiled(compiled, &sequences)
+        .map_err(|e| ApiError::compilation("Adding variation sequences", e))?;
+
+    *cache = Some(bytes.clone());
+    Ok(bytes)
+}
+
+/// Compiled font for substitution previews of the source font
+///
+/// Reuses the shaping font if it is compiled already; otherwise compiles
+/// without outlines and kerning, whose GPOS tables are the slowest part of
+/// a layout build and are not needed to apply GSUB.
+///
+/// # Arguments
+/// * `font` - The cached source font
+///
+/// # Returns
+/// * `Result<Vec<u8>, ApiError>` - Compiled font bytes with GSUB and GDEF
+pub fn source_substitution_font(font: &babelfont::Font) -> Result<Vec<u8>, ApiError> {
+    if let Some(bytes) = SOURCE_SHAPING_CACHE.lock().unwrap().as_ref() {
+        return Ok(bytes.clone());
+    }
+    let mut cache = SOURCE_SUBSTITUTION_CACHE.lock().unwrap();
+    if let Some(bytes) = cache.as_ref() {
+        return Ok(bytes.clone());
+    }
+
+    let options = CompilationOptions {
+        skip_kerning: true,
+        skip_features: false,
+        skip_metrics: false,
+        skip_outlines: true,
+        dont_use_production_names: false,
+    };
+    let mut font = font.clone();
+    crate::feature_includes::expand_includes(&mut font)?;
+    let bytes = BabelfontIrSource::compile(font, options)
+        .map_err(|e| ApiError::compilation("Compilation", format!("{:?}", e)))?;
+
+    *cache = Some(bytes.clone());
+    Ok(bytes)
+}
+
+/// Options controlling how text is shaped
+#[derive(Clone, Debug, Default)]
+pub struct ShapingOptions {
+    /// Feature settings, e.g. `ss01=1`, `kern=0`
+    pub features: Vec<Feature>,
+    /// Explicit script; guessed from the text if None
+    pub script: Option<Script>,
+    /// Explicit language; guessed from the environment if None
+    pub language: Option<Language>,
+    /// Explicit direction; guessed from the script if None
+    pub direction: Option<Direction>,
+    /// Variation location in user space
+    pub variations: Vec<Variation>,
+}
+
+impl ShapingOptions {
+    /// Parse shaping options from JSON
+    ///
+    /// ```json
+    /// {
+    ///   "features": { "ss01": 1, "kern": 0, "liga": false },
+    ///   "script": "latn",
+    ///   "language": "nl",
+    ///   "direction": "ltr",
+    ///   "location": { "wght": 700 }
+    /// }
+    /// ```
+    ///
+    /// `features` may also be an array of HarfBuzz-style feature strings
+    /// (`["ss01", "-kern", "aalt=2", "liga[3:5]=0"]`). All keys are optional.
+    pub fn from_json(options_json: Option<&str>) -> Result<Self, ApiError> {
+        let options_json = options_json.map(str::trim).unwrap_or("");
+        if options_json.is_empty() {
+            return Ok(ShapingOptions::default());
+        }
+
+        let options: serde_json::Value = serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("shaping options", e))?;
+        Self::from_value(&options)
+    }
+
+    /// Parse shaping options from an already decoded JSON value
+    pub fn from_value(options: &serde_json::Value) -> Result<Self, ApiError> {
+        let mut parsed = ShapingOptions::default();
+
+        match options.get("features") {
+            Some(serde_json::Value::Object(settings)) => {
+                for (tag, value) in settings {
+                    let value = match value {
+                        serde_json::Value::Bool(enabled) => *enabled as u32,
+                        serde_json::Value::Number(n) => n
+                            .as_u64()
+                            .ok_or_else(|| ApiError::invalid_argument("feature setting", format!("{}={}", tag, n)))?
+                            as u32,
+                        other => {
+                            return Err(ApiError::invalid_argument("feature setting", format!("{}={}", tag, other)))
+                        }
+                    };
+                    parsed.features.push(parse_feature(&format!("{}={}", tag, value))?);
+                }
+            }
+            Some(serde_json::Value::Array(settings)) => {
+                for setting in settings {
+                    let setting = setting
+                        .as_str()
+                        .ok_or_else(|| ApiError::invalid_argument("feature setting", setting))?;
+                    parsed.features.push(parse_feature(setting)?);
+                }
+            }
+            Some(serde_json::Value::Null) | None => {}
+            Some(other) => return Err(ApiError::invalid_argument("feature settings", other)),
+        }
+
+        if let Some(script) = options.get("script").and_then(|v| v.as_str()) {
+            parsed.script = Some(
+                Script::from_str(script).map_err(|_| ApiError::invalid_argument("script tag", script))?,
+            );
+        }
+
+        if let Some(language) = options.get("language").and_then(|v| v.as_str()) {
+            parsed.language = Some(
+                Language::from_str(language).map_err(|_| ApiError::invalid_argument("language tag", language))?,
+            );
+        }
+
+        if let Some(direction) = options.get("direction").and_then(|v| v.as_str()) {
+            parsed.direction = Some(
+                Direction::from_str(direction).map_err(|_| ApiError::invalid_argument("direction", direction))?,
+            );
+        }
+
+        if let Some(location) = options.get("location").and_then(|v| v.as_object()) {
+            for (tag, value) in location {
+                let value = value
+                    .as_f64()
+                    .ok_or_else(|| ApiError::invalid_argument("axis value", format!("{}={}", tag, value)))?;
+                parsed.variations.push(
+                    Variation::from_str(&format!("{}={}", tag, value))
+                        .map_err(|_| ApiError::invalid_argument("axis tag", tag))?,
+                );
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Short name of a direction ("ltr", "rtl", "ttb", "btt")
+pub fn direction_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::LeftToRight => "ltr",
+        Direction::RightToLeft => "rtl",
+        Direction::TopToBottom => "ttb",
+        Direction::BottomToTop => "btt",
+        _ => "invalid",
+    }
+}
+
+/// Whether a direction is horizontal (ltr or rtl)
+pub fn is_horizontal(direction: Direction) -> bool {
+    matches!(direction, Direction::LeftToRight | Direction::RightToLeft)
+}
+
+pub(crate) fn parse_feature(setting: &str) -> Result<Feature, ApiError> {
+    Feature::from_str(setting).map_err(|_| ApiError::invalid_argument("feature setting", setting))
+}
+
+/// A positioned glyph produced by shaping
+#[derive(Clone, Debug)]
+pub struct ShapedGlyph {
+    pub glyph_id: u32,
+    /// Byte offset of the cluster in the input text
+    pub cluster: u32,
+    pub x_advance: i32,
+    pub y_advance: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+}
+
+/// The result of shaping a run of text
+#[derive(Clone, Debug)]
+pub struct ShapedRun {
+    pub glyphs: Vec<ShapedGlyph>,
+    pub direction: Direction,
+    pub script: Script,
+    pub language: Option<Language>,
+}
+
+impl ShapedRun {
+    /// Total advance of the run along its direction
+    ///
+    /// Vertical runs have negative y advances (the pen moves down); their
+    /// length is reported as a positive value.
+    pub fn advance(&self) -> i32 {
+        self.glyphs
+            .iter()
+            .map(|g| if is_horizontal(self.direction) { g.x_advance } else { g.y_advance.abs() })
+            .sum()
+    }
+
+    /// Serialize the run, resolving glyph names from the compiled glyph order
+    pub fn to_json(&self, glyph_order: &[String]) -> serde_json::Value {
+        let glyphs: Vec<serde_json::Value> = self
+            .glyphs
+            .iter()
+            .map(|g| {
+                serde_json::json!({
+                    "glyphId": g.glyph_id,
+                    "name": glyph_name_for(glyph_order, read_fonts::types::GlyphId16::new(g.glyph_id as u16)),
+                    "cluster": g.cluster,
+                    "xAdvance": g.x_advance,
+                    "yAdvance": g.y_advance,
+                    "xOffset": g.x_offset,
+                    "yOffset": g.y_offset,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "direction": direction_name(self.direction),
+            "script": self.script.tag().to_string(),
+            "language": self.language.as_ref().map(|l| l.as_str().to_string()),
+            "advance": self.advance(),
+            "glyphs": glyphs,
+        })
+    }
+}
+
+/// Shape text against compiled font bytes
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to shape
+/// * `options` - Shaping options
+///
+/// # Returns
+/// * `Result<ShapedRun, String>` - The shaped glyphs with the resolved segment properties
+pub fn shape(font_bytes: &[u8], text: &str, options: &ShapingOptions) -> Result<ShapedRun, String> {
+    Ok(shape_each(font_bytes, &[text], options)?.remove(0))
+}
+
+/// Shape several texts against compiled font bytes, parsing the font once
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `texts` - Texts to shape, each as its own buffer
+/// * `options` - Shaping options applied to every text
+///
+/// # Returns
+/// * `Result<Vec<ShapedRun>, String>` - One shaped run per text, in order
+pub fn shape_each(font_bytes: &[u8], texts: &[&str], options: &ShapingOptions) -> Result<Vec<ShapedRun>, String> {
+    let font = harfrust::FontRef::new(font_bytes)
+        .map_err(|e| format!("Failed to parse font: {:?}", e))?;
+
+    let data = ShaperData::new(&font);
+    let instance = (!options.variations.is_empty())
+        .then(|| ShaperInstance::from_variations(&font, options.variations.iter().cloned()));
+    let shaper = data.shaper(&font).instance(instance.as_ref()).build();
+
+    Ok(texts.iter().map(|text| shape_with(&shaper, text, options)).collect())
+}
+
+/// Shape one text with a prepared shaper
+fn shape_with(shaper: &harfrust::Shaper, text: &str, options: &ShapingOptions) -> ShapedRun {
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    if let Some(direction) = options.direction {
+        buffer.set_direction(direction);
+    }
+    if let Some(script) = options.script {
+        buffer.set_script(script);
+    }
+    if let Some(language) = &options.language {
+        buffer.set_language(language.clone());
+    }
+    buffer.guess_segment_properties();
+
+    let direction = buffer.direction();
+    let script = buffer.script();
+    let language = buffer.language();
+
+    let glyph_buffer = shaper.shape(buffer, &options.features);
+    let glyphs = glyph_buffer
+        .glyph_infos()
+        .iter()
+        .zip(glyph_buffer.glyph_positions())
+        .map(|(info, pos)| ShapedGlyph {
+            glyph_id: info.glyph_id,
+            cluster: info.cluster,
+            x_advance: pos.x_advance,
+            y_advance: pos.y_advance,
+            x_offset: pos.x_offset,
+            y_offset: pos.y_offset,
+        })
+        .collect();
+
+    ShapedRun { glyphs, direction, script, language }
+}
+
+/// Shape text against compiled font bytes
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "direction": "ltr",
+///   "script": "Latn",
+///   "language": "en",
+///   "advance": 1832,
+///   "glyphs": [
+///     { "glyphId": 12, "name": "H", "cluster": 0,
+///       "xAdvance": 742, "yAdvance": 0, "xOffset": 0, "yOffset": 0 }
+///   ]
+/// }
+/// ```
+///
+/// Clusters are byte offsets into the UTF-8 text.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to shape
+/// * `options_json` - Optional JSON shaping options, e.g., '{"features": {"ss01": 1, "kern": 0}, "script": "latn", "language": "nl", "direction": "ltr", "location": {"wght": 700}}'
+///
+/// # Returns
+/// * `String` - JSON object with the shaped glyphs and resolved segment properties
+#[wasm_bindgen]
+pub fn shape_text(font_bytes: &[u8], text: &str, options_json: Option<String>) -> Result<String, JsValue> {
+    shape_to_json(font_bytes, text, options_json.as_deref())
+}
+
+/// Shape text against compiled font bytes and serialize the result as shape_text() does
+pub(crate) fn shape_to_json(font_bytes: &[u8], text: &str, options_json: Option<&str>) -> Result<String, JsValue> {
+    let options = ShapingOptions::from_json(options_json)?;
+
+    let run = shape(font_bytes, text, &options).map_err(ApiError::from)?;
+
+    let font = read_fonts::FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+
+    serde_json::to_string(&run.to_json(&glyph_order))
+        .map_err(|e| ApiError::serialization("shaped text", e).into())
+}
+
+/// Lookups referenced by the features of a layout table, in lookup order,
+/// with the tags of the features referencing them
+///
+/// Alternate features of feature variations count as the feature they replace.
+fn referenced_lookups(features: &FeatureList, variations: Option<&FeatureVariations>) -> Vec<(u16, Vec<String>)> {
+    let mut by_lookup: BTreeMap<u16, Vec<String>> = BTreeMap::new();
+    let mut add = |feature: &LayoutFeature, tag: Tag| {
+        let tag = tag.to_string();
+        for index in &feature.lookup_list_indices {
+            let tags = by_lookup.entry(*index).or_default();
+            if !tags.contains(&tag) {
+                tags.push(tag.clone());
+            }
+        }
+    };
+    for record in &features.feature_records {
+        add(&record.feature, record.feature_tag);
+    }
+    for record in variations.iter().flat_map(|variations| &variations.feature_variation_records) {
+        let substitutions = record.feature_table_substitution.as_ref().map(|table| table.substitutions.as_slice());
+        for substitution in substitutions.unwrap_or_default() {
+            if let Some(replaced) = features.feature_records.get(substitution.feature_index as usize) {
+                add(&substitution.alternate_feature, replaced.feature_tag);
+            }
+        }
+    }
+    by_lookup.into_iter().collect()
+}
+
+/// Remove the lookups that are not kept from every feature of a layout table
+///
+/// The lookup list stays as it is, so lookups called from contextual
+/// lookups keep their indices.
+fn keep_lookups(features: &mut FeatureList, variations: Option<&mut FeatureVariations>, keep: &dyn Fn(u16) -> bool) {
+    for record in features.feature_records.iter_mut() {
+        record.feature.lookup_list_indices.retain(|index| keep(*index));
+    }
+    for record in variations.into_iter().flat_map(|variations| variations.feature_variation_records.iter_mut()) {
+        if let Some(table) = record.feature_table_substitution.as_mut() {
+            for substitution in table.substitutions.iter_mut() {
+                substitution.alternate_feature.lookup_list_indices.retain(|index| keep(*index));
+            }
+        }
+    }
+}
+
+/// GSUB table bytes with only the kept lookups enabled by its features
+fn gsub_with_lookups(gsub: &Gsub, keep: &dyn Fn(u16) -> bool) -> Result<Vec<u8>, ApiError> {
+    let mut gsub = gsub.clone();
+    keep_lookups(&mut gsub.feature_list, gsub.feature_variations.as_mut(), keep);
+    write_fonts::dump_table(&gsub).map_err(|e| ApiError::compilation("GSUB", format!("{:?}", e)))
+}
+
+/// GPOS table bytes with only the kept lookups enabled by its features
+fn gpos_with_lookups(gpos: &Gpos, keep: &dyn Fn(u16) -> bool) -> Result<Vec<u8>, ApiError> {
+    let mut gpos = gpos.clone();
+    keep_lookups(&mut gpos.feature_list, gpos.feature_variations.as_mut(), keep);
+    write_fonts::dump_table(&gpos).map_err(|e| ApiError::compilation("GPOS", format!("{:?}", e)))
+}
+
+/// Font bytes with the given GSUB and GPOS tables and the other tables of a font
+fn with_layout_tables(font: &read_fonts::FontRef, gsub: Option<&[u8]>, gpos: Option<&[u8]>) -> Vec<u8> {
+    let mut builder = FontBuilder::new();
+    if let Some(gsub) = gsub {
+        builder.add_raw(Tag::new(b"GSUB"), gsub);
+    }
+    if let Some(gpos) = gpos {
+        builder.add_raw(Tag::new(b"GPOS"), gpos);
+    }
+    builder.copy_missing_tables(font.clone());
+    builder.build()
+}
+
+/// Glyph names of a run joined by spaces, e.g. "f_i n d"
+fn glyph_string(run: &ShapedRun, glyph_order: &[String]) -> String {
+    run.glyphs
+        .iter()
+        .map(|g| glyph_name_for(glyph_order, read_fonts::types::GlyphId16::new(g.glyph_id as u16)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Trace step for one lookup: how the run changed between two passes
+fn trace_step(
+    table: &str,
+    lookup: u16,
+    lookup_type: Option<u16>,
+    features: &[String],
+    previous: &ShapedRun,
+    current: &ShapedRun,
+    glyph_order: &[String],
+) -> serde_json::Value {
+    let substituted = previous.glyphs.len() != current.glyphs.len()
+        || previous
+            .glyphs
+            .iter()
+            .zip(&current.glyphs)
+            .any(|(a, b)| a.glyph_id != b.glyph_id || a.cluster != b.cluster);
+
+    let mut adjustments = Vec::new();
+    if !substituted {
+        for (glyph_index, (a, b)) in previous.glyphs.iter().zip(&current.glyphs).enumerate() {
+            if (a.x_advance, a.y_advance, a.x_offset, a.y_offset) != (b.x_advance, b.y_advance, b.x_offset, b.y_offset) {
+                adjustments.push(serde_json::json!({
+                    "index": glyph_index,
+                    "name": glyph_name_for(glyph_order, read_fonts::types::GlyphId16::new(b.glyph_id as u16)),
+                    "xAdvance": [a.x_advance, b.x_advance],
+                    "yAdvance": [a.y_advance, b.y_advance],
+                    "xOffset": [a.x_offset, b.x_offset],
+                    "yOffset": [a.y_offset, b.y_offset],
+                }));
+            }
+        }
+    }
+
+    serde_json::json!({
+        "table": table,
+        "lookupIndex": lookup,
+        "lookupType": lookup_type,
+        "features": features,
+        "substituted": substituted,
+        "positioned": !adjustments.is_empty(),
+        "before": glyph_string(previous, glyph_order),
+        "after": glyph_string(current, glyph_order),
+        "adjustments": adjustments,
+    })
+}
+
+/// Shape text and trace how each GSUB/GPOS lookup changes the glyph run
+///
+/// harfrust does not report lookup applications, so the trace reshapes the
+/// text once per lookup, like `hb-shape --trace` lists them: the text is
+/// shaped with the features of GSUB and GPOS enabling no lookups, then the
+/// lookups the features reference are enabled one at a time, GSUB before
+/// GPOS and in lookup order (the order the shaper applies them in), and each
+/// pass is compared with the previous one. Lookups only called from
+/// contextual lookups run as part of their caller and have no step of their
+/// own. Feature settings of the options apply to every pass, so a lookup of
+/// a feature that is off never changes the run.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "initial": { ...shape_text() result without lookups... },
+///   "steps": [
+///     { "table": "GSUB", "lookupIndex": 3, "lookupType": 4, "features": ["liga"],
+///       "substituted": true, "positioned": false,
+///       "before": "f i n d", "after": "f_i n d", "adjustments": [] },
+///     { "table": "GPOS", "lookupIndex": 7, "lookupType": 2, "features": ["kern"],
+///       "substituted": false, "positioned": true,
+///       "before": "T o", "after": "T o",
+///       "adjustments": [{ "index": 0, "name": "T",
+///                         "xAdvance": [612, 552], "yAdvance": [0, 0],
+///                         "xOffset": [0, 0], "yOffset": [0, 0] }] }
+///   ],
+///   "final": { ...shape_text() result... }
+/// }
+/// ```
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to shape
+/// * `options_json` - Optional JSON shaping options, as for shape_text()
+///
+/// # Returns
+/// * `String` - JSON object with the per-lookup trace
+#[wasm_bindgen]
+pub fn shape_text_trace(font_bytes: &[u8], text: &str, options_json: Option<String>) -> Result<String, JsValue> {
+    use read_fonts::TableProvider;
+
+    let options = ShapingOptions::from_json(options_json.as_deref())?;
+
+    let font = read_fonts::FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+
+    let read_gsub = font.gsub().ok();
+    let read_gpos = font.gpos().ok();
+    let gsub: Option<Gsub> = read_gsub.as_ref().map(|gsub| gsub.to_owned_table());
+    let gpos: Option<Gpos> = read_gpos.as_ref().map(|gpos| gpos.to_owned_table());
+    let gsub_lookups = gsub
+        .as_ref()
+        .map(|gsub| referenced_lookups(&gsub.feature_list, gsub.feature_variations.as_ref()))
+        .unwrap_or_default();
+    let gpos_lookups = gpos
+        .as_ref()
+        .map(|gpos| referenced_lookups(&gpos.feature_list, gpos.feature_variations.as_ref()))
+        .unwrap_or_default();
+    let gsub_type = |index: u16| {
+        let lookup = read_gsub.as_ref()?.lookup_list().ok()?.lookups().get(index as usize).ok()?;
+        Some(crate::layout_reader::gsub_lookup_type(&lookup.subtables().ok()?))
+    };
+    let gpos_type = |index: u16| {
+        let lookup = read_gpos.as_ref()?.lookup_list().ok()?.lookups().get(index as usize).ok()?;
+        Some(crate::layout_reader::gpos_lookup_type(&lookup.subtables().ok()?))
+    };
+
+    let shape_pass = |gsub_bytes: Option<&[u8]>, gpos_bytes: Option<&[u8]>| -> Result<ShapedRun, JsValue> {
+        let pass_font = with_layout_tables(&font, gsub_bytes, gpos_bytes);
+        shape(&pass_font, text, &options).map_err(|e| ApiError::from(e).into())
+    };
+
+    // GPOS enables no lookups while GSUB lookups are traced, GSUB all of
+    // its lookups while GPOS lookups are traced
+    let no_gpos = gpos.as_ref().map(|gpos| gpos_with_lookups(gpos, &|_| false)).transpose()?;
+    let all_gsub = font.table_data(Tag::new(b"GSUB"));
+    let all_gsub = all_gsub.as_ref().map(|data| data.as_bytes());
+
+    let no_gsub = gsub.as_ref().map(|gsub| gsub_with_lookups(gsub, &|_| false)).transpose()?;
+    let initial = shape_pass(no_gsub.as_deref(), no_gpos.as_deref())?;
+    let mut previous = initial.clone();
+    let mut steps = Vec::with_capacity(gsub_lookups.len() + gpos_lookups.len());
+
+    for (lookup, features) in &gsub_lookups {
+        let Some(gsub) = gsub.as_ref() else { break };
+        let enabled = gsub_with_lookups(gsub, &|index| index <= *lookup)?;
+        let current = shape_pass(Some(enabled.as_slice()), no_gpos.as_deref())?;
+        steps.push(trace_step("GSUB", *lookup, gsub_type(*lookup), features, &previous, &current, &glyph_order));
+        previous = current;
+    }
+    for (lookup, features) in &gpos_lookups {
+        let Some(gpos) = gpos.as_ref() else { break };
+        let enabled = gpos_with_lookups(gpos, &|index| index <= *lookup)?;
+        let current = shape_pass(all_gsub, Some(enabled.as_slice()))?;
+        steps.push(trace_step("GPOS", *lookup, gpos_type(*lookup), features, &previous, &current, &glyph_order));
+        previous = current;
+    }
+
+    let result = serde_json::json!({
+        "initial": initial.to_json(&glyph_order),
+        "steps": steps,
+        "final": previous.to_json(&glyph_order),
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("shaping trace", e).into())
+}
+
+/// Kinds of difference between two shaped runs of the same text
+///
+/// Glyphs are compared by name, so a changed glyph order between the two
+/// builds is not reported. Positions are only compared when the glyph
+/// sequences match.
+fn run_differences(
+    old: &ShapedRun,
+    old_order: &[String],
+    new: &ShapedRun,
+    new_order: &[String],
+) -> (Vec<&'static str>, Option<usize>) {
+    let name = |order: &[String], glyph: &ShapedGlyph| {
+        glyph_name_for(order, read_fonts::types::GlyphId16::new(glyph.glyph_id as u16))
+    };
+
+    let mut kinds = Vec::new();
+    let mut first_difference: Option<usize> = None;
+    let mut note = |kind: &'static str, index: usize, kinds: &mut Vec<&'static str>| {
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+        first_difference = Some(first_difference.map_or(index, |first| first.min(index)));
+    };
+
+    let glyphs_differ = old.glyphs.len() != new.glyphs.len()
+        || old.glyphs.iter().zip(&new.glyphs).any(|(a, b)| name(old_order, a) != name(new_order, b));
+
+    for (index, (a, b)) in old.glyphs.iter().zip(&new.glyphs).enumerate() {
+        if name(old_order, a) != name(new_order, b) {
+            note("glyphs", index, &mut kinds);
+        }
+        if a.cluster != b.cluster {
+            note("clusters", index, &mut kinds);
+        }
+        if !glyphs_differ
+            && (a.x_advance, a.y_advance, a.x_offset, a.y_offset) != (b.x_advance, b.y_advance, b.x_offset, b.y_offset)
+        {
+            note("positions", index, &mut kinds);
+        }
+    }
+    if old.glyphs.len() != new.glyphs.len() {
+        note("glyphs", old.glyphs.len().min(new.glyphs.len()), &mut kinds);
+    }
+
+    (kinds, first_difference)
+}
+
+/// Compare shaping of a word list between two compiled fonts
+///
+/// Shapes every word against both binaries with the same options and reports
+/// the words whose glyph names, clusters or glyph positions differ, e.g. to
+/// check that a refactor of the feature code did not change rendering.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "wordCount": 2,
+///   "changedCount": 1,
+///   "differences": [
+///     { "text": "office", "kinds": ["glyphs", "clusters"], "firstDifference": 1,
+///       "oldGlyphs": "o f_f_i c e", "newGlyphs": "o f f i c e",
+///       "old": { ...shape_text() result... }, "new": { ...shape_text() result... } }
+///   ]
+/// }
+/// ```
+///
+/// # Arguments
+/// * `old_bytes` - Compiled TTF/OTF bytes of the reference build
+/// * `new_bytes` - Compiled TTF/OTF bytes of the build under test
+/// * `wordlist_json` - JSON array of words (or any strings) to shape, e.g. '["office", "Tokyo"]'
+/// * `options_json` - Optional JSON shaping options, as for shape_text()
+///
+/// # Returns
+/// * `String` - JSON object with the differing words
+#[wasm_bindgen]
+pub fn diff_shaping(
+    old_bytes: &[u8],
+    new_bytes: &[u8],
+    wordlist_json: &str,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    let words: Vec<String> = serde_json::from_str(wordlist_json)
+        .map_err(|e| ApiError::invalid_json("word list", e))?;
+    let options = ShapingOptions::from_json(options_json.as_deref())?;
+
+    let texts: Vec<&str> = words.iter().map(String::as_str).collect();
+    let old_runs = shape_each(old_bytes, &texts, &options)
+        .map_err(|e| ApiError::OperationFailed { message: format!("Old font: {}", e) })?;
+    let new_runs = shape_each(new_bytes, &texts, &options)
+        .map_err(|e| ApiError::OperationFailed { message: format!("New font: {}", e) })?;
+
+    let old_font = read_fonts::FontRef::new(old_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let new_font = read_fonts::FontRef::new(new_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let old_order = read_glyph_order(&old_font)?;
+    let new_order = read_glyph_order(&new_font)?;
+
+    let differences: Vec<serde_json::Value> = words
+        .iter()
+        .zip(old_runs.iter().zip(&new_runs))
+        .filter_map(|(word, (old, new))| {
+            let (kinds, first_difference) = run_differences(old, &old_order, new, &new_order);
+            (!kinds.is_empty()).then(|| {
+                serde_json::json!({
+                    "text": word,
+                    "kinds": kinds,
+                    "firstDifference": first_difference,
+                    "oldGlyphs": glyph_string(old, &old_order),
+                    "newGlyphs": glyph_string(new, &new_order),
+                    "old": old.to_json(&old_order),
+                    "new": new.to_json(&new_order),
+                })
+            })
+        })
+        .collect();
+
+    let result = serde_json::json!({
+        "wordCount": words.len(),
+        "changedCount": differences.len(),
+        "differences": differences,
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| ApiError::serialization("shaping diff", e).into())
+}
@@ -0,0 +1,1406 @@
+// Source font QA checks
+//
+// This module checks the cached source font for problems that are hard to
+// spot in the editor and surface late (or not at all) when compiling, such
+// as contours with the wrong winding direction, open contours, stray
+// points, broken component references, accidental component transforms,
+// inconsistent mark anchors and ambiguous Unicode assignments.
+// Checks report findings in the same format as the compiled font
+// validation. Master consistency and designspace checks catch missing
+// layers, axis mappings and locations that would otherwise make fontc fail
+// with cryptic errors. It also measures outline complexity and glyphset
+// coverage of the source.
+
+use babelfont::{Layer, NodeType, Path, Shape};
+use kurbo::{Point, Shape as _};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use unicode_script::{Script, UnicodeScript};
+
+use crate::errors::ApiError;
+use crate::font_validation::{findings_report, Finding};
+use crate::kerning::capitalize;
+use crate::glyph_outlines::path_to_bezpath;
+use crate::metrics_keys::{metrics_key, MetricSide, MetricsKey};
+
+/// Glyphs selected by an optional JSON array of names, in font order
+///
+/// Unknown names are an error; omitting the list selects all glyphs.
+pub(crate) fn selected_glyphs<'a>(
+    font: &'a babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<Vec<&'a babelfont::Glyph>, ApiError> {
+    let Some(json) = glyph_names_json else {
+        return Ok(font.glyphs.iter().collect());
+    };
+    let names: Vec<String> = serde_json::from_str(json)
+        .map_err(|e| ApiError::invalid_json("glyph names", e))?;
+    names
+        .iter()
+        .map(|name| font.glyphs.get(name).ok_or_else(|| ApiError::glyph_not_found(name)))
+        .collect()
+}
+
+/// Display name of a layer: its name, else its ID
+pub(crate) fn layer_label(layer: &Layer, index: usize) -> String {
+    layer
+        .name
+        .clone()
+        .or_else(|| layer.id.clone())
+        .unwrap_or_else(|| format!("layer {}", index))
+}
+
+/// Layers of a glyph that are compiled (backgrounds are skipped)
+fn outline_layers(glyph: &babelfont::Glyph) -> impl Iterator<Item = (usize, &Layer)> {
+    glyph.layers.iter().enumerate().filter(|(_, layer)| !layer.is_background)
+}
+
+// ============================================================================
+// Path directions
+// ============================================================================
+
+/// Whether the outer contours of a font should run clockwise
+///
+/// A font with quadratic curves anywhere is a TrueType-style source with
+/// clockwise outer contours; cubic (PostScript-style) sources run them
+/// counter-clockwise. Deciding this per font keeps straight-line contours
+/// (which have no curves to tell) consistent with the rest of the font.
+pub(crate) fn outer_is_clockwise(font: &babelfont::Font) -> bool {
+    font.glyphs.iter().any(|glyph| {
+        glyph.layers.iter().any(|layer| {
+            layer.shapes.iter().any(|shape| match shape {
+                Shape::Path(path) => path.nodes.iter().any(|node| matches!(node.nodetype, NodeType::QCurve)),
+                Shape::Component(_) => false,
+            })
+        })
+    })
+}
+
+/// Closed contours of a layer whose direction disagrees with their nesting
+///
+/// A contour is an outer contour if it is nested in an even number of other
+/// contours. Contour A is nested in B if B's bounding box contains A's, B is
+/// larger, and A's first point lies inside B; this keeps overlapping (not
+/// nested) contours from counting as counters.
+///
+/// # Arguments
+/// * `layer` - Layer to check
+/// * `outer_clockwise` - Whether outer contours should run clockwise (see `outer_is_clockwise`)
+///
+/// # Returns
+/// * `Vec<(usize, bool, usize)>` - Shape index, whether the contour is clockwise, and its nesting depth
+fn wrong_directions(layer: &Layer, outer_clockwise: bool) -> Vec<(usize, bool, usize)> {
+    let contours: Vec<(usize, &Path, kurbo::BezPath)> = layer
+        .shapes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, shape)| match shape {
+            Shape::Path(path) if path.closed && path.nodes.len() > 1 => {
+                Some((index, path, path_to_bezpath(path)))
+            }
+            _ => None,
+        })
+        .collect();
+    let areas: Vec<f64> = contours.iter().map(|(_, _, bez)| bez.area()).collect();
+    let boxes: Vec<kurbo::Rect> = contours.iter().map(|(_, _, bez)| bez.bounding_box()).collect();
+
+    let mut wrong = Vec::new();
+    for (i, (index, path, _)) in contours.iter().enumerate() {
+        if areas[i] == 0.0 {
+            continue;
+        }
+        let sample = Point::new(path.nodes[0].x, path.nodes[0].y);
+        let depth = contours
+            .iter()
+            .enumerate()
+            .filter(|&(j, (_, _, bez))| {
+                j != i
+                    && areas[j].abs() > areas[i].abs()
+                    && boxes[j].union(boxes[i]) == boxes[j]
+                    && bez.winding(sample) != 0
+            })
+            .count();
+
+        // Positive area is counter-clockwise in the y-up font coordinate system
+        let clockwise = areas[i] < 0.0;
+        let is_outer = depth % 2 == 0;
+        if clockwise != (is_outer == outer_clockwise) {
+            wrong.push((*index, clockwise, depth));
+        }
+    }
+    wrong
+}
+
+/// Reverse the direction of a path, keeping its segments
+///
+/// On-curve nodes store the type of the segment that ends at them, so each
+/// on-curve node takes the type of the next on-curve node before the node
+/// order is reversed. The end node of an open path becomes its move node.
+fn reverse_path(path: &mut Path) {
+    let on_curves: Vec<usize> = path
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| !matches!(node.nodetype, NodeType::OffCurve))
+        .map(|(index, _)| index)
+        .collect();
+    if on_curves.is_empty() {
+        path.nodes.reverse();
+        return;
+    }
+
+    let types: Vec<NodeType> = path.nodes.iter().map(|node| node.nodetype).collect();
+    for (position, &index) in on_curves.iter().enumerate() {
+        let next = match on_curves.get(position + 1) {
+            Some(&next) => Some(next),
+            None if path.closed => Some(on_curves[0]),
+            None => None,
+        };
+        path.nodes[index].nodetype = next.map_or(NodeType::Move, |next| types[next]);
+    }
+    path.nodes.reverse();
+}
+
+/// Report closed contours with the wrong winding direction
+///
+/// Outer contours should run counter-clockwise and counters clockwise in
+/// cubic sources; fonts with quadratic curves follow the TrueType
+/// convention (outer clockwise). Wrong directions fill counters after
+/// overlap removal.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `JsonValue` - Findings report; each finding's context has `glyph`, `layer`, `path` (shape index), `direction` and `depth`
+pub fn check_path_directions(
+    font: &babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let outer_clockwise = outer_is_clockwise(font);
+    let mut findings = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (layer_index, layer) in outline_layers(glyph) {
+            for (path, clockwise, depth) in wrong_directions(layer, outer_clockwise) {
+                let layer = layer_label(layer, layer_index);
+                let kind = if depth % 2 == 0 { "Outer contour" } else { "Counter" };
+                findings.push(Finding::warning(
+                    "path-direction",
+                    format!("{} {} of '{}' ({}) has the wrong direction", kind, path, glyph.name, layer),
+                    serde_json::json!({
+                        "glyph": glyph.name.to_string(),
+                        "layer": layer,
+                        "path": path,
+                        "direction": if clockwise { "clockwise" } else { "counterClockwise" },
+                        "depth": depth,
+                    }),
+                ));
+            }
+        }
+    }
+    Ok(findings_report(&findings))
+}
+
+/// Reverse closed contours with the wrong winding direction in all layers
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `(usize, HashSet<String>)` - Number of reversed contours and names of the changed glyphs
+pub fn correct_path_directions(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<(usize, HashSet<String>), ApiError> {
+    let names: Vec<String> = selected_glyphs(font, glyph_names_json)?
+        .iter()
+        .map(|glyph| glyph.name.to_string())
+        .collect();
+
+    let outer_clockwise = outer_is_clockwise(font);
+    let mut reversed = 0;
+    let mut changed = HashSet::new();
+    for name in names {
+        let Some(glyph) = font.glyphs.get_mut(&name) else { continue };
+        for layer in glyph.layers.iter_mut().filter(|layer| !layer.is_background) {
+            let count = correct_layer_directions(layer, outer_clockwise);
+            if count > 0 {
+                reversed += count;
+                changed.insert(name.clone());
+            }
+        }
+    }
+    Ok((reversed, changed))
+}
+
+/// Reverse the direction of selected or all paths of a layer
+///
+/// Segment types move with the segments, so curves stay curves. Open paths
+/// are reversed too, their last node becoming the start.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_name` - Glyph to edit
+/// * `layer_id` - ID of the layer to edit
+/// * `path_indices` - Shape indices of the paths to reverse; all paths if None
+///
+/// # Returns
+/// * `Vec<usize>` - Shape indices of the reversed paths
+pub fn reverse_contours(
+    font: &mut babelfont::Font,
+    glyph_name: &str,
+    layer_id: &str,
+    path_indices: Option<&[usize]>,
+) -> Result<Vec<usize>, ApiError> {
+    let glyph = font
+        .glyphs
+        .get_mut(glyph_name)
+        .ok_or_else(|| ApiError::glyph_not_found(glyph_name))?;
+    let layer = glyph
+        .layers
+        .iter_mut()
+        .find(|layer| layer.id.as_deref() == Some(layer_id))
+        .ok_or_else(|| ApiError::layer_not_found(glyph_name, layer_id))?;
+
+    let mut indices: Vec<usize> = match path_indices {
+        Some(indices) => indices.to_vec(),
+        None => layer
+            .shapes
+            .iter()
+            .enumerate()
+            .filter(|(_, shape)| matches!(shape, Shape::Path(_)))
+            .map(|(index, _)| index)
+            .collect(),
+    };
+    indices.sort_unstable();
+    indices.dedup();
+    if let Some(&index) = indices.iter().find(|&&index| !matches!(layer.shapes.get(index), Some(Shape::Path(_)))) {
+        return Err(ApiError::invalid_argument("shape index", format!("{} (not a path)", index)));
+    }
+
+    for &index in &indices {
+        if let Some(Shape::Path(path)) = layer.shapes.get_mut(index) {
+            reverse_path(path);
+        }
+    }
+    Ok(indices)
+}
+
+/// Reverse the closed contours of a layer that have the wrong winding direction
+///
+/// # Arguments
+/// * `layer` - Layer to correct
+/// * `outer_clockwise` - Whether outer contours should run clockwise (see `outer_is_clockwise`)
+///
+/// # Returns
+/// * `usize` - Number of reversed contours
+pub(crate) fn correct_layer_directions(layer: &mut Layer, outer_clockwise: bool) -> usize {
+    let mut reversed = 0;
+    for (index, _, _) in wrong_directions(layer, outer_clockwise) {
+        if let Some(Shape::Path(path)) = layer.shapes.get_mut(index) {
+            reverse_path(path);
+            reversed += 1;
+        }
+    }
+    reversed
+}
+
+// ============================================================================
+// Contour integrity
+// ============================================================================
+
+/// Whether two nodes are at the same position
+fn same_position(a: &babelfont::Node, b: &babelfont::Node) -> bool {
+    (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6
+}
+
+/// Node indices of segments that start and end at the same point
+///
+/// A segment is zero-length if its end points and all of its off-curve
+/// points coincide. The index is that of the segment's end node.
+fn zero_length_segments(path: &Path) -> Vec<usize> {
+    let on_curves: Vec<usize> = path
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| !matches!(node.nodetype, NodeType::OffCurve))
+        .map(|(index, _)| index)
+        .collect();
+    if on_curves.len() < 2 {
+        return Vec::new();
+    }
+
+    // Pairs of (start, end) on-curve indices; closed paths wrap around
+    let mut segments: Vec<(usize, usize)> = on_curves.windows(2).map(|pair| (pair[0], pair[1])).collect();
+    if path.closed {
+        segments.push((on_curves[on_curves.len() - 1], on_curves[0]));
+    }
+
+    let node_count = path.nodes.len();
+    segments
+        .into_iter()
+        .filter(|&(start, end)| {
+            let start_node = &path.nodes[start];
+            let mut index = (start + 1) % node_count;
+            while index != end {
+                if !same_position(&path.nodes[index], start_node) {
+                    return false;
+                }
+                index = (index + 1) % node_count;
+            }
+            same_position(start_node, &path.nodes[end])
+        })
+        .map(|(_, end)| end)
+        .collect()
+}
+
+/// Report open contours, stray points and zero-length segments
+///
+/// Checks performed on every non-background layer:
+/// - `open-contour`: paths that are not closed (warning)
+/// - `stray-point`: paths with a single on-curve point, or none (error)
+/// - `zero-length-segment`: segments whose points all coincide (warning)
+///
+/// Open contours are dropped from TrueType output and stray points and
+/// zero-length segments break curve conversion, so these usually surface as
+/// missing outlines or compile failures.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `JsonValue` - Findings report; each finding's context has `glyph`, `layer` and `path` (shape index), plus `node` for zero-length segments
+pub fn check_contours(font: &babelfont::Font, glyph_names_json: Option<&str>) -> Result<serde_json::Value, ApiError> {
+    let mut findings = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (layer_index, layer) in outline_layers(glyph) {
+            let label = layer_label(layer, layer_index);
+            let context = |path: usize| {
+                serde_json::json!({
+                    "glyph": glyph.name.to_string(),
+                    "layer": label,
+                    "path": path,
+                })
+            };
+
+            for (index, shape) in layer.shapes.iter().enumerate() {
+                let Shape::Path(path) = shape else { continue };
+
+                let on_curve_count = path
+                    .nodes
+                    .iter()
+                    .filter(|node| !matches!(node.nodetype, NodeType::OffCurve))
+                    .count();
+                if on_curve_count <= 1 {
+                    findings.push(Finding::error(
+                        "stray-point",
+                        format!("Path {} of '{}' ({}) is a stray point", index, glyph.name, label),
+                        context(index),
+                    ));
+                    continue;
+                }
+
+                if !path.closed {
+                    findings.push(Finding::warning(
+                        "open-contour",
+                        format!("Path {} of '{}' ({}) is not closed", index, glyph.name, label),
+                        context(index),
+                    ));
+                }
+
+                for node in zero_length_segments(path) {
+                    let mut context = context(index);
+                    context["node"] = serde_json::json!(node);
+                    findings.push(Finding::warning(
+                        "zero-length-segment",
+                        format!(
+                            "Path {} of '{}' ({}) has a zero-length segment ending at node {}",
+                            index, glyph.name, label, node
+                        ),
+                        context,
+                    ));
+                }
+            }
+        }
+    }
+    Ok(findings_report(&findings))
+}
+
+// ============================================================================
+// Component references
+// ============================================================================
+
+/// Report components referencing missing glyphs, themselves or a loop
+///
+/// Checks performed:
+/// - `missing-component`: a component references a glyph that does not exist (error)
+/// - `self-reference`: a glyph uses itself as a component (error)
+/// - `component-cycle`: glyphs reference each other in a loop, e.g. a → b → a (error)
+///
+/// All layers are checked, including backgrounds. Each loop is reported
+/// once, with the chain of glyphs in reference order.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+///
+/// # Returns
+/// * `JsonValue` - Findings report; missing and self references have context `glyph`, `layer`, `path` (shape index) and `component`, loops have `glyphs`
+pub fn check_component_references(font: &babelfont::Font) -> serde_json::Value {
+    let mut findings = Vec::new();
+    for glyph in font.glyphs.iter() {
+        for (layer_index, layer) in glyph.layers.iter().enumerate() {
+            for (index, shape) in layer.shapes.iter().enumerate() {
+                let Shape::Component(component) = shape else { continue };
+                let reference = component.reference.to_string();
+                let context = serde_json::json!({
+                    "glyph": glyph.name.to_string(),
+                    "layer": layer_label(layer, layer_index),
+                    "path": index,
+                    "component": reference,
+                });
+
+                if reference == glyph.name.as_str() {
+                    findings.push(Finding::error(
+                        "self-reference",
+                        format!("'{}' uses itself as a component", glyph.name),
+                        context,
+                    ));
+                } else if font.glyphs.get(&reference).is_none() {
+                    findings.push(Finding::error(
+                        "missing-component",
+                        format!(
+                            "Component {} of '{}' ({}) references missing glyph '{}'",
+                            index,
+                            glyph.name,
+                            layer_label(layer, layer_index),
+                            reference
+                        ),
+                        context,
+                    ));
+                }
+            }
+        }
+    }
+
+    // Loops between glyphs (self references are reported above)
+    let graph = crate::glyph_outlines::component_graph(font);
+    for cycle in graph.cycles().into_iter().filter(|cycle| cycle.len() > 1) {
+        let mut chain = cycle.clone();
+        chain.push(cycle[0].clone());
+        findings.push(Finding::error(
+            "component-cycle",
+            format!("Circular component references: {}", chain.join(" → ")),
+            serde_json::json!({ "glyphs": cycle }),
+        ));
+    }
+
+    findings_report(&findings)
+}
+
+// ============================================================================
+// Component transforms
+// ============================================================================
+
+/// Components scaled below this factor on an axis are treated as accidents
+const NEAR_ZERO_SCALE: f64 = 0.05;
+
+/// Why a component transform looks like an accident, if it does
+///
+/// # Returns
+/// * `Option<(&str, String)>` - Reason ("flipped", "near-zero-scale", "large-offset" or "off-grid-offset") and a description
+fn suspicious_transform(transform: &kurbo::Affine, upm: f64) -> Option<(&'static str, String)> {
+    let [a, b, c, d, dx, dy] = transform.as_coeffs();
+    let (scale_x, scale_y) = (a.hypot(b), c.hypot(d));
+
+    if transform.determinant() < 0.0 {
+        return Some(("flipped", "is mirrored (negative scale)".to_string()));
+    }
+    if scale_x < NEAR_ZERO_SCALE || scale_y < NEAR_ZERO_SCALE {
+        return Some(("near-zero-scale", format!("is scaled to {:.3} x {:.3}", scale_x, scale_y)));
+    }
+    if dx.abs() > 2.0 * upm || dy.abs() > 2.0 * upm {
+        return Some(("large-offset", format!("is offset by ({}, {}), more than two em away", dx, dy)));
+    }
+    if (dx - dx.round()).abs() > 1e-3 || (dy - dy.round()).abs() > 1e-3 {
+        return Some(("off-grid-offset", format!("is offset by ({}, {}), off the unit grid", dx, dy)));
+    }
+    None
+}
+
+/// Report components with suspicious transforms
+///
+/// Flags components that are mirrored (negative scale; these interact badly
+/// with overlap removal and hinting), scaled to almost nothing, offset more
+/// than two em or offset by fractional units. All are warnings; use
+/// decompose_flagged_components() to replace them by outlines.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `JsonValue` - Findings report with check "component-transform"; contexts have `glyph`, `layer`, `component` (shape index), `reference`, `reason` and `transform` (6 affine coefficients)
+pub fn check_component_transforms(
+    font: &babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let upm = font.upm as f64;
+    let mut findings = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (layer_index, layer) in outline_layers(glyph) {
+            for (index, shape) in layer.shapes.iter().enumerate() {
+                let Shape::Component(component) = shape else { continue };
+                let Some((reason, description)) = suspicious_transform(&component.transform, upm) else {
+                    continue;
+                };
+                let layer = layer_label(layer, layer_index);
+                findings.push(Finding::warning(
+                    "component-transform",
+                    format!("Component '{}' in '{}' ({}) {}", component.reference, glyph.name, layer, description),
+                    serde_json::json!({
+                        "glyph": glyph.name.to_string(),
+                        "layer": layer,
+                        "component": index,
+                        "reference": component.reference.to_string(),
+                        "reason": reason,
+                        "transform": component.transform.as_coeffs(),
+                    }),
+                ));
+            }
+        }
+    }
+    Ok(findings_report(&findings))
+}
+
+/// Replace components with suspicious transforms by their outlines
+///
+/// Each flagged component is flattened at its layer's location (nested
+/// components included). Contours of mirrored components are reversed so
+/// the decomposed outlines keep the layer's winding direction.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+///
+/// # Returns
+/// * `(usize, HashSet<String>)` - Number of decomposed components and names of the changed glyphs
+pub fn decompose_flagged_components(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+) -> Result<(usize, HashSet<String>), ApiError> {
+    let upm = font.upm as f64;
+
+    // Flatten first, while the font is borrowed immutably:
+    // (glyph, layer index, shape index, decomposed paths)
+    let mut replacements: Vec<(String, usize, usize, Vec<Shape>)> = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (layer_index, layer) in outline_layers(glyph) {
+            for (index, shape) in layer.shapes.iter().enumerate() {
+                let Shape::Component(component) = shape else { continue };
+                if suspicious_transform(&component.transform, upm).is_none() {
+                    continue;
+                }
+                let location = crate::glyph_outlines::layer_location(font, layer).ok_or_else(|| {
+                    format!("Layer '{}' of '{}' has no location", layer_label(layer, layer_index), glyph.name)
+                })?;
+
+                let mut single = layer.clone();
+                single.shapes = vec![shape.clone()];
+                let mut paths = crate::glyph_outlines::flatten_layer(font, &single, &location)?;
+                if component.transform.determinant() < 0.0 {
+                    for path in paths.iter_mut() {
+                        if let Shape::Path(path) = path {
+                            reverse_path(path);
+                        }
+                    }
+                }
+                replacements.push((glyph.name.to_string(), layer_index, index, paths));
+            }
+        }
+    }
+
+    let mut changed = HashSet::new();
+    let count = replacements.len();
+    // Replace from the last shape backwards so earlier indices stay valid
+    for (name, layer_index, index, paths) in replacements.into_iter().rev() {
+        let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(layer_index)) else {
+            continue;
+        };
+        layer.shapes.splice(index..=index, paths);
+        changed.insert(name);
+    }
+    Ok((count, changed))
+}
+
+// ============================================================================
+// Mark anchors
+// ============================================================================
+
+/// Attachment name of a base anchor ("top_1" on ligatures attaches "_top")
+pub(crate) fn attachment_name(anchor: &str) -> &str {
+    match anchor.rsplit_once('_') {
+        Some((name, index)) if !name.is_empty() && index.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => anchor,
+    }
+}
+
+/// Script of a glyph, from its codepoints or those of the glyph it is a variant of
+///
+/// "a.sc" takes the script of "a". Common and inherited characters (such
+/// as combining marks) and unencoded glyphs are Common, which attaches to
+/// every script.
+fn glyph_script(font: &babelfont::Font, glyph: &babelfont::Glyph) -> Script {
+    let script_of = |glyph: &babelfont::Glyph| {
+        glyph
+            .codepoints
+            .iter()
+            .filter_map(|&codepoint| char::from_u32(codepoint))
+            .map(|c| c.script())
+            .find(|script| !matches!(script, Script::Common | Script::Inherited | Script::Unknown))
+    };
+    script_of(glyph)
+        .or_else(|| {
+            let base_name = glyph.name.split('.').next().unwrap_or_default();
+            font.glyphs.get(base_name).and_then(script_of)
+        })
+        .unwrap_or(Script::Common)
+}
+
+/// Master layers of a glyph, with their display names
+fn master_layers<'a>(font: &babelfont::Font, glyph: &'a babelfont::Glyph) -> Vec<(String, &'a Layer)> {
+    glyph
+        .layers
+        .iter()
+        .enumerate()
+        .filter(|(_, layer)| {
+            !layer.is_background && font.masters.iter().any(|master| Some(&master.id) == layer.id.as_ref())
+        })
+        .map(|(index, layer)| (layer_label(layer, index), layer))
+        .collect()
+}
+
+/// Anchors of a glyph across its masters
+struct GlyphAnchors<'a> {
+    glyph: &'a babelfont::Glyph,
+    script: Script,
+    is_mark: bool,
+    /// Attachment names of `_name` anchors
+    mark_anchors: BTreeSet<String>,
+    /// Attachment names of other anchors
+    base_anchors: BTreeSet<String>,
+}
+
+fn compatible_scripts(a: Script, b: Script) -> bool {
+    a == b || a == Script::Common || b == Script::Common
+}
+
+/// Report mark anchors that cannot attach and bases missing expected anchors
+///
+/// Checks performed:
+/// - `unattachable-mark`: a mark's `_name` anchor has no `name` anchor on
+///   any base or mark of a compatible script (error)
+/// - `missing-base-anchor`: a base glyph lacks an anchor that marks of its
+///   script attach to and that at least two thirds of the anchored bases
+///   of the script have (warning)
+/// - `anchor-master-mismatch`: an anchor is present in some masters of a
+///   glyph but not in others (error, breaks interpolation)
+///
+/// Scripts are taken from glyph codepoints, or from the glyph a variant
+/// belongs to ("a.sc" uses "a"); combining marks and unencoded glyphs are
+/// script-neutral. Ligature anchors ("top_1") count as their base name.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+///
+/// # Returns
+/// * `JsonValue` - Findings report; contexts have `glyph` and `anchor`, plus `script` for base anchors and `presentIn`/`missingIn` (master layer names) for mismatches
+pub fn check_anchors(font: &babelfont::Font) -> serde_json::Value {
+    let mut findings = Vec::new();
+    let mut glyphs: Vec<GlyphAnchors> = Vec::new();
+
+    for glyph in font.glyphs.iter() {
+        let layers = master_layers(font, glyph);
+
+        // Presence of each anchor per master
+        let mut presence: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (label, layer) in &layers {
+            for anchor in &layer.anchors {
+                presence.entry(anchor.name.as_str()).or_default().push(label.as_str());
+            }
+        }
+        for (anchor, present_in) in &presence {
+            if present_in.len() < layers.len() {
+                let missing_in: Vec<&str> = layers
+                    .iter()
+                    .map(|(label, _)| label.as_str())
+                    .filter(|label| !present_in.contains(label))
+                    .collect();
+                findings.push(Finding::error(
+                    "anchor-master-mismatch",
+                    format!("Anchor '{}' of '{}' is missing in {}", anchor, glyph.name, missing_in.join(", ")),
+                    serde_json::json!({
+                        "glyph": glyph.name.to_string(),
+                        "anchor": anchor,
+                        "presentIn": present_in,
+                        "missingIn": missing_in,
+                    }),
+                ));
+            }
+        }
+
+        let mut mark_anchors = BTreeSet::new();
+        let mut base_anchors = BTreeSet::new();
+        for name in presence.keys() {
+            match name.strip_prefix('_') {
+                Some(mark) => mark_anchors.insert(mark.to_string()),
+                None => base_anchors.insert(attachment_name(name).to_string()),
+            };
+        }
+        if mark_anchors.is_empty() && base_anchors.is_empty() {
+            continue;
+        }
+        glyphs.push(GlyphAnchors {
+            glyph,
+            script: glyph_script(font, glyph),
+            is_mark: matches!(glyph.category, babelfont::GlyphCategory::Mark) || !mark_anchors.is_empty(),
+            mark_anchors,
+            base_anchors,
+        });
+    }
+
+    // Marks whose anchors have nothing to attach to
+    for mark in glyphs.iter().filter(|glyph| glyph.is_mark) {
+        for anchor in &mark.mark_anchors {
+            let attachable = glyphs.iter().any(|other| {
+                !std::ptr::eq(other.glyph, mark.glyph)
+                    && compatible_scripts(other.script, mark.script)
+                    && other.base_anchors.contains(anchor)
+            });
+            if !attachable {
+                findings.push(Finding::error(
+                    "unattachable-mark",
+                    format!("Mark '{}' has anchor '_{}' but no glyph has a '{}' anchor", mark.glyph.name, anchor, anchor),
+                    serde_json::json!({
+                        "glyph": mark.glyph.name.to_string(),
+                        "anchor": format!("_{}", anchor),
+                        "script": mark.script.full_name(),
+                    }),
+                ));
+            }
+        }
+    }
+
+    // Bases missing anchors that most anchored bases of their script have
+    let scripts: BTreeSet<&str> = glyphs
+        .iter()
+        .filter(|glyph| !glyph.is_mark && glyph.script != Script::Common)
+        .map(|glyph| glyph.script.full_name())
+        .collect();
+    for script_name in scripts {
+        let bases: Vec<&GlyphAnchors> = glyphs
+            .iter()
+            .filter(|glyph| !glyph.is_mark && glyph.script.full_name() == script_name)
+            .collect();
+        let Some(script) = bases.first().map(|base| base.script) else { continue };
+        if bases.len() < 3 {
+            continue;
+        }
+        let used_by_marks: BTreeSet<&String> = glyphs
+            .iter()
+            .filter(|glyph| glyph.is_mark && compatible_scripts(glyph.script, script))
+            .flat_map(|glyph| glyph.mark_anchors.iter())
+            .collect();
+
+        for anchor in used_by_marks {
+            let with_anchor = bases.iter().filter(|base| base.base_anchors.contains(anchor)).count();
+            if with_anchor * 3 < bases.len() * 2 {
+                continue;
+            }
+            for base in bases.iter().filter(|base| !base.base_anchors.contains(anchor)) {
+                findings.push(Finding::warning(
+                    "missing-base-anchor",
+                    format!(
+                        "'{}' has no '{}' anchor ({} of {} {} bases have one)",
+                        base.glyph.name, anchor, with_anchor, bases.len(), script_name
+                    ),
+                    serde_json::json!({
+                        "glyph": base.glyph.name.to_string(),
+                        "anchor": anchor,
+                        "script": script_name,
+                    }),
+                ));
+            }
+        }
+    }
+
+    findings_report(&findings)
+}
+
+// ============================================================================
+// Unicode assignments
+// ============================================================================
+
+/// Check that codepoints are assigned to glyphs unambiguously
+///
+/// Checks performed:
+/// - `duplicate-codepoint`: a codepoint is assigned to several exported
+///   glyphs, so which one the cmap maps it to is arbitrary (error)
+/// - `name-codepoint-mismatch`: a glyph's name implies a codepoint
+///   ("Aacute", "uni0410", "u1F600") that is not among its own (warning)
+///
+/// # Arguments
+/// * `font` - Reference to the font
+///
+/// # Returns
+/// * `JsonValue` - Findings report; contexts have `codepoint` and `glyphs`, or `glyph`, `codepoints` and `implied` (plus `assignedTo` if another glyph has the implied codepoint)
+pub fn check_unicode_assignments(font: &babelfont::Font) -> serde_json::Value {
+    let mut findings = Vec::new();
+
+    let mut owners: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported) {
+        for &codepoint in glyph.codepoints.iter() {
+            let names = owners.entry(codepoint).or_default();
+            if !names.iter().any(|name| *name == glyph.name.as_str()) {
+                names.push(glyph.name.to_string());
+            }
+        }
+    }
+
+    for (codepoint, glyphs) in owners.iter().filter(|(_, glyphs)| glyphs.len() > 1) {
+        findings.push(Finding::error(
+            "duplicate-codepoint",
+            format!("U+{:04X} is assigned to {} glyphs: {}", codepoint, glyphs.len(), glyphs.join(", ")),
+            serde_json::json!({ "codepoint": codepoint, "glyphs": glyphs }),
+        ));
+    }
+
+    for glyph in font.glyphs.iter().filter(|glyph| glyph.exported && !glyph.codepoints.is_empty()) {
+        let Some(implied) = crate::charsets::glyph_name_codepoint(&glyph.name) else {
+            continue;
+        };
+        if glyph.codepoints.contains(&implied) {
+            continue;
+        }
+        let assigned: Vec<String> = glyph.codepoints.iter().map(|codepoint| format!("U+{:04X}", codepoint)).collect();
+        let assigned_to = owners.get(&implied);
+        let mut message = format!(
+            "Glyph '{}' has {} but its name implies U+{:04X}",
+            glyph.name,
+            assigned.join(", "),
+            implied
+        );
+        if let Some(owners) = assigned_to {
+            message.push_str(&format!(" (assigned to {})", owners.join(", ")));
+        }
+        findings.push(Finding::warning(
+            "name-codepoint-mismatch",
+            message,
+            serde_json::json!({
+                "glyph": glyph.name.to_string(),
+                "codepoints": glyph.codepoints,
+                "implied": implied,
+                "assignedTo": assigned_to,
+            }),
+        ));
+    }
+
+    findings_report(&findings)
+}
+
+// ============================================================================
+// Master consistency
+// ============================================================================
+
+/// ID of the master at the default location of every axis
+pub(crate) fn default_master_id(font: &babelfont::Font) -> Option<&str> {
+    let defaults: Vec<(String, f64)> = font
+        .axes
+        .iter()
+        .filter_map(|axis| {
+            let default = axis.default?;
+            let design = axis.userspace_to_designspace(default).ok()?;
+            Some((axis.tag.to_string(), design.to_f64()))
+        })
+        .collect();
+    font.masters
+        .iter()
+        .find(|master| {
+            defaults.iter().all(|(tag, value)| {
+                master
+                    .location
+                    .iter()
+                    .find(|(axis, _)| axis.to_string() == *tag)
+                    .is_none_or(|(_, coord)| (coord.to_f64() - value).abs() < 1e-6)
+            })
+        })
+        .map(|master| master.id.as_str())
+}
+
+/// Whether a glyph is meant to have no outline (spaces, joiners, selectors)
+fn expected_blank(glyph: &babelfont::Glyph) -> bool {
+    if glyph.codepoints.is_empty() {
+        let base_name = glyph.name.split('.').next().unwrap_or_default();
+        return base_name.ends_with("space") || base_name == "CR" || base_name == "NULL";
+    }
+    glyph.codepoints.iter().all(|&codepoint| {
+        char::from_u32(codepoint).is_some_and(char::is_whitespace)
+            || matches!(
+                codepoint,
+                0x00AD | 0x034F | 0x180E | 0x200B..=0x200F | 0x2028..=0x202F | 0x2060..=0x2064 | 0xFE00..=0xFE0F | 0xFEFF
+            )
+    })
+}
+
+/// Check that every glyph is drawn consistently in all masters
+///
+/// Checks performed:
+/// - `missing-master-layer`: a glyph has no layer for a master (error for
+///   the default master, warning for others; the glyph is then sparse and
+///   interpolates from the remaining masters)
+/// - `metrics-key-width`: a layer's width differs from what its width
+///   metrics key (`=H`, `=H+20`, `=H*1.5`, `=600`) gives in the same master,
+///   or the key references a missing glyph (warning)
+/// - `empty-layer`: an exported glyph that is not a space or other blank
+///   character has an empty master layer and exports as a blank glyph
+///   (warning)
+///
+/// Width metrics keys are read from the Glyphs `metricWidth` or
+/// `widthMetricsKey` entries of the layer's or the glyph's format-specific
+/// data.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names to check; omit to check all glyphs
+///
+/// # Returns
+/// * `Result<JsonValue, ApiError>` - Findings report; contexts have `glyph` and `master`
+pub fn check_master_consistency(font: &babelfont::Font, glyph_names_json: Option<&str>) -> Result<serde_json::Value, ApiError> {
+    let glyphs = selected_glyphs(font, glyph_names_json)?;
+    let default_master = default_master_id(font);
+    let master_width = |glyph: &'_ babelfont::Glyph, master_id: &str| -> Option<f64> {
+        glyph
+            .layers
+            .iter()
+            .find(|layer| !layer.is_background && layer.id.as_deref() == Some(master_id))
+            .map(|layer| layer.width as f64)
+    };
+    let mut findings = Vec::new();
+
+    for glyph in glyphs {
+        for master in font.masters.iter() {
+            let Some(layer) = glyph
+                .layers
+                .iter()
+                .find(|layer| !layer.is_background && layer.id.as_ref() == Some(&master.id))
+            else {
+                let message = format!("Glyph '{}' has no layer for master '{}'", glyph.name, master.id);
+                let context = serde_json::json!({ "glyph": glyph.name.to_string(), "master": master.id });
+                if default_master == Some(master.id.as_str()) {
+                    findings.push(Finding::error("missing-master-layer", message, context));
+                } else {
+                    findings.push(Finding::warning("missing-master-layer", message, context));
+                }
+                continue;
+            };
+
+            if let Some(key) = metrics_key(glyph, layer, MetricSide::Width) {
+                if let Some(parsed) = MetricsKey::parse(&key) {
+                    let reference_width = match &parsed {
+                        MetricsKey::Glyph { name, .. } => match font.glyphs.get(name) {
+                            Some(reference) => master_width(reference, &master.id),
+                            None => {
+                                findings.push(Finding::warning(
+                                    "metrics-key-width",
+                                    format!("Width key '{}' of glyph '{}' references missing glyph '{}'", key, glyph.name, name),
+                                    serde_json::json!({ "glyph": glyph.name.to_string(), "master": master.id, "key": key }),
+                                ));
+                                None
+                            }
+                        },
+                        MetricsKey::Fixed(_) => None,
+                    };
+                    if let Some(expected) = parsed.expected(reference_width) {
+                        let width = layer.width as f64;
+                        if (width - expected).abs() > 0.5 {
+                            findings.push(Finding::warning(
+                                "metrics-key-width",
+                                format!(
+                                    "Glyph '{}' is {} wide in master '{}', but its width key '{}' gives {}",
+                                    glyph.name, width, master.id, key, expected
+                                ),
+                                serde_json::json!({
+                                    "glyph": glyph.name.to_string(),
+                                    "master": master.id,
+                                    "key": key,
+                                    "width": width,
+                                    "expected": expected,
+                                }),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            if glyph.exported && layer.shapes.is_empty() && !expected_blank(glyph) {
+                findings.push(Finding::warning(
+                    "empty-layer",
+                    format!("Glyph '{}' is empty in master '{}' and exports as a blank glyph", glyph.name, master.id),
+                    serde_json::json!({ "glyph": glyph.name.to_string(), "master": master.id }),
+                ));
+            }
+        }
+    }
+
+    Ok(findings_report(&findings))
+}
+
+// ============================================================================
+// Designspace
+// ============================================================================
+
+/// Check that a list of mapping values strictly increases
+fn strictly_increasing(values: &[f64]) -> bool {
+    values.windows(2).all(|pair| pair[1] > pair[0])
+}
+
+/// Check axis ranges, user to design mappings, and master and instance locations
+///
+/// Checks performed:
+/// - `axis-range`: an axis lacks min, default or max, or they are not ordered
+///   (error)
+/// - `axis-mapping`: an axis mapping is not monotonic in user or design
+///   space (error), does not map the default (error), or does not cover min
+///   and max (warning; values outside the mapping are extrapolated)
+/// - `location-outside-designspace`: a master or instance location lies
+///   outside an axis' design range or uses an unknown axis (error), or lacks
+///   an axis (warning; the axis default is assumed)
+///
+/// # Arguments
+/// * `font` - Reference to the font
+///
+/// # Returns
+/// * `JsonValue` - Findings report; contexts have `axis`, plus `master` or `instance` (index) for locations
+pub fn check_axis_mappings(font: &babelfont::Font) -> serde_json::Value {
+    let mut findings = Vec::new();
+    // Axis tag -> design space (min, max) for the location checks
+    let mut design_ranges = BTreeMap::new();
+
+    for axis in font.axes.iter() {
+        let tag = axis.tag.to_string();
+        let (Some(min), Some(default), Some(max)) = (axis.min, axis.default, axis.max) else {
+            findings.push(Finding::error(
+                "axis-range",
+                format!("Axis '{}' lacks a minimum, default or maximum", tag),
+                serde_json::json!({ "axis": tag }),
+            ));
+            continue;
+        };
+        let (min, default, max) = (min.to_f64(), default.to_f64(), max.to_f64());
+        if !(min <= default && default <= max) {
+            findings.push(Finding::error(
+                "axis-range",
+                format!("Axis '{}' range is not ordered: min {}, default {}, max {}", tag, min, default, max),
+                serde_json::json!({ "axis": tag, "min": min, "default": default, "max": max }),
+            ));
+            continue;
+        }
+
+        if let Some(map) = axis.map.as_ref().filter(|map| !map.is_empty()) {
+            let mut pairs: Vec<(f64, f64)> = map.iter().map(|(user, design)| (user.to_f64(), design.to_f64())).collect();
+            let user: Vec<f64> = pairs.iter().map(|pair| pair.0).collect();
+            pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let design: Vec<f64> = pairs.iter().map(|pair| pair.1).collect();
+
+            if !strictly_increasing(&user) || !strictly_increasing(&design) {
+                findings.push(Finding::error(
+                    "axis-mapping",
+                    format!("Mapping of axis '{}' is not monotonic: user and design values must both increase", tag),
+                    serde_json::json!({ "axis": tag, "map": pairs }),
+                ));
+                continue;
+            }
+            if !user.contains(&default) {
+                findings.push(Finding::error(
+                    "axis-mapping",
+                    format!("Mapping of axis '{}' does not map the default ({})", tag, default),
+                    serde_json::json!({ "axis": tag, "default": default, "map": pairs }),
+                ));
+            }
+            let uncovered: Vec<f64> = [min, max].into_iter().filter(|value| !user.contains(value)).collect();
+            if !uncovered.is_empty() {
+                findings.push(Finding::warning(
+                    "axis-mapping",
+                    format!("Mapping of axis '{}' does not cover {:?}; these are extrapolated", tag, uncovered),
+                    serde_json::json!({ "axis": tag, "uncovered": uncovered, "map": pairs }),
+                ));
+            }
+        }
+
+        let to_design = |value: f64| {
+            axis.userspace_to_designspace(fontdrasil::coords::UserCoord::new(value))
+                .map(|coord| coord.to_f64())
+                .ok()
+        };
+        if let (Some(design_min), Some(design_max)) = (to_design(min), to_design(max)) {
+            design_ranges.insert(tag, (design_min, design_max));
+        }
+    }
+
+    let locations = font
+        .masters
+        .iter()
+        .map(|master| ("master", master.id.clone(), &master.location))
+        .chain(
+            font.instances
+                .iter()
+                .enumerate()
+                .map(|(index, instance)| ("instance", index.to_string(), &instance.location)),
+        );
+    for (kind, id, location) in locations {
+        let values: BTreeMap<String, f64> = location.iter().map(|(tag, coord)| (tag.to_string(), coord.to_f64())).collect();
+
+        for (tag, value) in &values {
+            let Some(&(design_min, design_max)) = design_ranges.get(tag) else {
+                if !font.axes.iter().any(|axis| axis.tag.to_string() == *tag) {
+                    findings.push(Finding::error(
+                        "location-outside-designspace",
+                        format!("{} '{}' has a location on unknown axis '{}'", capitalize(kind), id, tag),
+                        serde_json::json!({ kind: id, "axis": tag, "value": value }),
+                    ));
+                }
+                continue;
+            };
+            if *value < design_min || *value > design_max {
+                findings.push(Finding::error(
+                    "location-outside-designspace",
+                    format!(
+                        "{} '{}' lies outside axis '{}': {} is not within {}..{} (design space)",
+                        capitalize(kind), id, tag, value, design_min, design_max
+                    ),
+                    serde_json::json!({ kind: id, "axis": tag, "value": value, "min": design_min, "max": design_max }),
+                ));
+            }
+        }
+        for tag in design_ranges.keys().filter(|tag| !values.contains_key(*tag)) {
+            findings.push(Finding::warning(
+                "location-outside-designspace",
+                format!("{} '{}' has no location on axis '{}'; the default is assumed", capitalize(kind), id, tag),
+                serde_json::json!({ kind: id, "axis": tag }),
+            ));
+        }
+    }
+
+    findings_report(&findings)
+}
+
+// ============================================================================
+// Outline statistics
+// ============================================================================
+
+/// Segment counts of a path, by type of the segment's end node
+#[derive(Default)]
+struct SegmentMix {
+    lines: usize,
+    curves: usize,
+    qcurves: usize,
+    /// Off-curve nodes of quadratic segments, which become TrueType points
+    quad_off_curves: usize,
+}
+
+fn segment_mix(path: &Path) -> SegmentMix {
+    let is_on_curve = |node: &babelfont::Node| !matches!(node.nodetype, NodeType::OffCurve);
+    // Leading off-curves of a closed path belong to its last segment, so
+    // start after the last on-curve node
+    let start = if path.closed {
+        path.nodes.iter().rposition(is_on_curve).map_or(0, |last| last + 1)
+    } else {
+        0
+    };
+
+    let mut mix = SegmentMix::default();
+    let mut off_curves = 0;
+    for node in path.nodes[start..].iter().chain(path.nodes[..start].iter()) {
+        match node.nodetype {
+            NodeType::OffCurve => {
+                off_curves += 1;
+                continue;
+            }
+            NodeType::Move => {}
+            NodeType::Line => mix.lines += 1,
+            NodeType::Curve => mix.curves += 1,
+            NodeType::QCurve => {
+                mix.qcurves += 1;
+                mix.quad_off_curves += off_curves;
+            }
+        }
+        off_curves = 0;
+    }
+    mix
+}
+
+/// Estimated compiled size of a glyph in bytes (glyf plus gvar)
+///
+/// Cubic segments are assumed to become two quadratic segments (three
+/// TrueType points); a point costs about 3.5 bytes of flags and coordinates
+/// in glyf, and about 2 bytes of deltas per additional master in gvar.
+fn estimated_glyph_size(contours: usize, points: usize, components: usize, masters: usize) -> usize {
+    let glyf = if contours > 0 {
+        10 + 2 * contours + 2 + points * 7 / 2
+    } else if components > 0 {
+        10 + components * 8
+    } else {
+        0
+    };
+    let delta_points = if contours > 0 { points + 4 } else { components + 4 };
+    let gvar = if masters > 1 { (masters - 1) * (4 + delta_points * 2) } else { 0 };
+    glyf + gvar
+}
+
+/// Per-glyph outline statistics
+///
+/// Counts are taken from the default master layer (or the first layer if
+/// the glyph has none there). Sorting by `estimatedBytes` helps to find
+/// accidentally traced or auto-generated glyphs that bloat the font.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "glyphs": [
+///     { "glyph": "ampersand", "layers": 2, "contours": 3, "nodes": 148,
+///       "onCurveNodes": 52, "offCurveNodes": 96, "components": 0,
+///       "segments": { "line": 4, "curve": 48, "qcurve": 0 },
+///       "estimatedBytes": 1210 }
+///   ],
+///   "totals": { "contours": 1520, "nodes": 30112, "estimatedBytes": 184000 }
+/// }
+/// ```
+/// Glyphs are sorted by estimated size, largest first.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+pub fn outline_stats(font: &babelfont::Font, glyph_names_json: Option<&str>) -> Result<serde_json::Value, ApiError> {
+    let default_master = default_master_id(font);
+    let masters = font.masters.len();
+
+    let mut stats = Vec::new();
+    let (mut total_contours, mut total_nodes, mut total_bytes) = (0, 0, 0);
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        let layers: Vec<&Layer> = outline_layers(glyph).map(|(_, layer)| layer).collect();
+        let Some(layer) = layers
+            .iter()
+            .find(|layer| layer.id.is_some() && layer.id.as_deref() == default_master)
+            .or_else(|| layers.first())
+        else {
+            continue;
+        };
+
+        let (mut contours, mut nodes, mut on_curves, mut components) = (0, 0, 0, 0);
+        let mut mix = SegmentMix::default();
+        for shape in &layer.shapes {
+            match shape {
+                Shape::Path(path) => {
+                    contours += 1;
+                    nodes += path.nodes.len();
+                    on_curves += path.nodes.iter().filter(|node| !matches!(node.nodetype, NodeType::OffCurve)).count();
+                    let path_mix = segment_mix(path);
+                    mix.lines += path_mix.lines;
+                    mix.curves += path_mix.curves;
+                    mix.qcurves += path_mix.qcurves;
+                    mix.quad_off_curves += path_mix.quad_off_curves;
+                }
+                Shape::Component(_) => components += 1,
+            }
+        }
+
+        let points = mix.lines + mix.curves * 3 + mix.qcurves + mix.quad_off_curves;
+        let bytes = estimated_glyph_size(contours, points, components, masters);
+        total_contours += contours;
+        total_nodes += nodes;
+        total_bytes += bytes;
+        stats.push((
+            bytes,
+            serde_json::json!({
+                "glyph": glyph.name.to_string(),
+                "layers": layers.len(),
+                "contours": contours,
+                "nodes": nodes,
+                "onCurveNodes": on_curves,
+                "offCurveNodes": nodes - on_curves,
+                "components": components,
+                "segments": { "line": mix.lines, "curve": mix.curves, "qcurve": mix.qcurves },
+                "estimatedBytes": bytes,
+            }),
+        ));
+    }
+
+    stats.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(serde_json::json!({
+        "glyphs": stats.into_iter().map(|(_, value)| value).collect::<Vec<_>>(),
+        "totals": { "contours": total_contours, "nodes": total_nodes, "estimatedBytes": total_bytes },
+    }))
+}
+
+// ============================================================================
+// Glyphset coverage
+// ============================================================================
+
+/// Coverage of a character set by the exported glyphs of the source font
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "charset": "GF Latin Core",
+///   "total": 331,
+///   "coveredCount": 329,
+///   "missingCount": 2,
+///   "missing": [
+///     { "codepoint": 7838, "hex": "U+1E9E", "char": "ẞ", "suggestedName": "Germandbls", "existingGlyph": null },
+///     { "codepoint": 321, "hex": "U+0141", "char": "Ł", "suggestedName": "Lslash", "existingGlyph": "Lslash" }
+///   ]
+/// }
+/// ```
+///
+/// `existingGlyph` names a glyph with the suggested name that exists but
+/// does not carry the codepoint (or is not exported), which usually only
+/// needs its Unicode value set.
+///
+/// # Arguments
+/// * `font` - Reference to the font
+/// * `charset` - A named character set ("GF Latin Core", "Cyrillic Plus", "GF Greek Core", ...) or a JSON array of codepoints
+pub fn glyphset_coverage(font: &babelfont::Font, charset: &str) -> Result<serde_json::Value, ApiError> {
+    let (charset_name, codepoints) = crate::charsets::resolve_charset(charset)?;
+
+    let encoded: HashSet<u32> = font
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.exported)
+        .flat_map(|glyph| glyph.codepoints.iter().copied())
+        .collect();
+
+    let missing: Vec<serde_json::Value> = codepoints
+        .iter()
+        .filter(|codepoint| !encoded.contains(codepoint))
+        .map(|&codepoint| {
+            let suggested = crate::charsets::suggested_glyph_name(codepoint);
+            let existing = font.glyphs.get(&suggested).map(|glyph| glyph.name.to_string());
+            serde_json::json!({
+                "codepoint": codepoint,
+                "hex": format!("U+{:04X}", codepoint),
+                "char": char::from_u32(codepoint).map(|c| c.to_string()),
+                "suggestedName": suggested,
+                "existingGlyph": existing,
+            })
+        })
+        .collect();
+
+    Ok(serde_json::json!({
+        "charset": charset_name,
+        "total": codepoints.len(),
+        "coveredCount": codepoints.len() - missing.len(),
+        "missingCount": missing.len(),
+        "missing": missing,
+    }))
+}
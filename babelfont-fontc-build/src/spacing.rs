@@ -0,0 +1,701 @@
+// Spacing module
+//
+// This module sets sidebearings of source layers. Automatic spacing measures
+// the ink of each glyph within a vertical zone (such as baseline to x-height,
+// widened by the overshoot) and applies sidebearings from rules: fixed
+// values or those of reference glyphs like "n" for straight and "o" for
+// round sides. The editor's metrics commands (centering, setting
+// sidebearings or widths) work on the same ink bounds. Sidebearings change
+// by moving a layer's contents horizontally and adjusting its advance
+// width; composite glyphs follow the movement of their base component.
+// Sidebearings set in one master can be carried over to the other masters,
+// proportionally, by the same offset or as the same value.
+
+use babelfont::{Layer, Shape};
+use kurbo::{Affine, PathEl, Point};
+use std::collections::{HashMap, HashSet};
+
+use crate::errors::ApiError;
+use crate::glyph_outlines::{flatten_layer, layer_location, path_to_bezpath};
+use crate::path_ops::in_scope;
+use crate::source_checks::{layer_label, selected_glyphs};
+
+/// Flattening accuracy for measuring outlines, in font units
+const MEASURE_ACCURACY: f64 = 0.5;
+
+/// Horizontal distance from the extreme within which ink counts as part of a straight side
+const STRAIGHT_TOLERANCE: f64 = 2.0;
+
+/// Share of the zone height a side must run straight for to count as straight
+const STRAIGHT_SHARE: f64 = 0.25;
+
+/// Key of a layer across glyphs: glyph name and layer ID
+type LayerKey = (String, Option<String>);
+
+/// Move the shapes and anchors of a layer horizontally
+///
+/// Paths move node by node and components by their offset; the first
+/// `skip` shapes are left in place.
+pub(crate) fn shift_layer_contents(layer: &mut Layer, dx: f64, skip: usize) {
+    for shape in layer.shapes.iter_mut().skip(skip) {
+        match shape {
+            Shape::Path(path) => {
+                for node in &mut path.nodes {
+                    node.x += dx;
+                }
+            }
+            Shape::Component(component) => {
+                component.transform = Affine::translate((dx, 0.0)) * component.transform;
+            }
+        }
+    }
+    for anchor in &mut layer.anchors {
+        anchor.x += dx;
+    }
+}
+
+/// Whether a layer is a composite that takes its metrics from its first component
+pub(crate) fn base_component(layer: &Layer) -> Option<String> {
+    match layer.shapes.first() {
+        Some(Shape::Component(component)) => Some(component.reference.to_string()),
+        _ => None,
+    }
+}
+
+/// Outline of a layer (components flattened) as closed polylines
+pub(crate) fn layer_polylines(font: &babelfont::Font, layer: &Layer) -> Result<Vec<Vec<Point>>, String> {
+    let has_components = layer.shapes.iter().any(|shape| matches!(shape, Shape::Component(_)));
+    let flattened;
+    let shapes: &[Shape] = if has_components {
+        let location = layer_location(font, layer).ok_or_else(|| "Layer has no location".to_string())?;
+        flattened = flatten_layer(font, layer, &location).map_err(|e| e.to_string())?;
+        &flattened
+    } else {
+        &layer.shapes
+    };
+
+    Ok(shapes_polylines(shapes))
+}
+
+/// Paths among shapes as closed polylines
+pub(crate) fn shapes_polylines(shapes: &[Shape]) -> Vec<Vec<Point>> {
+    let mut polylines = Vec::new();
+    for shape in shapes {
+        let Shape::Path(path) = shape else { continue };
+        let mut current: Vec<Point> = Vec::new();
+        path_to_bezpath(path).flatten(MEASURE_ACCURACY, |element| match element {
+            PathEl::MoveTo(point) => {
+                if current.len() > 1 {
+                    polylines.push(std::mem::take(&mut current));
+                }
+                current = vec![point];
+            }
+            PathEl::LineTo(point) => current.push(point),
+            PathEl::ClosePath => {
+                if let Some(&first) = current.first() {
+                    current.push(first);
+                }
+            }
+            _ => {}
+        });
+        if current.len() > 1 {
+            polylines.push(current);
+        }
+    }
+    polylines
+}
+
+/// Ink extents of a layer within a vertical zone
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Profile {
+    pub left: f64,
+    pub right: f64,
+    /// Whether the ink runs straight along the left extreme (a stem) rather than touching it (a bowl)
+    pub left_straight: bool,
+    pub right_straight: bool,
+}
+
+/// Measure the ink of polylines between two heights
+pub(crate) fn measure_zone(polylines: &[Vec<Point>], bottom: f64, top: f64) -> Option<Profile> {
+    // Clip every segment to the zone
+    let mut clipped: Vec<(Point, Point)> = Vec::new();
+    for polyline in polylines {
+        for pair in polyline.windows(2) {
+            let (mut a, mut b) = (pair[0], pair[1]);
+            if a.y > b.y {
+                std::mem::swap(&mut a, &mut b);
+            }
+            if b.y < bottom || a.y > top {
+                continue;
+            }
+            let at = |y: f64| {
+                if (b.y - a.y).abs() < 1e-9 {
+                    Point::new(a.x, y)
+                } else {
+                    a.lerp(b, (y - a.y) / (b.y - a.y))
+                }
+            };
+            let start = if a.y < bottom { at(bottom) } else { a };
+            let end = if b.y > top { at(top) } else { b };
+            clipped.push((start, end));
+        }
+    }
+    if clipped.is_empty() {
+        return None;
+    }
+
+    let left = clipped.iter().map(|(a, b)| a.x.min(b.x)).fold(f64::INFINITY, f64::min);
+    let right = clipped.iter().map(|(a, b)| a.x.max(b.x)).fold(f64::NEG_INFINITY, f64::max);
+    // Height of the measured ink, as the zone may be unbounded
+    let ink_bottom = clipped.iter().map(|(a, _)| a.y).fold(f64::INFINITY, f64::min);
+    let ink_top = clipped.iter().map(|(_, b)| b.y).fold(f64::NEG_INFINITY, f64::max);
+    let straight_run = |extreme: f64| {
+        let run: f64 = clipped
+            .iter()
+            .filter(|(a, b)| {
+                (a.x - extreme).abs() <= STRAIGHT_TOLERANCE && (b.x - extreme).abs() <= STRAIGHT_TOLERANCE
+            })
+            .map(|(a, b)| b.y - a.y)
+            .sum();
+        run >= (ink_top - ink_bottom) * STRAIGHT_SHARE
+    };
+    Some(Profile {
+        left,
+        right,
+        left_straight: straight_run(left),
+        right_straight: straight_run(right),
+    })
+}
+
+/// Spacing category of a glyph from its codepoint, or that of the glyph it is a variant of
+pub(crate) fn spacing_category(font: &babelfont::Font, glyph: &babelfont::Glyph) -> &'static str {
+    let character = glyph.codepoints.first().copied().or_else(|| {
+        let base_name = glyph.name.split('.').next().unwrap_or_default();
+        font.glyphs.get(base_name).and_then(|base| base.codepoints.first().copied())
+    });
+    match character.and_then(char::from_u32) {
+        Some(c) if c.is_uppercase() => "uppercase",
+        Some(c) if c.is_lowercase() => "lowercase",
+        Some(c) if c.is_numeric() => "figures",
+        Some(c) if !c.is_alphanumeric() && !c.is_whitespace() => "punctuation",
+        _ => "other",
+    }
+}
+
+/// Vertical zone to measure in for a master: bottom and top
+fn zone_bounds(
+    font: &babelfont::Font,
+    layer: &Layer,
+    zone: &str,
+    overshoot: f64,
+) -> Result<(f64, f64), ApiError> {
+    let master = font
+        .masters
+        .iter()
+        .find(|master| Some(&master.id) == layer.id.as_ref())
+        .or_else(|| font.masters.first());
+    let metric = |metric: babelfont::MetricType| {
+        master.and_then(|master| master.metrics.get(&metric)).map(|&value| value as f64)
+    };
+    let top = match zone {
+        "full" => return Ok((f64::NEG_INFINITY, f64::INFINITY)),
+        "xHeight" => metric(babelfont::MetricType::XHeight),
+        "capHeight" => metric(babelfont::MetricType::CapHeight),
+        other => return Err(ApiError::invalid_argument("spacing zone", other)),
+    }
+    .ok_or_else(|| {
+        let master = master.map_or("", |master| master.id.as_str());
+        format!("Master '{}' has no {} metric", master, zone)
+    })?;
+    Ok((-overshoot, top + overshoot))
+}
+
+/// Spacing rules, resolved per glyph
+struct SpacingRules<'a> {
+    rules: &'a serde_json::Value,
+}
+
+impl<'a> SpacingRules<'a> {
+    /// The rule objects that apply to a glyph, most specific first
+    fn scopes(&self, glyph: &str, category: &str) -> [Option<&'a serde_json::Value>; 3] {
+        [
+            self.rules.get("glyphs").and_then(|glyphs| glyphs.get(glyph)),
+            self.rules.get("categories").and_then(|categories| categories.get(category)),
+            Some(self.rules),
+        ]
+    }
+
+    /// Rule value for a side: explicit "left"/"right" first, then "straight"/"round"
+    fn side(&self, glyph: &str, category: &str, side: &str, straight: bool) -> Option<&'a serde_json::Value> {
+        let shape = if straight { "straight" } else { "round" };
+        let scopes = self.scopes(glyph, category);
+        scopes
+            .iter()
+            .flatten()
+            .find_map(|scope| scope.get(side))
+            .or_else(|| scopes.iter().flatten().find_map(|scope| scope.get(shape)))
+    }
+
+    fn zone(&self, glyph: &str, category: &str) -> &'a str {
+        self.scopes(glyph, category)
+            .iter()
+            .flatten()
+            .find_map(|scope| scope.get("zone").and_then(|zone| zone.as_str()))
+            .unwrap_or("full")
+    }
+}
+
+/// Set sidebearings from ink bounds according to spacing rules
+///
+/// Each selected glyph's layers are measured within the rule's zone and
+/// get the rule's sidebearings: a number, or the name of a reference glyph
+/// whose sidebearing on the same side (in the same layer, measured in the
+/// same zone, before spacing) is copied. Rules can depend on whether a side
+/// is straight or round. Values are rounded to whole units. Composites
+/// (layers starting with a component) are not measured; they move with
+/// their base component and change width by as much as their base, in the
+/// whole font.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `rules_json` - JSON spacing rules (see `auto_space` in lib.rs)
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 8, "glyphs": ["A", "Aacute", "n"], "skipped": [{"glyph": "space", "reason": "..."}]}' and the changed glyph names
+pub fn auto_space(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    rules_json: &str,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let rules: serde_json::Value =
+        serde_json::from_str(rules_json).map_err(|e| ApiError::invalid_json("spacing rules", e))?;
+    let rules = SpacingRules { rules: &rules };
+    let overshoot = rules.rules.get("overshoot").and_then(|v| v.as_f64()).unwrap_or(0.0);
+
+    // Sidebearings of a reference glyph's layer within a zone
+    let mut reference_profiles: HashMap<(String, Option<String>, String), Option<Profile>> = HashMap::new();
+    let mut reference_profile = |name: &str, layer: &Layer, zone: &str| -> Result<Option<(Profile, f64)>, ApiError> {
+        let key = (name.to_string(), layer.id.clone(), zone.to_string());
+        let reference_layer = font
+            .glyphs
+            .get(name)
+            .ok_or_else(|| ApiError::glyph_not_found(name))?
+            .layers
+            .iter()
+            .find(|candidate| !candidate.is_background && candidate.id == layer.id);
+        let Some(reference_layer) = reference_layer else { return Ok(None) };
+        if !reference_profiles.contains_key(&key) {
+            let (bottom, top) = zone_bounds(font, reference_layer, zone, overshoot)?;
+            let profile = measure_zone(&layer_polylines(font, reference_layer)?, bottom, top);
+            reference_profiles.insert(key.clone(), profile);
+        }
+        Ok(reference_profiles[&key].map(|profile| (profile, reference_layer.width as f64)))
+    };
+
+    let mut updates: Vec<(String, usize, f64, f32)> = Vec::new();
+    let mut skipped = Vec::new();
+    'glyphs: for glyph in selected_glyphs(font, glyph_names_json)? {
+        let name = glyph.name.to_string();
+        if matches!(glyph.category, babelfont::GlyphCategory::Mark) {
+            skipped.push(serde_json::json!({ "glyph": name, "reason": "Marks are not spaced" }));
+            continue;
+        }
+        let category = spacing_category(font, glyph);
+        let zone = rules.zone(&name, category);
+
+        let mut glyph_updates = Vec::new();
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if layer.is_background {
+                continue;
+            }
+            if let Some(base) = base_component(layer) {
+                let reason = format!("Composite follows its base '{}'", base);
+                skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+                continue 'glyphs;
+            }
+            let label = layer_label(layer, index);
+            let context = |e: String| format!("'{}' ({}): {}", name, label, e);
+            let (bottom, top) = zone_bounds(font, layer, zone, overshoot)?;
+            let polylines = layer_polylines(font, layer).map_err(context)?;
+            let Some(profile) = measure_zone(&polylines, bottom, top) else {
+                let reason = format!("No ink in the {} zone of {}", zone, label);
+                skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+                continue 'glyphs;
+            };
+
+            let mut sidebearing = |side: &str, straight: bool| -> Result<f64, ApiError> {
+                let value = rules
+                    .side(&name, category, side, straight)
+                    .ok_or_else(|| {
+                        ApiError::invalid_json("spacing rules", format!("No rule for the {} side of '{}'", side, name))
+                    })?;
+                if let Some(units) = value.as_f64() {
+                    return Ok(units);
+                }
+                let reference = value
+                    .as_str()
+                    .ok_or_else(|| ApiError::invalid_argument("spacing rule", value))?;
+                let (profile, width) = reference_profile(reference, layer, zone)?
+                    .ok_or_else(|| format!("Reference glyph '{}' has no ink in {}", reference, label))?;
+                Ok(if side == "left" { profile.left } else { width - profile.right })
+            };
+            let left = sidebearing("left", profile.left_straight)?.round();
+            let right = sidebearing("right", profile.right_straight)?.round();
+
+            let dx = left - profile.left.round();
+            let width = (profile.right.round() + dx + right).max(0.0);
+            glyph_updates.push((name.clone(), index, dx, width as f32));
+        }
+        updates.extend(glyph_updates);
+    }
+
+    // Apply to the measured glyphs; composites follow their bases
+    let (layer_count, changed) = apply_layer_updates(font, updates);
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "skipped": skipped,
+    });
+    Ok((result, changed))
+}
+
+/// Shift layers and set their widths, then move composites along
+///
+/// `updates` holds the glyph name, layer index, horizontal shift and new
+/// width of each layer. Returns the number of layers changed (including
+/// composites following their base) and the changed glyph names.
+fn apply_layer_updates(
+    font: &mut babelfont::Font,
+    updates: Vec<(String, usize, f64, f32)>,
+) -> (usize, HashSet<String>) {
+    let mut moved: HashMap<LayerKey, (f64, f32)> = HashMap::new();
+    let mut changed = HashSet::new();
+    let mut layer_count = 0;
+    for (name, index, dx, width) in updates {
+        let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) else {
+            continue;
+        };
+        let dw = width - layer.width;
+        if dx == 0.0 && dw == 0.0 {
+            continue;
+        }
+        shift_layer_contents(layer, dx, 0);
+        layer.width = width;
+        moved.insert((name.clone(), layer.id.clone()), (dx, dw));
+        changed.insert(name);
+        layer_count += 1;
+    }
+    layer_count += follow_base_metrics(font, &mut moved, &mut changed);
+    (layer_count, changed)
+}
+
+/// Move composites along with their base components
+///
+/// `moved` maps layers to their horizontal shift and width change. Every
+/// composite layer whose base moved shifts its other shapes and anchors by
+/// the same amount and changes its width by as much as the base; nested
+/// composites follow in turn. Returns the number of composite layers
+/// changed, which are added to `moved` and `changed`.
+pub(crate) fn follow_base_metrics(
+    font: &mut babelfont::Font,
+    moved: &mut HashMap<LayerKey, (f64, f32)>,
+    changed: &mut HashSet<String>,
+) -> usize {
+    let mut layer_count = 0;
+    loop {
+        let mut pending: Vec<(String, usize, f64, f32)> = Vec::new();
+        for glyph in font.glyphs.iter() {
+            for (index, layer) in glyph.layers.iter().enumerate() {
+                let key = (glyph.name.to_string(), layer.id.clone());
+                if layer.is_background || moved.contains_key(&key) {
+                    continue;
+                }
+                let Some(base) = base_component(layer) else { continue };
+                if let Some(&(dx, dw)) = moved.get(&(base, layer.id.clone())) {
+                    pending.push((key.0, index, dx, dw));
+                }
+            }
+        }
+        if pending.is_empty() {
+            return layer_count;
+        }
+        for (name, index, dx, dw) in pending {
+            let Some(layer) = font.glyphs.get_mut(&name).and_then(|glyph| glyph.layers.get_mut(index)) else {
+                continue;
+            };
+            shift_layer_contents(layer, dx, 1);
+            layer.width += dw;
+            moved.insert((name.clone(), layer.id.clone()), (dx, dw));
+            changed.insert(name);
+            layer_count += 1;
+        }
+    }
+}
+
+/// How set_width() distributes a width change
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WidthAlignment {
+    /// Keep the left sidebearing
+    Left,
+    /// Split the change between both sidebearings
+    Center,
+    /// Keep the right sidebearing
+    Right,
+}
+
+impl WidthAlignment {
+    /// Parse an alignment name: "left", "center" or "right"
+    pub fn from_name(name: &str) -> Result<Self, ApiError> {
+        match name {
+            "left" => Ok(WidthAlignment::Left),
+            "center" => Ok(WidthAlignment::Center),
+            "right" => Ok(WidthAlignment::Right),
+            other => Err(ApiError::invalid_argument("alignment", other)),
+        }
+    }
+}
+
+/// A metrics command of the editor
+#[derive(Clone, Copy, Debug)]
+pub enum MetricsOp {
+    /// Equalize the sidebearings, keeping the width
+    Center,
+    /// Set either or both sidebearings
+    SetSidebearings { left: Option<f64>, right: Option<f64> },
+    /// Set the advance width
+    SetWidth { width: f64, alignment: WidthAlignment },
+}
+
+impl MetricsOp {
+    /// Horizontal shift and new width of a layer, from its ink extents (None without ink)
+    ///
+    /// Returns None if the command needs ink and the layer has none.
+    fn apply(&self, ink: Option<(f64, f64)>, width: f64) -> Option<(f64, f64)> {
+        match *self {
+            MetricsOp::Center => {
+                let (left, right) = ink?;
+                Some((((width - (right - left)) / 2.0 - left).round(), width))
+            }
+            MetricsOp::SetSidebearings { left: new_left, right: new_right } => {
+                let (left, right) = ink?;
+                let dx = new_left.map_or(0.0, |new_left| new_left.round() - left.round());
+                let new_width = match new_right {
+                    Some(new_right) => right.round() + dx + new_right.round(),
+                    None => width + dx,
+                };
+                Some((dx, new_width))
+            }
+            MetricsOp::SetWidth { width: new_width, alignment } => {
+                let change = new_width.round() - width;
+                let dx = match alignment {
+                    WidthAlignment::Left => 0.0,
+                    WidthAlignment::Center => (change / 2.0).round(),
+                    WidthAlignment::Right => change,
+                };
+                Some((dx, new_width.round()))
+            }
+        }
+    }
+
+    fn needs_ink(&self) -> bool {
+        !matches!(self, MetricsOp::SetWidth { .. })
+    }
+}
+
+/// Apply a metrics command to the layers of glyphs
+///
+/// Ink bounds include components. Composites in the font that use a changed
+/// glyph as their base component move along with it.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `layer_scope` - "all", "masters" or a layer/master ID
+/// * `op` - The command
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"layers": 2, "glyphs": ["A", "Aacute"], "skipped": [{"glyph": "space", "reason": "..."}]}' and the changed glyph names
+pub fn apply_metrics(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    layer_scope: &str,
+    op: MetricsOp,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    let mut updates: Vec<(String, usize, f64, f32)> = Vec::new();
+    let mut skipped = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            if !in_scope(font, layer, layer_scope) {
+                continue;
+            }
+            let ink = if op.needs_ink() {
+                let polylines = layer_polylines(font, layer)
+                    .map_err(|e| format!("'{}' ({}): {}", glyph.name, layer_label(layer, index), e))?;
+                measure_zone(&polylines, f64::NEG_INFINITY, f64::INFINITY).map(|profile| (profile.left, profile.right))
+            } else {
+                None
+            };
+            match op.apply(ink, layer.width as f64) {
+                Some((dx, width)) => updates.push((glyph.name.to_string(), index, dx, width.max(0.0) as f32)),
+                None => skipped.push(serde_json::json!({
+                    "glyph": glyph.name.to_string(),
+                    "reason": format!("No outlines in {}", layer_label(layer, index)),
+                })),
+            }
+        }
+    }
+
+    let (layer_count, changed) = apply_layer_updates(font, updates);
+
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "skipped": skipped,
+    });
+    Ok((result, changed))
+}
+
+/// How an edit in one master carries over to the other masters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Propagation {
+    /// Only the edited master changes
+    None,
+    /// Other masters change by the same factor as the edited one
+    Proportional,
+    /// Other masters change by the same amount in font units
+    Offset,
+    /// Other masters take the edited master's new value
+    Equal,
+}
+
+impl Propagation {
+    /// Parse a propagation name: "none", "proportional", "offset" or "equal"
+    pub fn from_name(name: &str) -> Result<Self, ApiError> {
+        match name {
+            "none" => Ok(Propagation::None),
+            "proportional" => Ok(Propagation::Proportional),
+            "offset" => Ok(Propagation::Offset),
+            "equal" => Ok(Propagation::Equal),
+            other => Err(ApiError::invalid_argument("propagation", other)),
+        }
+    }
+
+    /// New value of another master, from the edited master's old and new values
+    ///
+    /// Returns None if the other master is left alone. A proportional edit
+    /// of a value near zero has no factor, so it carries over as an offset.
+    pub(crate) fn propagate(&self, old: f64, new: f64, other: f64) -> Option<f64> {
+        match self {
+            Propagation::None => None,
+            Propagation::Proportional if old.abs() >= 1.0 => Some(other * new / old),
+            Propagation::Proportional | Propagation::Offset => Some(other + new - old),
+            Propagation::Equal => Some(new),
+        }
+    }
+}
+
+/// Set sidebearings in one master and carry the change over to the others
+///
+/// The sidebearings of the glyphs' layers for `master_id` are set like
+/// set_sidebearings() does; the master layers of the other masters get
+/// sidebearings derived from the change by `propagation`. Omitted sides
+/// keep their value in all masters. Composites using a changed glyph as
+/// their base follow it.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+/// * `glyph_names_json` - Optional JSON array of glyph names; all glyphs if None
+/// * `master_id` - Master the sidebearings are given for
+/// * `left` - Left sidebearing in font units, or None to keep it
+/// * `right` - Right sidebearing in font units, or None to keep it
+/// * `propagation` - How the other masters follow
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result as for apply_metrics() and the changed glyph names
+pub fn set_linked_sidebearings(
+    font: &mut babelfont::Font,
+    glyph_names_json: Option<&str>,
+    master_id: &str,
+    left: Option<f64>,
+    right: Option<f64>,
+    propagation: Propagation,
+) -> Result<(serde_json::Value, HashSet<String>), ApiError> {
+    if !font.masters.iter().any(|master| master.id == master_id) {
+        return Err(ApiError::master_not_found(master_id));
+    }
+
+    let mut updates: Vec<(String, usize, f64, f32)> = Vec::new();
+    let mut skipped = Vec::new();
+    for glyph in selected_glyphs(font, glyph_names_json)? {
+        let name = glyph.name.to_string();
+        let mut master_layers = Vec::new();
+        for (index, layer) in glyph.layers.iter().enumerate() {
+            let is_master = !layer.is_background
+                && font.masters.iter().any(|master| Some(&master.id) == layer.id.as_ref());
+            if !is_master {
+                continue;
+            }
+            let polylines = layer_polylines(font, layer)
+                .map_err(|e| format!("'{}' ({}): {}", name, layer_label(layer, index), e))?;
+            let ink = measure_zone(&polylines, f64::NEG_INFINITY, f64::INFINITY).map(|profile| (profile.left, profile.right));
+            master_layers.push((index, layer, ink));
+        }
+
+        let Some(&(source_index, _, source_ink)) =
+            master_layers.iter().find(|(_, layer, _)| layer.id.as_deref() == Some(master_id))
+        else {
+            let reason = format!("No layer for master '{}'", master_id);
+            skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+            continue;
+        };
+        let Some((source_left, source_right)) = source_ink else {
+            let reason = format!("No outlines in {}", master_id);
+            skipped.push(serde_json::json!({ "glyph": name, "reason": reason }));
+            continue;
+        };
+        let source_width = glyph.layers[source_index].width as f64;
+        let old_left = source_left.round();
+        let old_right = (source_width - source_right).round();
+
+        for (index, layer, ink) in master_layers {
+            let width = layer.width as f64;
+            let op = if index == source_index {
+                MetricsOp::SetSidebearings { left, right }
+            } else {
+                if propagation == Propagation::None {
+                    continue;
+                }
+                let Some((ink_left, ink_right)) = ink else {
+                    skipped.push(serde_json::json!({
+                        "glyph": name,
+                        "reason": format!("No outlines in {}", layer_label(layer, index)),
+                    }));
+                    continue;
+                };
+                MetricsOp::SetSidebearings {
+                    left: left.and_then(|new| propagation.propagate(old_left, new.round(), ink_left.round())),
+                    right: right.and_then(|new| propagation.propagate(old_right, new.round(), (width - ink_right).round())),
+                }
+            };
+            if let Some((dx, width)) = op.apply(ink, width) {
+                updates.push((name.clone(), index, dx, width.max(0.0) as f32));
+            }
+        }
+    }
+
+    let (layer_count, changed) = apply_layer_updates(font, updates);
+    let mut glyphs: Vec<&String> = changed.iter().collect();
+    glyphs.sort();
+    let result = serde_json::json!({
+        "layers": layer_count,
+        "glyphs": glyphs,
+        "skipped": skipped,
+    });
+    Ok((result, changed))
+}
@@ -0,0 +1,184 @@
+// Standard glyphs module
+//
+// This module creates glyphs that every font needs and validators expect:
+// .notdef (a box), .null, the space and no-break space and the carriage
+// return. Missing glyphs are created with a layer per master; existing
+// ones get layers for masters they lack. Widths follow each master's space.
+
+use babelfont::{Layer, Node, NodeType, Path, Shape};
+use std::collections::HashSet;
+
+use crate::source_checks::{correct_layer_directions, outer_is_clockwise};
+
+/// Space width as a share of the em when the font has no space
+const SPACE_WIDTH: f64 = 0.25;
+
+/// .notdef advance width as a share of the em
+const NOTDEF_WIDTH: f64 = 0.5;
+
+/// .notdef box height as a share of the em when a master has no cap height
+const NOTDEF_HEIGHT: f64 = 0.7;
+
+/// .notdef margin and stroke thickness as a share of the em
+const NOTDEF_STROKE: f64 = 0.05;
+
+/// A glyph with no layers, exported and categorized as a base glyph
+pub(crate) fn new_glyph(name: &str, codepoints: Vec<u32>) -> Result<babelfont::Glyph, String> {
+    let mut glyph: babelfont::Glyph = serde_json::from_value(serde_json::json!({
+        "name": name,
+        "codepoints": codepoints,
+        "layers": [],
+    }))
+    .map_err(|e| format!("Failed to create glyph '{}': {}", name, e))?;
+    glyph.category = babelfont::GlyphCategory::Base;
+    glyph.exported = true;
+    Ok(glyph)
+}
+
+/// An empty master layer
+fn master_layer(master: &babelfont::Master, width: f64, shapes: Vec<Shape>) -> Layer {
+    Layer {
+        id: Some(master.id.clone()),
+        name: None,
+        width: width.round() as f32,
+        shapes,
+        anchors: Vec::new(),
+        guides: Vec::new(),
+        color: None,
+        location: None,
+        is_background: false,
+        background_layer_id: None,
+        layer_index: None,
+        master: babelfont::LayerType::DefaultForMaster(master.id.clone()),
+        format_specific: Default::default(),
+    }
+}
+
+/// A closed rectangle path
+fn rectangle(left: f64, bottom: f64, right: f64, top: f64) -> Path {
+    let node = |x: f64, y: f64| Node { x, y, nodetype: NodeType::Line, smooth: false };
+    Path {
+        nodes: vec![node(left, bottom), node(right, bottom), node(right, top), node(left, top)],
+        closed: true,
+        format_specific: Default::default(),
+    }
+}
+
+/// The standard glyphs: name, codepoint, and alternative names they may exist under
+const STANDARD_GLYPHS: [(&str, Option<u32>, &[&str]); 5] = [
+    (".notdef", None, &[]),
+    (".null", None, &["NULL", "uni0000"]),
+    ("CR", Some(0x000D), &["uni000D", "nonmarkingreturn"]),
+    ("space", Some(0x0020), &[]),
+    ("nbspace", Some(0x00A0), &["uni00A0", "nonbreakingspace"]),
+];
+
+/// Create missing standard glyphs and master layers
+///
+/// .notdef gets a box of half an em wide and cap height tall (with a
+/// counter), .null an empty layer of zero width, and the space, no-break
+/// space and carriage return the width of the master's space (a quarter
+/// em if the font has none). Glyphs are found by name, alternative name or
+/// codepoint; layers are only added, never changed.
+///
+/// # Arguments
+/// * `font` - Mutable reference to the font
+///
+/// # Returns
+/// * `(JsonValue, HashSet<String>)` - Result '{"created": [".notdef", "CR"], "completed": [{"glyph": "space", "masters": ["m02"]}]}' and the changed glyph names
+pub fn generate_standard_glyphs(
+    font: &mut babelfont::Font,
+) -> Result<(serde_json::Value, HashSet<String>), String> {
+    let upm = font.upm as f64;
+    let masters = font.masters.clone();
+    let outer_clockwise = outer_is_clockwise(font);
+
+    // Space width per master, from the existing space glyph
+    let space = font
+        .glyphs
+        .iter()
+        .find(|glyph| glyph.name.as_str() == "space" || glyph.codepoints.contains(&0x20));
+    let space_width = |master: &babelfont::Master| {
+        space
+            .and_then(|glyph| {
+                glyph.layers.iter().find(|layer| !layer.is_background && layer.id.as_ref() == Some(&master.id))
+            })
+            .map(|layer| layer.width as f64)
+            .unwrap_or((upm * SPACE_WIDTH).round())
+    };
+    let space_widths: Vec<f64> = masters.iter().map(space_width).collect();
+
+    let mut created = Vec::new();
+    let mut completed = Vec::new();
+    let mut changed = HashSet::new();
+    for (name, codepoint, alternatives) in STANDARD_GLYPHS {
+        let existing = font
+            .glyphs
+            .iter()
+            .find(|glyph| {
+                glyph.name.as_str() == name
+                    || alternatives.contains(&glyph.name.as_str())
+                    || codepoint.is_some_and(|codepoint| glyph.codepoints.contains(&codepoint))
+            })
+            .map(|glyph| glyph.name.to_string());
+
+        let mut layers = Vec::new();
+        for (master, &space_width) in masters.iter().zip(&space_widths) {
+            let has_layer = existing.as_ref().and_then(|existing| font.glyphs.get(existing)).is_some_and(|glyph| {
+                glyph.layers.iter().any(|layer| !layer.is_background && layer.id.as_ref() == Some(&master.id))
+            });
+            if has_layer {
+                continue;
+            }
+            let layer = match name {
+                ".notdef" => {
+                    let width = (upm * NOTDEF_WIDTH).round();
+                    let stroke = (upm * NOTDEF_STROKE).round();
+                    let height = master
+                        .metrics
+                        .get(&babelfont::MetricType::CapHeight)
+                        .map(|&value| value as f64)
+                        .unwrap_or((upm * NOTDEF_HEIGHT).round());
+                    let (left, right) = (stroke, width - stroke);
+                    let shapes = vec![
+                        Shape::Path(rectangle(left, 0.0, right, height)),
+                        Shape::Path(rectangle(left + stroke, stroke, right - stroke, height - stroke)),
+                    ];
+                    let mut layer = master_layer(master, width, shapes);
+                    correct_layer_directions(&mut layer, outer_clockwise);
+                    layer
+                }
+                ".null" => master_layer(master, 0.0, Vec::new()),
+                _ => master_layer(master, space_width, Vec::new()),
+            };
+            layers.push(layer);
+        }
+        if layers.is_empty() {
+            continue;
+        }
+
+        match existing {
+            Some(existing) => {
+                let master_ids: Vec<Option<String>> = layers.iter().map(|layer| layer.id.clone()).collect();
+                if let Some(glyph) = font.glyphs.get_mut(&existing) {
+                    glyph.layers.extend(layers);
+                }
+                completed.push(serde_json::json!({ "glyph": existing, "masters": master_ids }));
+                changed.insert(existing);
+            }
+            None => {
+                let mut glyph = new_glyph(name, codepoint.into_iter().collect())?;
+                glyph.layers = layers;
+                font.glyphs.push(glyph);
+                created.push(name);
+                changed.insert(name.to_string());
+            }
+        }
+    }
+
+    let result = serde_json::json!({
+        "created": created,
+        "completed": completed,
+    });
+    Ok((result, changed))
+}
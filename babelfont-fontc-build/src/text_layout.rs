@@ -0,0 +1,616 @@
+// Text layout module
+//
+// This module lays out paragraphs of text for proofs: it splits text at
+// Unicode line break opportunities (UAX #14), shapes each paragraph with the
+// shaping module, wraps the result greedily to a column width, and reorders
+// mixed-direction lines with the Unicode Bidirectional Algorithm (UAX #9).
+
+use crate::errors::ApiError;
+use crate::font_reader::{glyph_name_for, read_glyph_order};
+use crate::shaping::{self, ShapingOptions};
+use read_fonts::types::GlyphId16;
+use read_fonts::{FontRef, TableProvider};
+use unicode_bidi::{BidiInfo, Level};
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+use wasm_bindgen::prelude::*;
+
+/// Horizontal alignment of lines within the column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alignment {
+    /// Left for left-to-right paragraphs, right for right-to-left ones
+    Start,
+    Left,
+    Center,
+    Right,
+}
+
+/// Options controlling text layout
+#[derive(Clone, Debug)]
+pub struct LayoutOptions {
+    /// Font size in output units; None lays out in font units
+    pub font_size: Option<f64>,
+    /// Line height as a multiple of the font size; None uses the hhea metrics
+    pub line_height: Option<f64>,
+    pub align: Alignment,
+    pub shaping: ShapingOptions,
+}
+
+impl LayoutOptions {
+    /// Parse layout options from JSON
+    ///
+    /// Accepts `fontSize`, `lineHeight`, `align` ("start", "left", "center",
+    /// "right") plus all shaping options (see `ShapingOptions::from_value`).
+    pub fn from_json(options_json: Option<&str>) -> Result<Self, ApiError> {
+        let options_json = options_json.map(str::trim).unwrap_or("");
+        let options: serde_json::Value = if options_json.is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::from_str(options_json)
+                .map_err(|e| ApiError::invalid_json("layout options", e))?
+        };
+        Self::from_value(&options)
+    }
+
+    /// Parse layout options from an already decoded JSON value
+    pub fn from_value(options: &serde_json::Value) -> Result<Self, ApiError> {
+        let align = match options.get("align").and_then(|v| v.as_str()).unwrap_or("start") {
+            "start" => Alignment::Start,
+            "left" => Alignment::Left,
+            "center" => Alignment::Center,
+            "right" => Alignment::Right,
+            other => return Err(ApiError::invalid_argument("alignment", other)),
+        };
+
+        Ok(LayoutOptions {
+            font_size: options.get("fontSize").and_then(|v| v.as_f64()),
+            line_height: options.get("lineHeight").and_then(|v| v.as_f64()),
+            align,
+            shaping: ShapingOptions::from_value(options)?,
+        })
+    }
+}
+
+/// Vertical font metrics used to place lines, in font units
+#[derive(Clone, Copy, Debug)]
+pub struct LineMetrics {
+    pub upm: f64,
+    pub ascender: f64,
+    pub descender: f64,
+    pub line_gap: f64,
+    /// Column spacing for vertical text from the vhea table, if present
+    pub vertical_line_height: Option<f64>,
+}
+
+impl LineMetrics {
+    pub fn from_font(font: &FontRef) -> Result<Self, String> {
+        let head = font.head().map_err(|e| format!("Failed to read head table: {:?}", e))?;
+        let hhea = font.hhea().map_err(|e| format!("Failed to read hhea table: {:?}", e))?;
+        Ok(LineMetrics {
+            upm: head.units_per_em() as f64,
+            ascender: hhea.ascender().to_i16() as f64,
+            descender: hhea.descender().to_i16() as f64,
+            line_gap: hhea.line_gap().to_i16() as f64,
+            vertical_line_height: font.vhea().ok().map(|vhea| {
+                (vhea.ascender().to_i16() as f64) - (vhea.descender().to_i16() as f64)
+                    + (vhea.line_gap().to_i16() as f64)
+            }),
+        })
+    }
+}
+
+/// A paragraph of text between mandatory breaks
+struct Paragraph {
+    /// Byte range of the paragraph content, excluding the line terminator
+    start: usize,
+    end: usize,
+    /// Allowed break positions inside the paragraph (absolute byte offsets)
+    breaks: Vec<usize>,
+}
+
+/// Split text into paragraphs at mandatory line breaks
+fn paragraphs(text: &str) -> Vec<Paragraph> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    let mut breaks = Vec::new();
+
+    for (position, opportunity) in linebreaks(text) {
+        match opportunity {
+            BreakOpportunity::Mandatory => {
+                let content = text[start..position]
+                    .trim_end_matches(['\n', '\r', '\u{0B}', '\u{0C}', '\u{85}', '\u{2028}', '\u{2029}']);
+                result.push(Paragraph {
+                    start,
+                    end: start + content.len(),
+                    breaks: std::mem::take(&mut breaks),
+                });
+                start = position;
+            }
+            BreakOpportunity::Allowed => breaks.push(position),
+        }
+    }
+
+    result
+}
+
+/// A directional run within a line, in visual order
+#[derive(Clone, Debug)]
+pub struct LineRun {
+    /// Byte range of the run's text
+    pub start: usize,
+    pub end: usize,
+    pub direction: harfrust::Direction,
+    /// Resolved bidi embedding level (even: left-to-right, odd: right-to-left)
+    pub level: u8,
+    /// Range of the run's glyphs within the line's glyphs
+    pub glyph_start: usize,
+    pub glyph_end: usize,
+}
+
+/// A laid out line of text
+pub struct Line {
+    /// Byte range of the line's text (trailing whitespace excluded)
+    pub start: usize,
+    pub end: usize,
+    /// Glyphs of all runs in visual order; the direction is the paragraph direction
+    pub run: shaping::ShapedRun,
+    pub runs: Vec<LineRun>,
+    /// True if a single unbreakable segment is wider than the column
+    pub overflow: bool,
+}
+
+/// Shape a range of a paragraph as directional runs in visual order
+///
+/// Horizontal text is split into runs with the Unicode Bidirectional
+/// Algorithm; each run is shaped in its own direction. Clusters are byte
+/// offsets into the paragraph content.
+fn shape_line(
+    font_bytes: &[u8],
+    content: &str,
+    range: std::ops::Range<usize>,
+    bidi: Option<&BidiInfo>,
+    options: &ShapingOptions,
+) -> Result<(shaping::ShapedRun, Vec<LineRun>), String> {
+    let shape_range = |range: std::ops::Range<usize>, options: &ShapingOptions| {
+        let mut run = shaping::shape(font_bytes, &content[range.clone()], options)?;
+        for glyph in &mut run.glyphs {
+            glyph.cluster += range.start as u32;
+        }
+        Ok::<_, String>(run)
+    };
+
+    let Some(bidi) = bidi.filter(|_| !range.is_empty()) else {
+        let run = shape_range(range.clone(), options)?;
+        let line_run = LineRun {
+            start: range.start,
+            end: range.end,
+            direction: run.direction,
+            level: (run.direction == harfrust::Direction::RightToLeft) as u8,
+            glyph_start: 0,
+            glyph_end: run.glyphs.len(),
+        };
+        return Ok((run, vec![line_run]));
+    };
+
+    let mut combined: Option<shaping::ShapedRun> = None;
+    let mut runs = Vec::new();
+
+    for paragraph in bidi.paragraphs.iter() {
+        let line_range = range.start.max(paragraph.range.start)..range.end.min(paragraph.range.end);
+        if line_range.is_empty() {
+            continue;
+        }
+
+        let (levels, visual_runs) = bidi.visual_runs(paragraph, line_range);
+        for visual_run in visual_runs {
+            let level = levels[visual_run.start];
+            let direction = if level.is_rtl() {
+                harfrust::Direction::RightToLeft
+            } else {
+                harfrust::Direction::LeftToRight
+            };
+            let mut run_options = options.clone();
+            run_options.direction = Some(direction);
+            let run = shape_range(visual_run.clone(), &run_options)?;
+
+            let combined = combined.get_or_insert_with(|| shaping::ShapedRun {
+                glyphs: Vec::new(),
+                direction: if paragraph.level.is_rtl() {
+                    harfrust::Direction::RightToLeft
+                } else {
+                    harfrust::Direction::LeftToRight
+                },
+                script: run.script,
+                language: run.language.clone(),
+            });
+            runs.push(LineRun {
+                start: visual_run.start,
+                end: visual_run.end,
+                direction,
+                level: level.number(),
+                glyph_start: combined.glyphs.len(),
+                glyph_end: combined.glyphs.len() + run.glyphs.len(),
+            });
+            combined.glyphs.extend(run.glyphs);
+        }
+    }
+
+    match combined {
+        Some(combined) => Ok((combined, runs)),
+        None => shape_line(font_bytes, content, range, None, options),
+    }
+}
+
+/// Break a paragraph into lines no wider than `max_width` font units
+fn break_paragraph(
+    font_bytes: &[u8],
+    text: &str,
+    paragraph: &Paragraph,
+    max_width: f64,
+    options: &ShapingOptions,
+) -> Result<Vec<Line>, String> {
+    let content = &text[paragraph.start..paragraph.end];
+
+    // Vertical text is not reordered; horizontal text follows the UBA with the
+    // paragraph direction taken from the options or the first strong character
+    let bidi = match options.direction {
+        Some(direction) if !shaping::is_horizontal(direction) => None,
+        Some(harfrust::Direction::RightToLeft) => Some(BidiInfo::new(content, Some(Level::rtl()))),
+        Some(harfrust::Direction::LeftToRight) => Some(BidiInfo::new(content, Some(Level::ltr()))),
+        _ => Some(BidiInfo::new(content, None)),
+    };
+
+    let (run, _) = shape_line(font_bytes, content, 0..content.len(), bidi.as_ref(), options)?;
+
+    // Advance contributed by each cluster, indexed by byte offset in the paragraph
+    let mut advance_at = vec![0f64; content.len() + 1];
+    for glyph in &run.glyphs {
+        let advance = if shaping::is_horizontal(run.direction) { glyph.x_advance } else { glyph.y_advance.abs() };
+        advance_at[glyph.cluster as usize] += advance as f64;
+    }
+    let measure = |start: usize, end: usize| -> f64 { advance_at[start..end].iter().sum() };
+
+    let mut boundaries: Vec<usize> = paragraph
+        .breaks
+        .iter()
+        .map(|b| b - paragraph.start)
+        .filter(|b| *b > 0 && *b < content.len())
+        .collect();
+    boundaries.push(content.len());
+
+    // Greedy line filling over break segments
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut line_start = 0;
+    let mut line_end = 0;
+    let mut line_width = 0.0;
+    let mut segment_start = 0;
+
+    for segment_end in boundaries {
+        let segment = &content[segment_start..segment_end];
+        let ink_end = segment_start + segment.trim_end().len();
+        let ink_width = measure(segment_start, ink_end);
+
+        if line_end > line_start && line_width + ink_width > max_width {
+            ranges.push((line_start, line_end));
+            line_start = segment_start;
+            line_width = 0.0;
+        }
+
+        line_width += measure(segment_start, segment_end);
+        line_end = ink_end;
+        segment_start = segment_end;
+    }
+    ranges.push((line_start, line_end));
+
+    let mut lines = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        let (mut run, mut runs) = shape_line(font_bytes, content, start..end, bidi.as_ref(), options)?;
+        let offset = paragraph.start;
+        for glyph in &mut run.glyphs {
+            glyph.cluster += offset as u32;
+        }
+        for line_run in &mut runs {
+            line_run.start += offset;
+            line_run.end += offset;
+        }
+        let overflow = run.advance() as f64 > max_width;
+        lines.push(Line {
+            start: paragraph.start + start,
+            end: paragraph.start + end,
+            run,
+            runs,
+            overflow,
+        });
+    }
+
+    Ok(lines)
+}
+
+/// Lay out text into lines wrapped to a column width
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to lay out; newlines start new paragraphs
+/// * `width` - Column width in output units (column height for vertical text)
+/// * `options` - Layout options
+///
+/// # Returns
+/// * `Result<Vec<Line>, String>` - Lines in order (top to bottom, or right to left for vertical text)
+pub fn layout(font_bytes: &[u8], text: &str, width: f64, options: &LayoutOptions) -> Result<Vec<Line>, String> {
+    let font = FontRef::new(font_bytes).map_err(|e| format!("Failed to parse font: {:?}", e))?;
+    let metrics = LineMetrics::from_font(&font)?;
+    let scale = options.font_size.map_or(1.0, |size| size / metrics.upm);
+
+    let mut lines = Vec::new();
+    for paragraph in paragraphs(text) {
+        lines.extend(break_paragraph(font_bytes, text, &paragraph, width / scale, &options.shaping)?);
+    }
+    Ok(lines)
+}
+
+/// A glyph positioned within a text block, in output units
+///
+/// The origin is the glyph's horizontal origin (where its outline is drawn
+/// from), also for vertical text.
+#[derive(Clone, Debug)]
+pub struct PositionedGlyph {
+    pub glyph_id: u32,
+    pub cluster: u32,
+    /// Horizontal position of the glyph origin from the left of the block
+    pub x: f64,
+    /// Position of the glyph origin upwards from the line's baseline
+    pub y: f64,
+    pub x_advance: f64,
+    pub y_advance: f64,
+}
+
+/// A positioned line within a text block, in output units
+///
+/// For vertical text a line is a column: `x` is the column's center line,
+/// `baseline` is where the column starts (downwards from the top of the
+/// block) and `width` is the column's length.
+#[derive(Clone, Debug)]
+pub struct PositionedLine {
+    pub start: usize,
+    pub end: usize,
+    pub x: f64,
+    /// Baseline position downwards from the top of the block
+    pub baseline: f64,
+    pub width: f64,
+    pub overflow: bool,
+    pub direction: harfrust::Direction,
+    pub glyphs: Vec<PositionedGlyph>,
+    /// Directional runs in visual order
+    pub runs: Vec<LineRun>,
+}
+
+/// A laid out block of text, in output units
+#[derive(Clone, Debug)]
+pub struct TextBlock {
+    pub width: f64,
+    pub height: f64,
+    pub font_size: f64,
+    pub line_height: f64,
+    pub lines: Vec<PositionedLine>,
+}
+
+impl TextBlock {
+    /// Serialize the block, resolving glyph names from the compiled glyph order
+    ///
+    /// `text` is the laid out text, used to report each line's content.
+    pub fn to_json(&self, text: &str, glyph_order: &[String]) -> serde_json::Value {
+        let lines_json: Vec<serde_json::Value> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let glyphs: Vec<serde_json::Value> = line
+                    .glyphs
+                    .iter()
+                    .map(|glyph| {
+                        serde_json::json!({
+                            "glyphId": glyph.glyph_id,
+                            "name": glyph_name_for(glyph_order, GlyphId16::new(glyph.glyph_id as u16)),
+                            "cluster": glyph.cluster,
+                            "x": glyph.x,
+                            "y": glyph.y,
+                            "xAdvance": glyph.x_advance,
+                            "yAdvance": glyph.y_advance,
+                        })
+                    })
+                    .collect();
+
+                let runs: Vec<serde_json::Value> = line
+                    .runs
+                    .iter()
+                    .map(|run| {
+                        serde_json::json!({
+                            "start": run.start,
+                            "end": run.end,
+                            "direction": shaping::direction_name(run.direction),
+                            "level": run.level,
+                            "glyphStart": run.glyph_start,
+                            "glyphEnd": run.glyph_end,
+                        })
+                    })
+                    .collect();
+
+                serde_json::json!({
+                    "text": &text[line.start..line.end],
+                    "start": line.start,
+                    "end": line.end,
+                    "x": line.x,
+                    "baseline": line.baseline,
+                    "width": line.width,
+                    "overflow": line.overflow,
+                    "direction": shaping::direction_name(line.direction),
+                    "runs": runs,
+                    "glyphs": glyphs,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "width": self.width,
+            "height": self.height,
+            "fontSize": self.font_size,
+            "lineHeight": self.line_height,
+            "lines": lines_json,
+        })
+    }
+}
+
+/// Lay out text and position its lines and glyphs in output units
+///
+/// An infinite `width` disables wrapping; lines are then aligned within the
+/// width of the longest line. Vertical text (direction "ttb") is set in
+/// columns from right to left and `width` is the column height.
+pub fn layout_block(font_bytes: &[u8], text: &str, width: f64, options: &LayoutOptions) -> Result<TextBlock, String> {
+    let font = FontRef::new(font_bytes).map_err(|e| format!("Failed to parse font: {:?}", e))?;
+    let metrics = LineMetrics::from_font(&font)?;
+    let vertical = options.shaping.direction.is_some_and(|direction| !shaping::is_horizontal(direction));
+
+    let font_size = options.font_size.unwrap_or(metrics.upm);
+    let scale = font_size / metrics.upm;
+    let line_height = match (options.line_height, vertical) {
+        (Some(multiple), _) => multiple * font_size,
+        (None, false) => (metrics.ascender - metrics.descender + metrics.line_gap) * scale,
+        (None, true) => metrics.vertical_line_height.unwrap_or(metrics.upm) * scale,
+    };
+
+    let lines = layout(font_bytes, text, width, options)?;
+    let extent = if width.is_finite() {
+        width
+    } else {
+        lines.iter().map(|line| line.run.advance() as f64 * scale).fold(0.0, f64::max)
+    };
+
+    let mut positioned = Vec::with_capacity(lines.len());
+    let line_count = lines.len();
+    for (index, line) in lines.into_iter().enumerate() {
+        let line_width = line.run.advance() as f64 * scale;
+        let rtl = line.run.direction == harfrust::Direction::RightToLeft;
+        let start = match options.align {
+            Alignment::Left => 0.0,
+            Alignment::Start if !rtl => 0.0,
+            Alignment::Center => (extent - line_width) / 2.0,
+            Alignment::Right | Alignment::Start => extent - line_width,
+        };
+
+        // Horizontal lines advance the pen along x; vertical columns along y (downwards)
+        let (x, baseline) = if vertical {
+            (line_count as f64 * line_height - (index as f64 + 0.5) * line_height, start)
+        } else {
+            (start, metrics.ascender * scale + index as f64 * line_height)
+        };
+
+        let mut pen_x = if vertical { x } else { start };
+        let mut pen_y = 0.0;
+        let glyphs = line
+            .run
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let positioned_glyph = PositionedGlyph {
+                    glyph_id: glyph.glyph_id,
+                    cluster: glyph.cluster,
+                    x: pen_x + glyph.x_offset as f64 * scale,
+                    y: pen_y + glyph.y_offset as f64 * scale,
+                    x_advance: glyph.x_advance as f64 * scale,
+                    y_advance: glyph.y_advance as f64 * scale,
+                };
+                pen_x += glyph.x_advance as f64 * scale;
+                pen_y += glyph.y_advance as f64 * scale;
+                positioned_glyph
+            })
+            .collect();
+
+        positioned.push(PositionedLine {
+            start: line.start,
+            end: line.end,
+            x,
+            baseline,
+            width: line_width,
+            overflow: line.overflow,
+            direction: line.run.direction,
+            glyphs,
+            runs: line.runs,
+        });
+    }
+
+    let (block_width, block_height) = if vertical {
+        (positioned.len() as f64 * line_height, extent)
+    } else if positioned.is_empty() {
+        (extent, 0.0)
+    } else {
+        (
+            extent,
+            (positioned.len() - 1) as f64 * line_height + (metrics.ascender - metrics.descender) * scale,
+        )
+    };
+
+    Ok(TextBlock {
+        width: block_width,
+        height: block_height,
+        font_size,
+        line_height,
+        lines: positioned,
+    })
+}
+
+/// Lay out text into lines wrapped to a column width
+///
+/// Text is split into paragraphs at mandatory breaks, shaped, and wrapped
+/// greedily at Unicode line break opportunities. Trailing whitespace does
+/// not count towards a line's width.
+///
+/// Returns a JSON object with structure:
+/// ```json
+/// {
+///   "width": 400, "height": 86.4, "fontSize": 24, "lineHeight": 28.8,
+///   "lines": [
+///     { "text": "Sphinx of black", "start": 0, "end": 15,
+///       "x": 0, "baseline": 22.1, "width": 371.5, "overflow": false,
+///       "direction": "ltr",
+///       "runs": [{ "start": 0, "end": 15, "direction": "ltr", "level": 0,
+///                  "glyphStart": 0, "glyphEnd": 15 }],
+///       "glyphs": [{ "glyphId": 54, "name": "S", "cluster": 0,
+///                    "x": 0, "y": 0, "xAdvance": 13.2, "yAdvance": 0 }] }
+///   ]
+/// }
+/// ```
+///
+/// Positions are in output units (font units scaled to `fontSize`). `baseline`
+/// is measured downwards from the top of the text block; glyph `y` positions
+/// are upwards from the baseline, as in font coordinates.
+///
+/// Mixed-direction lines are reordered with the Unicode Bidirectional
+/// Algorithm: `runs` lists each line's directional runs in visual order with
+/// their text range and glyph range, for cursor mapping. The paragraph
+/// direction comes from the `direction` option or the first strong character.
+///
+/// With `"direction": "ttb"` text is set vertically (applying `vert`/`vrt2`
+/// and vertical advances and origins) in columns from right to left: `width`
+/// is then the column height, each line's `x` is its column's center line and
+/// `baseline` the top of the column. Glyph positions are always horizontal
+/// glyph origins, so outlines can be drawn at them unchanged.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to lay out; newlines start new paragraphs
+/// * `width` - Column width in output units (column height for vertical text)
+/// * `options_json` - Optional JSON object: '{"fontSize": 24, "lineHeight": 1.2, "align": "start"}' plus shaping options (features, script, language, direction, location)
+///
+/// # Returns
+/// * `String` - JSON object with positioned lines
+#[wasm_bindgen]
+pub fn layout_text(font_bytes: &[u8], text: &str, width: f64, options_json: Option<String>) -> Result<String, JsValue> {
+    let options = LayoutOptions::from_json(options_json.as_deref())?;
+
+    let font = FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let glyph_order = read_glyph_order(&font)?;
+
+    let block = layout_block(font_bytes, text, width, &options).map_err(ApiError::from)?;
+
+    serde_json::to_string(&block.to_json(text, &glyph_order))
+        .map_err(|e| ApiError::serialization("layout", e).into())
+}
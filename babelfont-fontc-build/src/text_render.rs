@@ -0,0 +1,139 @@
+// Text rendering module
+//
+// This module renders shaped text from compiled font bytes without a canvas
+// round-trip: text is laid out with the text layout module and glyph outlines
+// are drawn with skrifa at the requested variation location.
+
+use crate::errors::ApiError;
+use crate::font_reader::{skrifa_location, BezPathPen};
+use crate::text_layout::{layout_block, LayoutOptions};
+use skrifa::instance::{Location, Size};
+use skrifa::outline::{DrawSettings, OutlineGlyphCollection};
+use skrifa::MetadataProvider;
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Draw a glyph outline in font units (y-up)
+///
+/// Returns None for glyphs without an outline (e.g. space).
+pub(crate) fn glyph_path(outlines: &OutlineGlyphCollection, glyph_id: u32, location: &Location) -> Option<kurbo::BezPath> {
+    let outline = outlines.get(skrifa::GlyphId::new(glyph_id))?;
+    let mut pen = BezPathPen::default();
+    outline
+        .draw(DrawSettings::unhinted(Size::unscaled(), location), &mut pen)
+        .ok()?;
+    (!pen.path.elements().is_empty()).then_some(pen.path)
+}
+
+/// Format a coordinate for SVG output (at most two decimals)
+pub(crate) fn svg_number(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Escape a string for use in an XML attribute
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Render shaped text to an SVG document
+///
+/// Each glyph outline is defined once (in font units) and placed with `<use>`
+/// elements, so positions include kerning and mark attachment offsets from
+/// shaping. Text wraps when a `width` is given, otherwise each paragraph is a
+/// single line.
+///
+/// # Arguments
+/// * `font_bytes` - Compiled TTF/OTF font bytes
+/// * `text` - Text to render; newlines start new paragraphs
+/// * `options_json` - Optional JSON object:
+///   - `fontSize`: number - Font size in pixels (default: 72)
+///   - `width`: number - Column width in pixels for wrapping (default: no wrapping)
+///   - `padding`: number - Padding around the text in pixels (default: 0)
+///   - `color`: string - Fill color of the glyphs (default: "#000000")
+///   - `background`: string - Background color (default: transparent)
+///   - `lineHeight`, `align` and shaping options (features, script, language, direction, location) as for layout_text()
+///
+/// # Returns
+/// * `String` - SVG document
+#[wasm_bindgen]
+pub fn render_text_svg(font_bytes: &[u8], text: &str, options_json: Option<String>) -> Result<String, JsValue> {
+    let options_json = options_json.as_deref().map(str::trim).unwrap_or("");
+    let options: serde_json::Value = if options_json.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::from_str(options_json)
+            .map_err(|e| ApiError::invalid_json("render options", e))?
+    };
+
+    let mut layout_options = LayoutOptions::from_value(&options)?;
+    layout_options.font_size = Some(layout_options.font_size.unwrap_or(72.0));
+    let width = options.get("width").and_then(|v| v.as_f64()).unwrap_or(f64::INFINITY);
+    let padding = options.get("padding").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let color = options.get("color").and_then(|v| v.as_str()).unwrap_or("#000000");
+    let background = options.get("background").and_then(|v| v.as_str());
+
+    let block = layout_block(font_bytes, text, width, &layout_options).map_err(ApiError::from)?;
+
+    let font = skrifa::FontRef::new(font_bytes)
+        .map_err(ApiError::invalid_font)?;
+    let location_json = options.get("location").map(|location| location.to_string());
+    let location = skrifa_location(&font, location_json.as_deref())?;
+    let outlines = font.outline_glyphs();
+    let scale = block.font_size / font.metrics(Size::unscaled(), &location).units_per_em as f64;
+
+    // Glyph outlines used by the text, keyed by glyph ID
+    let mut definitions: BTreeMap<u32, Option<String>> = BTreeMap::new();
+    let mut placements = String::new();
+
+    for line in &block.lines {
+        for glyph in &line.glyphs {
+            let defined = definitions
+                .entry(glyph.glyph_id)
+                .or_insert_with(|| glyph_path(&outlines, glyph.glyph_id, &location).map(|path| path.to_svg()));
+            if defined.is_none() {
+                continue;
+            }
+            placements.push_str(&format!(
+                "<use href=\"#g{}\" transform=\"matrix({} 0 0 {} {} {})\"/>\n",
+                glyph.glyph_id,
+                svg_number(scale),
+                svg_number(-scale),
+                svg_number(padding + glyph.x),
+                svg_number(padding + line.baseline - glyph.y),
+            ));
+        }
+    }
+
+    let svg_width = block.width + 2.0 * padding;
+    let svg_height = block.height + 2.0 * padding;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+        w = svg_number(svg_width),
+        h = svg_number(svg_height),
+    );
+    if let Some(background) = background {
+        svg.push_str(&format!("<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n", xml_escape(background)));
+    }
+    svg.push_str("<defs>\n");
+    for (glyph_id, path) in &definitions {
+        if let Some(path) = path {
+            svg.push_str(&format!("<path id=\"g{}\" d=\"{}\"/>\n", glyph_id, path));
+        }
+    }
+    svg.push_str("</defs>\n");
+    svg.push_str(&format!("<g fill=\"{}\">\n", xml_escape(color)));
+    svg.push_str(&placements);
+    svg.push_str("</g>\n</svg>\n");
+
+    Ok(svg)
+}
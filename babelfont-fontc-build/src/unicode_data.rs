@@ -0,0 +1,2952 @@
+// Unicode character data
+//
+// This module embeds the parts of the Unicode Character Database (Unicode
+// 16.0.0) the editor needs for filtering and describing characters, so the
+// "add glyph by character" dialog and the glyph overview do not need a JS
+// Unicode database: block ranges, general categories, and character names
+// for the blocks of Latin, Greek, Cyrillic, IPA, punctuation and common
+// symbols. Names of CJK ideographs and Hangul syllables are derived from
+// their codepoints. Scripts, bidi classes and decompositions come from the
+// unicode-script, unicode-bidi and unicode-normalization crates, which are
+// pinned in Cargo.toml to their releases for the same Unicode version, so
+// every property of a character comes from Unicode 16.0.0.
+
+use serde_json::json;
+use unicode_bidi::bidi_class;
+use unicode_script::UnicodeScript;
+use wasm_bindgen::prelude::*;
+
+use crate::charsets::suggested_glyph_name;
+use crate::errors::ApiError;
+
+/// Unicode blocks as inclusive (first, last, name) ranges, in codepoint order
+const BLOCKS: &[(u32, u32, &str)] = &[
+    (0x0000, 0x007F, "Basic Latin"),
+    (0x0080, 0x00FF, "Latin-1 Supplement"),
+    (0x0100, 0x017F, "Latin Extended-A"),
+    (0x0180, 0x024F, "Latin Extended-B"),
+    (0x0250, 0x02AF, "IPA Extensions"),
+    (0x02B0, 0x02FF, "Spacing Modifier Letters"),
+    (0x0300, 0x036F, "Combining Diacritical Marks"),
+    (0x0370, 0x03FF, "Greek and Coptic"),
+    (0x0400, 0x04FF, "Cyrillic"),
+    (0x0500, 0x052F, "Cyrillic Supplement"),
+    (0x0530, 0x058F, "Armenian"),
+    (0x0590, 0x05FF, "Hebrew"),
+    (0x0600, 0x06FF, "Arabic"),
+    (0x0700, 0x074F, "Syriac"),
+    (0x0750, 0x077F, "Arabic Supplement"),
+    (0x0780, 0x07BF, "Thaana"),
+    (0x07C0, 0x07FF, "NKo"),
+    (0x0800, 0x083F, "Samaritan"),
+    (0x0840, 0x085F, "Mandaic"),
+    (0x0860, 0x086F, "Syriac Supplement"),
+    (0x0870, 0x089F, "Arabic Extended-B"),
+    (0x08A0, 0x08FF, "Arabic Extended-A"),
+    (0x0900, 0x097F, "Devanagari"),
+    (0x0980, 0x09FF, "Bengali"),
+    (0x0A00, 0x0A7F, "Gurmukhi"),
+    (0x0A80, 0x0AFF, "Gujarati"),
+    (0x0B00, 0x0B7F, "Oriya"),
+    (0x0B80, 0x0BFF, "Tamil"),
+    (0x0C00, 0x0C7F, "Telugu"),
+    (0x0C80, 0x0CFF, "Kannada"),
+    (0x0D00, 0x0D7F, "Malayalam"),
+    (0x0D80, 0x0DFF, "Sinhala"),
+    (0x0E00, 0x0E7F, "Thai"),
+    (0x0E80, 0x0EFF, "Lao"),
+    (0x0F00, 0x0FFF, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul Jamo"),
+    (0x1200, 0x137F, "Ethiopic"),
+    (0x1380, 0x139F, "Ethiopic Supplement"),
+    (0x13A0, 0x13FF, "Cherokee"),
+    (0x1400, 0x167F, "Unified Canadian Aboriginal Syllabics"),
+    (0x1680, 0x169F, "Ogham"),
+    (0x16A0, 0x16FF, "Runic"),
+    (0x1700, 0x171F, "Tagalog"),
+    (0x1720, 0x173F, "Hanunoo"),
+    (0x1740, 0x175F, "Buhid"),
+    (0x1760, 0x177F, "Tagbanwa"),
+    (0x1780, 0x17FF, "Khmer"),
+    (0x1800, 0x18AF, "Mongolian"),
+    (0x18B0, 0x18FF, "Unified Canadian Aboriginal Syllabics Extended"),
+    (0x1900, 0x194F, "Limbu"),
+    (0x1950, 0x197F, "Tai Le"),
+    (0x1980, 0x19DF, "New Tai Lue"),
+    (0x19E0, 0x19FF, "Khmer Symbols"),
+    (0x1A00, 0x1A1F, "Buginese"),
+    (0x1A20, 0x1AAF, "Tai Tham"),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended"),
+    (0x1B00, 0x1B7F, "Balinese"),
+    (0x1B80, 0x1BBF, "Sundanese"),
+    (0x1BC0, 0x1BFF, "Batak"),
+    (0x1C00, 0x1C4F, "Lepcha"),
+    (0x1C50, 0x1C7F, "Ol Chiki"),
+    (0x1C80, 0x1C8F, "Cyrillic Extended-C"),
+    (0x1C90, 0x1CBF, "Georgian Extended"),
+    (0x1CC0, 0x1CCF, "Sundanese Supplement"),
+    (0x1CD0, 0x1CFF, "Vedic Extensions"),
+    (0x1D00, 0x1D7F, "Phonetic Extensions"),
+    (0x1D80, 0x1DBF, "Phonetic Extensions Supplement"),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement"),
+    (0x1E00, 0x1EFF, "Latin Extended Additional"),
+    (0x1F00, 0x1FFF, "Greek Extended"),
+    (0x2000, 0x206F, "General Punctuation"),
+    (0x2070, 0x209F, "Superscripts and Subscripts"),
+    (0x20A0, 0x20CF, "Currency Symbols"),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols"),
+    (0x2100, 0x214F, "Letterlike Symbols"),
+    (0x2150, 0x218F, "Number Forms"),
+    (0x2190, 0x21FF, "Arrows"),
+    (0x2200, 0x22FF, "Mathematical Operators"),
+    (0x2300, 0x23FF, "Miscellaneous Technical"),
+    (0x2400, 0x243F, "Control Pictures"),
+    (0x2440, 0x245F, "Optical Character Recognition"),
+    (0x2460, 0x24FF, "Enclosed Alphanumerics"),
+    (0x2500, 0x257F, "Box Drawing"),
+    (0x2580, 0x259F, "Block Elements"),
+    (0x25A0, 0x25FF, "Geometric Shapes"),
+    (0x2600, 0x26FF, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, "Dingbats"),
+    (0x27C0, 0x27EF, "Miscellaneous Mathematical Symbols-A"),
+    (0x27F0, 0x27FF, "Supplemental Arrows-A"),
+    (0x2800, 0x28FF, "Braille Patterns"),
+    (0x2900, 0x297F, "Supplemental Arrows-B"),
+    (0x2980, 0x29FF, "Miscellaneous Mathematical Symbols-B"),
+    (0x2A00, 0x2AFF, "Supplemental Mathematical Operators"),
+    (0x2B00, 0x2BFF, "Miscellaneous Symbols and Arrows"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin Extended-C"),
+    (0x2C80, 0x2CFF, "Coptic"),
+    (0x2D00, 0x2D2F, "Georgian Supplement"),
+    (0x2D30, 0x2D7F, "Tifinagh"),
+    (0x2D80, 0x2DDF, "Ethiopic Extended"),
+    (0x2DE0, 0x2DFF, "Cyrillic Extended-A"),
+    (0x2E00, 0x2E7F, "Supplemental Punctuation"),
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement"),
+    (0x2F00, 0x2FDF, "Kangxi Radicals"),
+    (0x2FF0, 0x2FFF, "Ideographic Description Characters"),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation"),
+    (0x3040, 0x309F, "Hiragana"),
+    (0x30A0, 0x30FF, "Katakana"),
+    (0x3100, 0x312F, "Bopomofo"),
+    (0x3130, 0x318F, "Hangul Compatibility Jamo"),
+    (0x3190, 0x319F, "Kanbun"),
+    (0x31A0, 0x31BF, "Bopomofo Extended"),
+    (0x31C0, 0x31EF, "CJK Strokes"),
+    (0x31F0, 0x31FF, "Katakana Phonetic Extensions"),
+    (0x3200, 0x32FF, "Enclosed CJK Letters and Months"),
+    (0x3300, 0x33FF, "CJK Compatibility"),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A"),
+    (0x4DC0, 0x4DFF, "Yijing Hexagram Symbols"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xA000, 0xA48F, "Yi Syllables"),
+    (0xA490, 0xA4CF, "Yi Radicals"),
+    (0xA4D0, 0xA4FF, "Lisu"),
+    (0xA500, 0xA63F, "Vai"),
+    (0xA640, 0xA69F, "Cyrillic Extended-B"),
+    (0xA6A0, 0xA6FF, "Bamum"),
+    (0xA700, 0xA71F, "Modifier Tone Letters"),
+    (0xA720, 0xA7FF, "Latin Extended-D"),
+    (0xA800, 0xA82F, "Syloti Nagri"),
+    (0xA830, 0xA83F, "Common Indic Number Forms"),
+    (0xA840, 0xA87F, "Phags-pa"),
+    (0xA880, 0xA8DF, "Saurashtra"),
+    (0xA8E0, 0xA8FF, "Devanagari Extended"),
+    (0xA900, 0xA92F, "Kayah Li"),
+    (0xA930, 0xA95F, "Rejang"),
+    (0xA960, 0xA97F, "Hangul Jamo Extended-A"),
+    (0xA980, 0xA9DF, "Javanese"),
+    (0xA9E0, 0xA9FF, "Myanmar Extended-B"),
+    (0xAA00, 0xAA5F, "Cham"),
+    (0xAA60, 0xAA7F, "Myanmar Extended-A"),
+    (0xAA80, 0xAADF, "Tai Viet"),
+    (0xAAE0, 0xAAFF, "Meetei Mayek Extensions"),
+    (0xAB00, 0xAB2F, "Ethiopic Extended-A"),
+    (0xAB30, 0xAB6F, "Latin Extended-E"),
+    (0xAB70, 0xABBF, "Cherokee Supplement"),
+    (0xABC0, 0xABFF, "Meetei Mayek"),
+    (0xAC00, 0xD7AF, "Hangul Syllables"),
+    (0xD7B0, 0xD7FF, "Hangul Jamo Extended-B"),
+    (0xD800, 0xDB7F, "High Surrogates"),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates"),
+    (0xDC00, 0xDFFF, "Low Surrogates"),
+    (0xE000, 0xF8FF, "Private Use Area"),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs"),
+    (0xFB00, 0xFB4F, "Alphabetic Presentation Forms"),
+    (0xFB50, 0xFDFF, "Arabic Presentation Forms-A"),
+    (0xFE00, 0xFE0F, "Variation Selectors"),
+    (0xFE10, 0xFE1F, "Vertical Forms"),
+    (0xFE20, 0xFE2F, "Combining Half Marks"),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms"),
+    (0xFE50, 0xFE6F, "Small Form Variants"),
+    (0xFE70, 0xFEFF, "Arabic Presentation Forms-B"),
+    (0xFF00, 0xFFEF, "Halfwidth and Fullwidth Forms"),
+    (0xFFF0, 0xFFFF, "Specials"),
+    (0x10000, 0x1007F, "Linear B Syllabary"),
+    (0x10080, 0x100FF, "Linear B Ideograms"),
+    (0x10100, 0x1013F, "Aegean Numbers"),
+    (0x10140, 0x1018F, "Ancient Greek Numbers"),
+    (0x10190, 0x101CF, "Ancient Symbols"),
+    (0x101D0, 0x101FF, "Phaistos Disc"),
+    (0x10280, 0x1029F, "Lycian"),
+    (0x102A0, 0x102DF, "Carian"),
+    (0x102E0, 0x102FF, "Coptic Epact Numbers"),
+    (0x10300, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034F, "Gothic"),
+    (0x10350, 0x1037F, "Old Permic"),
+    (0x10380, 0x1039F, "Ugaritic"),
+    (0x103A0, 0x103DF, "Old Persian"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x10480, 0x104AF, "Osmanya"),
+    (0x104B0, 0x104FF, "Osage"),
+    (0x10500, 0x1052F, "Elbasan"),
+    (0x10530, 0x1056F, "Caucasian Albanian"),
+    (0x10570, 0x105BF, "Vithkuqi"),
+    (0x105C0, 0x105FF, "Todhri"),
+    (0x10600, 0x1077F, "Linear A"),
+    (0x10780, 0x107BF, "Latin Extended-F"),
+    (0x10800, 0x1083F, "Cypriot Syllabary"),
+    (0x10840, 0x1085F, "Imperial Aramaic"),
+    (0x10860, 0x1087F, "Palmyrene"),
+    (0x10880, 0x108AF, "Nabataean"),
+    (0x108E0, 0x108FF, "Hatran"),
+    (0x10900, 0x1091F, "Phoenician"),
+    (0x10920, 0x1093F, "Lydian"),
+    (0x10980, 0x1099F, "Meroitic Hieroglyphs"),
+    (0x109A0, 0x109FF, "Meroitic Cursive"),
+    (0x10A00, 0x10A5F, "Kharoshthi"),
+    (0x10A60, 0x10A7F, "Old South Arabian"),
+    (0x10A80, 0x10A9F, "Old North Arabian"),
+    (0x10AC0, 0x10AFF, "Manichaean"),
+    (0x10B00, 0x10B3F, "Avestan"),
+    (0x10B40, 0x10B5F, "Inscriptional Parthian"),
+    (0x10B60, 0x10B7F, "Inscriptional Pahlavi"),
+    (0x10B80, 0x10BAF, "Psalter Pahlavi"),
+    (0x10C00, 0x10C4F, "Old Turkic"),
+    (0x10C80, 0x10CFF, "Old Hungarian"),
+    (0x10D00, 0x10D3F, "Hanifi Rohingya"),
+    (0x10D40, 0x10D8F, "Garay"),
+    (0x10E60, 0x10E7F, "Rumi Numeral Symbols"),
+    (0x10E80, 0x10EBF, "Yezidi"),
+    (0x10EC0, 0x10EFF, "Arabic Extended-C"),
+    (0x10F00, 0x10F2F, "Old Sogdian"),
+    (0x10F30, 0x10F6F, "Sogdian"),
+    (0x10F70, 0x10FAF, "Old Uyghur"),
+    (0x10FB0, 0x10FDF, "Chorasmian"),
+    (0x10FE0, 0x10FFF, "Elymaic"),
+    (0x11000, 0x1107F, "Brahmi"),
+    (0x11080, 0x110CF, "Kaithi"),
+    (0x110D0, 0x110FF, "Sora Sompeng"),
+    (0x11100, 0x1114F, "Chakma"),
+    (0x11150, 0x1117F, "Mahajani"),
+    (0x11180, 0x111DF, "Sharada"),
+    (0x111E0, 0x111FF, "Sinhala Archaic Numbers"),
+    (0x11200, 0x1124F, "Khojki"),
+    (0x11280, 0x112AF, "Multani"),
+    (0x112B0, 0x112FF, "Khudawadi"),
+    (0x11300, 0x1137F, "Grantha"),
+    (0x11380, 0x113FF, "Tulu-Tigalari"),
+    (0x11400, 0x1147F, "Newa"),
+    (0x11480, 0x114DF, "Tirhuta"),
+    (0x11580, 0x115FF, "Siddham"),
+    (0x11600, 0x1165F, "Modi"),
+    (0x11660, 0x1167F, "Mongolian Supplement"),
+    (0x11680, 0x116CF, "Takri"),
+    (0x116D0, 0x116FF, "Myanmar Extended-C"),
+    (0x11700, 0x1174F, "Ahom"),
+    (0x11800, 0x1184F, "Dogra"),
+    (0x118A0, 0x118FF, "Warang Citi"),
+    (0x11900, 0x1195F, "Dives Akuru"),
+    (0x119A0, 0x119FF, "Nandinagari"),
+    (0x11A00, 0x11A4F, "Zanabazar Square"),
+    (0x11A50, 0x11AAF, "Soyombo"),
+    (0x11AB0, 0x11ABF, "Unified Canadian Aboriginal Syllabics Extended-A"),
+    (0x11AC0, 0x11AFF, "Pau Cin Hau"),
+    (0x11B00, 0x11B5F, "Devanagari Extended-A"),
+    (0x11BC0, 0x11BFF, "Sunuwar"),
+    (0x11C00, 0x11C6F, "Bhaiksuki"),
+    (0x11C70, 0x11CBF, "Marchen"),
+    (0x11D00, 0x11D5F, "Masaram Gondi"),
+    (0x11D60, 0x11DAF, "Gunjala Gondi"),
+    (0x11EE0, 0x11EFF, "Makasar"),
+    (0x11F00, 0x11F5F, "Kawi"),
+    (0x11FB0, 0x11FBF, "Lisu Supplement"),
+    (0x11FC0, 0x11FFF, "Tamil Supplement"),
+    (0x12000, 0x123FF, "Cuneiform"),
+    (0x12400, 0x1247F, "Cuneiform Numbers and Punctuation"),
+    (0x12480, 0x1254F, "Early Dynastic Cuneiform"),
+    (0x12F90, 0x12FFF, "Cypro-Minoan"),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs"),
+    (0x13430, 0x1345F, "Egyptian Hieroglyph Format Controls"),
+    (0x13460, 0x143FF, "Egyptian Hieroglyphs Extended-A"),
+    (0x14400, 0x1467F, "Anatolian Hieroglyphs"),
+    (0x16100, 0x1613F, "Gurung Khema"),
+    (0x16800, 0x16A3F, "Bamum Supplement"),
+    (0x16A40, 0x16A6F, "Mro"),
+    (0x16A70, 0x16ACF, "Tangsa"),
+    (0x16AD0, 0x16AFF, "Bassa Vah"),
+    (0x16B00, 0x16B8F, "Pahawh Hmong"),
+    (0x16D40, 0x16D7F, "Kirat Rai"),
+    (0x16E40, 0x16E9F, "Medefaidrin"),
+    (0x16F00, 0x16F9F, "Miao"),
+    (0x16FE0, 0x16FFF, "Ideographic Symbols and Punctuation"),
+    (0x17000, 0x187FF, "Tangut"),
+    (0x18800, 0x18AFF, "Tangut Components"),
+    (0x18B00, 0x18CFF, "Khitan Small Script"),
+    (0x18D00, 0x18D7F, "Tangut Supplement"),
+    (0x1AFF0, 0x1AFFF, "Kana Extended-B"),
+    (0x1B000, 0x1B0FF, "Kana Supplement"),
+    (0x1B100, 0x1B12F, "Kana Extended-A"),
+    (0x1B130, 0x1B16F, "Small Kana Extension"),
+    (0x1B170, 0x1B2FF, "Nushu"),
+    (0x1BC00, 0x1BC9F, "Duployan"),
+    (0x1BCA0, 0x1BCAF, "Shorthand Format Controls"),
+    (0x1CC00, 0x1CEBF, "Symbols for Legacy Computing Supplement"),
+    (0x1CF00, 0x1CFCF, "Znamenny Musical Notation"),
+    (0x1D000, 0x1D0FF, "Byzantine Musical Symbols"),
+    (0x1D100, 0x1D1FF, "Musical Symbols"),
+    (0x1D200, 0x1D24F, "Ancient Greek Musical Notation"),
+    (0x1D2C0, 0x1D2DF, "Kaktovik Numerals"),
+    (0x1D2E0, 0x1D2FF, "Mayan Numerals"),
+    (0x1D300, 0x1D35F, "Tai Xuan Jing Symbols"),
+    (0x1D360, 0x1D37F, "Counting Rod Numerals"),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols"),
+    (0x1D800, 0x1DAAF, "Sutton SignWriting"),
+    (0x1DF00, 0x1DFFF, "Latin Extended-G"),
+    (0x1E000, 0x1E02F, "Glagolitic Supplement"),
+    (0x1E030, 0x1E08F, "Cyrillic Extended-D"),
+    (0x1E100, 0x1E14F, "Nyiakeng Puachue Hmong"),
+    (0x1E290, 0x1E2BF, "Toto"),
+    (0x1E2C0, 0x1E2FF, "Wancho"),
+    (0x1E4D0, 0x1E4FF, "Nag Mundari"),
+    (0x1E5D0, 0x1E5FF, "Ol Onal"),
+    (0x1E7E0, 0x1E7FF, "Ethiopic Extended-B"),
+    (0x1E800, 0x1E8DF, "Mende Kikakui"),
+    (0x1E900, 0x1E95F, "Adlam"),
+    (0x1EC70, 0x1ECBF, "Indic Siyaq Numbers"),
+    (0x1ED00, 0x1ED4F, "Ottoman Siyaq Numbers"),
+    (0x1EE00, 0x1EEFF, "Arabic Mathematical Alphabetic Symbols"),
+    (0x1F000, 0x1F02F, "Mahjong Tiles"),
+    (0x1F030, 0x1F09F, "Domino Tiles"),
+    (0x1F0A0, 0x1F0FF, "Playing Cards"),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement"),
+    (0x1F200, 0x1F2FF, "Enclosed Ideographic Supplement"),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs"),
+    (0x1F600, 0x1F64F, "Emoticons"),
+    (0x1F650, 0x1F67F, "Ornamental Dingbats"),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols"),
+    (0x1F700, 0x1F77F, "Alchemical Symbols"),
+    (0x1F780, 0x1F7FF, "Geometric Shapes Extended"),
+    (0x1F800, 0x1F8FF, "Supplemental Arrows-C"),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs"),
+    (0x1FA00, 0x1FA6F, "Chess Symbols"),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A"),
+    (0x1FB00, 0x1FBFF, "Symbols for Legacy Computing"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F"),
+    (0x2EBF0, 0x2EE5F, "CJK Unified Ideographs Extension I"),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0x31350, 0x323AF, "CJK Unified Ideographs Extension H"),
+    (0xE0000, 0xE007F, "Tags"),
+    (0xE0100, 0xE01EF, "Variation Selectors Supplement"),
+    (0xF0000, 0xFFFFF, "Supplementary Private Use Area-A"),
+    (0x100000, 0x10FFFF, "Supplementary Private Use Area-B"),
+];
+
+/// General categories as runs of codepoints starting at the given one
+const GENERAL_CATEGORIES: &[(u32, &str)] = &[
+    (0x0000, "Cc"), (0x0020, "Zs"), (0x0021, "Po"), (0x0024, "Sc"), (0x0025, "Po"), (0x0028, "Ps"),
+    (0x0029, "Pe"), (0x002A, "Po"), (0x002B, "Sm"), (0x002C, "Po"), (0x002D, "Pd"), (0x002E, "Po"),
+    (0x0030, "Nd"), (0x003A, "Po"), (0x003C, "Sm"), (0x003F, "Po"), (0x0041, "Lu"), (0x005B, "Ps"),
+    (0x005C, "Po"), (0x005D, "Pe"), (0x005E, "Sk"), (0x005F, "Pc"), (0x0060, "Sk"), (0x0061, "Ll"),
+    (0x007B, "Ps"), (0x007C, "Sm"), (0x007D, "Pe"), (0x007E, "Sm"), (0x007F, "Cc"), (0x00A0, "Zs"),
+    (0x00A1, "Po"), (0x00A2, "Sc"), (0x00A6, "So"), (0x00A7, "Po"), (0x00A8, "Sk"), (0x00A9, "So"),
+    (0x00AA, "Lo"), (0x00AB, "Pi"), (0x00AC, "Sm"), (0x00AD, "Cf"), (0x00AE, "So"), (0x00AF, "Sk"),
+    (0x00B0, "So"), (0x00B1, "Sm"), (0x00B2, "No"), (0x00B4, "Sk"), (0x00B5, "Ll"), (0x00B6, "Po"),
+    (0x00B8, "Sk"), (0x00B9, "No"), (0x00BA, "Lo"), (0x00BB, "Pf"), (0x00BC, "No"), (0x00BF, "Po"),
+    (0x00C0, "Lu"), (0x00D7, "Sm"), (0x00D8, "Lu"), (0x00DF, "Ll"), (0x00F7, "Sm"), (0x00F8, "Ll"),
+    (0x0100, "Lu"), (0x0101, "Ll"), (0x0102, "Lu"), (0x0103, "Ll"), (0x0104, "Lu"), (0x0105, "Ll"),
+    (0x0106, "Lu"), (0x0107, "Ll"), (0x0108, "Lu"), (0x0109, "Ll"), (0x010A, "Lu"), (0x010B, "Ll"),
+    (0x010C, "Lu"), (0x010D, "Ll"), (0x010E, "Lu"), (0x010F, "Ll"), (0x0110, "Lu"), (0x0111, "Ll"),
+    (0x0112, "Lu"), (0x0113, "Ll"), (0x0114, "Lu"), (0x0115, "Ll"), (0x0116, "Lu"), (0x0117, "Ll"),
+    (0x0118, "Lu"), (0x0119, "Ll"), (0x011A, "Lu"), (0x011B, "Ll"), (0x011C, "Lu"), (0x011D, "Ll"),
+    (0x011E, "Lu"), (0x011F, "Ll"), (0x0120, "Lu"), (0x0121, "Ll"), (0x0122, "Lu"), (0x0123, "Ll"),
+    (0x0124, "Lu"), (0x0125, "Ll"), (0x0126, "Lu"), (0x0127, "Ll"), (0x0128, "Lu"), (0x0129, "Ll"),
+    (0x012A, "Lu"), (0x012B, "Ll"), (0x012C, "Lu"), (0x012D, "Ll"), (0x012E, "Lu"), (0x012F, "Ll"),
+    (0x0130, "Lu"), (0x0131, "Ll"), (0x0132, "Lu"), (0x0133, "Ll"), (0x0134, "Lu"), (0x0135, "Ll"),
+    (0x0136, "Lu"), (0x0137, "Ll"), (0x0139, "Lu"), (0x013A, "Ll"), (0x013B, "Lu"), (0x013C, "Ll"),
+    (0x013D, "Lu"), (0x013E, "Ll"), (0x013F, "Lu"), (0x0140, "Ll"), (0x0141, "Lu"), (0x0142, "Ll"),
+    (0x0143, "Lu"), (0x0144, "Ll"), (0x0145, "Lu"), (0x0146, "Ll"), (0x0147, "Lu"), (0x0148, "Ll"),
+    (0x014A, "Lu"), (0x014B, "Ll"), (0x014C, "Lu"), (0x014D, "Ll"), (0x014E, "Lu"), (0x014F, "Ll"),
+    (0x0150, "Lu"), (0x0151, "Ll"), (0x0152, "Lu"), (0x0153, "Ll"), (0x0154, "Lu"), (0x0155, "Ll"),
+    (0x0156, "Lu"), (0x0157, "Ll"), (0x0158, "Lu"), (0x0159, "Ll"), (0x015A, "Lu"), (0x015B, "Ll"),
+    (0x015C, "Lu"), (0x015D, "Ll"), (0x015E, "Lu"), (0x015F, "Ll"), (0x0160, "Lu"), (0x0161, "Ll"),
+    (0x0162, "Lu"), (0x0163, "Ll"), (0x0164, "Lu"), (0x0165, "Ll"), (0x0166, "Lu"), (0x0167, "Ll"),
+    (0x0168, "Lu"), (0x0169, "Ll"), (0x016A, "Lu"), (0x016B, "Ll"), (0x016C, "Lu"), (0x016D, "Ll"),
+    (0x016E, "Lu"), (0x016F, "Ll"), (0x0170, "Lu"), (0x0171, "Ll"), (0x0172, "Lu"), (0x0173, "Ll"),
+    (0x0174, "Lu"), (0x0175, "Ll"), (0x0176, "Lu"), (0x0177, "Ll"), (0x0178, "Lu"), (0x017A, "Ll"),
+    (0x017B, "Lu"), (0x017C, "Ll"), (0x017D, "Lu"), (0x017E, "Ll"), (0x0181, "Lu"), (0x0183, "Ll"),
+    (0x0184, "Lu"), (0x0185, "Ll"), (0x0186, "Lu"), (0x0188, "Ll"), (0x0189, "Lu"), (0x018C, "Ll"),
+    (0x018E, "Lu"), (0x0192, "Ll"), (0x0193, "Lu"), (0x0195, "Ll"), (0x0196, "Lu"), (0x0199, "Ll"),
+    (0x019C, "Lu"), (0x019E, "Ll"), (0x019F, "Lu"), (0x01A1, "Ll"), (0x01A2, "Lu"), (0x01A3, "Ll"),
+    (0x01A4, "Lu"), (0x01A5, "Ll"), (0x01A6, "Lu"), (0x01A8, "Ll"), (0x01A9, "Lu"), (0x01AA, "Ll"),
+    (0x01AC, "Lu"), (0x01AD, "Ll"), (0x01AE, "Lu"), (0x01B0, "Ll"), (0x01B1, "Lu"), (0x01B4, "Ll"),
+    (0x01B5, "Lu"), (0x01B6, "Ll"), (0x01B7, "Lu"), (0x01B9, "Ll"), (0x01BB, "Lo"), (0x01BC, "Lu"),
+    (0x01BD, "Ll"), (0x01C0, "Lo"), (0x01C4, "Lu"), (0x01C5, "Lt"), (0x01C6, "Ll"), (0x01C7, "Lu"),
+    (0x01C8, "Lt"), (0x01C9, "Ll"), (0x01CA, "Lu"), (0x01CB, "Lt"), (0x01CC, "Ll"), (0x01CD, "Lu"),
+    (0x01CE, "Ll"), (0x01CF, "Lu"), (0x01D0, "Ll"), (0x01D1, "Lu"), (0x01D2, "Ll"), (0x01D3, "Lu"),
+    (0x01D4, "Ll"), (0x01D5, "Lu"), (0x01D6, "Ll"), (0x01D7, "Lu"), (0x01D8, "Ll"), (0x01D9, "Lu"),
+    (0x01DA, "Ll"), (0x01DB, "Lu"), (0x01DC, "Ll"), (0x01DE, "Lu"), (0x01DF, "Ll"), (0x01E0, "Lu"),
+    (0x01E1, "Ll"), (0x01E2, "Lu"), (0x01E3, "Ll"), (0x01E4, "Lu"), (0x01E5, "Ll"), (0x01E6, "Lu"),
+    (0x01E7, "Ll"), (0x01E8, "Lu"), (0x01E9, "Ll"), (0x01EA, "Lu"), (0x01EB, "Ll"), (0x01EC, "Lu"),
+    (0x01ED, "Ll"), (0x01EE, "Lu"), (0x01EF, "Ll"), (0x01F1, "Lu"), (0x01F2, "Lt"), (0x01F3, "Ll"),
+    (0x01F4, "Lu"), (0x01F5, "Ll"), (0x01F6, "Lu"), (0x01F9, "Ll"), (0x01FA, "Lu"), (0x01FB, "Ll"),
+    (0x01FC, "Lu"), (0x01FD, "Ll"), (0x01FE, "Lu"), (0x01FF, "Ll"), (0x0200, "Lu"), (0x0201, "Ll"),
+    (0x0202, "Lu"), (0x0203, "Ll"), (0x0204, "Lu"), (0x0205, "Ll"), (0x0206, "Lu"), (0x0207, "Ll"),
+    (0x0208, "Lu"), (0x0209, "Ll"), (0x020A, "Lu"), (0x020B, "Ll"), (0x020C, "Lu"), (0x020D, "Ll"),
+    (0x020E, "Lu"), (0x020F, "Ll"), (0x0210, "Lu"), (0x0211, "Ll"), (0x0212, "Lu"), (0x0213, "Ll"),
+    (0x0214, "Lu"), (0x0215, "Ll"), (0x0216, "Lu"), (0x0217, "Ll"), (0x0218, "Lu"), (0x0219, "Ll"),
+    (0x021A, "Lu"), (0x021B, "Ll"), (0x021C, "Lu"), (0x021D, "Ll"), (0x021E, "Lu"), (0x021F, "Ll"),
+    (0x0220, "Lu"), (0x0221, "Ll"), (0x0222, "Lu"), (0x0223, "Ll"), (0x0224, "Lu"), (0x0225, "Ll"),
+    (0x0226, "Lu"), (0x0227, "Ll"), (0x0228, "Lu"), (0x0229, "Ll"), (0x022A, "Lu"), (0x022B, "Ll"),
+    (0x022C, "Lu"), (0x022D, "Ll"), (0x022E, "Lu"), (0x022F, "Ll"), (0x0230, "Lu"), (0x0231, "Ll"),
+    (0x0232, "Lu"), (0x0233, "Ll"), (0x023A, "Lu"), (0x023C, "Ll"), (0x023D, "Lu"), (0x023F, "Ll"),
+    (0x0241, "Lu"), (0x0242, "Ll"), (0x0243, "Lu"), (0x0247, "Ll"), (0x0248, "Lu"), (0x0249, "Ll"),
+    (0x024A, "Lu"), (0x024B, "Ll"), (0x024C, "Lu"), (0x024D, "Ll"), (0x024E, "Lu"), (0x024F, "Ll"),
+    (0x0294, "Lo"), (0x0295, "Ll"), (0x02B0, "Lm"), (0x02C2, "Sk"), (0x02C6, "Lm"), (0x02D2, "Sk"),
+    (0x02E0, "Lm"), (0x02E5, "Sk"), (0x02EC, "Lm"), (0x02ED, "Sk"), (0x02EE, "Lm"), (0x02EF, "Sk"),
+    (0x0300, "Mn"), (0x0370, "Lu"), (0x0371, "Ll"), (0x0372, "Lu"), (0x0373, "Ll"), (0x0374, "Lm"),
+    (0x0375, "Sk"), (0x0376, "Lu"), (0x0377, "Ll"), (0x0378, "Cn"), (0x037A, "Lm"), (0x037B, "Ll"),
+    (0x037E, "Po"), (0x037F, "Lu"), (0x0380, "Cn"), (0x0384, "Sk"), (0x0386, "Lu"), (0x0387, "Po"),
+    (0x0388, "Lu"), (0x038B, "Cn"), (0x038C, "Lu"), (0x038D, "Cn"), (0x038E, "Lu"), (0x0390, "Ll"),
+    (0x0391, "Lu"), (0x03A2, "Cn"), (0x03A3, "Lu"), (0x03AC, "Ll"), (0x03CF, "Lu"), (0x03D0, "Ll"),
+    (0x03D2, "Lu"), (0x03D5, "Ll"), (0x03D8, "Lu"), (0x03D9, "Ll"), (0x03DA, "Lu"), (0x03DB, "Ll"),
+    (0x03DC, "Lu"), (0x03DD, "Ll"), (0x03DE, "Lu"), (0x03DF, "Ll"), (0x03E0, "Lu"), (0x03E1, "Ll"),
+    (0x03E2, "Lu"), (0x03E3, "Ll"), (0x03E4, "Lu"), (0x03E5, "Ll"), (0x03E6, "Lu"), (0x03E7, "Ll"),
+    (0x03E8, "Lu"), (0x03E9, "Ll"), (0x03EA, "Lu"), (0x03EB, "Ll"), (0x03EC, "Lu"), (0x03ED, "Ll"),
+    (0x03EE, "Lu"), (0x03EF, "Ll"), (0x03F4, "Lu"), (0x03F5, "Ll"), (0x03F6, "Sm"), (0x03F7, "Lu"),
+    (0x03F8, "Ll"), (0x03F9, "Lu"), (0x03FB, "Ll"), (0x03FD, "Lu"), (0x0430, "Ll"), (0x0460, "Lu"),
+    (0x0461, "Ll"), (0x0462, "Lu"), (0x0463, "Ll"), (0x0464, "Lu"), (0x0465, "Ll"), (0x0466, "Lu"),
+    (0x0467, "Ll"), (0x0468, "Lu"), (0x0469, "Ll"), (0x046A, "Lu"), (0x046B, "Ll"), (0x046C, "Lu"),
+    (0x046D, "Ll"), (0x046E, "Lu"), (0x046F, "Ll"), (0x0470, "Lu"), (0x0471, "Ll"), (0x0472, "Lu"),
+    (0x0473, "Ll"), (0x0474, "Lu"), (0x0475, "Ll"), (0x0476, "Lu"), (0x0477, "Ll"), (0x0478, "Lu"),
+    (0x0479, "Ll"), (0x047A, "Lu"), (0x047B, "Ll"), (0x047C, "Lu"), (0x047D, "Ll"), (0x047E, "Lu"),
+    (0x047F, "Ll"), (0x0480, "Lu"), (0x0481, "Ll"), (0x0482, "So"), (0x0483, "Mn"), (0x0488, "Me"),
+    (0x048A, "Lu"), (0x048B, "Ll"), (0x048C, "Lu"), (0x048D, "Ll"), (0x048E, "Lu"), (0x048F, "Ll"),
+    (0x0490, "Lu"), (0x0491, "Ll"), (0x0492, "Lu"), (0x0493, "Ll"), (0x0494, "Lu"), (0x0495, "Ll"),
+    (0x0496, "Lu"), (0x0497, "Ll"), (0x0498, "Lu"), (0x0499, "Ll"), (0x049A, "Lu"), (0x049B, "Ll"),
+    (0x049C, "Lu"), (0x049D, "Ll"), (0x049E, "Lu"), (0x049F, "Ll"), (0x04A0, "Lu"), (0x04A1, "Ll"),
+    (0x04A2, "Lu"), (0x04A3, "Ll"), (0x04A4, "Lu"), (0x04A5, "Ll"), (0x04A6, "Lu"), (0x04A7, "Ll"),
+    (0x04A8, "Lu"), (0x04A9, "Ll"), (0x04AA, "Lu"), (0x04AB, "Ll"), (0x04AC, "Lu"), (0x04AD, "Ll"),
+    (0x04AE, "Lu"), (0x04AF, "Ll"), (0x04B0, "Lu"), (0x04B1, "Ll"), (0x04B2, "Lu"), (0x04B3, "Ll"),
+    (0x04B4, "Lu"), (0x04B5, "Ll"), (0x04B6, "Lu"), (0x04B7, "Ll"), (0x04B8, "Lu"), (0x04B9, "Ll"),
+    (0x04BA, "Lu"), (0x04BB, "Ll"), (0x04BC, "Lu"), (0x04BD, "Ll"), (0x04BE, "Lu"), (0x04BF, "Ll"),
+    (0x04C0, "Lu"), (0x04C2, "Ll"), (0x04C3, "Lu"), (0x04C4, "Ll"), (0x04C5, "Lu"), (0x04C6, "Ll"),
+    (0x04C7, "Lu"), (0x04C8, "Ll"), (0x04C9, "Lu"), (0x04CA, "Ll"), (0x04CB, "Lu"), (0x04CC, "Ll"),
+    (0x04CD, "Lu"), (0x04CE, "Ll"), (0x04D0, "Lu"), (0x04D1, "Ll"), (0x04D2, "Lu"), (0x04D3, "Ll"),
+    (0x04D4, "Lu"), (0x04D5, "Ll"), (0x04D6, "Lu"), (0x04D7, "Ll"), (0x04D8, "Lu"), (0x04D9, "Ll"),
+    (0x04DA, "Lu"), (0x04DB, "Ll"), (0x04DC, "Lu"), (0x04DD, "Ll"), (0x04DE, "Lu"), (0x04DF, "Ll"),
+    (0x04E0, "Lu"), (0x04E1, "Ll"), (0x04E2, "Lu"), (0x04E3, "Ll"), (0x04E4, "Lu"), (0x04E5, "Ll"),
+    (0x04E6, "Lu"), (0x04E7, "Ll"), (0x04E8, "Lu"), (0x04E9, "Ll"), (0x04EA, "Lu"), (0x04EB, "Ll"),
+    (0x04EC, "Lu"), (0x04ED, "Ll"), (0x04EE, "Lu"), (0x04EF, "Ll"), (0x04F0, "Lu"), (0x04F1, "Ll"),
+    (0x04F2, "Lu"), (0x04F3, "Ll"), (0x04F4, "Lu"), (0x04F5, "Ll"), (0x04F6, "Lu"), (0x04F7, "Ll"),
+    (0x04F8, "Lu"), (0x04F9, "Ll"), (0x04FA, "Lu"), (0x04FB, "Ll"), (0x04FC, "Lu"), (0x04FD, "Ll"),
+    (0x04FE, "Lu"), (0x04FF, "Ll"), (0x0500, "Lu"), (0x0501, "Ll"), (0x0502, "Lu"), (0x0503, "Ll"),
+    (0x0504, "Lu"), (0x0505, "Ll"), (0x0506, "Lu"), (0x0507, "Ll"), (0x0508, "Lu"), (0x0509, "Ll"),
+    (0x050A, "Lu"), (0x050B, "Ll"), (0x050C, "Lu"), (0x050D, "Ll"), (0x050E, "Lu"), (0x050F, "Ll"),
+    (0x0510, "Lu"), (0x0511, "Ll"), (0x0512, "Lu"), (0x0513, "Ll"), (0x0514, "Lu"), (0x0515, "Ll"),
+    (0x0516, "Lu"), (0x0517, "Ll"), (0x0518, "Lu"), (0x0519, "Ll"), (0x051A, "Lu"), (0x051B, "Ll"),
+    (0x051C, "Lu"), (0x051D, "Ll"), (0x051E, "Lu"), (0x051F, "Ll"), (0x0520, "Lu"), (0x0521, "Ll"),
+    (0x0522, "Lu"), (0x0523, "Ll"), (0x0524, "Lu"), (0x0525, "Ll"), (0x0526, "Lu"), (0x0527, "Ll"),
+    (0x0528, "Lu"), (0x0529, "Ll"), (0x052A, "Lu"), (0x052B, "Ll"), (0x052C, "Lu"), (0x052D, "Ll"),
+    (0x052E, "Lu"), (0x052F, "Ll"), (0x0530, "Cn"), (0x0531, "Lu"), (0x0557, "Cn"), (0x0559, "Lm"),
+    (0x055A, "Po"), (0x0560, "Ll"), (0x0589, "Po"), (0x058A, "Pd"), (0x058B, "Cn"), (0x058D, "So"),
+    (0x058F, "Sc"), (0x0590, "Cn"), (0x0591, "Mn"), (0x05BE, "Pd"), (0x05BF, "Mn"), (0x05C0, "Po"),
+    (0x05C1, "Mn"), (0x05C3, "Po"), (0x05C4, "Mn"), (0x05C6, "Po"), (0x05C7, "Mn"), (0x05C8, "Cn"),
+    (0x05D0, "Lo"), (0x05EB, "Cn"), (0x05EF, "Lo"), (0x05F3, "Po"), (0x05F5, "Cn"), (0x0600, "Cf"),
+    (0x0606, "Sm"), (0x0609, "Po"), (0x060B, "Sc"), (0x060C, "Po"), (0x060E, "So"), (0x0610, "Mn"),
+    (0x061B, "Po"), (0x061C, "Cf"), (0x061D, "Po"), (0x0620, "Lo"), (0x0640, "Lm"), (0x0641, "Lo"),
+    (0x064B, "Mn"), (0x0660, "Nd"), (0x066A, "Po"), (0x066E, "Lo"), (0x0670, "Mn"), (0x0671, "Lo"),
+    (0x06D4, "Po"), (0x06D5, "Lo"), (0x06D6, "Mn"), (0x06DD, "Cf"), (0x06DE, "So"), (0x06DF, "Mn"),
+    (0x06E5, "Lm"), (0x06E7, "Mn"), (0x06E9, "So"), (0x06EA, "Mn"), (0x06EE, "Lo"), (0x06F0, "Nd"),
+    (0x06FA, "Lo"), (0x06FD, "So"), (0x06FF, "Lo"), (0x0700, "Po"), (0x070E, "Cn"), (0x070F, "Cf"),
+    (0x0710, "Lo"), (0x0711, "Mn"), (0x0712, "Lo"), (0x0730, "Mn"), (0x074B, "Cn"), (0x074D, "Lo"),
+    (0x07A6, "Mn"), (0x07B1, "Lo"), (0x07B2, "Cn"), (0x07C0, "Nd"), (0x07CA, "Lo"), (0x07EB, "Mn"),
+    (0x07F4, "Lm"), (0x07F6, "So"), (0x07F7, "Po"), (0x07FA, "Lm"), (0x07FB, "Cn"), (0x07FD, "Mn"),
+    (0x07FE, "Sc"), (0x0800, "Lo"), (0x0816, "Mn"), (0x081A, "Lm"), (0x081B, "Mn"), (0x0824, "Lm"),
+    (0x0825, "Mn"), (0x0828, "Lm"), (0x0829, "Mn"), (0x082E, "Cn"), (0x0830, "Po"), (0x083F, "Cn"),
+    (0x0840, "Lo"), (0x0859, "Mn"), (0x085C, "Cn"), (0x085E, "Po"), (0x085F, "Cn"), (0x0860, "Lo"),
+    (0x086B, "Cn"), (0x0870, "Lo"), (0x0888, "Sk"), (0x0889, "Lo"), (0x088F, "Cn"), (0x0890, "Cf"),
+    (0x0892, "Cn"), (0x0897, "Mn"), (0x08A0, "Lo"), (0x08C9, "Lm"), (0x08CA, "Mn"), (0x08E2, "Cf"),
+    (0x08E3, "Mn"), (0x0903, "Mc"), (0x0904, "Lo"), (0x093A, "Mn"), (0x093B, "Mc"), (0x093C, "Mn"),
+    (0x093D, "Lo"), (0x093E, "Mc"), (0x0941, "Mn"), (0x0949, "Mc"), (0x094D, "Mn"), (0x094E, "Mc"),
+    (0x0950, "Lo"), (0x0951, "Mn"), (0x0958, "Lo"), (0x0962, "Mn"), (0x0964, "Po"), (0x0966, "Nd"),
+    (0x0970, "Po"), (0x0971, "Lm"), (0x0972, "Lo"), (0x0981, "Mn"), (0x0982, "Mc"), (0x0984, "Cn"),
+    (0x0985, "Lo"), (0x098D, "Cn"), (0x098F, "Lo"), (0x0991, "Cn"), (0x0993, "Lo"), (0x09A9, "Cn"),
+    (0x09AA, "Lo"), (0x09B1, "Cn"), (0x09B2, "Lo"), (0x09B3, "Cn"), (0x09B6, "Lo"), (0x09BA, "Cn"),
+    (0x09BC, "Mn"), (0x09BD, "Lo"), (0x09BE, "Mc"), (0x09C1, "Mn"), (0x09C5, "Cn"), (0x09C7, "Mc"),
+    (0x09C9, "Cn"), (0x09CB, "Mc"), (0x09CD, "Mn"), (0x09CE, "Lo"), (0x09CF, "Cn"), (0x09D7, "Mc"),
+    (0x09D8, "Cn"), (0x09DC, "Lo"), (0x09DE, "Cn"), (0x09DF, "Lo"), (0x09E2, "Mn"), (0x09E4, "Cn"),
+    (0x09E6, "Nd"), (0x09F0, "Lo"), (0x09F2, "Sc"), (0x09F4, "No"), (0x09FA, "So"), (0x09FB, "Sc"),
+    (0x09FC, "Lo"), (0x09FD, "Po"), (0x09FE, "Mn"), (0x09FF, "Cn"), (0x0A01, "Mn"), (0x0A03, "Mc"),
+    (0x0A04, "Cn"), (0x0A05, "Lo"), (0x0A0B, "Cn"), (0x0A0F, "Lo"), (0x0A11, "Cn"), (0x0A13, "Lo"),
+    (0x0A29, "Cn"), (0x0A2A, "Lo"), (0x0A31, "Cn"), (0x0A32, "Lo"), (0x0A34, "Cn"), (0x0A35, "Lo"),
+    (0x0A37, "Cn"), (0x0A38, "Lo"), (0x0A3A, "Cn"), (0x0A3C, "Mn"), (0x0A3D, "Cn"), (0x0A3E, "Mc"),
+    (0x0A41, "Mn"), (0x0A43, "Cn"), (0x0A47, "Mn"), (0x0A49, "Cn"), (0x0A4B, "Mn"), (0x0A4E, "Cn"),
+    (0x0A51, "Mn"), (0x0A52, "Cn"), (0x0A59, "Lo"), (0x0A5D, "Cn"), (0x0A5E, "Lo"), (0x0A5F, "Cn"),
+    (0x0A66, "Nd"), (0x0A70, "Mn"), (0x0A72, "Lo"), (0x0A75, "Mn"), (0x0A76, "Po"), (0x0A77, "Cn"),
+    (0x0A81, "Mn"), (0x0A83, "Mc"), (0x0A84, "Cn"), (0x0A85, "Lo"), (0x0A8E, "Cn"), (0x0A8F, "Lo"),
+    (0x0A92, "Cn"), (0x0A93, "Lo"), (0x0AA9, "Cn"), (0x0AAA, "Lo"), (0x0AB1, "Cn"), (0x0AB2, "Lo"),
+    (0x0AB4, "Cn"), (0x0AB5, "Lo"), (0x0ABA, "Cn"), (0x0ABC, "Mn"), (0x0ABD, "Lo"), (0x0ABE, "Mc"),
+    (0x0AC1, "Mn"), (0x0AC6, "Cn"), (0x0AC7, "Mn"), (0x0AC9, "Mc"), (0x0ACA, "Cn"), (0x0ACB, "Mc"),
+    (0x0ACD, "Mn"), (0x0ACE, "Cn"), (0x0AD0, "Lo"), (0x0AD1, "Cn"), (0x0AE0, "Lo"), (0x0AE2, "Mn"),
+    (0x0AE4, "Cn"), (0x0AE6, "Nd"), (0x0AF0, "Po"), (0x0AF1, "Sc"), (0x0AF2, "Cn"), (0x0AF9, "Lo"),
+    (0x0AFA, "Mn"), (0x0B00, "Cn"), (0x0B01, "Mn"), (0x0B02, "Mc"), (0x0B04, "Cn"), (0x0B05, "Lo"),
+    (0x0B0D, "Cn"), (0x0B0F, "Lo"), (0x0B11, "Cn"), (0x0B13, "Lo"), (0x0B29, "Cn"), (0x0B2A, "Lo"),
+    (0x0B31, "Cn"), (0x0B32, "Lo"), (0x0B34, "Cn"), (0x0B35, "Lo"), (0x0B3A, "Cn"), (0x0B3C, "Mn"),
+    (0x0B3D, "Lo"), (0x0B3E, "Mc"), (0x0B3F, "Mn"), (0x0B40, "Mc"), (0x0B41, "Mn"), (0x0B45, "Cn"),
+    (0x0B47, "Mc"), (0x0B49, "Cn"), (0x0B4B, "Mc"), (0x0B4D, "Mn"), (0x0B4E, "Cn"), (0x0B55, "Mn"),
+    (0x0B57, "Mc"), (0x0B58, "Cn"), (0x0B5C, "Lo"), (0x0B5E, "Cn"), (0x0B5F, "Lo"), (0x0B62, "Mn"),
+    (0x0B64, "Cn"), (0x0B66, "Nd"), (0x0B70, "So"), (0x0B71, "Lo"), (0x0B72, "No"), (0x0B78, "Cn"),
+    (0x0B82, "Mn"), (0x0B83, "Lo"), (0x0B84, "Cn"), (0x0B85, "Lo"), (0x0B8B, "Cn"), (0x0B8E, "Lo"),
+    (0x0B91, "Cn"), (0x0B92, "Lo"), (0x0B96, "Cn"), (0x0B99, "Lo"), (0x0B9B, "Cn"), (0x0B9C, "Lo"),
+    (0x0B9D, "Cn"), (0x0B9E, "Lo"), (0x0BA0, "Cn"), (0x0BA3, "Lo"), (0x0BA5, "Cn"), (0x0BA8, "Lo"),
+    (0x0BAB, "Cn"), (0x0BAE, "Lo"), (0x0BBA, "Cn"), (0x0BBE, "Mc"), (0x0BC0, "Mn"), (0x0BC1, "Mc"),
+    (0x0BC3, "Cn"), (0x0BC6, "Mc"), (0x0BC9, "Cn"), (0x0BCA, "Mc"), (0x0BCD, "Mn"), (0x0BCE, "Cn"),
+    (0x0BD0, "Lo"), (0x0BD1, "Cn"), (0x0BD7, "Mc"), (0x0BD8, "Cn"), (0x0BE6, "Nd"), (0x0BF0, "No"),
+    (0x0BF3, "So"), (0x0BF9, "Sc"), (0x0BFA, "So"), (0x0BFB, "Cn"), (0x0C00, "Mn"), (0x0C01, "Mc"),
+    (0x0C04, "Mn"), (0x0C05, "Lo"), (0x0C0D, "Cn"), (0x0C0E, "Lo"), (0x0C11, "Cn"), (0x0C12, "Lo"),
+    (0x0C29, "Cn"), (0x0C2A, "Lo"), (0x0C3A, "Cn"), (0x0C3C, "Mn"), (0x0C3D, "Lo"), (0x0C3E, "Mn"),
+    (0x0C41, "Mc"), (0x0C45, "Cn"), (0x0C46, "Mn"), (0x0C49, "Cn"), (0x0C4A, "Mn"), (0x0C4E, "Cn"),
+    (0x0C55, "Mn"), (0x0C57, "Cn"), (0x0C58, "Lo"), (0x0C5B, "Cn"), (0x0C5D, "Lo"), (0x0C5E, "Cn"),
+    (0x0C60, "Lo"), (0x0C62, "Mn"), (0x0C64, "Cn"), (0x0C66, "Nd"), (0x0C70, "Cn"), (0x0C77, "Po"),
+    (0x0C78, "No"), (0x0C7F, "So"), (0x0C80, "Lo"), (0x0C81, "Mn"), (0x0C82, "Mc"), (0x0C84, "Po"),
+    (0x0C85, "Lo"), (0x0C8D, "Cn"), (0x0C8E, "Lo"), (0x0C91, "Cn"), (0x0C92, "Lo"), (0x0CA9, "Cn"),
+    (0x0CAA, "Lo"), (0x0CB4, "Cn"), (0x0CB5, "Lo"), (0x0CBA, "Cn"), (0x0CBC, "Mn"), (0x0CBD, "Lo"),
+    (0x0CBE, "Mc"), (0x0CBF, "Mn"), (0x0CC0, "Mc"), (0x0CC5, "Cn"), (0x0CC6, "Mn"), (0x0CC7, "Mc"),
+    (0x0CC9, "Cn"), (0x0CCA, "Mc"), (0x0CCC, "Mn"), (0x0CCE, "Cn"), (0x0CD5, "Mc"), (0x0CD7, "Cn"),
+    (0x0CDD, "Lo"), (0x0CDF, "Cn"), (0x0CE0, "Lo"), (0x0CE2, "Mn"), (0x0CE4, "Cn"), (0x0CE6, "Nd"),
+    (0x0CF0, "Cn"), (0x0CF1, "Lo"), (0x0CF3, "Mc"), (0x0CF4, "Cn"), (0x0D00, "Mn"), (0x0D02, "Mc"),
+    (0x0D04, "Lo"), (0x0D0D, "Cn"), (0x0D0E, "Lo"), (0x0D11, "Cn"), (0x0D12, "Lo"), (0x0D3B, "Mn"),
+    (0x0D3D, "Lo"), (0x0D3E, "Mc"), (0x0D41, "Mn"), (0x0D45, "Cn"), (0x0D46, "Mc"), (0x0D49, "Cn"),
+    (0x0D4A, "Mc"), (0x0D4D, "Mn"), (0x0D4E, "Lo"), (0x0D4F, "So"), (0x0D50, "Cn"), (0x0D54, "Lo"),
+    (0x0D57, "Mc"), (0x0D58, "No"), (0x0D5F, "Lo"), (0x0D62, "Mn"), (0x0D64, "Cn"), (0x0D66, "Nd"),
+    (0x0D70, "No"), (0x0D79, "So"), (0x0D7A, "Lo"), (0x0D80, "Cn"), (0x0D81, "Mn"), (0x0D82, "Mc"),
+    (0x0D84, "Cn"), (0x0D85, "Lo"), (0x0D97, "Cn"), (0x0D9A, "Lo"), (0x0DB2, "Cn"), (0x0DB3, "Lo"),
+    (0x0DBC, "Cn"), (0x0DBD, "Lo"), (0x0DBE, "Cn"), (0x0DC0, "Lo"), (0x0DC7, "Cn"), (0x0DCA, "Mn"),
+    (0x0DCB, "Cn"), (0x0DCF, "Mc"), (0x0DD2, "Mn"), (0x0DD5, "Cn"), (0x0DD6, "Mn"), (0x0DD7, "Cn"),
+    (0x0DD8, "Mc"), (0x0DE0, "Cn"), (0x0DE6, "Nd"), (0x0DF0, "Cn"), (0x0DF2, "Mc"), (0x0DF4, "Po"),
+    (0x0DF5, "Cn"), (0x0E01, "Lo"), (0x0E31, "Mn"), (0x0E32, "Lo"), (0x0E34, "Mn"), (0x0E3B, "Cn"),
+    (0x0E3F, "Sc"), (0x0E40, "Lo"), (0x0E46, "Lm"), (0x0E47, "Mn"), (0x0E4F, "Po"), (0x0E50, "Nd"),
+    (0x0E5A, "Po"), (0x0E5C, "Cn"), (0x0E81, "Lo"), (0x0E83, "Cn"), (0x0E84, "Lo"), (0x0E85, "Cn"),
+    (0x0E86, "Lo"), (0x0E8B, "Cn"), (0x0E8C, "Lo"), (0x0EA4, "Cn"), (0x0EA5, "Lo"), (0x0EA6, "Cn"),
+    (0x0EA7, "Lo"), (0x0EB1, "Mn"), (0x0EB2, "Lo"), (0x0EB4, "Mn"), (0x0EBD, "Lo"), (0x0EBE, "Cn"),
+    (0x0EC0, "Lo"), (0x0EC5, "Cn"), (0x0EC6, "Lm"), (0x0EC7, "Cn"), (0x0EC8, "Mn"), (0x0ECF, "Cn"),
+    (0x0ED0, "Nd"), (0x0EDA, "Cn"), (0x0EDC, "Lo"), (0x0EE0, "Cn"), (0x0F00, "Lo"), (0x0F01, "So"),
+    (0x0F04, "Po"), (0x0F13, "So"), (0x0F14, "Po"), (0x0F15, "So"), (0x0F18, "Mn"), (0x0F1A, "So"),
+    (0x0F20, "Nd"), (0x0F2A, "No"), (0x0F34, "So"), (0x0F35, "Mn"), (0x0F36, "So"), (0x0F37, "Mn"),
+    (0x0F38, "So"), (0x0F39, "Mn"), (0x0F3A, "Ps"), (0x0F3B, "Pe"), (0x0F3C, "Ps"), (0x0F3D, "Pe"),
+    (0x0F3E, "Mc"), (0x0F40, "Lo"), (0x0F48, "Cn"), (0x0F49, "Lo"), (0x0F6D, "Cn"), (0x0F71, "Mn"),
+    (0x0F7F, "Mc"), (0x0F80, "Mn"), (0x0F85, "Po"), (0x0F86, "Mn"), (0x0F88, "Lo"), (0x0F8D, "Mn"),
+    (0x0F98, "Cn"), (0x0F99, "Mn"), (0x0FBD, "Cn"), (0x0FBE, "So"), (0x0FC6, "Mn"), (0x0FC7, "So"),
+    (0x0FCD, "Cn"), (0x0FCE, "So"), (0x0FD0, "Po"), (0x0FD5, "So"), (0x0FD9, "Po"), (0x0FDB, "Cn"),
+    (0x1000, "Lo"), (0x102B, "Mc"), (0x102D, "Mn"), (0x1031, "Mc"), (0x1032, "Mn"), (0x1038, "Mc"),
+    (0x1039, "Mn"), (0x103B, "Mc"), (0x103D, "Mn"), (0x103F, "Lo"), (0x1040, "Nd"), (0x104A, "Po"),
+    (0x1050, "Lo"), (0x1056, "Mc"), (0x1058, "Mn"), (0x105A, "Lo"), (0x105E, "Mn"), (0x1061, "Lo"),
+    (0x1062, "Mc"), (0x1065, "Lo"), (0x1067, "Mc"), (0x106E, "Lo"), (0x1071, "Mn"), (0x1075, "Lo"),
+    (0x1082, "Mn"), (0x1083, "Mc"), (0x1085, "Mn"), (0x1087, "Mc"), (0x108D, "Mn"), (0x108E, "Lo"),
+    (0x108F, "Mc"), (0x1090, "Nd"), (0x109A, "Mc"), (0x109D, "Mn"), (0x109E, "So"), (0x10A0, "Lu"),
+    (0x10C6, "Cn"), (0x10C7, "Lu"), (0x10C8, "Cn"), (0x10CD, "Lu"), (0x10CE, "Cn"), (0x10D0, "Ll"),
+    (0x10FB, "Po"), (0x10FC, "Lm"), (0x10FD, "Ll"), (0x1100, "Lo"), (0x1249, "Cn"), (0x124A, "Lo"),
+    (0x124E, "Cn"), (0x1250, "Lo"), (0x1257, "Cn"), (0x1258, "Lo"), (0x1259, "Cn"), (0x125A, "Lo"),
+    (0x125E, "Cn"), (0x1260, "Lo"), (0x1289, "Cn"), (0x128A, "Lo"), (0x128E, "Cn"), (0x1290, "Lo"),
+    (0x12B1, "Cn"), (0x12B2, "Lo"), (0x12B6, "Cn"), (0x12B8, "Lo"), (0x12BF, "Cn"), (0x12C0, "Lo"),
+    (0x12C1, "Cn"), (0x12C2, "Lo"), (0x12C6, "Cn"), (0x12C8, "Lo"), (0x12D7, "Cn"), (0x12D8, "Lo"),
+    (0x1311, "Cn"), (0x1312, "Lo"), (0x1316, "Cn"), (0x1318, "Lo"), (0x135B, "Cn"), (0x135D, "Mn"),
+    (0x1360, "Po"), (0x1369, "No"), (0x137D, "Cn"), (0x1380, "Lo"), (0x1390, "So"), (0x139A, "Cn"),
+    (0x13A0, "Lu"), (0x13F6, "Cn"), (0x13F8, "Ll"), (0x13FE, "Cn"), (0x1400, "Pd"), (0x1401, "Lo"),
+    (0x166D, "So"), (0x166E, "Po"), (0x166F, "Lo"), (0x1680, "Zs"), (0x1681, "Lo"), (0x169B, "Ps"),
+    (0x169C, "Pe"), (0x169D, "Cn"), (0x16A0, "Lo"), (0x16EB, "Po"), (0x16EE, "Nl"), (0x16F1, "Lo"),
+    (0x16F9, "Cn"), (0x1700, "Lo"), (0x1712, "Mn"), (0x1715, "Mc"), (0x1716, "Cn"), (0x171F, "Lo"),
+    (0x1732, "Mn"), (0x1734, "Mc"), (0x1735, "Po"), (0x1737, "Cn"), (0x1740, "Lo"), (0x1752, "Mn"),
+    (0x1754, "Cn"), (0x1760, "Lo"), (0x176D, "Cn"), (0x176E, "Lo"), (0x1771, "Cn"), (0x1772, "Mn"),
+    (0x1774, "Cn"), (0x1780, "Lo"), (0x17B4, "Mn"), (0x17B6, "Mc"), (0x17B7, "Mn"), (0x17BE, "Mc"),
+    (0x17C6, "Mn"), (0x17C7, "Mc"), (0x17C9, "Mn"), (0x17D4, "Po"), (0x17D7, "Lm"), (0x17D8, "Po"),
+    (0x17DB, "Sc"), (0x17DC, "Lo"), (0x17DD, "Mn"), (0x17DE, "Cn"), (0x17E0, "Nd"), (0x17EA, "Cn"),
+    (0x17F0, "No"), (0x17FA, "Cn"), (0x1800, "Po"), (0x1806, "Pd"), (0x1807, "Po"), (0x180B, "Mn"),
+    (0x180E, "Cf"), (0x180F, "Mn"), (0x1810, "Nd"), (0x181A, "Cn"), (0x1820, "Lo"), (0x1843, "Lm"),
+    (0x1844, "Lo"), (0x1879, "Cn"), (0x1880, "Lo"), (0x1885, "Mn"), (0x1887, "Lo"), (0x18A9, "Mn"),
+    (0x18AA, "Lo"), (0x18AB, "Cn"), (0x18B0, "Lo"), (0x18F6, "Cn"), (0x1900, "Lo"), (0x191F, "Cn"),
+    (0x1920, "Mn"), (0x1923, "Mc"), (0x1927, "Mn"), (0x1929, "Mc"), (0x192C, "Cn"), (0x1930, "Mc"),
+    (0x1932, "Mn"), (0x1933, "Mc"), (0x1939, "Mn"), (0x193C, "Cn"), (0x1940, "So"), (0x1941, "Cn"),
+    (0x1944, "Po"), (0x1946, "Nd"), (0x1950, "Lo"), (0x196E, "Cn"), (0x1970, "Lo"), (0x1975, "Cn"),
+    (0x1980, "Lo"), (0x19AC, "Cn"), (0x19B0, "Lo"), (0x19CA, "Cn"), (0x19D0, "Nd"), (0x19DA, "No"),
+    (0x19DB, "Cn"), (0x19DE, "So"), (0x1A00, "Lo"), (0x1A17, "Mn"), (0x1A19, "Mc"), (0x1A1B, "Mn"),
+    (0x1A1C, "Cn"), (0x1A1E, "Po"), (0x1A20, "Lo"), (0x1A55, "Mc"), (0x1A56, "Mn"), (0x1A57, "Mc"),
+    (0x1A58, "Mn"), (0x1A5F, "Cn"), (0x1A60, "Mn"), (0x1A61, "Mc"), (0x1A62, "Mn"), (0x1A63, "Mc"),
+    (0x1A65, "Mn"), (0x1A6D, "Mc"), (0x1A73, "Mn"), (0x1A7D, "Cn"), (0x1A7F, "Mn"), (0x1A80, "Nd"),
+    (0x1A8A, "Cn"), (0x1A90, "Nd"), (0x1A9A, "Cn"), (0x1AA0, "Po"), (0x1AA7, "Lm"), (0x1AA8, "Po"),
+    (0x1AAE, "Cn"), (0x1AB0, "Mn"), (0x1ABE, "Me"), (0x1ABF, "Mn"), (0x1ACF, "Cn"), (0x1B00, "Mn"),
+    (0x1B04, "Mc"), (0x1B05, "Lo"), (0x1B34, "Mn"), (0x1B35, "Mc"), (0x1B36, "Mn"), (0x1B3B, "Mc"),
+    (0x1B3C, "Mn"), (0x1B3D, "Mc"), (0x1B42, "Mn"), (0x1B43, "Mc"), (0x1B45, "Lo"), (0x1B4D, "Cn"),
+    (0x1B4E, "Po"), (0x1B50, "Nd"), (0x1B5A, "Po"), (0x1B61, "So"), (0x1B6B, "Mn"), (0x1B74, "So"),
+    (0x1B7D, "Po"), (0x1B80, "Mn"), (0x1B82, "Mc"), (0x1B83, "Lo"), (0x1BA1, "Mc"), (0x1BA2, "Mn"),
+    (0x1BA6, "Mc"), (0x1BA8, "Mn"), (0x1BAA, "Mc"), (0x1BAB, "Mn"), (0x1BAE, "Lo"), (0x1BB0, "Nd"),
+    (0x1BBA, "Lo"), (0x1BE6, "Mn"), (0x1BE7, "Mc"), (0x1BE8, "Mn"), (0x1BEA, "Mc"), (0x1BED, "Mn"),
+    (0x1BEE, "Mc"), (0x1BEF, "Mn"), (0x1BF2, "Mc"), (0x1BF4, "Cn"), (0x1BFC, "Po"), (0x1C00, "Lo"),
+    (0x1C24, "Mc"), (0x1C2C, "Mn"), (0x1C34, "Mc"), (0x1C36, "Mn"), (0x1C38, "Cn"), (0x1C3B, "Po"),
+    (0x1C40, "Nd"), (0x1C4A, "Cn"), (0x1C4D, "Lo"), (0x1C50, "Nd"), (0x1C5A, "Lo"), (0x1C78, "Lm"),
+    (0x1C7E, "Po"), (0x1C80, "Ll"), (0x1C89, "Lu"), (0x1C8A, "Ll"), (0x1C8B, "Cn"), (0x1C90, "Lu"),
+    (0x1CBB, "Cn"), (0x1CBD, "Lu"), (0x1CC0, "Po"), (0x1CC8, "Cn"), (0x1CD0, "Mn"), (0x1CD3, "Po"),
+    (0x1CD4, "Mn"), (0x1CE1, "Mc"), (0x1CE2, "Mn"), (0x1CE9, "Lo"), (0x1CED, "Mn"), (0x1CEE, "Lo"),
+    (0x1CF4, "Mn"), (0x1CF5, "Lo"), (0x1CF7, "Mc"), (0x1CF8, "Mn"), (0x1CFA, "Lo"), (0x1CFB, "Cn"),
+    (0x1D00, "Ll"), (0x1D2C, "Lm"), (0x1D6B, "Ll"), (0x1D78, "Lm"), (0x1D79, "Ll"), (0x1D9B, "Lm"),
+    (0x1DC0, "Mn"), (0x1E00, "Lu"), (0x1E01, "Ll"), (0x1E02, "Lu"), (0x1E03, "Ll"), (0x1E04, "Lu"),
+    (0x1E05, "Ll"), (0x1E06, "Lu"), (0x1E07, "Ll"), (0x1E08, "Lu"), (0x1E09, "Ll"), (0x1E0A, "Lu"),
+    (0x1E0B, "Ll"), (0x1E0C, "Lu"), (0x1E0D, "Ll"), (0x1E0E, "Lu"), (0x1E0F, "Ll"), (0x1E10, "Lu"),
+    (0x1E11, "Ll"), (0x1E12, "Lu"), (0x1E13, "Ll"), (0x1E14, "Lu"), (0x1E15, "Ll"), (0x1E16, "Lu"),
+    (0x1E17, "Ll"), (0x1E18, "Lu"), (0x1E19, "Ll"), (0x1E1A, "Lu"), (0x1E1B, "Ll"), (0x1E1C, "Lu"),
+    (0x1E1D, "Ll"), (0x1E1E, "Lu"), (0x1E1F, "Ll"), (0x1E20, "Lu"), (0x1E21, "Ll"), (0x1E22, "Lu"),
+    (0x1E23, "Ll"), (0x1E24, "Lu"), (0x1E25, "Ll"), (0x1E26, "Lu"), (0x1E27, "Ll"), (0x1E28, "Lu"),
+    (0x1E29, "Ll"), (0x1E2A, "Lu"), (0x1E2B, "Ll"), (0x1E2C, "Lu"), (0x1E2D, "Ll"), (0x1E2E, "Lu"),
+    (0x1E2F, "Ll"), (0x1E30, "Lu"), (0x1E31, "Ll"), (0x1E32, "Lu"), (0x1E33, "Ll"), (0x1E34, "Lu"),
+    (0x1E35, "Ll"), (0x1E36, "Lu"), (0x1E37, "Ll"), (0x1E38, "Lu"), (0x1E39, "Ll"), (0x1E3A, "Lu"),
+    (0x1E3B, "Ll"), (0x1E3C, "Lu"), (0x1E3D, "Ll"), (0x1E3E, "Lu"), (0x1E3F, "Ll"), (0x1E40, "Lu"),
+    (0x1E41, "Ll"), (0x1E42, "Lu"), (0x1E43, "Ll"), (0x1E44, "Lu"), (0x1E45, "Ll"), (0x1E46, "Lu"),
+    (0x1E47, "Ll"), (0x1E48, "Lu"), (0x1E49, "Ll"), (0x1E4A, "Lu"), (0x1E4B, "Ll"), (0x1E4C, "Lu"),
+    (0x1E4D, "Ll"), (0x1E4E, "Lu"), (0x1E4F, "Ll"), (0x1E50, "Lu"), (0x1E51, "Ll"), (0x1E52, "Lu"),
+    (0x1E53, "Ll"), (0x1E54, "Lu"), (0x1E55, "Ll"), (0x1E56, "Lu"), (0x1E57, "Ll"), (0x1E58, "Lu"),
+    (0x1E59, "Ll"), (0x1E5A, "Lu"), (0x1E5B, "Ll"), (0x1E5C, "Lu"), (0x1E5D, "Ll"), (0x1E5E, "Lu"),
+    (0x1E5F, "Ll"), (0x1E60, "Lu"), (0x1E61, "Ll"), (0x1E62, "Lu"), (0x1E63, "Ll"), (0x1E64, "Lu"),
+    (0x1E65, "Ll"), (0x1E66, "Lu"), (0x1E67, "Ll"), (0x1E68, "Lu"), (0x1E69, "Ll"), (0x1E6A, "Lu"),
+    (0x1E6B, "Ll"), (0x1E6C, "Lu"), (0x1E6D, "Ll"), (0x1E6E, "Lu"), (0x1E6F, "Ll"), (0x1E70, "Lu"),
+    (0x1E71, "Ll"), (0x1E72, "Lu"), (0x1E73, "Ll"), (0x1E74, "Lu"), (0x1E75, "Ll"), (0x1E76, "Lu"),
+    (0x1E77, "Ll"), (0x1E78, "Lu"), (0x1E79, "Ll"), (0x1E7A, "Lu"), (0x1E7B, "Ll"), (0x1E7C, "Lu"),
+    (0x1E7D, "Ll"), (0x1E7E, "Lu"), (0x1E7F, "Ll"), (0x1E80, "Lu"), (0x1E81, "Ll"), (0x1E82, "Lu"),
+    (0x1E83, "Ll"), (0x1E84, "Lu"), (0x1E85, "Ll"), (0x1E86, "Lu"), (0x1E87, "Ll"), (0x1E88, "Lu"),
+    (0x1E89, "Ll"), (0x1E8A, "Lu"), (0x1E8B, "Ll"), (0x1E8C, "Lu"), (0x1E8D, "Ll"), (0x1E8E, "Lu"),
+    (0x1E8F, "Ll"), (0x1E90, "Lu"), (0x1E91, "Ll"), (0x1E92, "Lu"), (0x1E93, "Ll"), (0x1E94, "Lu"),
+    (0x1E95, "Ll"), (0x1E9E, "Lu"), (0x1E9F, "Ll"), (0x1EA0, "Lu"), (0x1EA1, "Ll"), (0x1EA2, "Lu"),
+    (0x1EA3, "Ll"), (0x1EA4, "Lu"), (0x1EA5, "Ll"), (0x1EA6, "Lu"), (0x1EA7, "Ll"), (0x1EA8, "Lu"),
+    (0x1EA9, "Ll"), (0x1EAA, "Lu"), (0x1EAB, "Ll"), (0x1EAC, "Lu"), (0x1EAD, "Ll"), (0x1EAE, "Lu"),
+    (0x1EAF, "Ll"), (0x1EB0, "Lu"), (0x1EB1, "Ll"), (0x1EB2, "Lu"), (0x1EB3, "Ll"), (0x1EB4, "Lu"),
+    (0x1EB5, "Ll"), (0x1EB6, "Lu"), (0x1EB7, "Ll"), (0x1EB8, "Lu"), (0x1EB9, "Ll"), (0x1EBA, "Lu"),
+    (0x1EBB, "Ll"), (0x1EBC, "Lu"), (0x1EBD, "Ll"), (0x1EBE, "Lu"), (0x1EBF, "Ll"), (0x1EC0, "Lu"),
+    (0x1EC1, "Ll"), (0x1EC2, "Lu"), (0x1EC3, "Ll"), (0x1EC4, "Lu"), (0x1EC5, "Ll"), (0x1EC6, "Lu"),
+    (0x1EC7, "Ll"), (0x1EC8, "Lu"), (0x1EC9, "Ll"), (0x1ECA, "Lu"), (0x1ECB, "Ll"), (0x1ECC, "Lu"),
+    (0x1ECD, "Ll"), (0x1ECE, "Lu"), (0x1ECF, "Ll"), (0x1ED0, "Lu"), (0x1ED1, "Ll"), (0x1ED2, "Lu"),
+    (0x1ED3, "Ll"), (0x1ED4, "Lu"), (0x1ED5, "Ll"), (0x1ED6, "Lu"), (0x1ED7, "Ll"), (0x1ED8, "Lu"),
+    (0x1ED9, "Ll"), (0x1EDA, "Lu"), (0x1EDB, "Ll"), (0x1EDC, "Lu"), (0x1EDD, "Ll"), (0x1EDE, "Lu"),
+    (0x1EDF, "Ll"), (0x1EE0, "Lu"), (0x1EE1, "Ll"), (0x1EE2, "Lu"), (0x1EE3, "Ll"), (0x1EE4, "Lu"),
+    (0x1EE5, "Ll"), (0x1EE6, "Lu"), (0x1EE7, "Ll"), (0x1EE8, "Lu"), (0x1EE9, "Ll"), (0x1EEA, "Lu"),
+    (0x1EEB, "Ll"), (0x1EEC, "Lu"), (0x1EED, "Ll"), (0x1EEE, "Lu"), (0x1EEF, "Ll"), (0x1EF0, "Lu"),
+    (0x1EF1, "Ll"), (0x1EF2, "Lu"), (0x1EF3, "Ll"), (0x1EF4, "Lu"), (0x1EF5, "Ll"), (0x1EF6, "Lu"),
+    (0x1EF7, "Ll"), (0x1EF8, "Lu"), (0x1EF9, "Ll"), (0x1EFA, "Lu"), (0x1EFB, "Ll"), (0x1EFC, "Lu"),
+    (0x1EFD, "Ll"), (0x1EFE, "Lu"), (0x1EFF, "Ll"), (0x1F08, "Lu"), (0x1F10, "Ll"), (0x1F16, "Cn"),
+    (0x1F18, "Lu"), (0x1F1E, "Cn"), (0x1F20, "Ll"), (0x1F28, "Lu"), (0x1F30, "Ll"), (0x1F38, "Lu"),
+    (0x1F40, "Ll"), (0x1F46, "Cn"), (0x1F48, "Lu"), (0x1F4E, "Cn"), (0x1F50, "Ll"), (0x1F58, "Cn"),
+    (0x1F59, "Lu"), (0x1F5A, "Cn"), (0x1F5B, "Lu"), (0x1F5C, "Cn"), (0x1F5D, "Lu"), (0x1F5E, "Cn"),
+    (0x1F5F, "Lu"), (0x1F60, "Ll"), (0x1F68, "Lu"), (0x1F70, "Ll"), (0x1F7E, "Cn"), (0x1F80, "Ll"),
+    (0x1F88, "Lt"), (0x1F90, "Ll"), (0x1F98, "Lt"), (0x1FA0, "Ll"), (0x1FA8, "Lt"), (0x1FB0, "Ll"),
+    (0x1FB5, "Cn"), (0x1FB6, "Ll"), (0x1FB8, "Lu"), (0x1FBC, "Lt"), (0x1FBD, "Sk"), (0x1FBE, "Ll"),
+    (0x1FBF, "Sk"), (0x1FC2, "Ll"), (0x1FC5, "Cn"), (0x1FC6, "Ll"), (0x1FC8, "Lu"), (0x1FCC, "Lt"),
+    (0x1FCD, "Sk"), (0x1FD0, "Ll"), (0x1FD4, "Cn"), (0x1FD6, "Ll"), (0x1FD8, "Lu"), (0x1FDC, "Cn"),
+    (0x1FDD, "Sk"), (0x1FE0, "Ll"), (0x1FE8, "Lu"), (0x1FED, "Sk"), (0x1FF0, "Cn"), (0x1FF2, "Ll"),
+    (0x1FF5, "Cn"), (0x1FF6, "Ll"), (0x1FF8, "Lu"), (0x1FFC, "Lt"), (0x1FFD, "Sk"), (0x1FFF, "Cn"),
+    (0x2000, "Zs"), (0x200B, "Cf"), (0x2010, "Pd"), (0x2016, "Po"), (0x2018, "Pi"), (0x2019, "Pf"),
+    (0x201A, "Ps"), (0x201B, "Pi"), (0x201D, "Pf"), (0x201E, "Ps"), (0x201F, "Pi"), (0x2020, "Po"),
+    (0x2028, "Zl"), (0x2029, "Zp"), (0x202A, "Cf"), (0x202F, "Zs"), (0x2030, "Po"), (0x2039, "Pi"),
+    (0x203A, "Pf"), (0x203B, "Po"), (0x203F, "Pc"), (0x2041, "Po"), (0x2044, "Sm"), (0x2045, "Ps"),
+    (0x2046, "Pe"), (0x2047, "Po"), (0x2052, "Sm"), (0x2053, "Po"), (0x2054, "Pc"), (0x2055, "Po"),
+    (0x205F, "Zs"), (0x2060, "Cf"), (0x2065, "Cn"), (0x2066, "Cf"), (0x2070, "No"), (0x2071, "Lm"),
+    (0x2072, "Cn"), (0x2074, "No"), (0x207A, "Sm"), (0x207D, "Ps"), (0x207E, "Pe"), (0x207F, "Lm"),
+    (0x2080, "No"), (0x208A, "Sm"), (0x208D, "Ps"), (0x208E, "Pe"), (0x208F, "Cn"), (0x2090, "Lm"),
+    (0x209D, "Cn"), (0x20A0, "Sc"), (0x20C1, "Cn"), (0x20D0, "Mn"), (0x20DD, "Me"), (0x20E1, "Mn"),
+    (0x20E2, "Me"), (0x20E5, "Mn"), (0x20F1, "Cn"), (0x2100, "So"), (0x2102, "Lu"), (0x2103, "So"),
+    (0x2107, "Lu"), (0x2108, "So"), (0x210A, "Ll"), (0x210B, "Lu"), (0x210E, "Ll"), (0x2110, "Lu"),
+    (0x2113, "Ll"), (0x2114, "So"), (0x2115, "Lu"), (0x2116, "So"), (0x2118, "Sm"), (0x2119, "Lu"),
+    (0x211E, "So"), (0x2124, "Lu"), (0x2125, "So"), (0x2126, "Lu"), (0x2127, "So"), (0x2128, "Lu"),
+    (0x2129, "So"), (0x212A, "Lu"), (0x212E, "So"), (0x212F, "Ll"), (0x2130, "Lu"), (0x2134, "Ll"),
+    (0x2135, "Lo"), (0x2139, "Ll"), (0x213A, "So"), (0x213C, "Ll"), (0x213E, "Lu"), (0x2140, "Sm"),
+    (0x2145, "Lu"), (0x2146, "Ll"), (0x214A, "So"), (0x214B, "Sm"), (0x214C, "So"), (0x214E, "Ll"),
+    (0x214F, "So"), (0x2150, "No"), (0x2160, "Nl"), (0x2183, "Lu"), (0x2184, "Ll"), (0x2185, "Nl"),
+    (0x2189, "No"), (0x218A, "So"), (0x218C, "Cn"), (0x2190, "Sm"), (0x2195, "So"), (0x219A, "Sm"),
+    (0x219C, "So"), (0x21A0, "Sm"), (0x21A1, "So"), (0x21A3, "Sm"), (0x21A4, "So"), (0x21A6, "Sm"),
+    (0x21A7, "So"), (0x21AE, "Sm"), (0x21AF, "So"), (0x21CE, "Sm"), (0x21D0, "So"), (0x21D2, "Sm"),
+    (0x21D3, "So"), (0x21D4, "Sm"), (0x21D5, "So"), (0x21F4, "Sm"), (0x2300, "So"), (0x2308, "Ps"),
+    (0x2309, "Pe"), (0x230A, "Ps"), (0x230B, "Pe"), (0x230C, "So"), (0x2320, "Sm"), (0x2322, "So"),
+    (0x2329, "Ps"), (0x232A, "Pe"), (0x232B, "So"), (0x237C, "Sm"), (0x237D, "So"), (0x239B, "Sm"),
+    (0x23B4, "So"), (0x23DC, "Sm"), (0x23E2, "So"), (0x242A, "Cn"), (0x2440, "So"), (0x244B, "Cn"),
+    (0x2460, "No"), (0x249C, "So"), (0x24EA, "No"), (0x2500, "So"), (0x25B7, "Sm"), (0x25B8, "So"),
+    (0x25C1, "Sm"), (0x25C2, "So"), (0x25F8, "Sm"), (0x2600, "So"), (0x266F, "Sm"), (0x2670, "So"),
+    (0x2768, "Ps"), (0x2769, "Pe"), (0x276A, "Ps"), (0x276B, "Pe"), (0x276C, "Ps"), (0x276D, "Pe"),
+    (0x276E, "Ps"), (0x276F, "Pe"), (0x2770, "Ps"), (0x2771, "Pe"), (0x2772, "Ps"), (0x2773, "Pe"),
+    (0x2774, "Ps"), (0x2775, "Pe"), (0x2776, "No"), (0x2794, "So"), (0x27C0, "Sm"), (0x27C5, "Ps"),
+    (0x27C6, "Pe"), (0x27C7, "Sm"), (0x27E6, "Ps"), (0x27E7, "Pe"), (0x27E8, "Ps"), (0x27E9, "Pe"),
+    (0x27EA, "Ps"), (0x27EB, "Pe"), (0x27EC, "Ps"), (0x27ED, "Pe"), (0x27EE, "Ps"), (0x27EF, "Pe"),
+    (0x27F0, "Sm"), (0x2800, "So"), (0x2900, "Sm"), (0x2983, "Ps"), (0x2984, "Pe"), (0x2985, "Ps"),
+    (0x2986, "Pe"), (0x2987, "Ps"), (0x2988, "Pe"), (0x2989, "Ps"), (0x298A, "Pe"), (0x298B, "Ps"),
+    (0x298C, "Pe"), (0x298D, "Ps"), (0x298E, "Pe"), (0x298F, "Ps"), (0x2990, "Pe"), (0x2991, "Ps"),
+    (0x2992, "Pe"), (0x2993, "Ps"), (0x2994, "Pe"), (0x2995, "Ps"), (0x2996, "Pe"), (0x2997, "Ps"),
+    (0x2998, "Pe"), (0x2999, "Sm"), (0x29D8, "Ps"), (0x29D9, "Pe"), (0x29DA, "Ps"), (0x29DB, "Pe"),
+    (0x29DC, "Sm"), (0x29FC, "Ps"), (0x29FD, "Pe"), (0x29FE, "Sm"), (0x2B00, "So"), (0x2B30, "Sm"),
+    (0x2B45, "So"), (0x2B47, "Sm"), (0x2B4D, "So"), (0x2B74, "Cn"), (0x2B76, "So"), (0x2B96, "Cn"),
+    (0x2B97, "So"), (0x2C00, "Lu"), (0x2C30, "Ll"), (0x2C60, "Lu"), (0x2C61, "Ll"), (0x2C62, "Lu"),
+    (0x2C65, "Ll"), (0x2C67, "Lu"), (0x2C68, "Ll"), (0x2C69, "Lu"), (0x2C6A, "Ll"), (0x2C6B, "Lu"),
+    (0x2C6C, "Ll"), (0x2C6D, "Lu"), (0x2C71, "Ll"), (0x2C72, "Lu"), (0x2C73, "Ll"), (0x2C75, "Lu"),
+    (0x2C76, "Ll"), (0x2C7C, "Lm"), (0x2C7E, "Lu"), (0x2C81, "Ll"), (0x2C82, "Lu"), (0x2C83, "Ll"),
+    (0x2C84, "Lu"), (0x2C85, "Ll"), (0x2C86, "Lu"), (0x2C87, "Ll"), (0x2C88, "Lu"), (0x2C89, "Ll"),
+    (0x2C8A, "Lu"), (0x2C8B, "Ll"), (0x2C8C, "Lu"), (0x2C8D, "Ll"), (0x2C8E, "Lu"), (0x2C8F, "Ll"),
+    (0x2C90, "Lu"), (0x2C91, "Ll"), (0x2C92, "Lu"), (0x2C93, "Ll"), (0x2C94, "Lu"), (0x2C95, "Ll"),
+    (0x2C96, "Lu"), (0x2C97, "Ll"), (0x2C98, "Lu"), (0x2C99, "Ll"), (0x2C9A, "Lu"), (0x2C9B, "Ll"),
+    (0x2C9C, "Lu"), (0x2C9D, "Ll"), (0x2C9E, "Lu"), (0x2C9F, "Ll"), (0x2CA0, "Lu"), (0x2CA1, "Ll"),
+    (0x2CA2, "Lu"), (0x2CA3, "Ll"), (0x2CA4, "Lu"), (0x2CA5, "Ll"), (0x2CA6, "Lu"), (0x2CA7, "Ll"),
+    (0x2CA8, "Lu"), (0x2CA9, "Ll"), (0x2CAA, "Lu"), (0x2CAB, "Ll"), (0x2CAC, "Lu"), (0x2CAD, "Ll"),
+    (0x2CAE, "Lu"), (0x2CAF, "Ll"), (0x2CB0, "Lu"), (0x2CB1, "Ll"), (0x2CB2, "Lu"), (0x2CB3, "Ll"),
+    (0x2CB4, "Lu"), (0x2CB5, "Ll"), (0x2CB6, "Lu"), (0x2CB7, "Ll"), (0x2CB8, "Lu"), (0x2CB9, "Ll"),
+    (0x2CBA, "Lu"), (0x2CBB, "Ll"), (0x2CBC, "Lu"), (0x2CBD, "Ll"), (0x2CBE, "Lu"), (0x2CBF, "Ll"),
+    (0x2CC0, "Lu"), (0x2CC1, "Ll"), (0x2CC2, "Lu"), (0x2CC3, "Ll"), (0x2CC4, "Lu"), (0x2CC5, "Ll"),
+    (0x2CC6, "Lu"), (0x2CC7, "Ll"), (0x2CC8, "Lu"), (0x2CC9, "Ll"), (0x2CCA, "Lu"), (0x2CCB, "Ll"),
+    (0x2CCC, "Lu"), (0x2CCD, "Ll"), (0x2CCE, "Lu"), (0x2CCF, "Ll"), (0x2CD0, "Lu"), (0x2CD1, "Ll"),
+    (0x2CD2, "Lu"), (0x2CD3, "Ll"), (0x2CD4, "Lu"), (0x2CD5, "Ll"), (0x2CD6, "Lu"), (0x2CD7, "Ll"),
+    (0x2CD8, "Lu"), (0x2CD9, "Ll"), (0x2CDA, "Lu"), (0x2CDB, "Ll"), (0x2CDC, "Lu"), (0x2CDD, "Ll"),
+    (0x2CDE, "Lu"), (0x2CDF, "Ll"), (0x2CE0, "Lu"), (0x2CE1, "Ll"), (0x2CE2, "Lu"), (0x2CE3, "Ll"),
+    (0x2CE5, "So"), (0x2CEB, "Lu"), (0x2CEC, "Ll"), (0x2CED, "Lu"), (0x2CEE, "Ll"), (0x2CEF, "Mn"),
+    (0x2CF2, "Lu"), (0x2CF3, "Ll"), (0x2CF4, "Cn"), (0x2CF9, "Po"), (0x2CFD, "No"), (0x2CFE, "Po"),
+    (0x2D00, "Ll"), (0x2D26, "Cn"), (0x2D27, "Ll"), (0x2D28, "Cn"), (0x2D2D, "Ll"), (0x2D2E, "Cn"),
+    (0x2D30, "Lo"), (0x2D68, "Cn"), (0x2D6F, "Lm"), (0x2D70, "Po"), (0x2D71, "Cn"), (0x2D7F, "Mn"),
+    (0x2D80, "Lo"), (0x2D97, "Cn"), (0x2DA0, "Lo"), (0x2DA7, "Cn"), (0x2DA8, "Lo"), (0x2DAF, "Cn"),
+    (0x2DB0, "Lo"), (0x2DB7, "Cn"), (0x2DB8, "Lo"), (0x2DBF, "Cn"), (0x2DC0, "Lo"), (0x2DC7, "Cn"),
+    (0x2DC8, "Lo"), (0x2DCF, "Cn"), (0x2DD0, "Lo"), (0x2DD7, "Cn"), (0x2DD8, "Lo"), (0x2DDF, "Cn"),
+    (0x2DE0, "Mn"), (0x2E00, "Po"), (0x2E02, "Pi"), (0x2E03, "Pf"), (0x2E04, "Pi"), (0x2E05, "Pf"),
+    (0x2E06, "Po"), (0x2E09, "Pi"), (0x2E0A, "Pf"), (0x2E0B, "Po"), (0x2E0C, "Pi"), (0x2E0D, "Pf"),
+    (0x2E0E, "Po"), (0x2E17, "Pd"), (0x2E18, "Po"), (0x2E1A, "Pd"), (0x2E1B, "Po"), (0x2E1C, "Pi"),
+    (0x2E1D, "Pf"), (0x2E1E, "Po"), (0x2E20, "Pi"), (0x2E21, "Pf"), (0x2E22, "Ps"), (0x2E23, "Pe"),
+    (0x2E24, "Ps"), (0x2E25, "Pe"), (0x2E26, "Ps"), (0x2E27, "Pe"), (0x2E28, "Ps"), (0x2E29, "Pe"),
+    (0x2E2A, "Po"), (0x2E2F, "Lm"), (0x2E30, "Po"), (0x2E3A, "Pd"), (0x2E3C, "Po"), (0x2E40, "Pd"),
+    (0x2E41, "Po"), (0x2E42, "Ps"), (0x2E43, "Po"), (0x2E50, "So"), (0x2E52, "Po"), (0x2E55, "Ps"),
+    (0x2E56, "Pe"), (0x2E57, "Ps"), (0x2E58, "Pe"), (0x2E59, "Ps"), (0x2E5A, "Pe"), (0x2E5B, "Ps"),
+    (0x2E5C, "Pe"), (0x2E5D, "Pd"), (0x2E5E, "Cn"), (0x2E80, "So"), (0x2E9A, "Cn"), (0x2E9B, "So"),
+    (0x2EF4, "Cn"), (0x2F00, "So"), (0x2FD6, "Cn"), (0x2FF0, "So"), (0x3000, "Zs"), (0x3001, "Po"),
+    (0x3004, "So"), (0x3005, "Lm"), (0x3006, "Lo"), (0x3007, "Nl"), (0x3008, "Ps"), (0x3009, "Pe"),
+    (0x300A, "Ps"), (0x300B, "Pe"), (0x300C, "Ps"), (0x300D, "Pe"), (0x300E, "Ps"), (0x300F, "Pe"),
+    (0x3010, "Ps"), (0x3011, "Pe"), (0x3012, "So"), (0x3014, "Ps"), (0x3015, "Pe"), (0x3016, "Ps"),
+    (0x3017, "Pe"), (0x3018, "Ps"), (0x3019, "Pe"), (0x301A, "Ps"), (0x301B, "Pe"), (0x301C, "Pd"),
+    (0x301D, "Ps"), (0x301E, "Pe"), (0x3020, "So"), (0x3021, "Nl"), (0x302A, "Mn"), (0x302E, "Mc"),
+    (0x3030, "Pd"), (0x3031, "Lm"), (0x3036, "So"), (0x3038, "Nl"), (0x303B, "Lm"), (0x303C, "Lo"),
+    (0x303D, "Po"), (0x303E, "So"), (0x3040, "Cn"), (0x3041, "Lo"), (0x3097, "Cn"), (0x3099, "Mn"),
+    (0x309B, "Sk"), (0x309D, "Lm"), (0x309F, "Lo"), (0x30A0, "Pd"), (0x30A1, "Lo"), (0x30FB, "Po"),
+    (0x30FC, "Lm"), (0x30FF, "Lo"), (0x3100, "Cn"), (0x3105, "Lo"), (0x3130, "Cn"), (0x3131, "Lo"),
+    (0x318F, "Cn"), (0x3190, "So"), (0x3192, "No"), (0x3196, "So"), (0x31A0, "Lo"), (0x31C0, "So"),
+    (0x31E6, "Cn"), (0x31EF, "So"), (0x31F0, "Lo"), (0x3200, "So"), (0x321F, "Cn"), (0x3220, "No"),
+    (0x322A, "So"), (0x3248, "No"), (0x3250, "So"), (0x3251, "No"), (0x3260, "So"), (0x3280, "No"),
+    (0x328A, "So"), (0x32B1, "No"), (0x32C0, "So"), (0x3400, "Lo"), (0x4DC0, "So"), (0x4E00, "Lo"),
+    (0xA015, "Lm"), (0xA016, "Lo"), (0xA48D, "Cn"), (0xA490, "So"), (0xA4C7, "Cn"), (0xA4D0, "Lo"),
+    (0xA4F8, "Lm"), (0xA4FE, "Po"), (0xA500, "Lo"), (0xA60C, "Lm"), (0xA60D, "Po"), (0xA610, "Lo"),
+    (0xA620, "Nd"), (0xA62A, "Lo"), (0xA62C, "Cn"), (0xA640, "Lu"), (0xA641, "Ll"), (0xA642, "Lu"),
+    (0xA643, "Ll"), (0xA644, "Lu"), (0xA645, "Ll"), (0xA646, "Lu"), (0xA647, "Ll"), (0xA648, "Lu"),
+    (0xA649, "Ll"), (0xA64A, "Lu"), (0xA64B, "Ll"), (0xA64C, "Lu"), (0xA64D, "Ll"), (0xA64E, "Lu"),
+    (0xA64F, "Ll"), (0xA650, "Lu"), (0xA651, "Ll"), (0xA652, "Lu"), (0xA653, "Ll"), (0xA654, "Lu"),
+    (0xA655, "Ll"), (0xA656, "Lu"), (0xA657, "Ll"), (0xA658, "Lu"), (0xA659, "Ll"), (0xA65A, "Lu"),
+    (0xA65B, "Ll"), (0xA65C, "Lu"), (0xA65D, "Ll"), (0xA65E, "Lu"), (0xA65F, "Ll"), (0xA660, "Lu"),
+    (0xA661, "Ll"), (0xA662, "Lu"), (0xA663, "Ll"), (0xA664, "Lu"), (0xA665, "Ll"), (0xA666, "Lu"),
+    (0xA667, "Ll"), (0xA668, "Lu"), (0xA669, "Ll"), (0xA66A, "Lu"), (0xA66B, "Ll"), (0xA66C, "Lu"),
+    (0xA66D, "Ll"), (0xA66E, "Lo"), (0xA66F, "Mn"), (0xA670, "Me"), (0xA673, "Po"), (0xA674, "Mn"),
+    (0xA67E, "Po"), (0xA67F, "Lm"), (0xA680, "Lu"), (0xA681, "Ll"), (0xA682, "Lu"), (0xA683, "Ll"),
+    (0xA684, "Lu"), (0xA685, "Ll"), (0xA686, "Lu"), (0xA687, "Ll"), (0xA688, "Lu"), (0xA689, "Ll"),
+    (0xA68A, "Lu"), (0xA68B, "Ll"), (0xA68C, "Lu"), (0xA68D, "Ll"), (0xA68E, "Lu"), (0xA68F, "Ll"),
+    (0xA690, "Lu"), (0xA691, "Ll"), (0xA692, "Lu"), (0xA693, "Ll"), (0xA694, "Lu"), (0xA695, "Ll"),
+    (0xA696, "Lu"), (0xA697, "Ll"), (0xA698, "Lu"), (0xA699, "Ll"), (0xA69A, "Lu"), (0xA69B, "Ll"),
+    (0xA69C, "Lm"), (0xA69E, "Mn"), (0xA6A0, "Lo"), (0xA6E6, "Nl"), (0xA6F0, "Mn"), (0xA6F2, "Po"),
+    (0xA6F8, "Cn"), (0xA700, "Sk"), (0xA717, "Lm"), (0xA720, "Sk"), (0xA722, "Lu"), (0xA723, "Ll"),
+    (0xA724, "Lu"), (0xA725, "Ll"), (0xA726, "Lu"), (0xA727, "Ll"), (0xA728, "Lu"), (0xA729, "Ll"),
+    (0xA72A, "Lu"), (0xA72B, "Ll"), (0xA72C, "Lu"), (0xA72D, "Ll"), (0xA72E, "Lu"), (0xA72F, "Ll"),
+    (0xA732, "Lu"), (0xA733, "Ll"), (0xA734, "Lu"), (0xA735, "Ll"), (0xA736, "Lu"), (0xA737, "Ll"),
+    (0xA738, "Lu"), (0xA739, "Ll"), (0xA73A, "Lu"), (0xA73B, "Ll"), (0xA73C, "Lu"), (0xA73D, "Ll"),
+    (0xA73E, "Lu"), (0xA73F, "Ll"), (0xA740, "Lu"), (0xA741, "Ll"), (0xA742, "Lu"), (0xA743, "Ll"),
+    (0xA744, "Lu"), (0xA745, "Ll"), (0xA746, "Lu"), (0xA747, "Ll"), (0xA748, "Lu"), (0xA749, "Ll"),
+    (0xA74A, "Lu"), (0xA74B, "Ll"), (0xA74C, "Lu"), (0xA74D, "Ll"), (0xA74E, "Lu"), (0xA74F, "Ll"),
+    (0xA750, "Lu"), (0xA751, "Ll"), (0xA752, "Lu"), (0xA753, "Ll"), (0xA754, "Lu"), (0xA755, "Ll"),
+    (0xA756, "Lu"), (0xA757, "Ll"), (0xA758, "Lu"), (0xA759, "Ll"), (0xA75A, "Lu"), (0xA75B, "Ll"),
+    (0xA75C, "Lu"), (0xA75D, "Ll"), (0xA75E, "Lu"), (0xA75F, "Ll"), (0xA760, "Lu"), (0xA761, "Ll"),
+    (0xA762, "Lu"), (0xA763, "Ll"), (0xA764, "Lu"), (0xA765, "Ll"), (0xA766, "Lu"), (0xA767, "Ll"),
+    (0xA768, "Lu"), (0xA769, "Ll"), (0xA76A, "Lu"), (0xA76B, "Ll"), (0xA76C, "Lu"), (0xA76D, "Ll"),
+    (0xA76E, "Lu"), (0xA76F, "Ll"), (0xA770, "Lm"), (0xA771, "Ll"), (0xA779, "Lu"), (0xA77A, "Ll"),
+    (0xA77B, "Lu"), (0xA77C, "Ll"), (0xA77D, "Lu"), (0xA77F, "Ll"), (0xA780, "Lu"), (0xA781, "Ll"),
+    (0xA782, "Lu"), (0xA783, "Ll"), (0xA784, "Lu"), (0xA785, "Ll"), (0xA786, "Lu"), (0xA787, "Ll"),
+    (0xA788, "Lm"), (0xA789, "Sk"), (0xA78B, "Lu"), (0xA78C, "Ll"), (0xA78D, "Lu"), (0xA78E, "Ll"),
+    (0xA78F, "Lo"), (0xA790, "Lu"), (0xA791, "Ll"), (0xA792, "Lu"), (0xA793, "Ll"), (0xA796, "Lu"),
+    (0xA797, "Ll"), (0xA798, "Lu"), (0xA799, "Ll"), (0xA79A, "Lu"), (0xA79B, "Ll"), (0xA79C, "Lu"),
+    (0xA79D, "Ll"), (0xA79E, "Lu"), (0xA79F, "Ll"), (0xA7A0, "Lu"), (0xA7A1, "Ll"), (0xA7A2, "Lu"),
+    (0xA7A3, "Ll"), (0xA7A4, "Lu"), (0xA7A5, "Ll"), (0xA7A6, "Lu"), (0xA7A7, "Ll"), (0xA7A8, "Lu"),
+    (0xA7A9, "Ll"), (0xA7AA, "Lu"), (0xA7AF, "Ll"), (0xA7B0, "Lu"), (0xA7B5, "Ll"), (0xA7B6, "Lu"),
+    (0xA7B7, "Ll"), (0xA7B8, "Lu"), (0xA7B9, "Ll"), (0xA7BA, "Lu"), (0xA7BB, "Ll"), (0xA7BC, "Lu"),
+    (0xA7BD, "Ll"), (0xA7BE, "Lu"), (0xA7BF, "Ll"), (0xA7C0, "Lu"), (0xA7C1, "Ll"), (0xA7C2, "Lu"),
+    (0xA7C3, "Ll"), (0xA7C4, "Lu"), (0xA7C8, "Ll"), (0xA7C9, "Lu"), (0xA7CA, "Ll"), (0xA7CB, "Lu"),
+    (0xA7CD, "Ll"), (0xA7CE, "Cn"), (0xA7D0, "Lu"), (0xA7D1, "Ll"), (0xA7D2, "Cn"), (0xA7D3, "Ll"),
+    (0xA7D4, "Cn"), (0xA7D5, "Ll"), (0xA7D6, "Lu"), (0xA7D7, "Ll"), (0xA7D8, "Lu"), (0xA7D9, "Ll"),
+    (0xA7DA, "Lu"), (0xA7DB, "Ll"), (0xA7DC, "Lu"), (0xA7DD, "Cn"), (0xA7F2, "Lm"), (0xA7F5, "Lu"),
+    (0xA7F6, "Ll"), (0xA7F7, "Lo"), (0xA7F8, "Lm"), (0xA7FA, "Ll"), (0xA7FB, "Lo"), (0xA802, "Mn"),
+    (0xA803, "Lo"), (0xA806, "Mn"), (0xA807, "Lo"), (0xA80B, "Mn"), (0xA80C, "Lo"), (0xA823, "Mc"),
+    (0xA825, "Mn"), (0xA827, "Mc"), (0xA828, "So"), (0xA82C, "Mn"), (0xA82D, "Cn"), (0xA830, "No"),
+    (0xA836, "So"), (0xA838, "Sc"), (0xA839, "So"), (0xA83A, "Cn"), (0xA840, "Lo"), (0xA874, "Po"),
+    (0xA878, "Cn"), (0xA880, "Mc"), (0xA882, "Lo"), (0xA8B4, "Mc"), (0xA8C4, "Mn"), (0xA8C6, "Cn"),
+    (0xA8CE, "Po"), (0xA8D0, "Nd"), (0xA8DA, "Cn"), (0xA8E0, "Mn"), (0xA8F2, "Lo"), (0xA8F8, "Po"),
+    (0xA8FB, "Lo"), (0xA8FC, "Po"), (0xA8FD, "Lo"), (0xA8FF, "Mn"), (0xA900, "Nd"), (0xA90A, "Lo"),
+    (0xA926, "Mn"), (0xA92E, "Po"), (0xA930, "Lo"), (0xA947, "Mn"), (0xA952, "Mc"), (0xA954, "Cn"),
+    (0xA95F, "Po"), (0xA960, "Lo"), (0xA97D, "Cn"), (0xA980, "Mn"), (0xA983, "Mc"), (0xA984, "Lo"),
+    (0xA9B3, "Mn"), (0xA9B4, "Mc"), (0xA9B6, "Mn"), (0xA9BA, "Mc"), (0xA9BC, "Mn"), (0xA9BE, "Mc"),
+    (0xA9C1, "Po"), (0xA9CE, "Cn"), (0xA9CF, "Lm"), (0xA9D0, "Nd"), (0xA9DA, "Cn"), (0xA9DE, "Po"),
+    (0xA9E0, "Lo"), (0xA9E5, "Mn"), (0xA9E6, "Lm"), (0xA9E7, "Lo"), (0xA9F0, "Nd"), (0xA9FA, "Lo"),
+    (0xA9FF, "Cn"), (0xAA00, "Lo"), (0xAA29, "Mn"), (0xAA2F, "Mc"), (0xAA31, "Mn"), (0xAA33, "Mc"),
+    (0xAA35, "Mn"), (0xAA37, "Cn"), (0xAA40, "Lo"), (0xAA43, "Mn"), (0xAA44, "Lo"), (0xAA4C, "Mn"),
+    (0xAA4D, "Mc"), (0xAA4E, "Cn"), (0xAA50, "Nd"), (0xAA5A, "Cn"), (0xAA5C, "Po"), (0xAA60, "Lo"),
+    (0xAA70, "Lm"), (0xAA71, "Lo"), (0xAA77, "So"), (0xAA7A, "Lo"), (0xAA7B, "Mc"), (0xAA7C, "Mn"),
+    (0xAA7D, "Mc"), (0xAA7E, "Lo"), (0xAAB0, "Mn"), (0xAAB1, "Lo"), (0xAAB2, "Mn"), (0xAAB5, "Lo"),
+    (0xAAB7, "Mn"), (0xAAB9, "Lo"), (0xAABE, "Mn"), (0xAAC0, "Lo"), (0xAAC1, "Mn"), (0xAAC2, "Lo"),
+    (0xAAC3, "Cn"), (0xAADB, "Lo"), (0xAADD, "Lm"), (0xAADE, "Po"), (0xAAE0, "Lo"), (0xAAEB, "Mc"),
+    (0xAAEC, "Mn"), (0xAAEE, "Mc"), (0xAAF0, "Po"), (0xAAF2, "Lo"), (0xAAF3, "Lm"), (0xAAF5, "Mc"),
+    (0xAAF6, "Mn"), (0xAAF7, "Cn"), (0xAB01, "Lo"), (0xAB07, "Cn"), (0xAB09, "Lo"), (0xAB0F, "Cn"),
+    (0xAB11, "Lo"), (0xAB17, "Cn"), (0xAB20, "Lo"), (0xAB27, "Cn"), (0xAB28, "Lo"), (0xAB2F, "Cn"),
+    (0xAB30, "Ll"), (0xAB5B, "Sk"), (0xAB5C, "Lm"), (0xAB60, "Ll"), (0xAB69, "Lm"), (0xAB6A, "Sk"),
+    (0xAB6C, "Cn"), (0xAB70, "Ll"), (0xABC0, "Lo"), (0xABE3, "Mc"), (0xABE5, "Mn"), (0xABE6, "Mc"),
+    (0xABE8, "Mn"), (0xABE9, "Mc"), (0xABEB, "Po"), (0xABEC, "Mc"), (0xABED, "Mn"), (0xABEE, "Cn"),
+    (0xABF0, "Nd"), (0xABFA, "Cn"), (0xAC00, "Lo"), (0xD7A4, "Cn"), (0xD7B0, "Lo"), (0xD7C7, "Cn"),
+    (0xD7CB, "Lo"), (0xD7FC, "Cn"), (0xD800, "Cs"), (0xE000, "Co"), (0xF900, "Lo"), (0xFA6E, "Cn"),
+    (0xFA70, "Lo"), (0xFADA, "Cn"), (0xFB00, "Ll"), (0xFB07, "Cn"), (0xFB13, "Ll"), (0xFB18, "Cn"),
+    (0xFB1D, "Lo"), (0xFB1E, "Mn"), (0xFB1F, "Lo"), (0xFB29, "Sm"), (0xFB2A, "Lo"), (0xFB37, "Cn"),
+    (0xFB38, "Lo"), (0xFB3D, "Cn"), (0xFB3E, "Lo"), (0xFB3F, "Cn"), (0xFB40, "Lo"), (0xFB42, "Cn"),
+    (0xFB43, "Lo"), (0xFB45, "Cn"), (0xFB46, "Lo"), (0xFBB2, "Sk"), (0xFBC3, "Cn"), (0xFBD3, "Lo"),
+    (0xFD3E, "Pe"), (0xFD3F, "Ps"), (0xFD40, "So"), (0xFD50, "Lo"), (0xFD90, "Cn"), (0xFD92, "Lo"),
+    (0xFDC8, "Cn"), (0xFDCF, "So"), (0xFDD0, "Cn"), (0xFDF0, "Lo"), (0xFDFC, "Sc"), (0xFDFD, "So"),
+    (0xFE00, "Mn"), (0xFE10, "Po"), (0xFE17, "Ps"), (0xFE18, "Pe"), (0xFE19, "Po"), (0xFE1A, "Cn"),
+    (0xFE20, "Mn"), (0xFE30, "Po"), (0xFE31, "Pd"), (0xFE33, "Pc"), (0xFE35, "Ps"), (0xFE36, "Pe"),
+    (0xFE37, "Ps"), (0xFE38, "Pe"), (0xFE39, "Ps"), (0xFE3A, "Pe"), (0xFE3B, "Ps"), (0xFE3C, "Pe"),
+    (0xFE3D, "Ps"), (0xFE3E, "Pe"), (0xFE3F, "Ps"), (0xFE40, "Pe"), (0xFE41, "Ps"), (0xFE42, "Pe"),
+    (0xFE43, "Ps"), (0xFE44, "Pe"), (0xFE45, "Po"), (0xFE47, "Ps"), (0xFE48, "Pe"), (0xFE49, "Po"),
+    (0xFE4D, "Pc"), (0xFE50, "Po"), (0xFE53, "Cn"), (0xFE54, "Po"), (0xFE58, "Pd"), (0xFE59, "Ps"),
+    (0xFE5A, "Pe"), (0xFE5B, "Ps"), (0xFE5C, "Pe"), (0xFE5D, "Ps"), (0xFE5E, "Pe"), (0xFE5F, "Po"),
+    (0xFE62, "Sm"), (0xFE63, "Pd"), (0xFE64, "Sm"), (0xFE67, "Cn"), (0xFE68, "Po"), (0xFE69, "Sc"),
+    (0xFE6A, "Po"), (0xFE6C, "Cn"), (0xFE70, "Lo"), (0xFE75, "Cn"), (0xFE76, "Lo"), (0xFEFD, "Cn"),
+    (0xFEFF, "Cf"), (0xFF00, "Cn"), (0xFF01, "Po"), (0xFF04, "Sc"), (0xFF05, "Po"), (0xFF08, "Ps"),
+    (0xFF09, "Pe"), (0xFF0A, "Po"), (0xFF0B, "Sm"), (0xFF0C, "Po"), (0xFF0D, "Pd"), (0xFF0E, "Po"),
+    (0xFF10, "Nd"), (0xFF1A, "Po"), (0xFF1C, "Sm"), (0xFF1F, "Po"), (0xFF21, "Lu"), (0xFF3B, "Ps"),
+    (0xFF3C, "Po"), (0xFF3D, "Pe"), (0xFF3E, "Sk"), (0xFF3F, "Pc"), (0xFF40, "Sk"), (0xFF41, "Ll"),
+    (0xFF5B, "Ps"), (0xFF5C, "Sm"), (0xFF5D, "Pe"), (0xFF5E, "Sm"), (0xFF5F, "Ps"), (0xFF60, "Pe"),
+    (0xFF61, "Po"), (0xFF62, "Ps"), (0xFF63, "Pe"), (0xFF64, "Po"), (0xFF66, "Lo"), (0xFF70, "Lm"),
+    (0xFF71, "Lo"), (0xFF9E, "Lm"), (0xFFA0, "Lo"), (0xFFBF, "Cn"), (0xFFC2, "Lo"), (0xFFC8, "Cn"),
+    (0xFFCA, "Lo"), (0xFFD0, "Cn"), (0xFFD2, "Lo"), (0xFFD8, "Cn"), (0xFFDA, "Lo"), (0xFFDD, "Cn"),
+    (0xFFE0, "Sc"), (0xFFE2, "Sm"), (0xFFE3, "Sk"), (0xFFE4, "So"), (0xFFE5, "Sc"), (0xFFE7, "Cn"),
+    (0xFFE8, "So"), (0xFFE9, "Sm"), (0xFFED, "So"), (0xFFEF, "Cn"), (0xFFF9, "Cf"), (0xFFFC, "So"),
+    (0xFFFE, "Cn"), (0x10000, "Lo"), (0x1000C, "Cn"), (0x1000D, "Lo"), (0x10027, "Cn"),
+    (0x10028, "Lo"), (0x1003B, "Cn"), (0x1003C, "Lo"), (0x1003E, "Cn"), (0x1003F, "Lo"),
+    (0x1004E, "Cn"), (0x10050, "Lo"), (0x1005E, "Cn"), (0x10080, "Lo"), (0x100FB, "Cn"),
+    (0x10100, "Po"), (0x10103, "Cn"), (0x10107, "No"), (0x10134, "Cn"), (0x10137, "So"),
+    (0x10140, "Nl"), (0x10175, "No"), (0x10179, "So"), (0x1018A, "No"), (0x1018C, "So"),
+    (0x1018F, "Cn"), (0x10190, "So"), (0x1019D, "Cn"), (0x101A0, "So"), (0x101A1, "Cn"),
+    (0x101D0, "So"), (0x101FD, "Mn"), (0x101FE, "Cn"), (0x10280, "Lo"), (0x1029D, "Cn"),
+    (0x102A0, "Lo"), (0x102D1, "Cn"), (0x102E0, "Mn"), (0x102E1, "No"), (0x102FC, "Cn"),
+    (0x10300, "Lo"), (0x10320, "No"), (0x10324, "Cn"), (0x1032D, "Lo"), (0x10341, "Nl"),
+    (0x10342, "Lo"), (0x1034A, "Nl"), (0x1034B, "Cn"), (0x10350, "Lo"), (0x10376, "Mn"),
+    (0x1037B, "Cn"), (0x10380, "Lo"), (0x1039E, "Cn"), (0x1039F, "Po"), (0x103A0, "Lo"),
+    (0x103C4, "Cn"), (0x103C8, "Lo"), (0x103D0, "Po"), (0x103D1, "Nl"), (0x103D6, "Cn"),
+    (0x10400, "Lu"), (0x10428, "Ll"), (0x10450, "Lo"), (0x1049E, "Cn"), (0x104A0, "Nd"),
+    (0x104AA, "Cn"), (0x104B0, "Lu"), (0x104D4, "Cn"), (0x104D8, "Ll"), (0x104FC, "Cn"),
+    (0x10500, "Lo"), (0x10528, "Cn"), (0x10530, "Lo"), (0x10564, "Cn"), (0x1056F, "Po"),
+    (0x10570, "Lu"), (0x1057B, "Cn"), (0x1057C, "Lu"), (0x1058B, "Cn"), (0x1058C, "Lu"),
+    (0x10593, "Cn"), (0x10594, "Lu"), (0x10596, "Cn"), (0x10597, "Ll"), (0x105A2, "Cn"),
+    (0x105A3, "Ll"), (0x105B2, "Cn"), (0x105B3, "Ll"), (0x105BA, "Cn"), (0x105BB, "Ll"),
+    (0x105BD, "Cn"), (0x105C0, "Lo"), (0x105F4, "Cn"), (0x10600, "Lo"), (0x10737, "Cn"),
+    (0x10740, "Lo"), (0x10756, "Cn"), (0x10760, "Lo"), (0x10768, "Cn"), (0x10780, "Lm"),
+    (0x10786, "Cn"), (0x10787, "Lm"), (0x107B1, "Cn"), (0x107B2, "Lm"), (0x107BB, "Cn"),
+    (0x10800, "Lo"), (0x10806, "Cn"), (0x10808, "Lo"), (0x10809, "Cn"), (0x1080A, "Lo"),
+    (0x10836, "Cn"), (0x10837, "Lo"), (0x10839, "Cn"), (0x1083C, "Lo"), (0x1083D, "Cn"),
+    (0x1083F, "Lo"), (0x10856, "Cn"), (0x10857, "Po"), (0x10858, "No"), (0x10860, "Lo"),
+    (0x10877, "So"), (0x10879, "No"), (0x10880, "Lo"), (0x1089F, "Cn"), (0x108A7, "No"),
+    (0x108B0, "Cn"), (0x108E0, "Lo"), (0x108F3, "Cn"), (0x108F4, "Lo"), (0x108F6, "Cn"),
+    (0x108FB, "No"), (0x10900, "Lo"), (0x10916, "No"), (0x1091C, "Cn"), (0x1091F, "Po"),
+    (0x10920, "Lo"), (0x1093A, "Cn"), (0x1093F, "Po"), (0x10940, "Cn"), (0x10980, "Lo"),
+    (0x109B8, "Cn"), (0x109BC, "No"), (0x109BE, "Lo"), (0x109C0, "No"), (0x109D0, "Cn"),
+    (0x109D2, "No"), (0x10A00, "Lo"), (0x10A01, "Mn"), (0x10A04, "Cn"), (0x10A05, "Mn"),
+    (0x10A07, "Cn"), (0x10A0C, "Mn"), (0x10A10, "Lo"), (0x10A14, "Cn"), (0x10A15, "Lo"),
+    (0x10A18, "Cn"), (0x10A19, "Lo"), (0x10A36, "Cn"), (0x10A38, "Mn"), (0x10A3B, "Cn"),
+    (0x10A3F, "Mn"), (0x10A40, "No"), (0x10A49, "Cn"), (0x10A50, "Po"), (0x10A59, "Cn"),
+    (0x10A60, "Lo"), (0x10A7D, "No"), (0x10A7F, "Po"), (0x10A80, "Lo"), (0x10A9D, "No"),
+    (0x10AA0, "Cn"), (0x10AC0, "Lo"), (0x10AC8, "So"), (0x10AC9, "Lo"), (0x10AE5, "Mn"),
+    (0x10AE7, "Cn"), (0x10AEB, "No"), (0x10AF0, "Po"), (0x10AF7, "Cn"), (0x10B00, "Lo"),
+    (0x10B36, "Cn"), (0x10B39, "Po"), (0x10B40, "Lo"), (0x10B56, "Cn"), (0x10B58, "No"),
+    (0x10B60, "Lo"), (0x10B73, "Cn"), (0x10B78, "No"), (0x10B80, "Lo"), (0x10B92, "Cn"),
+    (0x10B99, "Po"), (0x10B9D, "Cn"), (0x10BA9, "No"), (0x10BB0, "Cn"), (0x10C00, "Lo"),
+    (0x10C49, "Cn"), (0x10C80, "Lu"), (0x10CB3, "Cn"), (0x10CC0, "Ll"), (0x10CF3, "Cn"),
+    (0x10CFA, "No"), (0x10D00, "Lo"), (0x10D24, "Mn"), (0x10D28, "Cn"), (0x10D30, "Nd"),
+    (0x10D3A, "Cn"), (0x10D40, "Nd"), (0x10D4A, "Lo"), (0x10D4E, "Lm"), (0x10D4F, "Lo"),
+    (0x10D50, "Lu"), (0x10D66, "Cn"), (0x10D69, "Mn"), (0x10D6E, "Pd"), (0x10D6F, "Lm"),
+    (0x10D70, "Ll"), (0x10D86, "Cn"), (0x10D8E, "Sm"), (0x10D90, "Cn"), (0x10E60, "No"),
+    (0x10E7F, "Cn"), (0x10E80, "Lo"), (0x10EAA, "Cn"), (0x10EAB, "Mn"), (0x10EAD, "Pd"),
+    (0x10EAE, "Cn"), (0x10EB0, "Lo"), (0x10EB2, "Cn"), (0x10EC2, "Lo"), (0x10EC5, "Cn"),
+    (0x10EFC, "Mn"), (0x10F00, "Lo"), (0x10F1D, "No"), (0x10F27, "Lo"), (0x10F28, "Cn"),
+    (0x10F30, "Lo"), (0x10F46, "Mn"), (0x10F51, "No"), (0x10F55, "Po"), (0x10F5A, "Cn"),
+    (0x10F70, "Lo"), (0x10F82, "Mn"), (0x10F86, "Po"), (0x10F8A, "Cn"), (0x10FB0, "Lo"),
+    (0x10FC5, "No"), (0x10FCC, "Cn"), (0x10FE0, "Lo"), (0x10FF7, "Cn"), (0x11000, "Mc"),
+    (0x11001, "Mn"), (0x11002, "Mc"), (0x11003, "Lo"), (0x11038, "Mn"), (0x11047, "Po"),
+    (0x1104E, "Cn"), (0x11052, "No"), (0x11066, "Nd"), (0x11070, "Mn"), (0x11071, "Lo"),
+    (0x11073, "Mn"), (0x11075, "Lo"), (0x11076, "Cn"), (0x1107F, "Mn"), (0x11082, "Mc"),
+    (0x11083, "Lo"), (0x110B0, "Mc"), (0x110B3, "Mn"), (0x110B7, "Mc"), (0x110B9, "Mn"),
+    (0x110BB, "Po"), (0x110BD, "Cf"), (0x110BE, "Po"), (0x110C2, "Mn"), (0x110C3, "Cn"),
+    (0x110CD, "Cf"), (0x110CE, "Cn"), (0x110D0, "Lo"), (0x110E9, "Cn"), (0x110F0, "Nd"),
+    (0x110FA, "Cn"), (0x11100, "Mn"), (0x11103, "Lo"), (0x11127, "Mn"), (0x1112C, "Mc"),
+    (0x1112D, "Mn"), (0x11135, "Cn"), (0x11136, "Nd"), (0x11140, "Po"), (0x11144, "Lo"),
+    (0x11145, "Mc"), (0x11147, "Lo"), (0x11148, "Cn"), (0x11150, "Lo"), (0x11173, "Mn"),
+    (0x11174, "Po"), (0x11176, "Lo"), (0x11177, "Cn"), (0x11180, "Mn"), (0x11182, "Mc"),
+    (0x11183, "Lo"), (0x111B3, "Mc"), (0x111B6, "Mn"), (0x111BF, "Mc"), (0x111C1, "Lo"),
+    (0x111C5, "Po"), (0x111C9, "Mn"), (0x111CD, "Po"), (0x111CE, "Mc"), (0x111CF, "Mn"),
+    (0x111D0, "Nd"), (0x111DA, "Lo"), (0x111DB, "Po"), (0x111DC, "Lo"), (0x111DD, "Po"),
+    (0x111E0, "Cn"), (0x111E1, "No"), (0x111F5, "Cn"), (0x11200, "Lo"), (0x11212, "Cn"),
+    (0x11213, "Lo"), (0x1122C, "Mc"), (0x1122F, "Mn"), (0x11232, "Mc"), (0x11234, "Mn"),
+    (0x11235, "Mc"), (0x11236, "Mn"), (0x11238, "Po"), (0x1123E, "Mn"), (0x1123F, "Lo"),
+    (0x11241, "Mn"), (0x11242, "Cn"), (0x11280, "Lo"), (0x11287, "Cn"), (0x11288, "Lo"),
+    (0x11289, "Cn"), (0x1128A, "Lo"), (0x1128E, "Cn"), (0x1128F, "Lo"), (0x1129E, "Cn"),
+    (0x1129F, "Lo"), (0x112A9, "Po"), (0x112AA, "Cn"), (0x112B0, "Lo"), (0x112DF, "Mn"),
+    (0x112E0, "Mc"), (0x112E3, "Mn"), (0x112EB, "Cn"), (0x112F0, "Nd"), (0x112FA, "Cn"),
+    (0x11300, "Mn"), (0x11302, "Mc"), (0x11304, "Cn"), (0x11305, "Lo"), (0x1130D, "Cn"),
+    (0x1130F, "Lo"), (0x11311, "Cn"), (0x11313, "Lo"), (0x11329, "Cn"), (0x1132A, "Lo"),
+    (0x11331, "Cn"), (0x11332, "Lo"), (0x11334, "Cn"), (0x11335, "Lo"), (0x1133A, "Cn"),
+    (0x1133B, "Mn"), (0x1133D, "Lo"), (0x1133E, "Mc"), (0x11340, "Mn"), (0x11341, "Mc"),
+    (0x11345, "Cn"), (0x11347, "Mc"), (0x11349, "Cn"), (0x1134B, "Mc"), (0x1134E, "Cn"),
+    (0x11350, "Lo"), (0x11351, "Cn"), (0x11357, "Mc"), (0x11358, "Cn"), (0x1135D, "Lo"),
+    (0x11362, "Mc"), (0x11364, "Cn"), (0x11366, "Mn"), (0x1136D, "Cn"), (0x11370, "Mn"),
+    (0x11375, "Cn"), (0x11380, "Lo"), (0x1138A, "Cn"), (0x1138B, "Lo"), (0x1138C, "Cn"),
+    (0x1138E, "Lo"), (0x1138F, "Cn"), (0x11390, "Lo"), (0x113B6, "Cn"), (0x113B7, "Lo"),
+    (0x113B8, "Mc"), (0x113BB, "Mn"), (0x113C1, "Cn"), (0x113C2, "Mc"), (0x113C3, "Cn"),
+    (0x113C5, "Mc"), (0x113C6, "Cn"), (0x113C7, "Mc"), (0x113CB, "Cn"), (0x113CC, "Mc"),
+    (0x113CE, "Mn"), (0x113CF, "Mc"), (0x113D0, "Mn"), (0x113D1, "Lo"), (0x113D2, "Mn"),
+    (0x113D3, "Lo"), (0x113D4, "Po"), (0x113D6, "Cn"), (0x113D7, "Po"), (0x113D9, "Cn"),
+    (0x113E1, "Mn"), (0x113E3, "Cn"), (0x11400, "Lo"), (0x11435, "Mc"), (0x11438, "Mn"),
+    (0x11440, "Mc"), (0x11442, "Mn"), (0x11445, "Mc"), (0x11446, "Mn"), (0x11447, "Lo"),
+    (0x1144B, "Po"), (0x11450, "Nd"), (0x1145A, "Po"), (0x1145C, "Cn"), (0x1145D, "Po"),
+    (0x1145E, "Mn"), (0x1145F, "Lo"), (0x11462, "Cn"), (0x11480, "Lo"), (0x114B0, "Mc"),
+    (0x114B3, "Mn"), (0x114B9, "Mc"), (0x114BA, "Mn"), (0x114BB, "Mc"), (0x114BF, "Mn"),
+    (0x114C1, "Mc"), (0x114C2, "Mn"), (0x114C4, "Lo"), (0x114C6, "Po"), (0x114C7, "Lo"),
+    (0x114C8, "Cn"), (0x114D0, "Nd"), (0x114DA, "Cn"), (0x11580, "Lo"), (0x115AF, "Mc"),
+    (0x115B2, "Mn"), (0x115B6, "Cn"), (0x115B8, "Mc"), (0x115BC, "Mn"), (0x115BE, "Mc"),
+    (0x115BF, "Mn"), (0x115C1, "Po"), (0x115D8, "Lo"), (0x115DC, "Mn"), (0x115DE, "Cn"),
+    (0x11600, "Lo"), (0x11630, "Mc"), (0x11633, "Mn"), (0x1163B, "Mc"), (0x1163D, "Mn"),
+    (0x1163E, "Mc"), (0x1163F, "Mn"), (0x11641, "Po"), (0x11644, "Lo"), (0x11645, "Cn"),
+    (0x11650, "Nd"), (0x1165A, "Cn"), (0x11660, "Po"), (0x1166D, "Cn"), (0x11680, "Lo"),
+    (0x116AB, "Mn"), (0x116AC, "Mc"), (0x116AD, "Mn"), (0x116AE, "Mc"), (0x116B0, "Mn"),
+    (0x116B6, "Mc"), (0x116B7, "Mn"), (0x116B8, "Lo"), (0x116B9, "Po"), (0x116BA, "Cn"),
+    (0x116C0, "Nd"), (0x116CA, "Cn"), (0x116D0, "Nd"), (0x116E4, "Cn"), (0x11700, "Lo"),
+    (0x1171B, "Cn"), (0x1171D, "Mn"), (0x1171E, "Mc"), (0x1171F, "Mn"), (0x11720, "Mc"),
+    (0x11722, "Mn"), (0x11726, "Mc"), (0x11727, "Mn"), (0x1172C, "Cn"), (0x11730, "Nd"),
+    (0x1173A, "No"), (0x1173C, "Po"), (0x1173F, "So"), (0x11740, "Lo"), (0x11747, "Cn"),
+    (0x11800, "Lo"), (0x1182C, "Mc"), (0x1182F, "Mn"), (0x11838, "Mc"), (0x11839, "Mn"),
+    (0x1183B, "Po"), (0x1183C, "Cn"), (0x118A0, "Lu"), (0x118C0, "Ll"), (0x118E0, "Nd"),
+    (0x118EA, "No"), (0x118F3, "Cn"), (0x118FF, "Lo"), (0x11907, "Cn"), (0x11909, "Lo"),
+    (0x1190A, "Cn"), (0x1190C, "Lo"), (0x11914, "Cn"), (0x11915, "Lo"), (0x11917, "Cn"),
+    (0x11918, "Lo"), (0x11930, "Mc"), (0x11936, "Cn"), (0x11937, "Mc"), (0x11939, "Cn"),
+    (0x1193B, "Mn"), (0x1193D, "Mc"), (0x1193E, "Mn"), (0x1193F, "Lo"), (0x11940, "Mc"),
+    (0x11941, "Lo"), (0x11942, "Mc"), (0x11943, "Mn"), (0x11944, "Po"), (0x11947, "Cn"),
+    (0x11950, "Nd"), (0x1195A, "Cn"), (0x119A0, "Lo"), (0x119A8, "Cn"), (0x119AA, "Lo"),
+    (0x119D1, "Mc"), (0x119D4, "Mn"), (0x119D8, "Cn"), (0x119DA, "Mn"), (0x119DC, "Mc"),
+    (0x119E0, "Mn"), (0x119E1, "Lo"), (0x119E2, "Po"), (0x119E3, "Lo"), (0x119E4, "Mc"),
+    (0x119E5, "Cn"), (0x11A00, "Lo"), (0x11A01, "Mn"), (0x11A0B, "Lo"), (0x11A33, "Mn"),
+    (0x11A39, "Mc"), (0x11A3A, "Lo"), (0x11A3B, "Mn"), (0x11A3F, "Po"), (0x11A47, "Mn"),
+    (0x11A48, "Cn"), (0x11A50, "Lo"), (0x11A51, "Mn"), (0x11A57, "Mc"), (0x11A59, "Mn"),
+    (0x11A5C, "Lo"), (0x11A8A, "Mn"), (0x11A97, "Mc"), (0x11A98, "Mn"), (0x11A9A, "Po"),
+    (0x11A9D, "Lo"), (0x11A9E, "Po"), (0x11AA3, "Cn"), (0x11AB0, "Lo"), (0x11AF9, "Cn"),
+    (0x11B00, "Po"), (0x11B0A, "Cn"), (0x11BC0, "Lo"), (0x11BE1, "Po"), (0x11BE2, "Cn"),
+    (0x11BF0, "Nd"), (0x11BFA, "Cn"), (0x11C00, "Lo"), (0x11C09, "Cn"), (0x11C0A, "Lo"),
+    (0x11C2F, "Mc"), (0x11C30, "Mn"), (0x11C37, "Cn"), (0x11C38, "Mn"), (0x11C3E, "Mc"),
+    (0x11C3F, "Mn"), (0x11C40, "Lo"), (0x11C41, "Po"), (0x11C46, "Cn"), (0x11C50, "Nd"),
+    (0x11C5A, "No"), (0x11C6D, "Cn"), (0x11C70, "Po"), (0x11C72, "Lo"), (0x11C90, "Cn"),
+    (0x11C92, "Mn"), (0x11CA8, "Cn"), (0x11CA9, "Mc"), (0x11CAA, "Mn"), (0x11CB1, "Mc"),
+    (0x11CB2, "Mn"), (0x11CB4, "Mc"), (0x11CB5, "Mn"), (0x11CB7, "Cn"), (0x11D00, "Lo"),
+    (0x11D07, "Cn"), (0x11D08, "Lo"), (0x11D0A, "Cn"), (0x11D0B, "Lo"), (0x11D31, "Mn"),
+    (0x11D37, "Cn"), (0x11D3A, "Mn"), (0x11D3B, "Cn"), (0x11D3C, "Mn"), (0x11D3E, "Cn"),
+    (0x11D3F, "Mn"), (0x11D46, "Lo"), (0x11D47, "Mn"), (0x11D48, "Cn"), (0x11D50, "Nd"),
+    (0x11D5A, "Cn"), (0x11D60, "Lo"), (0x11D66, "Cn"), (0x11D67, "Lo"), (0x11D69, "Cn"),
+    (0x11D6A, "Lo"), (0x11D8A, "Mc"), (0x11D8F, "Cn"), (0x11D90, "Mn"), (0x11D92, "Cn"),
+    (0x11D93, "Mc"), (0x11D95, "Mn"), (0x11D96, "Mc"), (0x11D97, "Mn"), (0x11D98, "Lo"),
+    (0x11D99, "Cn"), (0x11DA0, "Nd"), (0x11DAA, "Cn"), (0x11EE0, "Lo"), (0x11EF3, "Mn"),
+    (0x11EF5, "Mc"), (0x11EF7, "Po"), (0x11EF9, "Cn"), (0x11F00, "Mn"), (0x11F02, "Lo"),
+    (0x11F03, "Mc"), (0x11F04, "Lo"), (0x11F11, "Cn"), (0x11F12, "Lo"), (0x11F34, "Mc"),
+    (0x11F36, "Mn"), (0x11F3B, "Cn"), (0x11F3E, "Mc"), (0x11F40, "Mn"), (0x11F41, "Mc"),
+    (0x11F42, "Mn"), (0x11F43, "Po"), (0x11F50, "Nd"), (0x11F5A, "Mn"), (0x11F5B, "Cn"),
+    (0x11FB0, "Lo"), (0x11FB1, "Cn"), (0x11FC0, "No"), (0x11FD5, "So"), (0x11FDD, "Sc"),
+    (0x11FE1, "So"), (0x11FF2, "Cn"), (0x11FFF, "Po"), (0x12000, "Lo"), (0x1239A, "Cn"),
+    (0x12400, "Nl"), (0x1246F, "Cn"), (0x12470, "Po"), (0x12475, "Cn"), (0x12480, "Lo"),
+    (0x12544, "Cn"), (0x12F90, "Lo"), (0x12FF1, "Po"), (0x12FF3, "Cn"), (0x13000, "Lo"),
+    (0x13430, "Cf"), (0x13440, "Mn"), (0x13441, "Lo"), (0x13447, "Mn"), (0x13456, "Cn"),
+    (0x13460, "Lo"), (0x143FB, "Cn"), (0x14400, "Lo"), (0x14647, "Cn"), (0x16100, "Lo"),
+    (0x1611E, "Mn"), (0x1612A, "Mc"), (0x1612D, "Mn"), (0x16130, "Nd"), (0x1613A, "Cn"),
+    (0x16800, "Lo"), (0x16A39, "Cn"), (0x16A40, "Lo"), (0x16A5F, "Cn"), (0x16A60, "Nd"),
+    (0x16A6A, "Cn"), (0x16A6E, "Po"), (0x16A70, "Lo"), (0x16ABF, "Cn"), (0x16AC0, "Nd"),
+    (0x16ACA, "Cn"), (0x16AD0, "Lo"), (0x16AEE, "Cn"), (0x16AF0, "Mn"), (0x16AF5, "Po"),
+    (0x16AF6, "Cn"), (0x16B00, "Lo"), (0x16B30, "Mn"), (0x16B37, "Po"), (0x16B3C, "So"),
+    (0x16B40, "Lm"), (0x16B44, "Po"), (0x16B45, "So"), (0x16B46, "Cn"), (0x16B50, "Nd"),
+    (0x16B5A, "Cn"), (0x16B5B, "No"), (0x16B62, "Cn"), (0x16B63, "Lo"), (0x16B78, "Cn"),
+    (0x16B7D, "Lo"), (0x16B90, "Cn"), (0x16D40, "Lm"), (0x16D43, "Lo"), (0x16D6B, "Lm"),
+    (0x16D6D, "Po"), (0x16D70, "Nd"), (0x16D7A, "Cn"), (0x16E40, "Lu"), (0x16E60, "Ll"),
+    (0x16E80, "No"), (0x16E97, "Po"), (0x16E9B, "Cn"), (0x16F00, "Lo"), (0x16F4B, "Cn"),
+    (0x16F4F, "Mn"), (0x16F50, "Lo"), (0x16F51, "Mc"), (0x16F88, "Cn"), (0x16F8F, "Mn"),
+    (0x16F93, "Lm"), (0x16FA0, "Cn"), (0x16FE0, "Lm"), (0x16FE2, "Po"), (0x16FE3, "Lm"),
+    (0x16FE4, "Mn"), (0x16FE5, "Cn"), (0x16FF0, "Mc"), (0x16FF2, "Cn"), (0x17000, "Lo"),
+    (0x187F8, "Cn"), (0x18800, "Lo"), (0x18CD6, "Cn"), (0x18CFF, "Lo"), (0x18D09, "Cn"),
+    (0x1AFF0, "Lm"), (0x1AFF4, "Cn"), (0x1AFF5, "Lm"), (0x1AFFC, "Cn"), (0x1AFFD, "Lm"),
+    (0x1AFFF, "Cn"), (0x1B000, "Lo"), (0x1B123, "Cn"), (0x1B132, "Lo"), (0x1B133, "Cn"),
+    (0x1B150, "Lo"), (0x1B153, "Cn"), (0x1B155, "Lo"), (0x1B156, "Cn"), (0x1B164, "Lo"),
+    (0x1B168, "Cn"), (0x1B170, "Lo"), (0x1B2FC, "Cn"), (0x1BC00, "Lo"), (0x1BC6B, "Cn"),
+    (0x1BC70, "Lo"), (0x1BC7D, "Cn"), (0x1BC80, "Lo"), (0x1BC89, "Cn"), (0x1BC90, "Lo"),
+    (0x1BC9A, "Cn"), (0x1BC9C, "So"), (0x1BC9D, "Mn"), (0x1BC9F, "Po"), (0x1BCA0, "Cf"),
+    (0x1BCA4, "Cn"), (0x1CC00, "So"), (0x1CCF0, "Nd"), (0x1CCFA, "Cn"), (0x1CD00, "So"),
+    (0x1CEB4, "Cn"), (0x1CF00, "Mn"), (0x1CF2E, "Cn"), (0x1CF30, "Mn"), (0x1CF47, "Cn"),
+    (0x1CF50, "So"), (0x1CFC4, "Cn"), (0x1D000, "So"), (0x1D0F6, "Cn"), (0x1D100, "So"),
+    (0x1D127, "Cn"), (0x1D129, "So"), (0x1D165, "Mc"), (0x1D167, "Mn"), (0x1D16A, "So"),
+    (0x1D16D, "Mc"), (0x1D173, "Cf"), (0x1D17B, "Mn"), (0x1D183, "So"), (0x1D185, "Mn"),
+    (0x1D18C, "So"), (0x1D1AA, "Mn"), (0x1D1AE, "So"), (0x1D1EB, "Cn"), (0x1D200, "So"),
+    (0x1D242, "Mn"), (0x1D245, "So"), (0x1D246, "Cn"), (0x1D2C0, "No"), (0x1D2D4, "Cn"),
+    (0x1D2E0, "No"), (0x1D2F4, "Cn"), (0x1D300, "So"), (0x1D357, "Cn"), (0x1D360, "No"),
+    (0x1D379, "Cn"), (0x1D400, "Lu"), (0x1D41A, "Ll"), (0x1D434, "Lu"), (0x1D44E, "Ll"),
+    (0x1D455, "Cn"), (0x1D456, "Ll"), (0x1D468, "Lu"), (0x1D482, "Ll"), (0x1D49C, "Lu"),
+    (0x1D49D, "Cn"), (0x1D49E, "Lu"), (0x1D4A0, "Cn"), (0x1D4A2, "Lu"), (0x1D4A3, "Cn"),
+    (0x1D4A5, "Lu"), (0x1D4A7, "Cn"), (0x1D4A9, "Lu"), (0x1D4AD, "Cn"), (0x1D4AE, "Lu"),
+    (0x1D4B6, "Ll"), (0x1D4BA, "Cn"), (0x1D4BB, "Ll"), (0x1D4BC, "Cn"), (0x1D4BD, "Ll"),
+    (0x1D4C4, "Cn"), (0x1D4C5, "Ll"), (0x1D4D0, "Lu"), (0x1D4EA, "Ll"), (0x1D504, "Lu"),
+    (0x1D506, "Cn"), (0x1D507, "Lu"), (0x1D50B, "Cn"), (0x1D50D, "Lu"), (0x1D515, "Cn"),
+    (0x1D516, "Lu"), (0x1D51D, "Cn"), (0x1D51E, "Ll"), (0x1D538, "Lu"), (0x1D53A, "Cn"),
+    (0x1D53B, "Lu"), (0x1D53F, "Cn"), (0x1D540, "Lu"), (0x1D545, "Cn"), (0x1D546, "Lu"),
+    (0x1D547, "Cn"), (0x1D54A, "Lu"), (0x1D551, "Cn"), (0x1D552, "Ll"), (0x1D56C, "Lu"),
+    (0x1D586, "Ll"), (0x1D5A0, "Lu"), (0x1D5BA, "Ll"), (0x1D5D4, "Lu"), (0x1D5EE, "Ll"),
+    (0x1D608, "Lu"), (0x1D622, "Ll"), (0x1D63C, "Lu"), (0x1D656, "Ll"), (0x1D670, "Lu"),
+    (0x1D68A, "Ll"), (0x1D6A6, "Cn"), (0x1D6A8, "Lu"), (0x1D6C1, "Sm"), (0x1D6C2, "Ll"),
+    (0x1D6DB, "Sm"), (0x1D6DC, "Ll"), (0x1D6E2, "Lu"), (0x1D6FB, "Sm"), (0x1D6FC, "Ll"),
+    (0x1D715, "Sm"), (0x1D716, "Ll"), (0x1D71C, "Lu"), (0x1D735, "Sm"), (0x1D736, "Ll"),
+    (0x1D74F, "Sm"), (0x1D750, "Ll"), (0x1D756, "Lu"), (0x1D76F, "Sm"), (0x1D770, "Ll"),
+    (0x1D789, "Sm"), (0x1D78A, "Ll"), (0x1D790, "Lu"), (0x1D7A9, "Sm"), (0x1D7AA, "Ll"),
+    (0x1D7C3, "Sm"), (0x1D7C4, "Ll"), (0x1D7CA, "Lu"), (0x1D7CB, "Ll"), (0x1D7CC, "Cn"),
+    (0x1D7CE, "Nd"), (0x1D800, "So"), (0x1DA00, "Mn"), (0x1DA37, "So"), (0x1DA3B, "Mn"),
+    (0x1DA6D, "So"), (0x1DA75, "Mn"), (0x1DA76, "So"), (0x1DA84, "Mn"), (0x1DA85, "So"),
+    (0x1DA87, "Po"), (0x1DA8C, "Cn"), (0x1DA9B, "Mn"), (0x1DAA0, "Cn"), (0x1DAA1, "Mn"),
+    (0x1DAB0, "Cn"), (0x1DF00, "Ll"), (0x1DF0A, "Lo"), (0x1DF0B, "Ll"), (0x1DF1F, "Cn"),
+    (0x1DF25, "Ll"), (0x1DF2B, "Cn"), (0x1E000, "Mn"), (0x1E007, "Cn"), (0x1E008, "Mn"),
+    (0x1E019, "Cn"), (0x1E01B, "Mn"), (0x1E022, "Cn"), (0x1E023, "Mn"), (0x1E025, "Cn"),
+    (0x1E026, "Mn"), (0x1E02B, "Cn"), (0x1E030, "Lm"), (0x1E06E, "Cn"), (0x1E08F, "Mn"),
+    (0x1E090, "Cn"), (0x1E100, "Lo"), (0x1E12D, "Cn"), (0x1E130, "Mn"), (0x1E137, "Lm"),
+    (0x1E13E, "Cn"), (0x1E140, "Nd"), (0x1E14A, "Cn"), (0x1E14E, "Lo"), (0x1E14F, "So"),
+    (0x1E150, "Cn"), (0x1E290, "Lo"), (0x1E2AE, "Mn"), (0x1E2AF, "Cn"), (0x1E2C0, "Lo"),
+    (0x1E2EC, "Mn"), (0x1E2F0, "Nd"), (0x1E2FA, "Cn"), (0x1E2FF, "Sc"), (0x1E300, "Cn"),
+    (0x1E4D0, "Lo"), (0x1E4EB, "Lm"), (0x1E4EC, "Mn"), (0x1E4F0, "Nd"), (0x1E4FA, "Cn"),
+    (0x1E5D0, "Lo"), (0x1E5EE, "Mn"), (0x1E5F0, "Lo"), (0x1E5F1, "Nd"), (0x1E5FB, "Cn"),
+    (0x1E5FF, "Po"), (0x1E600, "Cn"), (0x1E7E0, "Lo"), (0x1E7E7, "Cn"), (0x1E7E8, "Lo"),
+    (0x1E7EC, "Cn"), (0x1E7ED, "Lo"), (0x1E7EF, "Cn"), (0x1E7F0, "Lo"), (0x1E7FF, "Cn"),
+    (0x1E800, "Lo"), (0x1E8C5, "Cn"), (0x1E8C7, "No"), (0x1E8D0, "Mn"), (0x1E8D7, "Cn"),
+    (0x1E900, "Lu"), (0x1E922, "Ll"), (0x1E944, "Mn"), (0x1E94B, "Lm"), (0x1E94C, "Cn"),
+    (0x1E950, "Nd"), (0x1E95A, "Cn"), (0x1E95E, "Po"), (0x1E960, "Cn"), (0x1EC71, "No"),
+    (0x1ECAC, "So"), (0x1ECAD, "No"), (0x1ECB0, "Sc"), (0x1ECB1, "No"), (0x1ECB5, "Cn"),
+    (0x1ED01, "No"), (0x1ED2E, "So"), (0x1ED2F, "No"), (0x1ED3E, "Cn"), (0x1EE00, "Lo"),
+    (0x1EE04, "Cn"), (0x1EE05, "Lo"), (0x1EE20, "Cn"), (0x1EE21, "Lo"), (0x1EE23, "Cn"),
+    (0x1EE24, "Lo"), (0x1EE25, "Cn"), (0x1EE27, "Lo"), (0x1EE28, "Cn"), (0x1EE29, "Lo"),
+    (0x1EE33, "Cn"), (0x1EE34, "Lo"), (0x1EE38, "Cn"), (0x1EE39, "Lo"), (0x1EE3A, "Cn"),
+    (0x1EE3B, "Lo"), (0x1EE3C, "Cn"), (0x1EE42, "Lo"), (0x1EE43, "Cn"), (0x1EE47, "Lo"),
+    (0x1EE48, "Cn"), (0x1EE49, "Lo"), (0x1EE4A, "Cn"), (0x1EE4B, "Lo"), (0x1EE4C, "Cn"),
+    (0x1EE4D, "Lo"), (0x1EE50, "Cn"), (0x1EE51, "Lo"), (0x1EE53, "Cn"), (0x1EE54, "Lo"),
+    (0x1EE55, "Cn"), (0x1EE57, "Lo"), (0x1EE58, "Cn"), (0x1EE59, "Lo"), (0x1EE5A, "Cn"),
+    (0x1EE5B, "Lo"), (0x1EE5C, "Cn"), (0x1EE5D, "Lo"), (0x1EE5E, "Cn"), (0x1EE5F, "Lo"),
+    (0x1EE60, "Cn"), (0x1EE61, "Lo"), (0x1EE63, "Cn"), (0x1EE64, "Lo"), (0x1EE65, "Cn"),
+    (0x1EE67, "Lo"), (0x1EE6B, "Cn"), (0x1EE6C, "Lo"), (0x1EE73, "Cn"), (0x1EE74, "Lo"),
+    (0x1EE78, "Cn"), (0x1EE79, "Lo"), (0x1EE7D, "Cn"), (0x1EE7E, "Lo"), (0x1EE7F, "Cn"),
+    (0x1EE80, "Lo"), (0x1EE8A, "Cn"), (0x1EE8B, "Lo"), (0x1EE9C, "Cn"), (0x1EEA1, "Lo"),
+    (0x1EEA4, "Cn"), (0x1EEA5, "Lo"), (0x1EEAA, "Cn"), (0x1EEAB, "Lo"), (0x1EEBC, "Cn"),
+    (0x1EEF0, "Sm"), (0x1EEF2, "Cn"), (0x1F000, "So"), (0x1F02C, "Cn"), (0x1F030, "So"),
+    (0x1F094, "Cn"), (0x1F0A0, "So"), (0x1F0AF, "Cn"), (0x1F0B1, "So"), (0x1F0C0, "Cn"),
+    (0x1F0C1, "So"), (0x1F0D0, "Cn"), (0x1F0D1, "So"), (0x1F0F6, "Cn"), (0x1F100, "No"),
+    (0x1F10D, "So"), (0x1F1AE, "Cn"), (0x1F1E6, "So"), (0x1F203, "Cn"), (0x1F210, "So"),
+    (0x1F23C, "Cn"), (0x1F240, "So"), (0x1F249, "Cn"), (0x1F250, "So"), (0x1F252, "Cn"),
+    (0x1F260, "So"), (0x1F266, "Cn"), (0x1F300, "So"), (0x1F3FB, "Sk"), (0x1F400, "So"),
+    (0x1F6D8, "Cn"), (0x1F6DC, "So"), (0x1F6ED, "Cn"), (0x1F6F0, "So"), (0x1F6FD, "Cn"),
+    (0x1F700, "So"), (0x1F777, "Cn"), (0x1F77B, "So"), (0x1F7DA, "Cn"), (0x1F7E0, "So"),
+    (0x1F7EC, "Cn"), (0x1F7F0, "So"), (0x1F7F1, "Cn"), (0x1F800, "So"), (0x1F80C, "Cn"),
+    (0x1F810, "So"), (0x1F848, "Cn"), (0x1F850, "So"), (0x1F85A, "Cn"), (0x1F860, "So"),
+    (0x1F888, "Cn"), (0x1F890, "So"), (0x1F8AE, "Cn"), (0x1F8B0, "So"), (0x1F8BC, "Cn"),
+    (0x1F8C0, "So"), (0x1F8C2, "Cn"), (0x1F900, "So"), (0x1FA54, "Cn"), (0x1FA60, "So"),
+    (0x1FA6E, "Cn"), (0x1FA70, "So"), (0x1FA7D, "Cn"), (0x1FA80, "So"), (0x1FA8A, "Cn"),
+    (0x1FA8F, "So"), (0x1FAC7, "Cn"), (0x1FACE, "So"), (0x1FADD, "Cn"), (0x1FADF, "So"),
+    (0x1FAEA, "Cn"), (0x1FAF0, "So"), (0x1FAF9, "Cn"), (0x1FB00, "So"), (0x1FB93, "Cn"),
+    (0x1FB94, "So"), (0x1FBF0, "Nd"), (0x1FBFA, "Cn"), (0x20000, "Lo"), (0x2A6E0, "Cn"),
+    (0x2A700, "Lo"), (0x2B73A, "Cn"), (0x2B740, "Lo"), (0x2B81E, "Cn"), (0x2B820, "Lo"),
+    (0x2CEA2, "Cn"), (0x2CEB0, "Lo"), (0x2EBE1, "Cn"), (0x2EBF0, "Lo"), (0x2EE5E, "Cn"),
+    (0x2F800, "Lo"), (0x2FA1E, "Cn"), (0x30000, "Lo"), (0x3134B, "Cn"), (0x31350, "Lo"),
+    (0x323B0, "Cn"), (0xE0001, "Cf"), (0xE0002, "Cn"), (0xE0020, "Cf"), (0xE0080, "Cn"),
+    (0xE0100, "Mn"), (0xE01F0, "Cn"), (0xF0000, "Co"), (0xFFFFE, "Cn"), (0x100000, "Co"),
+    (0x10FFFE, "Cn"),
+];
+
+/// Character names, as runs of consecutive codepoints starting at the given
+/// one (empty strings are gaps)
+const CHARACTER_NAMES: &[(u32, &[&str])] = &[
+    (0x0020, &[
+        "SPACE", "EXCLAMATION MARK", "QUOTATION MARK", "NUMBER SIGN", "DOLLAR SIGN", "PERCENT SIGN",
+        "AMPERSAND", "APOSTROPHE", "LEFT PARENTHESIS", "RIGHT PARENTHESIS", "ASTERISK", "PLUS SIGN",
+        "COMMA", "HYPHEN-MINUS", "FULL STOP", "SOLIDUS", "DIGIT ZERO", "DIGIT ONE", "DIGIT TWO",
+        "DIGIT THREE", "DIGIT FOUR", "DIGIT FIVE", "DIGIT SIX", "DIGIT SEVEN", "DIGIT EIGHT",
+        "DIGIT NINE", "COLON", "SEMICOLON", "LESS-THAN SIGN", "EQUALS SIGN", "GREATER-THAN SIGN",
+        "QUESTION MARK", "COMMERCIAL AT", "LATIN CAPITAL LETTER A", "LATIN CAPITAL LETTER B",
+        "LATIN CAPITAL LETTER C", "LATIN CAPITAL LETTER D", "LATIN CAPITAL LETTER E",
+        "LATIN CAPITAL LETTER F", "LATIN CAPITAL LETTER G", "LATIN CAPITAL LETTER H",
+        "LATIN CAPITAL LETTER I", "LATIN CAPITAL LETTER J", "LATIN CAPITAL LETTER K",
+        "LATIN CAPITAL LETTER L", "LATIN CAPITAL LETTER M", "LATIN CAPITAL LETTER N",
+        "LATIN CAPITAL LETTER O", "LATIN CAPITAL LETTER P", "LATIN CAPITAL LETTER Q",
+        "LATIN CAPITAL LETTER R", "LATIN CAPITAL LETTER S", "LATIN CAPITAL LETTER T",
+        "LATIN CAPITAL LETTER U", "LATIN CAPITAL LETTER V", "LATIN CAPITAL LETTER W",
+        "LATIN CAPITAL LETTER X", "LATIN CAPITAL LETTER Y", "LATIN CAPITAL LETTER Z",
+        "LEFT SQUARE BRACKET", "REVERSE SOLIDUS", "RIGHT SQUARE BRACKET", "CIRCUMFLEX ACCENT",
+        "LOW LINE", "GRAVE ACCENT", "LATIN SMALL LETTER A", "LATIN SMALL LETTER B",
+        "LATIN SMALL LETTER C", "LATIN SMALL LETTER D", "LATIN SMALL LETTER E",
+        "LATIN SMALL LETTER F", "LATIN SMALL LETTER G", "LATIN SMALL LETTER H",
+        "LATIN SMALL LETTER I", "LATIN SMALL LETTER J", "LATIN SMALL LETTER K",
+        "LATIN SMALL LETTER L", "LATIN SMALL LETTER M", "LATIN SMALL LETTER N",
+        "LATIN SMALL LETTER O", "LATIN SMALL LETTER P", "LATIN SMALL LETTER Q",
+        "LATIN SMALL LETTER R", "LATIN SMALL LETTER S", "LATIN SMALL LETTER T",
+        "LATIN SMALL LETTER U", "LATIN SMALL LETTER V", "LATIN SMALL LETTER W",
+        "LATIN SMALL LETTER X", "LATIN SMALL LETTER Y", "LATIN SMALL LETTER Z",
+        "LEFT CURLY BRACKET", "VERTICAL LINE", "RIGHT CURLY BRACKET", "TILDE",
+    ]),
+    (0x00A0, &[
+        "NO-BREAK SPACE", "INVERTED EXCLAMATION MARK", "CENT SIGN", "POUND SIGN", "CURRENCY SIGN",
+        "YEN SIGN", "BROKEN BAR", "SECTION SIGN", "DIAERESIS", "COPYRIGHT SIGN",
+        "FEMININE ORDINAL INDICATOR", "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK", "NOT SIGN",
+        "SOFT HYPHEN", "REGISTERED SIGN", "MACRON", "DEGREE SIGN", "PLUS-MINUS SIGN",
+        "SUPERSCRIPT TWO", "SUPERSCRIPT THREE", "ACUTE ACCENT", "MICRO SIGN", "PILCROW SIGN",
+        "MIDDLE DOT", "CEDILLA", "SUPERSCRIPT ONE", "MASCULINE ORDINAL INDICATOR",
+        "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK", "VULGAR FRACTION ONE QUARTER",
+        "VULGAR FRACTION ONE HALF", "VULGAR FRACTION THREE QUARTERS", "INVERTED QUESTION MARK",
+        "LATIN CAPITAL LETTER A WITH GRAVE", "LATIN CAPITAL LETTER A WITH ACUTE",
+        "LATIN CAPITAL LETTER A WITH CIRCUMFLEX", "LATIN CAPITAL LETTER A WITH TILDE",
+        "LATIN CAPITAL LETTER A WITH DIAERESIS", "LATIN CAPITAL LETTER A WITH RING ABOVE",
+        "LATIN CAPITAL LETTER AE", "LATIN CAPITAL LETTER C WITH CEDILLA",
+        "LATIN CAPITAL LETTER E WITH GRAVE", "LATIN CAPITAL LETTER E WITH ACUTE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX", "LATIN CAPITAL LETTER E WITH DIAERESIS",
+        "LATIN CAPITAL LETTER I WITH GRAVE", "LATIN CAPITAL LETTER I WITH ACUTE",
+        "LATIN CAPITAL LETTER I WITH CIRCUMFLEX", "LATIN CAPITAL LETTER I WITH DIAERESIS",
+        "LATIN CAPITAL LETTER ETH", "LATIN CAPITAL LETTER N WITH TILDE",
+        "LATIN CAPITAL LETTER O WITH GRAVE", "LATIN CAPITAL LETTER O WITH ACUTE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX", "LATIN CAPITAL LETTER O WITH TILDE",
+        "LATIN CAPITAL LETTER O WITH DIAERESIS", "MULTIPLICATION SIGN",
+        "LATIN CAPITAL LETTER O WITH STROKE", "LATIN CAPITAL LETTER U WITH GRAVE",
+        "LATIN CAPITAL LETTER U WITH ACUTE", "LATIN CAPITAL LETTER U WITH CIRCUMFLEX",
+        "LATIN CAPITAL LETTER U WITH DIAERESIS", "LATIN CAPITAL LETTER Y WITH ACUTE",
+        "LATIN CAPITAL LETTER THORN", "LATIN SMALL LETTER SHARP S",
+        "LATIN SMALL LETTER A WITH GRAVE", "LATIN SMALL LETTER A WITH ACUTE",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX", "LATIN SMALL LETTER A WITH TILDE",
+        "LATIN SMALL LETTER A WITH DIAERESIS", "LATIN SMALL LETTER A WITH RING ABOVE",
+        "LATIN SMALL LETTER AE", "LATIN SMALL LETTER C WITH CEDILLA",
+        "LATIN SMALL LETTER E WITH GRAVE", "LATIN SMALL LETTER E WITH ACUTE",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX", "LATIN SMALL LETTER E WITH DIAERESIS",
+        "LATIN SMALL LETTER I WITH GRAVE", "LATIN SMALL LETTER I WITH ACUTE",
+        "LATIN SMALL LETTER I WITH CIRCUMFLEX", "LATIN SMALL LETTER I WITH DIAERESIS",
+        "LATIN SMALL LETTER ETH", "LATIN SMALL LETTER N WITH TILDE",
+        "LATIN SMALL LETTER O WITH GRAVE", "LATIN SMALL LETTER O WITH ACUTE",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX", "LATIN SMALL LETTER O WITH TILDE",
+        "LATIN SMALL LETTER O WITH DIAERESIS", "DIVISION SIGN", "LATIN SMALL LETTER O WITH STROKE",
+        "LATIN SMALL LETTER U WITH GRAVE", "LATIN SMALL LETTER U WITH ACUTE",
+        "LATIN SMALL LETTER U WITH CIRCUMFLEX", "LATIN SMALL LETTER U WITH DIAERESIS",
+        "LATIN SMALL LETTER Y WITH ACUTE", "LATIN SMALL LETTER THORN",
+        "LATIN SMALL LETTER Y WITH DIAERESIS", "LATIN CAPITAL LETTER A WITH MACRON",
+        "LATIN SMALL LETTER A WITH MACRON", "LATIN CAPITAL LETTER A WITH BREVE",
+        "LATIN SMALL LETTER A WITH BREVE", "LATIN CAPITAL LETTER A WITH OGONEK",
+        "LATIN SMALL LETTER A WITH OGONEK", "LATIN CAPITAL LETTER C WITH ACUTE",
+        "LATIN SMALL LETTER C WITH ACUTE", "LATIN CAPITAL LETTER C WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER C WITH CIRCUMFLEX", "LATIN CAPITAL LETTER C WITH DOT ABOVE",
+        "LATIN SMALL LETTER C WITH DOT ABOVE", "LATIN CAPITAL LETTER C WITH CARON",
+        "LATIN SMALL LETTER C WITH CARON", "LATIN CAPITAL LETTER D WITH CARON",
+        "LATIN SMALL LETTER D WITH CARON", "LATIN CAPITAL LETTER D WITH STROKE",
+        "LATIN SMALL LETTER D WITH STROKE", "LATIN CAPITAL LETTER E WITH MACRON",
+        "LATIN SMALL LETTER E WITH MACRON", "LATIN CAPITAL LETTER E WITH BREVE",
+        "LATIN SMALL LETTER E WITH BREVE", "LATIN CAPITAL LETTER E WITH DOT ABOVE",
+        "LATIN SMALL LETTER E WITH DOT ABOVE", "LATIN CAPITAL LETTER E WITH OGONEK",
+        "LATIN SMALL LETTER E WITH OGONEK", "LATIN CAPITAL LETTER E WITH CARON",
+        "LATIN SMALL LETTER E WITH CARON", "LATIN CAPITAL LETTER G WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER G WITH CIRCUMFLEX", "LATIN CAPITAL LETTER G WITH BREVE",
+        "LATIN SMALL LETTER G WITH BREVE", "LATIN CAPITAL LETTER G WITH DOT ABOVE",
+        "LATIN SMALL LETTER G WITH DOT ABOVE", "LATIN CAPITAL LETTER G WITH CEDILLA",
+        "LATIN SMALL LETTER G WITH CEDILLA", "LATIN CAPITAL LETTER H WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER H WITH CIRCUMFLEX", "LATIN CAPITAL LETTER H WITH STROKE",
+        "LATIN SMALL LETTER H WITH STROKE", "LATIN CAPITAL LETTER I WITH TILDE",
+        "LATIN SMALL LETTER I WITH TILDE", "LATIN CAPITAL LETTER I WITH MACRON",
+        "LATIN SMALL LETTER I WITH MACRON", "LATIN CAPITAL LETTER I WITH BREVE",
+        "LATIN SMALL LETTER I WITH BREVE", "LATIN CAPITAL LETTER I WITH OGONEK",
+        "LATIN SMALL LETTER I WITH OGONEK", "LATIN CAPITAL LETTER I WITH DOT ABOVE",
+        "LATIN SMALL LETTER DOTLESS I", "LATIN CAPITAL LIGATURE IJ", "LATIN SMALL LIGATURE IJ",
+        "LATIN CAPITAL LETTER J WITH CIRCUMFLEX", "LATIN SMALL LETTER J WITH CIRCUMFLEX",
+        "LATIN CAPITAL LETTER K WITH CEDILLA", "LATIN SMALL LETTER K WITH CEDILLA",
+        "LATIN SMALL LETTER KRA", "LATIN CAPITAL LETTER L WITH ACUTE",
+        "LATIN SMALL LETTER L WITH ACUTE", "LATIN CAPITAL LETTER L WITH CEDILLA",
+        "LATIN SMALL LETTER L WITH CEDILLA", "LATIN CAPITAL LETTER L WITH CARON",
+        "LATIN SMALL LETTER L WITH CARON", "LATIN CAPITAL LETTER L WITH MIDDLE DOT",
+        "LATIN SMALL LETTER L WITH MIDDLE DOT", "LATIN CAPITAL LETTER L WITH STROKE",
+        "LATIN SMALL LETTER L WITH STROKE", "LATIN CAPITAL LETTER N WITH ACUTE",
+        "LATIN SMALL LETTER N WITH ACUTE", "LATIN CAPITAL LETTER N WITH CEDILLA",
+        "LATIN SMALL LETTER N WITH CEDILLA", "LATIN CAPITAL LETTER N WITH CARON",
+        "LATIN SMALL LETTER N WITH CARON", "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE",
+        "LATIN CAPITAL LETTER ENG", "LATIN SMALL LETTER ENG", "LATIN CAPITAL LETTER O WITH MACRON",
+        "LATIN SMALL LETTER O WITH MACRON", "LATIN CAPITAL LETTER O WITH BREVE",
+        "LATIN SMALL LETTER O WITH BREVE", "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE",
+        "LATIN SMALL LETTER O WITH DOUBLE ACUTE", "LATIN CAPITAL LIGATURE OE",
+        "LATIN SMALL LIGATURE OE", "LATIN CAPITAL LETTER R WITH ACUTE",
+        "LATIN SMALL LETTER R WITH ACUTE", "LATIN CAPITAL LETTER R WITH CEDILLA",
+        "LATIN SMALL LETTER R WITH CEDILLA", "LATIN CAPITAL LETTER R WITH CARON",
+        "LATIN SMALL LETTER R WITH CARON", "LATIN CAPITAL LETTER S WITH ACUTE",
+        "LATIN SMALL LETTER S WITH ACUTE", "LATIN CAPITAL LETTER S WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER S WITH CIRCUMFLEX", "LATIN CAPITAL LETTER S WITH CEDILLA",
+        "LATIN SMALL LETTER S WITH CEDILLA", "LATIN CAPITAL LETTER S WITH CARON",
+        "LATIN SMALL LETTER S WITH CARON", "LATIN CAPITAL LETTER T WITH CEDILLA",
+        "LATIN SMALL LETTER T WITH CEDILLA", "LATIN CAPITAL LETTER T WITH CARON",
+        "LATIN SMALL LETTER T WITH CARON", "LATIN CAPITAL LETTER T WITH STROKE",
+        "LATIN SMALL LETTER T WITH STROKE", "LATIN CAPITAL LETTER U WITH TILDE",
+        "LATIN SMALL LETTER U WITH TILDE", "LATIN CAPITAL LETTER U WITH MACRON",
+        "LATIN SMALL LETTER U WITH MACRON", "LATIN CAPITAL LETTER U WITH BREVE",
+        "LATIN SMALL LETTER U WITH BREVE", "LATIN CAPITAL LETTER U WITH RING ABOVE",
+        "LATIN SMALL LETTER U WITH RING ABOVE", "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE",
+        "LATIN SMALL LETTER U WITH DOUBLE ACUTE", "LATIN CAPITAL LETTER U WITH OGONEK",
+        "LATIN SMALL LETTER U WITH OGONEK", "LATIN CAPITAL LETTER W WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER W WITH CIRCUMFLEX", "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER Y WITH CIRCUMFLEX", "LATIN CAPITAL LETTER Y WITH DIAERESIS",
+        "LATIN CAPITAL LETTER Z WITH ACUTE", "LATIN SMALL LETTER Z WITH ACUTE",
+        "LATIN CAPITAL LETTER Z WITH DOT ABOVE", "LATIN SMALL LETTER Z WITH DOT ABOVE",
+        "LATIN CAPITAL LETTER Z WITH CARON", "LATIN SMALL LETTER Z WITH CARON",
+        "LATIN SMALL LETTER LONG S", "LATIN SMALL LETTER B WITH STROKE",
+        "LATIN CAPITAL LETTER B WITH HOOK", "LATIN CAPITAL LETTER B WITH TOPBAR",
+        "LATIN SMALL LETTER B WITH TOPBAR", "LATIN CAPITAL LETTER TONE SIX",
+        "LATIN SMALL LETTER TONE SIX", "LATIN CAPITAL LETTER OPEN O",
+        "LATIN CAPITAL LETTER C WITH HOOK", "LATIN SMALL LETTER C WITH HOOK",
+        "LATIN CAPITAL LETTER AFRICAN D", "LATIN CAPITAL LETTER D WITH HOOK",
+        "LATIN CAPITAL LETTER D WITH TOPBAR", "LATIN SMALL LETTER D WITH TOPBAR",
+        "LATIN SMALL LETTER TURNED DELTA", "LATIN CAPITAL LETTER REVERSED E",
+        "LATIN CAPITAL LETTER SCHWA", "LATIN CAPITAL LETTER OPEN E",
+        "LATIN CAPITAL LETTER F WITH HOOK", "LATIN SMALL LETTER F WITH HOOK",
+        "LATIN CAPITAL LETTER G WITH HOOK", "LATIN CAPITAL LETTER GAMMA", "LATIN SMALL LETTER HV",
+        "LATIN CAPITAL LETTER IOTA", "LATIN CAPITAL LETTER I WITH STROKE",
+        "LATIN CAPITAL LETTER K WITH HOOK", "LATIN SMALL LETTER K WITH HOOK",
+        "LATIN SMALL LETTER L WITH BAR", "LATIN SMALL LETTER LAMBDA WITH STROKE",
+        "LATIN CAPITAL LETTER TURNED M", "LATIN CAPITAL LETTER N WITH LEFT HOOK",
+        "LATIN SMALL LETTER N WITH LONG RIGHT LEG", "LATIN CAPITAL LETTER O WITH MIDDLE TILDE",
+        "LATIN CAPITAL LETTER O WITH HORN", "LATIN SMALL LETTER O WITH HORN",
+        "LATIN CAPITAL LETTER OI", "LATIN SMALL LETTER OI", "LATIN CAPITAL LETTER P WITH HOOK",
+        "LATIN SMALL LETTER P WITH HOOK", "LATIN LETTER YR", "LATIN CAPITAL LETTER TONE TWO",
+        "LATIN SMALL LETTER TONE TWO", "LATIN CAPITAL LETTER ESH", "LATIN LETTER REVERSED ESH LOOP",
+        "LATIN SMALL LETTER T WITH PALATAL HOOK", "LATIN CAPITAL LETTER T WITH HOOK",
+        "LATIN SMALL LETTER T WITH HOOK", "LATIN CAPITAL LETTER T WITH RETROFLEX HOOK",
+        "LATIN CAPITAL LETTER U WITH HORN", "LATIN SMALL LETTER U WITH HORN",
+        "LATIN CAPITAL LETTER UPSILON", "LATIN CAPITAL LETTER V WITH HOOK",
+        "LATIN CAPITAL LETTER Y WITH HOOK", "LATIN SMALL LETTER Y WITH HOOK",
+        "LATIN CAPITAL LETTER Z WITH STROKE", "LATIN SMALL LETTER Z WITH STROKE",
+        "LATIN CAPITAL LETTER EZH", "LATIN CAPITAL LETTER EZH REVERSED",
+        "LATIN SMALL LETTER EZH REVERSED", "LATIN SMALL LETTER EZH WITH TAIL",
+        "LATIN LETTER TWO WITH STROKE", "LATIN CAPITAL LETTER TONE FIVE",
+        "LATIN SMALL LETTER TONE FIVE", "LATIN LETTER INVERTED GLOTTAL STOP WITH STROKE",
+        "LATIN LETTER WYNN", "LATIN LETTER DENTAL CLICK", "LATIN LETTER LATERAL CLICK",
+        "LATIN LETTER ALVEOLAR CLICK", "LATIN LETTER RETROFLEX CLICK",
+        "LATIN CAPITAL LETTER DZ WITH CARON",
+        "LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON", "LATIN SMALL LETTER DZ WITH CARON",
+        "LATIN CAPITAL LETTER LJ", "LATIN CAPITAL LETTER L WITH SMALL LETTER J",
+        "LATIN SMALL LETTER LJ", "LATIN CAPITAL LETTER NJ",
+        "LATIN CAPITAL LETTER N WITH SMALL LETTER J", "LATIN SMALL LETTER NJ",
+        "LATIN CAPITAL LETTER A WITH CARON", "LATIN SMALL LETTER A WITH CARON",
+        "LATIN CAPITAL LETTER I WITH CARON", "LATIN SMALL LETTER I WITH CARON",
+        "LATIN CAPITAL LETTER O WITH CARON", "LATIN SMALL LETTER O WITH CARON",
+        "LATIN CAPITAL LETTER U WITH CARON", "LATIN SMALL LETTER U WITH CARON",
+        "LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON",
+        "LATIN SMALL LETTER U WITH DIAERESIS AND MACRON",
+        "LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE",
+        "LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE",
+        "LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON",
+        "LATIN SMALL LETTER U WITH DIAERESIS AND CARON",
+        "LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE",
+        "LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE", "LATIN SMALL LETTER TURNED E",
+        "LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON",
+        "LATIN SMALL LETTER A WITH DIAERESIS AND MACRON",
+        "LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON",
+        "LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON", "LATIN CAPITAL LETTER AE WITH MACRON",
+        "LATIN SMALL LETTER AE WITH MACRON", "LATIN CAPITAL LETTER G WITH STROKE",
+        "LATIN SMALL LETTER G WITH STROKE", "LATIN CAPITAL LETTER G WITH CARON",
+        "LATIN SMALL LETTER G WITH CARON", "LATIN CAPITAL LETTER K WITH CARON",
+        "LATIN SMALL LETTER K WITH CARON", "LATIN CAPITAL LETTER O WITH OGONEK",
+        "LATIN SMALL LETTER O WITH OGONEK", "LATIN CAPITAL LETTER O WITH OGONEK AND MACRON",
+        "LATIN SMALL LETTER O WITH OGONEK AND MACRON", "LATIN CAPITAL LETTER EZH WITH CARON",
+        "LATIN SMALL LETTER EZH WITH CARON", "LATIN SMALL LETTER J WITH CARON",
+        "LATIN CAPITAL LETTER DZ", "LATIN CAPITAL LETTER D WITH SMALL LETTER Z",
+        "LATIN SMALL LETTER DZ", "LATIN CAPITAL LETTER G WITH ACUTE",
+        "LATIN SMALL LETTER G WITH ACUTE", "LATIN CAPITAL LETTER HWAIR",
+        "LATIN CAPITAL LETTER WYNN", "LATIN CAPITAL LETTER N WITH GRAVE",
+        "LATIN SMALL LETTER N WITH GRAVE", "LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE",
+        "LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE", "LATIN CAPITAL LETTER AE WITH ACUTE",
+        "LATIN SMALL LETTER AE WITH ACUTE", "LATIN CAPITAL LETTER O WITH STROKE AND ACUTE",
+        "LATIN SMALL LETTER O WITH STROKE AND ACUTE", "LATIN CAPITAL LETTER A WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER A WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER A WITH INVERTED BREVE",
+        "LATIN SMALL LETTER A WITH INVERTED BREVE", "LATIN CAPITAL LETTER E WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER E WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER E WITH INVERTED BREVE",
+        "LATIN SMALL LETTER E WITH INVERTED BREVE", "LATIN CAPITAL LETTER I WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER I WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER I WITH INVERTED BREVE",
+        "LATIN SMALL LETTER I WITH INVERTED BREVE", "LATIN CAPITAL LETTER O WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER O WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER O WITH INVERTED BREVE",
+        "LATIN SMALL LETTER O WITH INVERTED BREVE", "LATIN CAPITAL LETTER R WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER R WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER R WITH INVERTED BREVE",
+        "LATIN SMALL LETTER R WITH INVERTED BREVE", "LATIN CAPITAL LETTER U WITH DOUBLE GRAVE",
+        "LATIN SMALL LETTER U WITH DOUBLE GRAVE", "LATIN CAPITAL LETTER U WITH INVERTED BREVE",
+        "LATIN SMALL LETTER U WITH INVERTED BREVE", "LATIN CAPITAL LETTER S WITH COMMA BELOW",
+        "LATIN SMALL LETTER S WITH COMMA BELOW", "LATIN CAPITAL LETTER T WITH COMMA BELOW",
+        "LATIN SMALL LETTER T WITH COMMA BELOW", "LATIN CAPITAL LETTER YOGH",
+        "LATIN SMALL LETTER YOGH", "LATIN CAPITAL LETTER H WITH CARON",
+        "LATIN SMALL LETTER H WITH CARON", "LATIN CAPITAL LETTER N WITH LONG RIGHT LEG",
+        "LATIN SMALL LETTER D WITH CURL", "LATIN CAPITAL LETTER OU", "LATIN SMALL LETTER OU",
+        "LATIN CAPITAL LETTER Z WITH HOOK", "LATIN SMALL LETTER Z WITH HOOK",
+        "LATIN CAPITAL LETTER A WITH DOT ABOVE", "LATIN SMALL LETTER A WITH DOT ABOVE",
+        "LATIN CAPITAL LETTER E WITH CEDILLA", "LATIN SMALL LETTER E WITH CEDILLA",
+        "LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON",
+        "LATIN SMALL LETTER O WITH DIAERESIS AND MACRON",
+        "LATIN CAPITAL LETTER O WITH TILDE AND MACRON",
+        "LATIN SMALL LETTER O WITH TILDE AND MACRON", "LATIN CAPITAL LETTER O WITH DOT ABOVE",
+        "LATIN SMALL LETTER O WITH DOT ABOVE", "LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON",
+        "LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON", "LATIN CAPITAL LETTER Y WITH MACRON",
+        "LATIN SMALL LETTER Y WITH MACRON", "LATIN SMALL LETTER L WITH CURL",
+        "LATIN SMALL LETTER N WITH CURL", "LATIN SMALL LETTER T WITH CURL",
+        "LATIN SMALL LETTER DOTLESS J", "LATIN SMALL LETTER DB DIGRAPH",
+        "LATIN SMALL LETTER QP DIGRAPH", "LATIN CAPITAL LETTER A WITH STROKE",
+        "LATIN CAPITAL LETTER C WITH STROKE", "LATIN SMALL LETTER C WITH STROKE",
+        "LATIN CAPITAL LETTER L WITH BAR", "LATIN CAPITAL LETTER T WITH DIAGONAL STROKE",
+        "LATIN SMALL LETTER S WITH SWASH TAIL", "LATIN SMALL LETTER Z WITH SWASH TAIL",
+        "LATIN CAPITAL LETTER GLOTTAL STOP", "LATIN SMALL LETTER GLOTTAL STOP",
+        "LATIN CAPITAL LETTER B WITH STROKE", "LATIN CAPITAL LETTER U BAR",
+        "LATIN CAPITAL LETTER TURNED V", "LATIN CAPITAL LETTER E WITH STROKE",
+        "LATIN SMALL LETTER E WITH STROKE", "LATIN CAPITAL LETTER J WITH STROKE",
+        "LATIN SMALL LETTER J WITH STROKE", "LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL",
+        "LATIN SMALL LETTER Q WITH HOOK TAIL", "LATIN CAPITAL LETTER R WITH STROKE",
+        "LATIN SMALL LETTER R WITH STROKE", "LATIN CAPITAL LETTER Y WITH STROKE",
+        "LATIN SMALL LETTER Y WITH STROKE", "LATIN SMALL LETTER TURNED A",
+        "LATIN SMALL LETTER ALPHA", "LATIN SMALL LETTER TURNED ALPHA",
+        "LATIN SMALL LETTER B WITH HOOK", "LATIN SMALL LETTER OPEN O",
+        "LATIN SMALL LETTER C WITH CURL", "LATIN SMALL LETTER D WITH TAIL",
+        "LATIN SMALL LETTER D WITH HOOK", "LATIN SMALL LETTER REVERSED E",
+        "LATIN SMALL LETTER SCHWA", "LATIN SMALL LETTER SCHWA WITH HOOK",
+        "LATIN SMALL LETTER OPEN E", "LATIN SMALL LETTER REVERSED OPEN E",
+        "LATIN SMALL LETTER REVERSED OPEN E WITH HOOK", "LATIN SMALL LETTER CLOSED REVERSED OPEN E",
+        "LATIN SMALL LETTER DOTLESS J WITH STROKE", "LATIN SMALL LETTER G WITH HOOK",
+        "LATIN SMALL LETTER SCRIPT G", "LATIN LETTER SMALL CAPITAL G", "LATIN SMALL LETTER GAMMA",
+        "LATIN SMALL LETTER RAMS HORN", "LATIN SMALL LETTER TURNED H",
+        "LATIN SMALL LETTER H WITH HOOK", "LATIN SMALL LETTER HENG WITH HOOK",
+        "LATIN SMALL LETTER I WITH STROKE", "LATIN SMALL LETTER IOTA",
+        "LATIN LETTER SMALL CAPITAL I", "LATIN SMALL LETTER L WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER L WITH BELT", "LATIN SMALL LETTER L WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER LEZH", "LATIN SMALL LETTER TURNED M",
+        "LATIN SMALL LETTER TURNED M WITH LONG LEG", "LATIN SMALL LETTER M WITH HOOK",
+        "LATIN SMALL LETTER N WITH LEFT HOOK", "LATIN SMALL LETTER N WITH RETROFLEX HOOK",
+        "LATIN LETTER SMALL CAPITAL N", "LATIN SMALL LETTER BARRED O",
+        "LATIN LETTER SMALL CAPITAL OE", "LATIN SMALL LETTER CLOSED OMEGA",
+        "LATIN SMALL LETTER PHI", "LATIN SMALL LETTER TURNED R",
+        "LATIN SMALL LETTER TURNED R WITH LONG LEG", "LATIN SMALL LETTER TURNED R WITH HOOK",
+        "LATIN SMALL LETTER R WITH LONG LEG", "LATIN SMALL LETTER R WITH TAIL",
+        "LATIN SMALL LETTER R WITH FISHHOOK", "LATIN SMALL LETTER REVERSED R WITH FISHHOOK",
+        "LATIN LETTER SMALL CAPITAL R", "LATIN LETTER SMALL CAPITAL INVERTED R",
+        "LATIN SMALL LETTER S WITH HOOK", "LATIN SMALL LETTER ESH",
+        "LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK",
+        "LATIN SMALL LETTER SQUAT REVERSED ESH", "LATIN SMALL LETTER ESH WITH CURL",
+        "LATIN SMALL LETTER TURNED T", "LATIN SMALL LETTER T WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER U BAR", "LATIN SMALL LETTER UPSILON", "LATIN SMALL LETTER V WITH HOOK",
+        "LATIN SMALL LETTER TURNED V", "LATIN SMALL LETTER TURNED W", "LATIN SMALL LETTER TURNED Y",
+        "LATIN LETTER SMALL CAPITAL Y", "LATIN SMALL LETTER Z WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER Z WITH CURL", "LATIN SMALL LETTER EZH",
+        "LATIN SMALL LETTER EZH WITH CURL", "LATIN LETTER GLOTTAL STOP",
+        "LATIN LETTER PHARYNGEAL VOICED FRICATIVE", "LATIN LETTER INVERTED GLOTTAL STOP",
+        "LATIN LETTER STRETCHED C", "LATIN LETTER BILABIAL CLICK", "LATIN LETTER SMALL CAPITAL B",
+        "LATIN SMALL LETTER CLOSED OPEN E", "LATIN LETTER SMALL CAPITAL G WITH HOOK",
+        "LATIN LETTER SMALL CAPITAL H", "LATIN SMALL LETTER J WITH CROSSED-TAIL",
+        "LATIN SMALL LETTER TURNED K", "LATIN LETTER SMALL CAPITAL L",
+        "LATIN SMALL LETTER Q WITH HOOK", "LATIN LETTER GLOTTAL STOP WITH STROKE",
+        "LATIN LETTER REVERSED GLOTTAL STOP WITH STROKE", "LATIN SMALL LETTER DZ DIGRAPH",
+        "LATIN SMALL LETTER DEZH DIGRAPH", "LATIN SMALL LETTER DZ DIGRAPH WITH CURL",
+        "LATIN SMALL LETTER TS DIGRAPH", "LATIN SMALL LETTER TESH DIGRAPH",
+        "LATIN SMALL LETTER TC DIGRAPH WITH CURL", "LATIN SMALL LETTER FENG DIGRAPH",
+        "LATIN SMALL LETTER LS DIGRAPH", "LATIN SMALL LETTER LZ DIGRAPH",
+        "LATIN LETTER BILABIAL PERCUSSIVE", "LATIN LETTER BIDENTAL PERCUSSIVE",
+        "LATIN SMALL LETTER TURNED H WITH FISHHOOK",
+        "LATIN SMALL LETTER TURNED H WITH FISHHOOK AND TAIL", "MODIFIER LETTER SMALL H",
+        "MODIFIER LETTER SMALL H WITH HOOK", "MODIFIER LETTER SMALL J", "MODIFIER LETTER SMALL R",
+        "MODIFIER LETTER SMALL TURNED R", "MODIFIER LETTER SMALL TURNED R WITH HOOK",
+        "MODIFIER LETTER SMALL CAPITAL INVERTED R", "MODIFIER LETTER SMALL W",
+        "MODIFIER LETTER SMALL Y", "MODIFIER LETTER PRIME", "MODIFIER LETTER DOUBLE PRIME",
+        "MODIFIER LETTER TURNED COMMA", "MODIFIER LETTER APOSTROPHE",
+        "MODIFIER LETTER REVERSED COMMA", "MODIFIER LETTER RIGHT HALF RING",
+        "MODIFIER LETTER LEFT HALF RING", "MODIFIER LETTER GLOTTAL STOP",
+        "MODIFIER LETTER REVERSED GLOTTAL STOP", "MODIFIER LETTER LEFT ARROWHEAD",
+        "MODIFIER LETTER RIGHT ARROWHEAD", "MODIFIER LETTER UP ARROWHEAD",
+        "MODIFIER LETTER DOWN ARROWHEAD", "MODIFIER LETTER CIRCUMFLEX ACCENT", "CARON",
+        "MODIFIER LETTER VERTICAL LINE", "MODIFIER LETTER MACRON", "MODIFIER LETTER ACUTE ACCENT",
+        "MODIFIER LETTER GRAVE ACCENT", "MODIFIER LETTER LOW VERTICAL LINE",
+        "MODIFIER LETTER LOW MACRON", "MODIFIER LETTER LOW GRAVE ACCENT",
+        "MODIFIER LETTER LOW ACUTE ACCENT", "MODIFIER LETTER TRIANGULAR COLON",
+        "MODIFIER LETTER HALF TRIANGULAR COLON", "MODIFIER LETTER CENTRED RIGHT HALF RING",
+        "MODIFIER LETTER CENTRED LEFT HALF RING", "MODIFIER LETTER UP TACK",
+        "MODIFIER LETTER DOWN TACK", "MODIFIER LETTER PLUS SIGN", "MODIFIER LETTER MINUS SIGN",
+        "BREVE", "DOT ABOVE", "RING ABOVE", "OGONEK", "SMALL TILDE", "DOUBLE ACUTE ACCENT",
+        "MODIFIER LETTER RHOTIC HOOK", "MODIFIER LETTER CROSS ACCENT",
+        "MODIFIER LETTER SMALL GAMMA", "MODIFIER LETTER SMALL L", "MODIFIER LETTER SMALL S",
+        "MODIFIER LETTER SMALL X", "MODIFIER LETTER SMALL REVERSED GLOTTAL STOP",
+        "MODIFIER LETTER EXTRA-HIGH TONE BAR", "MODIFIER LETTER HIGH TONE BAR",
+        "MODIFIER LETTER MID TONE BAR", "MODIFIER LETTER LOW TONE BAR",
+        "MODIFIER LETTER EXTRA-LOW TONE BAR", "MODIFIER LETTER YIN DEPARTING TONE MARK",
+        "MODIFIER LETTER YANG DEPARTING TONE MARK", "MODIFIER LETTER VOICING",
+        "MODIFIER LETTER UNASPIRATED", "MODIFIER LETTER DOUBLE APOSTROPHE",
+        "MODIFIER LETTER LOW DOWN ARROWHEAD", "MODIFIER LETTER LOW UP ARROWHEAD",
+        "MODIFIER LETTER LOW LEFT ARROWHEAD", "MODIFIER LETTER LOW RIGHT ARROWHEAD",
+        "MODIFIER LETTER LOW RING", "MODIFIER LETTER MIDDLE GRAVE ACCENT",
+        "MODIFIER LETTER MIDDLE DOUBLE GRAVE ACCENT", "MODIFIER LETTER MIDDLE DOUBLE ACUTE ACCENT",
+        "MODIFIER LETTER LOW TILDE", "MODIFIER LETTER RAISED COLON",
+        "MODIFIER LETTER BEGIN HIGH TONE", "MODIFIER LETTER END HIGH TONE",
+        "MODIFIER LETTER BEGIN LOW TONE", "MODIFIER LETTER END LOW TONE", "MODIFIER LETTER SHELF",
+        "MODIFIER LETTER OPEN SHELF", "MODIFIER LETTER LOW LEFT ARROW", "COMBINING GRAVE ACCENT",
+        "COMBINING ACUTE ACCENT", "COMBINING CIRCUMFLEX ACCENT", "COMBINING TILDE",
+        "COMBINING MACRON", "COMBINING OVERLINE", "COMBINING BREVE", "COMBINING DOT ABOVE",
+        "COMBINING DIAERESIS", "COMBINING HOOK ABOVE", "COMBINING RING ABOVE",
+        "COMBINING DOUBLE ACUTE ACCENT", "COMBINING CARON", "COMBINING VERTICAL LINE ABOVE",
+        "COMBINING DOUBLE VERTICAL LINE ABOVE", "COMBINING DOUBLE GRAVE ACCENT",
+        "COMBINING CANDRABINDU", "COMBINING INVERTED BREVE", "COMBINING TURNED COMMA ABOVE",
+        "COMBINING COMMA ABOVE", "COMBINING REVERSED COMMA ABOVE", "COMBINING COMMA ABOVE RIGHT",
+        "COMBINING GRAVE ACCENT BELOW", "COMBINING ACUTE ACCENT BELOW", "COMBINING LEFT TACK BELOW",
+        "COMBINING RIGHT TACK BELOW", "COMBINING LEFT ANGLE ABOVE", "COMBINING HORN",
+        "COMBINING LEFT HALF RING BELOW", "COMBINING UP TACK BELOW", "COMBINING DOWN TACK BELOW",
+        "COMBINING PLUS SIGN BELOW", "COMBINING MINUS SIGN BELOW",
+        "COMBINING PALATALIZED HOOK BELOW", "COMBINING RETROFLEX HOOK BELOW", "COMBINING DOT BELOW",
+        "COMBINING DIAERESIS BELOW", "COMBINING RING BELOW", "COMBINING COMMA BELOW",
+        "COMBINING CEDILLA", "COMBINING OGONEK", "COMBINING VERTICAL LINE BELOW",
+        "COMBINING BRIDGE BELOW", "COMBINING INVERTED DOUBLE ARCH BELOW", "COMBINING CARON BELOW",
+        "COMBINING CIRCUMFLEX ACCENT BELOW", "COMBINING BREVE BELOW",
+        "COMBINING INVERTED BREVE BELOW", "COMBINING TILDE BELOW", "COMBINING MACRON BELOW",
+        "COMBINING LOW LINE", "COMBINING DOUBLE LOW LINE", "COMBINING TILDE OVERLAY",
+        "COMBINING SHORT STROKE OVERLAY", "COMBINING LONG STROKE OVERLAY",
+        "COMBINING SHORT SOLIDUS OVERLAY", "COMBINING LONG SOLIDUS OVERLAY",
+        "COMBINING RIGHT HALF RING BELOW", "COMBINING INVERTED BRIDGE BELOW",
+        "COMBINING SQUARE BELOW", "COMBINING SEAGULL BELOW", "COMBINING X ABOVE",
+        "COMBINING VERTICAL TILDE", "COMBINING DOUBLE OVERLINE", "COMBINING GRAVE TONE MARK",
+        "COMBINING ACUTE TONE MARK", "COMBINING GREEK PERISPOMENI", "COMBINING GREEK KORONIS",
+        "COMBINING GREEK DIALYTIKA TONOS", "COMBINING GREEK YPOGEGRAMMENI",
+        "COMBINING BRIDGE ABOVE", "COMBINING EQUALS SIGN BELOW",
+        "COMBINING DOUBLE VERTICAL LINE BELOW", "COMBINING LEFT ANGLE BELOW",
+        "COMBINING NOT TILDE ABOVE", "COMBINING HOMOTHETIC ABOVE",
+        "COMBINING ALMOST EQUAL TO ABOVE", "COMBINING LEFT RIGHT ARROW BELOW",
+        "COMBINING UPWARDS ARROW BELOW", "COMBINING GRAPHEME JOINER",
+        "COMBINING RIGHT ARROWHEAD ABOVE", "COMBINING LEFT HALF RING ABOVE", "COMBINING FERMATA",
+        "COMBINING X BELOW", "COMBINING LEFT ARROWHEAD BELOW", "COMBINING RIGHT ARROWHEAD BELOW",
+        "COMBINING RIGHT ARROWHEAD AND UP ARROWHEAD BELOW", "COMBINING RIGHT HALF RING ABOVE",
+        "COMBINING DOT ABOVE RIGHT", "COMBINING ASTERISK BELOW", "COMBINING DOUBLE RING BELOW",
+        "COMBINING ZIGZAG ABOVE", "COMBINING DOUBLE BREVE BELOW", "COMBINING DOUBLE BREVE",
+        "COMBINING DOUBLE MACRON", "COMBINING DOUBLE MACRON BELOW", "COMBINING DOUBLE TILDE",
+        "COMBINING DOUBLE INVERTED BREVE", "COMBINING DOUBLE RIGHTWARDS ARROW BELOW",
+        "COMBINING LATIN SMALL LETTER A", "COMBINING LATIN SMALL LETTER E",
+        "COMBINING LATIN SMALL LETTER I", "COMBINING LATIN SMALL LETTER O",
+        "COMBINING LATIN SMALL LETTER U", "COMBINING LATIN SMALL LETTER C",
+        "COMBINING LATIN SMALL LETTER D", "COMBINING LATIN SMALL LETTER H",
+        "COMBINING LATIN SMALL LETTER M", "COMBINING LATIN SMALL LETTER R",
+        "COMBINING LATIN SMALL LETTER T", "COMBINING LATIN SMALL LETTER V",
+        "COMBINING LATIN SMALL LETTER X", "GREEK CAPITAL LETTER HETA", "GREEK SMALL LETTER HETA",
+        "GREEK CAPITAL LETTER ARCHAIC SAMPI", "GREEK SMALL LETTER ARCHAIC SAMPI",
+        "GREEK NUMERAL SIGN", "GREEK LOWER NUMERAL SIGN", "GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA",
+        "GREEK SMALL LETTER PAMPHYLIAN DIGAMMA", "", "", "GREEK YPOGEGRAMMENI",
+        "GREEK SMALL REVERSED LUNATE SIGMA SYMBOL", "GREEK SMALL DOTTED LUNATE SIGMA SYMBOL",
+        "GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL", "GREEK QUESTION MARK",
+        "GREEK CAPITAL LETTER YOT",
+    ]),
+    (0x0384, &[
+        "GREEK TONOS", "GREEK DIALYTIKA TONOS", "GREEK CAPITAL LETTER ALPHA WITH TONOS",
+        "GREEK ANO TELEIA", "GREEK CAPITAL LETTER EPSILON WITH TONOS",
+        "GREEK CAPITAL LETTER ETA WITH TONOS", "GREEK CAPITAL LETTER IOTA WITH TONOS", "",
+        "GREEK CAPITAL LETTER OMICRON WITH TONOS", "", "GREEK CAPITAL LETTER UPSILON WITH TONOS",
+        "GREEK CAPITAL LETTER OMEGA WITH TONOS", "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS",
+        "GREEK CAPITAL LETTER ALPHA", "GREEK CAPITAL LETTER BETA", "GREEK CAPITAL LETTER GAMMA",
+        "GREEK CAPITAL LETTER DELTA", "GREEK CAPITAL LETTER EPSILON", "GREEK CAPITAL LETTER ZETA",
+        "GREEK CAPITAL LETTER ETA", "GREEK CAPITAL LETTER THETA", "GREEK CAPITAL LETTER IOTA",
+        "GREEK CAPITAL LETTER KAPPA", "GREEK CAPITAL LETTER LAMDA", "GREEK CAPITAL LETTER MU",
+        "GREEK CAPITAL LETTER NU", "GREEK CAPITAL LETTER XI", "GREEK CAPITAL LETTER OMICRON",
+        "GREEK CAPITAL LETTER PI", "GREEK CAPITAL LETTER RHO", "", "GREEK CAPITAL LETTER SIGMA",
+        "GREEK CAPITAL LETTER TAU", "GREEK CAPITAL LETTER UPSILON", "GREEK CAPITAL LETTER PHI",
+        "GREEK CAPITAL LETTER CHI", "GREEK CAPITAL LETTER PSI", "GREEK CAPITAL LETTER OMEGA",
+        "GREEK CAPITAL LETTER IOTA WITH DIALYTIKA", "GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA",
+        "GREEK SMALL LETTER ALPHA WITH TONOS", "GREEK SMALL LETTER EPSILON WITH TONOS",
+        "GREEK SMALL LETTER ETA WITH TONOS", "GREEK SMALL LETTER IOTA WITH TONOS",
+        "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS", "GREEK SMALL LETTER ALPHA",
+        "GREEK SMALL LETTER BETA", "GREEK SMALL LETTER GAMMA", "GREEK SMALL LETTER DELTA",
+        "GREEK SMALL LETTER EPSILON", "GREEK SMALL LETTER ZETA", "GREEK SMALL LETTER ETA",
+        "GREEK SMALL LETTER THETA", "GREEK SMALL LETTER IOTA", "GREEK SMALL LETTER KAPPA",
+        "GREEK SMALL LETTER LAMDA", "GREEK SMALL LETTER MU", "GREEK SMALL LETTER NU",
+        "GREEK SMALL LETTER XI", "GREEK SMALL LETTER OMICRON", "GREEK SMALL LETTER PI",
+        "GREEK SMALL LETTER RHO", "GREEK SMALL LETTER FINAL SIGMA", "GREEK SMALL LETTER SIGMA",
+        "GREEK SMALL LETTER TAU", "GREEK SMALL LETTER UPSILON", "GREEK SMALL LETTER PHI",
+        "GREEK SMALL LETTER CHI", "GREEK SMALL LETTER PSI", "GREEK SMALL LETTER OMEGA",
+        "GREEK SMALL LETTER IOTA WITH DIALYTIKA", "GREEK SMALL LETTER UPSILON WITH DIALYTIKA",
+        "GREEK SMALL LETTER OMICRON WITH TONOS", "GREEK SMALL LETTER UPSILON WITH TONOS",
+        "GREEK SMALL LETTER OMEGA WITH TONOS", "GREEK CAPITAL KAI SYMBOL", "GREEK BETA SYMBOL",
+        "GREEK THETA SYMBOL", "GREEK UPSILON WITH HOOK SYMBOL",
+        "GREEK UPSILON WITH ACUTE AND HOOK SYMBOL", "GREEK UPSILON WITH DIAERESIS AND HOOK SYMBOL",
+        "GREEK PHI SYMBOL", "GREEK PI SYMBOL", "GREEK KAI SYMBOL", "GREEK LETTER ARCHAIC KOPPA",
+        "GREEK SMALL LETTER ARCHAIC KOPPA", "GREEK LETTER STIGMA", "GREEK SMALL LETTER STIGMA",
+        "GREEK LETTER DIGAMMA", "GREEK SMALL LETTER DIGAMMA", "GREEK LETTER KOPPA",
+        "GREEK SMALL LETTER KOPPA", "GREEK LETTER SAMPI", "GREEK SMALL LETTER SAMPI",
+        "COPTIC CAPITAL LETTER SHEI", "COPTIC SMALL LETTER SHEI", "COPTIC CAPITAL LETTER FEI",
+        "COPTIC SMALL LETTER FEI", "COPTIC CAPITAL LETTER KHEI", "COPTIC SMALL LETTER KHEI",
+        "COPTIC CAPITAL LETTER HORI", "COPTIC SMALL LETTER HORI", "COPTIC CAPITAL LETTER GANGIA",
+        "COPTIC SMALL LETTER GANGIA", "COPTIC CAPITAL LETTER SHIMA", "COPTIC SMALL LETTER SHIMA",
+        "COPTIC CAPITAL LETTER DEI", "COPTIC SMALL LETTER DEI", "GREEK KAPPA SYMBOL",
+        "GREEK RHO SYMBOL", "GREEK LUNATE SIGMA SYMBOL", "GREEK LETTER YOT",
+        "GREEK CAPITAL THETA SYMBOL", "GREEK LUNATE EPSILON SYMBOL",
+        "GREEK REVERSED LUNATE EPSILON SYMBOL", "GREEK CAPITAL LETTER SHO",
+        "GREEK SMALL LETTER SHO", "GREEK CAPITAL LUNATE SIGMA SYMBOL", "GREEK CAPITAL LETTER SAN",
+        "GREEK SMALL LETTER SAN", "GREEK RHO WITH STROKE SYMBOL",
+        "GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL", "GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL",
+        "GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL",
+        "CYRILLIC CAPITAL LETTER IE WITH GRAVE", "CYRILLIC CAPITAL LETTER IO",
+        "CYRILLIC CAPITAL LETTER DJE", "CYRILLIC CAPITAL LETTER GJE",
+        "CYRILLIC CAPITAL LETTER UKRAINIAN IE", "CYRILLIC CAPITAL LETTER DZE",
+        "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I", "CYRILLIC CAPITAL LETTER YI",
+        "CYRILLIC CAPITAL LETTER JE", "CYRILLIC CAPITAL LETTER LJE", "CYRILLIC CAPITAL LETTER NJE",
+        "CYRILLIC CAPITAL LETTER TSHE", "CYRILLIC CAPITAL LETTER KJE",
+        "CYRILLIC CAPITAL LETTER I WITH GRAVE", "CYRILLIC CAPITAL LETTER SHORT U",
+        "CYRILLIC CAPITAL LETTER DZHE", "CYRILLIC CAPITAL LETTER A", "CYRILLIC CAPITAL LETTER BE",
+        "CYRILLIC CAPITAL LETTER VE", "CYRILLIC CAPITAL LETTER GHE", "CYRILLIC CAPITAL LETTER DE",
+        "CYRILLIC CAPITAL LETTER IE", "CYRILLIC CAPITAL LETTER ZHE", "CYRILLIC CAPITAL LETTER ZE",
+        "CYRILLIC CAPITAL LETTER I", "CYRILLIC CAPITAL LETTER SHORT I",
+        "CYRILLIC CAPITAL LETTER KA", "CYRILLIC CAPITAL LETTER EL", "CYRILLIC CAPITAL LETTER EM",
+        "CYRILLIC CAPITAL LETTER EN", "CYRILLIC CAPITAL LETTER O", "CYRILLIC CAPITAL LETTER PE",
+        "CYRILLIC CAPITAL LETTER ER", "CYRILLIC CAPITAL LETTER ES", "CYRILLIC CAPITAL LETTER TE",
+        "CYRILLIC CAPITAL LETTER U", "CYRILLIC CAPITAL LETTER EF", "CYRILLIC CAPITAL LETTER HA",
+        "CYRILLIC CAPITAL LETTER TSE", "CYRILLIC CAPITAL LETTER CHE", "CYRILLIC CAPITAL LETTER SHA",
+        "CYRILLIC CAPITAL LETTER SHCHA", "CYRILLIC CAPITAL LETTER HARD SIGN",
+        "CYRILLIC CAPITAL LETTER YERU", "CYRILLIC CAPITAL LETTER SOFT SIGN",
+        "CYRILLIC CAPITAL LETTER E", "CYRILLIC CAPITAL LETTER YU", "CYRILLIC CAPITAL LETTER YA",
+        "CYRILLIC SMALL LETTER A", "CYRILLIC SMALL LETTER BE", "CYRILLIC SMALL LETTER VE",
+        "CYRILLIC SMALL LETTER GHE", "CYRILLIC SMALL LETTER DE", "CYRILLIC SMALL LETTER IE",
+        "CYRILLIC SMALL LETTER ZHE", "CYRILLIC SMALL LETTER ZE", "CYRILLIC SMALL LETTER I",
+        "CYRILLIC SMALL LETTER SHORT I", "CYRILLIC SMALL LETTER KA", "CYRILLIC SMALL LETTER EL",
+        "CYRILLIC SMALL LETTER EM", "CYRILLIC SMALL LETTER EN", "CYRILLIC SMALL LETTER O",
+        "CYRILLIC SMALL LETTER PE", "CYRILLIC SMALL LETTER ER", "CYRILLIC SMALL LETTER ES",
+        "CYRILLIC SMALL LETTER TE", "CYRILLIC SMALL LETTER U", "CYRILLIC SMALL LETTER EF",
+        "CYRILLIC SMALL LETTER HA", "CYRILLIC SMALL LETTER TSE", "CYRILLIC SMALL LETTER CHE",
+        "CYRILLIC SMALL LETTER SHA", "CYRILLIC SMALL LETTER SHCHA",
+        "CYRILLIC SMALL LETTER HARD SIGN", "CYRILLIC SMALL LETTER YERU",
+        "CYRILLIC SMALL LETTER SOFT SIGN", "CYRILLIC SMALL LETTER E", "CYRILLIC SMALL LETTER YU",
+        "CYRILLIC SMALL LETTER YA", "CYRILLIC SMALL LETTER IE WITH GRAVE",
+        "CYRILLIC SMALL LETTER IO", "CYRILLIC SMALL LETTER DJE", "CYRILLIC SMALL LETTER GJE",
+        "CYRILLIC SMALL LETTER UKRAINIAN IE", "CYRILLIC SMALL LETTER DZE",
+        "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I", "CYRILLIC SMALL LETTER YI",
+        "CYRILLIC SMALL LETTER JE", "CYRILLIC SMALL LETTER LJE", "CYRILLIC SMALL LETTER NJE",
+        "CYRILLIC SMALL LETTER TSHE", "CYRILLIC SMALL LETTER KJE",
+        "CYRILLIC SMALL LETTER I WITH GRAVE", "CYRILLIC SMALL LETTER SHORT U",
+        "CYRILLIC SMALL LETTER DZHE", "CYRILLIC CAPITAL LETTER OMEGA",
+        "CYRILLIC SMALL LETTER OMEGA", "CYRILLIC CAPITAL LETTER YAT", "CYRILLIC SMALL LETTER YAT",
+        "CYRILLIC CAPITAL LETTER IOTIFIED E", "CYRILLIC SMALL LETTER IOTIFIED E",
+        "CYRILLIC CAPITAL LETTER LITTLE YUS", "CYRILLIC SMALL LETTER LITTLE YUS",
+        "CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS", "CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS",
+        "CYRILLIC CAPITAL LETTER BIG YUS", "CYRILLIC SMALL LETTER BIG YUS",
+        "CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS", "CYRILLIC SMALL LETTER IOTIFIED BIG YUS",
+        "CYRILLIC CAPITAL LETTER KSI", "CYRILLIC SMALL LETTER KSI", "CYRILLIC CAPITAL LETTER PSI",
+        "CYRILLIC SMALL LETTER PSI", "CYRILLIC CAPITAL LETTER FITA", "CYRILLIC SMALL LETTER FITA",
+        "CYRILLIC CAPITAL LETTER IZHITSA", "CYRILLIC SMALL LETTER IZHITSA",
+        "CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT",
+        "CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT", "CYRILLIC CAPITAL LETTER UK",
+        "CYRILLIC SMALL LETTER UK", "CYRILLIC CAPITAL LETTER ROUND OMEGA",
+        "CYRILLIC SMALL LETTER ROUND OMEGA", "CYRILLIC CAPITAL LETTER OMEGA WITH TITLO",
+        "CYRILLIC SMALL LETTER OMEGA WITH TITLO", "CYRILLIC CAPITAL LETTER OT",
+        "CYRILLIC SMALL LETTER OT", "CYRILLIC CAPITAL LETTER KOPPA", "CYRILLIC SMALL LETTER KOPPA",
+        "CYRILLIC THOUSANDS SIGN", "COMBINING CYRILLIC TITLO", "COMBINING CYRILLIC PALATALIZATION",
+        "COMBINING CYRILLIC DASIA PNEUMATA", "COMBINING CYRILLIC PSILI PNEUMATA",
+        "COMBINING CYRILLIC POKRYTIE", "COMBINING CYRILLIC HUNDRED THOUSANDS SIGN",
+        "COMBINING CYRILLIC MILLIONS SIGN", "CYRILLIC CAPITAL LETTER SHORT I WITH TAIL",
+        "CYRILLIC SMALL LETTER SHORT I WITH TAIL", "CYRILLIC CAPITAL LETTER SEMISOFT SIGN",
+        "CYRILLIC SMALL LETTER SEMISOFT SIGN", "CYRILLIC CAPITAL LETTER ER WITH TICK",
+        "CYRILLIC SMALL LETTER ER WITH TICK", "CYRILLIC CAPITAL LETTER GHE WITH UPTURN",
+        "CYRILLIC SMALL LETTER GHE WITH UPTURN", "CYRILLIC CAPITAL LETTER GHE WITH STROKE",
+        "CYRILLIC SMALL LETTER GHE WITH STROKE", "CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK",
+        "CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK", "CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER ZHE WITH DESCENDER", "CYRILLIC CAPITAL LETTER ZE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER ZE WITH DESCENDER", "CYRILLIC CAPITAL LETTER KA WITH DESCENDER",
+        "CYRILLIC SMALL LETTER KA WITH DESCENDER",
+        "CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE",
+        "CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE", "CYRILLIC CAPITAL LETTER KA WITH STROKE",
+        "CYRILLIC SMALL LETTER KA WITH STROKE", "CYRILLIC CAPITAL LETTER BASHKIR KA",
+        "CYRILLIC SMALL LETTER BASHKIR KA", "CYRILLIC CAPITAL LETTER EN WITH DESCENDER",
+        "CYRILLIC SMALL LETTER EN WITH DESCENDER", "CYRILLIC CAPITAL LIGATURE EN GHE",
+        "CYRILLIC SMALL LIGATURE EN GHE", "CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK",
+        "CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK", "CYRILLIC CAPITAL LETTER ABKHASIAN HA",
+        "CYRILLIC SMALL LETTER ABKHASIAN HA", "CYRILLIC CAPITAL LETTER ES WITH DESCENDER",
+        "CYRILLIC SMALL LETTER ES WITH DESCENDER", "CYRILLIC CAPITAL LETTER TE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER TE WITH DESCENDER", "CYRILLIC CAPITAL LETTER STRAIGHT U",
+        "CYRILLIC SMALL LETTER STRAIGHT U", "CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE",
+        "CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE", "CYRILLIC CAPITAL LETTER HA WITH DESCENDER",
+        "CYRILLIC SMALL LETTER HA WITH DESCENDER", "CYRILLIC CAPITAL LIGATURE TE TSE",
+        "CYRILLIC SMALL LIGATURE TE TSE", "CYRILLIC CAPITAL LETTER CHE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER CHE WITH DESCENDER",
+        "CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE",
+        "CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE", "CYRILLIC CAPITAL LETTER SHHA",
+        "CYRILLIC SMALL LETTER SHHA", "CYRILLIC CAPITAL LETTER ABKHASIAN CHE",
+        "CYRILLIC SMALL LETTER ABKHASIAN CHE",
+        "CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER", "CYRILLIC LETTER PALOCHKA",
+        "CYRILLIC CAPITAL LETTER ZHE WITH BREVE", "CYRILLIC SMALL LETTER ZHE WITH BREVE",
+        "CYRILLIC CAPITAL LETTER KA WITH HOOK", "CYRILLIC SMALL LETTER KA WITH HOOK",
+        "CYRILLIC CAPITAL LETTER EL WITH TAIL", "CYRILLIC SMALL LETTER EL WITH TAIL",
+        "CYRILLIC CAPITAL LETTER EN WITH HOOK", "CYRILLIC SMALL LETTER EN WITH HOOK",
+        "CYRILLIC CAPITAL LETTER EN WITH TAIL", "CYRILLIC SMALL LETTER EN WITH TAIL",
+        "CYRILLIC CAPITAL LETTER KHAKASSIAN CHE", "CYRILLIC SMALL LETTER KHAKASSIAN CHE",
+        "CYRILLIC CAPITAL LETTER EM WITH TAIL", "CYRILLIC SMALL LETTER EM WITH TAIL",
+        "CYRILLIC SMALL LETTER PALOCHKA", "CYRILLIC CAPITAL LETTER A WITH BREVE",
+        "CYRILLIC SMALL LETTER A WITH BREVE", "CYRILLIC CAPITAL LETTER A WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER A WITH DIAERESIS", "CYRILLIC CAPITAL LIGATURE A IE",
+        "CYRILLIC SMALL LIGATURE A IE", "CYRILLIC CAPITAL LETTER IE WITH BREVE",
+        "CYRILLIC SMALL LETTER IE WITH BREVE", "CYRILLIC CAPITAL LETTER SCHWA",
+        "CYRILLIC SMALL LETTER SCHWA", "CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS", "CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER ZHE WITH DIAERESIS", "CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER ZE WITH DIAERESIS", "CYRILLIC CAPITAL LETTER ABKHASIAN DZE",
+        "CYRILLIC SMALL LETTER ABKHASIAN DZE", "CYRILLIC CAPITAL LETTER I WITH MACRON",
+        "CYRILLIC SMALL LETTER I WITH MACRON", "CYRILLIC CAPITAL LETTER I WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER I WITH DIAERESIS", "CYRILLIC CAPITAL LETTER O WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER O WITH DIAERESIS", "CYRILLIC CAPITAL LETTER BARRED O",
+        "CYRILLIC SMALL LETTER BARRED O", "CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS", "CYRILLIC CAPITAL LETTER E WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER E WITH DIAERESIS", "CYRILLIC CAPITAL LETTER U WITH MACRON",
+        "CYRILLIC SMALL LETTER U WITH MACRON", "CYRILLIC CAPITAL LETTER U WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER U WITH DIAERESIS", "CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE",
+        "CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE", "CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER CHE WITH DIAERESIS", "CYRILLIC CAPITAL LETTER GHE WITH DESCENDER",
+        "CYRILLIC SMALL LETTER GHE WITH DESCENDER", "CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS",
+        "CYRILLIC SMALL LETTER YERU WITH DIAERESIS",
+        "CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK",
+        "CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK", "CYRILLIC CAPITAL LETTER HA WITH HOOK",
+        "CYRILLIC SMALL LETTER HA WITH HOOK", "CYRILLIC CAPITAL LETTER HA WITH STROKE",
+        "CYRILLIC SMALL LETTER HA WITH STROKE", "CYRILLIC CAPITAL LETTER KOMI DE",
+        "CYRILLIC SMALL LETTER KOMI DE", "CYRILLIC CAPITAL LETTER KOMI DJE",
+        "CYRILLIC SMALL LETTER KOMI DJE", "CYRILLIC CAPITAL LETTER KOMI ZJE",
+        "CYRILLIC SMALL LETTER KOMI ZJE", "CYRILLIC CAPITAL LETTER KOMI DZJE",
+        "CYRILLIC SMALL LETTER KOMI DZJE", "CYRILLIC CAPITAL LETTER KOMI LJE",
+        "CYRILLIC SMALL LETTER KOMI LJE", "CYRILLIC CAPITAL LETTER KOMI NJE",
+        "CYRILLIC SMALL LETTER KOMI NJE", "CYRILLIC CAPITAL LETTER KOMI SJE",
+        "CYRILLIC SMALL LETTER KOMI SJE", "CYRILLIC CAPITAL LETTER KOMI TJE",
+        "CYRILLIC SMALL LETTER KOMI TJE", "CYRILLIC CAPITAL LETTER REVERSED ZE",
+        "CYRILLIC SMALL LETTER REVERSED ZE", "CYRILLIC CAPITAL LETTER EL WITH HOOK",
+        "CYRILLIC SMALL LETTER EL WITH HOOK", "CYRILLIC CAPITAL LETTER LHA",
+        "CYRILLIC SMALL LETTER LHA", "CYRILLIC CAPITAL LETTER RHA", "CYRILLIC SMALL LETTER RHA",
+        "CYRILLIC CAPITAL LETTER YAE", "CYRILLIC SMALL LETTER YAE", "CYRILLIC CAPITAL LETTER QA",
+        "CYRILLIC SMALL LETTER QA", "CYRILLIC CAPITAL LETTER WE", "CYRILLIC SMALL LETTER WE",
+        "CYRILLIC CAPITAL LETTER ALEUT KA", "CYRILLIC SMALL LETTER ALEUT KA",
+        "CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK", "CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK",
+        "CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK", "CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK",
+        "CYRILLIC CAPITAL LETTER PE WITH DESCENDER", "CYRILLIC SMALL LETTER PE WITH DESCENDER",
+        "CYRILLIC CAPITAL LETTER SHHA WITH DESCENDER", "CYRILLIC SMALL LETTER SHHA WITH DESCENDER",
+        "CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK", "CYRILLIC SMALL LETTER EN WITH LEFT HOOK",
+        "CYRILLIC CAPITAL LETTER DZZHE", "CYRILLIC SMALL LETTER DZZHE",
+        "CYRILLIC CAPITAL LETTER DCHE", "CYRILLIC SMALL LETTER DCHE",
+        "CYRILLIC CAPITAL LETTER EL WITH DESCENDER", "CYRILLIC SMALL LETTER EL WITH DESCENDER",
+    ]),
+    (0x1AB0, &[
+        "COMBINING DOUBLED CIRCUMFLEX ACCENT", "COMBINING DIAERESIS-RING", "COMBINING INFINITY",
+        "COMBINING DOWNWARDS ARROW", "COMBINING TRIPLE DOT", "COMBINING X-X BELOW",
+        "COMBINING WIGGLY LINE BELOW", "COMBINING OPEN MARK BELOW",
+        "COMBINING DOUBLE OPEN MARK BELOW", "COMBINING LIGHT CENTRALIZATION STROKE BELOW",
+        "COMBINING STRONG CENTRALIZATION STROKE BELOW", "COMBINING PARENTHESES ABOVE",
+        "COMBINING DOUBLE PARENTHESES ABOVE", "COMBINING PARENTHESES BELOW",
+        "COMBINING PARENTHESES OVERLAY", "COMBINING LATIN SMALL LETTER W BELOW",
+        "COMBINING LATIN SMALL LETTER TURNED W BELOW", "COMBINING LEFT PARENTHESIS ABOVE LEFT",
+        "COMBINING RIGHT PARENTHESIS ABOVE RIGHT", "COMBINING LEFT PARENTHESIS BELOW LEFT",
+        "COMBINING RIGHT PARENTHESIS BELOW RIGHT", "COMBINING SQUARE BRACKETS ABOVE",
+        "COMBINING NUMBER SIGN ABOVE", "COMBINING INVERTED DOUBLE ARCH ABOVE",
+        "COMBINING PLUS SIGN ABOVE", "COMBINING DOUBLE PLUS SIGN ABOVE",
+        "COMBINING DOUBLE PLUS SIGN BELOW", "COMBINING TRIPLE ACUTE ACCENT",
+        "COMBINING LATIN SMALL LETTER INSULAR G", "COMBINING LATIN SMALL LETTER INSULAR R",
+        "COMBINING LATIN SMALL LETTER INSULAR T",
+    ]),
+    (0x1C80, &[
+        "CYRILLIC SMALL LETTER ROUNDED VE", "CYRILLIC SMALL LETTER LONG-LEGGED DE",
+        "CYRILLIC SMALL LETTER NARROW O", "CYRILLIC SMALL LETTER WIDE ES",
+        "CYRILLIC SMALL LETTER TALL TE", "CYRILLIC SMALL LETTER THREE-LEGGED TE",
+        "CYRILLIC SMALL LETTER TALL HARD SIGN", "CYRILLIC SMALL LETTER TALL YAT",
+        "CYRILLIC SMALL LETTER UNBLENDED UK", "CYRILLIC CAPITAL LETTER TJE",
+        "CYRILLIC SMALL LETTER TJE",
+    ]),
+    (0x1D00, &[
+        "LATIN LETTER SMALL CAPITAL A", "LATIN LETTER SMALL CAPITAL AE",
+        "LATIN SMALL LETTER TURNED AE", "LATIN LETTER SMALL CAPITAL BARRED B",
+        "LATIN LETTER SMALL CAPITAL C", "LATIN LETTER SMALL CAPITAL D",
+        "LATIN LETTER SMALL CAPITAL ETH", "LATIN LETTER SMALL CAPITAL E",
+        "LATIN SMALL LETTER TURNED OPEN E", "LATIN SMALL LETTER TURNED I",
+        "LATIN LETTER SMALL CAPITAL J", "LATIN LETTER SMALL CAPITAL K",
+        "LATIN LETTER SMALL CAPITAL L WITH STROKE", "LATIN LETTER SMALL CAPITAL M",
+        "LATIN LETTER SMALL CAPITAL REVERSED N", "LATIN LETTER SMALL CAPITAL O",
+        "LATIN LETTER SMALL CAPITAL OPEN O", "LATIN SMALL LETTER SIDEWAYS O",
+        "LATIN SMALL LETTER SIDEWAYS OPEN O", "LATIN SMALL LETTER SIDEWAYS O WITH STROKE",
+        "LATIN SMALL LETTER TURNED OE", "LATIN LETTER SMALL CAPITAL OU",
+        "LATIN SMALL LETTER TOP HALF O", "LATIN SMALL LETTER BOTTOM HALF O",
+        "LATIN LETTER SMALL CAPITAL P", "LATIN LETTER SMALL CAPITAL REVERSED R",
+        "LATIN LETTER SMALL CAPITAL TURNED R", "LATIN LETTER SMALL CAPITAL T",
+        "LATIN LETTER SMALL CAPITAL U", "LATIN SMALL LETTER SIDEWAYS U",
+        "LATIN SMALL LETTER SIDEWAYS DIAERESIZED U", "LATIN SMALL LETTER SIDEWAYS TURNED M",
+        "LATIN LETTER SMALL CAPITAL V", "LATIN LETTER SMALL CAPITAL W",
+        "LATIN LETTER SMALL CAPITAL Z", "LATIN LETTER SMALL CAPITAL EZH",
+        "LATIN LETTER VOICED LARYNGEAL SPIRANT", "LATIN LETTER AIN",
+        "GREEK LETTER SMALL CAPITAL GAMMA", "GREEK LETTER SMALL CAPITAL LAMDA",
+        "GREEK LETTER SMALL CAPITAL PI", "GREEK LETTER SMALL CAPITAL RHO",
+        "GREEK LETTER SMALL CAPITAL PSI", "CYRILLIC LETTER SMALL CAPITAL EL",
+        "MODIFIER LETTER CAPITAL A", "MODIFIER LETTER CAPITAL AE", "MODIFIER LETTER CAPITAL B",
+        "MODIFIER LETTER CAPITAL BARRED B", "MODIFIER LETTER CAPITAL D",
+        "MODIFIER LETTER CAPITAL E", "MODIFIER LETTER CAPITAL REVERSED E",
+        "MODIFIER LETTER CAPITAL G", "MODIFIER LETTER CAPITAL H", "MODIFIER LETTER CAPITAL I",
+        "MODIFIER LETTER CAPITAL J", "MODIFIER LETTER CAPITAL K", "MODIFIER LETTER CAPITAL L",
+        "MODIFIER LETTER CAPITAL M", "MODIFIER LETTER CAPITAL N",
+        "MODIFIER LETTER CAPITAL REVERSED N", "MODIFIER LETTER CAPITAL O",
+        "MODIFIER LETTER CAPITAL OU", "MODIFIER LETTER CAPITAL P", "MODIFIER LETTER CAPITAL R",
+        "MODIFIER LETTER CAPITAL T", "MODIFIER LETTER CAPITAL U", "MODIFIER LETTER CAPITAL W",
+        "MODIFIER LETTER SMALL A", "MODIFIER LETTER SMALL TURNED A", "MODIFIER LETTER SMALL ALPHA",
+        "MODIFIER LETTER SMALL TURNED AE", "MODIFIER LETTER SMALL B", "MODIFIER LETTER SMALL D",
+        "MODIFIER LETTER SMALL E", "MODIFIER LETTER SMALL SCHWA", "MODIFIER LETTER SMALL OPEN E",
+        "MODIFIER LETTER SMALL TURNED OPEN E", "MODIFIER LETTER SMALL G",
+        "MODIFIER LETTER SMALL TURNED I", "MODIFIER LETTER SMALL K", "MODIFIER LETTER SMALL M",
+        "MODIFIER LETTER SMALL ENG", "MODIFIER LETTER SMALL O", "MODIFIER LETTER SMALL OPEN O",
+        "MODIFIER LETTER SMALL TOP HALF O", "MODIFIER LETTER SMALL BOTTOM HALF O",
+        "MODIFIER LETTER SMALL P", "MODIFIER LETTER SMALL T", "MODIFIER LETTER SMALL U",
+        "MODIFIER LETTER SMALL SIDEWAYS U", "MODIFIER LETTER SMALL TURNED M",
+        "MODIFIER LETTER SMALL V", "MODIFIER LETTER SMALL AIN", "MODIFIER LETTER SMALL BETA",
+        "MODIFIER LETTER SMALL GREEK GAMMA", "MODIFIER LETTER SMALL DELTA",
+        "MODIFIER LETTER SMALL GREEK PHI", "MODIFIER LETTER SMALL CHI",
+        "LATIN SUBSCRIPT SMALL LETTER I", "LATIN SUBSCRIPT SMALL LETTER R",
+        "LATIN SUBSCRIPT SMALL LETTER U", "LATIN SUBSCRIPT SMALL LETTER V",
+        "GREEK SUBSCRIPT SMALL LETTER BETA", "GREEK SUBSCRIPT SMALL LETTER GAMMA",
+        "GREEK SUBSCRIPT SMALL LETTER RHO", "GREEK SUBSCRIPT SMALL LETTER PHI",
+        "GREEK SUBSCRIPT SMALL LETTER CHI", "LATIN SMALL LETTER UE",
+        "LATIN SMALL LETTER B WITH MIDDLE TILDE", "LATIN SMALL LETTER D WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER F WITH MIDDLE TILDE", "LATIN SMALL LETTER M WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER N WITH MIDDLE TILDE", "LATIN SMALL LETTER P WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER R WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER R WITH FISHHOOK AND MIDDLE TILDE",
+        "LATIN SMALL LETTER S WITH MIDDLE TILDE", "LATIN SMALL LETTER T WITH MIDDLE TILDE",
+        "LATIN SMALL LETTER Z WITH MIDDLE TILDE", "LATIN SMALL LETTER TURNED G",
+        "MODIFIER LETTER CYRILLIC EN", "LATIN SMALL LETTER INSULAR G",
+        "LATIN SMALL LETTER TH WITH STRIKETHROUGH", "LATIN SMALL CAPITAL LETTER I WITH STROKE",
+        "LATIN SMALL LETTER IOTA WITH STROKE", "LATIN SMALL LETTER P WITH STROKE",
+        "LATIN SMALL CAPITAL LETTER U WITH STROKE", "LATIN SMALL LETTER UPSILON WITH STROKE",
+        "LATIN SMALL LETTER B WITH PALATAL HOOK", "LATIN SMALL LETTER D WITH PALATAL HOOK",
+        "LATIN SMALL LETTER F WITH PALATAL HOOK", "LATIN SMALL LETTER G WITH PALATAL HOOK",
+        "LATIN SMALL LETTER K WITH PALATAL HOOK", "LATIN SMALL LETTER L WITH PALATAL HOOK",
+        "LATIN SMALL LETTER M WITH PALATAL HOOK", "LATIN SMALL LETTER N WITH PALATAL HOOK",
+        "LATIN SMALL LETTER P WITH PALATAL HOOK", "LATIN SMALL LETTER R WITH PALATAL HOOK",
+        "LATIN SMALL LETTER S WITH PALATAL HOOK", "LATIN SMALL LETTER ESH WITH PALATAL HOOK",
+        "LATIN SMALL LETTER V WITH PALATAL HOOK", "LATIN SMALL LETTER X WITH PALATAL HOOK",
+        "LATIN SMALL LETTER Z WITH PALATAL HOOK", "LATIN SMALL LETTER A WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER ALPHA WITH RETROFLEX HOOK", "LATIN SMALL LETTER D WITH HOOK AND TAIL",
+        "LATIN SMALL LETTER E WITH RETROFLEX HOOK", "LATIN SMALL LETTER OPEN E WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER REVERSED OPEN E WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER SCHWA WITH RETROFLEX HOOK", "LATIN SMALL LETTER I WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER OPEN O WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER ESH WITH RETROFLEX HOOK", "LATIN SMALL LETTER U WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER EZH WITH RETROFLEX HOOK", "MODIFIER LETTER SMALL TURNED ALPHA",
+        "MODIFIER LETTER SMALL C", "MODIFIER LETTER SMALL C WITH CURL", "MODIFIER LETTER SMALL ETH",
+        "MODIFIER LETTER SMALL REVERSED OPEN E", "MODIFIER LETTER SMALL F",
+        "MODIFIER LETTER SMALL DOTLESS J WITH STROKE", "MODIFIER LETTER SMALL SCRIPT G",
+        "MODIFIER LETTER SMALL TURNED H", "MODIFIER LETTER SMALL I WITH STROKE",
+        "MODIFIER LETTER SMALL IOTA", "MODIFIER LETTER SMALL CAPITAL I",
+        "MODIFIER LETTER SMALL CAPITAL I WITH STROKE", "MODIFIER LETTER SMALL J WITH CROSSED-TAIL",
+        "MODIFIER LETTER SMALL L WITH RETROFLEX HOOK", "MODIFIER LETTER SMALL L WITH PALATAL HOOK",
+        "MODIFIER LETTER SMALL CAPITAL L", "MODIFIER LETTER SMALL M WITH HOOK",
+        "MODIFIER LETTER SMALL TURNED M WITH LONG LEG", "MODIFIER LETTER SMALL N WITH LEFT HOOK",
+        "MODIFIER LETTER SMALL N WITH RETROFLEX HOOK", "MODIFIER LETTER SMALL CAPITAL N",
+        "MODIFIER LETTER SMALL BARRED O", "MODIFIER LETTER SMALL PHI",
+        "MODIFIER LETTER SMALL S WITH HOOK", "MODIFIER LETTER SMALL ESH",
+        "MODIFIER LETTER SMALL T WITH PALATAL HOOK", "MODIFIER LETTER SMALL U BAR",
+        "MODIFIER LETTER SMALL UPSILON", "MODIFIER LETTER SMALL CAPITAL U",
+        "MODIFIER LETTER SMALL V WITH HOOK", "MODIFIER LETTER SMALL TURNED V",
+        "MODIFIER LETTER SMALL Z", "MODIFIER LETTER SMALL Z WITH RETROFLEX HOOK",
+        "MODIFIER LETTER SMALL Z WITH CURL", "MODIFIER LETTER SMALL EZH",
+        "MODIFIER LETTER SMALL THETA", "COMBINING DOTTED GRAVE ACCENT",
+        "COMBINING DOTTED ACUTE ACCENT", "COMBINING SNAKE BELOW", "COMBINING SUSPENSION MARK",
+        "COMBINING MACRON-ACUTE", "COMBINING GRAVE-MACRON", "COMBINING MACRON-GRAVE",
+        "COMBINING ACUTE-MACRON", "COMBINING GRAVE-ACUTE-GRAVE", "COMBINING ACUTE-GRAVE-ACUTE",
+        "COMBINING LATIN SMALL LETTER R BELOW", "COMBINING BREVE-MACRON", "COMBINING MACRON-BREVE",
+        "COMBINING DOUBLE CIRCUMFLEX ABOVE", "COMBINING OGONEK ABOVE", "COMBINING ZIGZAG BELOW",
+        "COMBINING IS BELOW", "COMBINING UR ABOVE", "COMBINING US ABOVE",
+        "COMBINING LATIN SMALL LETTER FLATTENED OPEN A ABOVE", "COMBINING LATIN SMALL LETTER AE",
+        "COMBINING LATIN SMALL LETTER AO", "COMBINING LATIN SMALL LETTER AV",
+        "COMBINING LATIN SMALL LETTER C CEDILLA", "COMBINING LATIN SMALL LETTER INSULAR D",
+        "COMBINING LATIN SMALL LETTER ETH", "COMBINING LATIN SMALL LETTER G",
+        "COMBINING LATIN LETTER SMALL CAPITAL G", "COMBINING LATIN SMALL LETTER K",
+        "COMBINING LATIN SMALL LETTER L", "COMBINING LATIN LETTER SMALL CAPITAL L",
+        "COMBINING LATIN LETTER SMALL CAPITAL M", "COMBINING LATIN SMALL LETTER N",
+        "COMBINING LATIN LETTER SMALL CAPITAL N", "COMBINING LATIN LETTER SMALL CAPITAL R",
+        "COMBINING LATIN SMALL LETTER R ROTUNDA", "COMBINING LATIN SMALL LETTER S",
+        "COMBINING LATIN SMALL LETTER LONG S", "COMBINING LATIN SMALL LETTER Z",
+        "COMBINING LATIN SMALL LETTER ALPHA", "COMBINING LATIN SMALL LETTER B",
+        "COMBINING LATIN SMALL LETTER BETA", "COMBINING LATIN SMALL LETTER SCHWA",
+        "COMBINING LATIN SMALL LETTER F", "COMBINING LATIN SMALL LETTER L WITH DOUBLE MIDDLE TILDE",
+        "COMBINING LATIN SMALL LETTER O WITH LIGHT CENTRALIZATION STROKE",
+        "COMBINING LATIN SMALL LETTER P", "COMBINING LATIN SMALL LETTER ESH",
+        "COMBINING LATIN SMALL LETTER U WITH LIGHT CENTRALIZATION STROKE",
+        "COMBINING LATIN SMALL LETTER W", "COMBINING LATIN SMALL LETTER A WITH DIAERESIS",
+        "COMBINING LATIN SMALL LETTER O WITH DIAERESIS",
+        "COMBINING LATIN SMALL LETTER U WITH DIAERESIS", "COMBINING UP TACK ABOVE",
+        "COMBINING KAVYKA ABOVE RIGHT", "COMBINING KAVYKA ABOVE LEFT", "COMBINING DOT ABOVE LEFT",
+        "COMBINING WIDE INVERTED BRIDGE BELOW", "COMBINING DOT BELOW LEFT",
+        "COMBINING DELETION MARK", "COMBINING DOUBLE INVERTED BREVE BELOW",
+        "COMBINING ALMOST EQUAL TO BELOW", "COMBINING LEFT ARROWHEAD ABOVE",
+        "COMBINING RIGHT ARROWHEAD AND DOWN ARROWHEAD BELOW",
+        "LATIN CAPITAL LETTER A WITH RING BELOW", "LATIN SMALL LETTER A WITH RING BELOW",
+        "LATIN CAPITAL LETTER B WITH DOT ABOVE", "LATIN SMALL LETTER B WITH DOT ABOVE",
+        "LATIN CAPITAL LETTER B WITH DOT BELOW", "LATIN SMALL LETTER B WITH DOT BELOW",
+        "LATIN CAPITAL LETTER B WITH LINE BELOW", "LATIN SMALL LETTER B WITH LINE BELOW",
+        "LATIN CAPITAL LETTER C WITH CEDILLA AND ACUTE",
+        "LATIN SMALL LETTER C WITH CEDILLA AND ACUTE", "LATIN CAPITAL LETTER D WITH DOT ABOVE",
+        "LATIN SMALL LETTER D WITH DOT ABOVE", "LATIN CAPITAL LETTER D WITH DOT BELOW",
+        "LATIN SMALL LETTER D WITH DOT BELOW", "LATIN CAPITAL LETTER D WITH LINE BELOW",
+        "LATIN SMALL LETTER D WITH LINE BELOW", "LATIN CAPITAL LETTER D WITH CEDILLA",
+        "LATIN SMALL LETTER D WITH CEDILLA", "LATIN CAPITAL LETTER D WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER D WITH CIRCUMFLEX BELOW",
+        "LATIN CAPITAL LETTER E WITH MACRON AND GRAVE",
+        "LATIN SMALL LETTER E WITH MACRON AND GRAVE",
+        "LATIN CAPITAL LETTER E WITH MACRON AND ACUTE",
+        "LATIN SMALL LETTER E WITH MACRON AND ACUTE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX BELOW", "LATIN CAPITAL LETTER E WITH TILDE BELOW",
+        "LATIN SMALL LETTER E WITH TILDE BELOW", "LATIN CAPITAL LETTER E WITH CEDILLA AND BREVE",
+        "LATIN SMALL LETTER E WITH CEDILLA AND BREVE", "LATIN CAPITAL LETTER F WITH DOT ABOVE",
+        "LATIN SMALL LETTER F WITH DOT ABOVE", "LATIN CAPITAL LETTER G WITH MACRON",
+        "LATIN SMALL LETTER G WITH MACRON", "LATIN CAPITAL LETTER H WITH DOT ABOVE",
+        "LATIN SMALL LETTER H WITH DOT ABOVE", "LATIN CAPITAL LETTER H WITH DOT BELOW",
+        "LATIN SMALL LETTER H WITH DOT BELOW", "LATIN CAPITAL LETTER H WITH DIAERESIS",
+        "LATIN SMALL LETTER H WITH DIAERESIS", "LATIN CAPITAL LETTER H WITH CEDILLA",
+        "LATIN SMALL LETTER H WITH CEDILLA", "LATIN CAPITAL LETTER H WITH BREVE BELOW",
+        "LATIN SMALL LETTER H WITH BREVE BELOW", "LATIN CAPITAL LETTER I WITH TILDE BELOW",
+        "LATIN SMALL LETTER I WITH TILDE BELOW", "LATIN CAPITAL LETTER I WITH DIAERESIS AND ACUTE",
+        "LATIN SMALL LETTER I WITH DIAERESIS AND ACUTE", "LATIN CAPITAL LETTER K WITH ACUTE",
+        "LATIN SMALL LETTER K WITH ACUTE", "LATIN CAPITAL LETTER K WITH DOT BELOW",
+        "LATIN SMALL LETTER K WITH DOT BELOW", "LATIN CAPITAL LETTER K WITH LINE BELOW",
+        "LATIN SMALL LETTER K WITH LINE BELOW", "LATIN CAPITAL LETTER L WITH DOT BELOW",
+        "LATIN SMALL LETTER L WITH DOT BELOW", "LATIN CAPITAL LETTER L WITH DOT BELOW AND MACRON",
+        "LATIN SMALL LETTER L WITH DOT BELOW AND MACRON", "LATIN CAPITAL LETTER L WITH LINE BELOW",
+        "LATIN SMALL LETTER L WITH LINE BELOW", "LATIN CAPITAL LETTER L WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER L WITH CIRCUMFLEX BELOW", "LATIN CAPITAL LETTER M WITH ACUTE",
+        "LATIN SMALL LETTER M WITH ACUTE", "LATIN CAPITAL LETTER M WITH DOT ABOVE",
+        "LATIN SMALL LETTER M WITH DOT ABOVE", "LATIN CAPITAL LETTER M WITH DOT BELOW",
+        "LATIN SMALL LETTER M WITH DOT BELOW", "LATIN CAPITAL LETTER N WITH DOT ABOVE",
+        "LATIN SMALL LETTER N WITH DOT ABOVE", "LATIN CAPITAL LETTER N WITH DOT BELOW",
+        "LATIN SMALL LETTER N WITH DOT BELOW", "LATIN CAPITAL LETTER N WITH LINE BELOW",
+        "LATIN SMALL LETTER N WITH LINE BELOW", "LATIN CAPITAL LETTER N WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER N WITH CIRCUMFLEX BELOW", "LATIN CAPITAL LETTER O WITH TILDE AND ACUTE",
+        "LATIN SMALL LETTER O WITH TILDE AND ACUTE",
+        "LATIN CAPITAL LETTER O WITH TILDE AND DIAERESIS",
+        "LATIN SMALL LETTER O WITH TILDE AND DIAERESIS",
+        "LATIN CAPITAL LETTER O WITH MACRON AND GRAVE",
+        "LATIN SMALL LETTER O WITH MACRON AND GRAVE",
+        "LATIN CAPITAL LETTER O WITH MACRON AND ACUTE",
+        "LATIN SMALL LETTER O WITH MACRON AND ACUTE", "LATIN CAPITAL LETTER P WITH ACUTE",
+        "LATIN SMALL LETTER P WITH ACUTE", "LATIN CAPITAL LETTER P WITH DOT ABOVE",
+        "LATIN SMALL LETTER P WITH DOT ABOVE", "LATIN CAPITAL LETTER R WITH DOT ABOVE",
+        "LATIN SMALL LETTER R WITH DOT ABOVE", "LATIN CAPITAL LETTER R WITH DOT BELOW",
+        "LATIN SMALL LETTER R WITH DOT BELOW", "LATIN CAPITAL LETTER R WITH DOT BELOW AND MACRON",
+        "LATIN SMALL LETTER R WITH DOT BELOW AND MACRON", "LATIN CAPITAL LETTER R WITH LINE BELOW",
+        "LATIN SMALL LETTER R WITH LINE BELOW", "LATIN CAPITAL LETTER S WITH DOT ABOVE",
+        "LATIN SMALL LETTER S WITH DOT ABOVE", "LATIN CAPITAL LETTER S WITH DOT BELOW",
+        "LATIN SMALL LETTER S WITH DOT BELOW", "LATIN CAPITAL LETTER S WITH ACUTE AND DOT ABOVE",
+        "LATIN SMALL LETTER S WITH ACUTE AND DOT ABOVE",
+        "LATIN CAPITAL LETTER S WITH CARON AND DOT ABOVE",
+        "LATIN SMALL LETTER S WITH CARON AND DOT ABOVE",
+        "LATIN CAPITAL LETTER S WITH DOT BELOW AND DOT ABOVE",
+        "LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE",
+        "LATIN CAPITAL LETTER T WITH DOT ABOVE", "LATIN SMALL LETTER T WITH DOT ABOVE",
+        "LATIN CAPITAL LETTER T WITH DOT BELOW", "LATIN SMALL LETTER T WITH DOT BELOW",
+        "LATIN CAPITAL LETTER T WITH LINE BELOW", "LATIN SMALL LETTER T WITH LINE BELOW",
+        "LATIN CAPITAL LETTER T WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER T WITH CIRCUMFLEX BELOW", "LATIN CAPITAL LETTER U WITH DIAERESIS BELOW",
+        "LATIN SMALL LETTER U WITH DIAERESIS BELOW", "LATIN CAPITAL LETTER U WITH TILDE BELOW",
+        "LATIN SMALL LETTER U WITH TILDE BELOW", "LATIN CAPITAL LETTER U WITH CIRCUMFLEX BELOW",
+        "LATIN SMALL LETTER U WITH CIRCUMFLEX BELOW", "LATIN CAPITAL LETTER U WITH TILDE AND ACUTE",
+        "LATIN SMALL LETTER U WITH TILDE AND ACUTE",
+        "LATIN CAPITAL LETTER U WITH MACRON AND DIAERESIS",
+        "LATIN SMALL LETTER U WITH MACRON AND DIAERESIS", "LATIN CAPITAL LETTER V WITH TILDE",
+        "LATIN SMALL LETTER V WITH TILDE", "LATIN CAPITAL LETTER V WITH DOT BELOW",
+        "LATIN SMALL LETTER V WITH DOT BELOW", "LATIN CAPITAL LETTER W WITH GRAVE",
+        "LATIN SMALL LETTER W WITH GRAVE", "LATIN CAPITAL LETTER W WITH ACUTE",
+        "LATIN SMALL LETTER W WITH ACUTE", "LATIN CAPITAL LETTER W WITH DIAERESIS",
+        "LATIN SMALL LETTER W WITH DIAERESIS", "LATIN CAPITAL LETTER W WITH DOT ABOVE",
+        "LATIN SMALL LETTER W WITH DOT ABOVE", "LATIN CAPITAL LETTER W WITH DOT BELOW",
+        "LATIN SMALL LETTER W WITH DOT BELOW", "LATIN CAPITAL LETTER X WITH DOT ABOVE",
+        "LATIN SMALL LETTER X WITH DOT ABOVE", "LATIN CAPITAL LETTER X WITH DIAERESIS",
+        "LATIN SMALL LETTER X WITH DIAERESIS", "LATIN CAPITAL LETTER Y WITH DOT ABOVE",
+        "LATIN SMALL LETTER Y WITH DOT ABOVE", "LATIN CAPITAL LETTER Z WITH CIRCUMFLEX",
+        "LATIN SMALL LETTER Z WITH CIRCUMFLEX", "LATIN CAPITAL LETTER Z WITH DOT BELOW",
+        "LATIN SMALL LETTER Z WITH DOT BELOW", "LATIN CAPITAL LETTER Z WITH LINE BELOW",
+        "LATIN SMALL LETTER Z WITH LINE BELOW", "LATIN SMALL LETTER H WITH LINE BELOW",
+        "LATIN SMALL LETTER T WITH DIAERESIS", "LATIN SMALL LETTER W WITH RING ABOVE",
+        "LATIN SMALL LETTER Y WITH RING ABOVE", "LATIN SMALL LETTER A WITH RIGHT HALF RING",
+        "LATIN SMALL LETTER LONG S WITH DOT ABOVE",
+        "LATIN SMALL LETTER LONG S WITH DIAGONAL STROKE",
+        "LATIN SMALL LETTER LONG S WITH HIGH STROKE", "LATIN CAPITAL LETTER SHARP S",
+        "LATIN SMALL LETTER DELTA", "LATIN CAPITAL LETTER A WITH DOT BELOW",
+        "LATIN SMALL LETTER A WITH DOT BELOW", "LATIN CAPITAL LETTER A WITH HOOK ABOVE",
+        "LATIN SMALL LETTER A WITH HOOK ABOVE", "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE",
+        "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE",
+        "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE",
+        "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN CAPITAL LETTER A WITH BREVE AND ACUTE", "LATIN SMALL LETTER A WITH BREVE AND ACUTE",
+        "LATIN CAPITAL LETTER A WITH BREVE AND GRAVE", "LATIN SMALL LETTER A WITH BREVE AND GRAVE",
+        "LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE",
+        "LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER A WITH BREVE AND TILDE", "LATIN SMALL LETTER A WITH BREVE AND TILDE",
+        "LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW",
+        "LATIN SMALL LETTER A WITH BREVE AND DOT BELOW", "LATIN CAPITAL LETTER E WITH DOT BELOW",
+        "LATIN SMALL LETTER E WITH DOT BELOW", "LATIN CAPITAL LETTER E WITH HOOK ABOVE",
+        "LATIN SMALL LETTER E WITH HOOK ABOVE", "LATIN CAPITAL LETTER E WITH TILDE",
+        "LATIN SMALL LETTER E WITH TILDE", "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE",
+        "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN CAPITAL LETTER I WITH HOOK ABOVE", "LATIN SMALL LETTER I WITH HOOK ABOVE",
+        "LATIN CAPITAL LETTER I WITH DOT BELOW", "LATIN SMALL LETTER I WITH DOT BELOW",
+        "LATIN CAPITAL LETTER O WITH DOT BELOW", "LATIN SMALL LETTER O WITH DOT BELOW",
+        "LATIN CAPITAL LETTER O WITH HOOK ABOVE", "LATIN SMALL LETTER O WITH HOOK ABOVE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE",
+        "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW",
+        "LATIN CAPITAL LETTER O WITH HORN AND ACUTE", "LATIN SMALL LETTER O WITH HORN AND ACUTE",
+        "LATIN CAPITAL LETTER O WITH HORN AND GRAVE", "LATIN SMALL LETTER O WITH HORN AND GRAVE",
+        "LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE",
+        "LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER O WITH HORN AND TILDE", "LATIN SMALL LETTER O WITH HORN AND TILDE",
+        "LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW",
+        "LATIN SMALL LETTER O WITH HORN AND DOT BELOW", "LATIN CAPITAL LETTER U WITH DOT BELOW",
+        "LATIN SMALL LETTER U WITH DOT BELOW", "LATIN CAPITAL LETTER U WITH HOOK ABOVE",
+        "LATIN SMALL LETTER U WITH HOOK ABOVE", "LATIN CAPITAL LETTER U WITH HORN AND ACUTE",
+        "LATIN SMALL LETTER U WITH HORN AND ACUTE", "LATIN CAPITAL LETTER U WITH HORN AND GRAVE",
+        "LATIN SMALL LETTER U WITH HORN AND GRAVE",
+        "LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE",
+        "LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE",
+        "LATIN CAPITAL LETTER U WITH HORN AND TILDE", "LATIN SMALL LETTER U WITH HORN AND TILDE",
+        "LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW",
+        "LATIN SMALL LETTER U WITH HORN AND DOT BELOW", "LATIN CAPITAL LETTER Y WITH GRAVE",
+        "LATIN SMALL LETTER Y WITH GRAVE", "LATIN CAPITAL LETTER Y WITH DOT BELOW",
+        "LATIN SMALL LETTER Y WITH DOT BELOW", "LATIN CAPITAL LETTER Y WITH HOOK ABOVE",
+        "LATIN SMALL LETTER Y WITH HOOK ABOVE", "LATIN CAPITAL LETTER Y WITH TILDE",
+        "LATIN SMALL LETTER Y WITH TILDE", "LATIN CAPITAL LETTER MIDDLE-WELSH LL",
+        "LATIN SMALL LETTER MIDDLE-WELSH LL", "LATIN CAPITAL LETTER MIDDLE-WELSH V",
+        "LATIN SMALL LETTER MIDDLE-WELSH V", "LATIN CAPITAL LETTER Y WITH LOOP",
+        "LATIN SMALL LETTER Y WITH LOOP", "GREEK SMALL LETTER ALPHA WITH PSILI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA", "GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI", "GREEK CAPITAL LETTER ALPHA WITH DASIA",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI",
+        "GREEK SMALL LETTER EPSILON WITH PSILI", "GREEK SMALL LETTER EPSILON WITH DASIA",
+        "GREEK SMALL LETTER EPSILON WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER EPSILON WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER EPSILON WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER EPSILON WITH DASIA AND OXIA", "", "",
+        "GREEK CAPITAL LETTER EPSILON WITH PSILI", "GREEK CAPITAL LETTER EPSILON WITH DASIA",
+        "GREEK CAPITAL LETTER EPSILON WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER EPSILON WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER EPSILON WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER EPSILON WITH DASIA AND OXIA", "", "",
+        "GREEK SMALL LETTER ETA WITH PSILI", "GREEK SMALL LETTER ETA WITH DASIA",
+        "GREEK SMALL LETTER ETA WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER ETA WITH DASIA AND VARIA", "GREEK SMALL LETTER ETA WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER ETA WITH DASIA AND OXIA",
+        "GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI", "GREEK CAPITAL LETTER ETA WITH PSILI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA", "GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI", "GREEK SMALL LETTER IOTA WITH PSILI",
+        "GREEK SMALL LETTER IOTA WITH DASIA", "GREEK SMALL LETTER IOTA WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER IOTA WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER IOTA WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER IOTA WITH DASIA AND OXIA",
+        "GREEK SMALL LETTER IOTA WITH PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER IOTA WITH DASIA AND PERISPOMENI",
+        "GREEK CAPITAL LETTER IOTA WITH PSILI", "GREEK CAPITAL LETTER IOTA WITH DASIA",
+        "GREEK CAPITAL LETTER IOTA WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER IOTA WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER IOTA WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER IOTA WITH DASIA AND OXIA",
+        "GREEK CAPITAL LETTER IOTA WITH PSILI AND PERISPOMENI",
+        "GREEK CAPITAL LETTER IOTA WITH DASIA AND PERISPOMENI",
+        "GREEK SMALL LETTER OMICRON WITH PSILI", "GREEK SMALL LETTER OMICRON WITH DASIA",
+        "GREEK SMALL LETTER OMICRON WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER OMICRON WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER OMICRON WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER OMICRON WITH DASIA AND OXIA", "", "",
+        "GREEK CAPITAL LETTER OMICRON WITH PSILI", "GREEK CAPITAL LETTER OMICRON WITH DASIA",
+        "GREEK CAPITAL LETTER OMICRON WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER OMICRON WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER OMICRON WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER OMICRON WITH DASIA AND OXIA", "", "",
+        "GREEK SMALL LETTER UPSILON WITH PSILI", "GREEK SMALL LETTER UPSILON WITH DASIA",
+        "GREEK SMALL LETTER UPSILON WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER UPSILON WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER UPSILON WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER UPSILON WITH DASIA AND OXIA",
+        "GREEK SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER UPSILON WITH DASIA AND PERISPOMENI", "",
+        "GREEK CAPITAL LETTER UPSILON WITH DASIA", "",
+        "GREEK CAPITAL LETTER UPSILON WITH DASIA AND VARIA", "",
+        "GREEK CAPITAL LETTER UPSILON WITH DASIA AND OXIA", "",
+        "GREEK CAPITAL LETTER UPSILON WITH DASIA AND PERISPOMENI",
+        "GREEK SMALL LETTER OMEGA WITH PSILI", "GREEK SMALL LETTER OMEGA WITH DASIA",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI", "GREEK CAPITAL LETTER OMEGA WITH DASIA",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI",
+        "GREEK SMALL LETTER ALPHA WITH VARIA", "GREEK SMALL LETTER ALPHA WITH OXIA",
+        "GREEK SMALL LETTER EPSILON WITH VARIA", "GREEK SMALL LETTER EPSILON WITH OXIA",
+        "GREEK SMALL LETTER ETA WITH VARIA", "GREEK SMALL LETTER ETA WITH OXIA",
+        "GREEK SMALL LETTER IOTA WITH VARIA", "GREEK SMALL LETTER IOTA WITH OXIA",
+        "GREEK SMALL LETTER OMICRON WITH VARIA", "GREEK SMALL LETTER OMICRON WITH OXIA",
+        "GREEK SMALL LETTER UPSILON WITH VARIA", "GREEK SMALL LETTER UPSILON WITH OXIA",
+        "GREEK SMALL LETTER OMEGA WITH VARIA", "GREEK SMALL LETTER OMEGA WITH OXIA", "", "",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH VRACHY", "GREEK SMALL LETTER ALPHA WITH MACRON",
+        "GREEK SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI", "",
+        "GREEK SMALL LETTER ALPHA WITH PERISPOMENI",
+        "GREEK SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER ALPHA WITH VRACHY", "GREEK CAPITAL LETTER ALPHA WITH MACRON",
+        "GREEK CAPITAL LETTER ALPHA WITH VARIA", "GREEK CAPITAL LETTER ALPHA WITH OXIA",
+        "GREEK CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI", "GREEK KORONIS", "GREEK PROSGEGRAMMENI",
+        "GREEK PSILI", "GREEK PERISPOMENI", "GREEK DIALYTIKA AND PERISPOMENI",
+        "GREEK SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH YPOGEGRAMMENI",
+        "GREEK SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI", "",
+        "GREEK SMALL LETTER ETA WITH PERISPOMENI",
+        "GREEK SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER EPSILON WITH VARIA", "GREEK CAPITAL LETTER EPSILON WITH OXIA",
+        "GREEK CAPITAL LETTER ETA WITH VARIA", "GREEK CAPITAL LETTER ETA WITH OXIA",
+        "GREEK CAPITAL LETTER ETA WITH PROSGEGRAMMENI", "GREEK PSILI AND VARIA",
+        "GREEK PSILI AND OXIA", "GREEK PSILI AND PERISPOMENI",
+        "GREEK SMALL LETTER IOTA WITH VRACHY", "GREEK SMALL LETTER IOTA WITH MACRON",
+        "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND VARIA",
+        "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND OXIA", "", "",
+        "GREEK SMALL LETTER IOTA WITH PERISPOMENI",
+        "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI",
+        "GREEK CAPITAL LETTER IOTA WITH VRACHY", "GREEK CAPITAL LETTER IOTA WITH MACRON",
+        "GREEK CAPITAL LETTER IOTA WITH VARIA", "GREEK CAPITAL LETTER IOTA WITH OXIA", "",
+        "GREEK DASIA AND VARIA", "GREEK DASIA AND OXIA", "GREEK DASIA AND PERISPOMENI",
+        "GREEK SMALL LETTER UPSILON WITH VRACHY", "GREEK SMALL LETTER UPSILON WITH MACRON",
+        "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA",
+        "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA", "GREEK SMALL LETTER RHO WITH PSILI",
+        "GREEK SMALL LETTER RHO WITH DASIA", "GREEK SMALL LETTER UPSILON WITH PERISPOMENI",
+        "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI",
+        "GREEK CAPITAL LETTER UPSILON WITH VRACHY", "GREEK CAPITAL LETTER UPSILON WITH MACRON",
+        "GREEK CAPITAL LETTER UPSILON WITH VARIA", "GREEK CAPITAL LETTER UPSILON WITH OXIA",
+        "GREEK CAPITAL LETTER RHO WITH DASIA", "GREEK DIALYTIKA AND VARIA",
+        "GREEK DIALYTIKA AND OXIA", "GREEK VARIA", "", "",
+        "GREEK SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH YPOGEGRAMMENI",
+        "GREEK SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI", "",
+        "GREEK SMALL LETTER OMEGA WITH PERISPOMENI",
+        "GREEK SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI",
+        "GREEK CAPITAL LETTER OMICRON WITH VARIA", "GREEK CAPITAL LETTER OMICRON WITH OXIA",
+        "GREEK CAPITAL LETTER OMEGA WITH VARIA", "GREEK CAPITAL LETTER OMEGA WITH OXIA",
+        "GREEK CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI", "GREEK OXIA", "GREEK DASIA", "",
+        "EN QUAD", "EM QUAD", "EN SPACE", "EM SPACE", "THREE-PER-EM SPACE", "FOUR-PER-EM SPACE",
+        "SIX-PER-EM SPACE", "FIGURE SPACE", "PUNCTUATION SPACE", "THIN SPACE", "HAIR SPACE",
+        "ZERO WIDTH SPACE", "ZERO WIDTH NON-JOINER", "ZERO WIDTH JOINER", "LEFT-TO-RIGHT MARK",
+        "RIGHT-TO-LEFT MARK", "HYPHEN", "NON-BREAKING HYPHEN", "FIGURE DASH", "EN DASH", "EM DASH",
+        "HORIZONTAL BAR", "DOUBLE VERTICAL LINE", "DOUBLE LOW LINE", "LEFT SINGLE QUOTATION MARK",
+        "RIGHT SINGLE QUOTATION MARK", "SINGLE LOW-9 QUOTATION MARK",
+        "SINGLE HIGH-REVERSED-9 QUOTATION MARK", "LEFT DOUBLE QUOTATION MARK",
+        "RIGHT DOUBLE QUOTATION MARK", "DOUBLE LOW-9 QUOTATION MARK",
+        "DOUBLE HIGH-REVERSED-9 QUOTATION MARK", "DAGGER", "DOUBLE DAGGER", "BULLET",
+        "TRIANGULAR BULLET", "ONE DOT LEADER", "TWO DOT LEADER", "HORIZONTAL ELLIPSIS",
+        "HYPHENATION POINT", "LINE SEPARATOR", "PARAGRAPH SEPARATOR", "LEFT-TO-RIGHT EMBEDDING",
+        "RIGHT-TO-LEFT EMBEDDING", "POP DIRECTIONAL FORMATTING", "LEFT-TO-RIGHT OVERRIDE",
+        "RIGHT-TO-LEFT OVERRIDE", "NARROW NO-BREAK SPACE", "PER MILLE SIGN",
+        "PER TEN THOUSAND SIGN", "PRIME", "DOUBLE PRIME", "TRIPLE PRIME", "REVERSED PRIME",
+        "REVERSED DOUBLE PRIME", "REVERSED TRIPLE PRIME", "CARET",
+        "SINGLE LEFT-POINTING ANGLE QUOTATION MARK", "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK",
+        "REFERENCE MARK", "DOUBLE EXCLAMATION MARK", "INTERROBANG", "OVERLINE", "UNDERTIE",
+        "CHARACTER TIE", "CARET INSERTION POINT", "ASTERISM", "HYPHEN BULLET", "FRACTION SLASH",
+        "LEFT SQUARE BRACKET WITH QUILL", "RIGHT SQUARE BRACKET WITH QUILL", "DOUBLE QUESTION MARK",
+        "QUESTION EXCLAMATION MARK", "EXCLAMATION QUESTION MARK", "TIRONIAN SIGN ET",
+        "REVERSED PILCROW SIGN", "BLACK LEFTWARDS BULLET", "BLACK RIGHTWARDS BULLET",
+        "LOW ASTERISK", "REVERSED SEMICOLON", "CLOSE UP", "TWO ASTERISKS ALIGNED VERTICALLY",
+        "COMMERCIAL MINUS SIGN", "SWUNG DASH", "INVERTED UNDERTIE", "FLOWER PUNCTUATION MARK",
+        "THREE DOT PUNCTUATION", "QUADRUPLE PRIME", "FOUR DOT PUNCTUATION", "FIVE DOT PUNCTUATION",
+        "TWO DOT PUNCTUATION", "FOUR DOT MARK", "DOTTED CROSS", "TRICOLON", "VERTICAL FOUR DOTS",
+        "MEDIUM MATHEMATICAL SPACE", "WORD JOINER", "FUNCTION APPLICATION", "INVISIBLE TIMES",
+        "INVISIBLE SEPARATOR", "INVISIBLE PLUS", "", "LEFT-TO-RIGHT ISOLATE",
+        "RIGHT-TO-LEFT ISOLATE", "FIRST STRONG ISOLATE", "POP DIRECTIONAL ISOLATE",
+        "INHIBIT SYMMETRIC SWAPPING", "ACTIVATE SYMMETRIC SWAPPING", "INHIBIT ARABIC FORM SHAPING",
+        "ACTIVATE ARABIC FORM SHAPING", "NATIONAL DIGIT SHAPES", "NOMINAL DIGIT SHAPES",
+        "SUPERSCRIPT ZERO", "SUPERSCRIPT LATIN SMALL LETTER I", "", "", "SUPERSCRIPT FOUR",
+        "SUPERSCRIPT FIVE", "SUPERSCRIPT SIX", "SUPERSCRIPT SEVEN", "SUPERSCRIPT EIGHT",
+        "SUPERSCRIPT NINE", "SUPERSCRIPT PLUS SIGN", "SUPERSCRIPT MINUS", "SUPERSCRIPT EQUALS SIGN",
+        "SUPERSCRIPT LEFT PARENTHESIS", "SUPERSCRIPT RIGHT PARENTHESIS",
+        "SUPERSCRIPT LATIN SMALL LETTER N", "SUBSCRIPT ZERO", "SUBSCRIPT ONE", "SUBSCRIPT TWO",
+        "SUBSCRIPT THREE", "SUBSCRIPT FOUR", "SUBSCRIPT FIVE", "SUBSCRIPT SIX", "SUBSCRIPT SEVEN",
+        "SUBSCRIPT EIGHT", "SUBSCRIPT NINE", "SUBSCRIPT PLUS SIGN", "SUBSCRIPT MINUS",
+        "SUBSCRIPT EQUALS SIGN", "SUBSCRIPT LEFT PARENTHESIS", "SUBSCRIPT RIGHT PARENTHESIS", "",
+        "LATIN SUBSCRIPT SMALL LETTER A", "LATIN SUBSCRIPT SMALL LETTER E",
+        "LATIN SUBSCRIPT SMALL LETTER O", "LATIN SUBSCRIPT SMALL LETTER X",
+        "LATIN SUBSCRIPT SMALL LETTER SCHWA", "LATIN SUBSCRIPT SMALL LETTER H",
+        "LATIN SUBSCRIPT SMALL LETTER K", "LATIN SUBSCRIPT SMALL LETTER L",
+        "LATIN SUBSCRIPT SMALL LETTER M", "LATIN SUBSCRIPT SMALL LETTER N",
+        "LATIN SUBSCRIPT SMALL LETTER P", "LATIN SUBSCRIPT SMALL LETTER S",
+        "LATIN SUBSCRIPT SMALL LETTER T", "", "", "", "EURO-CURRENCY SIGN", "COLON SIGN",
+        "CRUZEIRO SIGN", "FRENCH FRANC SIGN", "LIRA SIGN", "MILL SIGN", "NAIRA SIGN", "PESETA SIGN",
+        "RUPEE SIGN", "WON SIGN", "NEW SHEQEL SIGN", "DONG SIGN", "EURO SIGN", "KIP SIGN",
+        "TUGRIK SIGN", "DRACHMA SIGN", "GERMAN PENNY SIGN", "PESO SIGN", "GUARANI SIGN",
+        "AUSTRAL SIGN", "HRYVNIA SIGN", "CEDI SIGN", "LIVRE TOURNOIS SIGN", "SPESMILO SIGN",
+        "TENGE SIGN", "INDIAN RUPEE SIGN", "TURKISH LIRA SIGN", "NORDIC MARK SIGN", "MANAT SIGN",
+        "RUBLE SIGN", "LARI SIGN", "BITCOIN SIGN", "SOM SIGN",
+    ]),
+    (0x20D0, &[
+        "COMBINING LEFT HARPOON ABOVE", "COMBINING RIGHT HARPOON ABOVE",
+        "COMBINING LONG VERTICAL LINE OVERLAY", "COMBINING SHORT VERTICAL LINE OVERLAY",
+        "COMBINING ANTICLOCKWISE ARROW ABOVE", "COMBINING CLOCKWISE ARROW ABOVE",
+        "COMBINING LEFT ARROW ABOVE", "COMBINING RIGHT ARROW ABOVE", "COMBINING RING OVERLAY",
+        "COMBINING CLOCKWISE RING OVERLAY", "COMBINING ANTICLOCKWISE RING OVERLAY",
+        "COMBINING THREE DOTS ABOVE", "COMBINING FOUR DOTS ABOVE", "COMBINING ENCLOSING CIRCLE",
+        "COMBINING ENCLOSING SQUARE", "COMBINING ENCLOSING DIAMOND",
+        "COMBINING ENCLOSING CIRCLE BACKSLASH", "COMBINING LEFT RIGHT ARROW ABOVE",
+        "COMBINING ENCLOSING SCREEN", "COMBINING ENCLOSING KEYCAP",
+        "COMBINING ENCLOSING UPWARD POINTING TRIANGLE", "COMBINING REVERSE SOLIDUS OVERLAY",
+        "COMBINING DOUBLE VERTICAL STROKE OVERLAY", "COMBINING ANNUITY SYMBOL",
+        "COMBINING TRIPLE UNDERDOT", "COMBINING WIDE BRIDGE ABOVE",
+        "COMBINING LEFTWARDS ARROW OVERLAY", "COMBINING LONG DOUBLE SOLIDUS OVERLAY",
+        "COMBINING RIGHTWARDS HARPOON WITH BARB DOWNWARDS",
+        "COMBINING LEFTWARDS HARPOON WITH BARB DOWNWARDS", "COMBINING LEFT ARROW BELOW",
+        "COMBINING RIGHT ARROW BELOW", "COMBINING ASTERISK ABOVE",
+    ]),
+    (0x2100, &[
+        "ACCOUNT OF", "ADDRESSED TO THE SUBJECT", "DOUBLE-STRUCK CAPITAL C", "DEGREE CELSIUS",
+        "CENTRE LINE SYMBOL", "CARE OF", "CADA UNA", "EULER CONSTANT", "SCRUPLE",
+        "DEGREE FAHRENHEIT", "SCRIPT SMALL G", "SCRIPT CAPITAL H", "BLACK-LETTER CAPITAL H",
+        "DOUBLE-STRUCK CAPITAL H", "PLANCK CONSTANT", "PLANCK CONSTANT OVER TWO PI",
+        "SCRIPT CAPITAL I", "BLACK-LETTER CAPITAL I", "SCRIPT CAPITAL L", "SCRIPT SMALL L",
+        "L B BAR SYMBOL", "DOUBLE-STRUCK CAPITAL N", "NUMERO SIGN", "SOUND RECORDING COPYRIGHT",
+        "SCRIPT CAPITAL P", "DOUBLE-STRUCK CAPITAL P", "DOUBLE-STRUCK CAPITAL Q",
+        "SCRIPT CAPITAL R", "BLACK-LETTER CAPITAL R", "DOUBLE-STRUCK CAPITAL R",
+        "PRESCRIPTION TAKE", "RESPONSE", "SERVICE MARK", "TELEPHONE SIGN", "TRADE MARK SIGN",
+        "VERSICLE", "DOUBLE-STRUCK CAPITAL Z", "OUNCE SIGN", "OHM SIGN", "INVERTED OHM SIGN",
+        "BLACK-LETTER CAPITAL Z", "TURNED GREEK SMALL LETTER IOTA", "KELVIN SIGN", "ANGSTROM SIGN",
+        "SCRIPT CAPITAL B", "BLACK-LETTER CAPITAL C", "ESTIMATED SYMBOL", "SCRIPT SMALL E",
+        "SCRIPT CAPITAL E", "SCRIPT CAPITAL F", "TURNED CAPITAL F", "SCRIPT CAPITAL M",
+        "SCRIPT SMALL O", "ALEF SYMBOL", "BET SYMBOL", "GIMEL SYMBOL", "DALET SYMBOL",
+        "INFORMATION SOURCE", "ROTATED CAPITAL Q", "FACSIMILE SIGN", "DOUBLE-STRUCK SMALL PI",
+        "DOUBLE-STRUCK SMALL GAMMA", "DOUBLE-STRUCK CAPITAL GAMMA", "DOUBLE-STRUCK CAPITAL PI",
+        "DOUBLE-STRUCK N-ARY SUMMATION", "TURNED SANS-SERIF CAPITAL G",
+        "TURNED SANS-SERIF CAPITAL L", "REVERSED SANS-SERIF CAPITAL L",
+        "TURNED SANS-SERIF CAPITAL Y", "DOUBLE-STRUCK ITALIC CAPITAL D",
+        "DOUBLE-STRUCK ITALIC SMALL D", "DOUBLE-STRUCK ITALIC SMALL E",
+        "DOUBLE-STRUCK ITALIC SMALL I", "DOUBLE-STRUCK ITALIC SMALL J", "PROPERTY LINE",
+        "TURNED AMPERSAND", "PER SIGN", "AKTIESELSKAB", "TURNED SMALL F",
+        "SYMBOL FOR SAMARITAN SOURCE", "VULGAR FRACTION ONE SEVENTH", "VULGAR FRACTION ONE NINTH",
+        "VULGAR FRACTION ONE TENTH", "VULGAR FRACTION ONE THIRD", "VULGAR FRACTION TWO THIRDS",
+        "VULGAR FRACTION ONE FIFTH", "VULGAR FRACTION TWO FIFTHS", "VULGAR FRACTION THREE FIFTHS",
+        "VULGAR FRACTION FOUR FIFTHS", "VULGAR FRACTION ONE SIXTH", "VULGAR FRACTION FIVE SIXTHS",
+        "VULGAR FRACTION ONE EIGHTH", "VULGAR FRACTION THREE EIGHTHS",
+        "VULGAR FRACTION FIVE EIGHTHS", "VULGAR FRACTION SEVEN EIGHTHS", "FRACTION NUMERATOR ONE",
+        "ROMAN NUMERAL ONE", "ROMAN NUMERAL TWO", "ROMAN NUMERAL THREE", "ROMAN NUMERAL FOUR",
+        "ROMAN NUMERAL FIVE", "ROMAN NUMERAL SIX", "ROMAN NUMERAL SEVEN", "ROMAN NUMERAL EIGHT",
+        "ROMAN NUMERAL NINE", "ROMAN NUMERAL TEN", "ROMAN NUMERAL ELEVEN", "ROMAN NUMERAL TWELVE",
+        "ROMAN NUMERAL FIFTY", "ROMAN NUMERAL ONE HUNDRED", "ROMAN NUMERAL FIVE HUNDRED",
+        "ROMAN NUMERAL ONE THOUSAND", "SMALL ROMAN NUMERAL ONE", "SMALL ROMAN NUMERAL TWO",
+        "SMALL ROMAN NUMERAL THREE", "SMALL ROMAN NUMERAL FOUR", "SMALL ROMAN NUMERAL FIVE",
+        "SMALL ROMAN NUMERAL SIX", "SMALL ROMAN NUMERAL SEVEN", "SMALL ROMAN NUMERAL EIGHT",
+        "SMALL ROMAN NUMERAL NINE", "SMALL ROMAN NUMERAL TEN", "SMALL ROMAN NUMERAL ELEVEN",
+        "SMALL ROMAN NUMERAL TWELVE", "SMALL ROMAN NUMERAL FIFTY",
+        "SMALL ROMAN NUMERAL ONE HUNDRED", "SMALL ROMAN NUMERAL FIVE HUNDRED",
+        "SMALL ROMAN NUMERAL ONE THOUSAND", "ROMAN NUMERAL ONE THOUSAND C D",
+        "ROMAN NUMERAL FIVE THOUSAND", "ROMAN NUMERAL TEN THOUSAND",
+        "ROMAN NUMERAL REVERSED ONE HUNDRED", "LATIN SMALL LETTER REVERSED C",
+        "ROMAN NUMERAL SIX LATE FORM", "ROMAN NUMERAL FIFTY EARLY FORM",
+        "ROMAN NUMERAL FIFTY THOUSAND", "ROMAN NUMERAL ONE HUNDRED THOUSAND",
+        "VULGAR FRACTION ZERO THIRDS", "TURNED DIGIT TWO", "TURNED DIGIT THREE",
+    ]),
+    (0x2190, &[
+        "LEFTWARDS ARROW", "UPWARDS ARROW", "RIGHTWARDS ARROW", "DOWNWARDS ARROW",
+        "LEFT RIGHT ARROW", "UP DOWN ARROW", "NORTH WEST ARROW", "NORTH EAST ARROW",
+        "SOUTH EAST ARROW", "SOUTH WEST ARROW", "LEFTWARDS ARROW WITH STROKE",
+        "RIGHTWARDS ARROW WITH STROKE", "LEFTWARDS WAVE ARROW", "RIGHTWARDS WAVE ARROW",
+        "LEFTWARDS TWO HEADED ARROW", "UPWARDS TWO HEADED ARROW", "RIGHTWARDS TWO HEADED ARROW",
+        "DOWNWARDS TWO HEADED ARROW", "LEFTWARDS ARROW WITH TAIL", "RIGHTWARDS ARROW WITH TAIL",
+        "LEFTWARDS ARROW FROM BAR", "UPWARDS ARROW FROM BAR", "RIGHTWARDS ARROW FROM BAR",
+        "DOWNWARDS ARROW FROM BAR", "UP DOWN ARROW WITH BASE", "LEFTWARDS ARROW WITH HOOK",
+        "RIGHTWARDS ARROW WITH HOOK", "LEFTWARDS ARROW WITH LOOP", "RIGHTWARDS ARROW WITH LOOP",
+        "LEFT RIGHT WAVE ARROW", "LEFT RIGHT ARROW WITH STROKE", "DOWNWARDS ZIGZAG ARROW",
+        "UPWARDS ARROW WITH TIP LEFTWARDS", "UPWARDS ARROW WITH TIP RIGHTWARDS",
+        "DOWNWARDS ARROW WITH TIP LEFTWARDS", "DOWNWARDS ARROW WITH TIP RIGHTWARDS",
+        "RIGHTWARDS ARROW WITH CORNER DOWNWARDS", "DOWNWARDS ARROW WITH CORNER LEFTWARDS",
+        "ANTICLOCKWISE TOP SEMICIRCLE ARROW", "CLOCKWISE TOP SEMICIRCLE ARROW",
+        "NORTH WEST ARROW TO LONG BAR", "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR",
+        "ANTICLOCKWISE OPEN CIRCLE ARROW", "CLOCKWISE OPEN CIRCLE ARROW",
+        "LEFTWARDS HARPOON WITH BARB UPWARDS", "LEFTWARDS HARPOON WITH BARB DOWNWARDS",
+        "UPWARDS HARPOON WITH BARB RIGHTWARDS", "UPWARDS HARPOON WITH BARB LEFTWARDS",
+        "RIGHTWARDS HARPOON WITH BARB UPWARDS", "RIGHTWARDS HARPOON WITH BARB DOWNWARDS",
+        "DOWNWARDS HARPOON WITH BARB RIGHTWARDS", "DOWNWARDS HARPOON WITH BARB LEFTWARDS",
+        "RIGHTWARDS ARROW OVER LEFTWARDS ARROW", "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW",
+        "LEFTWARDS ARROW OVER RIGHTWARDS ARROW", "LEFTWARDS PAIRED ARROWS", "UPWARDS PAIRED ARROWS",
+        "RIGHTWARDS PAIRED ARROWS", "DOWNWARDS PAIRED ARROWS",
+        "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON", "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON",
+        "LEFTWARDS DOUBLE ARROW WITH STROKE", "LEFT RIGHT DOUBLE ARROW WITH STROKE",
+        "RIGHTWARDS DOUBLE ARROW WITH STROKE", "LEFTWARDS DOUBLE ARROW", "UPWARDS DOUBLE ARROW",
+        "RIGHTWARDS DOUBLE ARROW", "DOWNWARDS DOUBLE ARROW", "LEFT RIGHT DOUBLE ARROW",
+        "UP DOWN DOUBLE ARROW", "NORTH WEST DOUBLE ARROW", "NORTH EAST DOUBLE ARROW",
+        "SOUTH EAST DOUBLE ARROW", "SOUTH WEST DOUBLE ARROW", "LEFTWARDS TRIPLE ARROW",
+        "RIGHTWARDS TRIPLE ARROW", "LEFTWARDS SQUIGGLE ARROW", "RIGHTWARDS SQUIGGLE ARROW",
+        "UPWARDS ARROW WITH DOUBLE STROKE", "DOWNWARDS ARROW WITH DOUBLE STROKE",
+        "LEFTWARDS DASHED ARROW", "UPWARDS DASHED ARROW", "RIGHTWARDS DASHED ARROW",
+        "DOWNWARDS DASHED ARROW", "LEFTWARDS ARROW TO BAR", "RIGHTWARDS ARROW TO BAR",
+        "LEFTWARDS WHITE ARROW", "UPWARDS WHITE ARROW", "RIGHTWARDS WHITE ARROW",
+        "DOWNWARDS WHITE ARROW", "UPWARDS WHITE ARROW FROM BAR", "UPWARDS WHITE ARROW ON PEDESTAL",
+        "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR",
+        "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR", "UPWARDS WHITE DOUBLE ARROW",
+        "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL", "RIGHTWARDS WHITE ARROW FROM WALL",
+        "NORTH WEST ARROW TO CORNER", "SOUTH EAST ARROW TO CORNER", "UP DOWN WHITE ARROW",
+        "RIGHT ARROW WITH SMALL CIRCLE", "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW",
+        "THREE RIGHTWARDS ARROWS", "LEFTWARDS ARROW WITH VERTICAL STROKE",
+        "RIGHTWARDS ARROW WITH VERTICAL STROKE", "LEFT RIGHT ARROW WITH VERTICAL STROKE",
+        "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE",
+        "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE",
+        "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE", "LEFTWARDS OPEN-HEADED ARROW",
+        "RIGHTWARDS OPEN-HEADED ARROW", "LEFT RIGHT OPEN-HEADED ARROW", "FOR ALL", "COMPLEMENT",
+        "PARTIAL DIFFERENTIAL", "THERE EXISTS", "THERE DOES NOT EXIST", "EMPTY SET", "INCREMENT",
+        "NABLA", "ELEMENT OF", "NOT AN ELEMENT OF", "SMALL ELEMENT OF", "CONTAINS AS MEMBER",
+        "DOES NOT CONTAIN AS MEMBER", "SMALL CONTAINS AS MEMBER", "END OF PROOF", "N-ARY PRODUCT",
+        "N-ARY COPRODUCT", "N-ARY SUMMATION", "MINUS SIGN", "MINUS-OR-PLUS SIGN", "DOT PLUS",
+        "DIVISION SLASH", "SET MINUS", "ASTERISK OPERATOR", "RING OPERATOR", "BULLET OPERATOR",
+        "SQUARE ROOT", "CUBE ROOT", "FOURTH ROOT", "PROPORTIONAL TO", "INFINITY", "RIGHT ANGLE",
+        "ANGLE", "MEASURED ANGLE", "SPHERICAL ANGLE", "DIVIDES", "DOES NOT DIVIDE", "PARALLEL TO",
+        "NOT PARALLEL TO", "LOGICAL AND", "LOGICAL OR", "INTERSECTION", "UNION", "INTEGRAL",
+        "DOUBLE INTEGRAL", "TRIPLE INTEGRAL", "CONTOUR INTEGRAL", "SURFACE INTEGRAL",
+        "VOLUME INTEGRAL", "CLOCKWISE INTEGRAL", "CLOCKWISE CONTOUR INTEGRAL",
+        "ANTICLOCKWISE CONTOUR INTEGRAL", "THEREFORE", "BECAUSE", "RATIO", "PROPORTION",
+        "DOT MINUS", "EXCESS", "GEOMETRIC PROPORTION", "HOMOTHETIC", "TILDE OPERATOR",
+        "REVERSED TILDE", "INVERTED LAZY S", "SINE WAVE", "WREATH PRODUCT", "NOT TILDE",
+        "MINUS TILDE", "ASYMPTOTICALLY EQUAL TO", "NOT ASYMPTOTICALLY EQUAL TO",
+        "APPROXIMATELY EQUAL TO", "APPROXIMATELY BUT NOT ACTUALLY EQUAL TO",
+        "NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO", "ALMOST EQUAL TO", "NOT ALMOST EQUAL TO",
+        "ALMOST EQUAL OR EQUAL TO", "TRIPLE TILDE", "ALL EQUAL TO", "EQUIVALENT TO",
+        "GEOMETRICALLY EQUIVALENT TO", "DIFFERENCE BETWEEN", "APPROACHES THE LIMIT",
+        "GEOMETRICALLY EQUAL TO", "APPROXIMATELY EQUAL TO OR THE IMAGE OF",
+        "IMAGE OF OR APPROXIMATELY EQUAL TO", "COLON EQUALS", "EQUALS COLON", "RING IN EQUAL TO",
+        "RING EQUAL TO", "CORRESPONDS TO", "ESTIMATES", "EQUIANGULAR TO", "STAR EQUALS",
+        "DELTA EQUAL TO", "EQUAL TO BY DEFINITION", "MEASURED BY", "QUESTIONED EQUAL TO",
+        "NOT EQUAL TO", "IDENTICAL TO", "NOT IDENTICAL TO", "STRICTLY EQUIVALENT TO",
+        "LESS-THAN OR EQUAL TO", "GREATER-THAN OR EQUAL TO", "LESS-THAN OVER EQUAL TO",
+        "GREATER-THAN OVER EQUAL TO", "LESS-THAN BUT NOT EQUAL TO", "GREATER-THAN BUT NOT EQUAL TO",
+        "MUCH LESS-THAN", "MUCH GREATER-THAN", "BETWEEN", "NOT EQUIVALENT TO", "NOT LESS-THAN",
+        "NOT GREATER-THAN", "NEITHER LESS-THAN NOR EQUAL TO", "NEITHER GREATER-THAN NOR EQUAL TO",
+        "LESS-THAN OR EQUIVALENT TO", "GREATER-THAN OR EQUIVALENT TO",
+        "NEITHER LESS-THAN NOR EQUIVALENT TO", "NEITHER GREATER-THAN NOR EQUIVALENT TO",
+        "LESS-THAN OR GREATER-THAN", "GREATER-THAN OR LESS-THAN",
+        "NEITHER LESS-THAN NOR GREATER-THAN", "NEITHER GREATER-THAN NOR LESS-THAN", "PRECEDES",
+        "SUCCEEDS", "PRECEDES OR EQUAL TO", "SUCCEEDS OR EQUAL TO", "PRECEDES OR EQUIVALENT TO",
+        "SUCCEEDS OR EQUIVALENT TO", "DOES NOT PRECEDE", "DOES NOT SUCCEED", "SUBSET OF",
+        "SUPERSET OF", "NOT A SUBSET OF", "NOT A SUPERSET OF", "SUBSET OF OR EQUAL TO",
+        "SUPERSET OF OR EQUAL TO", "NEITHER A SUBSET OF NOR EQUAL TO",
+        "NEITHER A SUPERSET OF NOR EQUAL TO", "SUBSET OF WITH NOT EQUAL TO",
+        "SUPERSET OF WITH NOT EQUAL TO", "MULTISET", "MULTISET MULTIPLICATION", "MULTISET UNION",
+        "SQUARE IMAGE OF", "SQUARE ORIGINAL OF", "SQUARE IMAGE OF OR EQUAL TO",
+        "SQUARE ORIGINAL OF OR EQUAL TO", "SQUARE CAP", "SQUARE CUP", "CIRCLED PLUS",
+        "CIRCLED MINUS", "CIRCLED TIMES", "CIRCLED DIVISION SLASH", "CIRCLED DOT OPERATOR",
+        "CIRCLED RING OPERATOR", "CIRCLED ASTERISK OPERATOR", "CIRCLED EQUALS", "CIRCLED DASH",
+        "SQUARED PLUS", "SQUARED MINUS", "SQUARED TIMES", "SQUARED DOT OPERATOR", "RIGHT TACK",
+        "LEFT TACK", "DOWN TACK", "UP TACK", "ASSERTION", "MODELS", "TRUE", "FORCES",
+        "TRIPLE VERTICAL BAR RIGHT TURNSTILE", "DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE",
+        "DOES NOT PROVE", "NOT TRUE", "DOES NOT FORCE",
+        "NEGATED DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE", "PRECEDES UNDER RELATION",
+        "SUCCEEDS UNDER RELATION", "NORMAL SUBGROUP OF", "CONTAINS AS NORMAL SUBGROUP",
+        "NORMAL SUBGROUP OF OR EQUAL TO", "CONTAINS AS NORMAL SUBGROUP OR EQUAL TO", "ORIGINAL OF",
+        "IMAGE OF", "MULTIMAP", "HERMITIAN CONJUGATE MATRIX", "INTERCALATE", "XOR", "NAND", "NOR",
+        "RIGHT ANGLE WITH ARC", "RIGHT TRIANGLE", "N-ARY LOGICAL AND", "N-ARY LOGICAL OR",
+        "N-ARY INTERSECTION", "N-ARY UNION", "DIAMOND OPERATOR", "DOT OPERATOR", "STAR OPERATOR",
+        "DIVISION TIMES", "BOWTIE", "LEFT NORMAL FACTOR SEMIDIRECT PRODUCT",
+        "RIGHT NORMAL FACTOR SEMIDIRECT PRODUCT", "LEFT SEMIDIRECT PRODUCT",
+        "RIGHT SEMIDIRECT PRODUCT", "REVERSED TILDE EQUALS", "CURLY LOGICAL OR",
+        "CURLY LOGICAL AND", "DOUBLE SUBSET", "DOUBLE SUPERSET", "DOUBLE INTERSECTION",
+        "DOUBLE UNION", "PITCHFORK", "EQUAL AND PARALLEL TO", "LESS-THAN WITH DOT",
+        "GREATER-THAN WITH DOT", "VERY MUCH LESS-THAN", "VERY MUCH GREATER-THAN",
+        "LESS-THAN EQUAL TO OR GREATER-THAN", "GREATER-THAN EQUAL TO OR LESS-THAN",
+        "EQUAL TO OR LESS-THAN", "EQUAL TO OR GREATER-THAN", "EQUAL TO OR PRECEDES",
+        "EQUAL TO OR SUCCEEDS", "DOES NOT PRECEDE OR EQUAL", "DOES NOT SUCCEED OR EQUAL",
+        "NOT SQUARE IMAGE OF OR EQUAL TO", "NOT SQUARE ORIGINAL OF OR EQUAL TO",
+        "SQUARE IMAGE OF OR NOT EQUAL TO", "SQUARE ORIGINAL OF OR NOT EQUAL TO",
+        "LESS-THAN BUT NOT EQUIVALENT TO", "GREATER-THAN BUT NOT EQUIVALENT TO",
+        "PRECEDES BUT NOT EQUIVALENT TO", "SUCCEEDS BUT NOT EQUIVALENT TO",
+        "NOT NORMAL SUBGROUP OF", "DOES NOT CONTAIN AS NORMAL SUBGROUP",
+        "NOT NORMAL SUBGROUP OF OR EQUAL TO", "DOES NOT CONTAIN AS NORMAL SUBGROUP OR EQUAL",
+        "VERTICAL ELLIPSIS", "MIDLINE HORIZONTAL ELLIPSIS", "UP RIGHT DIAGONAL ELLIPSIS",
+        "DOWN RIGHT DIAGONAL ELLIPSIS", "ELEMENT OF WITH LONG HORIZONTAL STROKE",
+        "ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+        "SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+        "ELEMENT OF WITH DOT ABOVE", "ELEMENT OF WITH OVERBAR", "SMALL ELEMENT OF WITH OVERBAR",
+        "ELEMENT OF WITH UNDERBAR", "ELEMENT OF WITH TWO HORIZONTAL STROKES",
+        "CONTAINS WITH LONG HORIZONTAL STROKE",
+        "CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+        "SMALL CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE", "CONTAINS WITH OVERBAR",
+        "SMALL CONTAINS WITH OVERBAR", "Z NOTATION BAG MEMBERSHIP",
+    ]),
+    (0x25A0, &[
+        "BLACK SQUARE", "WHITE SQUARE", "WHITE SQUARE WITH ROUNDED CORNERS",
+        "WHITE SQUARE CONTAINING BLACK SMALL SQUARE", "SQUARE WITH HORIZONTAL FILL",
+        "SQUARE WITH VERTICAL FILL", "SQUARE WITH ORTHOGONAL CROSSHATCH FILL",
+        "SQUARE WITH UPPER LEFT TO LOWER RIGHT FILL", "SQUARE WITH UPPER RIGHT TO LOWER LEFT FILL",
+        "SQUARE WITH DIAGONAL CROSSHATCH FILL", "BLACK SMALL SQUARE", "WHITE SMALL SQUARE",
+        "BLACK RECTANGLE", "WHITE RECTANGLE", "BLACK VERTICAL RECTANGLE",
+        "WHITE VERTICAL RECTANGLE", "BLACK PARALLELOGRAM", "WHITE PARALLELOGRAM",
+        "BLACK UP-POINTING TRIANGLE", "WHITE UP-POINTING TRIANGLE",
+        "BLACK UP-POINTING SMALL TRIANGLE", "WHITE UP-POINTING SMALL TRIANGLE",
+        "BLACK RIGHT-POINTING TRIANGLE", "WHITE RIGHT-POINTING TRIANGLE",
+        "BLACK RIGHT-POINTING SMALL TRIANGLE", "WHITE RIGHT-POINTING SMALL TRIANGLE",
+        "BLACK RIGHT-POINTING POINTER", "WHITE RIGHT-POINTING POINTER",
+        "BLACK DOWN-POINTING TRIANGLE", "WHITE DOWN-POINTING TRIANGLE",
+        "BLACK DOWN-POINTING SMALL TRIANGLE", "WHITE DOWN-POINTING SMALL TRIANGLE",
+        "BLACK LEFT-POINTING TRIANGLE", "WHITE LEFT-POINTING TRIANGLE",
+        "BLACK LEFT-POINTING SMALL TRIANGLE", "WHITE LEFT-POINTING SMALL TRIANGLE",
+        "BLACK LEFT-POINTING POINTER", "WHITE LEFT-POINTING POINTER", "BLACK DIAMOND",
+        "WHITE DIAMOND", "WHITE DIAMOND CONTAINING BLACK SMALL DIAMOND", "FISHEYE", "LOZENGE",
+        "WHITE CIRCLE", "DOTTED CIRCLE", "CIRCLE WITH VERTICAL FILL", "BULLSEYE", "BLACK CIRCLE",
+        "CIRCLE WITH LEFT HALF BLACK", "CIRCLE WITH RIGHT HALF BLACK",
+        "CIRCLE WITH LOWER HALF BLACK", "CIRCLE WITH UPPER HALF BLACK",
+        "CIRCLE WITH UPPER RIGHT QUADRANT BLACK", "CIRCLE WITH ALL BUT UPPER LEFT QUADRANT BLACK",
+        "LEFT HALF BLACK CIRCLE", "RIGHT HALF BLACK CIRCLE", "INVERSE BULLET",
+        "INVERSE WHITE CIRCLE", "UPPER HALF INVERSE WHITE CIRCLE",
+        "LOWER HALF INVERSE WHITE CIRCLE", "UPPER LEFT QUADRANT CIRCULAR ARC",
+        "UPPER RIGHT QUADRANT CIRCULAR ARC", "LOWER RIGHT QUADRANT CIRCULAR ARC",
+        "LOWER LEFT QUADRANT CIRCULAR ARC", "UPPER HALF CIRCLE", "LOWER HALF CIRCLE",
+        "BLACK LOWER RIGHT TRIANGLE", "BLACK LOWER LEFT TRIANGLE", "BLACK UPPER LEFT TRIANGLE",
+        "BLACK UPPER RIGHT TRIANGLE", "WHITE BULLET", "SQUARE WITH LEFT HALF BLACK",
+        "SQUARE WITH RIGHT HALF BLACK", "SQUARE WITH UPPER LEFT DIAGONAL HALF BLACK",
+        "SQUARE WITH LOWER RIGHT DIAGONAL HALF BLACK", "WHITE SQUARE WITH VERTICAL BISECTING LINE",
+        "WHITE UP-POINTING TRIANGLE WITH DOT", "UP-POINTING TRIANGLE WITH LEFT HALF BLACK",
+        "UP-POINTING TRIANGLE WITH RIGHT HALF BLACK", "LARGE CIRCLE",
+        "WHITE SQUARE WITH UPPER LEFT QUADRANT", "WHITE SQUARE WITH LOWER LEFT QUADRANT",
+        "WHITE SQUARE WITH LOWER RIGHT QUADRANT", "WHITE SQUARE WITH UPPER RIGHT QUADRANT",
+        "WHITE CIRCLE WITH UPPER LEFT QUADRANT", "WHITE CIRCLE WITH LOWER LEFT QUADRANT",
+        "WHITE CIRCLE WITH LOWER RIGHT QUADRANT", "WHITE CIRCLE WITH UPPER RIGHT QUADRANT",
+        "UPPER LEFT TRIANGLE", "UPPER RIGHT TRIANGLE", "LOWER LEFT TRIANGLE", "WHITE MEDIUM SQUARE",
+        "BLACK MEDIUM SQUARE", "WHITE MEDIUM SMALL SQUARE", "BLACK MEDIUM SMALL SQUARE",
+        "LOWER RIGHT TRIANGLE",
+    ]),
+    (0x2C60, &[
+        "LATIN CAPITAL LETTER L WITH DOUBLE BAR", "LATIN SMALL LETTER L WITH DOUBLE BAR",
+        "LATIN CAPITAL LETTER L WITH MIDDLE TILDE", "LATIN CAPITAL LETTER P WITH STROKE",
+        "LATIN CAPITAL LETTER R WITH TAIL", "LATIN SMALL LETTER A WITH STROKE",
+        "LATIN SMALL LETTER T WITH DIAGONAL STROKE", "LATIN CAPITAL LETTER H WITH DESCENDER",
+        "LATIN SMALL LETTER H WITH DESCENDER", "LATIN CAPITAL LETTER K WITH DESCENDER",
+        "LATIN SMALL LETTER K WITH DESCENDER", "LATIN CAPITAL LETTER Z WITH DESCENDER",
+        "LATIN SMALL LETTER Z WITH DESCENDER", "LATIN CAPITAL LETTER ALPHA",
+        "LATIN CAPITAL LETTER M WITH HOOK", "LATIN CAPITAL LETTER TURNED A",
+        "LATIN CAPITAL LETTER TURNED ALPHA", "LATIN SMALL LETTER V WITH RIGHT HOOK",
+        "LATIN CAPITAL LETTER W WITH HOOK", "LATIN SMALL LETTER W WITH HOOK",
+        "LATIN SMALL LETTER V WITH CURL", "LATIN CAPITAL LETTER HALF H",
+        "LATIN SMALL LETTER HALF H", "LATIN SMALL LETTER TAILLESS PHI",
+        "LATIN SMALL LETTER E WITH NOTCH", "LATIN SMALL LETTER TURNED R WITH TAIL",
+        "LATIN SMALL LETTER O WITH LOW RING INSIDE", "LATIN LETTER SMALL CAPITAL TURNED E",
+        "LATIN SUBSCRIPT SMALL LETTER J", "MODIFIER LETTER CAPITAL V",
+        "LATIN CAPITAL LETTER S WITH SWASH TAIL", "LATIN CAPITAL LETTER Z WITH SWASH TAIL",
+    ]),
+    (0x2DE0, &[
+        "COMBINING CYRILLIC LETTER BE", "COMBINING CYRILLIC LETTER VE",
+        "COMBINING CYRILLIC LETTER GHE", "COMBINING CYRILLIC LETTER DE",
+        "COMBINING CYRILLIC LETTER ZHE", "COMBINING CYRILLIC LETTER ZE",
+        "COMBINING CYRILLIC LETTER KA", "COMBINING CYRILLIC LETTER EL",
+        "COMBINING CYRILLIC LETTER EM", "COMBINING CYRILLIC LETTER EN",
+        "COMBINING CYRILLIC LETTER O", "COMBINING CYRILLIC LETTER PE",
+        "COMBINING CYRILLIC LETTER ER", "COMBINING CYRILLIC LETTER ES",
+        "COMBINING CYRILLIC LETTER TE", "COMBINING CYRILLIC LETTER HA",
+        "COMBINING CYRILLIC LETTER TSE", "COMBINING CYRILLIC LETTER CHE",
+        "COMBINING CYRILLIC LETTER SHA", "COMBINING CYRILLIC LETTER SHCHA",
+        "COMBINING CYRILLIC LETTER FITA", "COMBINING CYRILLIC LETTER ES-TE",
+        "COMBINING CYRILLIC LETTER A", "COMBINING CYRILLIC LETTER IE",
+        "COMBINING CYRILLIC LETTER DJERV", "COMBINING CYRILLIC LETTER MONOGRAPH UK",
+        "COMBINING CYRILLIC LETTER YAT", "COMBINING CYRILLIC LETTER YU",
+        "COMBINING CYRILLIC LETTER IOTIFIED A", "COMBINING CYRILLIC LETTER LITTLE YUS",
+        "COMBINING CYRILLIC LETTER BIG YUS", "COMBINING CYRILLIC LETTER IOTIFIED BIG YUS",
+        "RIGHT ANGLE SUBSTITUTION MARKER", "RIGHT ANGLE DOTTED SUBSTITUTION MARKER",
+        "LEFT SUBSTITUTION BRACKET", "RIGHT SUBSTITUTION BRACKET",
+        "LEFT DOTTED SUBSTITUTION BRACKET", "RIGHT DOTTED SUBSTITUTION BRACKET",
+        "RAISED INTERPOLATION MARKER", "RAISED DOTTED INTERPOLATION MARKER",
+        "DOTTED TRANSPOSITION MARKER", "LEFT TRANSPOSITION BRACKET", "RIGHT TRANSPOSITION BRACKET",
+        "RAISED SQUARE", "LEFT RAISED OMISSION BRACKET", "RIGHT RAISED OMISSION BRACKET",
+        "EDITORIAL CORONIS", "PARAGRAPHOS", "FORKED PARAGRAPHOS", "REVERSED FORKED PARAGRAPHOS",
+        "HYPODIASTOLE", "DOTTED OBELOS", "DOWNWARDS ANCORA", "UPWARDS ANCORA",
+        "DOTTED RIGHT-POINTING ANGLE", "DOUBLE OBLIQUE HYPHEN", "INVERTED INTERROBANG",
+        "PALM BRANCH", "HYPHEN WITH DIAERESIS", "TILDE WITH RING ABOVE",
+        "LEFT LOW PARAPHRASE BRACKET", "RIGHT LOW PARAPHRASE BRACKET", "TILDE WITH DOT ABOVE",
+        "TILDE WITH DOT BELOW", "LEFT VERTICAL BAR WITH QUILL", "RIGHT VERTICAL BAR WITH QUILL",
+        "TOP LEFT HALF BRACKET", "TOP RIGHT HALF BRACKET", "BOTTOM LEFT HALF BRACKET",
+        "BOTTOM RIGHT HALF BRACKET", "LEFT SIDEWAYS U BRACKET", "RIGHT SIDEWAYS U BRACKET",
+        "LEFT DOUBLE PARENTHESIS", "RIGHT DOUBLE PARENTHESIS", "TWO DOTS OVER ONE DOT PUNCTUATION",
+        "ONE DOT OVER TWO DOTS PUNCTUATION", "SQUARED FOUR DOT PUNCTUATION", "FIVE DOT MARK",
+        "REVERSED QUESTION MARK", "VERTICAL TILDE", "RING POINT", "WORD SEPARATOR MIDDLE DOT",
+        "TURNED COMMA", "RAISED DOT", "RAISED COMMA", "TURNED SEMICOLON", "DAGGER WITH LEFT GUARD",
+        "DAGGER WITH RIGHT GUARD", "TURNED DAGGER", "TOP HALF SECTION SIGN", "TWO-EM DASH",
+        "THREE-EM DASH", "STENOGRAPHIC FULL STOP", "VERTICAL SIX DOTS", "WIGGLY VERTICAL LINE",
+        "CAPITULUM", "DOUBLE HYPHEN", "REVERSED COMMA", "DOUBLE LOW-REVERSED-9 QUOTATION MARK",
+        "DASH WITH LEFT UPTURN", "DOUBLE SUSPENSION MARK", "INVERTED LOW KAVYKA",
+        "INVERTED LOW KAVYKA WITH KAVYKA ABOVE", "LOW KAVYKA", "LOW KAVYKA WITH DOT",
+        "DOUBLE STACKED COMMA", "DOTTED SOLIDUS", "TRIPLE DAGGER", "MEDIEVAL COMMA",
+        "PARAGRAPHUS MARK", "PUNCTUS ELEVATUS MARK", "CORNISH VERSE DIVIDER",
+        "CROSS PATTY WITH RIGHT CROSSBAR", "CROSS PATTY WITH LEFT CROSSBAR",
+        "TIRONIAN SIGN CAPITAL ET", "MEDIEVAL EXCLAMATION MARK", "MEDIEVAL QUESTION MARK",
+        "LEFT SQUARE BRACKET WITH STROKE", "RIGHT SQUARE BRACKET WITH STROKE",
+        "LEFT SQUARE BRACKET WITH DOUBLE STROKE", "RIGHT SQUARE BRACKET WITH DOUBLE STROKE",
+        "TOP HALF LEFT PARENTHESIS", "TOP HALF RIGHT PARENTHESIS", "BOTTOM HALF LEFT PARENTHESIS",
+        "BOTTOM HALF RIGHT PARENTHESIS", "OBLIQUE HYPHEN",
+    ]),
+    (0xA640, &[
+        "CYRILLIC CAPITAL LETTER ZEMLYA", "CYRILLIC SMALL LETTER ZEMLYA",
+        "CYRILLIC CAPITAL LETTER DZELO", "CYRILLIC SMALL LETTER DZELO",
+        "CYRILLIC CAPITAL LETTER REVERSED DZE", "CYRILLIC SMALL LETTER REVERSED DZE",
+        "CYRILLIC CAPITAL LETTER IOTA", "CYRILLIC SMALL LETTER IOTA",
+        "CYRILLIC CAPITAL LETTER DJERV", "CYRILLIC SMALL LETTER DJERV",
+        "CYRILLIC CAPITAL LETTER MONOGRAPH UK", "CYRILLIC SMALL LETTER MONOGRAPH UK",
+        "CYRILLIC CAPITAL LETTER BROAD OMEGA", "CYRILLIC SMALL LETTER BROAD OMEGA",
+        "CYRILLIC CAPITAL LETTER NEUTRAL YER", "CYRILLIC SMALL LETTER NEUTRAL YER",
+        "CYRILLIC CAPITAL LETTER YERU WITH BACK YER", "CYRILLIC SMALL LETTER YERU WITH BACK YER",
+        "CYRILLIC CAPITAL LETTER IOTIFIED YAT", "CYRILLIC SMALL LETTER IOTIFIED YAT",
+        "CYRILLIC CAPITAL LETTER REVERSED YU", "CYRILLIC SMALL LETTER REVERSED YU",
+        "CYRILLIC CAPITAL LETTER IOTIFIED A", "CYRILLIC SMALL LETTER IOTIFIED A",
+        "CYRILLIC CAPITAL LETTER CLOSED LITTLE YUS", "CYRILLIC SMALL LETTER CLOSED LITTLE YUS",
+        "CYRILLIC CAPITAL LETTER BLENDED YUS", "CYRILLIC SMALL LETTER BLENDED YUS",
+        "CYRILLIC CAPITAL LETTER IOTIFIED CLOSED LITTLE YUS",
+        "CYRILLIC SMALL LETTER IOTIFIED CLOSED LITTLE YUS", "CYRILLIC CAPITAL LETTER YN",
+        "CYRILLIC SMALL LETTER YN", "CYRILLIC CAPITAL LETTER REVERSED TSE",
+        "CYRILLIC SMALL LETTER REVERSED TSE", "CYRILLIC CAPITAL LETTER SOFT DE",
+        "CYRILLIC SMALL LETTER SOFT DE", "CYRILLIC CAPITAL LETTER SOFT EL",
+        "CYRILLIC SMALL LETTER SOFT EL", "CYRILLIC CAPITAL LETTER SOFT EM",
+        "CYRILLIC SMALL LETTER SOFT EM", "CYRILLIC CAPITAL LETTER MONOCULAR O",
+        "CYRILLIC SMALL LETTER MONOCULAR O", "CYRILLIC CAPITAL LETTER BINOCULAR O",
+        "CYRILLIC SMALL LETTER BINOCULAR O", "CYRILLIC CAPITAL LETTER DOUBLE MONOCULAR O",
+        "CYRILLIC SMALL LETTER DOUBLE MONOCULAR O", "CYRILLIC LETTER MULTIOCULAR O",
+        "COMBINING CYRILLIC VZMET", "COMBINING CYRILLIC TEN MILLIONS SIGN",
+        "COMBINING CYRILLIC HUNDRED MILLIONS SIGN", "COMBINING CYRILLIC THOUSAND MILLIONS SIGN",
+        "SLAVONIC ASTERISK", "COMBINING CYRILLIC LETTER UKRAINIAN IE",
+        "COMBINING CYRILLIC LETTER I", "COMBINING CYRILLIC LETTER YI",
+        "COMBINING CYRILLIC LETTER U", "COMBINING CYRILLIC LETTER HARD SIGN",
+        "COMBINING CYRILLIC LETTER YERU", "COMBINING CYRILLIC LETTER SOFT SIGN",
+        "COMBINING CYRILLIC LETTER OMEGA", "COMBINING CYRILLIC KAVYKA",
+        "COMBINING CYRILLIC PAYEROK", "CYRILLIC KAVYKA", "CYRILLIC PAYEROK",
+        "CYRILLIC CAPITAL LETTER DWE", "CYRILLIC SMALL LETTER DWE", "CYRILLIC CAPITAL LETTER DZWE",
+        "CYRILLIC SMALL LETTER DZWE", "CYRILLIC CAPITAL LETTER ZHWE", "CYRILLIC SMALL LETTER ZHWE",
+        "CYRILLIC CAPITAL LETTER CCHE", "CYRILLIC SMALL LETTER CCHE",
+        "CYRILLIC CAPITAL LETTER DZZE", "CYRILLIC SMALL LETTER DZZE",
+        "CYRILLIC CAPITAL LETTER TE WITH MIDDLE HOOK", "CYRILLIC SMALL LETTER TE WITH MIDDLE HOOK",
+        "CYRILLIC CAPITAL LETTER TWE", "CYRILLIC SMALL LETTER TWE", "CYRILLIC CAPITAL LETTER TSWE",
+        "CYRILLIC SMALL LETTER TSWE", "CYRILLIC CAPITAL LETTER TSSE", "CYRILLIC SMALL LETTER TSSE",
+        "CYRILLIC CAPITAL LETTER TCHE", "CYRILLIC SMALL LETTER TCHE", "CYRILLIC CAPITAL LETTER HWE",
+        "CYRILLIC SMALL LETTER HWE", "CYRILLIC CAPITAL LETTER SHWE", "CYRILLIC SMALL LETTER SHWE",
+        "CYRILLIC CAPITAL LETTER DOUBLE O", "CYRILLIC SMALL LETTER DOUBLE O",
+        "CYRILLIC CAPITAL LETTER CROSSED O", "CYRILLIC SMALL LETTER CROSSED O",
+        "MODIFIER LETTER CYRILLIC HARD SIGN", "MODIFIER LETTER CYRILLIC SOFT SIGN",
+        "COMBINING CYRILLIC LETTER EF", "COMBINING CYRILLIC LETTER IOTIFIED E",
+    ]),
+    (0xA700, &[
+        "MODIFIER LETTER CHINESE TONE YIN PING", "MODIFIER LETTER CHINESE TONE YANG PING",
+        "MODIFIER LETTER CHINESE TONE YIN SHANG", "MODIFIER LETTER CHINESE TONE YANG SHANG",
+        "MODIFIER LETTER CHINESE TONE YIN QU", "MODIFIER LETTER CHINESE TONE YANG QU",
+        "MODIFIER LETTER CHINESE TONE YIN RU", "MODIFIER LETTER CHINESE TONE YANG RU",
+        "MODIFIER LETTER EXTRA-HIGH DOTTED TONE BAR", "MODIFIER LETTER HIGH DOTTED TONE BAR",
+        "MODIFIER LETTER MID DOTTED TONE BAR", "MODIFIER LETTER LOW DOTTED TONE BAR",
+        "MODIFIER LETTER EXTRA-LOW DOTTED TONE BAR",
+        "MODIFIER LETTER EXTRA-HIGH DOTTED LEFT-STEM TONE BAR",
+        "MODIFIER LETTER HIGH DOTTED LEFT-STEM TONE BAR",
+        "MODIFIER LETTER MID DOTTED LEFT-STEM TONE BAR",
+        "MODIFIER LETTER LOW DOTTED LEFT-STEM TONE BAR",
+        "MODIFIER LETTER EXTRA-LOW DOTTED LEFT-STEM TONE BAR",
+        "MODIFIER LETTER EXTRA-HIGH LEFT-STEM TONE BAR", "MODIFIER LETTER HIGH LEFT-STEM TONE BAR",
+        "MODIFIER LETTER MID LEFT-STEM TONE BAR", "MODIFIER LETTER LOW LEFT-STEM TONE BAR",
+        "MODIFIER LETTER EXTRA-LOW LEFT-STEM TONE BAR", "MODIFIER LETTER DOT VERTICAL BAR",
+        "MODIFIER LETTER DOT SLASH", "MODIFIER LETTER DOT HORIZONTAL BAR",
+        "MODIFIER LETTER LOWER RIGHT CORNER ANGLE", "MODIFIER LETTER RAISED UP ARROW",
+        "MODIFIER LETTER RAISED DOWN ARROW", "MODIFIER LETTER RAISED EXCLAMATION MARK",
+        "MODIFIER LETTER RAISED INVERTED EXCLAMATION MARK",
+        "MODIFIER LETTER LOW INVERTED EXCLAMATION MARK", "MODIFIER LETTER STRESS AND HIGH TONE",
+        "MODIFIER LETTER STRESS AND LOW TONE", "LATIN CAPITAL LETTER EGYPTOLOGICAL ALEF",
+        "LATIN SMALL LETTER EGYPTOLOGICAL ALEF", "LATIN CAPITAL LETTER EGYPTOLOGICAL AIN",
+        "LATIN SMALL LETTER EGYPTOLOGICAL AIN", "LATIN CAPITAL LETTER HENG",
+        "LATIN SMALL LETTER HENG", "LATIN CAPITAL LETTER TZ", "LATIN SMALL LETTER TZ",
+        "LATIN CAPITAL LETTER TRESILLO", "LATIN SMALL LETTER TRESILLO",
+        "LATIN CAPITAL LETTER CUATRILLO", "LATIN SMALL LETTER CUATRILLO",
+        "LATIN CAPITAL LETTER CUATRILLO WITH COMMA", "LATIN SMALL LETTER CUATRILLO WITH COMMA",
+        "LATIN LETTER SMALL CAPITAL F", "LATIN LETTER SMALL CAPITAL S", "LATIN CAPITAL LETTER AA",
+        "LATIN SMALL LETTER AA", "LATIN CAPITAL LETTER AO", "LATIN SMALL LETTER AO",
+        "LATIN CAPITAL LETTER AU", "LATIN SMALL LETTER AU", "LATIN CAPITAL LETTER AV",
+        "LATIN SMALL LETTER AV", "LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR",
+        "LATIN SMALL LETTER AV WITH HORIZONTAL BAR", "LATIN CAPITAL LETTER AY",
+        "LATIN SMALL LETTER AY", "LATIN CAPITAL LETTER REVERSED C WITH DOT",
+        "LATIN SMALL LETTER REVERSED C WITH DOT", "LATIN CAPITAL LETTER K WITH STROKE",
+        "LATIN SMALL LETTER K WITH STROKE", "LATIN CAPITAL LETTER K WITH DIAGONAL STROKE",
+        "LATIN SMALL LETTER K WITH DIAGONAL STROKE",
+        "LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE",
+        "LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE", "LATIN CAPITAL LETTER BROKEN L",
+        "LATIN SMALL LETTER BROKEN L", "LATIN CAPITAL LETTER L WITH HIGH STROKE",
+        "LATIN SMALL LETTER L WITH HIGH STROKE", "LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY",
+        "LATIN SMALL LETTER O WITH LONG STROKE OVERLAY", "LATIN CAPITAL LETTER O WITH LOOP",
+        "LATIN SMALL LETTER O WITH LOOP", "LATIN CAPITAL LETTER OO", "LATIN SMALL LETTER OO",
+        "LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER",
+        "LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER",
+        "LATIN CAPITAL LETTER P WITH FLOURISH", "LATIN SMALL LETTER P WITH FLOURISH",
+        "LATIN CAPITAL LETTER P WITH SQUIRREL TAIL", "LATIN SMALL LETTER P WITH SQUIRREL TAIL",
+        "LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER",
+        "LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER",
+        "LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE", "LATIN SMALL LETTER Q WITH DIAGONAL STROKE",
+        "LATIN CAPITAL LETTER R ROTUNDA", "LATIN SMALL LETTER R ROTUNDA",
+        "LATIN CAPITAL LETTER RUM ROTUNDA", "LATIN SMALL LETTER RUM ROTUNDA",
+        "LATIN CAPITAL LETTER V WITH DIAGONAL STROKE", "LATIN SMALL LETTER V WITH DIAGONAL STROKE",
+        "LATIN CAPITAL LETTER VY", "LATIN SMALL LETTER VY", "LATIN CAPITAL LETTER VISIGOTHIC Z",
+        "LATIN SMALL LETTER VISIGOTHIC Z", "LATIN CAPITAL LETTER THORN WITH STROKE",
+        "LATIN SMALL LETTER THORN WITH STROKE",
+        "LATIN CAPITAL LETTER THORN WITH STROKE THROUGH DESCENDER",
+        "LATIN SMALL LETTER THORN WITH STROKE THROUGH DESCENDER", "LATIN CAPITAL LETTER VEND",
+        "LATIN SMALL LETTER VEND", "LATIN CAPITAL LETTER ET", "LATIN SMALL LETTER ET",
+        "LATIN CAPITAL LETTER IS", "LATIN SMALL LETTER IS", "LATIN CAPITAL LETTER CON",
+        "LATIN SMALL LETTER CON", "MODIFIER LETTER US", "LATIN SMALL LETTER DUM",
+        "LATIN SMALL LETTER LUM", "LATIN SMALL LETTER MUM", "LATIN SMALL LETTER NUM",
+        "LATIN SMALL LETTER RUM", "LATIN LETTER SMALL CAPITAL RUM", "LATIN SMALL LETTER TUM",
+        "LATIN SMALL LETTER UM", "LATIN CAPITAL LETTER INSULAR D", "LATIN SMALL LETTER INSULAR D",
+        "LATIN CAPITAL LETTER INSULAR F", "LATIN SMALL LETTER INSULAR F",
+        "LATIN CAPITAL LETTER INSULAR G", "LATIN CAPITAL LETTER TURNED INSULAR G",
+        "LATIN SMALL LETTER TURNED INSULAR G", "LATIN CAPITAL LETTER TURNED L",
+        "LATIN SMALL LETTER TURNED L", "LATIN CAPITAL LETTER INSULAR R",
+        "LATIN SMALL LETTER INSULAR R", "LATIN CAPITAL LETTER INSULAR S",
+        "LATIN SMALL LETTER INSULAR S", "LATIN CAPITAL LETTER INSULAR T",
+        "LATIN SMALL LETTER INSULAR T", "MODIFIER LETTER LOW CIRCUMFLEX ACCENT",
+        "MODIFIER LETTER COLON", "MODIFIER LETTER SHORT EQUALS SIGN",
+        "LATIN CAPITAL LETTER SALTILLO", "LATIN SMALL LETTER SALTILLO",
+        "LATIN CAPITAL LETTER TURNED H", "LATIN SMALL LETTER L WITH RETROFLEX HOOK AND BELT",
+        "LATIN LETTER SINOLOGICAL DOT", "LATIN CAPITAL LETTER N WITH DESCENDER",
+        "LATIN SMALL LETTER N WITH DESCENDER", "LATIN CAPITAL LETTER C WITH BAR",
+        "LATIN SMALL LETTER C WITH BAR", "LATIN SMALL LETTER C WITH PALATAL HOOK",
+        "LATIN SMALL LETTER H WITH PALATAL HOOK", "LATIN CAPITAL LETTER B WITH FLOURISH",
+        "LATIN SMALL LETTER B WITH FLOURISH", "LATIN CAPITAL LETTER F WITH STROKE",
+        "LATIN SMALL LETTER F WITH STROKE", "LATIN CAPITAL LETTER VOLAPUK AE",
+        "LATIN SMALL LETTER VOLAPUK AE", "LATIN CAPITAL LETTER VOLAPUK OE",
+        "LATIN SMALL LETTER VOLAPUK OE", "LATIN CAPITAL LETTER VOLAPUK UE",
+        "LATIN SMALL LETTER VOLAPUK UE", "LATIN CAPITAL LETTER G WITH OBLIQUE STROKE",
+        "LATIN SMALL LETTER G WITH OBLIQUE STROKE", "LATIN CAPITAL LETTER K WITH OBLIQUE STROKE",
+        "LATIN SMALL LETTER K WITH OBLIQUE STROKE", "LATIN CAPITAL LETTER N WITH OBLIQUE STROKE",
+        "LATIN SMALL LETTER N WITH OBLIQUE STROKE", "LATIN CAPITAL LETTER R WITH OBLIQUE STROKE",
+        "LATIN SMALL LETTER R WITH OBLIQUE STROKE", "LATIN CAPITAL LETTER S WITH OBLIQUE STROKE",
+        "LATIN SMALL LETTER S WITH OBLIQUE STROKE", "LATIN CAPITAL LETTER H WITH HOOK",
+        "LATIN CAPITAL LETTER REVERSED OPEN E", "LATIN CAPITAL LETTER SCRIPT G",
+        "LATIN CAPITAL LETTER L WITH BELT", "LATIN CAPITAL LETTER SMALL CAPITAL I",
+        "LATIN LETTER SMALL CAPITAL Q", "LATIN CAPITAL LETTER TURNED K",
+        "LATIN CAPITAL LETTER TURNED T", "LATIN CAPITAL LETTER J WITH CROSSED-TAIL",
+        "LATIN CAPITAL LETTER CHI", "LATIN CAPITAL LETTER BETA", "LATIN SMALL LETTER BETA",
+        "LATIN CAPITAL LETTER OMEGA", "LATIN SMALL LETTER OMEGA",
+        "LATIN CAPITAL LETTER U WITH STROKE", "LATIN SMALL LETTER U WITH STROKE",
+        "LATIN CAPITAL LETTER GLOTTAL A", "LATIN SMALL LETTER GLOTTAL A",
+        "LATIN CAPITAL LETTER GLOTTAL I", "LATIN SMALL LETTER GLOTTAL I",
+        "LATIN CAPITAL LETTER GLOTTAL U", "LATIN SMALL LETTER GLOTTAL U",
+        "LATIN CAPITAL LETTER OLD POLISH O", "LATIN SMALL LETTER OLD POLISH O",
+        "LATIN CAPITAL LETTER ANGLICANA W", "LATIN SMALL LETTER ANGLICANA W",
+        "LATIN CAPITAL LETTER C WITH PALATAL HOOK", "LATIN CAPITAL LETTER S WITH HOOK",
+        "LATIN CAPITAL LETTER Z WITH PALATAL HOOK",
+        "LATIN CAPITAL LETTER D WITH SHORT STROKE OVERLAY",
+        "LATIN SMALL LETTER D WITH SHORT STROKE OVERLAY",
+        "LATIN CAPITAL LETTER S WITH SHORT STROKE OVERLAY",
+        "LATIN SMALL LETTER S WITH SHORT STROKE OVERLAY", "LATIN CAPITAL LETTER RAMS HORN",
+        "LATIN CAPITAL LETTER S WITH DIAGONAL STROKE", "LATIN SMALL LETTER S WITH DIAGONAL STROKE",
+        "", "", "LATIN CAPITAL LETTER CLOSED INSULAR G", "LATIN SMALL LETTER CLOSED INSULAR G", "",
+        "LATIN SMALL LETTER DOUBLE THORN", "", "LATIN SMALL LETTER DOUBLE WYNN",
+        "LATIN CAPITAL LETTER MIDDLE SCOTS S", "LATIN SMALL LETTER MIDDLE SCOTS S",
+        "LATIN CAPITAL LETTER SIGMOID S", "LATIN SMALL LETTER SIGMOID S",
+        "LATIN CAPITAL LETTER LAMBDA", "LATIN SMALL LETTER LAMBDA",
+        "LATIN CAPITAL LETTER LAMBDA WITH STROKE",
+    ]),
+    (0xA7F2, &[
+        "MODIFIER LETTER CAPITAL C", "MODIFIER LETTER CAPITAL F", "MODIFIER LETTER CAPITAL Q",
+        "LATIN CAPITAL LETTER REVERSED HALF H", "LATIN SMALL LETTER REVERSED HALF H",
+        "LATIN EPIGRAPHIC LETTER SIDEWAYS I", "MODIFIER LETTER CAPITAL H WITH STROKE",
+        "MODIFIER LETTER SMALL LIGATURE OE", "LATIN LETTER SMALL CAPITAL TURNED M",
+        "LATIN EPIGRAPHIC LETTER REVERSED F", "LATIN EPIGRAPHIC LETTER REVERSED P",
+        "LATIN EPIGRAPHIC LETTER INVERTED M", "LATIN EPIGRAPHIC LETTER I LONGA",
+        "LATIN EPIGRAPHIC LETTER ARCHAIC M",
+    ]),
+    (0xAB30, &[
+        "LATIN SMALL LETTER BARRED ALPHA", "LATIN SMALL LETTER A REVERSED-SCHWA",
+        "LATIN SMALL LETTER BLACKLETTER E", "LATIN SMALL LETTER BARRED E",
+        "LATIN SMALL LETTER E WITH FLOURISH", "LATIN SMALL LETTER LENIS F",
+        "LATIN SMALL LETTER SCRIPT G WITH CROSSED-TAIL",
+        "LATIN SMALL LETTER L WITH INVERTED LAZY S",
+        "LATIN SMALL LETTER L WITH DOUBLE MIDDLE TILDE", "LATIN SMALL LETTER L WITH MIDDLE RING",
+        "LATIN SMALL LETTER M WITH CROSSED-TAIL", "LATIN SMALL LETTER N WITH CROSSED-TAIL",
+        "LATIN SMALL LETTER ENG WITH CROSSED-TAIL", "LATIN SMALL LETTER BLACKLETTER O",
+        "LATIN SMALL LETTER BLACKLETTER O WITH STROKE", "LATIN SMALL LETTER OPEN O WITH STROKE",
+        "LATIN SMALL LETTER INVERTED OE", "LATIN SMALL LETTER TURNED OE WITH STROKE",
+        "LATIN SMALL LETTER TURNED OE WITH HORIZONTAL STROKE", "LATIN SMALL LETTER TURNED O OPEN-O",
+        "LATIN SMALL LETTER TURNED O OPEN-O WITH STROKE", "LATIN SMALL LETTER STIRRUP R",
+        "LATIN LETTER SMALL CAPITAL R WITH RIGHT LEG", "LATIN SMALL LETTER R WITHOUT HANDLE",
+        "LATIN SMALL LETTER DOUBLE R", "LATIN SMALL LETTER R WITH CROSSED-TAIL",
+        "LATIN SMALL LETTER DOUBLE R WITH CROSSED-TAIL", "LATIN SMALL LETTER SCRIPT R",
+        "LATIN SMALL LETTER SCRIPT R WITH RING", "LATIN SMALL LETTER BASELINE ESH",
+        "LATIN SMALL LETTER U WITH SHORT RIGHT LEG",
+        "LATIN SMALL LETTER U BAR WITH SHORT RIGHT LEG", "LATIN SMALL LETTER UI",
+        "LATIN SMALL LETTER TURNED UI", "LATIN SMALL LETTER U WITH LEFT HOOK",
+        "LATIN SMALL LETTER CHI", "LATIN SMALL LETTER CHI WITH LOW RIGHT RING",
+        "LATIN SMALL LETTER CHI WITH LOW LEFT SERIF", "LATIN SMALL LETTER X WITH LOW RIGHT RING",
+        "LATIN SMALL LETTER X WITH LONG LEFT LEG",
+        "LATIN SMALL LETTER X WITH LONG LEFT LEG AND LOW RIGHT RING",
+        "LATIN SMALL LETTER X WITH LONG LEFT LEG WITH SERIF",
+        "LATIN SMALL LETTER Y WITH SHORT RIGHT LEG", "MODIFIER BREVE WITH INVERTED BREVE",
+        "MODIFIER LETTER SMALL HENG", "MODIFIER LETTER SMALL L WITH INVERTED LAZY S",
+        "MODIFIER LETTER SMALL L WITH MIDDLE TILDE", "MODIFIER LETTER SMALL U WITH LEFT HOOK",
+        "LATIN SMALL LETTER SAKHA YAT", "LATIN SMALL LETTER IOTIFIED E",
+        "LATIN SMALL LETTER OPEN OE", "LATIN SMALL LETTER UO", "LATIN SMALL LETTER INVERTED ALPHA",
+        "GREEK LETTER SMALL CAPITAL OMEGA", "LATIN SMALL LETTER DZ DIGRAPH WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER TS DIGRAPH WITH RETROFLEX HOOK",
+        "LATIN SMALL LETTER TURNED R WITH MIDDLE TILDE", "MODIFIER LETTER SMALL TURNED W",
+        "MODIFIER LETTER LEFT TACK", "MODIFIER LETTER RIGHT TACK",
+    ]),
+    (0xFB00, &[
+        "LATIN SMALL LIGATURE FF", "LATIN SMALL LIGATURE FI", "LATIN SMALL LIGATURE FL",
+        "LATIN SMALL LIGATURE FFI", "LATIN SMALL LIGATURE FFL", "LATIN SMALL LIGATURE LONG S T",
+        "LATIN SMALL LIGATURE ST",
+    ]),
+    (0xFB13, &[
+        "ARMENIAN SMALL LIGATURE MEN NOW", "ARMENIAN SMALL LIGATURE MEN ECH",
+        "ARMENIAN SMALL LIGATURE MEN INI", "ARMENIAN SMALL LIGATURE VEW NOW",
+        "ARMENIAN SMALL LIGATURE MEN XEH",
+    ]),
+    (0xFB1D, &[
+        "HEBREW LETTER YOD WITH HIRIQ", "HEBREW POINT JUDEO-SPANISH VARIKA",
+        "HEBREW LIGATURE YIDDISH YOD YOD PATAH", "HEBREW LETTER ALTERNATIVE AYIN",
+        "HEBREW LETTER WIDE ALEF", "HEBREW LETTER WIDE DALET", "HEBREW LETTER WIDE HE",
+        "HEBREW LETTER WIDE KAF", "HEBREW LETTER WIDE LAMED", "HEBREW LETTER WIDE FINAL MEM",
+        "HEBREW LETTER WIDE RESH", "HEBREW LETTER WIDE TAV", "HEBREW LETTER ALTERNATIVE PLUS SIGN",
+        "HEBREW LETTER SHIN WITH SHIN DOT", "HEBREW LETTER SHIN WITH SIN DOT",
+        "HEBREW LETTER SHIN WITH DAGESH AND SHIN DOT", "HEBREW LETTER SHIN WITH DAGESH AND SIN DOT",
+        "HEBREW LETTER ALEF WITH PATAH", "HEBREW LETTER ALEF WITH QAMATS",
+        "HEBREW LETTER ALEF WITH MAPIQ", "HEBREW LETTER BET WITH DAGESH",
+        "HEBREW LETTER GIMEL WITH DAGESH", "HEBREW LETTER DALET WITH DAGESH",
+        "HEBREW LETTER HE WITH MAPIQ", "HEBREW LETTER VAV WITH DAGESH",
+        "HEBREW LETTER ZAYIN WITH DAGESH", "", "HEBREW LETTER TET WITH DAGESH",
+        "HEBREW LETTER YOD WITH DAGESH", "HEBREW LETTER FINAL KAF WITH DAGESH",
+        "HEBREW LETTER KAF WITH DAGESH", "HEBREW LETTER LAMED WITH DAGESH", "",
+        "HEBREW LETTER MEM WITH DAGESH", "", "HEBREW LETTER NUN WITH DAGESH",
+        "HEBREW LETTER SAMEKH WITH DAGESH", "", "HEBREW LETTER FINAL PE WITH DAGESH",
+        "HEBREW LETTER PE WITH DAGESH", "", "HEBREW LETTER TSADI WITH DAGESH",
+        "HEBREW LETTER QOF WITH DAGESH", "HEBREW LETTER RESH WITH DAGESH",
+        "HEBREW LETTER SHIN WITH DAGESH", "HEBREW LETTER TAV WITH DAGESH",
+        "HEBREW LETTER VAV WITH HOLAM", "HEBREW LETTER BET WITH RAFE",
+        "HEBREW LETTER KAF WITH RAFE", "HEBREW LETTER PE WITH RAFE", "HEBREW LIGATURE ALEF LAMED",
+    ]),
+    (0xFE20, &[
+        "COMBINING LIGATURE LEFT HALF", "COMBINING LIGATURE RIGHT HALF",
+        "COMBINING DOUBLE TILDE LEFT HALF", "COMBINING DOUBLE TILDE RIGHT HALF",
+        "COMBINING MACRON LEFT HALF", "COMBINING MACRON RIGHT HALF", "COMBINING CONJOINING MACRON",
+        "COMBINING LIGATURE LEFT HALF BELOW", "COMBINING LIGATURE RIGHT HALF BELOW",
+        "COMBINING TILDE LEFT HALF BELOW", "COMBINING TILDE RIGHT HALF BELOW",
+        "COMBINING MACRON LEFT HALF BELOW", "COMBINING MACRON RIGHT HALF BELOW",
+        "COMBINING CONJOINING MACRON BELOW", "COMBINING CYRILLIC TITLO LEFT HALF",
+        "COMBINING CYRILLIC TITLO RIGHT HALF",
+    ]),
+    (0xFFF9, &[
+        "INTERLINEAR ANNOTATION ANCHOR", "INTERLINEAR ANNOTATION SEPARATOR",
+        "INTERLINEAR ANNOTATION TERMINATOR", "OBJECT REPLACEMENT CHARACTER",
+        "REPLACEMENT CHARACTER",
+    ]),
+];
+
+/// Blocks whose characters are named by a prefix and their codepoint
+const CODEPOINT_NAMED_BLOCKS: &[(&str, &str)] = &[
+    ("CJK Unified Ideographs", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension A", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension B", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension C", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension D", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension E", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension F", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension G", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension H", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Unified Ideographs Extension I", "CJK UNIFIED IDEOGRAPH-"),
+    ("CJK Compatibility Ideographs", "CJK COMPATIBILITY IDEOGRAPH-"),
+    ("CJK Compatibility Ideographs Supplement", "CJK COMPATIBILITY IDEOGRAPH-"),
+    ("Tangut", "TANGUT IDEOGRAPH-"),
+    ("Tangut Supplement", "TANGUT IDEOGRAPH-"),
+    ("Khitan Small Script", "KHITAN SMALL SCRIPT CHARACTER-"),
+    ("Nushu", "NUSHU CHARACTER-"),
+    ("Egyptian Hieroglyphs Extended-A", "EGYPTIAN HIEROGLYPH-"),
+];
+
+// Hangul syllable composition (Unicode chapter 3.12)
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_COUNT: u32 = 11172;
+const HANGUL_LEADING: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P", "H",
+];
+const HANGUL_VOWELS: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE", "WI",
+    "YU", "EU", "YI", "I",
+];
+const HANGUL_TRAILING: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M", "B",
+    "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// Normalize a property value name for loose matching (UAX #44 LM3)
+///
+/// Case, spaces, hyphens and underscores are ignored, so "latin_extended_a"
+/// matches "Latin Extended-A".
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Name of the block a codepoint belongs to, if any
+pub fn block_name(codepoint: u32) -> Option<&'static str> {
+    let index = BLOCKS.partition_point(|&(_, last, _)| last < codepoint);
+    BLOCKS
+        .get(index)
+        .filter(|&&(first, _, _)| first <= codepoint)
+        .map(|&(_, _, name)| name)
+}
+
+/// Find a block by name, returning its inclusive codepoint range and name
+pub fn find_block(name: &str) -> Option<(u32, u32, &'static str)> {
+    let wanted = normalize_name(name);
+    BLOCKS.iter().copied().find(|&(_, _, block)| normalize_name(block) == wanted)
+}
+
+/// Two-letter general category of a codepoint, e.g. "Lu"
+pub fn general_category(codepoint: u32) -> &'static str {
+    let index = GENERAL_CATEGORIES.partition_point(|&(start, _)| start <= codepoint);
+    GENERAL_CATEGORIES
+        .get(index.wrapping_sub(1))
+        .map(|&(_, category)| category)
+        .unwrap_or("Cn")
+}
+
+/// Long name of a general category, e.g. "Uppercase Letter" for "Lu"
+pub fn general_category_name(category: &str) -> &'static str {
+    match category {
+        "Lu" => "Uppercase Letter",
+        "Ll" => "Lowercase Letter",
+        "Lt" => "Titlecase Letter",
+        "Lm" => "Modifier Letter",
+        "Lo" => "Other Letter",
+        "Mn" => "Nonspacing Mark",
+        "Mc" => "Spacing Mark",
+        "Me" => "Enclosing Mark",
+        "Nd" => "Decimal Number",
+        "Nl" => "Letter Number",
+        "No" => "Other Number",
+        "Pc" => "Connector Punctuation",
+        "Pd" => "Dash Punctuation",
+        "Ps" => "Open Punctuation",
+        "Pe" => "Close Punctuation",
+        "Pi" => "Initial Punctuation",
+        "Pf" => "Final Punctuation",
+        "Po" => "Other Punctuation",
+        "Sm" => "Math Symbol",
+        "Sc" => "Currency Symbol",
+        "Sk" => "Modifier Symbol",
+        "So" => "Other Symbol",
+        "Zs" => "Space Separator",
+        "Zl" => "Line Separator",
+        "Zp" => "Paragraph Separator",
+        "Cc" => "Control",
+        "Cf" => "Format",
+        "Cs" => "Surrogate",
+        "Co" => "Private Use",
+        _ => "Unassigned",
+    }
+}
+
+/// Name of a Hangul syllable, e.g. "HANGUL SYLLABLE GAG"
+fn hangul_syllable_name(codepoint: u32) -> Option<String> {
+    let index = codepoint.checked_sub(HANGUL_BASE).filter(|index| *index < HANGUL_COUNT)?;
+    let leading = HANGUL_LEADING[(index / (21 * 28)) as usize];
+    let vowel = HANGUL_VOWELS[(index % (21 * 28) / 28) as usize];
+    let trailing = HANGUL_TRAILING[(index % 28) as usize];
+    Some(format!("HANGUL SYLLABLE {}{}{}", leading, vowel, trailing))
+}
+
+/// Unicode name of a character, if it is assigned and embedded or derivable
+pub fn character_name(codepoint: u32) -> Option<String> {
+    if general_category(codepoint) == "Cn" {
+        return None;
+    }
+    if let Some(name) = hangul_syllable_name(codepoint) {
+        return Some(name);
+    }
+    let block = block_name(codepoint)?;
+    if let Some(&(_, prefix)) = CODEPOINT_NAMED_BLOCKS.iter().find(|&&(named, _)| named == block) {
+        return Some(format!("{}{:04X}", prefix, codepoint));
+    }
+    CHARACTER_NAMES.iter().find_map(|&(start, names)| {
+        codepoint
+            .checked_sub(start)
+            .and_then(|offset| names.get(offset as usize))
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+    })
+}
+
+/// A codepoint argument as a character, rejecting surrogates and values past U+10FFFF
+fn codepoint_char(codepoint: u32) -> Result<char, ApiError> {
+    char::from_u32(codepoint).ok_or_else(|| ApiError::invalid_argument("codepoint", codepoint))
+}
+
+/// Get the Unicode properties of a character
+///
+/// For the "add glyph by character" dialog. Character names are embedded
+/// for Latin, Greek, Cyrillic, IPA, punctuation and common symbol blocks
+/// and derived for CJK ideographs and Hangul syllables; other characters
+/// have no name. The decomposition is the full canonical decomposition,
+/// else the full compatibility decomposition, if the character has one.
+///
+/// # Arguments
+/// * `codepoint` - Unicode codepoint
+///
+/// # Returns
+/// * `String` - JSON object: '{"codepoint": 193, "hex": "U+00C1", "char": "Á", "name": "LATIN CAPITAL LETTER A WITH ACUTE", "generalCategory": "Lu", "categoryName": "Uppercase Letter", "script": "Latin", "block": "Latin-1 Supplement", "bidiClass": "L", "decomposition": {"type": "canonical", "codepoints": [65, 769]}, "suggestedGlyphName": "Aacute"}'
+#[wasm_bindgen]
+pub fn get_char_info(codepoint: u32) -> Result<String, JsValue> {
+    let character = codepoint_char(codepoint)?;
+    let category = general_category(codepoint);
+
+    let mut canonical = Vec::new();
+    unicode_normalization::char::decompose_canonical(character, |part| canonical.push(part as u32));
+    let mut compatible = Vec::new();
+    unicode_normalization::char::decompose_compatible(character, |part| compatible.push(part as u32));
+    let decomposition = if canonical != [codepoint] {
+        json!({ "type": "canonical", "codepoints": canonical })
+    } else if compatible != [codepoint] {
+        json!({ "type": "compatibility", "codepoints": compatible })
+    } else {
+        serde_json::Value::Null
+    };
+
+    let info = json!({
+        "codepoint": codepoint,
+        "hex": format!("U+{:04X}", codepoint),
+        "char": character.to_string(),
+        "name": character_name(codepoint),
+        "generalCategory": category,
+        "categoryName": general_category_name(category),
+        "script": character.script().full_name(),
+        "block": block_name(codepoint),
+        "bidiClass": format!("{:?}", bidi_class(character)),
+        "decomposition": decomposition,
+        "suggestedGlyphName": suggested_glyph_name(codepoint),
+    });
+    serde_json::to_string(&info)
+        .map_err(|e| ApiError::serialization("result", e).into())
+}
+
+/// Suggest a glyph name for a character
+///
+/// Common Latin, Greek and Cyrillic characters get their customary
+/// (AGL/Glyphs style) names; other characters get "uniXXXX" or "uXXXXX".
+///
+/// # Arguments
+/// * `codepoint` - Unicode codepoint
+///
+/// # Returns
+/// * `String` - Glyph name, e.g., "Aacute"
+#[wasm_bindgen]
+pub fn suggest_glyph_name(codepoint: u32) -> Result<String, JsValue> {
+    codepoint_char(codepoint)?;
+    Ok(suggested_glyph_name(codepoint))
+}
@@ -0,0 +1,234 @@
+// Unicode Variation Sequences module
+//
+// This module stores Unicode Variation Sequences (UVS) on source glyphs and
+// writes them into a cmap format 14 subtable after compilation.
+// Sequences are kept in each glyph's format-specific data so they survive
+// saving and loading the .babelfont file.
+
+use read_fonts::{FontRef, TableProvider};
+use std::collections::{BTreeMap, HashMap};
+use write_fonts::from_obj::ToOwnedTable;
+use write_fonts::tables::cmap::{
+    Cmap, Cmap14, CmapSubtable, DefaultUvs, EncodingRecord, NonDefaultUvs, PlatformId, UnicodeRange,
+    UvsMapping, VariationSelector,
+};
+use write_fonts::types::{GlyphId16, Uint24};
+use write_fonts::FontBuilder;
+
+use crate::errors::ApiError;
+
+/// Format-specific key under which a glyph's variation sequences are stored
+pub const VARIATION_SEQUENCES_KEY: &str = "space.counterpunch.variationSequences";
+
+/// A variation sequence (base character + variation selector) that maps to a glyph
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VariationSequence {
+    pub base: u32,
+    pub selector: u32,
+}
+
+impl VariationSequence {
+    /// Parse a sequence from a JSON object: `{"base": 33883, "selector": 917760}`
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        Some(VariationSequence {
+            base: value.get("base")?.as_u64()? as u32,
+            selector: value.get("selector")?.as_u64()? as u32,
+        })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "base": self.base, "selector": self.selector })
+    }
+}
+
+/// A variation sequence collected from the source, with the glyph it maps to
+pub struct SourceSequence {
+    pub sequence: VariationSequence,
+    pub glyph_name: String,
+    /// Names the glyph may have in the compiled font (friendly, production, uniXXXX)
+    pub candidate_names: Vec<String>,
+}
+
+/// Read the variation sequences stored on a glyph
+pub fn glyph_sequences(glyph: &babelfont::Glyph) -> Vec<VariationSequence> {
+    glyph
+        .format_specific
+        .get(VARIATION_SEQUENCES_KEY)
+        .and_then(|value| value.as_array())
+        .map(|values| values.iter().filter_map(VariationSequence::from_json).collect())
+        .unwrap_or_default()
+}
+
+/// Replace the variation sequences stored on a glyph
+///
+/// Selectors must be in the variation selector blocks (U+FE00–U+FE0F or
+/// U+E0100–U+E01EF).
+pub fn set_glyph_sequences(glyph: &mut babelfont::Glyph, mut sequences: Vec<VariationSequence>) -> Result<(), ApiError> {
+    for sequence in &sequences {
+        let valid_selector = (0xFE00..=0xFE0F).contains(&sequence.selector)
+            || (0xE0100..=0xE01EF).contains(&sequence.selector);
+        if !valid_selector {
+            return Err(ApiError::invalid_argument("variation selector", format!("U+{:04X}", sequence.selector)));
+        }
+    }
+
+    sequences.sort();
+    sequences.dedup();
+
+    if sequences.is_empty() {
+        glyph.format_specific.remove(VARIATION_SEQUENCES_KEY);
+    } else {
+        let value = serde_json::Value::Array(sequences.iter().map(VariationSequence::to_json).collect());
+        glyph.format_specific.insert(VARIATION_SEQUENCES_KEY.to_string(), value);
+    }
+    Ok(())
+}
+
+/// Names a source glyph may have in the compiled font (friendly, production, uniXXXX)
+pub(crate) fn compiled_names(glyph: &babelfont::Glyph) -> Vec<String> {
+    let mut names = vec![glyph.name.to_string()];
+    if let Some(production_name) = &glyph.production_name {
+        names.push(production_name.to_string());
+    }
+    if let Some(codepoint) = glyph.codepoints.first() {
+        names.push(if *codepoint <= 0xFFFF {
+            format!("uni{:04X}", codepoint)
+        } else {
+            format!("u{:05X}", codepoint)
+        });
+    }
+    names
+}
+
+/// Collect all variation sequences of exported glyphs in a source font
+pub fn collect_sequences(font: &babelfont::Font) -> Vec<SourceSequence> {
+    let mut collected = Vec::new();
+
+    for glyph in font.glyphs.iter() {
+        if !glyph.exported {
+            continue;
+        }
+
+        let sequences = glyph_sequences(glyph);
+        if sequences.is_empty() {
+            continue;
+        }
+
+        let candidate_names = compiled_names(glyph);
+
+        for sequence in sequences {
+            collected.push(SourceSequence {
+                sequence,
+                glyph_name: glyph.name.to_string(),
+                candidate_names: candidate_names.clone(),
+            });
+        }
+    }
+
+    collected
+}
+
+/// Add a cmap format 14 subtable for the given sequences to compiled font bytes
+///
+/// Sequences whose glyph equals the base character's regular cmap mapping are
+/// written as default UVS; all others as non-default UVS. Any existing format
+/// 14 subtable is replaced. Returns the bytes unchanged if there are no sequences.
+pub fn add_to_compiled(font_bytes: Vec<u8>, sequences: &[SourceSequence]) -> Result<Vec<u8>, String> {
+    if sequences.is_empty() {
+        return Ok(font_bytes);
+    }
+
+    let font = FontRef::new(&font_bytes)
+        .map_err(|e| format!("Failed to parse compiled font: {:?}", e))?;
+    let read_cmap = font.cmap()
+        .map_err(|e| format!("Failed to read cmap table: {:?}", e))?;
+
+    // Map compiled glyph names to glyph IDs
+    let glyph_order = crate::font_reader::read_glyph_order(&font)
+        .map_err(|e| e.to_string())?;
+    let name_to_gid: HashMap<&str, u16> = glyph_order
+        .iter()
+        .enumerate()
+        .map(|(gid, name)| (name.as_str(), gid as u16))
+        .collect();
+
+    // selector -> (default bases, non-default (base, gid))
+    let mut by_selector: BTreeMap<u32, (Vec<u32>, Vec<(u32, u16)>)> = BTreeMap::new();
+
+    for source in sequences {
+        let gid = source
+            .candidate_names
+            .iter()
+            .find_map(|name| name_to_gid.get(name.as_str()).copied())
+            .ok_or_else(|| format!(
+                "Glyph '{}' used by variation sequence U+{:04X} U+{:04X} is not in the compiled font",
+                source.glyph_name, source.sequence.base, source.sequence.selector
+            ))?;
+
+        let entry = by_selector.entry(source.sequence.selector).or_default();
+        let default_gid = read_cmap.map_codepoint(source.sequence.base).map(|g| g.to_u32());
+        if default_gid == Some(gid as u32) {
+            entry.0.push(source.sequence.base);
+        } else {
+            entry.1.push((source.sequence.base, gid));
+        }
+    }
+
+    let mut var_selectors = Vec::with_capacity(by_selector.len());
+    for (selector, (mut default_bases, mut non_default)) in by_selector {
+        default_bases.sort();
+        default_bases.dedup();
+        non_default.sort();
+        non_default.dedup_by_key(|(base, _)| *base);
+
+        // Merge consecutive default bases into ranges (at most 256 per range)
+        let mut ranges: Vec<(u32, u8)> = Vec::new();
+        for base in default_bases {
+            match ranges.last_mut() {
+                Some((start, count)) if *start + *count as u32 + 1 == base && *count < u8::MAX => *count += 1,
+                _ => ranges.push((base, 0)),
+            }
+        }
+
+        let default_uvs = (!ranges.is_empty()).then(|| {
+            DefaultUvs::new(
+                ranges
+                    .into_iter()
+                    .map(|(start, count)| UnicodeRange::new(Uint24::new(start), count))
+                    .collect(),
+            )
+        });
+        let non_default_uvs = (!non_default.is_empty()).then(|| {
+            NonDefaultUvs::new(
+                non_default
+                    .into_iter()
+                    .map(|(base, gid)| UvsMapping::new(Uint24::new(base), GlyphId16::new(gid)))
+                    .collect(),
+            )
+        });
+
+        var_selectors.push(VariationSelector::new(Uint24::new(selector), default_uvs, non_default_uvs));
+    }
+
+    let mut cmap: Cmap = read_cmap.to_owned_table();
+    cmap.encoding_records
+        .retain(|record| !matches!(&*record.subtable, CmapSubtable::Format14(_)));
+
+    // Format 14 lives under platform 0 (Unicode), encoding 5, after the other Unicode records
+    let position = cmap
+        .encoding_records
+        .iter()
+        .position(|record| record.platform_id != PlatformId::Unicode)
+        .unwrap_or(cmap.encoding_records.len());
+    cmap.encoding_records.insert(
+        position,
+        EncodingRecord::new(PlatformId::Unicode, 5, CmapSubtable::Format14(Cmap14::new(var_selectors))),
+    );
+
+    let mut builder = FontBuilder::new();
+    builder
+        .add_table(&cmap)
+        .map_err(|e| format!("Failed to write cmap table: {:?}", e))?;
+    builder.copy_missing_tables(font);
+    Ok(builder.build())
+}
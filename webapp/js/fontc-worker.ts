@@ -5,6 +5,7 @@
 import init, {
     compile_babelfont,
     store_font,
+    close_font,
     interpolate_glyph,
     clear_font_cache,
     open_font_file,
@@ -14,6 +15,13 @@ import init, {
 
 let initialized = false;
 let cachedBabelfontJson: string | null = null; // Cache babelfont JSON for re-use
+let fontId: number | null = null; // Handle of the font stored in WASM
+
+// Store the font in WASM, replacing the font stored before so the worker
+// keeps a single font session
+function storeFont(babelfontJson: string) {
+    fontId = store_font(babelfontJson, fontId ?? undefined);
+}
 
 console.log('[Fontc Worker] Starting...');
 
@@ -151,7 +159,7 @@ self.onmessage = async (event) => {
             );
 
             // Store in cache (both in WASM and in worker)
-            store_font(babelfontJson);
+            storeFont(babelfontJson);
             cachedBabelfontJson = babelfontJson;
             console.log(
                 '[Fontc Worker] ✅ Font JSON cached in worker memory, cachedBabelfontJson is now:',
@@ -203,7 +211,12 @@ self.onmessage = async (event) => {
             );
 
             const locationJson = JSON.stringify(location);
-            const layerJson = interpolate_glyph(glyphName, locationJson);
+            const layerJson = interpolate_glyph(
+                glyphName,
+                locationJson,
+                undefined,
+                fontId ?? undefined
+            );
 
             console.log(
                 `[Fontc Worker] ✅ Interpolation successful for '${glyphName}', layer JSON length:`,
@@ -232,6 +245,7 @@ self.onmessage = async (event) => {
     if (data.type === 'clearCache') {
         try {
             clear_font_cache();
+            fontId = null;
             console.log('[Fontc Worker] 🗑️ Font cache cleared');
             self.postMessage({
                 type: 'clearCache',
@@ -295,13 +309,18 @@ self.onmessage = async (event) => {
                 contentsStr.substring(0, 100)
             );
 
-            const babelfontJson = open_font_file(filename, contentsStr);
+            // open_font_file() stores the font in WASM already, under a new
+            // handle; close the font opened before
+            const opened = open_font_file(filename, contentsStr);
+            if (fontId !== null && fontId !== opened.fontId) {
+                close_font(fontId);
+            }
+            fontId = opened.fontId;
+            const babelfontJson: string = opened.font;
             console.log(
                 `[Fontc Worker] Successfully converted to babelfont JSON (${babelfontJson.length} bytes)`
             );
 
-            // Store in cache (both in WASM and in worker)
-            store_font(babelfontJson);
             cachedBabelfontJson = babelfontJson;
             console.log('[Fontc Worker] Font cached in worker memory');
 
@@ -363,7 +382,9 @@ self.onmessage = async (event) => {
             const outlinesJson = get_glyphs_outlines(
                 glyphNamesJson,
                 locationJson,
-                flattenComponents
+                flattenComponents,
+                undefined,
+                fontId ?? undefined
             );
 
             console.log(
@@ -423,7 +444,7 @@ self.onmessage = async (event) => {
 
             // STEP 1: Store font in WASM cache for interpolation
             try {
-                store_font(babelfontJson);
+                storeFont(babelfontJson);
                 cachedBabelfontJson = babelfontJson; // Also cache in worker memory
                 console.log('[Fontc Worker] ✅ Font cached in WASM memory');
             } catch (cacheError) {
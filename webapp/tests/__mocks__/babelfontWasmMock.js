@@ -37,12 +37,16 @@ initBabelfontWasm.compile_babelfont = jest.fn(
 initBabelfontWasm.compile_cached_font = jest.fn(
     (options) => new Uint8Array(100)
 );
-initBabelfontWasm.store_font = jest.fn((json) => {});
+initBabelfontWasm.store_font = jest.fn((json, fontId) => fontId ?? 1);
+initBabelfontWasm.close_font = jest.fn((fontId) => true);
 initBabelfontWasm.clear_font_cache = jest.fn(() => {});
 initBabelfontWasm.interpolate_glyph = jest.fn((glyphName, locationJson) =>
     JSON.stringify({})
 );
 initBabelfontWasm.version = jest.fn(() => '0.1.0');
-initBabelfontWasm.open_font_file = jest.fn((filename, contents) => '{}');
+initBabelfontWasm.open_font_file = jest.fn((filename, contents) => ({
+    fontId: 1,
+    font: '{}'
+}));
 
 module.exports = initBabelfontWasm;